@@ -0,0 +1,420 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read-through cache of the objects of an [`ObjectStore`] on the local disk
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use object_store::path::Path;
+use object_store::{GetResult, ListResult, MultipartId, ObjectMeta, ObjectStore, Result};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::fs::{self, File};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+
+/// Wraps an [`ObjectStore`] and caches the objects read from it in a local
+/// directory, so that the objects read by repeated queries are only
+/// downloaded once.
+///
+/// An object is downloaded entirely the first time it is read, streamed to
+/// its cached file, and the following reads, including those of ranges, are
+/// served from the cached file. The cached files are named after a stable hash
+/// of the location, the size and the
+/// last modification time of the objects: the objects modified since they
+/// were cached are downloaded again once their new metadata is known, from
+/// [`ObjectStore::head`] or [`ObjectStore::list`]. The cached files are also
+/// used by the next sessions sharing the directory.
+///
+/// The concurrent reads of an object that is not cached yet wait for a single
+/// download of the object.
+#[derive(Debug)]
+pub struct CachedObjectStore {
+    inner: Arc<dyn ObjectStore>,
+    /// The directory of the cached files
+    dir: PathBuf,
+    /// The last known metadata of the objects, by location
+    metas: Mutex<HashMap<Path, ObjectMeta>>,
+    /// The locks of the cached files being downloaded, by path
+    downloads: Mutex<HashMap<PathBuf, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+/// Numbers the downloads of the process, so that each is written to its own
+/// temporary file
+static DOWNLOAD_ID: AtomicUsize = AtomicUsize::new(0);
+
+impl CachedObjectStore {
+    /// Creates a store caching the objects of `inner` in `dir`, which is
+    /// created if it does not exist
+    pub fn try_new(inner: Arc<dyn ObjectStore>, dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir).map_err(to_object_store_error)?;
+        Ok(Self {
+            inner,
+            dir,
+            metas: Mutex::new(HashMap::new()),
+            downloads: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn record(&self, meta: &ObjectMeta) {
+        self.metas
+            .lock()
+            .insert(meta.location.clone(), meta.clone());
+    }
+
+    fn forget(&self, location: &Path) {
+        self.metas.lock().remove(location);
+    }
+
+    /// Returns the path of the cached file of `location`, downloading the
+    /// object if it is not cached yet
+    async fn cached_file(&self, location: &Path) -> Result<PathBuf> {
+        let known = self.metas.lock().get(location).cloned();
+        let meta = match known {
+            Some(meta) => meta,
+            None => self.head(location).await?,
+        };
+
+        let key = format!(
+            "{}\n{}\n{}",
+            meta.location,
+            meta.size,
+            meta.last_modified.to_rfc3339()
+        );
+        let path = self
+            .dir
+            .join(format!("{:016x}", fnv1a_hash(key.as_bytes())));
+        if fs::metadata(&path).await.is_ok() {
+            return Ok(path);
+        }
+
+        // the reads of this process wait for the download in progress, if any
+        let lock = self
+            .downloads
+            .lock()
+            .entry(path.clone())
+            .or_default()
+            .clone();
+        let _guard = lock.lock().await;
+        let result = if fs::metadata(&path).await.is_ok() {
+            Ok(())
+        } else {
+            self.download(location, &path).await
+        };
+        self.downloads.lock().remove(&path);
+        result.map(|_| path)
+    }
+
+    /// Downloads the object at `location` to the cached file `path`.
+    ///
+    /// The object is written to a temporary file unique to the download, then
+    /// renamed to `path`, so that the cached files are always complete, even
+    /// when other processes sharing the directory download the same object.
+    async fn download(&self, location: &Path, path: &std::path::Path) -> Result<()> {
+        let download = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            DOWNLOAD_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let result = async {
+            let mut file = File::create(&download)
+                .await
+                .map_err(to_object_store_error)?;
+            let mut stream = self.inner.get(location).await?.into_stream();
+            while let Some(bytes) = stream.next().await {
+                file.write_all(&bytes?)
+                    .await
+                    .map_err(to_object_store_error)?;
+            }
+            file.flush().await.map_err(to_object_store_error)?;
+            fs::rename(&download, path)
+                .await
+                .map_err(to_object_store_error)
+        }
+        .await;
+        if result.is_err() {
+            let _ = fs::remove_file(&download).await;
+            // another process completed the same download
+            if fs::metadata(path).await.is_ok() {
+                return Ok(());
+            }
+        }
+        result
+    }
+}
+
+/// Returns the 64 bits FNV-1a hash of `bytes`, which unlike the hash of
+/// [`std::collections::hash_map::DefaultHasher`] is the same in every version
+/// of Rust, so that the names of the cached files are stable
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn to_object_store_error(e: std::io::Error) -> object_store::Error {
+    object_store::Error::Generic {
+        store: "CachedObjectStore",
+        source: Box::new(e),
+    }
+}
+
+impl Display for CachedObjectStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CachedObjectStore({}, {})",
+            self.inner,
+            self.dir.display()
+        )
+    }
+}
+
+#[async_trait]
+impl ObjectStore for CachedObjectStore {
+    async fn put(&self, location: &Path, bytes: Bytes) -> Result<()> {
+        self.forget(location);
+        self.inner.put(location, bytes).await
+    }
+
+    async fn put_multipart(
+        &self,
+        location: &Path,
+    ) -> Result<(MultipartId, Box<dyn AsyncWrite + Unpin + Send>)> {
+        self.forget(location);
+        self.inner.put_multipart(location).await
+    }
+
+    async fn abort_multipart(
+        &self,
+        location: &Path,
+        multipart_id: &MultipartId,
+    ) -> Result<()> {
+        self.inner.abort_multipart(location, multipart_id).await
+    }
+
+    async fn get(&self, location: &Path) -> Result<GetResult> {
+        let path = self.cached_file(location).await?;
+        let file = File::open(&path).await.map_err(to_object_store_error)?;
+        Ok(GetResult::File(file.into_std().await, path))
+    }
+
+    async fn get_range(&self, location: &Path, range: Range<usize>) -> Result<Bytes> {
+        let path = self.cached_file(location).await?;
+        let mut file = File::open(path).await.map_err(to_object_store_error)?;
+        file.seek(SeekFrom::Start(range.start as u64))
+            .await
+            .map_err(to_object_store_error)?;
+        let mut buffer = vec![0; range.end - range.start];
+        file.read_exact(&mut buffer)
+            .await
+            .map_err(to_object_store_error)?;
+        Ok(buffer.into())
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        let meta = self.inner.head(location).await?;
+        self.record(&meta);
+        Ok(meta)
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        self.forget(location);
+        self.inner.delete(location).await
+    }
+
+    async fn list(
+        &self,
+        prefix: Option<&Path>,
+    ) -> Result<BoxStream<'_, Result<ObjectMeta>>> {
+        let stream = self.inner.list(prefix).await?;
+        Ok(stream
+            .map(move |meta| {
+                if let Ok(meta) = &meta {
+                    self.record(meta);
+                }
+                meta
+            })
+            .boxed())
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        let result = self.inner.list_with_delimiter(prefix).await?;
+        result.objects.iter().for_each(|meta| self.record(meta));
+        Ok(result)
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        self.forget(to);
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.forget(to);
+        self.inner.copy_if_not_exists(from, to).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object_storage::DatafusionCliObjectStoreProvider;
+    use object_store::memory::InMemory;
+    use tokio::net::TcpListener;
+    use url::Url;
+
+    #[tokio::test]
+    async fn read_through_cache() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let inner = Arc::new(InMemory::new());
+        let location = Path::from("data/1.csv");
+        inner.put(&location, Bytes::from("a,b\n1,2\n")).await?;
+
+        let store = CachedObjectStore::try_new(inner.clone(), dir.path())?;
+        let meta = store.list(None).await?.next().await.unwrap()?;
+        assert_eq!(meta.size, 8);
+        assert_eq!(store.get_range(&location, 4..7).await?, Bytes::from("1,2"));
+
+        // the object is read from the cached file once downloaded
+        inner.delete(&location).await?;
+        assert_eq!(store.get_range(&location, 0..3).await?, Bytes::from("a,b"));
+        assert_eq!(
+            store.get(&location).await?.bytes().await?,
+            Bytes::from("a,b\n1,2\n")
+        );
+
+        // the objects modified are downloaded again
+        let new_location = Path::from("data/2.csv");
+        store.put(&new_location, Bytes::from("a,b\n3,4\n")).await?;
+        assert_eq!(
+            store.get_range(&new_location, 4..7).await?,
+            Bytes::from("3,4")
+        );
+        store
+            .put(&new_location, Bytes::from("a,b\n5,6\n7,8\n"))
+            .await?;
+        assert_eq!(
+            store.get_range(&new_location, 8..11).await?,
+            Bytes::from("7,8")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn stable_file_names() {
+        assert_eq!(fnv1a_hash(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a_hash(b"a"), 0xaf63dc4c8601ec8c);
+    }
+
+    /// Serves `body` at every path over HTTP, answering the `PROPFIND`
+    /// requests of the metadata as a WebDAV server, until `requests`
+    /// requests were served
+    async fn serve_http(body: &'static str, requests: usize) -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            for _ in 0..requests {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = vec![];
+                let mut buffer = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = socket.read(&mut buffer).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buffer[..n]);
+                }
+                let request = String::from_utf8(request).unwrap();
+                let (status, content) = if request.starts_with("PROPFIND ") {
+                    let path = request.split_whitespace().nth(1).unwrap();
+                    let content = format!(
+                        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+                        <multistatus xmlns=\"DAV:\"><response><href>{path}</href>\
+                        <propstat><prop>\
+                        <getlastmodified>Wed, 01 Mar 2023 00:00:00 GMT</getlastmodified>\
+                        <getcontentlength>{}</getcontentlength><resourcetype/>\
+                        </prop><status>HTTP/1.1 200 OK</status></propstat>\
+                        </response></multistatus>",
+                        body.len()
+                    );
+                    ("207 Multi-Status", content)
+                } else {
+                    ("200 OK", body.to_string())
+                };
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\n\
+                    Connection: close\r\n\r\n{content}",
+                    content.len()
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.shutdown().await.unwrap();
+            }
+        });
+        Url::parse(&url).unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn concurrent_reads_download_once() -> Result<()> {
+        // the metadata and the object are requested once
+        let url = serve_http("a,b\n1,2\n", 2).await;
+        let dir = tempfile::tempdir().unwrap();
+        let provider = DatafusionCliObjectStoreProvider::new().with_cache_dir(dir.path());
+        let store = provider
+            .build_object_store(&url, &HashMap::new())
+            .expect("the HTTP store is built");
+
+        let location = Path::from("data/1.csv");
+        store.head(&location).await?;
+        let ranges = futures::future::try_join_all(
+            (0..8).map(|_| store.get_range(&location, 4..7)),
+        )
+        .await?;
+        assert!(ranges.iter().all(|range| range == &Bytes::from("1,2")));
+
+        // no temporary file is left
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cache_http_store() -> Result<()> {
+        // the metadata and the object are requested once
+        let url = serve_http("a,b\n1,2\n", 2).await;
+        let dir = tempfile::tempdir().unwrap();
+        let provider = DatafusionCliObjectStoreProvider::new().with_cache_dir(dir.path());
+        let store = provider
+            .build_object_store(&url, &HashMap::new())
+            .expect("the HTTP store is built");
+        assert!(store.to_string().starts_with("CachedObjectStore("));
+
+        let location = Path::from("data/1.csv");
+        assert_eq!(store.get_range(&location, 4..7).await?, Bytes::from("1,2"));
+        assert_eq!(
+            store.get(&location).await?.bytes().await?,
+            Bytes::from("a,b\n1,2\n")
+        );
+        Ok(())
+    }
+}
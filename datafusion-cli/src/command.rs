@@ -20,15 +20,17 @@
 use crate::exec::exec_from_lines;
 use crate::functions::{display_all_functions, Function};
 use crate::print_format::PrintFormat;
-use crate::print_options::PrintOptions;
+use crate::print_options::{MaxRows, PrintOptions};
 use clap::ArgEnum;
 use datafusion::arrow::array::{ArrayRef, StringArray};
 use datafusion::arrow::datatypes::{DataType, Field, Schema};
 use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::error::{DataFusionError, Result};
+use datafusion::physical_plan::{displayable, ExecutionPlan};
 use datafusion::prelude::SessionContext;
-use std::fs::File;
+use std::fs::{File, OpenOptions};
 use std::io::BufReader;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
@@ -45,12 +47,26 @@ pub enum Command {
     SearchFunctions(String),
     QuietMode(Option<bool>),
     OutputFormat(Option<String>),
+    Timing(Option<bool>),
+    SetConfig(String, String),
+    ShowConfig(Option<String>),
+    Output(Option<String>),
+    Explain(String),
+    Memory,
+    History,
 }
 
 pub enum OutputFormat {
     ChangeFormat(String),
+    ChangeMaxRows(String),
+    ChangePager(String),
 }
 
+/// The pager used by `\pset pager on` if the `PAGER` environment variable is
+/// not set, which only pages the results taller than the terminal and scrolls
+/// the wide results horizontally
+const DEFAULT_PAGER: &str = "less -S -F -X";
+
 impl Command {
     pub async fn execute(
         &self,
@@ -118,9 +134,89 @@ impl Command {
             Self::OutputFormat(_) => Err(DataFusionError::Execution(
                 "Unexpected change output format, this should be handled outside".into(),
             )),
+            Self::Timing(timing) => {
+                if let Some(timing) = timing {
+                    print_options.timing = *timing;
+                    println!(
+                        "Timing set to {}",
+                        if print_options.timing { "on" } else { "off" }
+                    );
+                } else {
+                    println!(
+                        "Timing is {}",
+                        if print_options.timing { "on" } else { "off" }
+                    );
+                }
+                Ok(())
+            }
+            Self::SetConfig(name, value) => {
+                if !is_config_name(name) {
+                    return Err(DataFusionError::Execution(format!(
+                        "Invalid configuration option name {:?}",
+                        name
+                    )));
+                }
+                let sql = format!("SET {} TO '{}'", name, value.replace('\'', "''"));
+                ctx.sql(&sql).await?;
+                Ok(())
+            }
+            Self::ShowConfig(name) => {
+                let df = ctx.sql(&show_config_query(ctx, name.as_deref())).await?;
+                let batches = df.collect().await?;
+                print_options.print_batches(&batches, now)
+            }
+            Self::Output(filename) => {
+                if let Some(filename) = filename {
+                    // fail now rather than when printing the results
+                    OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(filename)
+                        .map_err(|e| {
+                            DataFusionError::Execution(format!(
+                                "Error opening {:?} {}",
+                                filename, e
+                            ))
+                        })?;
+                    print_options.output = Some(PathBuf::from(filename));
+                    println!("Output is written to {}", filename);
+                } else {
+                    print_options.output = None;
+                    println!("Output is written to stdout");
+                }
+                Ok(())
+            }
+            Self::Explain(query) => {
+                let query = query.trim_end().trim_end_matches(';');
+                let plan = ctx.sql(query).await?.create_physical_plan().await?;
+                print!("{}", format_plan_tree(&plan));
+                Ok(())
+            }
+            Self::Memory => {
+                let memory_pool = &ctx.runtime_env().memory_pool;
+                println!(
+                    "Memory reserved: {} bytes ({:?})",
+                    memory_pool.reserved(),
+                    memory_pool
+                );
+                Ok(())
+            }
+            Self::History => Err(DataFusionError::Execution(
+                "Unexpected history, this should be handled outside".into(),
+            )),
         }
     }
 
+    /// Returns the names of all the commands, such as `\d`
+    pub fn names() -> Vec<&'static str> {
+        let mut names = ALL_COMMANDS
+            .iter()
+            .filter_map(|c| c.get_name_and_description().0.split_whitespace().next())
+            .collect::<Vec<_>>();
+        names.dedup();
+        names
+    }
+
     fn get_name_and_description(&self) -> (&'static str, &'static str) {
         match self {
             Self::Quit => ("\\q", "quit datafusion-cli"),
@@ -133,14 +229,62 @@ impl Command {
             Self::ListFunctions => ("\\h", "function list"),
             Self::SearchFunctions(_) => ("\\h function", "search function"),
             Self::QuietMode(_) => ("\\quiet (true|false)?", "print or set quiet mode"),
-            Self::OutputFormat(_) => {
-                ("\\pset [NAME [VALUE]]", "set table output option\n(format)")
-            }
+            Self::OutputFormat(_) => (
+                "\\pset [NAME [VALUE]]",
+                "set table output option\n(format, maxrows, pager)",
+            ),
+            Self::Timing(_) => (
+                "\\timing (on|off)?",
+                "print or set the timing of the query stages",
+            ),
+            Self::SetConfig(_, _) => ("\\set name value", "set a configuration option"),
+            Self::ShowConfig(_) => (
+                "\\show [name]",
+                "show the configuration options\nmatching name",
+            ),
+            Self::Output(_) => (
+                "\\o [filename]",
+                "write the query results to filename,\nor to stdout",
+            ),
+            Self::Explain(_) => ("\\explain query", "show the plan tree of query"),
+            Self::Memory => ("\\memory", "show the memory pool usage"),
+            Self::History => ("\\history", "show the history of the statements"),
         }
     }
 }
 
-const ALL_COMMANDS: [Command; 9] = [
+/// Returns whether `name` is a dotted identifier, such as
+/// `datafusion.execution.batch_size`, which can be used as is in a `SET`
+/// statement
+fn is_config_name(name: &str) -> bool {
+    name.split('.').all(|part| {
+        !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// Returns the query of the configuration options named `name`, if it is the
+/// full name of an option, or else of those whose name contains `name`
+fn show_config_query(ctx: &SessionContext, name: Option<&str>) -> String {
+    let filter = match name {
+        None => String::new(),
+        Some(name) => {
+            let literal = name.replace('\'', "''");
+            let options = ctx.state().config_options().entries();
+            if options.iter().any(|option| option.key == name) {
+                format!(" WHERE name = '{}'", literal)
+            } else {
+                // unlike the pattern of LIKE, the substring has no wildcards
+                format!(" WHERE strpos(name, '{}') > 0", literal)
+            }
+        }
+    };
+    format!(
+        "SELECT name, setting FROM information_schema.df_settings{} ORDER BY name",
+        filter
+    )
+}
+
+const ALL_COMMANDS: [Command; 16] = [
     Command::ListTables,
     Command::DescribeTableStmt(String::new()),
     Command::Quit,
@@ -150,8 +294,43 @@ const ALL_COMMANDS: [Command; 9] = [
     Command::SearchFunctions(String::new()),
     Command::QuietMode(None),
     Command::OutputFormat(None),
+    Command::Timing(None),
+    Command::SetConfig(String::new(), String::new()),
+    Command::ShowConfig(None),
+    Command::Output(None),
+    Command::Explain(String::new()),
+    Command::Memory,
+    Command::History,
 ];
 
+/// Formats `plan` as a tree, with a line for each of its nodes
+fn format_plan_tree(plan: &Arc<dyn ExecutionPlan>) -> String {
+    fn format_node(
+        plan: &Arc<dyn ExecutionPlan>,
+        prefix: &str,
+        is_last: Option<bool>,
+        tree: &mut String,
+    ) {
+        let (connector, indent) = match is_last {
+            None => ("", ""),
+            Some(true) => ("└── ", "    "),
+            Some(false) => ("├── ", "│   "),
+        };
+        let node = displayable(plan.as_ref()).one_line().to_string();
+        tree.push_str(&format!("{prefix}{connector}{}\n", node.trim_end()));
+
+        let prefix = format!("{prefix}{indent}");
+        let children = plan.children();
+        for (i, child) in children.iter().enumerate() {
+            format_node(child, &prefix, Some(i + 1 == children.len()), tree);
+        }
+    }
+
+    let mut tree = String::new();
+    format_node(plan, "", None, &mut tree);
+    tree
+}
+
 fn all_commands_info() -> RecordBatch {
     let schema = Arc::new(Schema::new(vec![
         Field::new("Command", DataType::Utf8, false),
@@ -200,6 +379,24 @@ impl FromStr for Command {
                 Self::OutputFormat(Some(subcommand.to_string()))
             }
             ("pset", None) => Self::OutputFormat(None),
+            ("timing", Some("true" | "t" | "yes" | "y" | "on")) => {
+                Self::Timing(Some(true))
+            }
+            ("timing", Some("false" | "f" | "no" | "n" | "off")) => {
+                Self::Timing(Some(false))
+            }
+            ("timing", None) => Self::Timing(None),
+            ("set", Some(args)) => match args.split_once(' ') {
+                Some((name, value)) => Self::SetConfig(name.into(), value.into()),
+                None => return Err(()),
+            },
+            ("set" | "show", None) => Self::ShowConfig(None),
+            ("show", Some(name)) => Self::ShowConfig(Some(name.into())),
+            ("o", None) => Self::Output(None),
+            ("o", Some(filename)) => Self::Output(Some(filename.into())),
+            ("explain", Some(query)) => Self::Explain(query.into()),
+            ("memory", None) => Self::Memory,
+            ("history", None) => Self::History,
             _ => return Err(()),
         })
     }
@@ -216,6 +413,8 @@ impl FromStr for OutputFormat {
         };
         Ok(match (c, arg) {
             ("format", Some(format)) => Self::ChangeFormat(format.to_string()),
+            ("maxrows", Some(max_rows)) => Self::ChangeMaxRows(max_rows.to_string()),
+            ("pager", Some(pager)) => Self::ChangePager(pager.to_string()),
             _ => return Err(()),
         })
     }
@@ -237,6 +436,138 @@ impl OutputFormat {
                     )))
                 }
             }
+            Self::ChangeMaxRows(max_rows) => {
+                print_options.maxrows = max_rows
+                    .parse::<MaxRows>()
+                    .map_err(DataFusionError::Execution)?;
+                println!("Maxrows is {}.", print_options.maxrows);
+                Ok(())
+            }
+            Self::ChangePager(pager) => {
+                print_options.pager = match pager.as_str() {
+                    "off" => None,
+                    "on" => Some(
+                        std::env::var("PAGER")
+                            .unwrap_or_else(|_| DEFAULT_PAGER.to_string()),
+                    ),
+                    pager => Some(pager.to_string()),
+                };
+                println!(
+                    "Pager is {}.",
+                    print_options.pager.as_deref().unwrap_or("off")
+                );
+                Ok(())
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::physical_plan::empty::EmptyExec;
+    use datafusion::physical_plan::union::UnionExec;
+    use datafusion::prelude::SessionConfig;
+
+    #[test]
+    fn parse_commands() {
+        assert!(matches!(
+            "timing on".parse::<Command>(),
+            Ok(Command::Timing(Some(true)))
+        ));
+        assert!(matches!(
+            "set datafusion.execution.batch_size 1024".parse::<Command>(),
+            Ok(Command::SetConfig(name, value))
+                if name == "datafusion.execution.batch_size" && value == "1024"
+        ));
+        assert!("set datafusion.execution.batch_size"
+            .parse::<Command>()
+            .is_err());
+        assert!(matches!(
+            "show batch_size".parse::<Command>(),
+            Ok(Command::ShowConfig(Some(name))) if name == "batch_size"
+        ));
+        assert!(matches!(
+            "o results.csv".parse::<Command>(),
+            Ok(Command::Output(Some(filename))) if filename == "results.csv"
+        ));
+        assert!(matches!(
+            "explain SELECT 1".parse::<Command>(),
+            Ok(Command::Explain(query)) if query == "SELECT 1"
+        ));
+        assert!(matches!("memory".parse::<Command>(), Ok(Command::Memory)));
+        assert!(matches!("history".parse::<Command>(), Ok(Command::History)));
+    }
+
+    #[test]
+    fn config_names() {
+        assert!(is_config_name("datafusion.execution.batch_size"));
+        assert!(is_config_name("custom_1"));
+        assert!(!is_config_name(""));
+        assert!(!is_config_name("datafusion..batch_size"));
+        assert!(!is_config_name(
+            "datafusion.execution.batch_size = 1; SELECT 1"
+        ));
+    }
+
+    #[tokio::test]
+    async fn show_config() -> Result<()> {
+        let config = SessionConfig::new().with_information_schema(true);
+        let ctx = SessionContext::with_config(config);
+        let names = |name: &'static str| {
+            let ctx = &ctx;
+            async move {
+                let batches = ctx
+                    .sql(&show_config_query(ctx, Some(name)))
+                    .await?
+                    .collect()
+                    .await?;
+                let names = batches
+                    .iter()
+                    .flat_map(|batch| {
+                        let names = batch
+                            .column(0)
+                            .as_any()
+                            .downcast_ref::<StringArray>()
+                            .unwrap();
+                        names.iter().flatten().map(String::from).collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+                Result::Ok(names)
+            }
+        };
+
+        assert_eq!(
+            names("datafusion.execution.batch_size").await?,
+            vec!["datafusion.execution.batch_size"]
+        );
+        let matching = names("batch_size").await?;
+        assert!(matching.contains(&"datafusion.execution.batch_size".to_string()));
+        assert!(matching.iter().all(|name| name.contains("batch_size")));
+        // `_` and `%` are not wildcards
+        assert!(names("batch%size").await?.is_empty());
+        assert!(names("batch_siz_").await?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn plan_tree() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let empty =
+            || Arc::new(EmptyExec::new(false, schema.clone())) as Arc<dyn ExecutionPlan>;
+        let plan: Arc<dyn ExecutionPlan> = Arc::new(UnionExec::new(vec![
+            empty(),
+            Arc::new(UnionExec::new(vec![empty(), empty()])),
+        ]));
+
+        let expected = [
+            "UnionExec",
+            "├── EmptyExec: produce_one_row=false",
+            "└── UnionExec",
+            "    ├── EmptyExec: produce_one_row=false",
+            "    └── EmptyExec: produce_one_row=false",
+            "",
+        ];
+        assert_eq!(format_plan_tree(&plan), expected.join("\n"));
+    }
+}
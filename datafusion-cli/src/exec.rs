@@ -20,9 +20,10 @@
 use crate::{
     command::{Command, OutputFormat},
     helper::CliHelper,
-    print_options::PrintOptions,
+    print_options::{PrintOptions, QueryTimings},
 };
 use datafusion::error::Result;
+use datafusion::physical_plan::execute_stream;
 use datafusion::prelude::SessionContext;
 use rustyline::error::ReadlineError;
 use rustyline::Editor;
@@ -99,6 +100,9 @@ pub async fn exec_from_repl(
     let mut print_options = print_options.clone();
 
     loop {
+        if let Some(helper) = rl.helper_mut() {
+            helper.refresh_names(ctx).await;
+        }
         match rl.readline("❯ ") {
             Ok(line) if line.starts_with('\\') => {
                 rl.add_history_entry(line.trim_end());
@@ -106,6 +110,11 @@ pub async fn exec_from_repl(
                 if let Ok(cmd) = &command[1..].parse::<Command>() {
                     match cmd {
                         Command::Quit => break,
+                        Command::History => {
+                            for (i, entry) in rl.history().iter().enumerate() {
+                                println!("{:>5}  {}", i + 1, entry);
+                            }
+                        }
                         Command::OutputFormat(subcommand) => {
                             if let Some(subcommand) = subcommand {
                                 if let Ok(command) = subcommand.parse::<OutputFormat>() {
@@ -122,6 +131,11 @@ pub async fn exec_from_repl(
                                 }
                             } else {
                                 println!("Output format is {:?}.", print_options.format);
+                                println!("Maxrows is {}.", print_options.maxrows);
+                                println!(
+                                    "Pager is {}.",
+                                    print_options.pager.as_deref().unwrap_or("off")
+                                );
                             }
                         }
                         _ => {
@@ -136,6 +150,9 @@ pub async fn exec_from_repl(
             }
             Ok(line) => {
                 rl.add_history_entry(line.trim_end());
+                if let Some(helper) = rl.helper_mut() {
+                    helper.executed(&line);
+                }
                 match exec_and_print(ctx, &print_options, line).await {
                     Ok(_) => {}
                     Err(err) => eprintln!("{err}"),
@@ -166,8 +183,19 @@ async fn exec_and_print(
 ) -> Result<()> {
     let now = Instant::now();
     let df = ctx.sql(&sql).await?;
-    let results = df.collect().await?;
-    print_options.print_batches(&results, now)?;
+    let planning = now.elapsed();
+    let task_ctx = ctx.task_ctx();
+    let plan = df.create_physical_plan().await?;
+    let optimization = now.elapsed() - planning;
+    // print the batches as they are computed
+    let stream = execute_stream(plan, task_ctx)?;
+    print_options.print_stream(stream, now).await?;
+    let execution = now.elapsed() - planning - optimization;
+    print_options.print_timings(&QueryTimings {
+        planning,
+        optimization,
+        execution,
+    });
 
     Ok(())
 }
@@ -16,9 +16,17 @@
 // under the License.
 
 //! Helper that helps with interactive editing, including multi-line parsing and validation,
-//! and auto-completion for file name during creating external table.
+//! syntax highlighting, and auto-completion of SQL keywords, table, column and function
+//! names, commands and file names during creating external table.
 
+use crate::command::Command;
+use crate::highlighter::{complete_statement, tokenize, SyntaxHighlighter, TokenKind};
+use datafusion::common::cast::as_string_array;
+use datafusion::error::Result as DFResult;
+use datafusion::prelude::SessionContext;
 use datafusion::sql::parser::{DFParser, Statement};
+use datafusion::sql::sqlparser::ast::Statement as SQLStatement;
+use datafusion::sql::sqlparser::keywords::ALL_KEYWORDS;
 use rustyline::completion::Completer;
 use rustyline::completion::FilenameCompleter;
 use rustyline::completion::Pair;
@@ -31,13 +39,222 @@ use rustyline::validate::Validator;
 use rustyline::Context;
 use rustyline::Helper;
 use rustyline::Result;
+use std::borrow::Cow;
 
 #[derive(Default)]
 pub struct CliHelper {
     completer: FilenameCompleter,
+    highlighter: SyntaxHighlighter,
+    /// The names of the tables, columns and functions of the session, see
+    /// [`CliHelper::refresh_names`]
+    names: Vec<String>,
+    /// Whether `names` were loaded since the catalogs last changed
+    names_loaded: bool,
 }
 
-impl Highlighter for CliHelper {}
+impl CliHelper {
+    /// Reloads the names of the tables, columns and functions to complete
+    /// from the catalogs of `ctx` if they may have changed since they were
+    /// loaded, keeping the previous names on error
+    pub async fn refresh_names(&mut self, ctx: &SessionContext) {
+        if self.names_loaded {
+            return;
+        }
+        if let Ok(names) = session_names(ctx).await {
+            self.names = names;
+            self.names_loaded = true;
+        }
+    }
+
+    /// Marks the names to reload on the next [`CliHelper::refresh_names`] if
+    /// `sql` may change the tables, columns or functions of the session
+    pub fn executed(&mut self, sql: &str) {
+        if changes_catalog(sql) {
+            self.names_loaded = false;
+        }
+    }
+
+    /// Completes the word ending at the end of `line`, returning the start of
+    /// the word and its candidates
+    fn complete_word(&self, line: &str) -> (usize, Vec<Pair>) {
+        if line.starts_with('\\') {
+            if line.contains(char::is_whitespace) {
+                return (line.len(), vec![]);
+            }
+            let candidates = Command::names()
+                .into_iter()
+                .filter(|name| name.starts_with(line))
+                .map(|name| Pair {
+                    display: name.to_string(),
+                    replacement: name.to_string(),
+                })
+                .collect();
+            return (0, candidates);
+        }
+
+        // no completion in string literals and comments
+        if let Some(token) = tokenize(line).last() {
+            let in_literal = token.unterminated && token.kind != TokenKind::Identifier;
+            if in_literal || token.kind == TokenKind::Comment {
+                return (line.len(), vec![]);
+            }
+        }
+
+        let start = line
+            .char_indices()
+            .rev()
+            .find(|(_, c)| !(c.is_alphanumeric() || matches!(c, '_' | '.' | '"')))
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        let word = &line[start..];
+        if word.is_empty() {
+            return (start, vec![]);
+        }
+
+        let lowercase_word = word.to_lowercase();
+        let mut candidates = self
+            .names
+            .iter()
+            .filter(|name| name.to_lowercase().starts_with(&lowercase_word))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !word.contains(['.', '"']) {
+            // keep the case of the keywords typed
+            let is_lowercase = !word.chars().any(|c| c.is_uppercase());
+            candidates.extend(
+                ALL_KEYWORDS
+                    .iter()
+                    .filter(|keyword| keyword.to_lowercase().starts_with(&lowercase_word))
+                    .map(|keyword| {
+                        if is_lowercase {
+                            keyword.to_lowercase()
+                        } else {
+                            keyword.to_string()
+                        }
+                    }),
+            );
+        }
+        candidates.sort();
+        candidates.dedup();
+
+        let candidates = candidates
+            .into_iter()
+            .map(|candidate| Pair {
+                display: candidate.clone(),
+                replacement: candidate,
+            })
+            .collect();
+        (start, candidates)
+    }
+}
+
+/// Returns the names of the tables, their columns and the functions of the
+/// session, quoted when needed, as listed by its `information_schema`.
+///
+/// The tables are named by their table name if they are in the default
+/// schema, prefixed by their schema if they are in the default catalog, and
+/// always by their qualified name.
+async fn session_names(ctx: &SessionContext) -> DFResult<Vec<String>> {
+    let state = ctx.state();
+    let defaults = &state.config_options().catalog;
+    let mut names = vec![];
+
+    let sql =
+        "SELECT table_catalog, table_schema, table_name FROM information_schema.tables";
+    for batch in ctx.sql(sql).await?.collect().await? {
+        let catalogs = as_string_array(batch.column(0))?;
+        let schemas = as_string_array(batch.column(1))?;
+        let tables = as_string_array(batch.column(2))?;
+        for i in 0..batch.num_rows() {
+            let (catalog, schema, table) =
+                (catalogs.value(i), schemas.value(i), tables.value(i));
+            let table = quote_identifier(table);
+            let schema_and_table = format!("{}.{table}", quote_identifier(schema));
+            if catalog == defaults.default_catalog {
+                if schema == defaults.default_schema {
+                    names.push(table.to_string());
+                }
+                names.push(schema_and_table.clone());
+            }
+            names.push(format!("{}.{schema_and_table}", quote_identifier(catalog)));
+        }
+    }
+
+    let sql = "SELECT table_name, column_name FROM information_schema.columns";
+    for batch in ctx.sql(sql).await?.collect().await? {
+        let tables = as_string_array(batch.column(0))?;
+        let columns = as_string_array(batch.column(1))?;
+        for i in 0..batch.num_rows() {
+            let column = quote_identifier(columns.value(i));
+            names.push(format!("{}.{column}", quote_identifier(tables.value(i))));
+            names.push(column.to_string());
+        }
+    }
+
+    let sql = "SELECT DISTINCT routine_name FROM information_schema.routines";
+    for batch in ctx.sql(sql).await?.collect().await? {
+        let functions = as_string_array(batch.column(0))?;
+        names.extend(
+            functions
+                .iter()
+                .flatten()
+                .map(|f| quote_identifier(f).to_string()),
+        );
+    }
+
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+/// Returns true if `sql` parses to a statement that may change the tables,
+/// columns or functions of the session, that is anything but a query or a
+/// `SHOW`, `DESCRIBE`, `EXPLAIN` or `SET` statement
+fn changes_catalog(sql: &str) -> bool {
+    let statements = match DFParser::parse_sql(sql) {
+        Ok(statements) => statements,
+        Err(_) => return false,
+    };
+    statements.iter().any(|statement| match statement {
+        Statement::Statement(statement) => !matches!(
+            statement.as_ref(),
+            SQLStatement::Query(_)
+                | SQLStatement::Explain { .. }
+                | SQLStatement::ShowTables { .. }
+                | SQLStatement::ShowColumns { .. }
+                | SQLStatement::ShowCreate { .. }
+                | SQLStatement::ShowVariable { .. }
+                | SQLStatement::SetVariable { .. }
+        ),
+        Statement::DescribeTableStmt(_) | Statement::ShowFunctions(_) => false,
+        _ => true,
+    })
+}
+
+/// Quotes `name` unless it is a lowercase identifier, which would otherwise
+/// be normalized to lowercase or be parsed as something else
+fn quote_identifier(name: &str) -> Cow<str> {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .map_or(false, |c| c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if is_identifier {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(format!("\"{}\"", name.replace('"', "\"\"")))
+    }
+}
+
+impl Highlighter for CliHelper {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        self.highlighter.highlight(line)
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize) -> bool {
+        // the whole line is highlighted again as the tokens change
+        true
+    }
+}
 
 impl Hinter for CliHelper {
     type Hint = String;
@@ -68,7 +285,7 @@ impl Completer for CliHelper {
         if is_open_quote_for_location(line, pos) {
             self.completer.complete(line, pos, ctx)
         } else {
-            Ok((0, Vec::with_capacity(0)))
+            Ok(self.complete_word(&line[..pos]))
         }
     }
 }
@@ -76,7 +293,7 @@ impl Completer for CliHelper {
 impl Validator for CliHelper {
     fn validate(&self, ctx: &mut ValidationContext<'_>) -> Result<ValidationResult> {
         let input = ctx.input().trim_end();
-        if let Some(sql) = input.strip_suffix(';') {
+        if let Some(sql) = complete_statement(input) {
             match DFParser::parse_sql(sql) {
                 Ok(statements) if statements.is_empty() => Ok(ValidationResult::Invalid(
                     Some("  🤔 You entered an empty statement".to_string()),
@@ -100,3 +317,118 @@ impl Validator for CliHelper {
 }
 
 impl Helper for CliHelper {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complete(helper: &CliHelper, line: &str) -> (usize, Vec<String>) {
+        let (start, candidates) = helper.complete_word(line);
+        (
+            start,
+            candidates.into_iter().map(|c| c.replacement).collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn complete_names() -> DFResult<()> {
+        let ctx = SessionContext::with_config(
+            datafusion::prelude::SessionConfig::new().with_information_schema(true),
+        );
+        ctx.sql("CREATE TABLE orders (order_id INT, \"Order Date\" DATE)")
+            .await?;
+        let mut helper = CliHelper::default();
+        helper.refresh_names(&ctx).await;
+
+        assert_eq!(
+            complete(&helper, "SELECT order_i"),
+            (7, vec!["order_id".to_string()])
+        );
+        assert_eq!(
+            complete(&helper, "SELECT * FROM public.ord"),
+            (14, vec!["public.orders".to_string()])
+        );
+        assert_eq!(
+            complete(&helper, "SELECT orders.\"Ord"),
+            (7, vec!["orders.\"Order Date\"".to_string()])
+        );
+        assert_eq!(
+            complete(&helper, "SELECT * FROM datafusion.public.ord"),
+            (14, vec!["datafusion.public.orders".to_string()])
+        );
+        assert_eq!(
+            complete(&helper, "select to_timestamp_mi"),
+            (
+                7,
+                vec![
+                    "to_timestamp_micros".to_string(),
+                    "to_timestamp_millis".to_string()
+                ]
+            )
+        );
+
+        // the keywords keep the case typed
+        let (start, candidates) = complete(&helper, "select order_id fro");
+        assert_eq!(start, 16);
+        assert!(candidates.contains(&"from".to_string()), "{candidates:?}");
+        let (_, candidates) = complete(&helper, "SELECT order_id FRO");
+        assert!(candidates.contains(&"FROM".to_string()), "{candidates:?}");
+
+        assert_eq!(complete(&helper, "SELECT 'order_i"), (15, vec![]));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresh_names_after_ddl() -> DFResult<()> {
+        let ctx = SessionContext::with_config(
+            datafusion::prelude::SessionConfig::new().with_information_schema(true),
+        );
+        let mut helper = CliHelper::default();
+        helper.refresh_names(&ctx).await;
+
+        let sql = "CREATE TABLE orders (order_id INT)";
+        ctx.sql(sql).await?;
+        helper.executed("SELECT * FROM information_schema.tables");
+        helper.refresh_names(&ctx).await;
+        assert_eq!(complete(&helper, "SELECT order_i"), (7, vec![]));
+
+        helper.executed(sql);
+        helper.refresh_names(&ctx).await;
+        assert_eq!(
+            complete(&helper, "SELECT order_i"),
+            (7, vec!["order_id".to_string()])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn catalog_changes() {
+        assert!(changes_catalog("CREATE VIEW v AS SELECT 1"));
+        assert!(changes_catalog("DROP TABLE t"));
+        assert!(changes_catalog("ALTER TABLE t RENAME TO u"));
+        assert!(changes_catalog(
+            "CREATE EXTERNAL TABLE t STORED AS CSV LOCATION 'foo.csv'"
+        ));
+        assert!(!changes_catalog("SELECT 1"));
+        assert!(!changes_catalog("SHOW TABLES"));
+        assert!(!changes_catalog("EXPLAIN SELECT 1"));
+        assert!(!changes_catalog("SELEC 1"));
+    }
+
+    #[test]
+    fn complete_commands() {
+        let helper = CliHelper::default();
+        assert_eq!(
+            complete(&helper, "\\q"),
+            (0, vec!["\\q".to_string(), "\\quiet".to_string()])
+        );
+        assert_eq!(complete(&helper, "\\d orders"), (9, vec![]));
+    }
+
+    #[test]
+    fn quote_identifiers() {
+        assert_eq!(quote_identifier("order_id"), "order_id");
+        assert_eq!(quote_identifier("Order"), "\"Order\"");
+        assert_eq!(quote_identifier("1st \"x\""), "\"1st \"\"x\"\"\"");
+    }
+}
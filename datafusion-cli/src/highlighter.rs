@@ -0,0 +1,379 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Lexing of the SQL being edited, for syntax highlighting and to find where
+//! statements end
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// The kind of a [`Token`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Whitespace,
+    /// A `--` or `/* */` comment
+    Comment,
+    /// A reserved SQL keyword, see [`is_reserved_keyword`]
+    Keyword,
+    /// An unquoted or double quoted identifier
+    Identifier,
+    /// A single quoted string literal
+    String,
+    Number,
+    /// Any other character, such as an operator or `;`
+    Punctuation,
+}
+
+/// A token of the SQL being edited
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// The byte range of the token in the SQL
+    pub span: Range<usize>,
+    /// Whether the SQL ends before the end of the token, for the string
+    /// literals, quoted identifiers and block comments
+    pub unterminated: bool,
+}
+
+/// Splits `sql` into tokens covering all of its characters.
+///
+/// Unlike the tokenizer of the parser, this never fails, so that incomplete
+/// and invalid SQL can be highlighted as it is typed.
+pub fn tokenize(sql: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut chars = sql.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let mut unterminated = false;
+        let kind = match c {
+            c if c.is_whitespace() => {
+                while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+                TokenKind::Whitespace
+            }
+            '-' if sql[start..].starts_with("--") => {
+                while chars.next_if(|(_, c)| *c != '\n').is_some() {}
+                TokenKind::Comment
+            }
+            '/' if sql[start..].starts_with("/*") => {
+                chars.next();
+                unterminated = true;
+                let mut previous = None;
+                for (_, c) in chars.by_ref() {
+                    if previous == Some('*') && c == '/' {
+                        unterminated = false;
+                        break;
+                    }
+                    previous = Some(c);
+                }
+                TokenKind::Comment
+            }
+            '\'' | '"' => {
+                // the quote is escaped by doubling it
+                unterminated = true;
+                while let Some((_, next)) = chars.next() {
+                    if next == c && chars.next_if(|(_, next)| *next == c).is_none() {
+                        unterminated = false;
+                        break;
+                    }
+                }
+                if c == '\'' {
+                    TokenKind::String
+                } else {
+                    TokenKind::Identifier
+                }
+            }
+            c if c.is_ascii_digit() => {
+                while chars
+                    .next_if(|(_, c)| c.is_ascii_alphanumeric() || *c == '.')
+                    .is_some()
+                {}
+                TokenKind::Number
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                while chars
+                    .next_if(|(_, c)| c.is_alphanumeric() || *c == '_')
+                    .is_some()
+                {}
+                let end = chars.peek().map_or(sql.len(), |(i, _)| *i);
+                if is_reserved_keyword(&sql[start..end]) {
+                    TokenKind::Keyword
+                } else {
+                    TokenKind::Identifier
+                }
+            }
+            _ => TokenKind::Punctuation,
+        };
+        let end = chars.peek().map_or(sql.len(), |(i, _)| *i);
+        tokens.push(Token {
+            kind,
+            span: start..end,
+            unterminated,
+        });
+    }
+    tokens
+}
+
+/// The keywords reserved by PostgreSQL, whose SQL dialect DataFusion
+/// follows, sorted. The other keywords, such as `name` or `value`, are
+/// commonly used as identifiers and are not highlighted.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "ALL",
+    "ANALYSE",
+    "ANALYZE",
+    "AND",
+    "ANY",
+    "ARRAY",
+    "AS",
+    "ASC",
+    "ASYMMETRIC",
+    "AUTHORIZATION",
+    "BINARY",
+    "BOTH",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLLATE",
+    "COLLATION",
+    "COLUMN",
+    "CONCURRENTLY",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "CURRENT_CATALOG",
+    "CURRENT_DATE",
+    "CURRENT_ROLE",
+    "CURRENT_SCHEMA",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FOREIGN",
+    "FREEZE",
+    "FROM",
+    "FULL",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "ILIKE",
+    "IN",
+    "INITIALLY",
+    "INNER",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "ISNULL",
+    "JOIN",
+    "LATERAL",
+    "LEADING",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "NATURAL",
+    "NOT",
+    "NOTNULL",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "ONLY",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVERLAPS",
+    "PLACING",
+    "PRIMARY",
+    "REFERENCES",
+    "RETURNING",
+    "RIGHT",
+    "SELECT",
+    "SESSION_USER",
+    "SIMILAR",
+    "SOME",
+    "SYMMETRIC",
+    "TABLE",
+    "TABLESAMPLE",
+    "THEN",
+    "TO",
+    "TRAILING",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "USER",
+    "USING",
+    "VARIADIC",
+    "VERBOSE",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+/// Returns true if `word` is a reserved SQL keyword, in any case
+pub fn is_reserved_keyword(word: &str) -> bool {
+    RESERVED_KEYWORDS
+        .binary_search(&word.to_uppercase().as_str())
+        .is_ok()
+}
+
+/// Returns the statement of `input` if it ends with a `;` that is neither in
+/// a string literal nor in a comment, without that `;`
+pub fn complete_statement(input: &str) -> Option<&str> {
+    let tokens = tokenize(input);
+    if tokens.last().map_or(false, |t| t.unterminated) {
+        return None;
+    }
+    tokens
+        .iter()
+        .rev()
+        .find(|t| !matches!(t.kind, TokenKind::Whitespace | TokenKind::Comment))
+        .filter(|t| t.kind == TokenKind::Punctuation && &input[t.span.clone()] == ";")
+        .map(|t| &input[..t.span.start])
+}
+
+/// Highlights the keywords, literals and comments of SQL with ANSI colors
+#[derive(Debug, Default)]
+pub struct SyntaxHighlighter {}
+
+impl SyntaxHighlighter {
+    pub fn highlight<'l>(&self, line: &'l str) -> Cow<'l, str> {
+        let mut highlighted = String::with_capacity(line.len());
+        let mut colored = false;
+        for token in tokenize(line) {
+            let text = &line[token.span];
+            let color = match token.kind {
+                TokenKind::Keyword => "\x1b[1;34m",
+                TokenKind::String => "\x1b[32m",
+                TokenKind::Number => "\x1b[33m",
+                TokenKind::Comment => "\x1b[90m",
+                _ => {
+                    highlighted.push_str(text);
+                    continue;
+                }
+            };
+            colored = true;
+            highlighted.push_str(color);
+            highlighted.push_str(text);
+            highlighted.push_str("\x1b[0m");
+        }
+        if colored {
+            Cow::Owned(highlighted)
+        } else {
+            Cow::Borrowed(line)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(sql: &str) -> Vec<(TokenKind, &str, bool)> {
+        tokenize(sql)
+            .into_iter()
+            .map(|t| (t.kind, &sql[t.span], t.unterminated))
+            .collect()
+    }
+
+    #[test]
+    fn tokenize_sql() {
+        use TokenKind::*;
+        assert_eq!(
+            kinds("select \"A\"\"b\", 'it''s' -- c;\nFROM t1 where c1>=1.5;"),
+            vec![
+                (Keyword, "select", false),
+                (Whitespace, " ", false),
+                (Identifier, "\"A\"\"b\"", false),
+                (Punctuation, ",", false),
+                (Whitespace, " ", false),
+                (String, "'it''s'", false),
+                (Whitespace, " ", false),
+                (Comment, "-- c;", false),
+                (Whitespace, "\n", false),
+                (Keyword, "FROM", false),
+                (Whitespace, " ", false),
+                (Identifier, "t1", false),
+                (Whitespace, " ", false),
+                (Keyword, "where", false),
+                (Whitespace, " ", false),
+                (Identifier, "c1", false),
+                (Punctuation, ">", false),
+                (Punctuation, "=", false),
+                (Number, "1.5", false),
+                (Punctuation, ";", false),
+            ]
+        );
+        assert_eq!(
+            kinds("/* a */ 'b;"),
+            vec![
+                (Comment, "/* a */", false),
+                (Whitespace, " ", false),
+                (String, "'b;", true),
+            ]
+        );
+        assert_eq!(kinds("/* a;"), vec![(Comment, "/* a;", true)]);
+    }
+
+    #[test]
+    fn reserved_keywords() {
+        assert!(RESERVED_KEYWORDS.windows(2).all(|w| w[0] < w[1]));
+        assert!(is_reserved_keyword("Select"));
+        assert!(!is_reserved_keyword("name"));
+
+        use TokenKind::*;
+        assert_eq!(
+            kinds("select name, value"),
+            vec![
+                (Keyword, "select", false),
+                (Whitespace, " ", false),
+                (Identifier, "name", false),
+                (Punctuation, ",", false),
+                (Whitespace, " ", false),
+                (Identifier, "value", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn statement_end() {
+        assert_eq!(complete_statement("select 1;"), Some("select 1"));
+        assert_eq!(complete_statement("select 1; -- c\n"), Some("select 1"));
+        assert_eq!(complete_statement("select ';"), None);
+        assert_eq!(complete_statement("select 1 -- c;"), None);
+        assert_eq!(complete_statement("select 1 /* c;"), None);
+        assert_eq!(complete_statement("select 1"), None);
+    }
+
+    #[test]
+    fn highlight_sql() {
+        let highlighter = SyntaxHighlighter::default();
+        assert_eq!(highlighter.highlight("c1 + c2"), "c1 + c2");
+        assert_eq!(
+            highlighter.highlight("select 'a' from t1"),
+            "\x1b[1;34mselect\x1b[0m \x1b[32m'a'\x1b[0m \x1b[1;34mfrom\x1b[0m t1"
+        );
+    }
+}
@@ -18,11 +18,13 @@
 #![doc = include_str!("../README.md")]
 pub const DATAFUSION_CLI_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub mod cached_store;
 pub mod catalog;
 pub mod command;
 pub mod exec;
 pub mod functions;
 pub mod helper;
+pub mod highlighter;
 pub mod object_storage;
 pub mod print_format;
 pub mod print_options;
@@ -18,13 +18,18 @@
 use clap::Parser;
 use datafusion::datasource::object_store::ObjectStoreRegistry;
 use datafusion::error::{DataFusionError, Result};
-use datafusion::execution::context::SessionConfig;
+use datafusion::execution::context::{SessionConfig, SessionState};
 use datafusion::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
 use datafusion::prelude::SessionContext;
 use datafusion_cli::catalog::DynamicFileCatalog;
-use datafusion_cli::object_storage::DatafusionCliObjectStoreProvider;
+use datafusion_cli::object_storage::{
+    DatafusionCliObjectStoreProvider, ObjectStoreTableFactory,
+};
 use datafusion_cli::{
-    exec, print_format::PrintFormat, print_options::PrintOptions, DATAFUSION_CLI_VERSION,
+    exec,
+    print_format::PrintFormat,
+    print_options::{MaxRows, PrintOptions},
+    DATAFUSION_CLI_VERSION,
 };
 use mimalloc::MiMalloc;
 use std::env;
@@ -81,6 +86,24 @@ struct Args {
         help = "Reduce printing other than the results and work quietly"
     )]
     quiet: bool,
+
+    #[clap(
+        long,
+        help = "The maximum number of rows to print for each result, or inf, 40 by default when interactive"
+    )]
+    maxrows: Option<MaxRows>,
+
+    #[clap(
+        long,
+        help = "The command of the pager to print the results to, such as 'less -S -F -X'"
+    )]
+    pager: Option<String>,
+
+    #[clap(
+        long,
+        help = "Cache the files read from the remote object stores in this directory"
+    )]
+    object_store_cache: Option<String>,
 }
 
 #[tokio::main]
@@ -103,9 +126,22 @@ pub async fn main() -> Result<()> {
         session_config = session_config.with_batch_size(batch_size);
     };
 
-    let runtime_env = create_runtime_env()?;
-    let mut ctx =
-        SessionContext::with_config_rt(session_config.clone(), Arc::new(runtime_env));
+    let mut object_store_provider = DatafusionCliObjectStoreProvider::new();
+    if let Some(cache_dir) = args.object_store_cache {
+        object_store_provider = object_store_provider.with_cache_dir(cache_dir);
+    }
+    let object_store_provider = Arc::new(object_store_provider);
+    let runtime_env = create_runtime_env(object_store_provider.clone())?;
+    let mut state =
+        SessionState::with_config_rt(session_config.clone(), Arc::new(runtime_env));
+    // register the object stores configured in the options of the external tables
+    for factory in state.table_factories_mut().values_mut() {
+        *factory = Arc::new(ObjectStoreTableFactory::new(
+            factory.clone(),
+            object_store_provider.clone(),
+        ));
+    }
+    let mut ctx = SessionContext::with_state(state);
     ctx.refresh_catalogs().await?;
     // install dynamic catalog provider that knows how to open files
     ctx.register_catalog_list(Arc::new(DynamicFileCatalog::new(
@@ -113,12 +149,22 @@ pub async fn main() -> Result<()> {
         ctx.state_weak_ref(),
     )));
 
+    let files = args.file;
+    // the results of the files executed are complete by default
+    let maxrows = args.maxrows.unwrap_or(if files.is_empty() {
+        MaxRows::Limited(40)
+    } else {
+        MaxRows::Unlimited
+    });
     let mut print_options = PrintOptions {
         format: args.format,
         quiet: args.quiet,
+        timing: false,
+        output: None,
+        maxrows,
+        pager: args.pager,
     };
 
-    let files = args.file;
     let rc = match args.rc {
         Some(file) => file,
         None => {
@@ -148,10 +194,11 @@ pub async fn main() -> Result<()> {
     }
 }
 
-fn create_runtime_env() -> Result<RuntimeEnv> {
-    let object_store_provider = DatafusionCliObjectStoreProvider {};
+fn create_runtime_env(
+    object_store_provider: Arc<DatafusionCliObjectStoreProvider>,
+) -> Result<RuntimeEnv> {
     let object_store_registry =
-        ObjectStoreRegistry::new_with_provider(Some(Arc::new(object_store_provider)));
+        ObjectStoreRegistry::new_with_provider(Some(object_store_provider));
     let rn_config =
         RuntimeConfig::new().with_object_store_registry(Arc::new(object_store_registry));
     RuntimeEnv::new(rn_config)
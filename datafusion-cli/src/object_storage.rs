@@ -15,17 +15,39 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use async_trait::async_trait;
 use datafusion::error::Result;
+use datafusion::execution::context::SessionState;
+use datafusion::logical_expr::CreateExternalTable;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::{env, str::FromStr, sync::Arc};
 
-use datafusion::{datasource::object_store::ObjectStoreProvider, error::DataFusionError};
-use object_store::{aws::AmazonS3Builder, gcp::GoogleCloudStorageBuilder};
+use crate::cached_store::CachedObjectStore;
+use datafusion::{
+    datasource::{
+        datasource::TableProviderFactory, object_store::ObjectStoreProvider,
+        TableProvider,
+    },
+    error::DataFusionError,
+    sql::sqlparser::{
+        dialect::GenericDialect,
+        keywords::Keyword,
+        tokenizer::{Token, Tokenizer, Word},
+    },
+};
+use object_store::{
+    aws::AmazonS3Builder, azure::MicrosoftAzureBuilder, gcp::GoogleCloudStorageBuilder,
+    http::HttpBuilder, ObjectStore,
+};
 use url::Url;
 
 #[derive(Debug, PartialEq, Eq, clap::ArgEnum, Clone)]
 pub enum ObjectStoreScheme {
     S3,
     GCS,
+    Azure,
+    Http,
 }
 
 impl FromStr for ObjectStoreScheme {
@@ -35,6 +57,8 @@ impl FromStr for ObjectStoreScheme {
         match input {
             "s3" => Ok(ObjectStoreScheme::S3),
             "gs" | "gcs" => Ok(ObjectStoreScheme::GCS),
+            "az" | "azure" | "abfs" | "abfss" => Ok(ObjectStoreScheme::Azure),
+            "http" | "https" => Ok(ObjectStoreScheme::Http),
             _ => Err(DataFusionError::Execution(format!(
                 "Unsupported object store scheme {}",
                 input
@@ -43,32 +67,138 @@ impl FromStr for ObjectStoreScheme {
     }
 }
 
-#[derive(Debug)]
-pub struct DatafusionCliObjectStoreProvider {}
+/// The prefixes of the `OPTIONS` of `CREATE EXTERNAL TABLE` configuring the
+/// object stores, such as `'aws.region'`
+const OBJECT_STORE_OPTION_PREFIXES: [&str; 3] = ["aws.", "gcp.", "azure."];
 
-/// ObjectStoreProvider for S3 and GCS
+#[derive(Debug, Default)]
+pub struct DatafusionCliObjectStoreProvider {
+    /// The directory the objects read are cached in, if any
+    cache_dir: Option<PathBuf>,
+}
+
+impl DatafusionCliObjectStoreProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Caches the objects read from the object stores in `cache_dir`, see
+    /// [`CachedObjectStore`]
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    /// Builds the object store of `url`, configured by `options`, whose keys
+    /// have one of the [`OBJECT_STORE_OPTION_PREFIXES`], and by the
+    /// environment
+    pub fn build_object_store(
+        &self,
+        url: &Url,
+        options: &HashMap<String, String>,
+    ) -> Result<Arc<dyn ObjectStore>> {
+        let store = match ObjectStoreScheme::from_str(url.scheme())? {
+            ObjectStoreScheme::S3 => build_s3_object_store(url, options)?,
+            ObjectStoreScheme::GCS => build_gcs_object_store(url, options)?,
+            ObjectStoreScheme::Azure => build_azure_object_store(url, options)?,
+            ObjectStoreScheme::Http => build_http_object_store(url, options)?,
+        };
+        match &self.cache_dir {
+            Some(cache_dir) => {
+                // the objects of each store are cached in their own directory
+                let key = &url[url::Position::BeforeScheme..url::Position::BeforePath];
+                let dir =
+                    cache_dir.join(key.replace(|c: char| !c.is_alphanumeric(), "_"));
+                Ok(Arc::new(CachedObjectStore::try_new(store, dir)?))
+            }
+            None => Ok(store),
+        }
+    }
+}
+
+/// ObjectStoreProvider for S3, GCS, Azure and HTTP
 impl ObjectStoreProvider for DatafusionCliObjectStoreProvider {
     fn get_by_url(&self, url: &Url) -> Result<Arc<dyn object_store::ObjectStore>> {
-        ObjectStoreScheme::from_str(url.scheme()).map(|scheme| match scheme {
-            ObjectStoreScheme::S3 => build_s3_object_store(url),
-            ObjectStoreScheme::GCS => build_gcs_object_store(url),
-        })?
+        self.build_object_store(url, &HashMap::new())
     }
 }
 
-fn build_s3_object_store(url: &Url) -> Result<Arc<dyn object_store::ObjectStore>> {
+/// Returns the value of the object store option `key`, if any, checking that
+/// all the options with the prefix of `key` are known
+fn object_store_option<'a>(
+    options: &'a HashMap<String, String>,
+    key: &str,
+    known_keys: &[&str],
+) -> Result<Option<&'a str>> {
+    let (prefix, _) = key.split_once('.').unwrap_or_default();
+    for option in options.keys() {
+        let option = option.to_lowercase();
+        if option.starts_with(&format!("{prefix}."))
+            && !known_keys.contains(&option.as_str())
+        {
+            return Err(DataFusionError::Plan(format!(
+                "Unknown object store option '{option}', expected one of {}",
+                known_keys.join(", ")
+            )));
+        }
+    }
+    Ok(options
+        .iter()
+        .find(|(option, _)| option.eq_ignore_ascii_case(key))
+        .map(|(_, value)| value.as_str()))
+}
+
+const AWS_OPTIONS: [&str; 5] = [
+    "aws.access_key_id",
+    "aws.secret_access_key",
+    "aws.session_token",
+    "aws.region",
+    "aws.endpoint",
+];
+
+fn build_s3_object_store(
+    url: &Url,
+    options: &HashMap<String, String>,
+) -> Result<Arc<dyn object_store::ObjectStore>> {
     let host = get_host_name(url)?;
-    match AmazonS3Builder::from_env().with_bucket_name(host).build() {
+    let mut builder = AmazonS3Builder::from_env().with_bucket_name(host);
+
+    let option = |key| object_store_option(options, key, &AWS_OPTIONS);
+    if let Some(access_key_id) = option("aws.access_key_id")? {
+        builder = builder.with_access_key_id(access_key_id);
+    }
+    if let Some(secret_access_key) = option("aws.secret_access_key")? {
+        builder = builder.with_secret_access_key(secret_access_key);
+    }
+    if let Some(session_token) = option("aws.session_token")? {
+        builder = builder.with_token(session_token);
+    }
+    if let Some(region) = option("aws.region")? {
+        builder = builder.with_region(region);
+    }
+    if let Some(endpoint) = option("aws.endpoint")? {
+        builder = builder.with_endpoint(endpoint);
+    }
+    match builder.build() {
         Ok(s3) => Ok(Arc::new(s3)),
         Err(err) => Err(DataFusionError::External(Box::new(err))),
     }
 }
 
-fn build_gcs_object_store(url: &Url) -> Result<Arc<dyn object_store::ObjectStore>> {
+const GCP_OPTIONS: [&str; 1] = ["gcp.service_account_path"];
+
+fn build_gcs_object_store(
+    url: &Url,
+    options: &HashMap<String, String>,
+) -> Result<Arc<dyn object_store::ObjectStore>> {
     let host = get_host_name(url)?;
     let mut builder = GoogleCloudStorageBuilder::new().with_bucket_name(host);
 
-    if let Ok(path) = env::var("GCP_SERVICE_ACCOUNT_PATH") {
+    let service_account_path =
+        object_store_option(options, "gcp.service_account_path", &GCP_OPTIONS)?
+            .map(|path| path.to_string())
+            .or_else(|| env::var("GCP_SERVICE_ACCOUNT_PATH").ok());
+    if let Some(path) = service_account_path {
         builder = builder.with_service_account_path(path);
     }
     match builder.build() {
@@ -77,6 +207,57 @@ fn build_gcs_object_store(url: &Url) -> Result<Arc<dyn object_store::ObjectStore
     }
 }
 
+const AZURE_OPTIONS: [&str; 2] = ["azure.account_name", "azure.account_key"];
+
+/// Builds the store of an `az://<container>/<path>` or
+/// `abfs[s]://<container>@<account>.dfs.core.windows.net/<path>` URL
+fn build_azure_object_store(
+    url: &Url,
+    options: &HashMap<String, String>,
+) -> Result<Arc<dyn object_store::ObjectStore>> {
+    let host = get_host_name(url)?;
+    let (container, account) = match url.username() {
+        "" => (host, None),
+        container => (container, host.split('.').next()),
+    };
+    let mut builder = MicrosoftAzureBuilder::new().with_container_name(container);
+
+    let option = |key| object_store_option(options, key, &AZURE_OPTIONS);
+    let account = option("azure.account_name")?
+        .or(account)
+        .map(|account| account.to_string())
+        .or_else(|| env::var("AZURE_STORAGE_ACCOUNT_NAME").ok());
+    if let Some(account) = account {
+        builder = builder.with_account(account);
+    }
+    let access_key = option("azure.account_key")?
+        .map(|key| key.to_string())
+        .or_else(|| env::var("AZURE_STORAGE_ACCOUNT_KEY").ok());
+    if let Some(access_key) = access_key {
+        builder = builder.with_access_key(access_key);
+    }
+    match builder.build() {
+        Ok(azure) => Ok(Arc::new(azure)),
+        Err(err) => Err(DataFusionError::External(Box::new(err))),
+    }
+}
+
+fn build_http_object_store(
+    url: &Url,
+    options: &HashMap<String, String>,
+) -> Result<Arc<dyn object_store::ObjectStore>> {
+    if let Some(option) = options.keys().find(|option| is_object_store_option(option)) {
+        return Err(DataFusionError::Plan(format!(
+            "Unknown object store option '{option}', the HTTP object stores have no options"
+        )));
+    }
+    let base_url = &url[url::Position::BeforeScheme..url::Position::BeforePath];
+    match HttpBuilder::new().with_url(base_url).build() {
+        Ok(http) => Ok(Arc::new(http)),
+        Err(err) => Err(DataFusionError::External(Box::new(err))),
+    }
+}
+
 fn get_host_name(url: &Url) -> Result<&str> {
     url.host_str().ok_or_else(|| {
         DataFusionError::Execution(format!(
@@ -86,19 +267,189 @@ fn get_host_name(url: &Url) -> Result<&str> {
     })
 }
 
+fn is_object_store_option(key: &str) -> bool {
+    let key = key.to_lowercase();
+    OBJECT_STORE_OPTION_PREFIXES
+        .iter()
+        .any(|prefix| key.starts_with(prefix))
+}
+
+/// The object store options holding credentials, which are removed from the
+/// definitions of the tables
+const CREDENTIAL_OPTIONS: [&str; 5] = [
+    "aws.access_key_id",
+    "aws.secret_access_key",
+    "aws.session_token",
+    "azure.account_key",
+    "gcp.service_account_path",
+];
+
+/// Removes the [`CREDENTIAL_OPTIONS`] from the `OPTIONS` of the
+/// `CREATE EXTERNAL TABLE` statement `definition`, so that the credentials are
+/// neither shown nor persisted with the definition of the table
+fn strip_credentials(definition: &str) -> Result<String> {
+    let tokens = Tokenizer::new(&GenericDialect {}, definition)
+        .tokenize()
+        .map_err(|e| DataFusionError::SQL(e.into()))?;
+    let mut sql = String::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let is_options = matches!(
+            &tokens[i],
+            Token::Word(Word {
+                keyword: Keyword::OPTIONS,
+                ..
+            })
+        );
+        let start = tokens[i + 1..]
+            .iter()
+            .position(|token| !matches!(token, Token::Whitespace(_)))
+            .map(|position| i + 1 + position)
+            .filter(|start| is_options && tokens[*start] == Token::LParen);
+        let end = start.and_then(|start| {
+            tokens[start..]
+                .iter()
+                .position(|token| *token == Token::RParen)
+                .map(|position| start + position)
+        });
+        let (start, end) = match (start, end) {
+            (Some(start), Some(end)) => (start, end),
+            _ => {
+                sql.push_str(&token_sql(&tokens[i]));
+                i += 1;
+                continue;
+            }
+        };
+
+        // the options only hold literals, separated by commas
+        let options = tokens[start + 1..end]
+            .split(|token| *token == Token::Comma)
+            .filter(|option| {
+                let key = option.iter().find_map(|token| match token {
+                    Token::SingleQuotedString(key) => Some(key),
+                    Token::Word(word) => Some(&word.value),
+                    _ => None,
+                });
+                !key.map_or(false, |key| {
+                    CREDENTIAL_OPTIONS
+                        .iter()
+                        .any(|credential| key.eq_ignore_ascii_case(credential))
+                })
+            })
+            .map(|option| option.iter().map(token_sql).collect::<String>())
+            .map(|option| option.trim().to_string())
+            .filter(|option| !option.is_empty())
+            .collect::<Vec<_>>();
+        if options.is_empty() {
+            sql.truncate(sql.trim_end().len());
+        } else {
+            sql.push_str(&format!("OPTIONS ({})", options.join(", ")));
+        }
+        i = end + 1;
+    }
+    Ok(sql)
+}
+
+/// Returns the SQL of `token`, escaping the quotes of the quoted strings and
+/// identifiers
+fn token_sql(token: &Token) -> String {
+    match token {
+        Token::SingleQuotedString(s) => format!("'{}'", s.replace('\'', "''")),
+        Token::Word(Word {
+            value,
+            quote_style: Some(quote),
+            ..
+        }) => {
+            let end = match *quote {
+                '[' => ']',
+                quote => quote,
+            };
+            format!("{quote}{}{end}", value.replace(end, &format!("{end}{end}")))
+        }
+        token => token.to_string(),
+    }
+}
+
+/// Wraps a [`TableProviderFactory`] to register the object store of the
+/// location of the tables created with object store options, such as
+/// credentials, in their `OPTIONS`:
+///
+/// ```sql
+/// CREATE EXTERNAL TABLE t STORED AS PARQUET LOCATION 's3://bucket/t/'
+/// OPTIONS ('aws.access_key_id' '...', 'aws.secret_access_key' '...', 'aws.region' 'us-east-2')
+/// ```
+///
+/// The other options are passed to the wrapped factory, and the credentials
+/// are removed from the definition of the table.
+pub struct ObjectStoreTableFactory {
+    inner: Arc<dyn TableProviderFactory>,
+    provider: Arc<DatafusionCliObjectStoreProvider>,
+}
+
+impl ObjectStoreTableFactory {
+    pub fn new(
+        inner: Arc<dyn TableProviderFactory>,
+        provider: Arc<DatafusionCliObjectStoreProvider>,
+    ) -> Self {
+        Self { inner, provider }
+    }
+}
+
+#[async_trait]
+impl TableProviderFactory for ObjectStoreTableFactory {
+    async fn create(
+        &self,
+        state: &SessionState,
+        cmd: &CreateExternalTable,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let (store_options, options): (HashMap<_, _>, HashMap<_, _>) = cmd
+            .options
+            .clone()
+            .into_iter()
+            .partition(|(key, _)| is_object_store_option(key));
+        if store_options.is_empty() {
+            return self.inner.create(state, cmd).await;
+        }
+
+        let url = Url::parse(&cmd.location).map_err(|_| {
+            DataFusionError::Plan(format!(
+                "The object store options require the location to be a URL, got {}",
+                cmd.location
+            ))
+        })?;
+        let store = self.provider.build_object_store(&url, &store_options)?;
+        let host = &url[url::Position::BeforeUsername..url::Position::AfterPort];
+        state
+            .runtime_env()
+            .register_object_store(url.scheme(), host, store);
+
+        let definition = cmd
+            .definition
+            .as_deref()
+            .map(strip_credentials)
+            .transpose()?;
+        let cmd = CreateExternalTable {
+            options,
+            definition,
+            ..cmd.clone()
+        };
+        self.inner.create(state, &cmd).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{env, str::FromStr};
+    use std::{collections::HashMap, env, str::FromStr};
 
     use datafusion::datasource::object_store::ObjectStoreProvider;
     use url::Url;
 
-    use super::DatafusionCliObjectStoreProvider;
+    use super::{strip_credentials, DatafusionCliObjectStoreProvider};
 
     #[test]
     fn s3_provider_no_host() {
         let no_host_url = "s3:///";
-        let provider = DatafusionCliObjectStoreProvider {};
+        let provider = DatafusionCliObjectStoreProvider::new();
         let err = provider
             .get_by_url(&Url::from_str(no_host_url).unwrap())
             .unwrap_err();
@@ -110,7 +461,7 @@ mod tests {
     #[test]
     fn gs_provider_no_host() {
         let no_host_url = "gs:///";
-        let provider = DatafusionCliObjectStoreProvider {};
+        let provider = DatafusionCliObjectStoreProvider::new();
         let err = provider
             .get_by_url(&Url::from_str(no_host_url).unwrap())
             .unwrap_err();
@@ -122,7 +473,7 @@ mod tests {
     #[test]
     fn gcs_provider_no_host() {
         let no_host_url = "gcs:///";
-        let provider = DatafusionCliObjectStoreProvider {};
+        let provider = DatafusionCliObjectStoreProvider::new();
         let err = provider
             .get_by_url(&Url::from_str(no_host_url).unwrap())
             .unwrap_err();
@@ -131,10 +482,47 @@ mod tests {
             .contains("Not able to parse hostname from url"))
     }
 
+    #[test]
+    fn azure_provider_no_host() {
+        let no_host_url = "az:///";
+        let provider = DatafusionCliObjectStoreProvider::new();
+        let err = provider
+            .get_by_url(&Url::from_str(no_host_url).unwrap())
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Not able to parse hostname from url"))
+    }
+
+    #[test]
+    fn http_provider() {
+        let url = "https://example.com:8080/data/file.csv";
+        let provider = DatafusionCliObjectStoreProvider::new();
+        provider.get_by_url(&Url::from_str(url).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn unknown_object_store_option() {
+        let url = Url::from_str("s3://bucket_name/path").unwrap();
+        let provider = DatafusionCliObjectStoreProvider::new();
+        let options = HashMap::from([
+            ("aws.region".to_string(), "us-east-1".to_string()),
+            ("aws.secret".to_string(), "secret".to_string()),
+        ]);
+        let err = provider.build_object_store(&url, &options).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unknown object store option 'aws.secret'"));
+
+        let options =
+            HashMap::from([("aws.region".to_string(), "us-east-1".to_string())]);
+        provider.build_object_store(&url, &options).unwrap();
+    }
+
     #[test]
     fn unknown_object_store_type() {
         let unknown = "unknown://bucket_name/path";
-        let provider = DatafusionCliObjectStoreProvider {};
+        let provider = DatafusionCliObjectStoreProvider::new();
         let err = provider
             .get_by_url(&Url::from_str(unknown).unwrap())
             .unwrap_err();
@@ -146,7 +534,7 @@ mod tests {
     #[test]
     fn s3_region_validation() {
         let s3 = "s3://bucket_name/path";
-        let provider = DatafusionCliObjectStoreProvider {};
+        let provider = DatafusionCliObjectStoreProvider::new();
         let err = provider
             .get_by_url(&Url::from_str(s3).unwrap())
             .unwrap_err();
@@ -162,4 +550,35 @@ mod tests {
         assert_eq!("".to_string(), msg); // Fail with error message
         env::remove_var("AWS_REGION");
     }
+
+    #[test]
+    fn strip_credential_options() {
+        let definition =
+            "CREATE EXTERNAL TABLE t STORED AS CSV LOCATION 's3://bucket/it''s/' \
+            OPTIONS ('aws.access_key_id' 'id', 'AWS.SECRET_ACCESS_KEY' 'secret', \
+            'aws.region' 'us-east-2', 'delimiter' ';')";
+        assert_eq!(
+            strip_credentials(definition).unwrap(),
+            "CREATE EXTERNAL TABLE t STORED AS CSV LOCATION 's3://bucket/it''s/' \
+            OPTIONS ('aws.region' 'us-east-2', 'delimiter' ';')"
+        );
+
+        let definition = "CREATE EXTERNAL TABLE t STORED AS CSV \
+            LOCATION 'az://container/t/' \
+            OPTIONS ('azure.account_name' 'name', 'azure.account_key' 'key')";
+        assert_eq!(
+            strip_credentials(definition).unwrap(),
+            "CREATE EXTERNAL TABLE t STORED AS CSV \
+            LOCATION 'az://container/t/' \
+            OPTIONS ('azure.account_name' 'name')"
+        );
+
+        let definition =
+            "CREATE EXTERNAL TABLE t STORED AS CSV LOCATION 'gs://bucket/t/' \
+            OPTIONS (\"gcp.service_account_path\" '/key.json')";
+        assert_eq!(
+            strip_credentials(definition).unwrap(),
+            "CREATE EXTERNAL TABLE t STORED AS CSV LOCATION 'gs://bucket/t/'"
+        );
+    }
 }
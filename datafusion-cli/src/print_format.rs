@@ -54,10 +54,18 @@ macro_rules! batches_to_json {
 }
 
 fn print_batches_with_sep(batches: &[RecordBatch], delimiter: u8) -> Result<String> {
+    format_batches_with_sep(batches, delimiter, true)
+}
+
+fn format_batches_with_sep(
+    batches: &[RecordBatch],
+    delimiter: u8,
+    with_header: bool,
+) -> Result<String> {
     let mut bytes = vec![];
     {
         let builder = WriterBuilder::new()
-            .has_headers(true)
+            .has_headers(with_header)
             .with_delimiter(delimiter);
         let mut writer = builder.build(&mut bytes);
         for batch in batches {
@@ -72,16 +80,65 @@ fn print_batches_with_sep(batches: &[RecordBatch], delimiter: u8) -> Result<Stri
 impl PrintFormat {
     /// print the batches to stdout using the specified format
     pub fn print_batches(&self, batches: &[RecordBatch]) -> Result<()> {
-        match self {
-            Self::Csv => println!("{}", print_batches_with_sep(batches, b',')?),
-            Self::Tsv => println!("{}", print_batches_with_sep(batches, b'\t')?),
-            Self::Table => pretty::print_batches(batches)?,
-            Self::Json => println!("{}", batches_to_json!(ArrayWriter, batches)),
-            Self::NdJson => {
-                println!("{}", batches_to_json!(LineDelimitedWriter, batches))
+        println!("{}", self.format_batches(batches)?);
+        Ok(())
+    }
+
+    /// format the batches using the specified format
+    pub fn format_batches(&self, batches: &[RecordBatch]) -> Result<String> {
+        Ok(match self {
+            Self::Csv => print_batches_with_sep(batches, b',')?,
+            Self::Tsv => print_batches_with_sep(batches, b'\t')?,
+            Self::Table => pretty::pretty_format_batches(batches)?.to_string(),
+            Self::Json => batches_to_json!(ArrayWriter, batches),
+            Self::NdJson => batches_to_json!(LineDelimitedWriter, batches),
+        })
+    }
+
+    /// format the batches as a part of a result printed as it is computed,
+    /// `is_first` being true for the first part: the parts, followed by
+    /// [`PrintFormat::format_end`], form the whole result. A part of the
+    /// `table` format is a table of its own.
+    pub fn format_batches_part(
+        &self,
+        batches: &[RecordBatch],
+        is_first: bool,
+    ) -> Result<String> {
+        Ok(match self {
+            Self::Csv => format_batches_with_sep(batches, b',', is_first)?,
+            Self::Tsv => format_batches_with_sep(batches, b'\t', is_first)?,
+            Self::Table => {
+                let mut formatted = pretty::pretty_format_batches(batches)?.to_string();
+                formatted.push('\n');
+                formatted
+            }
+            Self::Json => {
+                let formatted = batches_to_json!(ArrayWriter, batches);
+                // the elements of the array, which is opened by the first part
+                // and closed by the end
+                let elements = formatted
+                    .strip_prefix('[')
+                    .and_then(|formatted| formatted.strip_suffix(']'))
+                    .unwrap_or_default();
+                if elements.is_empty() {
+                    String::new()
+                } else if is_first {
+                    format!("[{elements}")
+                } else {
+                    format!(",{elements}")
+                }
             }
+            Self::NdJson => batches_to_json!(LineDelimitedWriter, batches),
+        })
+    }
+
+    /// The text to print after the last part of a result with rows, see
+    /// [`PrintFormat::format_batches_part`]
+    pub fn format_end(&self) -> &'static str {
+        match self {
+            Self::Json => "]\n",
+            _ => "",
         }
-        Ok(())
     }
 }
 
@@ -152,4 +209,28 @@ mod tests {
         assert_eq!("{\"a\":1,\"b\":4,\"c\":7}\n{\"a\":2,\"b\":5,\"c\":8}\n{\"a\":3,\"b\":6,\"c\":9}\n", r);
         Ok(())
     }
+
+    #[test]
+    fn format_json_parts() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = |values: &[i32]| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Int32Array::from_slice(values))],
+            )
+        };
+
+        let format = PrintFormat::Json;
+        let mut formatted = format.format_batches_part(&[batch(&[1, 2])?], true)?;
+        formatted += &format.format_batches_part(&[batch(&[3])?], false)?;
+        formatted += format.format_end();
+        assert_eq!(formatted, "[{\"a\":1},{\"a\":2},{\"a\":3}]\n");
+
+        let format = PrintFormat::Csv;
+        let mut formatted = format.format_batches_part(&[batch(&[1])?], true)?;
+        formatted += &format.format_batches_part(&[batch(&[2])?], false)?;
+        formatted += format.format_end();
+        assert_eq!(formatted, "a\n1\n2\n");
+        Ok(())
+    }
 }
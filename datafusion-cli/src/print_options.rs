@@ -17,38 +17,355 @@
 
 use crate::print_format::PrintFormat;
 use datafusion::arrow::record_batch::RecordBatch;
-use datafusion::error::Result;
-use std::time::Instant;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::physical_plan::SendableRecordBatchStream;
+use futures::StreamExt;
+use std::fmt::{Display, Formatter};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Stdout, Write};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone)]
 pub struct PrintOptions {
     pub format: PrintFormat,
     pub quiet: bool,
+    /// Whether to print the durations of the stages of the queries
+    pub timing: bool,
+    /// The file the results are appended to, instead of stdout
+    pub output: Option<PathBuf>,
+    /// The maximum number of rows printed to stdout or to the pager
+    pub maxrows: MaxRows,
+    /// The command of the pager the results are piped to, if any
+    pub pager: Option<String>,
 }
 
-fn print_timing_info(row_count: usize, now: Instant) {
+/// The maximum number of rows of a result to print
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaxRows {
+    Unlimited,
+    Limited(usize),
+}
+
+impl FromStr for MaxRows {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("none") {
+            Ok(Self::Unlimited)
+        } else {
+            s.parse().map(Self::Limited).map_err(|_| {
+                format!("{s:?} is not a valid number of rows, nor inf or none")
+            })
+        }
+    }
+}
+
+impl Display for MaxRows {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unlimited => write!(f, "inf"),
+            Self::Limited(max_rows) => write!(f, "{max_rows}"),
+        }
+    }
+}
+
+/// The durations of the stages of a query
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QueryTimings {
+    /// Parsing and creating the logical plan
+    pub planning: Duration,
+    /// Optimizing the logical plan and creating the physical plan
+    pub optimization: Duration,
+    /// Executing the physical plan and printing the results
+    pub execution: Duration,
+}
+
+fn print_timing_info(row_count: usize, is_truncated: bool, now: Instant) {
     println!(
-        "{} {} in set. Query took {:.3} seconds.",
+        "{} {} in set{}. Query took {:.3} seconds.",
         row_count,
         if row_count == 1 { "row" } else { "rows" },
+        if is_truncated { OMITTED_ROWS } else { "" },
         now.elapsed().as_secs_f64()
     );
 }
 
+/// The notice of a result printed up to the maximum number of rows
+const OMITTED_ROWS: &str = " (more rows omitted, see \\pset maxrows)";
+
+/// The number of rows the `table` format formats at once: like with
+/// `FETCH_COUNT` in psql, the larger results are printed as consecutive tables
+const TABLE_CHUNK_ROWS: usize = 1000;
+
 impl PrintOptions {
-    /// print the batches to stdout using the specified format
+    /// print the batches to stdout, to the pager or to the output file, using the
+    /// specified format
     pub fn print_batches(&self, batches: &[RecordBatch], now: Instant) -> Result<()> {
-        if batches.is_empty() {
-            if !self.quiet {
-                print_timing_info(0, now);
+        let mut printer = Printer::new(self);
+        for batch in batches {
+            if printer.is_full() {
+                break;
+            }
+            printer.push(batch)?;
+        }
+        printer.finish(now)
+    }
+
+    /// print the batches of the stream as they are computed, keeping in memory
+    /// only the rows printed at once by the format. The stream is no longer
+    /// polled once the maximum number of rows is printed.
+    pub async fn print_stream(
+        &self,
+        mut stream: SendableRecordBatchStream,
+        now: Instant,
+    ) -> Result<()> {
+        let mut printer = Printer::new(self);
+        while !printer.is_full() {
+            match stream.next().await {
+                Some(batch) => printer.push(&batch?)?,
+                None => break,
+            }
+        }
+        printer.finish(now)
+    }
+
+    /// print the durations of the stages of a query if timing is on
+    pub fn print_timings(&self, timings: &QueryTimings) {
+        if self.timing {
+            println!(
+                "Planning took {:.3} seconds, optimization {:.3} seconds, execution {:.3} seconds.",
+                timings.planning.as_secs_f64(),
+                timings.optimization.as_secs_f64(),
+                timings.execution.as_secs_f64()
+            );
+        }
+    }
+}
+
+/// Where the results are printed
+enum Output {
+    Stdout(Stdout),
+    File(File),
+    /// A pager reading the results from its stdin
+    Pager(Child),
+}
+
+impl Output {
+    fn try_new(options: &PrintOptions) -> Result<Self> {
+        if let Some(path) = &options.output {
+            let file = OpenOptions::new().create(true).append(true).open(path)?;
+            return Ok(Self::File(file));
+        }
+        match &options.pager {
+            Some(pager) => {
+                let mut args = pager.split_whitespace();
+                let program = args.next().ok_or_else(|| {
+                    DataFusionError::Execution("The pager command is empty".to_string())
+                })?;
+                let child = Command::new(program)
+                    .args(args)
+                    .stdin(Stdio::piped())
+                    .spawn()
+                    .map_err(|e| {
+                        DataFusionError::Execution(format!(
+                            "Error starting the pager {pager:?}: {e}"
+                        ))
+                    })?;
+                Ok(Self::Pager(child))
+            }
+            None => Ok(Self::Stdout(io::stdout())),
+        }
+    }
+
+    fn writer(&mut self) -> &mut dyn Write {
+        match self {
+            Self::Stdout(stdout) => stdout,
+            Self::File(file) => file,
+            Self::Pager(child) => child.stdin.as_mut().expect("the stdin is piped"),
+        }
+    }
+}
+
+/// Prints the batches of a result as they are pushed, up to the maximum
+/// number of rows
+struct Printer<'a> {
+    options: &'a PrintOptions,
+    /// Where the rows are printed, opened when the first rows are written so
+    /// that no pager is started for the statements without rows
+    output: Option<Output>,
+    /// The maximum number of rows to print
+    max_rows: usize,
+    /// The number of rows pushed, which exceeds `max_rows` once the result
+    /// is truncated
+    row_count: usize,
+    /// The rows of the `table` format not formatted yet, see
+    /// [`TABLE_CHUNK_ROWS`]
+    buffered: Vec<RecordBatch>,
+    buffered_rows: usize,
+    /// Whether rows have already been written, after the header
+    has_written: bool,
+    /// Whether the pager has exited, in which case the rest of the result
+    /// is only counted
+    is_closed: bool,
+}
+
+impl<'a> Printer<'a> {
+    fn new(options: &'a PrintOptions) -> Self {
+        let max_rows = match options.maxrows {
+            // the result written to a file is complete
+            MaxRows::Limited(max_rows) if options.output.is_none() => max_rows,
+            _ => usize::MAX,
+        };
+        Self {
+            options,
+            output: None,
+            max_rows,
+            row_count: 0,
+            buffered: vec![],
+            buffered_rows: 0,
+            has_written: false,
+            is_closed: false,
+        }
+    }
+
+    /// Returns true once more rows than the maximum were pushed, after which
+    /// the rest of the result is omitted
+    fn is_full(&self) -> bool {
+        self.row_count > self.max_rows
+    }
+
+    fn push(&mut self, batch: &RecordBatch) -> Result<()> {
+        let rows = batch
+            .num_rows()
+            .min(self.max_rows.saturating_sub(self.row_count));
+        self.row_count += batch.num_rows();
+        if rows == 0 {
+            return Ok(());
+        }
+        let batch = batch.slice(0, rows);
+        if self.options.format == PrintFormat::Table {
+            self.buffered.push(batch);
+            self.buffered_rows += rows;
+            if self.buffered_rows >= TABLE_CHUNK_ROWS {
+                self.write_buffered()?;
             }
+            Ok(())
         } else {
-            self.format.print_batches(batches)?;
-            if !self.quiet {
-                let row_count: usize = batches.iter().map(|b| b.num_rows()).sum();
-                print_timing_info(row_count, now);
+            self.write_batches(&[batch])
+        }
+    }
+
+    fn write_buffered(&mut self) -> Result<()> {
+        let batches = std::mem::take(&mut self.buffered);
+        self.buffered_rows = 0;
+        if batches.is_empty() {
+            return Ok(());
+        }
+        self.write_batches(&batches)
+    }
+
+    fn write_batches(&mut self, batches: &[RecordBatch]) -> Result<()> {
+        let formatted = self
+            .options
+            .format
+            .format_batches_part(batches, !self.has_written)?;
+        self.write(formatted.as_bytes())
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        if self.is_closed || bytes.is_empty() {
+            return Ok(());
+        }
+        if self.output.is_none() {
+            self.output = Some(Output::try_new(self.options)?);
+        }
+        let output = self.output.as_mut().expect("the output is open");
+        match output.writer().write_all(bytes) {
+            Ok(()) => {
+                self.has_written = true;
+                Ok(())
+            }
+            // the user has quit the pager
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {
+                self.is_closed = true;
+                Ok(())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn finish(mut self, now: Instant) -> Result<()> {
+        self.write_buffered()?;
+        if self.has_written {
+            self.write(self.options.format.format_end().as_bytes())?;
+        }
+        match &mut self.output {
+            None => {}
+            Some(Output::Stdout(stdout)) => stdout.flush()?,
+            Some(Output::File(file)) => file.flush()?,
+            Some(Output::Pager(child)) => {
+                // closing the stdin lets the pager know the result is complete
+                drop(child.stdin.take());
+                child.wait()?;
             }
         }
+        let is_truncated = self.is_full();
+        let printed_rows = self.row_count.min(self.max_rows);
+        if !self.options.quiet {
+            print_timing_info(printed_rows, is_truncated, now);
+        } else if is_truncated {
+            // a truncated result must not be mistaken for the whole result
+            println!("{printed_rows} rows printed{OMITTED_ROWS}.");
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion::arrow::array::Int32Array;
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+    use std::sync::Arc;
+
+    #[test]
+    fn parse_max_rows() {
+        assert_eq!("40".parse::<MaxRows>(), Ok(MaxRows::Limited(40)));
+        assert_eq!("inf".parse::<MaxRows>(), Ok(MaxRows::Unlimited));
+        assert_eq!("NONE".parse::<MaxRows>(), Ok(MaxRows::Unlimited));
+        assert!("-1".parse::<MaxRows>().is_err());
+        assert_eq!(MaxRows::Limited(40).to_string(), "40");
+        assert_eq!(MaxRows::Unlimited.to_string(), "inf");
+    }
+
+    #[tokio::test]
+    async fn stop_at_max_rows() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1]))],
+        )?;
+        // the error after the maximum number of rows is never polled
+        let batches = vec![
+            Ok(batch.clone()),
+            Ok(batch.clone()),
+            Ok(batch),
+            Err(DataFusionError::Execution("polled".to_string())),
+        ];
+        let stream =
+            RecordBatchStreamAdapter::new(schema, futures::stream::iter(batches));
+        let options = PrintOptions {
+            format: PrintFormat::Csv,
+            quiet: true,
+            timing: false,
+            output: None,
+            maxrows: MaxRows::Limited(2),
+            pager: None,
+        };
+        options.print_stream(Box::pin(stream), Instant::now()).await
+    }
+}
@@ -24,10 +24,10 @@ use crate::{downcast_value, DataFusionError};
 use arrow::{
     array::{
         Array, BinaryArray, BooleanArray, Date32Array, Date64Array, Decimal128Array,
-        DictionaryArray, FixedSizeBinaryArray, FixedSizeListArray, Float32Array,
-        Float64Array, GenericBinaryArray, GenericListArray, GenericStringArray,
-        Int32Array, Int64Array, LargeListArray, ListArray, MapArray, NullArray,
-        OffsetSizeTrait, PrimitiveArray, StringArray, StructArray,
+        Decimal256Array, DictionaryArray, FixedSizeBinaryArray, FixedSizeListArray,
+        Float32Array, Float64Array, GenericBinaryArray, GenericListArray,
+        GenericStringArray, Int32Array, Int64Array, LargeListArray, ListArray, MapArray,
+        NullArray, OffsetSizeTrait, PrimitiveArray, StringArray, StructArray,
         TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
         TimestampSecondArray, UInt32Array, UInt64Array, UnionArray,
     },
@@ -61,6 +61,13 @@ pub fn as_decimal128_array(
     Ok(downcast_value!(array, Decimal128Array))
 }
 
+// Downcast ArrayRef to Decimal256Array
+pub fn as_decimal256_array(
+    array: &dyn Array,
+) -> Result<&Decimal256Array, DataFusionError> {
+    Ok(downcast_value!(array, Decimal256Array))
+}
+
 // Downcast ArrayRef to Float32Array
 pub fn as_float32_array(array: &dyn Array) -> Result<&Float32Array, DataFusionError> {
     Ok(downcast_value!(array, Float32Array))
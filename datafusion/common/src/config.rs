@@ -253,6 +253,44 @@ config_namespace! {
         /// will be reordered heuristically to minimize the cost of evaluation. If false,
         /// the filters are applied in the same order as written in the query
         pub reorder_filters: bool, default = false
+
+        /// If true, the parquet reader reads the bloom filters of the columns compared
+        /// with `=` or `IN` in the predicate, if present in the file, to skip entire row
+        /// groups that can not contain the compared values
+        pub bloom_filter_enabled: bool, default = false
+
+        // The following options affect the parquet files written by DataFusion
+
+        /// Sets the default compression codec used when writing parquet files. Valid values
+        /// are: uncompressed, snappy, gzip(level), lzo, brotli(level), lz4, zstd(level) and
+        /// lz4_raw. If not specified, files are written uncompressed
+        pub compression: Option<String>, default = None
+
+        /// Sets the compression codec of individual columns when writing parquet files, as a
+        /// comma separated list of `column:codec` pairs such as `c1:snappy, c2:zstd(3)`,
+        /// overriding `compression` for these columns
+        pub column_compression: Option<String>, default = None
+
+        /// Sets the maximum number of rows in each row group of the written parquet files
+        pub max_row_group_size: usize, default = 1024 * 1024
+
+        /// Sets the best effort maximum size in bytes of the data pages of the written
+        /// parquet files
+        pub data_pagesize_limit: usize, default = 1024 * 1024
+
+        /// If true, dictionary encoding is enabled for the columns of the written parquet files
+        pub dictionary_enabled: bool, default = true
+
+        /// Sets the level of the statistics written to parquet files. Valid values are:
+        /// none, chunk (column chunk statistics) and page (column chunk and page statistics)
+        pub statistics_enabled: String, default = "page".to_string()
+
+        /// Sets the columns for which bloom filters are written to parquet files, as a comma
+        /// separated list of column names
+        pub bloom_filter_columns: Option<String>, default = None
+
+        /// Sets the `created_by` metadata of the written parquet files
+        pub created_by: String, default = concat!("datafusion version ", env!("CARGO_PKG_VERSION")).to_string()
     }
 }
 
@@ -281,9 +319,9 @@ config_namespace! {
         pub repartition_joins: bool, default = true
 
         /// When set to true, file groups will be repartitioned to achieve maximum parallelism.
-        /// Currently supported only for Parquet format in which case
-        /// multiple row groups from the same file may be read concurrently. If false then each
-        /// row group is read serially, though different files may be read in parallel.
+        /// Currently supported for Parquet and Arrow IPC files, in which case
+        /// multiple row groups or record batches from the same file may be read concurrently.
+        /// If false then each file is read serially, though different files may be read in parallel.
         pub repartition_file_scans: bool, default = true
 
         /// Should DataFusion repartition data using the partitions keys to execute window
@@ -314,6 +352,17 @@ config_namespace! {
         /// process to reorder the join keys
         pub top_down_join_key_reordering: bool, default = true
 
+        /// When set to true, the logical plan optimizer will reorder inner joins
+        /// using the statistics of the joined tables, if they are available, to
+        /// minimize the size of the intermediate results
+        pub enable_join_reordering: bool, default = true
+
+        /// When set to true, the physical plan optimizer will let a sort with a
+        /// limit share the threshold of its top rows with the parquet scans below
+        /// it, so that they can skip row groups and pages that can not be part of
+        /// the result
+        pub enable_topk_dynamic_pruning: bool, default = true
+
         /// When set to true, the physical plan optimizer will prefer HashJoin over SortMergeJoin.
         /// HashJoin can work more efficiently than SortMergeJoin but consumes more memory
         pub prefer_hash_join: bool, default = true
@@ -26,8 +26,8 @@ use std::str::FromStr;
 use std::{convert::TryFrom, fmt, iter::repeat, sync::Arc};
 
 use crate::cast::{
-    as_decimal128_array, as_dictionary_array, as_fixed_size_binary_array,
-    as_fixed_size_list_array, as_list_array, as_struct_array,
+    as_decimal128_array, as_decimal256_array, as_dictionary_array,
+    as_fixed_size_binary_array, as_fixed_size_list_array, as_list_array, as_struct_array,
 };
 use crate::delta::shift_months;
 use crate::error::{DataFusionError, Result};
@@ -35,12 +35,12 @@ use arrow::{
     array::*,
     compute::kernels::cast::{cast, cast_with_options, CastOptions},
     datatypes::{
-        ArrowDictionaryKeyType, ArrowNativeType, DataType, Field, Float32Type,
+        i256, ArrowDictionaryKeyType, ArrowNativeType, DataType, Field, Float32Type,
         Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, IntervalDayTimeType,
         IntervalMonthDayNanoType, IntervalUnit, IntervalYearMonthType, TimeUnit,
         TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
         TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
-        DECIMAL128_MAX_PRECISION,
+        DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
     },
 };
 use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
@@ -63,6 +63,8 @@ pub enum ScalarValue {
     Float64(Option<f64>),
     /// 128bit decimal, using the i128 to represent the decimal, precision scale
     Decimal128(Option<i128>, u8, i8),
+    /// 256bit decimal, using the i256 to represent the decimal, precision scale
+    Decimal256(Option<i256>, u8, i8),
     /// signed 8bit int
     Int8(Option<i8>),
     /// signed 16bit int
@@ -120,6 +122,14 @@ pub enum ScalarValue {
     /// Months and days are encoded as 32-bit signed integers.
     /// Nanoseconds is encoded as a 64-bit signed integer (no leap seconds).
     IntervalMonthDayNano(Option<i128>),
+    /// Duration in seconds
+    DurationSecond(Option<i64>),
+    /// Duration in milliseconds
+    DurationMillisecond(Option<i64>),
+    /// Duration in microseconds
+    DurationMicrosecond(Option<i64>),
+    /// Duration in nanoseconds
+    DurationNanosecond(Option<i64>),
     /// struct of nested ScalarValue
     Struct(Option<Vec<ScalarValue>>, Box<Vec<Field>>),
     /// Dictionary type: index type and value
@@ -138,6 +148,10 @@ impl PartialEq for ScalarValue {
                 v1.eq(v2) && p1.eq(p2) && s1.eq(s2)
             }
             (Decimal128(_, _, _), _) => false,
+            (Decimal256(v1, p1, s1), Decimal256(v2, p2, s2)) => {
+                v1.eq(v2) && p1.eq(p2) && s1.eq(s2)
+            }
+            (Decimal256(_, _, _), _) => false,
             (Boolean(v1), Boolean(v2)) => v1.eq(v2),
             (Boolean(_), _) => false,
             (Float32(v1), Float32(v2)) => match (v1, v2) {
@@ -204,6 +218,14 @@ impl PartialEq for ScalarValue {
             (IntervalDayTime(_), _) => false,
             (IntervalMonthDayNano(v1), IntervalMonthDayNano(v2)) => v1.eq(v2),
             (IntervalMonthDayNano(_), _) => false,
+            (DurationSecond(v1), DurationSecond(v2)) => v1.eq(v2),
+            (DurationSecond(_), _) => false,
+            (DurationMillisecond(v1), DurationMillisecond(v2)) => v1.eq(v2),
+            (DurationMillisecond(_), _) => false,
+            (DurationMicrosecond(v1), DurationMicrosecond(v2)) => v1.eq(v2),
+            (DurationMicrosecond(_), _) => false,
+            (DurationNanosecond(v1), DurationNanosecond(v2)) => v1.eq(v2),
+            (DurationNanosecond(_), _) => false,
             (Struct(v1, t1), Struct(v2, t2)) => v1.eq(v2) && t1.eq(t2),
             (Struct(_, _), _) => false,
             (Dictionary(k1, v1), Dictionary(k2, v2)) => k1.eq(k2) && v1.eq(v2),
@@ -231,6 +253,15 @@ impl PartialOrd for ScalarValue {
                 }
             }
             (Decimal128(_, _, _), _) => None,
+            (Decimal256(v1, p1, s1), Decimal256(v2, p2, s2)) => {
+                if p1.eq(p2) && s1.eq(s2) {
+                    v1.partial_cmp(v2)
+                } else {
+                    // Two decimal values can be compared if they have the same precision and scale.
+                    None
+                }
+            }
+            (Decimal256(_, _, _), _) => None,
             (Boolean(v1), Boolean(v2)) => v1.partial_cmp(v2),
             (Boolean(_), _) => None,
             (Float32(v1), Float32(v2)) => match (v1, v2) {
@@ -309,6 +340,14 @@ impl PartialOrd for ScalarValue {
             (IntervalDayTime(_), _) => None,
             (IntervalMonthDayNano(v1), IntervalMonthDayNano(v2)) => v1.partial_cmp(v2),
             (IntervalMonthDayNano(_), _) => None,
+            (DurationSecond(v1), DurationSecond(v2)) => v1.partial_cmp(v2),
+            (DurationSecond(_), _) => None,
+            (DurationMillisecond(v1), DurationMillisecond(v2)) => v1.partial_cmp(v2),
+            (DurationMillisecond(_), _) => None,
+            (DurationMicrosecond(v1), DurationMicrosecond(v2)) => v1.partial_cmp(v2),
+            (DurationMicrosecond(_), _) => None,
+            (DurationNanosecond(v1), DurationNanosecond(v2)) => v1.partial_cmp(v2),
+            (DurationNanosecond(_), _) => None,
             (Struct(v1, t1), Struct(v2, t2)) => {
                 if t1.eq(t2) {
                     v1.partial_cmp(v2)
@@ -406,6 +445,64 @@ macro_rules! decimal_right {
     };
 }
 
+/// Returns `10^exp` as an [`i256`]
+fn i256_pow10(exp: u32) -> i256 {
+    let ten = i256::from_i128(10);
+    (0..exp).fold(i256::ONE, |acc, _| acc.wrapping_mul(ten))
+}
+
+/// Returns the error for a `Decimal256` operation that overflows
+fn decimal256_overflow(lhs: i256, op: &str, rhs: i256) -> DataFusionError {
+    DataFusionError::Execution(format!("Overflow happened on: {lhs:?} {op} {rhs:?}"))
+}
+
+/// Adds (`sign` >= 0) or subtracts (`sign` < 0) two `Decimal256` values,
+/// rescaling the operands to the larger of the two scales first.
+/// Mirrors the null handling of `decimal_op!` for `Decimal128`, but returns
+/// an error instead of wrapping around when the result overflows.
+fn decimal256_op(
+    lhs: &Option<i256>,
+    rhs: &Option<i256>,
+    precision: u8,
+    lhs_scale: i8,
+    rhs_scale: i8,
+    sign: i32,
+) -> Result<ScalarValue> {
+    let scale = max(lhs_scale, rhs_scale);
+    let rescale = |value: i256, from_scale: i8| {
+        let mul = i256_pow10((scale - from_scale) as u32);
+        value
+            .checked_mul(mul)
+            .ok_or_else(|| decimal256_overflow(value, "*", mul))
+    };
+    let value = match (lhs, rhs) {
+        (None, None) => None,
+        (Some(lhs), None) => Some(rescale(*lhs, lhs_scale)?),
+        (None, Some(rhs)) => {
+            let rhs = rescale(*rhs, rhs_scale)?;
+            Some(if sign < 0 {
+                i256::ZERO
+                    .checked_sub(rhs)
+                    .ok_or_else(|| decimal256_overflow(i256::ZERO, "-", rhs))?
+            } else {
+                rhs
+            })
+        }
+        (Some(lhs), Some(rhs)) => {
+            let lhs = rescale(*lhs, lhs_scale)?;
+            let rhs = rescale(*rhs, rhs_scale)?;
+            Some(if sign < 0 {
+                lhs.checked_sub(rhs)
+                    .ok_or_else(|| decimal256_overflow(lhs, "-", rhs))?
+            } else {
+                lhs.checked_add(rhs)
+                    .ok_or_else(|| decimal256_overflow(lhs, "+", rhs))?
+            })
+        }
+    };
+    Ok(ScalarValue::Decimal256(value, precision, scale))
+}
+
 // Returns the result of applying operation to two scalar values.
 macro_rules! primitive_op {
     ($LEFT:expr, $RIGHT:expr, $SCALAR:ident, $OPERATION:tt) => {
@@ -473,6 +570,10 @@ macro_rules! impl_op {
             ) => {
                 decimal_op!(v1, v2, *p1.max(p2), *s1, *s2, $OPERATION)
             }
+            (
+                ScalarValue::Decimal256(v1, p1, s1),
+                ScalarValue::Decimal256(v2, p2, s2),
+            ) => decimal256_op(v1, v2, *p1.max(p2), *s1, *s2, get_sign!($OPERATION)),
             (ScalarValue::Float64(lhs), ScalarValue::Float64(rhs)) => {
                 primitive_op!(lhs, rhs, Float64, $OPERATION)
             }
@@ -503,6 +604,52 @@ macro_rules! impl_op {
             (ScalarValue::Int8(lhs), ScalarValue::Int8(rhs)) => {
                 primitive_op!(lhs, rhs, Int8, $OPERATION)
             }
+            (ScalarValue::DurationSecond(lhs), ScalarValue::DurationSecond(rhs)) => {
+                primitive_op!(lhs, rhs, DurationSecond, $OPERATION)
+            }
+            (
+                ScalarValue::DurationMillisecond(lhs),
+                ScalarValue::DurationMillisecond(rhs),
+            ) => {
+                primitive_op!(lhs, rhs, DurationMillisecond, $OPERATION)
+            }
+            (
+                ScalarValue::DurationMicrosecond(lhs),
+                ScalarValue::DurationMicrosecond(rhs),
+            ) => {
+                primitive_op!(lhs, rhs, DurationMicrosecond, $OPERATION)
+            }
+            (
+                ScalarValue::DurationNanosecond(lhs),
+                ScalarValue::DurationNanosecond(rhs),
+            ) => {
+                primitive_op!(lhs, rhs, DurationNanosecond, $OPERATION)
+            }
+            // Subtracting two timestamps of the same unit yields a duration
+            (
+                ScalarValue::TimestampSecond(lhs, _),
+                ScalarValue::TimestampSecond(rhs, _),
+            ) => {
+                timestamp_sub!(lhs, rhs, DurationSecond, $OPERATION)
+            }
+            (
+                ScalarValue::TimestampMillisecond(lhs, _),
+                ScalarValue::TimestampMillisecond(rhs, _),
+            ) => {
+                timestamp_sub!(lhs, rhs, DurationMillisecond, $OPERATION)
+            }
+            (
+                ScalarValue::TimestampMicrosecond(lhs, _),
+                ScalarValue::TimestampMicrosecond(rhs, _),
+            ) => {
+                timestamp_sub!(lhs, rhs, DurationMicrosecond, $OPERATION)
+            }
+            (
+                ScalarValue::TimestampNanosecond(lhs, _),
+                ScalarValue::TimestampNanosecond(rhs, _),
+            ) => {
+                timestamp_sub!(lhs, rhs, DurationNanosecond, $OPERATION)
+            }
             // Binary operations on arguments with different types:
             (ScalarValue::Date32(Some(days)), _) => {
                 let value = date32_add(*days, $RHS, get_sign!($OPERATION))?;
@@ -554,6 +701,28 @@ macro_rules! impl_op {
     };
 }
 
+macro_rules! timestamp_sub {
+    ($LHS:expr, $RHS:expr, $DURATION:ident, -) => {
+        match ($LHS, $RHS) {
+            (Some(lhs), Some(rhs)) => lhs
+                .checked_sub(*rhs)
+                .map(|duration| ScalarValue::$DURATION(Some(duration)))
+                .ok_or_else(|| {
+                    DataFusionError::Execution(format!(
+                        "Overflow happened on: {lhs:?} - {rhs:?}"
+                    ))
+                }),
+            _ => Ok(ScalarValue::$DURATION(None)),
+        }
+    };
+    ($LHS:expr, $RHS:expr, $DURATION:ident, +) => {{
+        let _ = ($LHS, $RHS);
+        Err(DataFusionError::Internal(
+            "Operator + is not implemented for two timestamps".to_string(),
+        ))
+    }};
+}
+
 macro_rules! get_sign {
     (+) => {
         1
@@ -622,18 +791,75 @@ fn do_date_time_math(
 
 fn do_date_math<D>(prior: D, scalar: &ScalarValue, sign: i32) -> Result<D>
 where
-    D: Datelike + Add<Duration, Output = D>,
+    D: Datelike + Add<Duration, Output = D> + CheckedDurationAdd,
 {
     Ok(match scalar {
         ScalarValue::IntervalDayTime(Some(i)) => add_day_time(prior, *i, sign),
         ScalarValue::IntervalYearMonth(Some(i)) => shift_months(prior, *i * sign),
         ScalarValue::IntervalMonthDayNano(Some(i)) => add_m_d_nano(prior, *i, sign),
+        // chrono durations are limited to i64::MAX milliseconds
+        ScalarValue::DurationSecond(Some(v)) => add_duration(
+            prior,
+            v.checked_mul(sign as i64)
+                .and_then(|v| v.checked_mul(1000))
+                .map(Duration::milliseconds),
+            scalar,
+        )?,
+        ScalarValue::DurationMillisecond(Some(v)) => add_duration(
+            prior,
+            v.checked_mul(sign as i64).map(Duration::milliseconds),
+            scalar,
+        )?,
+        ScalarValue::DurationMicrosecond(Some(v)) => add_duration(
+            prior,
+            v.checked_mul(sign as i64).map(Duration::microseconds),
+            scalar,
+        )?,
+        ScalarValue::DurationNanosecond(Some(v)) => add_duration(
+            prior,
+            v.checked_mul(sign as i64).map(Duration::nanoseconds),
+            scalar,
+        )?,
         other => Err(DataFusionError::Execution(format!(
             "DateIntervalExpr does not support non-interval type {other:?}"
         )))?,
     })
 }
 
+/// Dates and timestamps that a [`Duration`] can be added to without panicking
+/// when the result is out of range
+trait CheckedDurationAdd: Sized {
+    fn checked_add_duration(self, duration: Duration) -> Option<Self>;
+}
+
+impl CheckedDurationAdd for NaiveDate {
+    fn checked_add_duration(self, duration: Duration) -> Option<Self> {
+        self.checked_add_signed(duration)
+    }
+}
+
+impl CheckedDurationAdd for NaiveDateTime {
+    fn checked_add_duration(self, duration: Duration) -> Option<Self> {
+        self.checked_add_signed(duration)
+    }
+}
+
+/// Adds `duration`, which is `None` if computing it from `scalar` overflowed,
+/// to `prior`
+fn add_duration<D: CheckedDurationAdd>(
+    prior: D,
+    duration: Option<Duration>,
+    scalar: &ScalarValue,
+) -> Result<D> {
+    duration
+        .and_then(|duration| prior.checked_add_duration(duration))
+        .ok_or_else(|| {
+            DataFusionError::Execution(format!(
+                "Overflow happened adding {scalar:?} to a date or timestamp"
+            ))
+        })
+}
+
 // Can remove once chrono:0.4.23 is released
 fn add_m_d_nano<D>(prior: D, interval: i128, sign: i32) -> D
 where
@@ -660,6 +886,16 @@ where
     intermediate.add(Duration::milliseconds(ms as i64))
 }
 
+/// Negates a duration, returning an error instead of overflowing on `i64::MIN`
+fn duration_negate(v: &Option<i64>) -> Result<Option<i64>> {
+    v.map(|v| {
+        v.checked_neg().ok_or_else(|| {
+            DataFusionError::Execution(format!("Overflow happened on: - {v:?}"))
+        })
+    })
+    .transpose()
+}
+
 //Float wrapper over f32/f64. Just because we cannot build std::hash::Hash for floats directly we have to do it through type wrapper
 struct Fl<T>(T);
 
@@ -686,6 +922,11 @@ impl std::hash::Hash for ScalarValue {
                 p.hash(state);
                 s.hash(state)
             }
+            Decimal256(v, p, s) => {
+                v.hash(state);
+                p.hash(state);
+                s.hash(state)
+            }
             Boolean(v) => v.hash(state),
             Float32(v) => v.map(Fl).hash(state),
             Float64(v) => v.map(Fl).hash(state),
@@ -719,6 +960,10 @@ impl std::hash::Hash for ScalarValue {
             IntervalYearMonth(v) => v.hash(state),
             IntervalDayTime(v) => v.hash(state),
             IntervalMonthDayNano(v) => v.hash(state),
+            DurationSecond(v) => v.hash(state),
+            DurationMillisecond(v) => v.hash(state),
+            DurationMicrosecond(v) => v.hash(state),
+            DurationNanosecond(v) => v.hash(state),
             Struct(v, t) => {
                 v.hash(state);
                 t.hash(state);
@@ -1004,6 +1249,17 @@ impl ScalarValue {
         )))
     }
 
+    /// Create a 256 bit decimal Scalar from value/precision and scale.
+    pub fn try_new_decimal256(value: i256, precision: u8, scale: i8) -> Result<Self> {
+        // make sure the precision and scale is valid
+        if precision <= DECIMAL256_MAX_PRECISION && scale.unsigned_abs() <= precision {
+            return Ok(ScalarValue::Decimal256(Some(value), precision, scale));
+        }
+        Err(DataFusionError::Internal(format!(
+            "Can not new a decimal type ScalarValue for precision {precision} and scale {scale}"
+        )))
+    }
+
     /// Returns a [`ScalarValue::Utf8`] representing `val`
     pub fn new_utf8(val: impl Into<String>) -> Self {
         ScalarValue::Utf8(Some(val.into()))
@@ -1091,6 +1347,9 @@ impl ScalarValue {
             ScalarValue::Decimal128(_, precision, scale) => {
                 DataType::Decimal128(*precision, *scale)
             }
+            ScalarValue::Decimal256(_, precision, scale) => {
+                DataType::Decimal256(*precision, *scale)
+            }
             ScalarValue::TimestampSecond(_, tz_opt) => {
                 DataType::Timestamp(TimeUnit::Second, tz_opt.clone())
             }
@@ -1128,6 +1387,16 @@ impl ScalarValue {
             ScalarValue::IntervalMonthDayNano(_) => {
                 DataType::Interval(IntervalUnit::MonthDayNano)
             }
+            ScalarValue::DurationSecond(_) => DataType::Duration(TimeUnit::Second),
+            ScalarValue::DurationMillisecond(_) => {
+                DataType::Duration(TimeUnit::Millisecond)
+            }
+            ScalarValue::DurationMicrosecond(_) => {
+                DataType::Duration(TimeUnit::Microsecond)
+            }
+            ScalarValue::DurationNanosecond(_) => {
+                DataType::Duration(TimeUnit::Nanosecond)
+            }
             ScalarValue::Struct(_, fields) => DataType::Struct(fields.as_ref().clone()),
             ScalarValue::Dictionary(k, v) => {
                 DataType::Dictionary(k.clone(), Box::new(v.get_datatype()))
@@ -1153,6 +1422,24 @@ impl ScalarValue {
             ScalarValue::Decimal128(Some(v), precision, scale) => {
                 Ok(ScalarValue::Decimal128(Some(-v), *precision, *scale))
             }
+            ScalarValue::Decimal256(Some(v), precision, scale) => {
+                let negated = i256::ZERO
+                    .checked_sub(*v)
+                    .ok_or_else(|| decimal256_overflow(i256::ZERO, "-", *v))?;
+                Ok(ScalarValue::Decimal256(Some(negated), *precision, *scale))
+            }
+            ScalarValue::DurationSecond(v) => {
+                Ok(ScalarValue::DurationSecond(duration_negate(v)?))
+            }
+            ScalarValue::DurationMillisecond(v) => {
+                Ok(ScalarValue::DurationMillisecond(duration_negate(v)?))
+            }
+            ScalarValue::DurationMicrosecond(v) => {
+                Ok(ScalarValue::DurationMicrosecond(duration_negate(v)?))
+            }
+            ScalarValue::DurationNanosecond(v) => {
+                Ok(ScalarValue::DurationNanosecond(duration_negate(v)?))
+            }
             value => Err(DataFusionError::Internal(format!(
                 "Can not run arithmetic negative on scalar value {value:?}"
             ))),
@@ -1187,6 +1474,7 @@ impl ScalarValue {
             ScalarValue::Float32(v) => v.is_none(),
             ScalarValue::Float64(v) => v.is_none(),
             ScalarValue::Decimal128(v, _, _) => v.is_none(),
+            ScalarValue::Decimal256(v, _, _) => v.is_none(),
             ScalarValue::Int8(v) => v.is_none(),
             ScalarValue::Int16(v) => v.is_none(),
             ScalarValue::Int32(v) => v.is_none(),
@@ -1214,6 +1502,10 @@ impl ScalarValue {
             ScalarValue::IntervalYearMonth(v) => v.is_none(),
             ScalarValue::IntervalDayTime(v) => v.is_none(),
             ScalarValue::IntervalMonthDayNano(v) => v.is_none(),
+            ScalarValue::DurationSecond(v) => v.is_none(),
+            ScalarValue::DurationMillisecond(v) => v.is_none(),
+            ScalarValue::DurationMicrosecond(v) => v.is_none(),
+            ScalarValue::DurationNanosecond(v) => v.is_none(),
             ScalarValue::Struct(v, _) => v.is_none(),
             ScalarValue::Dictionary(_, v) => v.is_null(),
         }
@@ -1443,10 +1735,10 @@ impl ScalarValue {
                     ScalarValue::iter_to_decimal_array(scalars, *precision, *scale)?;
                 Arc::new(decimal_array)
             }
-            DataType::Decimal256(_, _) => {
-                return Err(DataFusionError::Internal(
-                    "Decimal256 is not supported for ScalarValue".to_string(),
-                ));
+            DataType::Decimal256(precision, scale) => {
+                let decimal_array =
+                    ScalarValue::iter_to_decimal256_array(scalars, *precision, *scale)?;
+                Arc::new(decimal_array)
             }
             DataType::Null => ScalarValue::iter_to_null_array(scalars),
             DataType::Boolean => build_array_primitive!(BooleanArray, Boolean),
@@ -1508,6 +1800,21 @@ impl ScalarValue {
             DataType::Interval(IntervalUnit::YearMonth) => {
                 build_array_primitive!(IntervalYearMonthArray, IntervalYearMonth)
             }
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                build_array_primitive!(IntervalMonthDayNanoArray, IntervalMonthDayNano)
+            }
+            DataType::Duration(TimeUnit::Second) => {
+                build_array_primitive!(DurationSecondArray, DurationSecond)
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                build_array_primitive!(DurationMillisecondArray, DurationMillisecond)
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                build_array_primitive!(DurationMicrosecondArray, DurationMicrosecond)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                build_array_primitive!(DurationNanosecondArray, DurationNanosecond)
+            }
             DataType::List(fields) if fields.data_type() == &DataType::Int8 => {
                 build_array_list_primitive!(Int8Type, Int8, i8)
             }
@@ -1655,9 +1962,7 @@ impl ScalarValue {
             | DataType::Time32(TimeUnit::Nanosecond)
             | DataType::Time64(TimeUnit::Second)
             | DataType::Time64(TimeUnit::Millisecond)
-            | DataType::Duration(_)
             | DataType::FixedSizeList(_, _)
-            | DataType::Interval(_)
             | DataType::LargeList(_)
             | DataType::Union(_, _, _)
             | DataType::Map(_, _)
@@ -1764,6 +2069,22 @@ impl ScalarValue {
         Ok(list_array)
     }
 
+    fn iter_to_decimal256_array(
+        scalars: impl IntoIterator<Item = ScalarValue>,
+        precision: u8,
+        scale: i8,
+    ) -> Result<Decimal256Array> {
+        let array = scalars
+            .into_iter()
+            .map(|element: ScalarValue| match element {
+                ScalarValue::Decimal256(v1, _, _) => v1,
+                _ => unreachable!(),
+            })
+            .collect::<Decimal256Array>()
+            .with_precision_and_scale(precision, scale)?;
+        Ok(array)
+    }
+
     fn build_decimal_array(
         value: Option<i128>,
         precision: u8,
@@ -1777,12 +2098,28 @@ impl ScalarValue {
             .unwrap()
     }
 
+    fn build_decimal256_array(
+        value: Option<i256>,
+        precision: u8,
+        scale: i8,
+        size: usize,
+    ) -> Decimal256Array {
+        std::iter::repeat(value)
+            .take(size)
+            .collect::<Decimal256Array>()
+            .with_precision_and_scale(precision, scale)
+            .unwrap()
+    }
+
     /// Converts a scalar value into an array of `size` rows.
     pub fn to_array_of_size(&self, size: usize) -> ArrayRef {
         match self {
             ScalarValue::Decimal128(e, precision, scale) => Arc::new(
                 ScalarValue::build_decimal_array(*e, *precision, *scale, size),
             ),
+            ScalarValue::Decimal256(e, precision, scale) => Arc::new(
+                ScalarValue::build_decimal256_array(*e, *precision, *scale, size),
+            ),
             ScalarValue::Boolean(e) => {
                 Arc::new(BooleanArray::from(vec![*e; size])) as ArrayRef
             }
@@ -1981,6 +2318,34 @@ impl ScalarValue {
                 e,
                 size
             ),
+            ScalarValue::DurationSecond(e) => build_array_from_option!(
+                Duration,
+                TimeUnit::Second,
+                DurationSecondArray,
+                e,
+                size
+            ),
+            ScalarValue::DurationMillisecond(e) => build_array_from_option!(
+                Duration,
+                TimeUnit::Millisecond,
+                DurationMillisecondArray,
+                e,
+                size
+            ),
+            ScalarValue::DurationMicrosecond(e) => build_array_from_option!(
+                Duration,
+                TimeUnit::Microsecond,
+                DurationMicrosecondArray,
+                e,
+                size
+            ),
+            ScalarValue::DurationNanosecond(e) => build_array_from_option!(
+                Duration,
+                TimeUnit::Nanosecond,
+                DurationNanosecondArray,
+                e,
+                size
+            ),
             ScalarValue::Struct(values, fields) => match values {
                 Some(values) => {
                     let field_values: Vec<_> = fields
@@ -2039,6 +2404,21 @@ impl ScalarValue {
         }
     }
 
+    fn get_decimal256_value_from_array(
+        array: &dyn Array,
+        index: usize,
+        precision: u8,
+        scale: i8,
+    ) -> Result<ScalarValue> {
+        let array = as_decimal256_array(array)?;
+        if array.is_null(index) {
+            Ok(ScalarValue::Decimal256(None, precision, scale))
+        } else {
+            let value = array.value(index);
+            Ok(ScalarValue::Decimal256(Some(value), precision, scale))
+        }
+    }
+
     /// Converts a value in `array` at `index` into a ScalarValue
     pub fn try_from_array(array: &dyn Array, index: usize) -> Result<Self> {
         // handle NULL value
@@ -2053,6 +2433,11 @@ impl ScalarValue {
                     array, index, *precision, *scale,
                 )?
             }
+            DataType::Decimal256(precision, scale) => {
+                ScalarValue::get_decimal256_value_from_array(
+                    array, index, *precision, *scale,
+                )?
+            }
             DataType::Boolean => typed_cast!(array, index, BooleanArray, Boolean),
             DataType::Float64 => typed_cast!(array, index, Float64Array, Float64),
             DataType::Float32 => typed_cast!(array, index, Float32Array, Float32),
@@ -2138,6 +2523,32 @@ impl ScalarValue {
                     tz_opt
                 )
             }
+            DataType::Interval(IntervalUnit::YearMonth) => {
+                typed_cast!(array, index, IntervalYearMonthArray, IntervalYearMonth)
+            }
+            DataType::Interval(IntervalUnit::DayTime) => {
+                typed_cast!(array, index, IntervalDayTimeArray, IntervalDayTime)
+            }
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                typed_cast!(
+                    array,
+                    index,
+                    IntervalMonthDayNanoArray,
+                    IntervalMonthDayNano
+                )
+            }
+            DataType::Duration(TimeUnit::Second) => {
+                typed_cast!(array, index, DurationSecondArray, DurationSecond)
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                typed_cast!(array, index, DurationMillisecondArray, DurationMillisecond)
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                typed_cast!(array, index, DurationMicrosecondArray, DurationMicrosecond)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                typed_cast!(array, index, DurationNanosecondArray, DurationNanosecond)
+            }
             DataType::Dictionary(key_type, _) => {
                 let (values_array, values_index) = match key_type.as_ref() {
                     DataType::Int8 => get_dict_value::<Int8Type>(array, index),
@@ -2234,6 +2645,25 @@ impl ScalarValue {
         }
     }
 
+    fn eq_array_decimal256(
+        array: &ArrayRef,
+        index: usize,
+        value: Option<&i256>,
+        precision: u8,
+        scale: i8,
+    ) -> Result<bool> {
+        let array = as_decimal256_array(array)?;
+        if array.precision() != precision || array.scale() != scale {
+            return Ok(false);
+        }
+        let is_null = array.is_null(index);
+        if let Some(v) = value {
+            Ok(!array.is_null(index) && array.value(index) == *v)
+        } else {
+            Ok(is_null)
+        }
+    }
+
     /// Compares a single row of array @ index for equality with self,
     /// in an optimized fashion.
     ///
@@ -2263,6 +2693,16 @@ impl ScalarValue {
                 )
                 .unwrap()
             }
+            ScalarValue::Decimal256(v, precision, scale) => {
+                ScalarValue::eq_array_decimal256(
+                    array,
+                    index,
+                    v.as_ref(),
+                    *precision,
+                    *scale,
+                )
+                .unwrap()
+            }
             ScalarValue::Boolean(val) => {
                 eq_array_primitive!(array, index, BooleanArray, val)
             }
@@ -2339,6 +2779,18 @@ impl ScalarValue {
             ScalarValue::IntervalMonthDayNano(val) => {
                 eq_array_primitive!(array, index, IntervalMonthDayNanoArray, val)
             }
+            ScalarValue::DurationSecond(val) => {
+                eq_array_primitive!(array, index, DurationSecondArray, val)
+            }
+            ScalarValue::DurationMillisecond(val) => {
+                eq_array_primitive!(array, index, DurationMillisecondArray, val)
+            }
+            ScalarValue::DurationMicrosecond(val) => {
+                eq_array_primitive!(array, index, DurationMicrosecondArray, val)
+            }
+            ScalarValue::DurationNanosecond(val) => {
+                eq_array_primitive!(array, index, DurationNanosecondArray, val)
+            }
             ScalarValue::Struct(_, _) => unimplemented!(),
             ScalarValue::Dictionary(key_type, v) => {
                 let (values_array, values_index) = match key_type.as_ref() {
@@ -2372,6 +2824,7 @@ impl ScalarValue {
                 | ScalarValue::Float32(_)
                 | ScalarValue::Float64(_)
                 | ScalarValue::Decimal128(_, _, _)
+                | ScalarValue::Decimal256(_, _, _)
                 | ScalarValue::Int8(_)
                 | ScalarValue::Int16(_)
                 | ScalarValue::Int32(_)
@@ -2388,7 +2841,11 @@ impl ScalarValue {
                 | ScalarValue::Time64Nanosecond(_)
                 | ScalarValue::IntervalYearMonth(_)
                 | ScalarValue::IntervalDayTime(_)
-                | ScalarValue::IntervalMonthDayNano(_) => 0,
+                | ScalarValue::IntervalMonthDayNano(_)
+                | ScalarValue::DurationSecond(_)
+                | ScalarValue::DurationMillisecond(_)
+                | ScalarValue::DurationMicrosecond(_)
+                | ScalarValue::DurationNanosecond(_) => 0,
                 ScalarValue::Utf8(s)
                 | ScalarValue::LargeUtf8(s)
                 | ScalarValue::TimestampSecond(_, s)
@@ -2597,6 +3054,22 @@ impl TryFrom<ScalarValue> for i128 {
     }
 }
 
+// special implementation for i256 because of Decimal256
+impl TryFrom<ScalarValue> for i256 {
+    type Error = DataFusionError;
+
+    fn try_from(value: ScalarValue) -> Result<Self> {
+        match value {
+            ScalarValue::Decimal256(Some(inner_value), _, _) => Ok(inner_value),
+            _ => Err(DataFusionError::Internal(format!(
+                "Cannot convert {:?} to {}",
+                value,
+                std::any::type_name::<Self>()
+            ))),
+        }
+    }
+}
+
 impl_try_from!(UInt8, u8);
 impl_try_from!(UInt16, u16);
 impl_try_from!(UInt32, u32);
@@ -2634,6 +3107,9 @@ impl TryFrom<&DataType> for ScalarValue {
             DataType::Decimal128(precision, scale) => {
                 ScalarValue::Decimal128(None, *precision, *scale)
             }
+            DataType::Decimal256(precision, scale) => {
+                ScalarValue::Decimal256(None, *precision, *scale)
+            }
             DataType::Utf8 => ScalarValue::Utf8(None),
             DataType::LargeUtf8 => ScalarValue::LargeUtf8(None),
             DataType::Binary => ScalarValue::Binary(None),
@@ -2670,6 +3146,16 @@ impl TryFrom<&DataType> for ScalarValue {
             DataType::Interval(IntervalUnit::MonthDayNano) => {
                 ScalarValue::IntervalMonthDayNano(None)
             }
+            DataType::Duration(TimeUnit::Second) => ScalarValue::DurationSecond(None),
+            DataType::Duration(TimeUnit::Millisecond) => {
+                ScalarValue::DurationMillisecond(None)
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                ScalarValue::DurationMicrosecond(None)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                ScalarValue::DurationNanosecond(None)
+            }
             DataType::Dictionary(index_type, value_type) => ScalarValue::Dictionary(
                 index_type.clone(),
                 Box::new(value_type.as_ref().try_into()?),
@@ -2705,6 +3191,9 @@ impl fmt::Display for ScalarValue {
             ScalarValue::Decimal128(v, p, s) => {
                 write!(f, "{v:?},{p:?},{s:?}")?;
             }
+            ScalarValue::Decimal256(v, p, s) => {
+                write!(f, "{v:?},{p:?},{s:?}")?;
+            }
             ScalarValue::Boolean(e) => format_option!(f, e)?,
             ScalarValue::Float32(e) => format_option!(f, e)?,
             ScalarValue::Float64(e) => format_option!(f, e)?,
@@ -2775,6 +3264,10 @@ impl fmt::Display for ScalarValue {
             ScalarValue::IntervalDayTime(e) => format_option!(f, e)?,
             ScalarValue::IntervalYearMonth(e) => format_option!(f, e)?,
             ScalarValue::IntervalMonthDayNano(e) => format_option!(f, e)?,
+            ScalarValue::DurationSecond(e) => format_option!(f, e)?,
+            ScalarValue::DurationMillisecond(e) => format_option!(f, e)?,
+            ScalarValue::DurationMicrosecond(e) => format_option!(f, e)?,
+            ScalarValue::DurationNanosecond(e) => format_option!(f, e)?,
             ScalarValue::Struct(e, fields) => match e {
                 Some(l) => write!(
                     f,
@@ -2798,6 +3291,7 @@ impl fmt::Debug for ScalarValue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             ScalarValue::Decimal128(_, _, _) => write!(f, "Decimal128({self})"),
+            ScalarValue::Decimal256(_, _, _) => write!(f, "Decimal256({self})"),
             ScalarValue::Boolean(_) => write!(f, "Boolean({self})"),
             ScalarValue::Float32(_) => write!(f, "Float32({self})"),
             ScalarValue::Float64(_) => write!(f, "Float64({self})"),
@@ -2857,6 +3351,16 @@ impl fmt::Debug for ScalarValue {
             ScalarValue::IntervalMonthDayNano(_) => {
                 write!(f, "IntervalMonthDayNano(\"{self}\")")
             }
+            ScalarValue::DurationSecond(_) => write!(f, "DurationSecond(\"{self}\")"),
+            ScalarValue::DurationMillisecond(_) => {
+                write!(f, "DurationMillisecond(\"{self}\")")
+            }
+            ScalarValue::DurationMicrosecond(_) => {
+                write!(f, "DurationMicrosecond(\"{self}\")")
+            }
+            ScalarValue::DurationNanosecond(_) => {
+                write!(f, "DurationNanosecond(\"{self}\")")
+            }
             ScalarValue::Struct(e, fields) => {
                 // Use Debug representation of field values
                 match e {
@@ -2979,6 +3483,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_duration_overflow() -> Result<()> {
+        let min = ScalarValue::TimestampSecond(Some(i64::MIN), None);
+        let max = ScalarValue::TimestampSecond(Some(i64::MAX), None);
+        assert!(max.sub(&min).is_err());
+        assert_eq!(min.sub(&min)?, ScalarValue::DurationSecond(Some(0)),);
+
+        let timestamp = ScalarValue::TimestampNanosecond(Some(0), None);
+        for duration in [
+            ScalarValue::DurationSecond(Some(i64::MIN)),
+            ScalarValue::DurationSecond(Some(i64::MAX / 1000)),
+            ScalarValue::DurationMillisecond(Some(i64::MIN)),
+            ScalarValue::DurationMicrosecond(Some(i64::MIN)),
+            ScalarValue::DurationNanosecond(Some(i64::MIN)),
+        ] {
+            assert!(timestamp.sub(&duration).is_err(), "{duration:?}");
+        }
+        let duration = ScalarValue::DurationMicrosecond(Some(i64::MIN));
+        assert!(duration.arithmetic_negate().is_err());
+        assert_eq!(
+            timestamp.add(&ScalarValue::DurationMillisecond(Some(1000)))?,
+            ScalarValue::TimestampNanosecond(Some(1_000_000_000), None),
+        );
+        Ok(())
+    }
+
     #[test]
     fn scalar_decimal_test() -> Result<()> {
         let decimal_value = ScalarValue::Decimal128(Some(123), 10, 1);
@@ -3371,7 +3901,7 @@ mod tests {
         // The alignment requirements differ across architectures and
         // thus the size of the enum appears to as as well
 
-        assert_eq!(std::mem::size_of::<ScalarValue>(), 48);
+        assert_eq!(std::mem::size_of::<ScalarValue>(), 64);
     }
 
     #[test]
@@ -4337,6 +4867,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decimal256_operations() -> Result<()> {
+        let value =
+            |v: i128, s: i8| ScalarValue::Decimal256(Some(i256::from_i128(v)), 50, s);
+
+        assert_eq!(value(123, 2).add(&value(124, 3))?, value(1354, 3));
+        assert_eq!(value(123, 2).sub(&value(124, 3))?, value(1106, 3));
+        assert_eq!(
+            ScalarValue::Decimal256(None, 50, 2).sub(&value(124, 2))?,
+            value(-124, 2)
+        );
+
+        let max = ScalarValue::Decimal256(Some(i256::MAX), 76, 0);
+        let min = ScalarValue::Decimal256(Some(i256::MIN), 76, 0);
+        let one = ScalarValue::Decimal256(Some(i256::ONE), 76, 0);
+        for err in [
+            max.add(&one).unwrap_err(),
+            min.sub(&one).unwrap_err(),
+            ScalarValue::Decimal256(None, 76, 0).sub(&min).unwrap_err(),
+            min.arithmetic_negate().unwrap_err(),
+            // rescaling to the larger scale overflows
+            max.add(&ScalarValue::Decimal256(Some(i256::ONE), 76, 2))
+                .unwrap_err(),
+        ] {
+            assert!(err.to_string().contains("Overflow happened"), "{err}");
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_scalar_distance() {
         let cases = [
@@ -20,6 +20,7 @@
 use crate::{DataFusionError, Result, ScalarValue};
 use arrow::array::ArrayRef;
 use arrow::compute::SortOptions;
+use arrow::datatypes::{DataType, Field, Schema};
 use std::cmp::Ordering;
 
 /// Given column vectors, returns row at `idx`.
@@ -158,6 +159,66 @@ where
     Ok(low)
 }
 
+/// Prunes the struct columns of `schema` to the nested fields named by
+/// `paths`. Each path starts with the name of a column, followed by the names
+/// of the struct fields leading to the nested field, e.g.
+/// `["payload", "user", "id"]`. A column is pruned to the union of its paths,
+/// and columns without a path are kept whole.
+///
+/// A struct field is kept whole if none of its paths name one of its fields.
+pub fn project_nested_fields(schema: &Schema, paths: &[Vec<String>]) -> Schema {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let field_paths = paths
+                .iter()
+                .filter(|path| path.first() == Some(field.name()))
+                .map(|path| &path[1..])
+                .collect::<Vec<_>>();
+            if field_paths.is_empty() {
+                field.clone()
+            } else {
+                project_nested_field(field, &field_paths)
+            }
+        })
+        .collect();
+    Schema::new(fields).with_metadata(schema.metadata().clone())
+}
+
+/// Prunes `field` to the nested fields named by `paths`, relative to `field`
+fn project_nested_field(field: &Field, paths: &[&[String]]) -> Field {
+    let children = match field.data_type() {
+        // an empty path selects the whole field
+        DataType::Struct(children) if paths.iter().all(|path| !path.is_empty()) => {
+            children
+        }
+        _ => return field.clone(),
+    };
+
+    let children = children
+        .iter()
+        .filter_map(|child| {
+            let child_paths = paths
+                .iter()
+                .filter(|path| &path[0] == child.name())
+                .map(|path| &path[1..])
+                .collect::<Vec<_>>();
+            (!child_paths.is_empty()).then(|| project_nested_field(child, &child_paths))
+        })
+        .collect::<Vec<_>>();
+    if children.is_empty() {
+        return field.clone();
+    }
+
+    Field::new(
+        field.name(),
+        DataType::Struct(children),
+        field.is_nullable(),
+    )
+    .with_metadata(field.metadata().clone())
+}
+
 #[cfg(test)]
 mod tests {
     use arrow::array::Float64Array;
@@ -330,4 +391,49 @@ mod tests {
         assert_eq!(res, 2);
         Ok(())
     }
+
+    #[test]
+    fn test_project_nested_fields() {
+        let user = DataType::Struct(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let payload = DataType::Struct(vec![
+            Field::new("user", user, true),
+            Field::new("kind", DataType::Utf8, true),
+        ]);
+        let schema = Schema::new(vec![
+            Field::new("payload", payload.clone(), true),
+            Field::new("other", payload.clone(), true),
+            Field::new("ts", DataType::Int64, false),
+        ]);
+        let path = |p: &[&str]| p.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let projected = project_nested_fields(
+            &schema,
+            &[path(&["payload", "user", "id"]), path(&["ts", "x"])],
+        );
+        let expected = Schema::new(vec![
+            Field::new(
+                "payload",
+                DataType::Struct(vec![Field::new(
+                    "user",
+                    DataType::Struct(vec![Field::new("id", DataType::Int64, false)]),
+                    true,
+                )]),
+                true,
+            ),
+            Field::new("other", payload, true),
+            Field::new("ts", DataType::Int64, false),
+        ]);
+        assert_eq!(projected, expected);
+
+        // a path to the column, or to unknown fields, keeps the column whole
+        for paths in [
+            vec![path(&["payload", "user", "id"]), path(&["payload"])],
+            vec![path(&["payload", "unknown"])],
+        ] {
+            assert_eq!(project_nested_fields(&schema, &paths), schema);
+        }
+    }
 }
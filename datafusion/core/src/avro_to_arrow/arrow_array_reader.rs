@@ -34,6 +34,7 @@ use crate::arrow::datatypes::{
 use crate::arrow::error::ArrowError;
 use crate::arrow::record_batch::RecordBatch;
 use crate::arrow::util::bit_util;
+use crate::avro_to_arrow::schema::AVRO_DEFAULT_KEY;
 use crate::error::{DataFusionError, Result};
 use apache_avro::{
     schema::{Schema as AvroSchema, SchemaKind},
@@ -56,6 +57,8 @@ pub struct AvroArrowArrayReader<'a, R: Read> {
     schema: SchemaRef,
     projection: Option<Vec<String>>,
     schema_lookup: BTreeMap<String, usize>,
+    /// The default values of the fields of `schema` that the file does not have
+    defaults: BTreeMap<String, Value>,
 }
 
 impl<'a, R: Read> AvroArrowArrayReader<'a, R> {
@@ -67,14 +70,42 @@ impl<'a, R: Read> AvroArrowArrayReader<'a, R> {
         let reader = AvroReader::new(reader)?;
         let writer_schema = reader.writer_schema().clone();
         let schema_lookup = Self::schema_lookup(writer_schema)?;
+        let defaults = Self::defaults(&schema, &schema_lookup)?;
         Ok(Self {
             reader,
             schema,
             projection,
             schema_lookup,
+            defaults,
         })
     }
 
+    /// Returns the default values of the fields of `schema` that are not in
+    /// the writer schema of the file, as recorded by `to_arrow_schema` in
+    /// their metadata
+    fn defaults(
+        schema: &Schema,
+        schema_lookup: &BTreeMap<String, usize>,
+    ) -> Result<BTreeMap<String, Value>> {
+        let mut defaults = BTreeMap::new();
+        for field in schema.fields() {
+            if schema_lookup.contains_key(field.name()) {
+                continue;
+            }
+            if let Some(default) = field.metadata().get(AVRO_DEFAULT_KEY) {
+                let default: serde_json::Value =
+                    serde_json::from_str(default).map_err(|e| {
+                        DataFusionError::Execution(format!(
+                            "Invalid avro default value for field {}: {e}",
+                            field.name()
+                        ))
+                    })?;
+                defaults.insert(field.name().clone(), Value::from(default));
+            }
+        }
+        Ok(defaults)
+    }
+
     pub fn schema_lookup(schema: AvroSchema) -> Result<BTreeMap<String, usize>> {
         match schema {
             AvroSchema::Record {
@@ -738,17 +769,20 @@ impl<'a, R: Read> AvroArrowArrayReader<'a, R> {
                         let len = rows.len();
                         let num_bytes = bit_util::ceil(len, 8);
                         let mut null_buffer = MutableBuffer::from_len_zeroed(num_bytes);
+                        // null structs, and structs missing in the file, are
+                        // read from empty records
+                        let empty_record = vec![];
                         let struct_rows = rows
                             .iter()
                             .enumerate()
                             .map(|(i, row)| (i, self.field_lookup(field.name(), row)))
-                            .map(|(i, v)| {
-                                if let Some(Value::Record(value)) = v {
+                            .map(|(i, v)| match v.map(maybe_resolve_union) {
+                                Some(Value::Record(value)) => {
                                     bit_util::set_bit(null_buffer.as_slice_mut(), i);
                                     value
-                                } else {
-                                    panic!("expected struct got {v:?}");
                                 }
+                                Some(Value::Null) | None => &empty_record,
+                                Some(v) => panic!("expected struct got {v:?}"),
                             })
                             .collect::<Vec<&Vec<(String, Value)>>>();
                         let arrays =
@@ -804,14 +838,15 @@ impl<'a, R: Read> AvroArrowArrayReader<'a, R> {
     }
 
     fn field_lookup<'b>(
-        &self,
+        &'b self,
         name: &str,
         row: &'b [(String, Value)],
     ) -> Option<&'b Value> {
-        self.schema_lookup
-            .get(name)
-            .and_then(|i| row.get(*i))
-            .map(|o| &o.1)
+        match self.schema_lookup.get(name) {
+            Some(i) => row.get(*i).map(|o| &o.1),
+            // the field was added after the file was written
+            None => self.defaults.get(name),
+        }
     }
 }
 
@@ -23,12 +23,18 @@ mod arrow_array_reader;
 mod reader;
 #[cfg(feature = "avro")]
 mod schema;
+#[cfg(feature = "avro")]
+mod writer;
 
 use crate::arrow::datatypes::Schema;
 use crate::error::Result;
 #[cfg(feature = "avro")]
 pub use reader::{Reader, ReaderBuilder};
+#[cfg(feature = "avro")]
+pub use schema::AVRO_DEFAULT_KEY;
 use std::io::Read;
+#[cfg(feature = "avro")]
+pub use writer::{to_avro_schema, Writer};
 
 #[cfg(feature = "avro")]
 /// Read Avro schema given a reader
@@ -24,17 +24,26 @@ use arrow::datatypes::Field;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 
+/// The key of the field metadata holding the JSON encoded default value of
+/// the fields of avro records, used for the files that do not have them
+pub const AVRO_DEFAULT_KEY: &str = "avro::default";
+
 /// Converts an avro schema to an arrow schema
 pub fn to_arrow_schema(avro_schema: &apache_avro::Schema) -> Result<Schema> {
     let mut schema_fields = vec![];
     match avro_schema {
         AvroSchema::Record { fields, .. } => {
             for field in fields {
+                let mut props = external_props(&field.schema);
+                // a null default is the same as no default
+                if let Some(default) = field.default.as_ref().filter(|d| !d.is_null()) {
+                    props.insert(AVRO_DEFAULT_KEY.to_string(), default.to_string());
+                }
                 schema_fields.push(schema_to_field_with_props(
                     &field.schema,
                     Some(&field.name),
                     false,
-                    Some(external_props(&field.schema)),
+                    Some(props),
                 )?)
             }
         }
@@ -303,7 +312,7 @@ pub fn aliased(
 
 #[cfg(test)]
 mod test {
-    use super::{aliased, external_props, to_arrow_schema};
+    use super::{aliased, external_props, to_arrow_schema, AVRO_DEFAULT_KEY};
     use crate::arrow::datatypes::DataType::{Binary, Float32, Float64, Timestamp, Utf8};
     use crate::arrow::datatypes::TimeUnit::Microsecond;
     use crate::arrow::datatypes::{Field, Schema};
@@ -446,6 +455,32 @@ mod test {
         assert_eq!(arrow_schema.unwrap(), expected);
     }
 
+    #[test]
+    fn test_field_defaults() {
+        let schema = AvroSchema::parse_str(
+            r#"
+            {
+              "type" : "record",
+              "name" : "topLevelRecord",
+              "fields" : [ {
+                "name" : "id",
+                "type" : "long"
+              }, {
+                "name" : "source",
+                "type" : "string",
+                "default" : "kafka"
+              } ]
+            }"#,
+        )
+        .unwrap();
+        let arrow_schema = to_arrow_schema(&schema).unwrap();
+        assert!(arrow_schema.field(0).metadata().is_empty());
+        assert_eq!(
+            arrow_schema.field(1).metadata().get(AVRO_DEFAULT_KEY),
+            Some(&"\"kafka\"".to_string())
+        );
+    }
+
     #[test]
     fn test_non_record_schema() {
         let arrow_schema = to_arrow_schema(&AvroSchema::String);
@@ -0,0 +1,317 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Writing of arrow record batches to avro files
+
+use crate::arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use crate::arrow::record_batch::RecordBatch;
+use crate::error::{DataFusionError, Result};
+use crate::scalar::ScalarValue;
+use apache_avro::types::Value;
+use apache_avro::{Decimal, Schema as AvroSchema, Writer as AvroWriter};
+use serde_json::{json, Value as JsonValue};
+use std::io::Write;
+
+/// Converts an arrow schema to an avro record schema.
+///
+/// Nullable fields are unions of `null` and their type, with a `null`
+/// default. Timestamps with second and nanosecond precision are written
+/// with millisecond and microsecond precision, dictionaries as their values
+pub fn to_avro_schema(schema: &Schema) -> Result<AvroSchema> {
+    let record = record_schema("arrow_schema", schema.fields())?;
+    Ok(AvroSchema::parse(&record)?)
+}
+
+/// Converts `fields` to a record schema named `name`. Nested records and
+/// fixed types are named after the path of their field, e.g.
+/// `arrow_schema.a.b`, as names must be unique within an avro schema
+fn record_schema(name: &str, fields: &[Field]) -> Result<JsonValue> {
+    let record_name = name;
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let name = field.name();
+            let is_avro_name = name.chars().enumerate().all(|(i, c)| {
+                c == '_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+            });
+            if name.is_empty() || !is_avro_name {
+                return Err(DataFusionError::Execution(format!(
+                    "Can not write column '{name}' to avro, avro names must start with \
+                     a letter or '_' and only contain letters, digits and '_'"
+                )));
+            }
+            let schema = field_schema(field, &format!("{record_name}.{name}"))?;
+            Ok(if field.is_nullable() {
+                json!({"name": name, "type": schema, "default": null})
+            } else {
+                json!({"name": name, "type": schema})
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(json!({"type": "record", "name": record_name, "fields": fields}))
+}
+
+fn field_schema(field: &Field, name: &str) -> Result<JsonValue> {
+    let schema = type_schema(field.data_type(), name)?;
+    Ok(if field.is_nullable() {
+        json!(["null", schema])
+    } else {
+        schema
+    })
+}
+
+fn type_schema(data_type: &DataType, name: &str) -> Result<JsonValue> {
+    Ok(match data_type {
+        DataType::Null => json!("null"),
+        DataType::Boolean => json!("boolean"),
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::UInt8
+        | DataType::UInt16 => json!("int"),
+        DataType::Int64 | DataType::UInt32 | DataType::UInt64 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Utf8 | DataType::LargeUtf8 => json!("string"),
+        DataType::Binary | DataType::LargeBinary => json!("bytes"),
+        DataType::FixedSizeBinary(size) => {
+            json!({"type": "fixed", "name": name, "size": size})
+        }
+        DataType::Decimal128(precision, scale) => json!({
+            "type": "bytes",
+            "logicalType": "decimal",
+            "precision": precision,
+            "scale": scale
+        }),
+        DataType::Date32 => json!({"type": "int", "logicalType": "date"}),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            json!({"type": "int", "logicalType": "time-millis"})
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            json!({"type": "long", "logicalType": "time-micros"})
+        }
+        DataType::Timestamp(TimeUnit::Second | TimeUnit::Millisecond, _) => {
+            json!({"type": "long", "logicalType": "timestamp-millis"})
+        }
+        DataType::Timestamp(TimeUnit::Microsecond | TimeUnit::Nanosecond, _) => {
+            json!({"type": "long", "logicalType": "timestamp-micros"})
+        }
+        DataType::List(field) => {
+            json!({"type": "array", "items": field_schema(field, name)?})
+        }
+        DataType::Struct(fields) => record_schema(name, fields)?,
+        DataType::Dictionary(_, value_type) => type_schema(value_type, name)?,
+        other => {
+            return Err(DataFusionError::NotImplemented(format!(
+                "Writing {other:?} columns to avro is not supported"
+            )))
+        }
+    })
+}
+
+/// Converts a scalar value to an avro value of the type written by
+/// [`to_avro_schema`] for its data type
+fn to_avro_value(scalar: ScalarValue, nullable: bool) -> Result<Value> {
+    if scalar.is_null() {
+        return Ok(if nullable {
+            Value::Union(0, Box::new(Value::Null))
+        } else {
+            Value::Null
+        });
+    }
+
+    let value = match scalar {
+        ScalarValue::Boolean(Some(v)) => Value::Boolean(v),
+        ScalarValue::Int8(Some(v)) => Value::Int(v.into()),
+        ScalarValue::Int16(Some(v)) => Value::Int(v.into()),
+        ScalarValue::Int32(Some(v)) => Value::Int(v),
+        ScalarValue::UInt8(Some(v)) => Value::Int(v.into()),
+        ScalarValue::UInt16(Some(v)) => Value::Int(v.into()),
+        ScalarValue::Int64(Some(v)) => Value::Long(v),
+        ScalarValue::UInt32(Some(v)) => Value::Long(v.into()),
+        ScalarValue::UInt64(Some(v)) => Value::Long(i64::try_from(v).map_err(|_| {
+            DataFusionError::Execution(format!(
+                "Can not write UInt64 value {v} to avro, which has no unsigned longs"
+            ))
+        })?),
+        ScalarValue::Float32(Some(v)) => Value::Float(v),
+        ScalarValue::Float64(Some(v)) => Value::Double(v),
+        ScalarValue::Utf8(Some(v)) | ScalarValue::LargeUtf8(Some(v)) => Value::String(v),
+        ScalarValue::Binary(Some(v)) | ScalarValue::LargeBinary(Some(v)) => {
+            Value::Bytes(v)
+        }
+        ScalarValue::FixedSizeBinary(size, Some(v)) => Value::Fixed(size as usize, v),
+        ScalarValue::Decimal128(Some(v), _, _) => {
+            Value::Decimal(Decimal::from(v.to_be_bytes().to_vec()))
+        }
+        ScalarValue::Date32(Some(v)) => Value::Date(v),
+        ScalarValue::Time32Millisecond(Some(v)) => Value::TimeMillis(v),
+        ScalarValue::Time64Microsecond(Some(v)) => Value::TimeMicros(v),
+        ScalarValue::TimestampSecond(Some(v), _) => {
+            Value::TimestampMillis(v.checked_mul(1000).ok_or_else(|| {
+                DataFusionError::Execution(format!(
+                    "Can not write timestamp {v}s to avro, it overflows as milliseconds"
+                ))
+            })?)
+        }
+        ScalarValue::TimestampMillisecond(Some(v), _) => Value::TimestampMillis(v),
+        ScalarValue::TimestampMicrosecond(Some(v), _) => Value::TimestampMicros(v),
+        ScalarValue::TimestampNanosecond(Some(v), _) => {
+            Value::TimestampMicros(v.div_euclid(1000))
+        }
+        ScalarValue::List(Some(values), field) => Value::Array(
+            values
+                .into_iter()
+                .map(|v| to_avro_value(v, field.is_nullable()))
+                .collect::<Result<_>>()?,
+        ),
+        ScalarValue::Struct(Some(values), fields) => Value::Record(
+            values
+                .into_iter()
+                .zip(fields.iter())
+                .map(|(v, field)| {
+                    Ok((field.name().clone(), to_avro_value(v, field.is_nullable())?))
+                })
+                .collect::<Result<_>>()?,
+        ),
+        ScalarValue::Dictionary(_, value) => to_avro_value(*value, false)?,
+        other => {
+            return Err(DataFusionError::NotImplemented(format!(
+                "Writing {:?} values to avro is not supported",
+                other.get_datatype()
+            )))
+        }
+    };
+    Ok(if nullable {
+        Value::Union(1, Box::new(value))
+    } else {
+        value
+    })
+}
+
+/// Writes arrow record batches to an avro file, with a schema converted
+/// by [`to_avro_schema`]
+pub struct Writer<'a, W: Write> {
+    writer: AvroWriter<'a, W>,
+}
+
+impl<'a, W: Write> Writer<'a, W> {
+    /// Create a new writer of the avro `schema` to `writer`
+    pub fn new(schema: &'a AvroSchema, writer: W) -> Self {
+        Self {
+            writer: AvroWriter::new(schema, writer),
+        }
+    }
+
+    /// Write the rows of `batch`
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        let schema = batch.schema();
+        for row in 0..batch.num_rows() {
+            let fields = batch
+                .columns()
+                .iter()
+                .zip(schema.fields())
+                .map(|(column, field)| {
+                    let scalar = ScalarValue::try_from_array(column, row)?;
+                    let value = to_avro_value(scalar, field.is_nullable())?;
+                    Ok((field.name().clone(), value))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            self.writer.append(Value::Record(fields))?;
+        }
+        Ok(())
+    }
+
+    /// Flush the written rows and return the underlying writer
+    pub fn finish(self) -> Result<W> {
+        Ok(self.writer.into_inner()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::array::{Int32Array, StringArray};
+    use crate::avro_to_arrow::{read_avro_schema_from_reader, Reader};
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    #[test]
+    fn write_and_read() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+            ],
+        )?;
+
+        let avro_schema = to_avro_schema(&schema)?;
+        let mut writer = Writer::new(&avro_schema, vec![]);
+        writer.write(&batch)?;
+        let data = writer.finish()?;
+
+        assert_eq!(
+            read_avro_schema_from_reader(&mut Cursor::new(&data))?,
+            schema.as_ref().clone()
+        );
+        let mut reader = Reader::try_new(Cursor::new(&data), schema, 1024, None)?;
+        assert_eq!(reader.next()?.unwrap(), batch);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_name() {
+        let schema = Schema::new(vec![Field::new("COUNT(*)", DataType::Int64, false)]);
+        let err = to_avro_schema(&schema).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Can not write column 'COUNT(*)' to avro"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn nested_record_names() -> Result<()> {
+        let inner = DataType::Struct(vec![Field::new("x", DataType::Int32, true)]);
+        let outer = DataType::Struct(vec![Field::new("inner", inner, true)]);
+        let schema = Schema::new(vec![
+            Field::new("a", outer.clone(), true),
+            Field::new("b", outer, true),
+        ]);
+        // the nested records of both columns are in a field named `inner`
+        let canonical_form = to_avro_schema(&schema)?.canonical_form();
+        for name in ["arrow_schema.a.inner", "arrow_schema.b.inner"] {
+            assert!(canonical_form.contains(name), "{canonical_form}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_overflow() {
+        let value = ScalarValue::TimestampSecond(Some(i64::MAX), None);
+        let err = to_avro_value(value, false).unwrap_err();
+        assert!(
+            err.to_string().contains("overflows as milliseconds"),
+            "{err}"
+        );
+    }
+}
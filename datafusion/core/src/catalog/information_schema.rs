@@ -23,7 +23,7 @@ use async_trait::async_trait;
 use std::{any::Any, sync::Arc};
 
 use arrow::{
-    array::{StringBuilder, UInt64Builder},
+    array::{new_null_array, StringBuilder, UInt64Builder},
     datatypes::{DataType, Field, Schema, SchemaRef},
     record_batch::RecordBatch,
 };
@@ -31,10 +31,15 @@ use arrow::{
 use crate::config::{ConfigEntry, ConfigOptions};
 use crate::datasource::streaming::{PartitionStream, StreamingTable};
 use crate::datasource::TableProvider;
+use crate::error::Result;
 use crate::execution::context::TaskContext;
-use crate::logical_expr::TableType;
+use crate::logical_expr::{
+    aggregate_function, function, window_function, AggregateFunction, AggregateUDF,
+    BuiltInWindowFunction, BuiltinScalarFunction, ScalarUDF, Signature, SqlFunction,
+    TableType, TypeSignature, Volatility, WindowFunction,
+};
 use crate::physical_plan::stream::RecordBatchStreamAdapter;
-use crate::physical_plan::SendableRecordBatchStream;
+use crate::physical_plan::{SendableRecordBatchStream, Statistics};
 
 use super::{catalog::CatalogList, schema::SchemaProvider};
 
@@ -43,9 +48,24 @@ pub const TABLES: &str = "tables";
 pub const VIEWS: &str = "views";
 pub const COLUMNS: &str = "columns";
 pub const DF_SETTINGS: &str = "df_settings";
+pub const ROUTINES: &str = "routines";
+pub const SCHEMATA: &str = "schemata";
+pub const PARAMETERS: &str = "parameters";
+pub const KEY_COLUMN_USAGE: &str = "key_column_usage";
+pub const TABLE_STATISTICS: &str = "table_statistics";
 
 /// All information schema tables
-pub const INFORMATION_SCHEMA_TABLES: &[&str] = &[TABLES, VIEWS, COLUMNS, DF_SETTINGS];
+pub const INFORMATION_SCHEMA_TABLES: &[&str] = &[
+    TABLES,
+    VIEWS,
+    COLUMNS,
+    DF_SETTINGS,
+    ROUTINES,
+    SCHEMATA,
+    PARAMETERS,
+    KEY_COLUMN_USAGE,
+    TABLE_STATISTICS,
+];
 
 /// Implements the `information_schema` virtual schema and tables
 ///
@@ -61,14 +81,136 @@ impl InformationSchemaProvider {
     /// Creates a new [`InformationSchemaProvider`] for the provided `catalog_list`
     pub fn new(catalog_list: Arc<dyn CatalogList>) -> Self {
         Self {
-            config: InformationSchemaConfig { catalog_list },
+            config: InformationSchemaConfig {
+                catalog_list,
+                scalar_functions: vec![],
+                aggregate_functions: vec![],
+                sql_functions: vec![],
+            },
         }
     }
+
+    /// Sets the user defined functions listed in `information_schema.routines`
+    /// next to the built-in functions
+    pub fn with_functions(
+        mut self,
+        scalar_functions: Vec<Arc<ScalarUDF>>,
+        aggregate_functions: Vec<Arc<AggregateUDF>>,
+        sql_functions: Vec<Arc<SqlFunction>>,
+    ) -> Self {
+        self.config.scalar_functions = scalar_functions;
+        self.config.aggregate_functions = aggregate_functions;
+        self.config.sql_functions = sql_functions;
+        self
+    }
 }
 
 #[derive(Clone)]
 struct InformationSchemaConfig {
     catalog_list: Arc<dyn CatalogList>,
+    scalar_functions: Vec<Arc<ScalarUDF>>,
+    aggregate_functions: Vec<Arc<AggregateUDF>>,
+    sql_functions: Vec<Arc<SqlFunction>>,
+}
+
+/// A function listed in `information_schema.routines`, with a row for each
+/// of its signatures
+struct Routine {
+    name: String,
+    /// SCALAR, AGGREGATE or WINDOW
+    function_type: &'static str,
+    /// SQL for the functions defined in SQL, else EXTERNAL
+    body: &'static str,
+    definition: Option<String>,
+    /// The volatility, unknown for the functions defined in SQL
+    volatility: Option<Volatility>,
+    is_user_defined: bool,
+    signatures: Vec<RoutineSignature>,
+}
+
+/// A signature of a [`Routine`], whose parameters are listed in
+/// `information_schema.parameters`
+struct RoutineSignature {
+    /// The name identifying the signature among those of all the routines
+    specific_name: String,
+    /// The names, if known, and the types of the parameters, `None`
+    /// standing for any type
+    parameters: Vec<(Option<String>, Option<DataType>)>,
+    /// Whether the last parameter takes any number of arguments
+    is_variadic: bool,
+    return_type: Option<DataType>,
+}
+
+impl Routine {
+    /// Creates a routine implemented in Rust, with a signature for each of the
+    /// parameter types accepted by `signature`
+    fn external(
+        name: impl Into<String>,
+        function_type: &'static str,
+        signature: &Signature,
+        is_user_defined: bool,
+        return_type: impl Fn(&[DataType]) -> Result<DataType>,
+    ) -> Self {
+        let signatures = expand_type_signature(&signature.type_signature)
+            .into_iter()
+            .map(|(types, is_variadic)| {
+                // the return type depends on the types of the arguments,
+                // unknown when the parameters accept any type
+                let return_type = types
+                    .iter()
+                    .cloned()
+                    .collect::<Option<Vec<_>>>()
+                    .and_then(|types| return_type(&types).ok());
+                RoutineSignature {
+                    specific_name: String::new(),
+                    parameters: types.into_iter().map(|t| (None, t)).collect(),
+                    is_variadic,
+                    return_type,
+                }
+            })
+            .collect();
+        Self {
+            name: name.into(),
+            function_type,
+            body: "EXTERNAL",
+            definition: None,
+            volatility: Some(signature.volatility),
+            is_user_defined,
+            signatures,
+        }
+    }
+}
+
+/// Expands `signature` into the types of the parameters of each of the
+/// signatures it accepts, `None` standing for any type, and whether the last
+/// parameter is variadic
+fn expand_type_signature(
+    signature: &TypeSignature,
+) -> Vec<(Vec<Option<DataType>>, bool)> {
+    match signature {
+        TypeSignature::Variadic(types) => types
+            .iter()
+            .map(|t| (vec![Some(t.clone())], true))
+            .collect(),
+        TypeSignature::VariadicEqual => vec![(vec![None], true)],
+        TypeSignature::Uniform(n, types) => types
+            .iter()
+            .map(|t| (vec![Some(t.clone()); *n], false))
+            .collect(),
+        TypeSignature::Exact(types) => {
+            vec![(types.iter().cloned().map(Some).collect(), false)]
+        }
+        TypeSignature::Any(n) => vec![(vec![None; *n], false)],
+        TypeSignature::OneOf(signatures) => {
+            signatures.iter().flat_map(expand_type_signature).collect()
+        }
+    }
+}
+
+/// Formats the type of a parameter or return value
+fn format_data_type(data_type: Option<&DataType>) -> String {
+    // "System supplied type" --> Use debug format of the datatype
+    data_type.map_or_else(|| "ANY".to_string(), |t| format!("{t:?}"))
 }
 
 impl InformationSchemaConfig {
@@ -95,20 +237,14 @@ impl InformationSchemaConfig {
             }
 
             // Add a final list for the information schema tables themselves
-            builder.add_table(&catalog_name, INFORMATION_SCHEMA, TABLES, TableType::View);
-            builder.add_table(&catalog_name, INFORMATION_SCHEMA, VIEWS, TableType::View);
-            builder.add_table(
-                &catalog_name,
-                INFORMATION_SCHEMA,
-                COLUMNS,
-                TableType::View,
-            );
-            builder.add_table(
-                &catalog_name,
-                INFORMATION_SCHEMA,
-                DF_SETTINGS,
-                TableType::View,
-            );
+            for table_name in INFORMATION_SCHEMA_TABLES {
+                builder.add_table(
+                    &catalog_name,
+                    INFORMATION_SCHEMA,
+                    table_name,
+                    TableType::View,
+                );
+            }
         }
     }
 
@@ -160,6 +296,153 @@ impl InformationSchemaConfig {
         }
     }
 
+    /// Returns the built-in and user defined functions, sorted by name
+    fn routines(&self) -> Vec<Routine> {
+        let mut routines: Vec<_> = self
+            .sql_functions
+            .iter()
+            .map(|f| Routine {
+                name: f.name.clone(),
+                function_type: "SCALAR",
+                body: "SQL",
+                definition: Some(f.definition.clone()),
+                volatility: None,
+                is_user_defined: true,
+                signatures: vec![RoutineSignature {
+                    specific_name: String::new(),
+                    parameters: f
+                        .args
+                        .iter()
+                        .map(|(name, data_type)| {
+                            (Some(name.clone()), Some(data_type.clone()))
+                        })
+                        .collect(),
+                    is_variadic: false,
+                    return_type: f.return_type.clone(),
+                }],
+            })
+            .collect();
+        routines.extend(self.scalar_functions.iter().map(|f| {
+            Routine::external(&f.name, "SCALAR", &f.signature, true, |types| {
+                Ok((f.return_type)(types)?.as_ref().clone())
+            })
+        }));
+        routines.extend(self.aggregate_functions.iter().map(|f| {
+            Routine::external(&f.name, "AGGREGATE", &f.signature, true, |types| {
+                Ok((f.return_type)(types)?.as_ref().clone())
+            })
+        }));
+        routines.extend(BuiltinScalarFunction::by_name().iter().map(|(name, f)| {
+            Routine::external(*name, "SCALAR", &function::signature(f), false, |types| {
+                function::return_type(f, types)
+            })
+        }));
+        routines.extend(AggregateFunction::by_name().iter().map(|(name, f)| {
+            Routine::external(
+                *name,
+                "AGGREGATE",
+                &aggregate_function::signature(f),
+                false,
+                |types| aggregate_function::return_type(f, types),
+            )
+        }));
+        routines.extend(BuiltInWindowFunction::by_name().iter().map(|(name, f)| {
+            let fun = WindowFunction::BuiltInWindowFunction(f.clone());
+            Routine::external(
+                *name,
+                "WINDOW",
+                &window_function::signature(&fun),
+                false,
+                |types| window_function::return_type(&fun, types),
+            )
+        }));
+        routines.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // number the signatures of the routines sharing a name
+        let mut previous_name = None;
+        let mut ordinal = 0;
+        for routine in &mut routines {
+            if previous_name.as_ref() != Some(&routine.name) {
+                previous_name = Some(routine.name.clone());
+                ordinal = 0;
+            }
+            for signature in &mut routine.signatures {
+                ordinal += 1;
+                signature.specific_name = format!("{}_{ordinal}", routine.name);
+            }
+        }
+        routines
+    }
+
+    /// Construct the `information_schema.routines` virtual table
+    fn make_routines(&self, builder: &mut InformationSchemaRoutinesBuilder) {
+        for routine in self.routines() {
+            for signature in &routine.signatures {
+                builder.add_routine(&routine, signature);
+            }
+        }
+    }
+
+    /// Construct the `information_schema.parameters` virtual table
+    fn make_parameters(&self, builder: &mut InformationSchemaParametersBuilder) {
+        for routine in self.routines() {
+            for signature in &routine.signatures {
+                let count = signature.parameters.len();
+                for (i, (name, data_type)) in signature.parameters.iter().enumerate() {
+                    builder.add_parameter(
+                        &signature.specific_name,
+                        i + 1,
+                        name.as_ref(),
+                        data_type.as_ref(),
+                        signature.is_variadic && i + 1 == count,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Construct the `information_schema.schemata` virtual table
+    fn make_schemata(&self, builder: &mut InformationSchemaSchemataBuilder) {
+        for catalog_name in self.catalog_list.catalog_names() {
+            let catalog = self.catalog_list.catalog(&catalog_name).unwrap();
+
+            for schema_name in catalog.schema_names() {
+                if schema_name != INFORMATION_SCHEMA {
+                    builder.add_schema(&catalog_name, &schema_name);
+                }
+            }
+            builder.add_schema(&catalog_name, INFORMATION_SCHEMA);
+        }
+    }
+
+    /// Construct the `information_schema.table_statistics` virtual table.
+    ///
+    /// Listing tables have no statistics until their files are listed by a
+    /// scan, so their statistics are null
+    async fn make_table_statistics(
+        &self,
+        builder: &mut InformationSchemaTableStatisticsBuilder,
+    ) {
+        for catalog_name in self.catalog_list.catalog_names() {
+            let catalog = self.catalog_list.catalog(&catalog_name).unwrap();
+
+            for schema_name in catalog.schema_names() {
+                if schema_name != INFORMATION_SCHEMA {
+                    let schema = catalog.schema(&schema_name).unwrap();
+                    for table_name in schema.table_names() {
+                        let table = schema.table(&table_name).await.unwrap();
+                        builder.add_table_statistics(
+                            &catalog_name,
+                            &schema_name,
+                            &table_name,
+                            table.statistics(),
+                        )
+                    }
+                }
+            }
+        }
+    }
+
     /// Construct the `information_schema.df_settings` virtual table
     fn make_df_settings(
         &self,
@@ -179,26 +462,25 @@ impl SchemaProvider for InformationSchemaProvider {
     }
 
     fn table_names(&self) -> Vec<String> {
-        vec![
-            TABLES.to_string(),
-            VIEWS.to_string(),
-            COLUMNS.to_string(),
-            DF_SETTINGS.to_string(),
-        ]
+        INFORMATION_SCHEMA_TABLES
+            .iter()
+            .map(|t| t.to_string())
+            .collect()
     }
 
     async fn table(&self, name: &str) -> Option<Arc<dyn TableProvider>> {
         let config = self.config.clone();
-        let table: Arc<dyn PartitionStream> = if name.eq_ignore_ascii_case("tables") {
-            Arc::new(InformationSchemaTables::new(config))
-        } else if name.eq_ignore_ascii_case("columns") {
-            Arc::new(InformationSchemaColumns::new(config))
-        } else if name.eq_ignore_ascii_case("views") {
-            Arc::new(InformationSchemaViews::new(config))
-        } else if name.eq_ignore_ascii_case("df_settings") {
-            Arc::new(InformationSchemaDfSettings::new(config))
-        } else {
-            return None;
+        let table: Arc<dyn PartitionStream> = match name.to_ascii_lowercase().as_str() {
+            TABLES => Arc::new(InformationSchemaTables::new(config)),
+            COLUMNS => Arc::new(InformationSchemaColumns::new(config)),
+            VIEWS => Arc::new(InformationSchemaViews::new(config)),
+            DF_SETTINGS => Arc::new(InformationSchemaDfSettings::new(config)),
+            ROUTINES => Arc::new(InformationSchemaRoutines::new(config)),
+            SCHEMATA => Arc::new(InformationSchemaSchemata::new(config)),
+            PARAMETERS => Arc::new(InformationSchemaParameters::new(config)),
+            KEY_COLUMN_USAGE => Arc::new(InformationSchemaKeyColumnUsage::new()),
+            TABLE_STATISTICS => Arc::new(InformationSchemaTableStatistics::new(config)),
+            _ => return None,
         };
 
         Some(Arc::new(
@@ -207,7 +489,7 @@ impl SchemaProvider for InformationSchemaProvider {
     }
 
     fn table_exist(&self, name: &str) -> bool {
-        matches!(name.to_ascii_lowercase().as_str(), TABLES | VIEWS | COLUMNS)
+        INFORMATION_SCHEMA_TABLES.contains(&name.to_ascii_lowercase().as_str())
     }
 }
 
@@ -677,3 +959,457 @@ impl InformationSchemaDfSettingsBuilder {
         .unwrap()
     }
 }
+
+struct InformationSchemaRoutines {
+    schema: SchemaRef,
+    config: InformationSchemaConfig,
+}
+
+impl InformationSchemaRoutines {
+    fn new(config: InformationSchemaConfig) -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("specific_name", DataType::Utf8, false),
+            Field::new("routine_name", DataType::Utf8, false),
+            Field::new("routine_type", DataType::Utf8, false),
+            Field::new("data_type", DataType::Utf8, true),
+            Field::new("routine_body", DataType::Utf8, false),
+            Field::new("routine_definition", DataType::Utf8, true),
+            Field::new("is_deterministic", DataType::Utf8, true),
+            Field::new("function_type", DataType::Utf8, false),
+            Field::new("is_user_defined", DataType::Utf8, false),
+        ]));
+
+        Self { schema, config }
+    }
+
+    fn builder(&self) -> InformationSchemaRoutinesBuilder {
+        InformationSchemaRoutinesBuilder {
+            specific_names: StringBuilder::new(),
+            routine_names: StringBuilder::new(),
+            routine_types: StringBuilder::new(),
+            data_types: StringBuilder::new(),
+            routine_bodies: StringBuilder::new(),
+            routine_definitions: StringBuilder::new(),
+            is_deterministics: StringBuilder::new(),
+            function_types: StringBuilder::new(),
+            is_user_defineds: StringBuilder::new(),
+            schema: self.schema.clone(),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaRoutines {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = self.builder();
+        let config = self.config.clone();
+        Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            // TODO: Stream this
+            futures::stream::once(async move {
+                config.make_routines(&mut builder);
+                Ok(builder.finish())
+            }),
+        ))
+    }
+}
+
+/// Builds the `information_schema.ROUTINES` table row by row, with a row for
+/// each signature of the functions
+///
+/// Columns are based on <https://www.postgresql.org/docs/current/infoschema-routines.html>
+struct InformationSchemaRoutinesBuilder {
+    schema: SchemaRef,
+    specific_names: StringBuilder,
+    routine_names: StringBuilder,
+    routine_types: StringBuilder,
+    data_types: StringBuilder,
+    routine_bodies: StringBuilder,
+    routine_definitions: StringBuilder,
+    is_deterministics: StringBuilder,
+    function_types: StringBuilder,
+    is_user_defineds: StringBuilder,
+}
+
+impl InformationSchemaRoutinesBuilder {
+    fn add_routine(&mut self, routine: &Routine, signature: &RoutineSignature) {
+        // Note: append_value is actually infallable.
+        self.specific_names.append_value(&signature.specific_name);
+        self.routine_names.append_value(&routine.name);
+        self.routine_types.append_value("FUNCTION");
+        self.data_types.append_option(
+            signature
+                .return_type
+                .as_ref()
+                .map(|t| format_data_type(Some(t))),
+        );
+        // "If the routine is an SQL function, then SQL, else EXTERNAL."
+        self.routine_bodies.append_value(routine.body);
+        self.routine_definitions
+            .append_option(routine.definition.as_ref());
+        // "If the function is declared immutable (called deterministic in
+        // the SQL standard), then YES, else NO."
+        self.is_deterministics
+            .append_option(routine.volatility.map(|volatility| match volatility {
+                Volatility::Immutable => "YES",
+                Volatility::Stable | Volatility::Volatile => "NO",
+            }));
+        self.function_types.append_value(routine.function_type);
+        self.is_user_defineds
+            .append_value(if routine.is_user_defined { "YES" } else { "NO" });
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        RecordBatch::try_new(
+            self.schema.clone(),
+            vec![
+                Arc::new(self.specific_names.finish()),
+                Arc::new(self.routine_names.finish()),
+                Arc::new(self.routine_types.finish()),
+                Arc::new(self.data_types.finish()),
+                Arc::new(self.routine_bodies.finish()),
+                Arc::new(self.routine_definitions.finish()),
+                Arc::new(self.is_deterministics.finish()),
+                Arc::new(self.function_types.finish()),
+                Arc::new(self.is_user_defineds.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+struct InformationSchemaParameters {
+    schema: SchemaRef,
+    config: InformationSchemaConfig,
+}
+
+impl InformationSchemaParameters {
+    fn new(config: InformationSchemaConfig) -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("specific_name", DataType::Utf8, false),
+            Field::new("ordinal_position", DataType::UInt64, false),
+            Field::new("parameter_mode", DataType::Utf8, false),
+            Field::new("parameter_name", DataType::Utf8, true),
+            Field::new("data_type", DataType::Utf8, false),
+            Field::new("is_variadic", DataType::Utf8, false),
+        ]));
+
+        Self { schema, config }
+    }
+
+    fn builder(&self) -> InformationSchemaParametersBuilder {
+        InformationSchemaParametersBuilder {
+            specific_names: StringBuilder::new(),
+            ordinal_positions: UInt64Builder::new(),
+            parameter_modes: StringBuilder::new(),
+            parameter_names: StringBuilder::new(),
+            data_types: StringBuilder::new(),
+            is_variadics: StringBuilder::new(),
+            schema: self.schema.clone(),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaParameters {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = self.builder();
+        let config = self.config.clone();
+        Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            // TODO: Stream this
+            futures::stream::once(async move {
+                config.make_parameters(&mut builder);
+                Ok(builder.finish())
+            }),
+        ))
+    }
+}
+
+/// Builds the `information_schema.PARAMETERS` table row by row
+///
+/// Columns are based on <https://www.postgresql.org/docs/current/infoschema-parameters.html>
+struct InformationSchemaParametersBuilder {
+    schema: SchemaRef,
+    specific_names: StringBuilder,
+    ordinal_positions: UInt64Builder,
+    parameter_modes: StringBuilder,
+    parameter_names: StringBuilder,
+    data_types: StringBuilder,
+    is_variadics: StringBuilder,
+}
+
+impl InformationSchemaParametersBuilder {
+    fn add_parameter(
+        &mut self,
+        specific_name: impl AsRef<str>,
+        ordinal_position: usize,
+        parameter_name: Option<impl AsRef<str>>,
+        data_type: Option<&DataType>,
+        is_variadic: bool,
+    ) {
+        // Note: append_value is actually infallable.
+        self.specific_names.append_value(specific_name.as_ref());
+        // "Ordinal position of the parameter in the argument list of the
+        // function (count starts at 1)"
+        self.ordinal_positions.append_value(ordinal_position as u64);
+        // DataFusion functions only have input parameters
+        self.parameter_modes.append_value("IN");
+        self.parameter_names.append_option(parameter_name);
+        self.data_types.append_value(format_data_type(data_type));
+        self.is_variadics
+            .append_value(if is_variadic { "YES" } else { "NO" });
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        RecordBatch::try_new(
+            self.schema.clone(),
+            vec![
+                Arc::new(self.specific_names.finish()),
+                Arc::new(self.ordinal_positions.finish()),
+                Arc::new(self.parameter_modes.finish()),
+                Arc::new(self.parameter_names.finish()),
+                Arc::new(self.data_types.finish()),
+                Arc::new(self.is_variadics.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+struct InformationSchemaSchemata {
+    schema: SchemaRef,
+    config: InformationSchemaConfig,
+}
+
+impl InformationSchemaSchemata {
+    fn new(config: InformationSchemaConfig) -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("schema_name", DataType::Utf8, false),
+            Field::new("schema_owner", DataType::Utf8, true),
+            Field::new("default_character_set_catalog", DataType::Utf8, true),
+            Field::new("default_character_set_schema", DataType::Utf8, true),
+            Field::new("default_character_set_name", DataType::Utf8, true),
+            Field::new("sql_path", DataType::Utf8, true),
+        ]));
+
+        Self { schema, config }
+    }
+
+    fn builder(&self) -> InformationSchemaSchemataBuilder {
+        InformationSchemaSchemataBuilder {
+            catalog_names: StringBuilder::new(),
+            schema_names: StringBuilder::new(),
+            schema: self.schema.clone(),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaSchemata {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = self.builder();
+        let config = self.config.clone();
+        Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            // TODO: Stream this
+            futures::stream::once(async move {
+                config.make_schemata(&mut builder);
+                Ok(builder.finish())
+            }),
+        ))
+    }
+}
+
+/// Builds the `information_schema.SCHEMATA` table row by row
+///
+/// Columns are based on <https://www.postgresql.org/docs/current/infoschema-schemata.html>
+struct InformationSchemaSchemataBuilder {
+    schema: SchemaRef,
+    catalog_names: StringBuilder,
+    schema_names: StringBuilder,
+}
+
+impl InformationSchemaSchemataBuilder {
+    fn add_schema(
+        &mut self,
+        catalog_name: impl AsRef<str>,
+        schema_name: impl AsRef<str>,
+    ) {
+        // Note: append_value is actually infallable.
+        self.catalog_names.append_value(catalog_name.as_ref());
+        self.schema_names.append_value(schema_name.as_ref());
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        let catalog_names = self.catalog_names.finish();
+        let num_rows = catalog_names.len();
+        // DataFusion has no schema owners, character sets or SQL paths,
+        // so the remaining columns are null
+        let nulls = || Arc::new(new_null_array(&DataType::Utf8, num_rows));
+        RecordBatch::try_new(
+            self.schema.clone(),
+            vec![
+                Arc::new(catalog_names),
+                Arc::new(self.schema_names.finish()),
+                nulls(),
+                nulls(),
+                nulls(),
+                nulls(),
+                nulls(),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+/// The `information_schema.key_column_usage` table, which is empty as
+/// DataFusion tables have no key constraints
+struct InformationSchemaKeyColumnUsage {
+    schema: SchemaRef,
+}
+
+impl InformationSchemaKeyColumnUsage {
+    fn new() -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("constraint_catalog", DataType::Utf8, false),
+            Field::new("constraint_schema", DataType::Utf8, false),
+            Field::new("constraint_name", DataType::Utf8, false),
+            Field::new("table_catalog", DataType::Utf8, false),
+            Field::new("table_schema", DataType::Utf8, false),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("ordinal_position", DataType::UInt64, false),
+        ]));
+
+        Self { schema }
+    }
+}
+
+impl PartitionStream for InformationSchemaKeyColumnUsage {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let batch = RecordBatch::new_empty(self.schema.clone());
+        Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            futures::stream::once(async move { Ok(batch) }),
+        ))
+    }
+}
+
+struct InformationSchemaTableStatistics {
+    schema: SchemaRef,
+    config: InformationSchemaConfig,
+}
+
+impl InformationSchemaTableStatistics {
+    fn new(config: InformationSchemaConfig) -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("table_catalog", DataType::Utf8, false),
+            Field::new("table_schema", DataType::Utf8, false),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("num_rows", DataType::UInt64, true),
+            Field::new("total_byte_size", DataType::UInt64, true),
+            Field::new("is_exact", DataType::Utf8, true),
+        ]));
+
+        Self { schema, config }
+    }
+
+    fn builder(&self) -> InformationSchemaTableStatisticsBuilder {
+        InformationSchemaTableStatisticsBuilder {
+            catalog_names: StringBuilder::new(),
+            schema_names: StringBuilder::new(),
+            table_names: StringBuilder::new(),
+            num_rows: UInt64Builder::new(),
+            total_byte_sizes: UInt64Builder::new(),
+            is_exacts: StringBuilder::new(),
+            schema: self.schema.clone(),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaTableStatistics {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = self.builder();
+        let config = self.config.clone();
+        Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            // TODO: Stream this
+            futures::stream::once(async move {
+                config.make_table_statistics(&mut builder).await;
+                builder.finish()
+            }),
+        ))
+    }
+}
+
+/// Builds the DataFusion specific `information_schema.TABLE_STATISTICS` table
+/// row by row, from the statistics of the table providers
+struct InformationSchemaTableStatisticsBuilder {
+    schema: SchemaRef,
+    catalog_names: StringBuilder,
+    schema_names: StringBuilder,
+    table_names: StringBuilder,
+    num_rows: UInt64Builder,
+    total_byte_sizes: UInt64Builder,
+    is_exacts: StringBuilder,
+}
+
+impl InformationSchemaTableStatisticsBuilder {
+    fn add_table_statistics(
+        &mut self,
+        catalog_name: impl AsRef<str>,
+        schema_name: impl AsRef<str>,
+        table_name: impl AsRef<str>,
+        statistics: Option<Statistics>,
+    ) {
+        // Note: append_value is actually infallable.
+        self.catalog_names.append_value(catalog_name.as_ref());
+        self.schema_names.append_value(schema_name.as_ref());
+        self.table_names.append_value(table_name.as_ref());
+        let statistics = statistics.as_ref();
+        self.num_rows
+            .append_option(statistics.and_then(|s| s.num_rows).map(|n| n as u64));
+        self.total_byte_sizes
+            .append_option(statistics.and_then(|s| s.total_byte_size).map(|n| n as u64));
+        self.is_exacts.append_option(statistics.map(|s| {
+            if s.is_exact {
+                "YES"
+            } else {
+                "NO"
+            }
+        }));
+    }
+
+    fn finish(&mut self) -> Result<RecordBatch> {
+        Ok(RecordBatch::try_new(
+            self.schema.clone(),
+            vec![
+                Arc::new(self.catalog_names.finish()),
+                Arc::new(self.schema_names.finish()),
+                Arc::new(self.table_names.finish()),
+                Arc::new(self.num_rows.finish()),
+                Arc::new(self.total_byte_sizes.finish()),
+                Arc::new(self.is_exacts.finish()),
+            ],
+        )?)
+    }
+}
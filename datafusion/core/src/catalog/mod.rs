@@ -22,6 +22,8 @@
 pub mod catalog;
 pub(crate) mod information_schema;
 pub mod listing_schema;
+pub mod persistent;
+pub mod remote;
 pub mod schema;
 
 pub use datafusion_sql::{ResolvedTableReference, TableReference};
@@ -0,0 +1,731 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Catalogs and schemas that persist the definitions of their tables and
+//! views to a local directory, so that they survive restarts
+
+use crate::catalog::catalog::CatalogProvider;
+use crate::catalog::schema::SchemaProvider;
+use crate::datasource::view::ViewTable;
+use crate::datasource::TableProvider;
+use crate::error::{DataFusionError, Result};
+use crate::execution::context::SessionState;
+use crate::physical_plan::ExecutionPlan;
+use arrow::datatypes::{Schema, SchemaRef};
+use async_trait::async_trait;
+use dashmap::DashMap;
+use datafusion_expr::{CreateView, Expr, LogicalPlan, TableType};
+use datafusion_sql::parser::{DFParser, Statement as DFStatement};
+use datafusion_sql::sqlparser::ast::Statement;
+use log::warn;
+use parking_lot::RwLock;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+use serde_json::{json, Value};
+use std::any::Any;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// The extension of the files the schemas are persisted to
+const SCHEMA_FILE_EXTENSION: &str = "json";
+
+/// A table of a [`PersistentSchemaProvider`]
+struct PersistentTable {
+    name: String,
+    table: Arc<dyn TableProvider>,
+}
+
+/// A `SchemaProvider` that persists the SQL definitions of its tables to a
+/// JSON file, and creates them again when it is loaded.
+///
+/// The tables created by `CREATE EXTERNAL TABLE` and `CREATE VIEW` statements
+/// have a definition, see [`TableProvider::get_table_definition`], and are
+/// persisted: their location, file format, options and partition columns,
+/// or the query of the views. The other tables, such as in-memory tables,
+/// are only registered for the lifetime of the provider.
+///
+/// The definitions are planned with the default catalog and schema of the
+/// session, so the unqualified table names of the views are resolved as when
+/// they were created if the schema is the default schema.
+///
+/// The tables whose definitions can no longer be planned, e.g. the views of
+/// tables that were renamed or dropped since, are still registered when the
+/// schema is loaded, so that they can be dropped, but fail to be scanned.
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use datafusion::catalog::persistent::PersistentSchemaProvider;
+/// # use datafusion::error::Result;
+/// # use datafusion::prelude::{SessionConfig, SessionContext};
+/// # async fn f() -> Result<()> {
+/// let config = SessionConfig::new().with_default_catalog_and_schema("datafusion", "app");
+/// let ctx = SessionContext::with_config(config);
+/// let schema = Arc::new(PersistentSchemaProvider::new("/var/lib/app/app.json"));
+/// ctx.catalog("datafusion").unwrap().register_schema("app", schema.clone())?;
+/// // create the tables and views defined before
+/// for error in schema.load(&ctx.state()).await? {
+///     eprintln!("{error}");
+/// }
+///
+/// // the table is still defined after a restart
+/// ctx.sql("CREATE EXTERNAL TABLE t STORED AS PARQUET LOCATION '/data/t/'").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PersistentSchemaProvider {
+    path: PathBuf,
+    /// The tables in the order they were registered, which is the order
+    /// they are created in when loaded, so views follow their tables
+    tables: RwLock<Vec<PersistentTable>>,
+}
+
+impl PersistentSchemaProvider {
+    /// Create a new schema persisted to the file at `path`, without tables.
+    /// The tables persisted to the file are created by [`Self::load`]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            tables: RwLock::new(vec![]),
+        }
+    }
+
+    /// The file the schema is persisted to
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Create the tables persisted to the file of the schema, if it exists,
+    /// by planning their definitions in `state`. The tables that are already
+    /// registered are replaced in place, so loading the schema again creates
+    /// its tables again rather than adding them twice.
+    ///
+    /// The schema should be registered in the catalogs of `state` first, so
+    /// that the views of the schema can refer to its tables.
+    ///
+    /// The definitions that can not be planned don't prevent the other
+    /// tables from being created: their errors are returned, and the tables
+    /// are registered with their definitions so that they are still persisted
+    /// and can be dropped, but fail to be scanned with their error.
+    pub async fn load(&self, state: &SessionState) -> Result<Vec<DataFusionError>> {
+        if !self.path.exists() {
+            return Ok(vec![]);
+        }
+        let data = fs::read(&self.path)?;
+        let value: Value = serde_json::from_slice(&data).map_err(|e| {
+            DataFusionError::Execution(format!(
+                "Invalid schema file {}: {e}",
+                self.path.display()
+            ))
+        })?;
+        let tables = value
+            .get("tables")
+            .and_then(Value::as_array)
+            .ok_or_else(|| self.invalid_file("missing tables"))?;
+
+        let mut errors = vec![];
+        for table in tables {
+            let name = table
+                .get("name")
+                .and_then(Value::as_str)
+                .ok_or_else(|| self.invalid_file("missing table name"))?;
+            let definition = table
+                .get("definition")
+                .and_then(Value::as_str)
+                .ok_or_else(|| self.invalid_file("missing table definition"))?;
+            let provider = match create_table(state, definition).await {
+                Ok(provider) => provider,
+                Err(e) => {
+                    let e = DataFusionError::Execution(format!(
+                        "Could not create table {name} of {}: {e}",
+                        self.path.display()
+                    ));
+                    warn!("{e}");
+                    let provider = Arc::new(UnloadedTable::new(definition, &e));
+                    errors.push(e);
+                    provider
+                }
+            };
+            let mut tables = self.tables.write();
+            match tables.iter_mut().find(|t| t.name == name) {
+                Some(table) => table.table = provider,
+                None => tables.push(PersistentTable {
+                    name: name.to_string(),
+                    table: provider,
+                }),
+            }
+        }
+        Ok(errors)
+    }
+
+    fn invalid_file(&self, message: &str) -> DataFusionError {
+        DataFusionError::Execution(format!(
+            "Invalid schema file {}: {message}",
+            self.path.display()
+        ))
+    }
+
+    /// Write the definitions of `tables` to the file of the schema, replacing
+    /// it once written
+    fn persist(&self, tables: &[PersistentTable]) -> Result<()> {
+        let tables: Vec<_> = tables
+            .iter()
+            .filter_map(|t| {
+                let definition = t.table.get_table_definition()?;
+                Some(json!({"name": t.name, "definition": definition}))
+            })
+            .collect();
+        let data = serde_json::to_vec_pretty(&json!({ "tables": tables }))
+            .map_err(|e| DataFusionError::Execution(e.to_string()))?;
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        fs::write(&tmp, data)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+/// Creates the table or view of a `CREATE EXTERNAL TABLE` or `CREATE VIEW`
+/// statement
+async fn create_table(
+    state: &SessionState,
+    definition: &str,
+) -> Result<Arc<dyn TableProvider>> {
+    match state.create_logical_plan(definition).await? {
+        LogicalPlan::CreateExternalTable(cmd) => state.create_custom_table(&cmd).await,
+        LogicalPlan::CreateView(CreateView {
+            input, definition, ..
+        }) => Ok(Arc::new(ViewTable::try_new((*input).clone(), definition)?)),
+        other => Err(DataFusionError::Execution(format!(
+            "Expected a CREATE EXTERNAL TABLE or CREATE VIEW statement, got {}",
+            other.display()
+        ))),
+    }
+}
+
+/// A table of a [`PersistentSchemaProvider`] whose definition could not be
+/// planned when the schema was loaded. It keeps the definition, so that it
+/// is persisted again, and fails to be scanned with the error of the
+/// definition
+struct UnloadedTable {
+    definition: String,
+    table_type: TableType,
+    error: String,
+}
+
+impl UnloadedTable {
+    fn new(definition: &str, error: &DataFusionError) -> Self {
+        // a view needs to be dropped with DROP VIEW
+        let table_type = match DFParser::parse_sql(definition)
+            .ok()
+            .and_then(|mut statements| statements.pop_front())
+        {
+            Some(DFStatement::Statement(statement))
+                if matches!(*statement, Statement::CreateView { .. }) =>
+            {
+                TableType::View
+            }
+            _ => TableType::Base,
+        };
+        Self {
+            definition: definition.to_string(),
+            table_type,
+            error: error.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl TableProvider for UnloadedTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::new(Schema::empty())
+    }
+
+    fn table_type(&self) -> TableType {
+        self.table_type
+    }
+
+    fn get_table_definition(&self) -> Option<&str> {
+        Some(&self.definition)
+    }
+
+    async fn scan(
+        &self,
+        _state: &SessionState,
+        _projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Err(DataFusionError::Execution(self.error.clone()))
+    }
+}
+
+#[async_trait]
+impl SchemaProvider for PersistentSchemaProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn table_names(&self) -> Vec<String> {
+        self.tables.read().iter().map(|t| t.name.clone()).collect()
+    }
+
+    async fn table(&self, name: &str) -> Option<Arc<dyn TableProvider>> {
+        self.tables
+            .read()
+            .iter()
+            .find(|t| t.name == name)
+            .map(|t| t.table.clone())
+    }
+
+    fn register_table(
+        &self,
+        name: String,
+        table: Arc<dyn TableProvider>,
+    ) -> Result<Option<Arc<dyn TableProvider>>> {
+        let mut tables = self.tables.write();
+        if tables.iter().any(|t| t.name == name) {
+            return Err(DataFusionError::Execution(format!(
+                "The table {name} already exists"
+            )));
+        }
+        tables.push(PersistentTable { name, table });
+        if let Err(e) = self.persist(&tables) {
+            tables.pop();
+            return Err(e);
+        }
+        Ok(None)
+    }
+
+    fn deregister_table(&self, name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+        let mut tables = self.tables.write();
+        let index = match tables.iter().position(|t| t.name == name) {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let table = tables.remove(index);
+        if let Err(e) = self.persist(&tables) {
+            tables.insert(index, table);
+            return Err(e);
+        }
+        Ok(Some(table.table))
+    }
+
+    /// Renames the table in place, so that it is still created before the
+    /// views that follow it when the schema is loaded
+    fn rename_table(&self, name: &str, new_name: String) -> Result<()> {
+        let mut tables = self.tables.write();
+        if tables.iter().any(|t| t.name == new_name) {
+            return Err(DataFusionError::Execution(format!(
+                "The table {new_name} already exists"
+            )));
+        }
+        let index = tables.iter().position(|t| t.name == name).ok_or_else(|| {
+            DataFusionError::Execution(format!("The table {name} doesn't exist"))
+        })?;
+        let name = std::mem::replace(&mut tables[index].name, new_name);
+        if let Err(e) = self.persist(&tables) {
+            tables[index].name = name;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Replaces the table in place, see [`Self::rename_table`]
+    fn replace_table(
+        &self,
+        name: String,
+        table: Arc<dyn TableProvider>,
+    ) -> Result<Option<Arc<dyn TableProvider>>> {
+        let mut tables = self.tables.write();
+        let index = match tables.iter().position(|t| t.name == name) {
+            Some(index) => index,
+            None => {
+                drop(tables);
+                return self.register_table(name, table);
+            }
+        };
+        let replaced = std::mem::replace(&mut tables[index].table, table);
+        if let Err(e) = self.persist(&tables) {
+            tables[index].table = replaced;
+            return Err(e);
+        }
+        Ok(Some(replaced))
+    }
+
+    fn table_exist(&self, name: &str) -> bool {
+        self.tables.read().iter().any(|t| t.name == name)
+    }
+}
+
+/// A `CatalogProvider` whose schemas are [`PersistentSchemaProvider`]s
+/// persisted to the files of a local directory, one per schema.
+///
+/// The schemas registered in the catalog, e.g. by `CREATE SCHEMA`, are
+/// persisted too. [`Self::load`] creates the schemas persisted to the
+/// directory and their tables.
+pub struct PersistentCatalogProvider {
+    dir: PathBuf,
+    schemas: DashMap<String, Arc<dyn SchemaProvider>>,
+}
+
+impl PersistentCatalogProvider {
+    /// Create a new catalog persisted to the directory `dir`, without
+    /// schemas. The schemas persisted to the directory are created by
+    /// [`Self::load`]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            schemas: DashMap::new(),
+        }
+    }
+
+    /// Create the schemas persisted to the directory of the catalog and
+    /// their tables, by planning their definitions in `state`. The schemas
+    /// that are already registered are loaded again, see
+    /// [`PersistentSchemaProvider::load`].
+    ///
+    /// The catalog should be registered in `state` first, so that the views
+    /// of the schemas can refer to their tables. The errors of the definitions
+    /// that could not be planned are returned.
+    pub async fn load(&self, state: &SessionState) -> Result<Vec<DataFusionError>> {
+        fs::create_dir_all(&self.dir)?;
+        let mut schemas = vec![];
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(SCHEMA_FILE_EXTENSION) {
+                continue;
+            }
+            let name = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .map(|s| percent_decode_str(s).decode_utf8_lossy().into_owned())
+                .ok_or_else(|| {
+                    DataFusionError::Execution(format!(
+                        "Invalid schema file name {}",
+                        path.display()
+                    ))
+                })?;
+            schemas.push((name, path));
+        }
+        schemas.sort();
+
+        // register all the schemas before creating their tables, for the
+        // views that refer to the tables of other schemas
+        let schemas: Vec<_> = schemas
+            .into_iter()
+            .map(|(name, path)| {
+                self.schemas
+                    .entry(name)
+                    .or_insert_with(|| Arc::new(PersistentSchemaProvider::new(path)))
+                    .value()
+                    .clone()
+            })
+            .collect();
+        let mut errors = vec![];
+        for schema in &schemas {
+            // the schemas of the catalog are all persistent, see `register_schema`
+            if let Some(schema) =
+                schema.as_any().downcast_ref::<PersistentSchemaProvider>()
+            {
+                errors.extend(schema.load(state).await?);
+            }
+        }
+        Ok(errors)
+    }
+
+    /// The file the schema `name` is persisted to
+    fn schema_path(&self, name: &str) -> PathBuf {
+        let file_name = utf8_percent_encode(name, NON_ALPHANUMERIC).to_string();
+        self.dir
+            .join(file_name)
+            .with_extension(SCHEMA_FILE_EXTENSION)
+    }
+}
+
+impl CatalogProvider for PersistentCatalogProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema_names(&self) -> Vec<String> {
+        self.schemas.iter().map(|s| s.key().clone()).collect()
+    }
+
+    fn schema(&self, name: &str) -> Option<Arc<dyn SchemaProvider>> {
+        self.schemas.get(name).map(|s| s.value().clone())
+    }
+
+    /// Adds a new schema to this catalog. Schemas that are not
+    /// [`PersistentSchemaProvider`]s must not have tables, and are replaced
+    /// by a `PersistentSchemaProvider` persisted to the directory of the
+    /// catalog
+    fn register_schema(
+        &self,
+        name: &str,
+        schema: Arc<dyn SchemaProvider>,
+    ) -> Result<Option<Arc<dyn SchemaProvider>>> {
+        let schema: Arc<dyn SchemaProvider> =
+            if schema.as_any().is::<PersistentSchemaProvider>() {
+                schema
+            } else if schema.table_names().is_empty() {
+                let schema = PersistentSchemaProvider::new(self.schema_path(name));
+                schema.persist(&[])?;
+                Arc::new(schema)
+            } else {
+                return Err(DataFusionError::Execution(format!(
+                    "Can not register schema {name} with tables in a persistent \
+                     catalog, only PersistentSchemaProviders or empty schemas"
+                )));
+            };
+        Ok(self.schemas.insert(name.into(), schema))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_batches_sorted_eq;
+    use crate::prelude::{SessionConfig, SessionContext};
+    use tempfile::TempDir;
+
+    /// Opens the catalog persisted to `dir`, returning the errors of the
+    /// definitions that could not be planned
+    async fn load(dir: &Path) -> Result<(SessionContext, Vec<DataFusionError>)> {
+        let config =
+            SessionConfig::new().with_default_catalog_and_schema("persistent", "s");
+        let ctx = SessionContext::with_config(config);
+        let catalog = Arc::new(PersistentCatalogProvider::new(dir));
+        ctx.register_catalog("persistent", catalog.clone());
+        let errors = catalog.load(&ctx.state()).await?;
+        ctx.sql("CREATE SCHEMA IF NOT EXISTS s").await?;
+        Ok((ctx, errors))
+    }
+
+    async fn open(dir: &Path) -> Result<SessionContext> {
+        let (ctx, errors) = load(dir).await?;
+        assert!(errors.is_empty(), "{errors:?}");
+        Ok(ctx)
+    }
+
+    #[tokio::test]
+    async fn tables_survive_restarts() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let ctx = open(tmp_dir.path()).await?;
+        ctx.sql(
+            "CREATE EXTERNAL TABLE t (a INT, b INT, c INT) \
+             STORED AS CSV WITH HEADER ROW LOCATION 'tests/data/example.csv'",
+        )
+        .await?;
+        ctx.sql("CREATE VIEW v AS SELECT a, c FROM t").await?;
+        ctx.sql(
+            "CREATE EXTERNAL TABLE d STORED AS CSV LOCATION 'tests/data/example.csv'",
+        )
+        .await?;
+        ctx.sql("DROP TABLE d").await?;
+        // in-memory tables are not persisted
+        ctx.sql("CREATE TABLE m AS VALUES (1)").await?;
+        drop(ctx);
+
+        let ctx = open(tmp_dir.path()).await?;
+        let schema = ctx.catalog("persistent").unwrap().schema("s").unwrap();
+        assert_eq!(schema.table_names(), vec!["t", "v"]);
+
+        let batches = ctx.sql("SELECT * FROM v").await?.collect().await?;
+        let expected = vec![
+            "+---+---+",
+            "| a | c |",
+            "+---+---+",
+            "| 1 | 3 |",
+            "+---+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn altered_tables_survive_restarts() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let ctx = open(tmp_dir.path()).await?;
+        ctx.sql("CREATE EXTERNAL TABLE t STORED AS JSON LOCATION 'tests/jsons/2.json'")
+            .await?;
+        ctx.sql("ALTER TABLE t RENAME TO u").await?;
+        ctx.sql("ALTER TABLE u ADD COLUMN e VARCHAR").await?;
+        drop(ctx);
+
+        let ctx = open(tmp_dir.path()).await?;
+        let schema = ctx.catalog("persistent").unwrap().schema("s").unwrap();
+        assert_eq!(schema.table_names(), vec!["u"]);
+
+        let batches = ctx
+            .sql("SELECT a, e FROM u WHERE a > 10")
+            .await?
+            .collect()
+            .await?;
+        let expected = vec![
+            "+-----------------+---+",
+            "| a               | e |",
+            "+-----------------+---+",
+            "| 100000000000000 |   |",
+            "+-----------------+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_added_columns_survive_restarts() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let testdata = crate::test_util::parquet_test_data();
+        let ctx = open(tmp_dir.path()).await?;
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE p STORED AS PARQUET \
+             LOCATION '{testdata}/alltypes_plain.parquet'"
+        ))
+        .await?;
+        ctx.sql("ALTER TABLE p ADD COLUMN e VARCHAR").await?;
+        ctx.sql("ALTER TABLE p SET OPTIONS ('compression' 'zstd(3)')")
+            .await?;
+        let expected = vec![
+            "+----+---+",
+            "| id | e |",
+            "+----+---+",
+            "| 0  |   |",
+            "| 1  |   |",
+            "+----+---+",
+        ];
+        let batches = ctx
+            .sql("SELECT id, e FROM p WHERE id < 2")
+            .await?
+            .collect()
+            .await?;
+        assert_batches_sorted_eq!(expected, &batches);
+        drop(ctx);
+
+        let ctx = open(tmp_dir.path()).await?;
+        let batches = ctx
+            .sql("SELECT id, e FROM p WHERE id < 2")
+            .await?
+            .collect()
+            .await?;
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn views_of_renamed_tables_survive_restarts() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let ctx = open(tmp_dir.path()).await?;
+        ctx.sql(
+            "CREATE EXTERNAL TABLE t (a INT, b INT, c INT) \
+             STORED AS CSV WITH HEADER ROW LOCATION 'tests/data/example.csv'",
+        )
+        .await?;
+        ctx.sql("CREATE VIEW v AS SELECT a, c FROM t").await?;
+        ctx.sql("CREATE VIEW w AS SELECT a FROM t").await?;
+        ctx.sql("ALTER TABLE t RENAME TO u").await?;
+        drop(ctx);
+
+        // the views can't be planned anymore, but don't prevent loading u
+        let (ctx, errors) = load(tmp_dir.path()).await?;
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        let schema = ctx.catalog("persistent").unwrap().schema("s").unwrap();
+        assert_eq!(schema.table_names(), vec!["u", "v", "w"]);
+
+        let batches = ctx.sql("SELECT a, c FROM u").await?.collect().await?;
+        let expected = vec![
+            "+---+---+",
+            "| a | c |",
+            "+---+---+",
+            "| 1 | 3 |",
+            "+---+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        let err = ctx
+            .sql("SELECT count(*) FROM v")
+            .await?
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Could not create table v"),
+            "{err}"
+        );
+
+        // the broken views are still persisted until they are dropped
+        ctx.sql("DROP VIEW v").await?;
+        drop(ctx);
+
+        let (ctx, errors) = load(tmp_dir.path()).await?;
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        let schema = ctx.catalog("persistent").unwrap().schema("s").unwrap();
+        assert_eq!(schema.table_names(), vec!["u", "w"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn load_twice() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let ctx = open(tmp_dir.path()).await?;
+        ctx.sql(
+            "CREATE EXTERNAL TABLE t (a INT, b INT, c INT) \
+             STORED AS CSV WITH HEADER ROW LOCATION 'tests/data/example.csv'",
+        )
+        .await?;
+        ctx.sql("CREATE VIEW v AS SELECT a, c FROM t").await?;
+        ctx.sql("CREATE TABLE m AS VALUES (1)").await?;
+
+        let catalog = ctx.catalog("persistent").unwrap();
+        let schema = catalog.schema("s").unwrap();
+        let persistent = schema
+            .as_any()
+            .downcast_ref::<PersistentSchemaProvider>()
+            .unwrap();
+        persistent.load(&ctx.state()).await?;
+        assert_eq!(schema.table_names(), vec!["t", "v", "m"]);
+
+        let persistent = catalog
+            .as_any()
+            .downcast_ref::<PersistentCatalogProvider>()
+            .unwrap();
+        persistent.load(&ctx.state()).await?;
+        assert_eq!(catalog.schema_names(), vec!["s"]);
+        assert_eq!(
+            catalog.schema("s").unwrap().table_names(),
+            vec!["t", "v", "m"]
+        );
+
+        let batches = ctx.sql("SELECT * FROM v").await?.collect().await?;
+        let expected = vec![
+            "+---+---+",
+            "| a | c |",
+            "+---+---+",
+            "| 1 | 3 |",
+            "+---+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Catalogs whose schemas and tables are looked up asynchronously, such as
+//! the catalogs of remote metastores
+
+use crate::datasource::TableProvider;
+use crate::error::Result;
+use async_trait::async_trait;
+use std::any::Any;
+use std::sync::Arc;
+
+/// A catalog whose schemas are looked up asynchronously, for instance from a
+/// Hive Metastore, AWS Glue or an Iceberg REST catalog.
+///
+/// Unlike a [`CatalogProvider`], an `AsyncCatalogProvider` does not list its
+/// schemas and tables: they are only fetched when a query references them.
+/// When planning a SQL statement, the tables it references are resolved
+/// before the statement is planned, see
+/// [`SessionState::resolve_table_references`], so that each of them is only
+/// fetched once per statement.
+///
+/// Register it with [`SessionContext::register_async_catalog`]. The catalogs
+/// registered with [`SessionContext::register_catalog`] take precedence over
+/// the asynchronous catalogs of the same name.
+///
+/// [`CatalogProvider`]: crate::catalog::catalog::CatalogProvider
+/// [`SessionState::resolve_table_references`]: crate::execution::context::SessionState::resolve_table_references
+/// [`SessionContext::register_async_catalog`]: crate::execution::context::SessionContext::register_async_catalog
+/// [`SessionContext::register_catalog`]: crate::execution::context::SessionContext::register_catalog
+#[async_trait]
+pub trait AsyncCatalogProvider: Sync + Send {
+    /// Returns the catalog provider as [`Any`](std::any::Any)
+    /// so that it can be downcast to a specific implementation.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Retrieves a specific schema from the catalog by name, provided it exists.
+    async fn schema(&self, name: &str) -> Result<Option<Arc<dyn AsyncSchemaProvider>>>;
+}
+
+/// A schema of an [`AsyncCatalogProvider`], whose tables are looked up
+/// asynchronously
+#[async_trait]
+pub trait AsyncSchemaProvider: Sync + Send {
+    /// Returns the schema provider as [`Any`](std::any::Any)
+    /// so that it can be downcast to a specific implementation.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Retrieves a specific table from the schema by name, provided it exists.
+    async fn table(&self, name: &str) -> Result<Option<Arc<dyn TableProvider>>>;
+}
@@ -61,6 +61,51 @@ pub trait SchemaProvider: Sync + Send {
         ))
     }
 
+    /// If supported by the implementation, renames the table `name` of this
+    /// schema to `new_name`, for `ALTER TABLE ... RENAME TO`.
+    /// Returns an error if no table of that name exists, or if a table named
+    /// `new_name` exists.
+    ///
+    /// The default implementation deregisters the table and registers it
+    /// again with its new name.
+    fn rename_table(&self, name: &str, new_name: String) -> Result<()> {
+        if self.table_exist(&new_name) {
+            return Err(DataFusionError::Execution(format!(
+                "The table {new_name} already exists"
+            )));
+        }
+        let table = self.deregister_table(name)?.ok_or_else(|| {
+            DataFusionError::Execution(format!("The table {name} doesn't exist"))
+        })?;
+        if let Err(e) = self.register_table(new_name, table.clone()) {
+            self.register_table(name.to_string(), table)?;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// If supported by the implementation, replaces the table `name` of this
+    /// schema by `table` and returns the replaced table, for `ALTER TABLE`
+    /// and `CREATE OR REPLACE`. If no table of that name exists, adds the
+    /// table like [`Self::register_table`] and returns Ok(None).
+    ///
+    /// The default implementation deregisters the replaced table and
+    /// registers `table`, or the replaced table again if that fails.
+    fn replace_table(
+        &self,
+        name: String,
+        table: Arc<dyn TableProvider>,
+    ) -> Result<Option<Arc<dyn TableProvider>>> {
+        let replaced = self.deregister_table(&name)?;
+        if let Err(e) = self.register_table(name.clone(), table) {
+            if let Some(replaced) = replaced {
+                self.register_table(name, replaced)?;
+            }
+            return Err(e);
+        }
+        Ok(replaced)
+    }
+
     /// If supported by the implementation, checks the table exist in the schema provider or not.
     /// If no matched table in the schema provider, return false.
     /// Otherwise, return true.
@@ -121,6 +166,14 @@ impl SchemaProvider for MemorySchemaProvider {
         Ok(self.tables.remove(name).map(|(_, table)| table))
     }
 
+    fn replace_table(
+        &self,
+        name: String,
+        table: Arc<dyn TableProvider>,
+    ) -> Result<Option<Arc<dyn TableProvider>>> {
+        Ok(self.tables.insert(name, table))
+    }
+
     fn table_exist(&self, name: &str) -> bool {
         self.tables.contains_key(name)
     }
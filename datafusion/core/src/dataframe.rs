@@ -48,7 +48,9 @@ use crate::logical_expr::{
     col, utils::find_window_exprs, Expr, JoinType, LogicalPlan, LogicalPlanBuilder,
     Partitioning, TableType,
 };
-use crate::physical_plan::file_format::{plan_to_csv, plan_to_json, plan_to_parquet};
+use crate::physical_plan::file_format::{
+    plan_to_arrow, plan_to_avro, plan_to_csv, plan_to_json, plan_to_parquet,
+};
 use crate::physical_plan::SendableRecordBatchStream;
 use crate::physical_plan::{collect, collect_partitioned};
 use crate::physical_plan::{execute_stream, execute_stream_partitioned, ExecutionPlan};
@@ -964,6 +966,20 @@ impl DataFrame {
         plan_to_json(task_ctx, plan, path).await
     }
 
+    /// Executes a query and writes the results to a partitioned Arrow IPC file.
+    pub async fn write_arrow(self, path: impl AsRef<str>) -> Result<()> {
+        let plan = self.session_state.create_physical_plan(&self.plan).await?;
+        let task_ctx = Arc::new(self.task_ctx());
+        plan_to_arrow(task_ctx, plan, path).await
+    }
+
+    /// Executes a query and writes the results to a partitioned Avro file.
+    pub async fn write_avro(self, path: impl AsRef<str>) -> Result<()> {
+        let plan = self.session_state.create_physical_plan(&self.plan).await?;
+        let task_ctx = Arc::new(self.task_ctx());
+        plan_to_avro(task_ctx, plan, path).await
+    }
+
     /// Add an additional column to the DataFrame.
     ///
     /// ```
@@ -1146,6 +1162,7 @@ mod tests {
 
     use arrow::array::Int32Array;
     use arrow::datatypes::DataType;
+    use arrow::util::pretty::pretty_format_batches;
 
     use datafusion_expr::{
         avg, cast, count, count_distinct, create_udf, expr, lit, max, min, sum,
@@ -1852,6 +1869,79 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn write_arrow_round_trip() -> Result<()> {
+        let ctx = SessionContext::new();
+        let tmp_dir = tempfile::TempDir::new()?;
+        let out_dir = tmp_dir.path().join("out");
+        let out_dir = out_dir.to_str().unwrap();
+
+        let df = test_table()
+            .await?
+            .select_columns(&["c1", "c2"])?
+            .filter(col("c2").lt_eq(lit(2)))?;
+        let expected = df
+            .clone()
+            .sort(vec![col("c1").sort(true, true), col("c2").sort(true, true)])?
+            .collect()
+            .await?;
+        df.write_arrow(out_dir).await?;
+
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE t STORED AS ARROW LOCATION '{out_dir}'"
+        ))
+        .await?;
+        let results = ctx
+            .sql("SELECT c1, c2 FROM t ORDER BY c1, c2")
+            .await?
+            .collect()
+            .await?;
+
+        assert_eq!(
+            pretty_format_batches(&expected)?.to_string(),
+            pretty_format_batches(&results)?.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "avro")]
+    #[tokio::test]
+    async fn write_avro_round_trip() -> Result<()> {
+        let ctx = SessionContext::new();
+        let tmp_dir = tempfile::TempDir::new()?;
+        let out_dir = tmp_dir.path().join("out");
+        let out_dir = out_dir.to_str().unwrap();
+
+        let df = test_table()
+            .await?
+            .select_columns(&["c1", "c2"])?
+            .filter(col("c2").lt_eq(lit(2)))?;
+        let expected = df
+            .clone()
+            .sort(vec![col("c1").sort(true, true), col("c2").sort(true, true)])?
+            .collect()
+            .await?;
+        df.write_avro(out_dir).await?;
+
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE t STORED AS AVRO LOCATION '{out_dir}'"
+        ))
+        .await?;
+        let results = ctx
+            .sql("SELECT c1, c2 FROM t ORDER BY c1, c2")
+            .await?
+            .collect()
+            .await?;
+
+        assert_eq!(
+            pretty_format_batches(&expected)?.to_string(),
+            pretty_format_batches(&results)?.to_string()
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn row_writer_resize_test() -> Result<()> {
         let schema = Arc::new(Schema::new(vec![arrow::datatypes::Field::new(
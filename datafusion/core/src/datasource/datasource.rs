@@ -18,6 +18,7 @@
 //! Data source traits
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -25,8 +26,8 @@ use datafusion_common::Statistics;
 use datafusion_expr::{CreateExternalTable, LogicalPlan};
 pub use datafusion_expr::{TableProviderFilterPushDown, TableType};
 
-use crate::arrow::datatypes::SchemaRef;
-use crate::error::Result;
+use crate::arrow::datatypes::{Field, SchemaRef};
+use crate::error::{DataFusionError, Result};
 use crate::execution::context::SessionState;
 use crate::logical_expr::Expr;
 use crate::physical_plan::ExecutionPlan;
@@ -70,6 +71,32 @@ pub trait TableProvider: Sync + Send {
         limit: Option<usize>,
     ) -> Result<Arc<dyn ExecutionPlan>>;
 
+    /// Tests whether the table provider can read only some of the nested
+    /// fields of its struct columns with [`Self::scan_nested`]
+    fn supports_nested_projection(&self) -> bool {
+        false
+    }
+
+    /// Create an ExecutionPlan that will scan the table, like [`Self::scan`],
+    /// reading the struct columns of the projection that have paths in
+    /// `nested_projection` with only the nested fields the paths lead to.
+    ///
+    /// Each path starts with the name of a column followed by the names of
+    /// struct fields, e.g. `["payload", "user", "id"]`. This is only called
+    /// if [`Self::supports_nested_projection`] returns true
+    async fn scan_nested(
+        &self,
+        _state: &SessionState,
+        _projection: Option<&Vec<usize>>,
+        _nested_projection: &[Vec<String>],
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Err(DataFusionError::NotImplemented(
+            "Nested projection is not supported by this table provider".to_string(),
+        ))
+    }
+
     /// Tests whether the table provider can make use of a filter expression
     /// to optimise data retrieval.
     #[deprecated(since = "20.0.0", note = "use supports_filters_pushdown instead")]
@@ -97,6 +124,38 @@ pub trait TableProvider: Sync + Send {
     fn statistics(&self) -> Option<Statistics> {
         None
     }
+
+    /// Create a copy of this table with the nullable column `field` added
+    /// to its schema, for `ALTER TABLE ... ADD COLUMN`. The column is null
+    /// in the rows the table already has.
+    ///
+    /// The copy replaces this table in its schema, see
+    /// [`SchemaProvider::replace_table`](crate::catalog::schema::SchemaProvider::replace_table)
+    async fn add_column(
+        &self,
+        _state: &SessionState,
+        _field: Field,
+    ) -> Result<Arc<dyn TableProvider>> {
+        Err(DataFusionError::NotImplemented(
+            "Adding columns is not supported by this table provider".to_string(),
+        ))
+    }
+
+    /// Create a copy of this table with the table(provider) specific
+    /// `options`, replacing the options with the same keys, for
+    /// `ALTER TABLE ... SET OPTIONS`.
+    ///
+    /// The copy replaces this table in its schema, see
+    /// [`SchemaProvider::replace_table`](crate::catalog::schema::SchemaProvider::replace_table)
+    async fn set_options(
+        &self,
+        _state: &SessionState,
+        _options: &HashMap<String, String>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        Err(DataFusionError::NotImplemented(
+            "Setting options is not supported by this table provider".to_string(),
+        ))
+    }
 }
 
 /// A factory which creates [`TableProvider`]s at runtime given a URL.
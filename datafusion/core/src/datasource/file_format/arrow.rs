@@ -0,0 +1,262 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Apache Arrow IPC format abstractions
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::datatypes::{Schema, SchemaRef};
+use async_trait::async_trait;
+use datafusion_physical_expr::PhysicalExpr;
+use object_store::{ObjectMeta, ObjectStore};
+
+use super::FileFormat;
+use crate::error::Result;
+use crate::execution::context::SessionState;
+use crate::physical_plan::file_format::{read_arrow_schema, ArrowExec, FileScanConfig};
+use crate::physical_plan::ExecutionPlan;
+use crate::physical_plan::Statistics;
+
+/// The default file extension of arrow files
+pub const DEFAULT_ARROW_EXTENSION: &str = ".arrow";
+/// Arrow IPC `FileFormat` implementation, reading both the Arrow IPC file
+/// format and the Arrow IPC streaming format
+#[derive(Default, Debug)]
+pub struct ArrowFormat;
+
+#[async_trait]
+impl FileFormat for ArrowFormat {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn infer_schema(
+        &self,
+        _state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> Result<SchemaRef> {
+        let mut schemas = vec![];
+        for object in objects {
+            let schema = read_arrow_schema(store.as_ref(), object).await?;
+            schemas.push(schema.as_ref().clone());
+        }
+        let merged_schema = Schema::try_merge(schemas)?;
+        Ok(Arc::new(merged_schema))
+    }
+
+    async fn infer_stats(
+        &self,
+        _state: &SessionState,
+        _store: &Arc<dyn ObjectStore>,
+        _table_schema: SchemaRef,
+        _object: &ObjectMeta,
+    ) -> Result<Statistics> {
+        Ok(Statistics::default())
+    }
+
+    async fn create_physical_plan(
+        &self,
+        _state: &SessionState,
+        conf: FileScanConfig,
+        _filters: Option<&Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let exec = ArrowExec::new(conf);
+        Ok(Arc::new(exec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_batches_eq;
+    use crate::datasource::file_format::test_util::scan_format;
+    use crate::physical_plan::collect;
+    use crate::physical_plan::common::IPCWriter;
+    use crate::prelude::SessionContext;
+    use crate::test::object_store::{
+        local_unpartitioned_file, RequestCountingObjectStore,
+    };
+    use arrow::array::{Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field};
+    use arrow::ipc::writer::StreamWriter;
+    use arrow::record_batch::RecordBatch;
+    use object_store::local::LocalFileSystem;
+    use std::fs::File;
+    use tempfile::TempDir;
+
+    fn batches() -> Vec<RecordBatch> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        (0..3)
+            .map(|i| {
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(Int32Array::from(vec![2 * i, 2 * i + 1])),
+                        Arc::new(StringArray::from(vec![Some(format!("a{i}")), None])),
+                    ],
+                )
+                .unwrap()
+            })
+            .collect()
+    }
+
+    /// Writes `batches()` to `file.arrow` in the Arrow IPC file format and
+    /// to `stream.arrow` in the Arrow IPC streaming format
+    fn write_files(dir: &TempDir) -> Result<()> {
+        let batches = batches();
+        let schema = batches[0].schema();
+
+        let mut writer = IPCWriter::new(&dir.path().join("file.arrow"), &schema)?;
+        for batch in &batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+
+        let file = File::create(dir.path().join("stream.arrow"))?;
+        let mut writer = StreamWriter::try_new(file, &schema)?;
+        for batch in &batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_file_and_stream() -> Result<()> {
+        let dir = TempDir::new()?;
+        write_files(&dir)?;
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+
+        for file_name in ["file.arrow", "stream.arrow"] {
+            let exec = scan_format(
+                &state,
+                &ArrowFormat::default(),
+                dir.path().to_str().unwrap(),
+                file_name,
+                Some(vec![1, 0]),
+                Some(4),
+            )
+            .await?;
+            let batches = collect(exec, state.task_ctx()).await?;
+
+            let expected = vec![
+                "+------+----+",
+                "| name | id |",
+                "+------+----+",
+                "| a0   | 0  |",
+                "|      | 1  |",
+                "| a1   | 2  |",
+                "|      | 3  |",
+                "+------+----+",
+            ];
+            assert_batches_eq!(expected, &batches);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_file_ranges() -> Result<()> {
+        let dir = TempDir::new()?;
+        write_files(&dir)?;
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+
+        for file_name in ["file.arrow", "stream.arrow"] {
+            let exec = scan_format(
+                &state,
+                &ArrowFormat::default(),
+                dir.path().to_str().unwrap(),
+                file_name,
+                None,
+                None,
+            )
+            .await?;
+            let exec = exec.as_any().downcast_ref::<ArrowExec>().unwrap();
+            // split the file into ranges of a few bytes
+            let exec = exec.get_repartitioned(1000, 0);
+            assert!(exec.base_config().file_groups.len() > 3);
+
+            // each record batch is read by exactly one range
+            let batches = collect(Arc::new(exec), state.task_ctx()).await?;
+            let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+            assert_eq!(num_rows, 6);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_file_with_range_requests() -> Result<()> {
+        let dir = TempDir::new()?;
+        write_files(&dir)?;
+        // the store does not implement `get`, so objects can only be read
+        // with range requests
+        let store = Arc::new(RequestCountingObjectStore::new(Arc::new(
+            LocalFileSystem::new(),
+        )));
+        let session_ctx = SessionContext::new();
+        session_ctx
+            .runtime_env()
+            .register_object_store("file", "", store.upcast());
+        let state = session_ctx.state();
+        let expected_schema = batches()[0].schema();
+
+        // the schema of a file is read from its trailer and footer
+        let meta = local_unpartitioned_file(dir.path().join("file.arrow"));
+        let schema = ArrowFormat::default()
+            .infer_schema(&state, &store.upcast(), &[meta])
+            .await?;
+        assert_eq!(schema, expected_schema);
+        assert_eq!(store.request_count(), 2);
+
+        // the schema of a stream is read from its first message, after
+        // checking for the trailer of a file
+        let meta = local_unpartitioned_file(dir.path().join("stream.arrow"));
+        let schema = ArrowFormat::default()
+            .infer_schema(&state, &store.upcast(), &[meta])
+            .await?;
+        assert_eq!(schema, expected_schema);
+        assert_eq!(store.request_count(), 5);
+
+        let exec = scan_format(
+            &state,
+            &ArrowFormat::default(),
+            dir.path().to_str().unwrap(),
+            "file.arrow",
+            None,
+            None,
+        )
+        .await?;
+        let exec = exec.as_any().downcast_ref::<ArrowExec>().unwrap();
+        let exec = exec.get_repartitioned(1000, 0);
+        let ranges: usize = exec.base_config().file_groups.iter().map(Vec::len).sum();
+        assert!(ranges > 3);
+
+        let batches = collect(Arc::new(exec), state.task_ctx()).await?;
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(num_rows, 6);
+        // each range reads the trailer and the footer, and each of the 3
+        // record batches is fetched by the range it starts in
+        assert_eq!(store.request_count(), 5 + 2 * ranges + 3);
+        Ok(())
+    }
+}
@@ -20,7 +20,7 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use arrow::datatypes::Schema;
+use arrow::datatypes::{DataType, Field, Schema};
 use arrow::{self, datatypes::SchemaRef};
 use async_trait::async_trait;
 use datafusion_physical_expr::PhysicalExpr;
@@ -28,7 +28,7 @@ use object_store::{GetResult, ObjectMeta, ObjectStore};
 
 use super::FileFormat;
 use crate::avro_to_arrow::read_avro_schema_from_reader;
-use crate::error::Result;
+use crate::error::{DataFusionError, Result};
 use crate::execution::context::SessionState;
 use crate::physical_plan::file_format::{AvroExec, FileScanConfig};
 use crate::physical_plan::ExecutionPlan;
@@ -37,6 +37,13 @@ use crate::physical_plan::Statistics;
 /// The default file extension of avro files
 pub const DEFAULT_AVRO_EXTENSION: &str = ".avro";
 /// Avro `FileFormat` implementation.
+///
+/// The schemas of the files of a table may evolve: the inferred schema has
+/// the fields of all the files, the promoted type of fields whose type was
+/// promoted from `int` to `long`, `float` or `double`, from `long` to `float`
+/// or `double`, from `float` to `double` or between `bytes` and `string`, and
+/// the default values of the fields added to later files. Files without a
+/// field read its default value, or null if it has none.
 #[derive(Default, Debug)]
 pub struct AvroFormat;
 
@@ -64,7 +71,7 @@ impl FileFormat for AvroFormat {
             };
             schemas.push(schema);
         }
-        let merged_schema = Schema::try_merge(schemas)?;
+        let merged_schema = merge_schemas(&schemas)?;
         Ok(Arc::new(merged_schema))
     }
 
@@ -89,6 +96,68 @@ impl FileFormat for AvroFormat {
     }
 }
 
+/// Merges the schemas of the files of a table, whose fields may have been
+/// added, removed or promoted to wider types. The metadata of the fields,
+/// such as their default values, is taken from the last schema with them
+fn merge_schemas(schemas: &[Schema]) -> Result<Schema> {
+    let mut fields: Vec<Field> = vec![];
+    for schema in schemas {
+        for field in schema.fields() {
+            match fields.iter_mut().find(|f| f.name() == field.name()) {
+                Some(merged) => {
+                    let data_type = promote(merged.data_type(), field.data_type())
+                        .ok_or_else(|| {
+                            DataFusionError::Execution(format!(
+                                "Can not merge the avro schemas of the files: \
+                                 field {} has types {:?} and {:?}",
+                                field.name(),
+                                merged.data_type(),
+                                field.data_type()
+                            ))
+                        })?;
+                    let nullable = merged.is_nullable() || field.is_nullable();
+                    *merged = Field::new(field.name(), data_type, nullable);
+                    merged.set_metadata(field.metadata().clone());
+                }
+                None => fields.push(field.clone()),
+            }
+        }
+    }
+
+    // the fields missing in some files are null in them, unless they
+    // have a default value
+    for field in fields.iter_mut() {
+        if !schemas
+            .iter()
+            .all(|s| s.field_with_name(field.name()).is_ok())
+        {
+            let metadata = field.metadata().clone();
+            *field = Field::new(field.name(), field.data_type().clone(), true);
+            field.set_metadata(metadata);
+        }
+    }
+
+    let metadata = schemas.iter().flat_map(|s| s.metadata().clone()).collect();
+    Ok(Schema::new_with_metadata(fields, metadata))
+}
+
+/// Returns the type that values of both types can be read as, following the
+/// promotions of the avro schema resolution
+fn promote(a: &DataType, b: &DataType) -> Option<DataType> {
+    use DataType::*;
+    match (a, b) {
+        _ if a == b => Some(a.clone()),
+        (Null, other) | (other, Null) => Some(other.clone()),
+        (Int32, Int64) | (Int64, Int32) => Some(Int64),
+        (Int32 | Int64, Float32) | (Float32, Int32 | Int64) => Some(Float32),
+        (Int32 | Int64 | Float32, Float64) | (Float64, Int32 | Int64 | Float32) => {
+            Some(Float64)
+        }
+        (Utf8, Binary) | (Binary, Utf8) => Some(Utf8),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "avro")]
 mod tests {
@@ -350,6 +419,97 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn read_evolved_schemas() -> Result<()> {
+        use crate::prelude::AvroReadOptions;
+        use apache_avro::types::Value;
+
+        let v1 = r#"{"type": "record", "name": "event", "fields": [
+            {"name": "id", "type": "int"},
+            {"name": "name", "type": "string"}
+        ]}"#;
+        // `id` is promoted to long, `name` is removed and `source` added
+        let v2 = r#"{"type": "record", "name": "event", "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "source", "type": "string", "default": "kafka"}
+        ]}"#;
+        let files = vec![
+            (
+                v1,
+                vec![
+                    vec![("id", Value::Int(1)), ("name", Value::String("a".into()))],
+                    vec![("id", Value::Int(2)), ("name", Value::String("b".into()))],
+                ],
+            ),
+            (
+                v2,
+                vec![vec![
+                    ("id", Value::Long(3)),
+                    ("source", Value::String("web".into())),
+                ]],
+            ),
+        ];
+
+        let dir = tempfile::TempDir::new()?;
+        for (i, (schema, records)) in files.into_iter().enumerate() {
+            let schema = apache_avro::Schema::parse_str(schema)?;
+            let file = std::fs::File::create(dir.path().join(format!("{i}.avro")))?;
+            let mut writer = apache_avro::Writer::new(&schema, file);
+            for record in records {
+                let fields = record
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value))
+                    .collect();
+                writer.append(Value::Record(fields))?;
+            }
+            writer.flush()?;
+        }
+
+        let ctx = SessionContext::new();
+        ctx.register_avro(
+            "events",
+            dir.path().to_str().unwrap(),
+            AvroReadOptions::default(),
+        )
+        .await?;
+        let df = ctx
+            .sql("SELECT id, name, source FROM events ORDER BY id")
+            .await?;
+        let schema = df.schema();
+        assert_eq!(
+            schema.field_with_unqualified_name("id")?.data_type(),
+            &DataType::Int64
+        );
+        assert!(schema.field_with_unqualified_name("name")?.is_nullable());
+
+        let batches = df.collect().await?;
+        let expected = vec![
+            "+----+------+--------+",
+            "| id | name | source |",
+            "+----+------+--------+",
+            "| 1  | a    | kafka  |",
+            "| 2  | b    | kafka  |",
+            "| 3  |      | web    |",
+            "+----+------+--------+",
+        ];
+        crate::assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[test]
+    fn merge_incompatible_schemas() {
+        let schemas = vec![
+            Schema::new(vec![Field::new("id", DataType::Int64, false)]),
+            Schema::new(vec![Field::new("id", DataType::Boolean, false)]),
+        ];
+        let err = merge_schemas(&schemas).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("field id has types Int64 and Boolean"),
+            "{err}"
+        );
+    }
+
     async fn get_exec(
         state: &SessionState,
         file_name: &str,
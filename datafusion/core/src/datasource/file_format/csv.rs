@@ -20,6 +20,7 @@
 use std::any::Any;
 
 use std::collections::HashSet;
+use std::io::{Cursor, Read};
 use std::sync::Arc;
 
 use arrow::datatypes::{DataType, Field, Schema};
@@ -38,17 +39,33 @@ use crate::datasource::file_format::file_type::FileCompressionType;
 use crate::datasource::file_format::DEFAULT_SCHEMA_INFER_MAX_RECORD;
 use crate::error::Result;
 use crate::execution::context::SessionState;
-use crate::physical_plan::file_format::{CsvExec, FileScanConfig};
+use crate::physical_plan::file_format::{
+    to_standard_csv, CsvDialect, CsvExec, CsvMalformedMode, FileScanConfig, RecordParser,
+    MALFORMED_ROW_COLUMN,
+};
 use crate::physical_plan::ExecutionPlan;
 use crate::physical_plan::Statistics;
 
 /// The default file extension of csv files
 pub const DEFAULT_CSV_EXTENSION: &str = ".csv";
+
+/// The `OPTIONS` of a `CREATE EXTERNAL TABLE ... STORED AS CSV` statement,
+/// see [`CsvFormat::with_options`]
+pub const CSV_OPTIONS: &[&str] = &[
+    "quote",
+    "escape",
+    "terminator",
+    "comment",
+    "null_regex",
+    "malformed",
+];
+
 /// Character Separated Value `FileFormat` implementation.
 #[derive(Debug)]
 pub struct CsvFormat {
     has_header: bool,
     delimiter: u8,
+    dialect: CsvDialect,
     schema_infer_max_rec: Option<usize>,
     file_compression_type: FileCompressionType,
 }
@@ -59,6 +76,7 @@ impl Default for CsvFormat {
             schema_infer_max_rec: Some(DEFAULT_SCHEMA_INFER_MAX_RECORD),
             has_header: true,
             delimiter: b',',
+            dialect: CsvDialect::default(),
             file_compression_type: FileCompressionType::UNCOMPRESSED,
         }
     }
@@ -105,6 +123,109 @@ impl CsvFormat {
     pub fn delimiter(&self) -> u8 {
         self.delimiter
     }
+
+    /// The character quoting values.
+    /// - default to '"'
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.dialect.quote = quote;
+        self
+    }
+
+    /// The character escaping the next character within quoted values.
+    /// - default to None, quotes are escaped by doubling them
+    pub fn with_escape(mut self, escape: Option<u8>) -> Self {
+        self.dialect.escape = escape;
+        self
+    }
+
+    /// The character terminating rows.
+    /// - default to None, rows are terminated by '\n' or '\r\n'
+    pub fn with_terminator(mut self, terminator: Option<u8>) -> Self {
+        self.dialect.terminator = terminator;
+        self
+    }
+
+    /// Lines starting with this character are ignored.
+    /// - default to None
+    pub fn with_comment(mut self, comment: Option<u8>) -> Self {
+        self.dialect.comment = comment;
+        self
+    }
+
+    /// Values matching this regular expression, such as `\\N`, are read
+    /// as null. The expression has to match the whole value.
+    /// - default to None
+    pub fn with_null_regex(mut self, null_regex: Option<String>) -> Self {
+        self.dialect.null_regex = null_regex;
+        self
+    }
+
+    /// How to handle rows with the wrong number of fields or values that
+    /// can not be parsed as the type of their column.
+    /// - default to `CsvMalformedMode::Fail`
+    ///
+    /// With [`CsvMalformedMode::Capture`], inferred schemas have an extra
+    /// [`MALFORMED_ROW_COLUMN`] holding the text of malformed rows
+    pub fn with_malformed_mode(mut self, malformed_mode: CsvMalformedMode) -> Self {
+        self.dialect.malformed_mode = malformed_mode;
+        self
+    }
+
+    /// The dialect of the files, e.g. their quote and comment characters
+    pub fn dialect(&self) -> &CsvDialect {
+        &self.dialect
+    }
+
+    /// Set the dialect from the `OPTIONS` of a `CREATE EXTERNAL TABLE`
+    /// statement. The keys are the names in [`CSV_OPTIONS`]: the `quote`,
+    /// `escape`, `terminator` and `comment` characters, the `null_regex`
+    /// and the `malformed` row mode, one of `fail`, `skip` or `capture`.
+    ///
+    /// Returns an error for unknown keys and invalid values
+    pub fn with_options<K, V>(
+        mut self,
+        options: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        for (key, value) in options {
+            let key = key.into().to_lowercase();
+            let value = value.into();
+            match key.as_str() {
+                "quote" => self.dialect.quote = parse_char(&key, &value)?,
+                "escape" => self.dialect.escape = Some(parse_char(&key, &value)?),
+                "terminator" => self.dialect.terminator = Some(parse_char(&key, &value)?),
+                "comment" => self.dialect.comment = Some(parse_char(&key, &value)?),
+                "null_regex" => self.dialect.null_regex = Some(value),
+                "malformed" => self.dialect.malformed_mode = value.parse()?,
+                _ => {
+                    return Err(DataFusionError::Plan(format!(
+                        "Unknown CSV option '{key}', expected one of {}",
+                        CSV_OPTIONS.join(", ")
+                    )))
+                }
+            }
+        }
+        // reject invalid regular expressions when the table is created
+        self.dialect.compile_null_regex()?;
+        Ok(self)
+    }
+}
+
+/// Parses the value of a CSV option that is a single character, such as
+/// `'` or an escaped `\t`
+fn parse_char(key: &str, value: &str) -> Result<u8> {
+    match value {
+        "\\t" => Ok(b'\t'),
+        "\\n" => Ok(b'\n'),
+        "\\r" => Ok(b'\r'),
+        _ if value.len() == 1 => Ok(value.as_bytes()[0]),
+        _ => Err(DataFusionError::Plan(format!(
+            "CSV option '{key}' must be a single ASCII character, found '{value}'"
+        ))),
+    }
 }
 
 #[async_trait]
@@ -136,7 +257,14 @@ impl FileFormat for CsvFormat {
             }
         }
 
-        let merged_schema = Schema::try_merge(schemas)?;
+        let mut merged_schema = Schema::try_merge(schemas)?;
+        if self.dialect.malformed_mode == CsvMalformedMode::Capture
+            && merged_schema.index_of(MALFORMED_ROW_COLUMN).is_err()
+        {
+            let mut fields = merged_schema.fields().clone();
+            fields.push(Field::new(MALFORMED_ROW_COLUMN, DataType::Utf8, true));
+            merged_schema = Schema::new_with_metadata(fields, merged_schema.metadata);
+        }
         Ok(Arc::new(merged_schema))
     }
 
@@ -161,7 +289,8 @@ impl FileFormat for CsvFormat {
             self.has_header,
             self.delimiter,
             self.file_compression_type.to_owned(),
-        );
+        )
+        .with_dialect(self.dialect.clone());
         Ok(Arc::new(exec))
     }
 }
@@ -202,13 +331,37 @@ impl CsvFormat {
         let mut column_type_possibilities = vec![];
         let mut first_chunk = true;
 
+        // files of other dialects than the default one are rewritten as
+        // comma separated values with standard quoting
+        let dialect = if self.dialect != CsvDialect::default() {
+            let parser = RecordParser::new(self.delimiter, &self.dialect);
+            Some((parser, self.dialect.compile_null_regex()?))
+        } else {
+            None
+        };
+
         pin_mut!(stream);
 
         while let Some(chunk) = stream.next().await.transpose()? {
+            let mut reader = self.file_compression_type.convert_read(chunk.reader())?;
+            let (reader, delimiter): (Box<dyn Read + Send>, u8) = match &dialect {
+                Some((parser, null_regex)) => {
+                    let mut data = vec![];
+                    reader.read_to_end(&mut data)?;
+                    let data = to_standard_csv(
+                        parser,
+                        &self.dialect,
+                        null_regex.as_ref(),
+                        &data,
+                    );
+                    (Box::new(Cursor::new(data)), b',')
+                }
+                None => (reader, self.delimiter),
+            };
             let (Schema { fields, .. }, records_read) =
                 arrow::csv::reader::infer_reader_schema(
-                    self.file_compression_type.convert_read(chunk.reader())?,
-                    self.delimiter,
+                    reader,
+                    delimiter,
                     Some(records_to_read),
                     // only consider header for first chunk
                     self.has_header && first_chunk,
@@ -15,17 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Line delimited JSON format abstractions
+//! JSON format abstractions, for files of line delimited JSON objects or
+//! holding a top-level array of JSON objects
 
 use std::any::Any;
 
-use std::io::BufReader;
+use std::io::Read;
 use std::sync::Arc;
 
-use arrow::datatypes::Schema;
 use arrow::datatypes::SchemaRef;
-use arrow::json::reader::infer_json_schema_from_iterator;
-use arrow::json::reader::ValueIter;
 use async_trait::async_trait;
 use bytes::Buf;
 
@@ -36,18 +34,32 @@ use super::FileFormat;
 use super::FileScanConfig;
 use crate::datasource::file_format::file_type::FileCompressionType;
 use crate::datasource::file_format::DEFAULT_SCHEMA_INFER_MAX_RECORD;
-use crate::error::Result;
+use crate::error::{DataFusionError, Result};
 use crate::execution::context::SessionState;
-use crate::physical_plan::file_format::NdJsonExec;
+use crate::physical_plan::file_format::{
+    JsonSchemaInference, JsonValueDecoder, NdJsonExec,
+};
 use crate::physical_plan::ExecutionPlan;
 use crate::physical_plan::Statistics;
 
 /// The default file extension of json files
 pub const DEFAULT_JSON_EXTENSION: &str = ".json";
-/// New line delimited JSON `FileFormat` implementation.
+
+/// The `OPTIONS` of a `CREATE EXTERNAL TABLE ... STORED AS JSON` statement,
+/// see [`JsonFormat::with_options`]
+pub const JSON_OPTIONS: &[&str] = &["schema_infer_max_records", "schema_infer_max_depth"];
+
+/// JSON `FileFormat` implementation, for files of new line delimited JSON
+/// objects or holding a top-level array of JSON objects.
+///
+/// The inferred schema has struct and list fields for the nested objects and
+/// arrays. Fields with values of conflicting types, e.g. numbers and strings,
+/// and the values nested deeper than the maximum inference depth are read as
+/// `Utf8` columns holding the JSON text of the values.
 #[derive(Debug)]
 pub struct JsonFormat {
     schema_infer_max_rec: Option<usize>,
+    schema_infer_max_depth: Option<usize>,
     file_compression_type: FileCompressionType,
 }
 
@@ -55,6 +67,7 @@ impl Default for JsonFormat {
     fn default() -> Self {
         Self {
             schema_infer_max_rec: Some(DEFAULT_SCHEMA_INFER_MAX_RECORD),
+            schema_infer_max_depth: None,
             file_compression_type: FileCompressionType::UNCOMPRESSED,
         }
     }
@@ -68,6 +81,15 @@ impl JsonFormat {
         self
     }
 
+    /// Set the maximum depth of the nested structs and lists of the inferred
+    /// schema, the columns being the first level. Deeper values are read as
+    /// their JSON text
+    /// - defaults to no limit
+    pub fn with_schema_infer_max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.schema_infer_max_depth = max_depth;
+        self
+    }
+
     /// Set a `FileCompressionType` of JSON
     /// - defaults to `FileCompressionType::UNCOMPRESSED`
     pub fn with_file_compression_type(
@@ -77,6 +99,67 @@ impl JsonFormat {
         self.file_compression_type = file_compression_type;
         self
     }
+
+    /// Set the schema inference limits from the `OPTIONS` of a `CREATE
+    /// EXTERNAL TABLE` statement. The keys are the names in [`JSON_OPTIONS`],
+    /// the values are numbers.
+    ///
+    /// Returns an error for unknown keys and invalid values
+    pub fn with_options<K, V>(
+        mut self,
+        options: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        for (key, value) in options {
+            let key = key.into().to_lowercase();
+            let value = value.into();
+            let limit = value.parse::<usize>().map_err(|_| {
+                DataFusionError::Plan(format!(
+                    "Invalid value '{value}' for JSON option '{key}', expected a number"
+                ))
+            });
+            match key.as_str() {
+                "schema_infer_max_records" => self.schema_infer_max_rec = Some(limit?),
+                "schema_infer_max_depth" => self.schema_infer_max_depth = Some(limit?),
+                _ => {
+                    return Err(DataFusionError::Plan(format!(
+                        "Unknown JSON option '{key}', expected one of {}",
+                        JSON_OPTIONS.join(", ")
+                    )))
+                }
+            }
+        }
+        Ok(self)
+    }
+}
+
+/// Infers the schema of the values read from `reader`, up to `records_to_read`
+fn infer_values(
+    mut reader: impl Read,
+    inference: &mut JsonSchemaInference,
+    records_to_read: &mut usize,
+) -> Result<()> {
+    let mut decoder = JsonValueDecoder::default();
+    let mut values = vec![];
+    let mut buf = vec![0; 64 * 1024];
+    while *records_to_read > 0 {
+        let read = reader.read(&mut buf)?;
+        if read == 0 {
+            return decoder.finish();
+        }
+        decoder.decode(&buf[..read], &mut values)?;
+        for value in values.drain(..) {
+            if *records_to_read == 0 {
+                break;
+            }
+            inference.push(&value)?;
+            *records_to_read -= 1;
+        }
+    }
+    Ok(())
 }
 
 #[async_trait]
@@ -91,42 +174,28 @@ impl FileFormat for JsonFormat {
         store: &Arc<dyn ObjectStore>,
         objects: &[ObjectMeta],
     ) -> Result<SchemaRef> {
-        let mut schemas = Vec::new();
+        let mut inference = JsonSchemaInference::new(self.schema_infer_max_depth);
         let mut records_to_read = self.schema_infer_max_rec.unwrap_or(usize::MAX);
         let file_compression_type = self.file_compression_type.to_owned();
         for object in objects {
-            let mut take_while = || {
-                let should_take = records_to_read > 0;
-                if should_take {
-                    records_to_read -= 1;
-                }
-                should_take
-            };
-
-            let schema = match store.get(&object.location).await? {
+            match store.get(&object.location).await? {
                 GetResult::File(file, _) => {
-                    let decoder = file_compression_type.convert_read(file)?;
-                    let mut reader = BufReader::new(decoder);
-                    let iter = ValueIter::new(&mut reader, None);
-                    infer_json_schema_from_iterator(iter.take_while(|_| take_while()))?
+                    let reader = file_compression_type.convert_read(file)?;
+                    infer_values(reader, &mut inference, &mut records_to_read)?
                 }
                 r @ GetResult::Stream(_) => {
                     let data = r.bytes().await?;
-                    let decoder = file_compression_type.convert_read(data.reader())?;
-                    let mut reader = BufReader::new(decoder);
-                    let iter = ValueIter::new(&mut reader, None);
-                    infer_json_schema_from_iterator(iter.take_while(|_| take_while()))?
+                    let reader = file_compression_type.convert_read(data.reader())?;
+                    infer_values(reader, &mut inference, &mut records_to_read)?
                 }
             };
 
-            schemas.push(schema);
             if records_to_read == 0 {
                 break;
             }
         }
 
-        let schema = Schema::try_merge(schemas)?;
-        Ok(Arc::new(schema))
+        Ok(Arc::new(inference.finish()))
     }
 
     async fn infer_stats(
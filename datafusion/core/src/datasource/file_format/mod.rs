@@ -20,6 +20,7 @@
 /// Default max records to scan to infer the schema
 pub const DEFAULT_SCHEMA_INFER_MAX_RECORD: usize = 1000;
 
+pub mod arrow;
 pub mod avro;
 pub mod csv;
 pub mod file_type;
@@ -31,7 +32,7 @@ use std::any::Any;
 use std::fmt;
 use std::sync::Arc;
 
-use crate::arrow::datatypes::SchemaRef;
+use crate::arrow::datatypes::{Schema, SchemaRef};
 use crate::error::Result;
 use crate::physical_plan::file_format::FileScanConfig;
 use crate::physical_plan::{ExecutionPlan, Statistics};
@@ -63,6 +64,28 @@ pub trait FileFormat: Send + Sync + fmt::Debug {
         objects: &[ObjectMeta],
     ) -> Result<SchemaRef>;
 
+    /// Infer the schema of each of the provided objects, to be merged with
+    /// [`merge_schemas`] into a schema whose columns may have been widened or
+    /// renamed between the files. The default implementation infers the
+    /// schema of each object with [`FileFormat::infer_schema`].
+    ///
+    /// [`merge_schemas`]: crate::physical_plan::file_format::merge_schemas
+    async fn infer_file_schemas(
+        &self,
+        state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> Result<Vec<Schema>> {
+        let mut schemas = Vec::with_capacity(objects.len());
+        for object in objects {
+            let schema = self
+                .infer_schema(state, store, std::slice::from_ref(object))
+                .await?;
+            schemas.push(schema.as_ref().clone());
+        }
+        Ok(schemas)
+    }
+
     /// Infer the statistics for the provided object. The cost and accuracy of the
     /// estimated statistics might vary greatly between file formats.
     ///
@@ -86,6 +109,13 @@ pub trait FileFormat: Send + Sync + fmt::Debug {
         conf: FileScanConfig,
         filters: Option<&Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn ExecutionPlan>>;
+
+    /// Returns true if the execution plans of this format honor the
+    /// [`FileScanConfig::nested_projection`], reading only some of the
+    /// nested fields of struct columns
+    fn supports_nested_projection(&self) -> bool {
+        false
+    }
 }
 
 #[cfg(test)]
@@ -96,6 +126,7 @@ pub(crate) mod test_util {
     use super::*;
     use crate::datasource::listing::PartitionedFile;
     use crate::datasource::object_store::ObjectStoreUrl;
+    use crate::physical_plan::file_format::SchemaEvolution;
     use crate::test::object_store::local_unpartitioned_file;
     use bytes::Bytes;
     use futures::stream::BoxStream;
@@ -138,10 +169,12 @@ pub(crate) mod test_util {
                     file_groups,
                     statistics,
                     projection,
+                    nested_projection: None,
                     limit,
                     table_partition_cols: vec![],
                     output_ordering: None,
                     infinite_source: false,
+                    schema_evolution: SchemaEvolution::Strict,
                 },
                 None,
             )
@@ -23,6 +23,7 @@ use arrow::datatypes::{DataType, Schema, SchemaRef};
 use async_trait::async_trait;
 use datafusion_common::DataFusionError;
 
+use crate::datasource::file_format::arrow::DEFAULT_ARROW_EXTENSION;
 use crate::datasource::file_format::avro::DEFAULT_AVRO_EXTENSION;
 use crate::datasource::file_format::csv::DEFAULT_CSV_EXTENSION;
 use crate::datasource::file_format::file_type::FileCompressionType;
@@ -32,12 +33,14 @@ use crate::datasource::file_format::DEFAULT_SCHEMA_INFER_MAX_RECORD;
 use crate::datasource::listing::ListingTableUrl;
 use crate::datasource::{
     file_format::{
-        avro::AvroFormat, csv::CsvFormat, json::JsonFormat, parquet::ParquetFormat,
+        arrow::ArrowFormat, avro::AvroFormat, csv::CsvFormat, json::JsonFormat,
+        parquet::ParquetFormat,
     },
     listing::ListingOptions,
 };
 use crate::error::Result;
 use crate::execution::context::{SessionConfig, SessionState};
+use crate::physical_plan::file_format::CsvMalformedMode;
 
 /// Options that control the reading of CSV files.
 ///
@@ -54,6 +57,21 @@ pub struct CsvReadOptions<'a> {
     pub has_header: bool,
     /// An optional column delimiter. Defaults to `b','`.
     pub delimiter: u8,
+    /// The character quoting values. Defaults to `b'"'`.
+    pub quote: u8,
+    /// An optional character escaping the next character within quoted
+    /// values. Quotes are escaped by doubling them if `None`.
+    pub escape: Option<u8>,
+    /// An optional character terminating rows. Rows are terminated by
+    /// `\n` or `\r\n` if `None`.
+    pub terminator: Option<u8>,
+    /// An optional character starting comment lines, which are ignored.
+    pub comment: Option<u8>,
+    /// An optional regular expression matching the values read as null,
+    /// such as `\\N`.
+    pub null_regex: Option<&'a str>,
+    /// How to handle malformed rows. Defaults to failing the scan.
+    pub malformed_mode: CsvMalformedMode,
     /// An optional schema representing the CSV files. If None, CSV reader will try to infer it
     /// based on data in file.
     pub schema: Option<&'a Schema>,
@@ -84,6 +102,12 @@ impl<'a> CsvReadOptions<'a> {
             schema: None,
             schema_infer_max_records: DEFAULT_SCHEMA_INFER_MAX_RECORD,
             delimiter: b',',
+            quote: b'"',
+            escape: None,
+            terminator: None,
+            comment: None,
+            null_regex: None,
+            malformed_mode: CsvMalformedMode::Fail,
             file_extension: DEFAULT_CSV_EXTENSION,
             table_partition_cols: vec![],
             file_compression_type: FileCompressionType::UNCOMPRESSED,
@@ -109,6 +133,42 @@ impl<'a> CsvReadOptions<'a> {
         self
     }
 
+    /// Specify the quote character to use for CSV read
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Specify the escape character to use for CSV read
+    pub fn escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Specify the row terminator to use for CSV read
+    pub fn terminator(mut self, terminator: u8) -> Self {
+        self.terminator = Some(terminator);
+        self
+    }
+
+    /// Specify the character starting comment lines to use for CSV read
+    pub fn comment(mut self, comment: u8) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Specify a regular expression matching the values to read as null
+    pub fn null_regex(mut self, null_regex: &'a str) -> Self {
+        self.null_regex = Some(null_regex);
+        self
+    }
+
+    /// Configure how to handle malformed rows
+    pub fn malformed_mode(mut self, malformed_mode: CsvMalformedMode) -> Self {
+        self.malformed_mode = malformed_mode;
+        self
+    }
+
     /// Specify the file extension for CSV file selection
     pub fn file_extension(mut self, file_extension: &'a str) -> Self {
         self.file_extension = file_extension;
@@ -268,6 +328,51 @@ impl<'a> AvroReadOptions<'a> {
     }
 }
 
+/// Options that control the reading of Arrow IPC files and streams.
+///
+/// Note this structure is supplied when a datasource is created and
+/// can not not vary from statement to statement. For settings that
+/// can vary statement to statement see
+/// [`ConfigOptions`](crate::config::ConfigOptions).
+#[derive(Clone)]
+pub struct ArrowReadOptions<'a> {
+    /// The data source schema.
+    pub schema: Option<&'a Schema>,
+
+    /// File extension; only files with this extension are selected for data input.
+    /// Defaults to `FileType::ARROW.get_ext().as_str()`.
+    pub file_extension: &'a str,
+    /// Partition Columns
+    pub table_partition_cols: Vec<(String, DataType)>,
+}
+
+impl<'a> Default for ArrowReadOptions<'a> {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            file_extension: DEFAULT_ARROW_EXTENSION,
+            table_partition_cols: vec![],
+        }
+    }
+}
+
+impl<'a> ArrowReadOptions<'a> {
+    /// Specify table_partition_cols for partition pruning
+    pub fn table_partition_cols(
+        mut self,
+        table_partition_cols: Vec<(String, DataType)>,
+    ) -> Self {
+        self.table_partition_cols = table_partition_cols;
+        self
+    }
+
+    /// Specify schema to use for Arrow read
+    pub fn schema(mut self, schema: &'a Schema) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+}
+
 /// Options that control the reading of Line-delimited JSON files (NDJson)
 ///
 /// Note this structure is supplied when a datasource is created and
@@ -280,6 +385,9 @@ pub struct NdJsonReadOptions<'a> {
     pub schema: Option<&'a Schema>,
     /// Max number of rows to read from JSON files for schema inference if needed. Defaults to `DEFAULT_SCHEMA_INFER_MAX_RECORD`.
     pub schema_infer_max_records: usize,
+    /// Max depth of the nested structs and lists inferred from JSON files, deeper values are read as their JSON text.
+    /// Defaults to no limit.
+    pub schema_infer_max_depth: Option<usize>,
     /// File extension; only files with this extension are selected for data input.
     /// Defaults to `FileType::JSON.get_ext().as_str()`.
     pub file_extension: &'a str,
@@ -296,6 +404,7 @@ impl<'a> Default for NdJsonReadOptions<'a> {
         Self {
             schema: None,
             schema_infer_max_records: DEFAULT_SCHEMA_INFER_MAX_RECORD,
+            schema_infer_max_depth: None,
             file_extension: DEFAULT_JSON_EXTENSION,
             table_partition_cols: vec![],
             file_compression_type: FileCompressionType::UNCOMPRESSED,
@@ -340,6 +449,18 @@ impl<'a> NdJsonReadOptions<'a> {
         self.schema = Some(schema);
         self
     }
+
+    /// Configure number of max records to read for schema inference
+    pub fn schema_infer_max_records(mut self, max_records: usize) -> Self {
+        self.schema_infer_max_records = max_records;
+        self
+    }
+
+    /// Configure the max depth of the nested structs and lists of the inferred schema
+    pub fn schema_infer_max_depth(mut self, max_depth: usize) -> Self {
+        self.schema_infer_max_depth = Some(max_depth);
+        self
+    }
 }
 
 #[async_trait]
@@ -388,6 +509,12 @@ impl ReadOptions<'_> for CsvReadOptions<'_> {
         let file_format = CsvFormat::default()
             .with_has_header(self.has_header)
             .with_delimiter(self.delimiter)
+            .with_quote(self.quote)
+            .with_escape(self.escape)
+            .with_terminator(self.terminator)
+            .with_comment(self.comment)
+            .with_null_regex(self.null_regex.map(str::to_string))
+            .with_malformed_mode(self.malformed_mode)
             .with_schema_infer_max_rec(Some(self.schema_infer_max_records))
             .with_file_compression_type(self.file_compression_type.to_owned());
 
@@ -442,6 +569,8 @@ impl ReadOptions<'_> for ParquetReadOptions<'_> {
 impl ReadOptions<'_> for NdJsonReadOptions<'_> {
     fn to_listing_options(&self, config: &SessionConfig) -> ListingOptions {
         let file_format = JsonFormat::default()
+            .with_schema_infer_max_rec(Some(self.schema_infer_max_records))
+            .with_schema_infer_max_depth(self.schema_infer_max_depth)
             .with_file_compression_type(self.file_compression_type.to_owned());
 
         ListingOptions::new(Arc::new(file_format))
@@ -462,6 +591,28 @@ impl ReadOptions<'_> for NdJsonReadOptions<'_> {
     }
 }
 
+#[async_trait]
+impl ReadOptions<'_> for ArrowReadOptions<'_> {
+    fn to_listing_options(&self, config: &SessionConfig) -> ListingOptions {
+        let file_format = ArrowFormat::default();
+
+        ListingOptions::new(Arc::new(file_format))
+            .with_file_extension(self.file_extension)
+            .with_target_partitions(config.target_partitions())
+            .with_table_partition_cols(self.table_partition_cols.clone())
+    }
+
+    async fn get_resolved_schema(
+        &self,
+        config: &SessionConfig,
+        state: SessionState,
+        table_path: ListingTableUrl,
+    ) -> Result<SchemaRef> {
+        self._get_resolved_schema(config, state, table_path, self.schema, false)
+            .await
+    }
+}
+
 #[async_trait]
 impl ReadOptions<'_> for AvroReadOptions<'_> {
     fn to_listing_options(&self, config: &SessionConfig) -> ListingOptions {
@@ -18,6 +18,7 @@
 //! Parquet format abstractions
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use arrow::datatypes::Schema;
@@ -31,6 +32,7 @@ use object_store::{ObjectMeta, ObjectStore};
 use parquet::arrow::parquet_to_arrow_schema;
 use parquet::file::footer::{decode_footer, decode_metadata};
 use parquet::file::metadata::ParquetMetaData;
+use parquet::file::properties::WriterProperties;
 use parquet::file::statistics::Statistics as ParquetStatistics;
 
 use super::FileFormat;
@@ -41,16 +43,33 @@ use crate::arrow::array::{
 use crate::arrow::datatypes::{DataType, Field};
 use crate::config::ConfigOptions;
 
+use crate::datasource::object_store::ObjectStoreUrl;
 use crate::datasource::{create_max_min_accs, get_col_stats};
 use crate::error::Result;
+use crate::execution::cache::FileMetadataCache;
 use crate::execution::context::SessionState;
 use crate::physical_plan::expressions::{MaxAccumulator, MinAccumulator};
-use crate::physical_plan::file_format::{ParquetExec, SchemaAdapter};
+use crate::physical_plan::file_format::{
+    parquet_writer_properties, ParquetExec, SchemaAdapter, PARQUET_FIELD_ID_META_KEY,
+};
 use crate::physical_plan::{Accumulator, ExecutionPlan, Statistics};
 
 /// The default file extension of parquet files
 pub const DEFAULT_PARQUET_EXTENSION: &str = ".parquet";
 
+/// The `datafusion.execution.parquet.*` options that can be overridden for
+/// the files written to a table, see [`ParquetFormat::with_writer_options`]
+pub const PARQUET_WRITER_OPTIONS: &[&str] = &[
+    "compression",
+    "column_compression",
+    "max_row_group_size",
+    "data_pagesize_limit",
+    "dictionary_enabled",
+    "statistics_enabled",
+    "bloom_filter_columns",
+    "created_by",
+];
+
 /// The Apache Parquet `FileFormat` implementation
 ///
 /// Note it is recommended these are instead configured on the [`ConfigOptions`]
@@ -66,6 +85,8 @@ pub struct ParquetFormat {
     metadata_size_hint: Option<usize>,
     /// Override the global setting for `skip_metadata`
     skip_metadata: Option<bool>,
+    /// Override the global parquet writer options, keyed by option name
+    writer_options: BTreeMap<String, String>,
 }
 
 impl ParquetFormat {
@@ -120,6 +141,53 @@ impl ParquetFormat {
         self.skip_metadata
             .unwrap_or(config_options.execution.parquet.skip_metadata)
     }
+
+    /// Override the parquet writer options of `config_options` for files
+    /// written to this table, e.g. from the `OPTIONS` of a
+    /// `CREATE EXTERNAL TABLE` statement. The keys are the names in
+    /// [`PARQUET_WRITER_OPTIONS`], such as `compression` for
+    /// `datafusion.execution.parquet.compression`.
+    ///
+    /// DataFusion does not write to tables itself, the options are applied
+    /// by writers that use [`Self::writer_properties`], for instance
+    /// with [`DataFrame::write_parquet`](crate::dataframe::DataFrame::write_parquet).
+    ///
+    /// Returns an error for unknown keys
+    pub fn with_writer_options<K, V>(
+        mut self,
+        options: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        for (key, value) in options {
+            let key = key.into().to_lowercase();
+            if !PARQUET_WRITER_OPTIONS.contains(&key.as_str()) {
+                return Err(DataFusionError::Plan(format!(
+                    "Unknown parquet writer option '{key}', expected one of {}",
+                    PARQUET_WRITER_OPTIONS.join(", ")
+                )));
+            }
+            self.writer_options.insert(key, value.into());
+        }
+        Ok(self)
+    }
+
+    /// Return the [`WriterProperties`] for files written to this table
+    pub fn writer_properties(
+        &self,
+        config_options: &ConfigOptions,
+    ) -> Result<WriterProperties> {
+        if self.writer_options.is_empty() {
+            return parquet_writer_properties(&config_options.execution.parquet);
+        }
+        let mut config_options = config_options.clone();
+        for (key, value) in &self.writer_options {
+            config_options.set(&format!("datafusion.execution.parquet.{key}"), value)?;
+        }
+        parquet_writer_properties(&config_options.execution.parquet)
+    }
 }
 
 /// Clears all metadata (Schema level and field level) on an iterator
@@ -139,6 +207,25 @@ fn clear_metadata(
     })
 }
 
+/// Clears the metadata of the schema like [`clear_metadata`], except for the
+/// field ids of its fields, which are needed to match renamed columns
+fn clear_metadata_except_field_ids(schema: Schema) -> Schema {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let metadata = field
+                .metadata()
+                .iter()
+                .filter(|(key, _)| key.as_str() == PARQUET_FIELD_ID_META_KEY)
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            field.clone().with_metadata(metadata)
+        })
+        .collect::<Vec<_>>();
+    Schema::new(fields)
+}
+
 #[async_trait]
 impl FileFormat for ParquetFormat {
     fn as_any(&self) -> &dyn Any {
@@ -151,10 +238,13 @@ impl FileFormat for ParquetFormat {
         store: &Arc<dyn ObjectStore>,
         objects: &[ObjectMeta],
     ) -> Result<SchemaRef> {
+        let cache = metadata_cache(state, store);
+        let cache = cache.as_ref().map(|(url, cache)| (url, cache.as_ref()));
         let mut schemas = Vec::with_capacity(objects.len());
         for object in objects {
             let schema =
-                fetch_schema(store.as_ref(), object, self.metadata_size_hint).await?;
+                fetch_schema(store.as_ref(), object, self.metadata_size_hint, cache)
+                    .await?;
             schemas.push(schema)
         }
 
@@ -167,18 +257,43 @@ impl FileFormat for ParquetFormat {
         Ok(Arc::new(schema))
     }
 
+    async fn infer_file_schemas(
+        &self,
+        state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> Result<Vec<Schema>> {
+        let cache = metadata_cache(state, store);
+        let cache = cache.as_ref().map(|(url, cache)| (url, cache.as_ref()));
+        let skip_metadata = self.skip_metadata(state.config_options());
+        let mut schemas = Vec::with_capacity(objects.len());
+        for object in objects {
+            let schema =
+                fetch_schema(store.as_ref(), object, self.metadata_size_hint, cache)
+                    .await?;
+            schemas.push(if skip_metadata {
+                clear_metadata_except_field_ids(schema)
+            } else {
+                schema
+            })
+        }
+        Ok(schemas)
+    }
+
     async fn infer_stats(
         &self,
-        _state: &SessionState,
+        state: &SessionState,
         store: &Arc<dyn ObjectStore>,
         table_schema: SchemaRef,
         object: &ObjectMeta,
     ) -> Result<Statistics> {
+        let cache = metadata_cache(state, store);
         let stats = fetch_statistics(
             store.as_ref(),
             table_schema,
             object,
             self.metadata_size_hint,
+            cache.as_ref().map(|(url, cache)| (url, cache.as_ref())),
         )
         .await?;
         Ok(stats)
@@ -204,6 +319,10 @@ impl FileFormat for ParquetFormat {
             self.metadata_size_hint(state.config_options()),
         )))
     }
+
+    fn supports_nested_projection(&self) -> bool {
+        true
+    }
 }
 
 fn summarize_min_max(
@@ -437,13 +556,53 @@ pub async fn fetch_parquet_metadata(
     }
 }
 
+/// Fetches parquet metadata from ObjectStore for given object, returning it
+/// from `cache` if it has been fetched before. `cache` is the cache to use
+/// along with the url of `store`
+pub(crate) async fn fetch_parquet_metadata_cached(
+    store: &dyn ObjectStore,
+    meta: &ObjectMeta,
+    size_hint: Option<usize>,
+    cache: Option<(&ObjectStoreUrl, &dyn FileMetadataCache)>,
+) -> Result<Arc<ParquetMetaData>> {
+    let (store_url, cache) = match cache {
+        Some(cache) => cache,
+        None => {
+            return Ok(Arc::new(
+                fetch_parquet_metadata(store, meta, size_hint).await?,
+            ))
+        }
+    };
+    if let Some(metadata) = cache.get_parquet_metadata(store_url, meta) {
+        return Ok(metadata);
+    }
+
+    let metadata = Arc::new(fetch_parquet_metadata(store, meta, size_hint).await?);
+    cache.put_parquet_metadata(store_url, meta, metadata.clone());
+    Ok(metadata)
+}
+
+/// Returns the file metadata cache of the runtime of `state` along with the
+/// url of `store`, if the runtime has a cache and `store` is registered on it
+fn metadata_cache(
+    state: &SessionState,
+    store: &Arc<dyn ObjectStore>,
+) -> Option<(ObjectStoreUrl, Arc<dyn FileMetadataCache>)> {
+    let runtime = state.runtime_env();
+    let cache = runtime.file_metadata_cache.clone()?;
+    let store_url = runtime.object_store_registry.get_url(store)?;
+    Some((store_url, cache))
+}
+
 /// Read and parse the schema of the Parquet file at location `path`
 async fn fetch_schema(
     store: &dyn ObjectStore,
     file: &ObjectMeta,
     metadata_size_hint: Option<usize>,
+    cache: Option<(&ObjectStoreUrl, &dyn FileMetadataCache)>,
 ) -> Result<Schema> {
-    let metadata = fetch_parquet_metadata(store, file, metadata_size_hint).await?;
+    let metadata =
+        fetch_parquet_metadata_cached(store, file, metadata_size_hint, cache).await?;
     let file_metadata = metadata.file_metadata();
     let schema = parquet_to_arrow_schema(
         file_metadata.schema_descr(),
@@ -458,8 +617,10 @@ async fn fetch_statistics(
     table_schema: SchemaRef,
     file: &ObjectMeta,
     metadata_size_hint: Option<usize>,
+    cache: Option<(&ObjectStoreUrl, &dyn FileMetadataCache)>,
 ) -> Result<Statistics> {
-    let metadata = fetch_parquet_metadata(store, file, metadata_size_hint).await?;
+    let metadata =
+        fetch_parquet_metadata_cached(store, file, metadata_size_hint, cache).await?;
     let file_metadata = metadata.file_metadata();
 
     let file_schema = parquet_to_arrow_schema(
@@ -613,36 +774,29 @@ pub(crate) mod test_util {
 mod tests {
     use super::super::test_util::scan_format;
     use crate::physical_plan::collect;
-    use std::fmt::{Display, Formatter};
-    use std::ops::Range;
-    use std::sync::atomic::{AtomicUsize, Ordering};
 
     use super::*;
 
     use crate::datasource::file_format::parquet::test_util::store_parquet;
     use crate::physical_plan::metrics::MetricValue;
     use crate::prelude::{SessionConfig, SessionContext};
+    use crate::test::object_store::RequestCountingObjectStore;
     use arrow::array::{Array, ArrayRef, StringArray};
     use arrow::record_batch::RecordBatch;
-    use async_trait::async_trait;
-    use bytes::Bytes;
     use datafusion_common::cast::{
         as_binary_array, as_boolean_array, as_float32_array, as_float64_array,
         as_int32_array, as_timestamp_nanosecond_array,
     };
     use datafusion_common::ScalarValue;
-    use futures::stream::BoxStream;
     use futures::StreamExt;
     use log::error;
     use object_store::local::LocalFileSystem;
     use object_store::path::Path;
-    use object_store::{GetResult, ListResult, MultipartId};
     use parquet::arrow::arrow_reader::ArrowReaderOptions;
     use parquet::arrow::ParquetRecordBatchStreamBuilder;
     use parquet::file::metadata::{ParquetColumnIndex, ParquetOffsetIndex};
     use parquet::file::page_index::index::Index;
     use tokio::fs::File;
-    use tokio::io::AsyncWrite;
 
     #[tokio::test]
     async fn read_merged_batches() -> Result<()> {
@@ -663,7 +817,8 @@ mod tests {
         let schema = format.infer_schema(&ctx, &store, &meta).await.unwrap();
 
         let stats =
-            fetch_statistics(store.as_ref(), schema.clone(), &meta[0], None).await?;
+            fetch_statistics(store.as_ref(), schema.clone(), &meta[0], None, None)
+                .await?;
 
         assert_eq!(stats.num_rows, Some(3));
         let c1_stats = &stats.column_statistics.as_ref().expect("missing c1 stats")[0];
@@ -671,7 +826,8 @@ mod tests {
         assert_eq!(c1_stats.null_count, Some(1));
         assert_eq!(c2_stats.null_count, Some(3));
 
-        let stats = fetch_statistics(store.as_ref(), schema, &meta[1], None).await?;
+        let stats =
+            fetch_statistics(store.as_ref(), schema, &meta[1], None, None).await?;
         assert_eq!(stats.num_rows, Some(3));
         let c1_stats = &stats.column_statistics.as_ref().expect("missing c1 stats")[0];
         let c2_stats = &stats.column_statistics.as_ref().expect("missing c2 stats")[1];
@@ -683,106 +839,6 @@ mod tests {
         Ok(())
     }
 
-    #[derive(Debug)]
-    struct RequestCountingObjectStore {
-        inner: Arc<dyn ObjectStore>,
-        request_count: AtomicUsize,
-    }
-
-    impl Display for RequestCountingObjectStore {
-        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-            write!(f, "RequestCounting({})", self.inner)
-        }
-    }
-
-    impl RequestCountingObjectStore {
-        pub fn new(inner: Arc<dyn ObjectStore>) -> Self {
-            Self {
-                inner,
-                request_count: Default::default(),
-            }
-        }
-
-        pub fn request_count(&self) -> usize {
-            self.request_count.load(Ordering::SeqCst)
-        }
-
-        pub fn upcast(self: &Arc<Self>) -> Arc<dyn ObjectStore> {
-            self.clone()
-        }
-    }
-
-    #[async_trait]
-    impl ObjectStore for RequestCountingObjectStore {
-        async fn put(&self, _location: &Path, _bytes: Bytes) -> object_store::Result<()> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn put_multipart(
-            &self,
-            _location: &Path,
-        ) -> object_store::Result<(MultipartId, Box<dyn AsyncWrite + Unpin + Send>)>
-        {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn abort_multipart(
-            &self,
-            _location: &Path,
-            _multipart_id: &MultipartId,
-        ) -> object_store::Result<()> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn get(&self, _location: &Path) -> object_store::Result<GetResult> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn get_range(
-            &self,
-            location: &Path,
-            range: Range<usize>,
-        ) -> object_store::Result<Bytes> {
-            self.request_count.fetch_add(1, Ordering::SeqCst);
-            self.inner.get_range(location, range).await
-        }
-
-        async fn head(&self, _location: &Path) -> object_store::Result<ObjectMeta> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn delete(&self, _location: &Path) -> object_store::Result<()> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn list(
-            &self,
-            _prefix: Option<&Path>,
-        ) -> object_store::Result<BoxStream<'_, object_store::Result<ObjectMeta>>>
-        {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn list_with_delimiter(
-            &self,
-            _prefix: Option<&Path>,
-        ) -> object_store::Result<ListResult> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn copy(&self, _from: &Path, _to: &Path) -> object_store::Result<()> {
-            Err(object_store::Error::NotImplemented)
-        }
-
-        async fn copy_if_not_exists(
-            &self,
-            _from: &Path,
-            _to: &Path,
-        ) -> object_store::Result<()> {
-            Err(object_store::Error::NotImplemented)
-        }
-    }
-
     #[tokio::test]
     async fn fetch_metadata_with_size_hint() -> Result<()> {
         let c1: ArrayRef =
@@ -814,9 +870,14 @@ mod tests {
             .await
             .unwrap();
 
-        let stats =
-            fetch_statistics(store.upcast().as_ref(), schema.clone(), &meta[0], Some(9))
-                .await?;
+        let stats = fetch_statistics(
+            store.upcast().as_ref(),
+            schema.clone(),
+            &meta[0],
+            Some(9),
+            None,
+        )
+        .await?;
 
         assert_eq!(stats.num_rows, Some(3));
         let c1_stats = &stats.column_statistics.as_ref().expect("missing c1 stats")[0];
@@ -848,6 +909,7 @@ mod tests {
             schema.clone(),
             &meta[0],
             Some(size_hint),
+            None,
         )
         .await?;
 
@@ -17,26 +17,36 @@
 
 //! The table implementation.
 
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::{any::Any, sync::Arc};
 
 use arrow::compute::SortOptions;
-use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
 use async_trait::async_trait;
 use dashmap::DashMap;
+use datafusion_common::utils::project_nested_fields;
 use datafusion_common::ToDFSchema;
 use datafusion_expr::expr::Sort;
+use datafusion_expr::LogicalPlan;
 use datafusion_optimizer::utils::conjunction;
 use datafusion_physical_expr::{create_physical_expr, PhysicalSortExpr};
+use datafusion_sql::parser::{
+    CreateExternalTable as CreateExternalTableStatement, DFParser,
+    Statement as DFStatement,
+};
 use futures::{future, stream, StreamExt, TryStreamExt};
 use object_store::path::Path;
 use object_store::ObjectMeta;
+use sqlparser::ast::{
+    ColumnDef, ColumnOption, ColumnOptionDef, DataType as SQLDataType, Ident, ObjectName,
+};
 
 use crate::datasource::file_format::file_type::{FileCompressionType, FileType};
 use crate::datasource::{
     file_format::{
-        avro::AvroFormat, csv::CsvFormat, json::JsonFormat, parquet::ParquetFormat,
-        FileFormat,
+        arrow::ArrowFormat, avro::AvroFormat, csv::CsvFormat, json::JsonFormat,
+        parquet::ParquetFormat, FileFormat,
     },
     get_statistics_with_limit,
     listing::ListingTableUrl,
@@ -50,8 +60,9 @@ use crate::{
     execution::context::SessionState,
     logical_expr::Expr,
     physical_plan::{
-        empty::EmptyExec, file_format::FileScanConfig, project_schema, ExecutionPlan,
-        Statistics,
+        empty::EmptyExec,
+        file_format::{merge_schemas, FileScanConfig, SchemaEvolution},
+        project_schema, ExecutionPlan, Statistics,
     },
 };
 
@@ -136,6 +147,7 @@ impl ListingTableConfig {
             .map_err(|_| DataFusionError::Internal(err_msg))?;
 
         let file_format: Arc<dyn FileFormat> = match file_type {
+            FileType::ARROW => Arc::new(ArrowFormat::default()),
             FileType::AVRO => Arc::new(AvroFormat::default()),
             FileType::CSV => Arc::new(
                 CsvFormat::default().with_file_compression_type(file_compression_type),
@@ -240,6 +252,12 @@ pub struct ListingOptions {
     /// In order to support infinite inputs, DataFusion may adjust query
     /// plans (e.g. joins) to run the given query in full pipelining mode.
     pub infinite_source: bool,
+    /// How the schemas of the files may differ. With [`SchemaEvolution::Merge`]
+    /// the schema is inferred by merging the schemas of all the files, and the
+    /// columns of each file are adapted to the merged schema when it is read.
+    /// Column statistics are then not collected, as the columns of the files
+    /// may have other names and types than the columns of the table.
+    pub schema_evolution: SchemaEvolution,
 }
 
 impl ListingOptions {
@@ -258,6 +276,7 @@ impl ListingOptions {
             target_partitions: 1,
             file_sort_order: None,
             infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
         }
     }
 
@@ -383,6 +402,25 @@ impl ListingOptions {
         self
     }
 
+    /// Set the schema evolution mode on [`ListingOptions`] and returns self.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use datafusion::datasource::{listing::ListingOptions, file_format::parquet::ParquetFormat};
+    /// # use datafusion::physical_plan::file_format::SchemaEvolution;
+    ///
+    /// let listing_options = ListingOptions::new(Arc::new(
+    ///     ParquetFormat::default()
+    ///   ))
+    ///   .with_schema_evolution(SchemaEvolution::Merge);
+    ///
+    /// assert_eq!(listing_options.schema_evolution, SchemaEvolution::Merge);
+    /// ```
+    pub fn with_schema_evolution(mut self, schema_evolution: SchemaEvolution) -> Self {
+        self.schema_evolution = schema_evolution;
+        self
+    }
+
     /// Infer the schema of the files at the given path on the provided object store.
     /// The inferred schema does not include the partitioning columns.
     ///
@@ -396,12 +434,27 @@ impl ListingOptions {
     ) -> Result<SchemaRef> {
         let store = state.runtime_env().object_store(table_path)?;
 
-        let files: Vec<_> = table_path
+        let mut files: Vec<_> = table_path
             .list_all_files(store.as_ref(), &self.file_extension)
             .try_collect()
             .await?;
 
-        self.format.infer_schema(state, &store, &files).await
+        match self.schema_evolution {
+            SchemaEvolution::Strict => {
+                self.format.infer_schema(state, &store, &files).await
+            }
+            SchemaEvolution::Merge => {
+                // the columns renamed in later files take their latest name
+                files.sort_by(|a, b| {
+                    (a.last_modified, &a.location).cmp(&(b.last_modified, &b.location))
+                });
+                let schemas = self
+                    .format
+                    .infer_file_schemas(state, &store, &files)
+                    .await?;
+                Ok(Arc::new(merge_schemas(&schemas)?))
+            }
+        }
     }
 }
 
@@ -636,13 +689,225 @@ impl TableProvider for ListingTable {
         projection: Option<&Vec<usize>>,
         filters: &[Expr],
         limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.scan_files(state, projection, None, filters, limit)
+            .await
+    }
+
+    fn supports_nested_projection(&self) -> bool {
+        self.options.format.supports_nested_projection()
+    }
+
+    async fn scan_nested(
+        &self,
+        state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        nested_projection: &[Vec<String>],
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.scan_files(state, projection, Some(nested_projection), filters, limit)
+            .await
+    }
+
+    fn supports_filter_pushdown(
+        &self,
+        filter: &Expr,
+    ) -> Result<TableProviderFilterPushDown> {
+        if expr_applicable_for_cols(
+            &self
+                .options
+                .table_partition_cols
+                .iter()
+                .map(|x| x.0.clone())
+                .collect::<Vec<_>>(),
+            filter,
+        ) {
+            // if filter can be handled by partiton pruning, it is exact
+            Ok(TableProviderFilterPushDown::Exact)
+        } else {
+            // otherwise, we still might be able to handle the filter with file
+            // level mechanisms such as Parquet row group pruning.
+            Ok(TableProviderFilterPushDown::Inexact)
+        }
+    }
+
+    fn get_table_definition(&self) -> Option<&str> {
+        self.definition.as_deref()
+    }
+
+    /// Adds the column to the schema of the files, and to the columns of
+    /// the `CREATE EXTERNAL TABLE` definition of the table, if any. The
+    /// columns of a definition without columns are declared first, as the
+    /// schema of the table is no longer the schema inferred from its files.
+    async fn add_column(
+        &self,
+        _state: &SessionState,
+        field: Field,
+    ) -> Result<Arc<dyn TableProvider>> {
+        // the fields of a CSV row are read by position, so the files
+        // already written can not miss a column
+        if self.options.format.as_any().is::<CsvFormat>() {
+            return Err(DataFusionError::NotImplemented(format!(
+                "Can not add the column {} to a CSV table",
+                field.name()
+            )));
+        }
+        let definition = match &self.definition {
+            Some(definition) => {
+                let mut statement = parse_definition(definition)?;
+                if statement.columns.is_empty() {
+                    let partition_fields = self
+                        .options
+                        .table_partition_cols
+                        .iter()
+                        .map(|(name, data_type)| {
+                            Field::new(name, data_type.clone(), false)
+                        })
+                        .collect::<Vec<_>>();
+                    statement.columns = self
+                        .file_schema
+                        .fields()
+                        .iter()
+                        .chain(&partition_fields)
+                        .map(column_def)
+                        .collect::<Result<_>>()?;
+                }
+                statement.columns.push(column_def(&field)?);
+                Some(statement.to_string())
+            }
+            None => None,
+        };
+
+        let mut fields = self.file_schema.fields().clone();
+        fields.push(field);
+        let file_schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.file_schema.metadata().clone(),
+        ));
+        let config = ListingTableConfig::new_with_multi_paths(self.table_paths.clone())
+            .with_listing_options(self.options.clone())
+            .with_schema(file_schema);
+        Ok(Arc::new(
+            ListingTable::try_new(config)?.with_definition(definition),
+        ))
+    }
+
+    /// Sets the options of the `CREATE EXTERNAL TABLE` definition of the
+    /// table, and creates the table it defines
+    async fn set_options(
+        &self,
+        state: &SessionState,
+        options: &HashMap<String, String>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let definition = self.definition.as_ref().ok_or_else(|| {
+            DataFusionError::NotImplemented(
+                "Only the options of listing tables created by CREATE EXTERNAL TABLE \
+                 can be set"
+                    .to_string(),
+            )
+        })?;
+        let mut statement = parse_definition(definition)?;
+        statement.options.extend(options.clone());
+        match state.create_logical_plan(&statement.to_string()).await? {
+            LogicalPlan::CreateExternalTable(cmd) => {
+                state.create_custom_table(&cmd).await
+            }
+            plan => Err(DataFusionError::Internal(format!(
+                "Expected a CREATE EXTERNAL TABLE plan, got {}",
+                plan.display()
+            ))),
+        }
+    }
+}
+
+/// Parses the `CREATE EXTERNAL TABLE` statement of a table definition
+fn parse_definition(definition: &str) -> Result<CreateExternalTableStatement> {
+    match DFParser::parse_sql(definition)?.pop_front() {
+        Some(DFStatement::CreateExternalTable(statement)) => Ok(statement),
+        _ => Err(DataFusionError::Internal(format!(
+            "Invalid listing table definition: {definition}"
+        ))),
+    }
+}
+
+/// The SQL column definition of `field`, with the SQL type that is planned
+/// as the data type of the field
+fn column_def(field: &Field) -> Result<ColumnDef> {
+    let (type_name, modifiers) = match field.data_type() {
+        DataType::Boolean => ("BOOLEAN", vec![]),
+        DataType::Int8 => ("TINYINT", vec![]),
+        DataType::Int16 => ("SMALLINT", vec![]),
+        DataType::Int32 => ("INT", vec![]),
+        DataType::Int64 => ("BIGINT", vec![]),
+        DataType::UInt8 => ("TINYINT UNSIGNED", vec![]),
+        DataType::UInt16 => ("SMALLINT UNSIGNED", vec![]),
+        DataType::UInt32 => ("INT UNSIGNED", vec![]),
+        DataType::UInt64 => ("BIGINT UNSIGNED", vec![]),
+        DataType::Float32 => ("FLOAT", vec![]),
+        DataType::Float64 => ("DOUBLE", vec![]),
+        DataType::Utf8 => ("VARCHAR", vec![]),
+        DataType::Binary => ("BYTEA", vec![]),
+        DataType::Date32 => ("DATE", vec![]),
+        DataType::Time64(TimeUnit::Nanosecond) => ("TIME", vec![]),
+        DataType::Timestamp(unit, None) => {
+            let precision = match unit {
+                TimeUnit::Second => 0,
+                TimeUnit::Millisecond => 3,
+                TimeUnit::Microsecond => 6,
+                TimeUnit::Nanosecond => 9,
+            };
+            ("TIMESTAMP", vec![precision.to_string()])
+        }
+        DataType::Decimal128(precision, scale) => {
+            ("DECIMAL", vec![precision.to_string(), scale.to_string()])
+        }
+        other => {
+            return Err(DataFusionError::NotImplemented(format!(
+                "The column {} of type {other:?} can not be declared in SQL",
+                field.name()
+            )))
+        }
+    };
+    let options = if field.is_nullable() {
+        vec![ColumnOptionDef {
+            name: None,
+            option: ColumnOption::Null,
+        }]
+    } else {
+        vec![]
+    };
+    Ok(ColumnDef {
+        name: Ident::with_quote('"', field.name()),
+        data_type: SQLDataType::Custom(
+            ObjectName(vec![Ident::new(type_name)]),
+            modifiers,
+        ),
+        collation: None,
+        options,
+    })
+}
+
+impl ListingTable {
+    /// Create the execution plan scanning the files of the table, reading
+    /// struct columns with only the fields of `nested_projection`, if any
+    async fn scan_files(
+        &self,
+        state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        nested_projection: Option<&[Vec<String>]>,
+        filters: &[Expr],
+        limit: Option<usize>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
         let (partitioned_file_lists, statistics) =
             self.list_files_for_scan(state, filters, limit).await?;
 
         // if no files need to be read, return an `EmptyExec`
         if partitioned_file_lists.is_empty() {
-            let schema = self.schema();
+            let schema = match nested_projection {
+                Some(paths) => Arc::new(project_nested_fields(&self.schema(), paths)),
+                None => self.schema(),
+            };
             let projected_schema = project_schema(&schema, projection)?;
             return Ok(Arc::new(EmptyExec::new(false, projected_schema)));
         }
@@ -688,44 +953,18 @@ impl TableProvider for ListingTable {
                     file_groups: partitioned_file_lists,
                     statistics,
                     projection: projection.cloned(),
+                    nested_projection: nested_projection.map(<[_]>::to_vec),
                     limit,
                     output_ordering: self.try_create_output_ordering()?,
                     table_partition_cols,
                     infinite_source: self.infinite_source,
+                    schema_evolution: self.options.schema_evolution,
                 },
                 filters.as_ref(),
             )
             .await
     }
 
-    fn supports_filter_pushdown(
-        &self,
-        filter: &Expr,
-    ) -> Result<TableProviderFilterPushDown> {
-        if expr_applicable_for_cols(
-            &self
-                .options
-                .table_partition_cols
-                .iter()
-                .map(|x| x.0.clone())
-                .collect::<Vec<_>>(),
-            filter,
-        ) {
-            // if filter can be handled by partiton pruning, it is exact
-            Ok(TableProviderFilterPushDown::Exact)
-        } else {
-            // otherwise, we still might be able to handle the filter with file
-            // level mechanisms such as Parquet row group pruning.
-            Ok(TableProviderFilterPushDown::Inexact)
-        }
-    }
-
-    fn get_table_definition(&self) -> Option<&str> {
-        self.definition.as_deref()
-    }
-}
-
-impl ListingTable {
     /// Get the list of files for a scan as well as the file level statistics.
     /// The list is grouped to let the execution plan know how the files should
     /// be distributed to different threads / executors.
@@ -752,31 +991,52 @@ impl ListingTable {
 
         let file_list = stream::iter(file_list).flatten();
 
+        // the statistics cache of the runtime is shared by all tables,
+        // otherwise each table keeps the statistics it collected
+        let statistics_cache = ctx.runtime_env().file_statistics_cache.clone();
+        let store_url = self.table_paths.get(0).unwrap().object_store();
+
         // collect the statistics if required by the config
         let files = file_list.then(|part_file| async {
             let part_file = part_file?;
             let statistics = if self.options.collect_stat {
-                match self.collected_statistics.get(&part_file.object_meta) {
+                let meta = &part_file.object_meta;
+                let cached = match &statistics_cache {
+                    Some(cache) => cache.get(&store_url, meta, &self.file_schema),
+                    None => self.collected_statistics.get(meta),
+                };
+                match cached {
                     Some(statistics) => statistics,
                     None => {
                         let statistics = self
                             .options
                             .format
-                            .infer_stats(
-                                ctx,
-                                &store,
-                                self.file_schema.clone(),
-                                &part_file.object_meta,
-                            )
+                            .infer_stats(ctx, &store, self.file_schema.clone(), meta)
                             .await?;
-                        self.collected_statistics
-                            .save(part_file.object_meta.clone(), statistics.clone());
+                        match &statistics_cache {
+                            Some(cache) => cache.put(
+                                &store_url,
+                                meta,
+                                &self.file_schema,
+                                statistics.clone(),
+                            ),
+                            None => self
+                                .collected_statistics
+                                .save(meta.clone(), statistics.clone()),
+                        }
                         statistics
                     }
                 }
             } else {
                 Statistics::default()
             };
+            let statistics = match self.options.schema_evolution {
+                SchemaEvolution::Strict => statistics,
+                SchemaEvolution::Merge => Statistics {
+                    column_statistics: None,
+                    ..statistics
+                },
+            };
             Ok((part_file, statistics)) as Result<(PartitionedFile, Statistics)>
         });
 
@@ -1088,6 +1348,93 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn read_merged_schemas() -> Result<()> {
+        use arrow::array::{
+            ArrayRef, Float64Array, Int32Array, Int64Array, StringArray, StructArray,
+        };
+        use arrow::record_batch::RecordBatch;
+        use parquet::arrow::ArrowWriter;
+
+        // `id` is widened, `s` gains a field and `v` is added in the second file
+        let struct_field = |name: &str, array: ArrayRef| {
+            (Field::new(name, array.data_type().clone(), true), array)
+        };
+        let batches = vec![
+            RecordBatch::try_from_iter(vec![
+                ("id", Arc::new(Int32Array::from(vec![1])) as ArrayRef),
+                (
+                    "s",
+                    Arc::new(StructArray::from(vec![struct_field(
+                        "a",
+                        Arc::new(Int32Array::from(vec![10])),
+                    )])),
+                ),
+            ])?,
+            RecordBatch::try_from_iter(vec![
+                ("id", Arc::new(Int64Array::from(vec![2])) as ArrayRef),
+                (
+                    "s",
+                    Arc::new(StructArray::from(vec![
+                        struct_field("a", Arc::new(Int32Array::from(vec![20]))),
+                        struct_field("b", Arc::new(StringArray::from(vec!["x"]))),
+                    ])),
+                ),
+                ("v", Arc::new(Float64Array::from(vec![2.5]))),
+            ])?,
+        ];
+        let tmp_dir = TempDir::new()?;
+        for (i, batch) in batches.iter().enumerate() {
+            let file = File::create(tmp_dir.path().join(format!("{i}.parquet")))?;
+            let mut writer = ArrowWriter::try_new(file, batch.schema(), None)?;
+            writer.write(batch)?;
+            writer.close()?;
+        }
+
+        let ctx = SessionContext::new();
+        let state = ctx.state();
+        let table_path = ListingTableUrl::parse(tmp_dir.path().to_str().unwrap())?;
+
+        // the types of `id` can not be merged without schema evolution
+        let opt = ListingOptions::new(Arc::new(ParquetFormat::default()));
+        assert!(opt.infer_schema(&state, &table_path).await.is_err());
+
+        let opt = opt.with_schema_evolution(SchemaEvolution::Merge);
+        let schema = opt.infer_schema(&state, &table_path).await?;
+        let struct_type = DataType::Struct(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        assert_eq!(
+            schema.as_ref(),
+            &Schema::new(vec![
+                Field::new("id", DataType::Int64, true),
+                Field::new("s", struct_type, true),
+                Field::new("v", DataType::Float64, true),
+            ])
+        );
+
+        let config = ListingTableConfig::new(table_path)
+            .with_listing_options(opt)
+            .with_schema(schema);
+        ctx.register_table("t", Arc::new(ListingTable::try_new(config)?))?;
+        let batches = ctx
+            .sql("SELECT id, v, s FROM t ORDER BY id")
+            .await?
+            .collect()
+            .await?;
+        let expected = vec![
+            "+----+-----+---------------+",
+            "| id | v   | s             |",
+            "+----+-----+---------------+",
+            "| 1  |     | {a: 10, b: }  |",
+            "| 2  | 2.5 | {a: 20, b: x} |",
+            "+----+-----+---------------+",
+        ];
+        crate::assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn unbounded_avro_table_without_schema() -> Result<()> {
         let tmp_dir = TempDir::new()?;
@@ -18,6 +18,7 @@
 //! Factory for creating ListingTables with default options
 
 use crate::datasource::datasource::TableProviderFactory;
+use crate::datasource::file_format::arrow::ArrowFormat;
 use crate::datasource::file_format::avro::AvroFormat;
 use crate::datasource::file_format::csv::CsvFormat;
 use crate::datasource::file_format::file_type::{FileCompressionType, FileType};
@@ -29,6 +30,7 @@ use crate::datasource::listing::{
 };
 use crate::datasource::TableProvider;
 use crate::execution::context::SessionState;
+use crate::physical_plan::file_format::SchemaEvolution;
 use arrow::datatypes::{DataType, SchemaRef};
 use async_trait::async_trait;
 use datafusion_common::DataFusionError;
@@ -36,6 +38,10 @@ use datafusion_expr::CreateExternalTable;
 use std::str::FromStr;
 use std::sync::Arc;
 
+/// The `OPTIONS` key of the [`SchemaEvolution`] mode of a table, `strict`
+/// or `merge`
+pub const SCHEMA_EVOLUTION_OPTION: &str = "schema_evolution";
+
 /// A `TableProviderFactory` capable of creating new `ListingTable`s
 pub struct ListingTableFactory {}
 
@@ -67,17 +73,36 @@ impl TableProviderFactory for ListingTableFactory {
         let file_extension =
             file_type.get_ext_with_compression(file_compression_type.to_owned())?;
 
+        // the schema evolution mode applies to all the file types, the other
+        // options are options of the file format
+        let mut format_options = cmd.options.clone();
+        let schema_evolution = format_options
+            .remove(SCHEMA_EVOLUTION_OPTION)
+            .map(|mode| SchemaEvolution::from_str(&mode))
+            .transpose()?
+            .unwrap_or_default();
+
         let file_format: Arc<dyn FileFormat> = match file_type {
             FileType::CSV => Arc::new(
                 CsvFormat::default()
                     .with_has_header(cmd.has_header)
                     .with_delimiter(cmd.delimiter as u8)
-                    .with_file_compression_type(file_compression_type),
+                    .with_file_compression_type(file_compression_type)
+                    .with_options(format_options)?,
             ),
-            FileType::PARQUET => Arc::new(ParquetFormat::default()),
+            FileType::PARQUET => {
+                let format =
+                    ParquetFormat::default().with_writer_options(format_options)?;
+                // reject invalid option values when the table is created
+                format.writer_properties(state.config_options())?;
+                Arc::new(format)
+            }
             FileType::AVRO => Arc::new(AvroFormat::default()),
+            FileType::ARROW => Arc::new(ArrowFormat::default()),
             FileType::JSON => Arc::new(
-                JsonFormat::default().with_file_compression_type(file_compression_type),
+                JsonFormat::default()
+                    .with_file_compression_type(file_compression_type)
+                    .with_options(format_options)?,
             ),
         };
 
@@ -121,7 +146,8 @@ impl TableProviderFactory for ListingTableFactory {
             .with_file_extension(file_extension)
             .with_target_partitions(state.config().target_partitions())
             .with_table_partition_cols(table_partition_cols)
-            .with_file_sort_order(None);
+            .with_file_sort_order(None)
+            .with_schema_evolution(schema_evolution);
 
         let table_path = ListingTableUrl::parse(&cmd.location)?;
         let resolved_schema = match provided_schema {
@@ -23,7 +23,8 @@ use futures::StreamExt;
 use std::any::Any;
 use std::sync::Arc;
 
-use arrow::datatypes::SchemaRef;
+use arrow::array::new_null_array;
+use arrow::datatypes::{Field, Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
 use async_trait::async_trait;
 
@@ -34,8 +35,8 @@ use crate::logical_expr::Expr;
 use crate::physical_plan::common;
 use crate::physical_plan::common::AbortOnDropSingle;
 use crate::physical_plan::memory::MemoryExec;
-use crate::physical_plan::ExecutionPlan;
 use crate::physical_plan::{repartition::RepartitionExec, Partitioning};
+use crate::physical_plan::{ExecutionPlan, Statistics};
 
 /// In-memory table
 #[derive(Debug)]
@@ -149,6 +150,43 @@ impl TableProvider for MemTable {
             projection.cloned(),
         )?))
     }
+
+    /// The exact statistics of the batches of the table
+    fn statistics(&self) -> Option<Statistics> {
+        Some(common::compute_record_batch_statistics(
+            &self.batches,
+            &self.schema,
+            None,
+        ))
+    }
+
+    async fn add_column(
+        &self,
+        _state: &SessionState,
+        field: Field,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let mut fields = self.schema.fields().clone();
+        fields.push(field.clone());
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema.metadata().clone(),
+        ));
+        let partitions = self
+            .batches
+            .iter()
+            .map(|batches| {
+                batches
+                    .iter()
+                    .map(|batch| {
+                        let mut columns = batch.columns().to_vec();
+                        columns.push(new_null_array(field.data_type(), batch.num_rows()));
+                        Ok(RecordBatch::try_new(schema.clone(), columns)?)
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Arc::new(MemTable::try_new(schema, partitions)?))
+    }
 }
 
 #[cfg(test)]
@@ -157,11 +195,29 @@ mod tests {
     use crate::from_slice::FromSlice;
     use crate::prelude::SessionContext;
     use arrow::array::Int32Array;
-    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::datatypes::DataType;
     use arrow::error::ArrowError;
     use futures::StreamExt;
     use std::collections::HashMap;
 
+    #[test]
+    fn test_statistics() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]))],
+        )?;
+        let provider = MemTable::try_new(schema, vec![vec![batch.clone()], vec![batch]])?;
+
+        let statistics = provider.statistics().unwrap();
+        assert!(statistics.is_exact);
+        assert_eq!(statistics.num_rows, Some(6));
+        assert!(statistics.total_byte_size.unwrap() > 0);
+        let column_statistics = statistics.column_statistics.unwrap();
+        assert_eq!(column_statistics[0].null_count, Some(2));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_with_projection() -> Result<()> {
         let session_ctx = SessionContext::new();
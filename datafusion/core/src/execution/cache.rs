@@ -0,0 +1,619 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Caches of file metadata and statistics that are shared by all the
+//! queries run against a [`RuntimeEnv`](super::runtime_env::RuntimeEnv)
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+use chrono::{DateTime, Utc};
+use object_store::path::Path;
+use object_store::ObjectMeta;
+use parking_lot::Mutex;
+use parquet::basic::Encoding;
+use parquet::file::metadata::{ColumnChunkMetaData, ParquetMetaData, RowGroupMetaData};
+use parquet::file::page_index::index::{Index, PageIndex};
+use parquet::file::statistics::Statistics as ParquetStatistics;
+use parquet::format::{KeyValue, PageLocation};
+use parquet::schema::types::ColumnDescriptor;
+
+use crate::datasource::object_store::ObjectStoreUrl;
+use crate::physical_plan::{ColumnStatistics, Statistics};
+
+/// Caches the parsed metadata (e.g. parquet footers) of files, so that
+/// queries do not have to fetch and decode it again for files they have
+/// already read.
+///
+/// Entries are keyed by the [`ObjectStoreUrl`] of the store and the location,
+/// size and last modification time of the file, so that an entry is never
+/// returned for a file of another store or for a file that has changed.
+pub trait FileMetadataCache: Debug + Send + Sync {
+    /// Returns the cached parquet metadata of the file `meta` of the store
+    /// `store_url`, if any
+    fn get_parquet_metadata(
+        &self,
+        store_url: &ObjectStoreUrl,
+        meta: &ObjectMeta,
+    ) -> Option<Arc<ParquetMetaData>>;
+
+    /// Caches the parquet metadata of the file `meta` of the store `store_url`
+    fn put_parquet_metadata(
+        &self,
+        store_url: &ObjectStoreUrl,
+        meta: &ObjectMeta,
+        metadata: Arc<ParquetMetaData>,
+    );
+
+    /// Removes all the cached entries
+    fn clear(&self);
+}
+
+/// Caches the [`Statistics`] of files computed for the schema of a table,
+/// so that the statistics of a `ListingTable` do not have to be collected
+/// again for every query.
+///
+/// Entries are keyed like the entries of a [`FileMetadataCache`] and must
+/// only be returned for the table schema they were computed for.
+pub trait FileStatisticsCache: Debug + Send + Sync {
+    /// Returns the cached statistics of the file `meta` of the store
+    /// `store_url` for `table_schema`
+    fn get(
+        &self,
+        store_url: &ObjectStoreUrl,
+        meta: &ObjectMeta,
+        table_schema: &SchemaRef,
+    ) -> Option<Statistics>;
+
+    /// Caches the statistics of the file `meta` of the store `store_url`
+    /// for `table_schema`
+    fn put(
+        &self,
+        store_url: &ObjectStoreUrl,
+        meta: &ObjectMeta,
+        table_schema: &SchemaRef,
+        statistics: Statistics,
+    );
+
+    /// Removes all the cached entries
+    fn clear(&self);
+}
+
+/// The default [`FileMetadataCache`], that keeps the most recently used
+/// entries up to a memory limit
+#[derive(Debug)]
+pub struct DefaultFileMetadataCache {
+    parquet_metadata: Mutex<LruCache<Arc<ParquetMetaData>>>,
+}
+
+impl DefaultFileMetadataCache {
+    /// Creates a cache that holds up to `memory_limit` bytes of metadata
+    pub fn new(memory_limit: usize) -> Self {
+        Self {
+            parquet_metadata: Mutex::new(LruCache::new(memory_limit)),
+        }
+    }
+
+    /// Returns the approximate number of bytes used by the cached entries
+    pub fn memory_used(&self) -> usize {
+        self.parquet_metadata.lock().used
+    }
+}
+
+impl FileMetadataCache for DefaultFileMetadataCache {
+    fn get_parquet_metadata(
+        &self,
+        store_url: &ObjectStoreUrl,
+        meta: &ObjectMeta,
+    ) -> Option<Arc<ParquetMetaData>> {
+        let key = CacheKey::new(store_url, meta);
+        self.parquet_metadata.lock().get(&key, |_| true).cloned()
+    }
+
+    fn put_parquet_metadata(
+        &self,
+        store_url: &ObjectStoreUrl,
+        meta: &ObjectMeta,
+        metadata: Arc<ParquetMetaData>,
+    ) {
+        let size = parquet_metadata_size(&metadata);
+        let key = CacheKey::new(store_url, meta);
+        self.parquet_metadata.lock().put(key, metadata, size)
+    }
+
+    fn clear(&self) {
+        self.parquet_metadata.lock().clear()
+    }
+}
+
+/// The default [`FileStatisticsCache`], that keeps the most recently used
+/// entries up to a memory limit
+#[derive(Debug)]
+pub struct DefaultFileStatisticsCache {
+    statistics: Mutex<LruCache<(SchemaRef, Statistics)>>,
+}
+
+impl DefaultFileStatisticsCache {
+    /// Creates a cache that holds up to `memory_limit` bytes of statistics
+    pub fn new(memory_limit: usize) -> Self {
+        Self {
+            statistics: Mutex::new(LruCache::new(memory_limit)),
+        }
+    }
+
+    /// Returns the approximate number of bytes used by the cached entries
+    pub fn memory_used(&self) -> usize {
+        self.statistics.lock().used
+    }
+}
+
+impl FileStatisticsCache for DefaultFileStatisticsCache {
+    fn get(
+        &self,
+        store_url: &ObjectStoreUrl,
+        meta: &ObjectMeta,
+        table_schema: &SchemaRef,
+    ) -> Option<Statistics> {
+        let key = CacheKey::new(store_url, meta);
+        self.statistics
+            .lock()
+            .get(&key, |(schema, _)| schema == table_schema)
+            .map(|(_, statistics)| statistics.clone())
+    }
+
+    fn put(
+        &self,
+        store_url: &ObjectStoreUrl,
+        meta: &ObjectMeta,
+        table_schema: &SchemaRef,
+        statistics: Statistics,
+    ) {
+        let size = statistics_size(&statistics);
+        let key = CacheKey::new(store_url, meta);
+        self.statistics
+            .lock()
+            .put(key, (table_schema.clone(), statistics), size)
+    }
+
+    fn clear(&self) {
+        self.statistics.lock().clear()
+    }
+}
+
+/// Returns the approximate number of bytes used by `statistics`
+fn statistics_size(statistics: &Statistics) -> usize {
+    let columns = statistics.column_statistics.iter().flatten();
+    size_of::<Statistics>()
+        + columns
+            .map(|column| {
+                size_of::<ColumnStatistics>()
+                    + [&column.min_value, &column.max_value]
+                        .into_iter()
+                        .flatten()
+                        .map(|value| value.size())
+                        .sum::<usize>()
+            })
+            .sum::<usize>()
+}
+
+/// Returns the approximate number of bytes used by the decoded parquet
+/// `metadata`, including the statistics and page indexes of its columns
+fn parquet_metadata_size(metadata: &ParquetMetaData) -> usize {
+    let file_metadata = metadata.file_metadata();
+    let schema = file_metadata.schema_descr();
+    let columns = (0..schema.num_columns())
+        .map(|i| {
+            size_of::<ColumnDescriptor>()
+                + schema
+                    .column(i)
+                    .path()
+                    .parts()
+                    .iter()
+                    .map(|part| size_of::<String>() + part.len())
+                    .sum::<usize>()
+        })
+        .sum::<usize>();
+    let key_value_metadata = file_metadata
+        .key_value_metadata()
+        .into_iter()
+        .flatten()
+        .map(|kv| {
+            size_of::<KeyValue>()
+                + kv.key.len()
+                + kv.value.as_ref().map_or(0, String::len)
+        })
+        .sum::<usize>();
+    let created_by = file_metadata.created_by().map_or(0, str::len);
+
+    let row_groups = metadata
+        .row_groups()
+        .iter()
+        .map(|row_group| {
+            size_of::<RowGroupMetaData>()
+                + row_group
+                    .columns()
+                    .iter()
+                    .map(column_chunk_size)
+                    .sum::<usize>()
+        })
+        .sum::<usize>();
+
+    // the page indexes hold an entry per page of each column chunk
+    let column_indexes = metadata
+        .page_indexes()
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(index_size)
+        .sum::<usize>();
+    let offset_indexes = metadata
+        .offset_indexes()
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|locations| {
+            size_of::<Vec<PageLocation>>() + locations.len() * size_of::<PageLocation>()
+        })
+        .sum::<usize>();
+
+    size_of::<ParquetMetaData>()
+        + columns
+        + key_value_metadata
+        + created_by
+        + row_groups
+        + column_indexes
+        + offset_indexes
+}
+
+/// Returns the approximate number of bytes used by the metadata of a
+/// column chunk
+fn column_chunk_size(column: &ColumnChunkMetaData) -> usize {
+    // the values of the statistics of byte arrays are stored on the heap
+    let statistics = match column.statistics() {
+        Some(statistics)
+            if statistics.has_min_max_set()
+                && matches!(
+                    statistics,
+                    ParquetStatistics::ByteArray(_)
+                        | ParquetStatistics::FixedLenByteArray(_)
+                ) =>
+        {
+            statistics.min_bytes().len() + statistics.max_bytes().len()
+        }
+        _ => 0,
+    };
+    size_of::<ColumnChunkMetaData>()
+        + column.file_path().map_or(0, str::len)
+        + column.encodings().len() * size_of::<Encoding>()
+        + statistics
+}
+
+/// Returns the approximate number of bytes used by the column index of a
+/// column chunk
+fn index_size(index: &Index) -> usize {
+    let pages = match index {
+        Index::NONE => 0,
+        Index::BOOLEAN(index) => page_indexes_size(&index.indexes, |_| 0),
+        Index::INT32(index) => page_indexes_size(&index.indexes, |_| 0),
+        Index::INT64(index) => page_indexes_size(&index.indexes, |_| 0),
+        Index::INT96(index) => page_indexes_size(&index.indexes, |_| 0),
+        Index::FLOAT(index) => page_indexes_size(&index.indexes, |_| 0),
+        Index::DOUBLE(index) => page_indexes_size(&index.indexes, |_| 0),
+        Index::BYTE_ARRAY(index) => {
+            page_indexes_size(&index.indexes, |value| value.as_ref().len())
+        }
+        Index::FIXED_LEN_BYTE_ARRAY(index) => {
+            page_indexes_size(&index.indexes, |value| value.as_ref().len())
+        }
+    };
+    size_of::<Index>() + pages
+}
+
+/// Returns the approximate number of bytes used by the entries of a column
+/// index, `heap_size` returning the bytes a min or max value stores on the heap
+fn page_indexes_size<T>(
+    indexes: &[PageIndex<T>],
+    heap_size: impl Fn(&T) -> usize,
+) -> usize {
+    indexes
+        .iter()
+        .map(|page| {
+            size_of::<PageIndex<T>>()
+                + page.min().map_or(0, &heap_size)
+                + page.max().map_or(0, &heap_size)
+        })
+        .sum()
+}
+
+/// The key of a cached entry: the file `location` of the store `store_url`,
+/// at the version identified by its `size` and `last_modified` time
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    store_url: ObjectStoreUrl,
+    location: Path,
+    last_modified: DateTime<Utc>,
+    size: usize,
+}
+
+impl CacheKey {
+    fn new(store_url: &ObjectStoreUrl, meta: &ObjectMeta) -> Self {
+        Self {
+            store_url: store_url.clone(),
+            location: meta.location.clone(),
+            last_modified: meta.last_modified,
+            size: meta.size,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct LruEntry<V> {
+    value: V,
+    size: usize,
+    /// The tick of the last access, the key of the entry in `LruCache::lru`
+    tick: u64,
+}
+
+/// A map from file versions to values that evicts the least recently used
+/// entries once their size exceeds a limit
+#[derive(Debug)]
+struct LruCache<V> {
+    entries: HashMap<CacheKey, LruEntry<V>>,
+    /// The keys of the entries, ordered by their last access
+    lru: BTreeMap<u64, CacheKey>,
+    next_tick: u64,
+    used: usize,
+    limit: usize,
+}
+
+impl<V> LruCache<V> {
+    fn new(limit: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            lru: BTreeMap::new(),
+            next_tick: 0,
+            used: 0,
+            limit,
+        }
+    }
+
+    /// Returns the value cached for `key`, if it is accepted by `valid`
+    fn get(&mut self, key: &CacheKey, valid: impl Fn(&V) -> bool) -> Option<&V> {
+        if !valid(&self.entries.get(key)?.value) {
+            return None;
+        }
+
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        let entry = self.entries.get_mut(key)?;
+        let key = self.lru.remove(&entry.tick)?;
+        self.lru.insert(tick, key);
+        entry.tick = tick;
+        Some(&entry.value)
+    }
+
+    fn put(&mut self, key: CacheKey, value: V, size: usize) {
+        self.remove(&key);
+        if size > self.limit {
+            return;
+        }
+
+        while self.used + size > self.limit {
+            let oldest = self.lru.values().next().cloned();
+            match oldest {
+                Some(key) => self.remove(&key),
+                None => break,
+            }
+        }
+
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        self.lru.insert(tick, key.clone());
+        self.used += size;
+        self.entries.insert(key, LruEntry { value, size, tick });
+    }
+
+    fn remove(&mut self, key: &CacheKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.lru.remove(&entry.tick);
+            self.used -= entry.size;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.lru.clear();
+        self.used = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Result;
+    use crate::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
+    use crate::prelude::{ParquetReadOptions, SessionConfig, SessionContext};
+    use crate::test_util::parquet_test_data;
+    use arrow::datatypes::{DataType, Field, Schema};
+    use chrono::DateTime;
+    use datafusion_common::ScalarValue;
+    use parquet::arrow::arrow_reader::ArrowReaderOptions;
+    use parquet::arrow::ParquetRecordBatchStreamBuilder;
+    use tokio::fs::File;
+
+    fn meta(location: &str, size: usize) -> ObjectMeta {
+        ObjectMeta {
+            location: Path::from(location),
+            last_modified: DateTime::parse_from_rfc3339("2022-09-27T22:36:00+02:00")
+                .unwrap()
+                .into(),
+            size,
+        }
+    }
+
+    async fn read_metadata(file: &str) -> Arc<ParquetMetaData> {
+        let file = File::open(format!("{}/{file}", parquet_test_data()))
+            .await
+            .unwrap();
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        ParquetRecordBatchStreamBuilder::new_with_options(file, options)
+            .await
+            .unwrap()
+            .metadata()
+            .clone()
+    }
+
+    fn key(location: &str, size: usize) -> CacheKey {
+        CacheKey::new(&ObjectStoreUrl::local_filesystem(), &meta(location, size))
+    }
+
+    #[test]
+    fn lru_cache_evicts_least_recently_used() {
+        let mut cache = LruCache::new(30);
+        cache.put(key("a", 1), 1, 10);
+        cache.put(key("b", 1), 2, 10);
+        cache.put(key("c", 1), 3, 10);
+        assert_eq!(cache.used, 30);
+
+        // touch "a", so that "b" is evicted next
+        assert_eq!(cache.get(&key("a", 1), |_| true), Some(&1));
+        cache.put(key("d", 1), 4, 10);
+        assert_eq!(cache.used, 30);
+        assert_eq!(cache.get(&key("b", 1), |_| true), None);
+        assert_eq!(cache.get(&key("a", 1), |_| true), Some(&1));
+
+        // an entry larger than the limit is not cached
+        cache.put(key("e", 1), 5, 40);
+        assert_eq!(cache.get(&key("e", 1), |_| true), None);
+        assert_eq!(cache.used, 30);
+
+        // replacing an entry updates the memory used
+        cache.put(key("a", 1), 6, 20);
+        assert_eq!(cache.get(&key("a", 1), |_| true), Some(&6));
+        assert!(cache.used <= 30);
+    }
+
+    #[tokio::test]
+    async fn cache_key_identifies_file_version() {
+        let cache = DefaultFileMetadataCache::new(1024 * 1024);
+        let local = ObjectStoreUrl::local_filesystem();
+        let metadata = read_metadata("alltypes_plain.parquet").await;
+        cache.put_parquet_metadata(&local, &meta("a", 1), metadata);
+        assert!(cache.get_parquet_metadata(&local, &meta("a", 1)).is_some());
+
+        // the same path in another store
+        let s3 = ObjectStoreUrl::parse("s3://bucket").unwrap();
+        assert!(cache.get_parquet_metadata(&s3, &meta("a", 1)).is_none());
+
+        // file size changed
+        assert!(cache.get_parquet_metadata(&local, &meta("a", 2)).is_none());
+
+        // file last_modified changed
+        let mut changed = meta("a", 1);
+        changed.last_modified = DateTime::parse_from_rfc3339("2022-09-27T22:40:00+02:00")
+            .unwrap()
+            .into();
+        assert!(cache.get_parquet_metadata(&local, &changed).is_none());
+    }
+
+    #[tokio::test]
+    async fn parquet_metadata_size_includes_row_groups() {
+        let metadata = read_metadata("alltypes_tiny_pages.parquet").await;
+        assert!(metadata.page_indexes().is_some());
+
+        let size = parquet_metadata_size(&metadata);
+        let row_groups = metadata
+            .row_groups()
+            .iter()
+            .map(|row_group| row_group.columns().len() * size_of::<ColumnChunkMetaData>())
+            .sum::<usize>();
+        assert!(size > size_of::<ParquetMetaData>() + row_groups);
+    }
+
+    #[test]
+    fn statistics_cache_checks_schema() {
+        let schema_a =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let schema_b =
+            Arc::new(Schema::new(vec![Field::new("b", DataType::Int32, true)]));
+        let statistics = Statistics {
+            num_rows: Some(10),
+            column_statistics: Some(vec![ColumnStatistics {
+                null_count: Some(0),
+                max_value: Some(ScalarValue::Int32(Some(10))),
+                min_value: Some(ScalarValue::Int32(Some(1))),
+                distinct_count: None,
+            }]),
+            ..Default::default()
+        };
+
+        let cache = DefaultFileStatisticsCache::new(1024 * 1024);
+        let url = ObjectStoreUrl::local_filesystem();
+        cache.put(&url, &meta("a", 1), &schema_a, statistics.clone());
+        assert!(cache.memory_used() > 0);
+        assert_eq!(cache.get(&url, &meta("a", 1), &schema_a), Some(statistics));
+        assert_eq!(cache.get(&url, &meta("a", 1), &schema_b), None);
+
+        cache.clear();
+        assert_eq!(cache.get(&url, &meta("a", 1), &schema_a), None);
+        assert_eq!(cache.memory_used(), 0);
+    }
+
+    #[tokio::test]
+    async fn caches_shared_across_sessions() -> Result<()> {
+        let metadata_cache = Arc::new(DefaultFileMetadataCache::new(1024 * 1024));
+        let statistics_cache = Arc::new(DefaultFileStatisticsCache::new(1024 * 1024));
+        let runtime = Arc::new(RuntimeEnv::new(
+            RuntimeConfig::new()
+                .with_file_metadata_cache(metadata_cache.clone())
+                .with_file_statistics_cache(statistics_cache.clone()),
+        )?);
+        let path = format!("{}/alltypes_plain.parquet", parquet_test_data());
+
+        // inferring the schema caches the footer, the scan its statistics
+        let ctx = SessionContext::with_config_rt(
+            SessionConfig::new().with_collect_statistics(true),
+            runtime.clone(),
+        );
+        ctx.register_parquet("t", &path, ParquetReadOptions::default())
+            .await?;
+        let metadata_used = metadata_cache.memory_used();
+        assert!(metadata_used > 0);
+        assert_eq!(statistics_cache.memory_used(), 0);
+
+        let expected = ctx.sql("SELECT id FROM t").await?.collect().await?;
+        let statistics_used = statistics_cache.memory_used();
+        assert!(statistics_used > 0);
+
+        // a new session reuses the cached entries
+        let ctx = SessionContext::with_config_rt(
+            SessionConfig::new().with_collect_statistics(true),
+            runtime,
+        );
+        ctx.register_parquet("t", &path, ParquetReadOptions::default())
+            .await?;
+        let actual = ctx.sql("SELECT id FROM t").await?.collect().await?;
+        assert_eq!(expected, actual);
+        assert_eq!(metadata_cache.memory_used(), metadata_used);
+        assert_eq!(statistics_cache.memory_used(), statistics_used);
+
+        Ok(())
+    }
+}
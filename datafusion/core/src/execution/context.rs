@@ -65,9 +65,10 @@ use crate::datasource::{
 };
 use crate::error::{DataFusionError, Result};
 use crate::logical_expr::{
-    CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateMemoryTable,
-    CreateView, DropTable, DropView, Explain, LogicalPlan, LogicalPlanBuilder,
-    SetVariable, TableSource, TableType, UNNAMED_TABLE,
+    AlterTable, AlterTableOperation, AlterView, CreateCatalog, CreateCatalogSchema,
+    CreateExternalTable, CreateFunction, CreateMemoryTable, CreateView, DropFunction,
+    DropTable, DropView, Explain, LogicalPlan, LogicalPlanBuilder, SetVariable,
+    SqlFunction, TableSource, TableType, UNNAMED_TABLE,
 };
 use crate::optimizer::OptimizerRule;
 use datafusion_sql::{planner::ParserOptions, ResolvedTableReference, TableReference};
@@ -78,7 +79,9 @@ use crate::physical_optimizer::repartition::Repartition;
 use crate::config::ConfigOptions;
 use crate::execution::{runtime_env::RuntimeEnv, FunctionRegistry};
 use crate::physical_optimizer::dist_enforcement::EnforceDistribution;
-use crate::physical_plan::file_format::{plan_to_csv, plan_to_json, plan_to_parquet};
+use crate::physical_plan::file_format::{
+    plan_to_arrow, plan_to_avro, plan_to_csv, plan_to_json, plan_to_parquet,
+};
 use crate::physical_plan::planner::DefaultPhysicalPlanner;
 use crate::physical_plan::udaf::AggregateUDF;
 use crate::physical_plan::udf::ScalarUDF;
@@ -97,18 +100,21 @@ use url::Url;
 
 use crate::catalog::information_schema::{InformationSchemaProvider, INFORMATION_SCHEMA};
 use crate::catalog::listing_schema::ListingSchemaProvider;
+use crate::catalog::remote::AsyncCatalogProvider;
 use crate::datasource::object_store::ObjectStoreUrl;
 use crate::execution::memory_pool::MemoryPool;
 use crate::physical_optimizer::global_sort_selection::GlobalSortSelection;
 use crate::physical_optimizer::pipeline_checker::PipelineChecker;
 use crate::physical_optimizer::pipeline_fixer::PipelineFixer;
 use crate::physical_optimizer::sort_enforcement::EnforceSorting;
+use crate::physical_optimizer::topk_pushdown::TopKPushdown;
 use datafusion_optimizer::OptimizerConfig;
 use datafusion_sql::planner::object_name_to_table_reference;
 use uuid::Uuid;
 
 use super::options::{
-    AvroReadOptions, CsvReadOptions, NdJsonReadOptions, ParquetReadOptions, ReadOptions,
+    ArrowReadOptions, AvroReadOptions, CsvReadOptions, NdJsonReadOptions,
+    ParquetReadOptions, ReadOptions,
 };
 
 /// DataFilePaths adds a method to convert strings and vector of strings to vector of [`ListingTableUrl`] URLs.
@@ -373,11 +379,11 @@ impl SessionContext {
 
                 match (or_replace, view) {
                     (true, Ok(_)) => {
-                        self.deregister_table(&name)?;
                         let table =
                             Arc::new(ViewTable::try_new((*input).clone(), definition)?);
 
-                        self.register_table(&name, table)?;
+                        let schema = self.state.read().schema_for_ref(&name)?;
+                        schema.replace_table(name.table().to_string(), table)?;
                         self.return_empty_dataframe()
                     }
                     (_, Err(_)) => {
@@ -419,6 +425,46 @@ impl SessionContext {
                 }
             }
 
+            LogicalPlan::AlterTable(AlterTable {
+                name, operation, ..
+            }) => self.alter_table(&name, operation).await,
+
+            LogicalPlan::AlterView(AlterView { name, new_name, .. }) => {
+                self.rename_table(&name, &new_name, TableType::View).await
+            }
+
+            LogicalPlan::CreateFunction(CreateFunction {
+                function,
+                or_replace,
+                ..
+            }) => {
+                let exists = self.state.read().sql_functions.contains_key(&function.name);
+
+                match (or_replace, exists) {
+                    (false, true) => Err(DataFusionError::Execution(format!(
+                        "Function '{}' already exists",
+                        function.name
+                    ))),
+                    (_, _) => {
+                        self.register_sql_function(function);
+                        self.return_empty_dataframe()
+                    }
+                }
+            }
+
+            LogicalPlan::DropFunction(DropFunction {
+                name, if_exists, ..
+            }) => {
+                let result = self.deregister_sql_function(&name);
+                match (result, if_exists) {
+                    (Some(_), _) => self.return_empty_dataframe(),
+                    (_, true) => self.return_empty_dataframe(),
+                    (_, _) => Err(DataFusionError::Execution(format!(
+                        "Function '{name}' doesn't exist."
+                    ))),
+                }
+            }
+
             LogicalPlan::SetVariable(SetVariable {
                 variable, value, ..
             }) => {
@@ -568,7 +614,7 @@ impl SessionContext {
         cmd: &CreateExternalTable,
     ) -> Result<DataFrame> {
         let exist = self.table_exist(&cmd.name)?;
-        if exist {
+        if exist && !cmd.or_replace {
             match cmd.if_not_exists {
                 true => return self.return_empty_dataframe(),
                 false => {
@@ -581,29 +627,102 @@ impl SessionContext {
         }
 
         let table_provider: Arc<dyn TableProvider> =
-            self.create_custom_table(cmd).await?;
-        self.register_table(&cmd.name, table_provider)?;
+            self.state().create_custom_table(cmd).await?;
+        if cmd.or_replace {
+            let schema = self.state.read().schema_for_ref(&cmd.name)?;
+            schema.replace_table(cmd.name.table().to_string(), table_provider)?;
+        } else {
+            self.register_table(&cmd.name, table_provider)?;
+        }
         self.return_empty_dataframe()
     }
 
-    async fn create_custom_table(
+    async fn alter_table(
         &self,
-        cmd: &CreateExternalTable,
-    ) -> Result<Arc<dyn TableProvider>> {
-        let state = self.state.read().clone();
-        let file_type = cmd.file_type.to_uppercase();
-        let factory =
-            &state
-                .table_factories
-                .get(file_type.as_str())
-                .ok_or_else(|| {
-                    DataFusionError::Execution(format!(
-                        "Unable to find factory for {}",
-                        cmd.file_type
-                    ))
-                })?;
-        let table = (*factory).create(&state, cmd).await?;
-        Ok(table)
+        name: &OwnedTableReference,
+        operation: AlterTableOperation,
+    ) -> Result<DataFrame> {
+        let (schema, table) = self.find_table_to_alter(name, TableType::Base).await?;
+        let state = self.state();
+        let table = match operation {
+            AlterTableOperation::RenameTo(new_name) => {
+                return self.rename_table(name, &new_name, TableType::Base).await
+            }
+            AlterTableOperation::AddColumn(field) => {
+                if table.schema().field_with_name(field.name()).is_ok() {
+                    return Err(DataFusionError::Execution(format!(
+                        "Column '{}' already exists in table '{name}'",
+                        field.name()
+                    )));
+                }
+                table.add_column(&state, field).await?
+            }
+            AlterTableOperation::SetOptions(options) => {
+                table.set_options(&state, &options).await?
+            }
+        };
+        schema.replace_table(name.table().to_string(), table)?;
+        self.return_empty_dataframe()
+    }
+
+    /// Renames the table or view `name`, which may be moved to another
+    /// schema of the catalogs
+    async fn rename_table(
+        &self,
+        name: &OwnedTableReference,
+        new_name: &OwnedTableReference,
+        table_type: TableType,
+    ) -> Result<DataFrame> {
+        let (schema, table) = self.find_table_to_alter(name, table_type).await?;
+        let (same_schema, new_schema) = {
+            let state = self.state.read();
+            let resolved = state.resolve_table_ref(name);
+            let new_resolved = state.resolve_table_ref(new_name);
+            let same_schema = resolved.catalog == new_resolved.catalog
+                && resolved.schema == new_resolved.schema;
+            (same_schema, state.schema_for_ref(new_name)?)
+        };
+
+        if same_schema {
+            schema.rename_table(name.table(), new_name.table().to_string())?;
+        } else {
+            if new_schema.table_exist(new_name.table()) {
+                return Err(DataFusionError::Execution(format!(
+                    "Table '{new_name}' already exists"
+                )));
+            }
+            // the table is moved by registering it in the new schema first,
+            // and removed from it again if it can not be deregistered from
+            // the old one, so that it is in one of the schemas at the end
+            new_schema.register_table(new_name.table().to_string(), table)?;
+            if let Err(e) = schema.deregister_table(name.table()) {
+                return Err(match new_schema.deregister_table(new_name.table()) {
+                    Ok(_) => e,
+                    Err(rollback) => DataFusionError::Execution(format!(
+                        "{e}, and table '{new_name}' could not be removed again: \
+                         {rollback}"
+                    )),
+                });
+            }
+        }
+        self.return_empty_dataframe()
+    }
+
+    /// Get the table or view `name` to alter and its schema, or an error if
+    /// it doesn't exist or is not of the type `table_type`
+    async fn find_table_to_alter(
+        &self,
+        name: &OwnedTableReference,
+        table_type: TableType,
+    ) -> Result<(Arc<dyn SchemaProvider>, Arc<dyn TableProvider>)> {
+        let schema = self.state.read().schema_for_ref(name)?;
+        match schema.table(name.table()).await {
+            Some(table) if table.table_type() == table_type => Ok((schema, table)),
+            _ => Err(DataFusionError::Execution(match table_type {
+                TableType::View => format!("View '{name}' doesn't exist."),
+                _ => format!("Table '{name}' doesn't exist."),
+            })),
+        }
     }
 
     async fn find_and_deregister<'a>(
@@ -674,6 +793,25 @@ impl SessionContext {
             .insert(f.name.clone(), Arc::new(f));
     }
 
+    /// Registers a SQL macro function within this context, replacing any
+    /// function with the same name.
+    ///
+    /// Calls to the function in SQL queries are replaced by its body
+    /// during planning. Such functions are usually created with
+    /// `CREATE FUNCTION`.
+    pub fn register_sql_function(&self, f: SqlFunction) {
+        self.state
+            .write()
+            .sql_functions
+            .insert(f.name.clone(), Arc::new(f));
+    }
+
+    /// Deregisters a SQL macro function within this context, returning
+    /// it if it was registered.
+    pub fn deregister_sql_function(&self, name: &str) -> Option<Arc<SqlFunction>> {
+        self.state.write().sql_functions.remove(name)
+    }
+
     /// Creates a [`DataFrame`] for reading a data source.
     ///
     /// For more control such as reading multiple files, you can use
@@ -710,6 +848,20 @@ impl SessionContext {
         self._read_type(table_paths, options).await
     }
 
+    /// Creates a [`DataFrame`] for reading an Arrow IPC data source.
+    ///
+    /// For more control such as reading multiple files, you can use
+    /// [`read_table`](Self::read_table) with a [`ListingTable`].
+    ///
+    /// For an example, see [`read_csv`](Self::read_csv)
+    pub async fn read_arrow<P: DataFilePaths>(
+        &self,
+        table_paths: P,
+        options: ArrowReadOptions<'_>,
+    ) -> Result<DataFrame> {
+        self._read_type(table_paths, options).await
+    }
+
     /// Creates a [`DataFrame`] for reading an JSON data source.
     ///
     /// For more control such as reading multiple files, you can use
@@ -914,6 +1066,27 @@ impl SessionContext {
         Ok(())
     }
 
+    /// Registers an Arrow IPC file as a table that can be referenced from
+    /// SQL statements executed against this context.
+    pub async fn register_arrow(
+        &self,
+        name: &str,
+        table_path: &str,
+        options: ArrowReadOptions<'_>,
+    ) -> Result<()> {
+        let listing_options = options.to_listing_options(&self.copied_config());
+
+        self.register_listing_table(
+            name,
+            table_path,
+            listing_options,
+            options.schema.map(|s| Arc::new(s.to_owned())),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
     /// Registers a named catalog using a custom `CatalogProvider` so that
     /// it can be referenced from SQL statements executed against this
     /// context.
@@ -932,6 +1105,30 @@ impl SessionContext {
             .register_catalog(name, catalog)
     }
 
+    /// Registers a named catalog whose schemas and tables are looked up
+    /// asynchronously, such as the catalog of a remote metastore, so that it
+    /// can be referenced from SQL statements executed against this context.
+    ///
+    /// Its tables are only fetched when a statement or [`table`] references
+    /// them. A catalog of the same name registered with [`register_catalog`]
+    /// takes precedence.
+    ///
+    /// Returns the [`AsyncCatalogProvider`] previously registered for this
+    /// name, if any
+    ///
+    /// [`table`]: SessionContext::table
+    /// [`register_catalog`]: SessionContext::register_catalog
+    pub fn register_async_catalog(
+        &self,
+        name: impl Into<String>,
+        catalog: Arc<dyn AsyncCatalogProvider>,
+    ) -> Option<Arc<dyn AsyncCatalogProvider>> {
+        self.state
+            .write()
+            .async_catalogs
+            .insert(name.into(), catalog)
+    }
+
     /// Retrieves the list of available catalog names.
     pub fn catalog_names(&self) -> Vec<String> {
         self.state.read().catalog_list.catalog_names()
@@ -1019,9 +1216,10 @@ impl SessionContext {
     ) -> Result<Arc<dyn TableProvider>> {
         let table_ref = table_ref.into();
         let table = table_ref.table().to_owned();
-        let schema = self.state.read().schema_for_ref(table_ref)?;
-        match schema.table(&table).await {
-            Some(ref provider) => Ok(Arc::clone(provider)),
+        let state = self.state.read().clone();
+        let resolved = state.resolve_table_ref(table_ref);
+        match state.table_for_ref(resolved).await? {
+            Some(provider) => Ok(provider),
             _ => Err(DataFusionError::Plan(format!("No table named '{table}'"))),
         }
     }
@@ -1084,6 +1282,24 @@ impl SessionContext {
         plan_to_json(self.task_ctx(), plan, path).await
     }
 
+    /// Executes a query and writes the results to a partitioned Arrow IPC file.
+    pub async fn write_arrow(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        path: impl AsRef<str>,
+    ) -> Result<()> {
+        plan_to_arrow(self.task_ctx(), plan, path).await
+    }
+
+    /// Executes a query and writes the results to a partitioned Avro file.
+    pub async fn write_avro(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        path: impl AsRef<str>,
+    ) -> Result<()> {
+        plan_to_avro(self.task_ctx(), plan, path).await
+    }
+
     /// Executes a query and writes the results to a partitioned Parquet file.
     pub async fn write_parquet(
         &self,
@@ -1512,10 +1728,14 @@ pub struct SessionState {
     query_planner: Arc<dyn QueryPlanner + Send + Sync>,
     /// Collection of catalogs containing schemas and ultimately TableProviders
     catalog_list: Arc<dyn CatalogList>,
+    /// Catalogs whose schemas and tables are looked up asynchronously
+    async_catalogs: HashMap<String, Arc<dyn AsyncCatalogProvider>>,
     /// Scalar functions that are registered with the context
     scalar_functions: HashMap<String, Arc<ScalarUDF>>,
     /// Aggregate functions registered in the context
     aggregate_functions: HashMap<String, Arc<AggregateUDF>>,
+    /// SQL macro functions registered in the context
+    sql_functions: HashMap<String, Arc<SqlFunction>>,
     /// Session configuration
     config: SessionConfig,
     /// Execution properties
@@ -1569,6 +1789,7 @@ impl SessionState {
         table_factories.insert("JSON".into(), Arc::new(ListingTableFactory::new()));
         table_factories.insert("NDJSON".into(), Arc::new(ListingTableFactory::new()));
         table_factories.insert("AVRO".into(), Arc::new(ListingTableFactory::new()));
+        table_factories.insert("ARROW".into(), Arc::new(ListingTableFactory::new()));
 
         if config.create_default_catalog_and_schema() {
             let default_catalog = MemoryCatalogProvider::new();
@@ -1636,6 +1857,10 @@ impl SessionState {
             // The CoalesceBatches rule will not influence the distribution and ordering of the
             // whole plan tree. Therefore, to avoid influencing other rules, it should run last.
             Arc::new(CoalesceBatches::new()),
+            // The TopKPushdown rule hands the threshold of sorts with a limit to the scans
+            // below them. It runs once the whole plan tree is determined, since it can only
+            // look through operators that keep the sorted column and rows unchanged.
+            Arc::new(TopKPushdown::new()),
             // The PipelineChecker rule will reject non-runnable query plans that use
             // pipeline-breaking operators on infinite input(s). The rule generates a
             // diagnostic error message when this happens. It makes no changes to the
@@ -1649,8 +1874,10 @@ impl SessionState {
             physical_optimizers,
             query_planner: Arc::new(DefaultQueryPlanner {}),
             catalog_list,
+            async_catalogs: HashMap::new(),
             scalar_functions: HashMap::new(),
             aggregate_functions: HashMap::new(),
+            sql_functions: HashMap::new(),
             config,
             execution_props: ExecutionProps::new(),
             runtime_env: runtime,
@@ -1720,9 +1947,13 @@ impl SessionState {
     ) -> Result<Arc<dyn SchemaProvider>> {
         let resolved_ref = self.resolve_table_ref(table_ref);
         if self.config.information_schema() && resolved_ref.schema == INFORMATION_SCHEMA {
-            return Ok(Arc::new(InformationSchemaProvider::new(
-                self.catalog_list.clone(),
-            )));
+            return Ok(Arc::new(
+                InformationSchemaProvider::new(self.catalog_list.clone()).with_functions(
+                    self.scalar_functions.values().cloned().collect(),
+                    self.aggregate_functions.values().cloned().collect(),
+                    self.sql_functions.values().cloned().collect(),
+                ),
+            ));
         }
 
         self.catalog_list
@@ -1742,6 +1973,35 @@ impl SessionState {
             })
     }
 
+    /// Looks up the table of `resolved_ref`, in the registered catalogs or,
+    /// if there is none of that name, in the asynchronous catalogs.
+    ///
+    /// Returns `None` if the catalog, the schema or the table does not exist,
+    /// and the errors of the asynchronous catalogs
+    async fn table_for_ref(
+        &self,
+        resolved_ref: ResolvedTableReference<'_>,
+    ) -> Result<Option<Arc<dyn TableProvider>>> {
+        let is_information_schema =
+            self.config.information_schema() && resolved_ref.schema == INFORMATION_SCHEMA;
+        if !is_information_schema
+            && self.catalog_list.catalog(&resolved_ref.catalog).is_none()
+        {
+            if let Some(catalog) = self.async_catalogs.get(resolved_ref.catalog.as_ref())
+            {
+                return match catalog.schema(&resolved_ref.schema).await? {
+                    Some(schema) => schema.table(&resolved_ref.table).await,
+                    None => Ok(None),
+                };
+            }
+        }
+
+        Ok(match self.schema_for_ref(resolved_ref.clone()) {
+            Ok(schema) => schema.table(&resolved_ref.table).await,
+            Err(_) => None,
+        })
+    }
+
     /// Replace the random session id.
     pub fn with_session_id(mut self, session_id: String) -> Self {
         self.session_id = session_id;
@@ -1793,6 +2053,25 @@ impl SessionState {
         self
     }
 
+    /// Create the table of a `CREATE EXTERNAL TABLE` statement with the
+    /// [`TableProviderFactory`] of its file type
+    pub(crate) async fn create_custom_table(
+        &self,
+        cmd: &CreateExternalTable,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let file_type = cmd.file_type.to_uppercase();
+        let factory = self
+            .table_factories
+            .get(file_type.as_str())
+            .ok_or_else(|| {
+                DataFusionError::Execution(format!(
+                    "Unable to find factory for {}",
+                    cmd.file_type
+                ))
+            })?;
+        factory.create(self, cmd).await
+    }
+
     /// Get the table factories
     pub fn table_factories(&self) -> &HashMap<String, Arc<dyn TableProviderFactory>> {
         &self.table_factories
@@ -1825,6 +2104,10 @@ impl SessionState {
     }
 
     /// Resolve all table references in the SQL statement.
+    ///
+    /// [`statement_to_plan`](Self::statement_to_plan) looks up the tables of
+    /// these references, including those of the [`AsyncCatalogProvider`]s,
+    /// before planning the statement.
     pub fn resolve_table_references(
         &self,
         statement: &datafusion_sql::parser::Statement,
@@ -1876,6 +2159,11 @@ impl SessionState {
                 relations
                     .get_or_insert_with(&table.table_name, |_| table.table_name.clone());
             }
+            DFStatement::CreateFunction(_)
+            | DFStatement::DropFunction(_)
+            | DFStatement::ShowFunctions(_)
+            | DFStatement::AlterTable(_)
+            | DFStatement::AlterView(_) => {}
         }
 
         // Always include information_schema if available
@@ -1913,13 +2201,10 @@ impl SessionState {
         let parse_float_as_decimal =
             self.config.options.sql_parser.parse_float_as_decimal;
         for reference in references {
-            let table = reference.table();
             let resolved = self.resolve_table_ref(reference.as_table_reference());
             if let Entry::Vacant(v) = provider.tables.entry(resolved.to_string()) {
-                if let Ok(schema) = self.schema_for_ref(resolved) {
-                    if let Some(table) = schema.table(table).await {
-                        v.insert(provider_as_source(table));
-                    }
+                if let Some(table) = self.table_for_ref(resolved).await? {
+                    v.insert(provider_as_source(table));
                 }
             }
         }
@@ -2042,6 +2327,11 @@ impl SessionState {
     pub fn aggregate_functions(&self) -> &HashMap<String, Arc<AggregateUDF>> {
         &self.aggregate_functions
     }
+
+    /// Return reference to sql_functions
+    pub fn sql_functions(&self) -> &HashMap<String, Arc<SqlFunction>> {
+        &self.sql_functions
+    }
 }
 
 struct SessionContextProvider<'a> {
@@ -2066,6 +2356,10 @@ impl<'a> ContextProvider for SessionContextProvider<'a> {
         self.state.aggregate_functions().get(name).cloned()
     }
 
+    fn get_sql_function(&self, name: &str) -> Option<Arc<SqlFunction>> {
+        self.state.sql_functions().get(name).cloned()
+    }
+
     fn get_variable_type(&self, variable_names: &[String]) -> Option<DataType> {
         if variable_names.is_empty() {
             return None;
@@ -2627,6 +2921,67 @@ mod tests {
         }
     }
 
+    /// A schema whose tables can not be registered or deregistered
+    struct ReadOnlySchemaProvider(MemorySchemaProvider);
+
+    #[async_trait]
+    impl SchemaProvider for ReadOnlySchemaProvider {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn table_names(&self) -> Vec<String> {
+            self.0.table_names()
+        }
+
+        async fn table(&self, name: &str) -> Option<Arc<dyn TableProvider>> {
+            self.0.table(name).await
+        }
+
+        fn table_exist(&self, name: &str) -> bool {
+            self.0.table_exist(name)
+        }
+    }
+
+    #[tokio::test]
+    async fn rename_table_across_schemas() -> Result<()> {
+        let ctx = SessionContext::new();
+        let read_only = MemorySchemaProvider::new();
+        read_only.register_table("r".to_owned(), test::table_with_sequence(1, 1)?)?;
+        let catalog = ctx.catalog("datafusion").unwrap();
+        catalog
+            .register_schema("read_only", Arc::new(ReadOnlySchemaProvider(read_only)))?;
+        ctx.sql("CREATE TABLE t AS VALUES (1)").await?;
+
+        // the tables stay in their schema if they can not be registered in
+        // the new one, or deregistered from the old one
+        let err = ctx
+            .sql("ALTER TABLE t RENAME TO read_only.u")
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("does not support registering tables"),
+            "{err}"
+        );
+        let err = ctx
+            .sql("ALTER TABLE read_only.r RENAME TO public.r")
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("does not support deregistering tables"),
+            "{err}"
+        );
+
+        assert_eq!(catalog.schema("public").unwrap().table_names(), vec!["t"]);
+        assert_eq!(
+            catalog.schema("read_only").unwrap().table_names(),
+            vec!["r"]
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn cross_catalog_access() -> Result<()> {
         let ctx = SessionContext::new();
@@ -2671,6 +3026,102 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn async_catalog_access() -> Result<()> {
+        use crate::catalog::remote::AsyncSchemaProvider;
+
+        /// A remote catalog recording the tables it is asked for
+        struct RemoteCatalog {
+            lookups: Arc<parking_lot::Mutex<Vec<String>>>,
+        }
+
+        #[async_trait]
+        impl AsyncCatalogProvider for RemoteCatalog {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            async fn schema(
+                &self,
+                name: &str,
+            ) -> Result<Option<Arc<dyn AsyncSchemaProvider>>> {
+                match name {
+                    "remote_schema" => Ok(Some(Arc::new(RemoteSchema {
+                        lookups: self.lookups.clone(),
+                    }))),
+                    "unavailable" => Err(DataFusionError::Execution(
+                        "the metastore is unavailable".to_string(),
+                    )),
+                    _ => Ok(None),
+                }
+            }
+        }
+
+        struct RemoteSchema {
+            lookups: Arc<parking_lot::Mutex<Vec<String>>>,
+        }
+
+        #[async_trait]
+        impl AsyncSchemaProvider for RemoteSchema {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            async fn table(&self, name: &str) -> Result<Option<Arc<dyn TableProvider>>> {
+                self.lookups.lock().push(name.to_string());
+                match name {
+                    "t1" => Ok(Some(test::table_with_sequence(1, 3)?)),
+                    "t2" => Ok(Some(test::table_with_sequence(1, 5)?)),
+                    _ => Ok(None),
+                }
+            }
+        }
+
+        let ctx = SessionContext::new();
+        let lookups = Arc::new(parking_lot::Mutex::new(vec![]));
+        ctx.register_async_catalog(
+            "remote",
+            Arc::new(RemoteCatalog {
+                lookups: lookups.clone(),
+            }),
+        );
+
+        let result = plan_and_collect(
+            &ctx,
+            "SELECT SUM(a.i) AS total FROM remote.remote_schema.t1 a \
+             JOIN remote.remote_schema.t1 b ON a.i = b.i",
+        )
+        .await?;
+        let expected = vec![
+            "+-------+",
+            "| total |",
+            "+-------+",
+            "| 6     |",
+            "+-------+",
+        ];
+        assert_batches_eq!(expected, &result);
+        // only the referenced table is fetched, once
+        assert_eq!(*lookups.lock(), vec!["t1".to_string()]);
+
+        let df = ctx.table("remote.remote_schema.t2").await?;
+        assert_eq!(df.count().await?, 5);
+
+        let err = plan_and_collect(&ctx, "SELECT * FROM remote.remote_schema.t3")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not found"), "{err}");
+
+        let err = plan_and_collect(&ctx, "SELECT * FROM remote.unavailable.t1")
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("the metastore is unavailable"),
+            "{err}"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn catalogs_not_leaked() {
         // the information schema used to introduce cyclic Arcs
@@ -40,6 +40,7 @@
 //! In particular it is the state passed to [`crate::physical_plan::ExecutionPlan::execute`]
 //!
 
+pub mod cache;
 pub mod context;
 // backwards compatibility
 pub use crate::datasource::file_format::options;
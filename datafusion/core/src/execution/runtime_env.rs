@@ -20,6 +20,10 @@
 
 use crate::{
     error::Result,
+    execution::cache::{
+        DefaultFileMetadataCache, DefaultFileStatisticsCache, FileMetadataCache,
+        FileStatisticsCache,
+    },
     execution::disk_manager::{DiskManager, DiskManagerConfig},
 };
 
@@ -43,6 +47,10 @@ pub struct RuntimeEnv {
     pub disk_manager: Arc<DiskManager>,
     /// Object Store Registry
     pub object_store_registry: Arc<ObjectStoreRegistry>,
+    /// Cache of parsed file metadata, such as parquet footers
+    pub file_metadata_cache: Option<Arc<dyn FileMetadataCache>>,
+    /// Cache of the statistics of the files of listing tables
+    pub file_statistics_cache: Option<Arc<dyn FileStatisticsCache>>,
 }
 
 impl Debug for RuntimeEnv {
@@ -58,6 +66,8 @@ impl RuntimeEnv {
             memory_pool,
             disk_manager,
             object_store_registry,
+            file_metadata_cache,
+            file_statistics_cache,
         } = config;
 
         let memory_pool =
@@ -67,6 +77,8 @@ impl RuntimeEnv {
             memory_pool,
             disk_manager: DiskManager::try_new(disk_manager)?,
             object_store_registry,
+            file_metadata_cache,
+            file_statistics_cache,
         })
     }
 
@@ -116,6 +128,14 @@ pub struct RuntimeConfig {
     pub memory_pool: Option<Arc<dyn MemoryPool>>,
     /// ObjectStoreRegistry to get object store based on url
     pub object_store_registry: Arc<ObjectStoreRegistry>,
+    /// [`FileMetadataCache`] shared by all the queries of the runtime
+    ///
+    /// File metadata is not cached if `None`
+    pub file_metadata_cache: Option<Arc<dyn FileMetadataCache>>,
+    /// [`FileStatisticsCache`] shared by all the queries of the runtime
+    ///
+    /// Defaults to a cache owned by each listing table if `None`
+    pub file_statistics_cache: Option<Arc<dyn FileStatisticsCache>>,
 }
 
 impl RuntimeConfig {
@@ -145,6 +165,36 @@ impl RuntimeConfig {
         self
     }
 
+    /// Customize the cache of file metadata
+    pub fn with_file_metadata_cache(
+        mut self,
+        file_metadata_cache: Arc<dyn FileMetadataCache>,
+    ) -> Self {
+        self.file_metadata_cache = Some(file_metadata_cache);
+        self
+    }
+
+    /// Customize the cache of file statistics
+    pub fn with_file_statistics_cache(
+        mut self,
+        file_statistics_cache: Arc<dyn FileStatisticsCache>,
+    ) -> Self {
+        self.file_statistics_cache = Some(file_statistics_cache);
+        self
+    }
+
+    /// Cache up to `memory_limit` bytes of file metadata and up to
+    /// `memory_limit` bytes of file statistics, using the
+    /// [`DefaultFileMetadataCache`] and [`DefaultFileStatisticsCache`]
+    pub fn with_file_cache_limit(self, memory_limit: usize) -> Self {
+        self.with_file_metadata_cache(Arc::new(DefaultFileMetadataCache::new(
+            memory_limit,
+        )))
+        .with_file_statistics_cache(Arc::new(DefaultFileStatisticsCache::new(
+            memory_limit,
+        )))
+    }
+
     /// Specify the total memory to use while running the DataFusion
     /// plan to `max_memory * memory_fraction` in bytes.
     ///
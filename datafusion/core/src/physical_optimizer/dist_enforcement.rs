@@ -974,7 +974,9 @@ mod tests {
         AggregateExec, AggregateMode, PhysicalGroupBy,
     };
     use crate::physical_plan::expressions::col;
-    use crate::physical_plan::file_format::{FileScanConfig, ParquetExec};
+    use crate::physical_plan::file_format::{
+        FileScanConfig, ParquetExec, SchemaEvolution,
+    };
     use crate::physical_plan::joins::{
         utils::JoinOn, HashJoinExec, PartitionMode, SortMergeJoinExec,
     };
@@ -1005,10 +1007,12 @@ mod tests {
                 file_groups: vec![vec![PartitionedFile::new("x".to_string(), 100)]],
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -28,6 +28,7 @@ pub mod pipeline_checker;
 pub mod pruning;
 pub mod repartition;
 pub mod sort_enforcement;
+pub mod topk_pushdown;
 mod utils;
 
 pub mod pipeline_fixer;
@@ -23,7 +23,8 @@ use crate::config::ConfigOptions;
 use crate::error::Result;
 use crate::physical_plan::Partitioning::*;
 use crate::physical_plan::{
-    file_format::ParquetExec, repartition::RepartitionExec,
+    file_format::{ArrowExec, ParquetExec},
+    repartition::RepartitionExec,
     with_new_children_if_necessary, ExecutionPlan,
 };
 
@@ -249,6 +250,16 @@ fn optimize_partitions(
         }
     }
 
+    // Likewise for ArrowExec, whose files are split between record batches
+    if let Some(arrow_exec) = new_plan.as_any().downcast_ref::<ArrowExec>() {
+        if repartition_file_scans {
+            return Ok(Arc::new(
+                arrow_exec
+                    .get_repartitioned(target_partitions, repartition_file_min_size),
+            ));
+        }
+    }
+
     // Otherwise - return plan wrapped up in RepartitionExec
     Ok(Arc::new(RepartitionExec::try_new(
         new_plan,
@@ -322,7 +333,9 @@ mod tests {
         AggregateExec, AggregateMode, PhysicalGroupBy,
     };
     use crate::physical_plan::expressions::{col, PhysicalSortExpr};
-    use crate::physical_plan::file_format::{FileScanConfig, ParquetExec};
+    use crate::physical_plan::file_format::{
+        FileScanConfig, ParquetExec, SchemaEvolution,
+    };
     use crate::physical_plan::filter::FilterExec;
     use crate::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
     use crate::physical_plan::projection::ProjectionExec;
@@ -344,10 +357,12 @@ mod tests {
                 file_groups: vec![vec![PartitionedFile::new("x".to_string(), 100)]],
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -366,10 +381,12 @@ mod tests {
                 ],
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -390,10 +407,12 @@ mod tests {
                 file_groups: vec![vec![PartitionedFile::new("x".to_string(), 100)]],
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: Some(sort_exprs),
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
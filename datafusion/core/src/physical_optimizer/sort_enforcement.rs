@@ -892,7 +892,9 @@ mod tests {
     use crate::physical_optimizer::dist_enforcement::EnforceDistribution;
     use crate::physical_plan::aggregates::PhysicalGroupBy;
     use crate::physical_plan::aggregates::{AggregateExec, AggregateMode};
-    use crate::physical_plan::file_format::{FileScanConfig, ParquetExec};
+    use crate::physical_plan::file_format::{
+        FileScanConfig, ParquetExec, SchemaEvolution,
+    };
     use crate::physical_plan::filter::FilterExec;
     use crate::physical_plan::memory::MemoryExec;
     use crate::physical_plan::repartition::RepartitionExec;
@@ -1872,10 +1874,12 @@ mod tests {
                 file_groups: vec![vec![PartitionedFile::new("x".to_string(), 100)]],
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -1896,10 +1900,12 @@ mod tests {
                 file_groups: vec![vec![PartitionedFile::new("x".to_string(), 100)]],
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: Some(sort_exprs),
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
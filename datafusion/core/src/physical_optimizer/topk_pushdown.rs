@@ -0,0 +1,313 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! TopKPushdown optimizer that hands the threshold of a sort with a limit
+//! to the parquet scans below it for dynamic pruning
+
+use std::sync::Arc;
+
+use crate::config::ConfigOptions;
+use crate::error::Result;
+use crate::physical_optimizer::PhysicalOptimizerRule;
+use crate::physical_plan::coalesce_batches::CoalesceBatchesExec;
+use crate::physical_plan::coalesce_partitions::CoalescePartitionsExec;
+use crate::physical_plan::expressions::Column;
+use crate::physical_plan::file_format::ParquetExec;
+use crate::physical_plan::filter::FilterExec;
+use crate::physical_plan::projection::ProjectionExec;
+use crate::physical_plan::repartition::RepartitionExec;
+use crate::physical_plan::rewrite::TreeNodeRewritable;
+use crate::physical_plan::sorts::sort::SortExec;
+use crate::physical_plan::sorts::topk::TopKThreshold;
+use crate::physical_plan::union::UnionExec;
+use crate::physical_plan::ExecutionPlan;
+
+/// A [`SortExec`] with a `fetch` keeps its top rows in a heap and publishes
+/// the first sort key of the worst of them as a [`TopKThreshold`]. When the
+/// first sort expression is a column that is read unchanged from a parquet
+/// file, this rule passes the threshold to the [`ParquetExec`] so that files
+/// opened later in the scan skip the row groups and pages that can not be
+/// part of the result.
+///
+/// The threshold is only passed through operators that neither change the
+/// values of the column nor the set of rows that reach the sort.
+#[derive(Default)]
+pub struct TopKPushdown {}
+
+impl TopKPushdown {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PhysicalOptimizerRule for TopKPushdown {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if !config.optimizer.enable_topk_dynamic_pruning {
+            return Ok(plan);
+        }
+
+        plan.transform_up(&|plan| {
+            let sort_exec = match plan.as_any().downcast_ref::<SortExec>() {
+                Some(sort_exec) => sort_exec,
+                None => return Ok(None),
+            };
+            let threshold = match sort_exec.topk_threshold() {
+                Some(threshold) => threshold,
+                None => return Ok(None),
+            };
+            let column = match sort_exec.expr()[0].expr.as_any().downcast_ref::<Column>()
+            {
+                Some(column) => column,
+                None => return Ok(None),
+            };
+
+            match push_threshold(sort_exec.input(), threshold, column.index())? {
+                Some(input) => Ok(Some(plan.clone().with_new_children(vec![input])?)),
+                None => Ok(None),
+            }
+        })
+    }
+
+    fn name(&self) -> &str {
+        "topk_pushdown"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+/// Passes `threshold`, on the column at `index` of the output of `plan`,
+/// down to the parquet scans producing that column. Returns the new plan if
+/// any scan consults the threshold
+fn push_threshold(
+    plan: &Arc<dyn ExecutionPlan>,
+    threshold: &Arc<TopKThreshold>,
+    index: usize,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let plan_any = plan.as_any();
+    if let Some(parquet) = plan_any.downcast_ref::<ParquetExec>() {
+        let config = parquet.base_config();
+        let file_index = match &config.projection {
+            Some(projection) => projection[index],
+            None => index,
+        };
+        // partition columns are not read from the files
+        if file_index >= config.file_schema.fields().len() {
+            return Ok(None);
+        }
+        let column = Column::new(config.file_schema.field(file_index).name(), file_index);
+        let parquet = parquet
+            .clone()
+            .with_topk_threshold(threshold.clone(), column);
+        return Ok(Some(Arc::new(parquet)));
+    }
+
+    let input_index = if let Some(projection) = plan_any.downcast_ref::<ProjectionExec>()
+    {
+        match projection.expr()[index].0.as_any().downcast_ref::<Column>() {
+            Some(column) => column.index(),
+            None => return Ok(None),
+        }
+    } else if plan_any.is::<FilterExec>()
+        || plan_any.is::<CoalesceBatchesExec>()
+        || plan_any.is::<CoalescePartitionsExec>()
+        || plan_any.is::<RepartitionExec>()
+        || plan_any.is::<UnionExec>()
+    {
+        index
+    } else {
+        return Ok(None);
+    };
+
+    let mut pushed = false;
+    let children = plan
+        .children()
+        .into_iter()
+        .map(|child| {
+            Ok(match push_threshold(&child, threshold, input_index)? {
+                Some(new_child) => {
+                    pushed = true;
+                    new_child
+                }
+                None => child,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if pushed {
+        Ok(Some(plan.clone().with_new_children(children)?))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::listing::PartitionedFile;
+    use crate::datasource::object_store::ObjectStoreUrl;
+    use crate::physical_plan::expressions::{col, lit, BinaryExpr, PhysicalSortExpr};
+    use crate::physical_plan::file_format::{FileScanConfig, SchemaEvolution};
+    use crate::physical_plan::limit::GlobalLimitExec;
+    use crate::physical_plan::Statistics;
+    use arrow::compute::SortOptions;
+    use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+    use datafusion_expr::Operator;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+        ]))
+    }
+
+    fn parquet_exec(
+        schema: &SchemaRef,
+        projection: Option<Vec<usize>>,
+    ) -> Arc<ParquetExec> {
+        Arc::new(ParquetExec::new(
+            FileScanConfig {
+                object_store_url: ObjectStoreUrl::parse("test:///").unwrap(),
+                file_schema: schema.clone(),
+                file_groups: vec![vec![PartitionedFile::new("x".to_string(), 100)]],
+                statistics: Statistics::default(),
+                projection,
+                nested_projection: None,
+                limit: None,
+                table_partition_cols: vec![],
+                output_ordering: None,
+                infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
+            },
+            None,
+            None,
+        ))
+    }
+
+    fn sort_exec(name: &str, input: Arc<dyn ExecutionPlan>) -> Arc<dyn ExecutionPlan> {
+        let schema = input.schema();
+        Arc::new(
+            SortExec::try_new(
+                vec![PhysicalSortExpr {
+                    expr: col(name, &schema).unwrap(),
+                    options: SortOptions {
+                        descending: true,
+                        nulls_first: true,
+                    },
+                }],
+                input,
+                Some(10),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn optimize(plan: Arc<dyn ExecutionPlan>) -> Arc<dyn ExecutionPlan> {
+        TopKPushdown::new()
+            .optimize(plan, &ConfigOptions::new())
+            .unwrap()
+    }
+
+    /// Returns the threshold of the sort at the root of `plan` and the one
+    /// consulted by the parquet scan at its leaf
+    fn thresholds(
+        plan: &Arc<dyn ExecutionPlan>,
+    ) -> (Arc<TopKThreshold>, Option<Arc<TopKThreshold>>) {
+        let sort = plan.as_any().downcast_ref::<SortExec>().unwrap();
+        let mut leaf = plan.clone();
+        while let Some(child) = leaf.children().first() {
+            leaf = child.clone();
+        }
+        let parquet = leaf.as_any().downcast_ref::<ParquetExec>().unwrap();
+        (
+            sort.topk_threshold().unwrap().clone(),
+            parquet.topk_threshold().cloned(),
+        )
+    }
+
+    #[test]
+    fn push_through_projection_and_filter() {
+        let schema = schema();
+        let parquet = parquet_exec(&schema, Some(vec![1, 0]));
+        let filter = Arc::new(
+            FilterExec::try_new(
+                Arc::new(BinaryExpr::new(
+                    col("a", &parquet.schema()).unwrap(),
+                    Operator::Gt,
+                    lit(1i64),
+                )),
+                parquet,
+            )
+            .unwrap(),
+        );
+        let projection = Arc::new(
+            ProjectionExec::try_new(
+                vec![(col("a", &filter.schema()).unwrap(), "x".to_string())],
+                filter,
+            )
+            .unwrap(),
+        );
+        let plan = optimize(sort_exec("x", projection));
+
+        let (sort_threshold, scan_threshold) = thresholds(&plan);
+        assert!(Arc::ptr_eq(&sort_threshold, &scan_threshold.unwrap()));
+    }
+
+    #[test]
+    fn no_push_through_limit() {
+        let schema = schema();
+        let limit = Arc::new(GlobalLimitExec::new(
+            parquet_exec(&schema, None),
+            0,
+            Some(5),
+        ));
+        let plan = optimize(sort_exec("b", limit));
+
+        let (_, scan_threshold) = thresholds(&plan);
+        assert!(scan_threshold.is_none());
+    }
+
+    #[test]
+    fn no_push_for_computed_column() {
+        let schema = schema();
+        let parquet = parquet_exec(&schema, None);
+        let projection = Arc::new(
+            ProjectionExec::try_new(
+                vec![(
+                    Arc::new(BinaryExpr::new(
+                        col("a", &schema).unwrap(),
+                        Operator::Plus,
+                        col("b", &schema).unwrap(),
+                    )),
+                    "x".to_string(),
+                )],
+                parquet,
+            )
+            .unwrap(),
+        );
+        let plan = optimize(sort_exec("x", projection));
+
+        let (_, scan_threshold) = thresholds(&plan);
+        assert!(scan_threshold.is_none());
+    }
+}
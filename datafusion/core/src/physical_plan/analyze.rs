@@ -55,6 +55,16 @@ impl AnalyzeExec {
             schema,
         }
     }
+
+    /// Whether the metrics of all the operators are printed
+    pub fn verbose(&self) -> bool {
+        self.verbose
+    }
+
+    /// The input plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
 }
 
 impl ExecutionPlan for AnalyzeExec {
@@ -0,0 +1,582 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Execution plan for reading Arrow IPC files and streams
+use crate::datasource::listing::FileRange;
+use crate::error::{DataFusionError, Result};
+use crate::execution::context::TaskContext;
+use crate::physical_plan::common::{AbortOnDropSingle, IPCWriter};
+use crate::physical_plan::expressions::PhysicalSortExpr;
+use crate::physical_plan::file_format::file_stream::{
+    FileOpenFuture, FileOpener, FileStream,
+};
+use crate::physical_plan::file_format::{FileMeta, SchemaAdapter, SchemaEvolution};
+use crate::physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
+use crate::physical_plan::{
+    DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream, Statistics,
+};
+use arrow::buffer::Buffer;
+use arrow::datatypes::SchemaRef;
+use arrow::error::ArrowError;
+use arrow::ipc::convert::fb_to_schema;
+use arrow::ipc::reader::{read_dictionary, read_record_batch, StreamReader};
+use arrow::ipc::{root_as_footer, root_as_message, Message};
+use arrow::record_batch::RecordBatch;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryFutureExt};
+use object_store::{GetResult, ObjectMeta, ObjectStore};
+use std::any::Any;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::task::{self, JoinHandle};
+
+use super::{get_output_ordering, FileScanConfig};
+
+/// The magic bytes that start and end Arrow IPC files. Arrow IPC streams
+/// have no magic bytes
+const ARROW_MAGIC: &[u8; 6] = b"ARROW1";
+
+/// The length of the trailer of an Arrow IPC file: the length of its footer
+/// followed by the magic bytes
+const ARROW_TRAILER_LEN: usize = 10;
+
+/// The marker that precedes the length of the Arrow IPC messages written
+/// since format version 0.15
+const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+/// Execution plan for scanning Arrow IPC files and streams
+#[derive(Debug, Clone)]
+pub struct ArrowExec {
+    base_config: FileScanConfig,
+    projected_statistics: Statistics,
+    projected_schema: SchemaRef,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+}
+
+impl ArrowExec {
+    /// Create a new Arrow reader execution plan provided base configurations
+    pub fn new(base_config: FileScanConfig) -> Self {
+        let (projected_schema, projected_statistics) = base_config.project();
+
+        Self {
+            base_config,
+            projected_schema,
+            projected_statistics,
+            metrics: ExecutionPlanMetricsSet::new(),
+        }
+    }
+
+    /// Ref to the base configs
+    pub fn base_config(&self) -> &FileScanConfig {
+        &self.base_config
+    }
+
+    /// Redistribute files across partitions according to their size. Arrow
+    /// IPC files are split between their record batches, while Arrow IPC
+    /// streams are read whole by the partition reading their first byte
+    pub fn get_repartitioned(
+        &self,
+        target_partitions: usize,
+        repartition_file_min_size: usize,
+    ) -> Self {
+        let repartitioned_file_groups = FileScanConfig::repartition_file_groups(
+            &self.base_config.file_groups,
+            target_partitions,
+            repartition_file_min_size,
+        );
+
+        let mut new_arrow_exec = self.clone();
+        if let Some(file_groups) = repartitioned_file_groups {
+            new_arrow_exec.base_config.file_groups = file_groups;
+        }
+        new_arrow_exec
+    }
+}
+
+impl ExecutionPlan for ArrowExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.projected_schema.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(self.base_config.file_groups.len())
+    }
+
+    fn unbounded_output(&self, _: &[bool]) -> Result<bool> {
+        Ok(self.base_config.infinite_source)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        get_output_ordering(&self.base_config)
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        Vec::new()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let object_store = context
+            .runtime_env()
+            .object_store(&self.base_config.object_store_url)?;
+
+        let projection = match self.base_config.file_column_projection_indices() {
+            Some(proj) => proj,
+            None => (0..self.base_config.file_schema.fields().len()).collect(),
+        };
+
+        let opener = ArrowOpener {
+            object_store,
+            projection: Arc::from(projection),
+            table_schema: self.base_config.file_schema.clone(),
+            schema_evolution: self.base_config.schema_evolution,
+        };
+
+        let stream =
+            FileStream::new(&self.base_config, partition, opener, &self.metrics)?;
+
+        Ok(Box::pin(stream) as SendableRecordBatchStream)
+    }
+
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default => {
+                write!(
+                    f,
+                    "ArrowExec: limit={:?}, files={}",
+                    self.base_config.limit,
+                    super::FileGroupsDisplay(&self.base_config.file_groups),
+                )
+            }
+        }
+    }
+
+    fn statistics(&self) -> Statistics {
+        self.projected_statistics.clone()
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+struct ArrowOpener {
+    object_store: Arc<dyn ObjectStore>,
+    projection: Arc<[usize]>,
+    table_schema: SchemaRef,
+    schema_evolution: SchemaEvolution,
+}
+
+impl FileOpener for ArrowOpener {
+    fn open(&self, file_meta: FileMeta) -> Result<FileOpenFuture> {
+        let store = self.object_store.clone();
+        let schema_adapter = SchemaAdapter::new(self.table_schema.clone())
+            .with_schema_evolution(self.schema_evolution);
+        let projection = self.projection.clone();
+        let range = file_meta.range.clone();
+
+        Ok(Box::pin(async move {
+            let meta = file_meta.object_meta;
+            if let Some(footer) = read_arrow_footer(store.as_ref(), &meta).await? {
+                return read_arrow_file_batches(
+                    store,
+                    meta,
+                    footer,
+                    schema_adapter,
+                    projection,
+                    range,
+                )
+                .await;
+            }
+
+            // streams can not be split, so they are only read by the range
+            // that starts at their first byte
+            if matches!(&range, Some(range) if range.start > 0) {
+                return Ok(futures::stream::empty().boxed());
+            }
+            let batches = match store.get(&meta.location).await? {
+                GetResult::File(file, _) => {
+                    read_arrow_stream_batches(file, schema_adapter, projection)?
+                }
+                r @ GetResult::Stream(_) => {
+                    let data = r.bytes().await?;
+                    read_arrow_stream_batches(
+                        Cursor::new(data),
+                        schema_adapter,
+                        projection,
+                    )?
+                }
+            };
+            Ok(futures::stream::iter(batches).boxed())
+        }))
+    }
+}
+
+type RecordBatchIter = Box<dyn Iterator<Item = Result<RecordBatch, ArrowError>> + Send>;
+
+/// The schema and the locations of the messages of an Arrow IPC file, read
+/// from its footer
+#[derive(Debug)]
+struct ArrowFileFooter {
+    schema: SchemaRef,
+    /// The byte ranges of the dictionary batches
+    dictionaries: Vec<Range<usize>>,
+    /// The byte ranges of the record batches
+    record_batches: Vec<Range<usize>>,
+}
+
+/// Reads the footer of the Arrow IPC file `meta`, or returns `None` if
+/// `meta` is an Arrow IPC stream. Only the trailer and the footer at the end
+/// of the file are fetched
+async fn read_arrow_footer(
+    store: &dyn ObjectStore,
+    meta: &ObjectMeta,
+) -> Result<Option<ArrowFileFooter>> {
+    // a file starts with the padded magic bytes and ends with the trailer
+    if meta.size < ARROW_MAGIC.len() + 2 + ARROW_TRAILER_LEN {
+        return Ok(None);
+    }
+    let trailer_start = meta.size - ARROW_TRAILER_LEN;
+    let trailer = store
+        .get_range(&meta.location, trailer_start..meta.size)
+        .await?;
+    if &trailer[4..] != ARROW_MAGIC {
+        return Ok(None);
+    }
+
+    let footer_len = i32::from_le_bytes(trailer[..4].try_into().unwrap());
+    let footer_len = usize::try_from(footer_len)
+        .ok()
+        .filter(|len| *len <= trailer_start)
+        .ok_or_else(|| {
+            DataFusionError::Execution(format!(
+                "Invalid Arrow IPC footer length {footer_len}"
+            ))
+        })?;
+    let footer = store
+        .get_range(&meta.location, trailer_start - footer_len..trailer_start)
+        .await?;
+    let footer = root_as_footer(&footer).map_err(|e| {
+        DataFusionError::Execution(format!("Unable to read Arrow IPC footer: {e:?}"))
+    })?;
+
+    let schema = footer.schema().ok_or_else(|| {
+        DataFusionError::Execution("Arrow IPC footer has no schema".to_string())
+    })?;
+    let dictionaries = footer
+        .dictionaries()
+        .map(|blocks| {
+            blocks
+                .iter()
+                .map(|block| {
+                    block_range(
+                        block.offset(),
+                        block.metaDataLength(),
+                        block.bodyLength(),
+                    )
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+    let record_batches = footer
+        .recordBatches()
+        .map(|blocks| {
+            blocks
+                .iter()
+                .map(|block| {
+                    block_range(
+                        block.offset(),
+                        block.metaDataLength(),
+                        block.bodyLength(),
+                    )
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(Some(ArrowFileFooter {
+        schema: Arc::new(fb_to_schema(schema)),
+        dictionaries,
+        record_batches,
+    }))
+}
+
+/// Returns the byte range of a message of an Arrow IPC file from the
+/// location recorded in the footer
+fn block_range(offset: i64, metadata_len: i32, body_len: i64) -> Result<Range<usize>> {
+    let invalid = || {
+        DataFusionError::Execution(format!(
+            "Invalid Arrow IPC block at offset {offset} with metadata length \
+             {metadata_len} and body length {body_len}"
+        ))
+    };
+    let offset = usize::try_from(offset).map_err(|_| invalid())?;
+    let metadata_len = usize::try_from(metadata_len).map_err(|_| invalid())?;
+    let body_len = usize::try_from(body_len).map_err(|_| invalid())?;
+    Ok(offset..offset + metadata_len + body_len)
+}
+
+/// Returns the offset and the length of the flatbuffer header of an
+/// encapsulated Arrow IPC message from its first 8 bytes. Messages written
+/// since format version 0.15 start with a continuation marker before the
+/// length of the header
+fn message_header_len(prefix: &[u8]) -> (usize, i32) {
+    if prefix[..4] == CONTINUATION_MARKER {
+        (8, i32::from_le_bytes(prefix[4..8].try_into().unwrap()))
+    } else {
+        (4, i32::from_le_bytes(prefix[..4].try_into().unwrap()))
+    }
+}
+
+/// Splits an encapsulated message of an Arrow IPC file into its flatbuffer
+/// header and its body
+fn decode_block(block: &[u8]) -> Result<(Message<'_>, Buffer)> {
+    let invalid = || DataFusionError::Execution("Invalid Arrow IPC message".to_string());
+    if block.len() < 8 {
+        return Err(invalid());
+    }
+    let (prefix_len, header_len) = message_header_len(&block[..8]);
+    let header_end = usize::try_from(header_len)
+        .ok()
+        .map(|len| prefix_len + len)
+        .filter(|end| *end <= block.len())
+        .ok_or_else(invalid)?;
+
+    let message = root_as_message(&block[prefix_len..header_end]).map_err(|e| {
+        DataFusionError::Execution(format!("Unable to read Arrow IPC message: {e:?}"))
+    })?;
+    let body_len = usize::try_from(message.bodyLength()).map_err(|_| invalid())?;
+    let body = block
+        .get(header_end..header_end + body_len)
+        .ok_or_else(invalid)?;
+    Ok((message, Buffer::from(body)))
+}
+
+/// Reads the projected columns of the record batches of an Arrow IPC file
+/// and adapts them to the table schema.
+///
+/// With a `range`, only the record batches that start in the range are
+/// read. Besides the footer and the dictionaries, only the record batches
+/// that are read are fetched from the store, one at a time
+async fn read_arrow_file_batches(
+    store: Arc<dyn ObjectStore>,
+    meta: ObjectMeta,
+    footer: ArrowFileFooter,
+    schema_adapter: SchemaAdapter,
+    projection: Arc<[usize]>,
+    range: Option<FileRange>,
+) -> Result<BoxStream<'static, Result<RecordBatch, ArrowError>>> {
+    let ArrowFileFooter {
+        schema,
+        dictionaries,
+        record_batches,
+    } = footer;
+    let file_projection: Arc<[usize]> =
+        Arc::from(schema_adapter.map_projections(&schema, &projection)?);
+
+    let record_batches: Vec<_> = match &range {
+        Some(FileRange { start, end }) => record_batches
+            .into_iter()
+            .filter(|batch| {
+                let offset = batch.start as i64;
+                offset >= *start && offset < *end
+            })
+            .collect(),
+        None => record_batches,
+    };
+    if record_batches.is_empty() {
+        return Ok(futures::stream::empty().boxed());
+    }
+
+    let mut dictionaries_by_id = HashMap::new();
+    if !dictionaries.is_empty() {
+        for block in store.get_ranges(&meta.location, &dictionaries).await? {
+            let (message, body) = decode_block(&block)?;
+            let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                DataFusionError::Execution(
+                    "Expected an Arrow IPC dictionary batch".to_string(),
+                )
+            })?;
+            read_dictionary(
+                &body,
+                batch,
+                &schema,
+                &mut dictionaries_by_id,
+                &message.version(),
+            )?;
+        }
+    }
+    let dictionaries_by_id = Arc::new(dictionaries_by_id);
+
+    let stream = futures::stream::iter(record_batches).then(move |block| {
+        let store = store.clone();
+        let location = meta.location.clone();
+        let schema = schema.clone();
+        let dictionaries_by_id = dictionaries_by_id.clone();
+        let schema_adapter = schema_adapter.clone();
+        let file_projection = file_projection.clone();
+        let projection = projection.clone();
+        async move {
+            let block = store.get_range(&location, block).await?;
+            let (message, body) = decode_block(&block)?;
+            let batch = message.header_as_record_batch().ok_or_else(|| {
+                DataFusionError::Execution(
+                    "Expected an Arrow IPC record batch".to_string(),
+                )
+            })?;
+            let batch = read_record_batch(
+                &body,
+                batch,
+                schema,
+                &dictionaries_by_id,
+                Some(file_projection.as_ref()),
+                &message.version(),
+            )?;
+            Ok::<_, DataFusionError>(schema_adapter.adapt_batch(batch, &projection)?)
+        }
+        .map_err(ArrowError::from)
+    });
+    Ok(stream.boxed())
+}
+
+/// Reads the schema from the header of an Arrow IPC stream, fetching only
+/// the schema message at its start
+async fn read_arrow_stream_schema(
+    store: &dyn ObjectStore,
+    meta: &ObjectMeta,
+) -> Result<SchemaRef> {
+    let invalid = || {
+        DataFusionError::Execution(format!(
+            "{} is not an Arrow IPC file or stream",
+            meta.location
+        ))
+    };
+    if meta.size < 8 {
+        return Err(invalid());
+    }
+    let prefix = store.get_range(&meta.location, 0..8).await?;
+    let (header_start, header_len) = message_header_len(&prefix);
+    let header_end = usize::try_from(header_len)
+        .ok()
+        .map(|len| header_start + len)
+        .filter(|end| *end <= meta.size)
+        .ok_or_else(invalid)?;
+
+    let header = store
+        .get_range(&meta.location, header_start..header_end)
+        .await?;
+    let message = root_as_message(&header).map_err(|_| invalid())?;
+    let schema = message.header_as_schema().ok_or_else(invalid)?;
+    Ok(Arc::new(fb_to_schema(schema)))
+}
+
+/// Reads the schema of an Arrow IPC file from its footer, or of an Arrow IPC
+/// stream from its header, without fetching the whole object
+pub(crate) async fn read_arrow_schema(
+    store: &dyn ObjectStore,
+    meta: &ObjectMeta,
+) -> Result<SchemaRef> {
+    match read_arrow_footer(store, meta).await? {
+        Some(footer) => Ok(footer.schema),
+        None => read_arrow_stream_schema(store, meta).await,
+    }
+}
+
+/// Reads the projected columns of the record batches of an Arrow IPC stream
+/// and adapts them to the table schema
+fn read_arrow_stream_batches<R: Read + Seek + Send + 'static>(
+    mut reader: R,
+    schema_adapter: SchemaAdapter,
+    projection: Arc<[usize]>,
+) -> Result<RecordBatchIter> {
+    let file_schema = StreamReader::try_new(&mut reader, None)?.schema();
+    reader.seek(SeekFrom::Start(0))?;
+    let file_projection = schema_adapter.map_projections(&file_schema, &projection)?;
+    let batches = StreamReader::try_new(reader, Some(file_projection))?;
+
+    Ok(Box::new(batches.map(
+        move |batch| -> Result<RecordBatch, ArrowError> {
+            Ok(schema_adapter.adapt_batch(batch?, &projection)?)
+        },
+    )))
+}
+
+/// Executes `plan` and writes the batches of each of its partitions to an
+/// Arrow IPC file in the directory `path`
+pub async fn plan_to_arrow(
+    task_ctx: Arc<TaskContext>,
+    plan: Arc<dyn ExecutionPlan>,
+    path: impl AsRef<str>,
+) -> Result<()> {
+    let path = path.as_ref();
+    // create directory to contain the Arrow files (one per partition)
+    let fs_path = Path::new(path);
+    if let Err(e) = fs::create_dir(fs_path) {
+        return Err(DataFusionError::Execution(format!(
+            "Could not create directory {path}: {e:?}"
+        )));
+    }
+
+    let mut tasks = vec![];
+    for i in 0..plan.output_partitioning().partition_count() {
+        let plan = plan.clone();
+        let filename = format!("part-{i}.arrow");
+        let path = fs_path.join(filename);
+        let mut writer = IPCWriter::new(&path, plan.schema().as_ref())?;
+        let mut stream = plan.execute(i, task_ctx.clone())?;
+        let handle: JoinHandle<Result<()>> = task::spawn(async move {
+            while let Some(batch) = stream.next().await.transpose()? {
+                writer.write(&batch)?;
+            }
+            writer.finish()
+        });
+        tasks.push(AbortOnDropSingle::new(handle));
+    }
+
+    futures::future::join_all(tasks)
+        .await
+        .into_iter()
+        .try_for_each(|result| {
+            result.map_err(|e| DataFusionError::Execution(format!("{e}")))?
+        })?;
+    Ok(())
+}
@@ -206,6 +206,73 @@ mod private {
     }
 }
 
+/// Executes `plan` and writes its partitions to the Avro files `part-{i}.avro`
+/// in the directory `path`
+#[cfg(feature = "avro")]
+pub async fn plan_to_avro(
+    task_ctx: Arc<TaskContext>,
+    plan: Arc<dyn ExecutionPlan>,
+    path: impl AsRef<str>,
+) -> Result<()> {
+    use crate::avro_to_arrow::{to_avro_schema, Writer};
+    use crate::error::DataFusionError;
+    use crate::physical_plan::common::AbortOnDropSingle;
+    use futures::StreamExt;
+    use std::fs;
+    use std::path::Path;
+    use tokio::task::{self, JoinHandle};
+
+    let path = path.as_ref();
+    // create directory to contain the Avro files (one per partition)
+    let fs_path = Path::new(path);
+    if let Err(e) = fs::create_dir(fs_path) {
+        return Err(DataFusionError::Execution(format!(
+            "Could not create directory {path}: {e:?}"
+        )));
+    }
+
+    let schema = to_avro_schema(&plan.schema())?;
+    let mut tasks = vec![];
+    for i in 0..plan.output_partitioning().partition_count() {
+        let plan = plan.clone();
+        let schema = schema.clone();
+        let filename = format!("part-{i}.avro");
+        let path = fs_path.join(filename);
+        let file = fs::File::create(path)?;
+        let mut stream = plan.execute(i, task_ctx.clone())?;
+        let handle: JoinHandle<Result<()>> = task::spawn(async move {
+            let mut writer = Writer::new(&schema, file);
+            while let Some(batch) = stream.next().await {
+                writer.write(&batch?)?;
+            }
+            writer.finish()?;
+            Ok(())
+        });
+        tasks.push(AbortOnDropSingle::new(handle));
+    }
+
+    futures::future::join_all(tasks)
+        .await
+        .into_iter()
+        .try_for_each(|result| {
+            result.map_err(|e| DataFusionError::Execution(format!("{e}")))?
+        })?;
+    Ok(())
+}
+
+/// Executes `plan` and writes its partitions to Avro files (requires the
+/// avro feature)
+#[cfg(not(feature = "avro"))]
+pub async fn plan_to_avro(
+    _task_ctx: Arc<TaskContext>,
+    _plan: Arc<dyn ExecutionPlan>,
+    _path: impl AsRef<str>,
+) -> Result<()> {
+    Err(crate::error::DataFusionError::NotImplemented(
+        "Cannot write avro files without avro feature enabled".to_string(),
+    ))
+}
+
 #[cfg(test)]
 #[cfg(feature = "avro")]
 mod tests {
@@ -213,7 +280,7 @@ mod tests {
     use crate::datasource::listing::PartitionedFile;
     use crate::datasource::object_store::ObjectStoreUrl;
     use crate::physical_plan::file_format::chunked_store::ChunkedStore;
-    use crate::physical_plan::file_format::partition_type_wrap;
+    use crate::physical_plan::file_format::{partition_type_wrap, SchemaEvolution};
     use crate::prelude::SessionContext;
     use crate::scalar::ScalarValue;
     use crate::test::object_store::local_unpartitioned_file;
@@ -264,10 +331,12 @@ mod tests {
             file_schema,
             statistics: Statistics::default(),
             projection: Some(vec![0, 1, 2]),
+            nested_projection: None,
             limit: None,
             table_partition_cols: vec![],
             output_ordering: None,
             infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
         });
         assert_eq!(avro_exec.output_partitioning().partition_count(), 1);
         let mut results = avro_exec
@@ -336,10 +405,12 @@ mod tests {
             file_schema,
             statistics: Statistics::default(),
             projection,
+            nested_projection: None,
             limit: None,
             table_partition_cols: vec![],
             output_ordering: None,
             infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
         });
         assert_eq!(avro_exec.output_partitioning().partition_count(), 1);
 
@@ -408,6 +479,7 @@ mod tests {
             file_groups: vec![vec![partitioned_file]],
             file_schema,
             statistics: Statistics::default(),
+            nested_projection: None,
             limit: None,
             table_partition_cols: vec![(
                 "date".to_owned(),
@@ -415,6 +487,7 @@ mod tests {
             )],
             output_ordering: None,
             infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
         });
         assert_eq!(avro_exec.output_partitioning().partition_count(), 1);
 
@@ -21,6 +21,7 @@ use crate::datasource::file_format::file_type::FileCompressionType;
 use crate::error::{DataFusionError, Result};
 use crate::execution::context::TaskContext;
 use crate::physical_plan::expressions::PhysicalSortExpr;
+use crate::physical_plan::file_format::csv_dialect::{CsvDialect, DialectDecoder};
 use crate::physical_plan::file_format::file_stream::{
     FileOpenFuture, FileOpener, FileStream,
 };
@@ -31,16 +32,20 @@ use crate::physical_plan::{
 };
 use arrow::csv;
 use arrow::datatypes::SchemaRef;
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
 
 use bytes::Buf;
 
 use crate::physical_plan::common::AbortOnDropSingle;
 use bytes::Bytes;
 use futures::ready;
+use futures::stream::BoxStream;
 use futures::{StreamExt, TryStreamExt};
 use object_store::{GetResult, ObjectStore};
 use std::any::Any;
 use std::fs;
+use std::io::Read;
 use std::path::Path;
 use std::sync::Arc;
 use std::task::Poll;
@@ -56,6 +61,7 @@ pub struct CsvExec {
     projected_schema: SchemaRef,
     has_header: bool,
     delimiter: u8,
+    dialect: CsvDialect,
     /// Execution metrics
     metrics: ExecutionPlanMetricsSet,
     file_compression_type: FileCompressionType,
@@ -77,11 +83,18 @@ impl CsvExec {
             projected_statistics,
             has_header,
             delimiter,
+            dialect: CsvDialect::default(),
             metrics: ExecutionPlanMetricsSet::new(),
             file_compression_type,
         }
     }
 
+    /// Set the dialect of the files, e.g. their quote and comment characters
+    pub fn with_dialect(mut self, dialect: CsvDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
     /// Ref to the base configs
     pub fn base_config(&self) -> &FileScanConfig {
         &self.base_config
@@ -94,6 +107,10 @@ impl CsvExec {
     pub fn delimiter(&self) -> u8 {
         self.delimiter
     }
+    /// The dialect of the files
+    pub fn dialect(&self) -> &CsvDialect {
+        &self.dialect
+    }
 }
 
 impl ExecutionPlan for CsvExec {
@@ -148,6 +165,7 @@ impl ExecutionPlan for CsvExec {
             file_projection: self.base_config.file_column_projection_indices(),
             has_header: self.has_header,
             delimiter: self.delimiter,
+            dialect: self.dialect.clone(),
             object_store,
         });
 
@@ -195,37 +213,133 @@ struct CsvConfig {
     file_projection: Option<Vec<usize>>,
     has_header: bool,
     delimiter: u8,
+    dialect: CsvDialect,
     object_store: Arc<dyn ObjectStore>,
 }
 
 impl CsvConfig {
-    fn open<R: std::io::Read>(&self, reader: R) -> csv::Reader<R> {
-        let datetime_format = None;
-        csv::Reader::new(
-            reader,
-            Arc::clone(&self.file_schema),
-            self.has_header,
-            Some(self.delimiter),
-            self.batch_size,
-            None,
-            self.file_projection.clone(),
-            datetime_format,
-        )
-    }
-
     fn builder(&self) -> csv::ReaderBuilder {
         let mut builder = csv::ReaderBuilder::new()
             .with_schema(self.file_schema.clone())
             .with_delimiter(self.delimiter)
+            .with_quote(self.dialect.quote)
             .with_batch_size(self.batch_size)
             .has_header(self.has_header);
 
+        if let Some(escape) = self.dialect.escape {
+            builder = builder.with_escape(escape);
+        }
+        if let Some(terminator) = self.dialect.terminator {
+            builder = builder.with_terminator(terminator);
+        }
         if let Some(proj) = &self.file_projection {
             builder = builder.with_projection(proj.clone());
         }
 
         builder
     }
+
+    /// Decodes `input` with the arrow CSV decoder
+    fn decode(
+        &self,
+        input: BoxStream<'static, Result<Bytes>>,
+    ) -> BoxStream<'static, Result<RecordBatch, ArrowError>> {
+        let mut decoder = self.builder().build_decoder();
+        let mut input = input.fuse();
+        let mut buffered = Bytes::new();
+
+        futures::stream::poll_fn(move |cx| {
+            loop {
+                if buffered.is_empty() {
+                    match ready!(input.poll_next_unpin(cx)) {
+                        Some(Ok(b)) => buffered = b,
+                        Some(Err(e)) => return Poll::Ready(Some(Err(e.into()))),
+                        None => {}
+                    };
+                }
+                let decoded = match decoder.decode(buffered.as_ref()) {
+                    // Note: the decoder needs to be called with an empty
+                    // array to delimt the final record
+                    Ok(0) => break,
+                    Ok(decoded) => decoded,
+                    Err(e) => return Poll::Ready(Some(Err(e))),
+                };
+                buffered.advance(decoded);
+            }
+
+            Poll::Ready(decoder.flush().transpose())
+        })
+        .boxed()
+    }
+
+    /// Decodes `input` with a [`DialectDecoder`], for the dialects the arrow
+    /// CSV decoder does not support
+    fn decode_dialect(
+        &self,
+        input: BoxStream<'static, Result<Bytes>>,
+    ) -> Result<BoxStream<'static, Result<RecordBatch, ArrowError>>> {
+        let decoder = DialectDecoder::try_new(
+            self.delimiter,
+            &self.dialect,
+            self.has_header,
+            self.batch_size,
+            Arc::clone(&self.file_schema),
+            self.file_projection.clone(),
+        )?;
+
+        let state = Some((input, decoder, false));
+        let s = futures::stream::unfold(state, |state| async move {
+            let (mut input, mut decoder, mut eof) = state?;
+            loop {
+                if eof || decoder.has_batch() {
+                    return match decoder.flush() {
+                        Ok(Some(batch)) => Some((Ok(batch), Some((input, decoder, eof)))),
+                        Ok(None) => None,
+                        Err(e) => Some((Err(e.into()), None)),
+                    };
+                }
+                match input.next().await {
+                    Some(Ok(data)) => decoder.decode(&data, false),
+                    Some(Err(e)) => return Some((Err(e.into()), None)),
+                    None => {
+                        decoder.decode(&[], true);
+                        eof = true;
+                    }
+                }
+            }
+        });
+        Ok(s.boxed())
+    }
+}
+
+/// The size of the chunks local files are read in
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Reads `reader` in chunks of [`READ_CHUNK_SIZE`] bytes
+pub(super) fn read_chunks(
+    mut reader: impl Read + Send,
+) -> impl Iterator<Item = Result<Bytes>> {
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let mut buf = vec![0; READ_CHUNK_SIZE];
+        match reader.read(&mut buf) {
+            Ok(0) => {
+                done = true;
+                None
+            }
+            Ok(read) => {
+                buf.truncate(read);
+                Some(Ok(Bytes::from(buf)))
+            }
+            Err(e) => {
+                done = true;
+                Some(Err(e.into()))
+            }
+        }
+    })
 }
 
 struct CsvOpener {
@@ -238,42 +352,20 @@ impl FileOpener for CsvOpener {
         let config = self.config.clone();
         let file_compression_type = self.file_compression_type.to_owned();
         Ok(Box::pin(async move {
-            match config.object_store.get(file_meta.location()).await? {
+            let input = match config.object_store.get(file_meta.location()).await? {
                 GetResult::File(file, _) => {
-                    let decoder = file_compression_type.convert_read(file)?;
-                    Ok(futures::stream::iter(config.open(decoder)).boxed())
+                    let reader = file_compression_type.convert_read(file)?;
+                    futures::stream::iter(read_chunks(reader)).boxed()
                 }
                 GetResult::Stream(s) => {
-                    let mut decoder = config.builder().build_decoder();
                     let s = s.map_err(DataFusionError::from);
-                    let mut input = file_compression_type.convert_stream(s)?.fuse();
-                    let mut buffered = Bytes::new();
-
-                    let s = futures::stream::poll_fn(move |cx| {
-                        loop {
-                            if buffered.is_empty() {
-                                match ready!(input.poll_next_unpin(cx)) {
-                                    Some(Ok(b)) => buffered = b,
-                                    Some(Err(e)) => {
-                                        return Poll::Ready(Some(Err(e.into())))
-                                    }
-                                    None => {}
-                                };
-                            }
-                            let decoded = match decoder.decode(buffered.as_ref()) {
-                                // Note: the decoder needs to be called with an empty
-                                // array to delimt the final record
-                                Ok(0) => break,
-                                Ok(decoded) => decoded,
-                                Err(e) => return Poll::Ready(Some(Err(e))),
-                            };
-                            buffered.advance(decoded);
-                        }
-
-                        Poll::Ready(decoder.flush().transpose())
-                    });
-                    Ok(s.boxed())
+                    file_compression_type.convert_stream(s)?.boxed()
                 }
+            };
+            if config.dialect.is_arrow_compatible() {
+                Ok(config.decode(input))
+            } else {
+                config.decode_dialect(input)
             }
         }))
     }
@@ -327,6 +419,7 @@ mod tests {
     use crate::datasource::file_format::file_type::FileType;
     use crate::physical_plan::file_format::chunked_store::ChunkedStore;
     use crate::physical_plan::file_format::partition_type_wrap;
+    use crate::physical_plan::file_format::CsvMalformedMode;
     use crate::prelude::*;
     use crate::test::{partitioned_csv_config, partitioned_file_groups};
     use crate::test_util::{aggr_test_schema_with_missing_col, arrow_test_data};
@@ -742,6 +835,41 @@ mod tests {
         crate::assert_batches_eq!(expected, &result);
     }
 
+    #[tokio::test]
+    async fn test_csv_dialect() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let store = object_store::memory::InMemory::new();
+
+        let data = bytes::Bytes::from("# comment\na;b\n1;'x;y'\n2;NULL\n3\n");
+        let path = object_store::path::Path::from("a.csv");
+        store.put(&path, data).await.unwrap();
+
+        session_ctx
+            .runtime_env()
+            .register_object_store("memory", "", Arc::new(store));
+
+        let options = CsvReadOptions::new()
+            .delimiter(b';')
+            .quote(b'\'')
+            .comment(b'#')
+            .null_regex("NULL")
+            .malformed_mode(CsvMalformedMode::Capture);
+        let df = session_ctx.read_csv("memory:///", options).await?;
+        let result = df.collect().await?;
+
+        let expected = vec![
+            "+---+-----+----------------+",
+            "| a | b   | _malformed_row |",
+            "+---+-----+----------------+",
+            "| 1 | x;y |                |",
+            "| 2 |     |                |",
+            "|   |     | 3              |",
+            "+---+-----+----------------+",
+        ];
+        crate::assert_batches_eq!(expected, &result);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn write_csv_results_error_handling() -> Result<()> {
         let ctx = SessionContext::new();
@@ -0,0 +1,798 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading of CSV dialects that the arrow CSV reader does not support:
+//! comment lines, null markers and lenient handling of malformed rows
+
+use std::mem;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, BooleanArray, StringArray};
+use arrow::compute::{cast_with_options, filter_record_batch, CastOptions};
+use arrow::datatypes::{DataType, SchemaRef};
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use regex::Regex;
+
+use crate::error::{DataFusionError, Result};
+
+/// The name of the column that holds the text of malformed rows with
+/// [`CsvMalformedMode::Capture`]
+pub const MALFORMED_ROW_COLUMN: &str = "_malformed_row";
+
+/// How to handle the rows of CSV files that can not be read with the table
+/// schema, because they have the wrong number of fields or values that can
+/// not be parsed as the type of their column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvMalformedMode {
+    /// Fail the scan on the first malformed row
+    Fail,
+    /// Skip malformed rows
+    Skip,
+    /// Keep malformed rows, with null for the values that can not be read
+    /// and the text of the row in the [`MALFORMED_ROW_COLUMN`] column, if
+    /// the table has it
+    Capture,
+}
+
+impl Default for CsvMalformedMode {
+    fn default() -> Self {
+        Self::Fail
+    }
+}
+
+impl FromStr for CsvMalformedMode {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "fail" => Ok(Self::Fail),
+            "skip" => Ok(Self::Skip),
+            "capture" => Ok(Self::Capture),
+            _ => Err(DataFusionError::Plan(format!(
+                "Unknown CSV malformed row mode '{s}', expected one of fail, skip, capture"
+            ))),
+        }
+    }
+}
+
+/// The dialect of CSV files, beyond their delimiter and header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvDialect {
+    /// The character quoting values, `"` by default
+    pub quote: u8,
+    /// The character escaping the next character within quoted values.
+    /// Quotes are escaped by doubling them if `None`
+    pub escape: Option<u8>,
+    /// The character terminating rows, `\n` or `\r\n` if `None`
+    pub terminator: Option<u8>,
+    /// Lines starting with this character are ignored
+    pub comment: Option<u8>,
+    /// Values matching this regular expression are read as null
+    pub null_regex: Option<String>,
+    /// How to handle malformed rows
+    pub malformed_mode: CsvMalformedMode,
+}
+
+impl Default for CsvDialect {
+    fn default() -> Self {
+        Self {
+            quote: b'"',
+            escape: None,
+            terminator: None,
+            comment: None,
+            null_regex: None,
+            malformed_mode: CsvMalformedMode::Fail,
+        }
+    }
+}
+
+impl CsvDialect {
+    /// Returns true if files of this dialect can be read by the arrow CSV
+    /// reader, which does not support comments, null markers and lenient
+    /// handling of malformed rows
+    pub fn is_arrow_compatible(&self) -> bool {
+        self.comment.is_none()
+            && self.null_regex.is_none()
+            && self.malformed_mode == CsvMalformedMode::Fail
+    }
+
+    /// Compiles the null regex, anchored to match whole values
+    pub(crate) fn compile_null_regex(&self) -> Result<Option<Regex>> {
+        self.null_regex
+            .as_ref()
+            .map(|null_regex| {
+                Regex::new(&format!("^(?:{null_regex})$")).map_err(|e| {
+                    DataFusionError::Plan(format!(
+                        "Invalid CSV null regex '{null_regex}': {e}"
+                    ))
+                })
+            })
+            .transpose()
+    }
+}
+
+/// A record of a CSV file, split into its fields
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct RawRecord {
+    /// The unquoted bytes of the fields
+    pub fields: Vec<Vec<u8>>,
+    /// The bytes of the whole record, without its terminator
+    pub raw: Vec<u8>,
+}
+
+/// The state of a record that [`RecordParser::resume_record`] has not
+/// finished parsing, as the data ended within it
+#[derive(Debug, Default)]
+pub(crate) struct PartialRecord {
+    fields: Vec<Vec<u8>>,
+    field: Vec<u8>,
+    field_started: bool,
+    in_quotes: bool,
+    /// The number of bytes of the record that have been parsed
+    parsed: usize,
+}
+
+/// Splits CSV data of a [`CsvDialect`] into records
+#[derive(Debug, Clone)]
+pub(crate) struct RecordParser {
+    delimiter: u8,
+    quote: u8,
+    escape: Option<u8>,
+    terminator: Option<u8>,
+    comment: Option<u8>,
+}
+
+impl RecordParser {
+    pub(crate) fn new(delimiter: u8, dialect: &CsvDialect) -> Self {
+        Self {
+            delimiter,
+            quote: dialect.quote,
+            escape: dialect.escape,
+            terminator: dialect.terminator,
+            comment: dialect.comment,
+        }
+    }
+
+    fn is_terminator(&self, b: u8) -> bool {
+        match self.terminator {
+            Some(terminator) => b == terminator,
+            None => b == b'\n' || b == b'\r',
+        }
+    }
+
+    /// Parses the record at the start of `buf`. Returns the record, or
+    /// `None` for comment and empty lines, and the number of bytes that
+    /// were consumed. Returns `None` if `buf` ends within the record and
+    /// more data is expected, i.e. unless `eof`
+    pub(crate) fn next_record(
+        &self,
+        buf: &[u8],
+        eof: bool,
+    ) -> Option<(Option<RawRecord>, usize)> {
+        self.resume_record(&mut PartialRecord::default(), buf, eof)
+    }
+
+    /// Like [`Self::next_record`], but resumes parsing the record at the
+    /// start of `buf` where `partial` stopped, rather than from its start.
+    /// `partial` is updated if `buf` ends within the record, and reset once
+    /// the record is complete
+    pub(crate) fn resume_record(
+        &self,
+        partial: &mut PartialRecord,
+        buf: &[u8],
+        eof: bool,
+    ) -> Option<(Option<RawRecord>, usize)> {
+        if buf.is_empty() {
+            return None;
+        }
+
+        let is_comment = self.comment == Some(buf[0]);
+        let mut i = partial.parsed;
+        while i < buf.len() {
+            let b = buf[i];
+            if partial.in_quotes {
+                if Some(b) == self.escape || b == self.quote {
+                    let next = match buf.get(i + 1) {
+                        Some(next) => Some(*next),
+                        None if eof => None,
+                        None => {
+                            partial.parsed = i;
+                            return None;
+                        }
+                    };
+                    if Some(b) == self.escape && next.is_some() {
+                        partial.field.extend(next);
+                        i += 2;
+                    } else if b == self.quote && next == Some(self.quote) {
+                        // a doubled quote is a literal quote
+                        partial.field.push(self.quote);
+                        i += 2;
+                    } else if b == self.quote {
+                        partial.in_quotes = false;
+                        i += 1;
+                    } else {
+                        partial.field.push(b);
+                        i += 1;
+                    }
+                } else {
+                    partial.field.push(b);
+                    i += 1;
+                }
+            } else if self.is_terminator(b) {
+                // `\r\n` is a single terminator
+                let mut consumed = i + 1;
+                if self.terminator.is_none() && b == b'\r' {
+                    match buf.get(i + 1) {
+                        Some(b'\n') => consumed += 1,
+                        None if !eof => {
+                            partial.parsed = i;
+                            return None;
+                        }
+                        _ => {}
+                    }
+                }
+                let PartialRecord {
+                    mut fields, field, ..
+                } = mem::take(partial);
+                if is_comment || i == 0 {
+                    return Some((None, consumed));
+                }
+                fields.push(field);
+                let raw = buf[..i].to_vec();
+                return Some((Some(RawRecord { fields, raw }), consumed));
+            } else if is_comment {
+                i += 1;
+            } else if b == self.delimiter {
+                let field = mem::take(&mut partial.field);
+                partial.fields.push(field);
+                partial.field_started = false;
+                i += 1;
+            } else if b == self.quote && !partial.field_started {
+                partial.in_quotes = true;
+                partial.field_started = true;
+                i += 1;
+            } else {
+                partial.field.push(b);
+                partial.field_started = true;
+                i += 1;
+            }
+        }
+
+        // the data ends without a terminator
+        if !eof {
+            partial.parsed = i;
+            return None;
+        }
+        let PartialRecord {
+            mut fields, field, ..
+        } = mem::take(partial);
+        if is_comment {
+            return Some((None, buf.len()));
+        }
+        fields.push(field);
+        let raw = buf.to_vec();
+        Some((Some(RawRecord { fields, raw }), buf.len()))
+    }
+
+    /// Parses all the records of `buf`, which holds whole records
+    pub(crate) fn records(&self, mut buf: &[u8]) -> Vec<RawRecord> {
+        let mut records = vec![];
+        while let Some((record, consumed)) = self.next_record(buf, true) {
+            records.extend(record);
+            buf = &buf[consumed..];
+        }
+        records
+    }
+}
+
+/// Rewrites the records of `buf`, which holds whole records of a
+/// [`CsvDialect`], as comma separated values that the arrow CSV reader can
+/// read, e.g. to infer their schema. Comments are removed and values
+/// matching `null_regex` are emptied.
+///
+/// Unless malformed rows fail the scan, records with a different number of
+/// fields than the first one are removed as well
+pub(crate) fn to_standard_csv(
+    parser: &RecordParser,
+    dialect: &CsvDialect,
+    null_regex: Option<&Regex>,
+    buf: &[u8],
+) -> Vec<u8> {
+    let records = parser.records(buf);
+    let num_fields = records.first().map(|record| record.fields.len());
+
+    let mut out = Vec::with_capacity(buf.len());
+    for record in records {
+        if dialect.malformed_mode != CsvMalformedMode::Fail
+            && Some(record.fields.len()) != num_fields
+        {
+            continue;
+        }
+        for (i, field) in record.fields.iter().enumerate() {
+            if i > 0 {
+                out.push(b',');
+            }
+            let is_null = match (null_regex, std::str::from_utf8(field)) {
+                (Some(null_regex), Ok(value)) => null_regex.is_match(value),
+                _ => false,
+            };
+            if !is_null && !field.is_empty() {
+                out.push(b'"');
+                for b in field {
+                    if *b == b'"' {
+                        out.push(b'"');
+                    }
+                    out.push(*b);
+                }
+                out.push(b'"');
+            }
+        }
+        out.push(b'\n');
+    }
+    out
+}
+
+/// A record read from a CSV file, to be converted to the table schema
+struct CsvRecord {
+    /// The number of the record in its file, counting from 1
+    number: usize,
+    /// The text of the record
+    raw: String,
+    /// The values of the record, or why the record is malformed
+    values: std::result::Result<Vec<String>, String>,
+}
+
+/// Decodes CSV data of a [`CsvDialect`] into record batches of the file
+/// schema, handling malformed rows according to the dialect
+pub(crate) struct DialectDecoder {
+    parser: RecordParser,
+    malformed_mode: CsvMalformedMode,
+    null_regex: Option<Regex>,
+    has_header: bool,
+    batch_size: usize,
+    file_schema: SchemaRef,
+    /// The columns of the file schema that are read from the files
+    csv_columns: Vec<usize>,
+    projection: Vec<usize>,
+    /// Data that does not hold a whole record yet
+    buffer: Vec<u8>,
+    /// How far the record at the start of `buffer` has been parsed
+    partial: PartialRecord,
+    records: Vec<CsvRecord>,
+    records_read: usize,
+}
+
+impl DialectDecoder {
+    pub(crate) fn try_new(
+        delimiter: u8,
+        dialect: &CsvDialect,
+        has_header: bool,
+        batch_size: usize,
+        file_schema: SchemaRef,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self> {
+        let malformed_column = match dialect.malformed_mode {
+            CsvMalformedMode::Capture => file_schema.index_of(MALFORMED_ROW_COLUMN).ok(),
+            _ => None,
+        };
+        let num_columns = file_schema.fields().len();
+        let csv_columns = (0..num_columns)
+            .filter(|i| Some(*i) != malformed_column)
+            .collect();
+        let projection = projection.unwrap_or_else(|| (0..num_columns).collect());
+
+        Ok(Self {
+            parser: RecordParser::new(delimiter, dialect),
+            malformed_mode: dialect.malformed_mode,
+            null_regex: dialect.compile_null_regex()?,
+            has_header,
+            batch_size,
+            file_schema,
+            csv_columns,
+            projection,
+            buffer: vec![],
+            partial: PartialRecord::default(),
+            records: vec![],
+            records_read: 0,
+        })
+    }
+
+    /// Parses the whole records of `data`, buffering the rest until more
+    /// data is decoded. Parses the remaining data as the last record if
+    /// `eof`
+    pub(crate) fn decode(&mut self, data: &[u8], eof: bool) {
+        self.buffer.extend_from_slice(data);
+        let mut start = 0;
+        while let Some((record, consumed)) =
+            self.parser
+                .resume_record(&mut self.partial, &self.buffer[start..], eof)
+        {
+            start += consumed;
+            if let Some(record) = record {
+                self.records_read += 1;
+                if self.has_header && self.records_read == 1 {
+                    continue;
+                }
+                let record = self.to_csv_record(record);
+                self.records.push(record);
+            }
+        }
+        self.buffer.drain(..start);
+    }
+
+    fn to_csv_record(&self, record: RawRecord) -> CsvRecord {
+        let raw = String::from_utf8_lossy(&record.raw).into_owned();
+        let values = if record.fields.len() != self.csv_columns.len() {
+            Err(format!(
+                "expected {} fields, found {}",
+                self.csv_columns.len(),
+                record.fields.len()
+            ))
+        } else {
+            record
+                .fields
+                .into_iter()
+                .map(|field| {
+                    String::from_utf8(field).map_err(|_| "invalid UTF-8".to_string())
+                })
+                .collect()
+        };
+        CsvRecord {
+            number: self.records_read,
+            raw,
+            values,
+        }
+    }
+
+    /// Returns true if a whole batch of records has been decoded
+    pub(crate) fn has_batch(&self) -> bool {
+        self.records.len() >= self.batch_size
+    }
+
+    /// Returns the next batch of the decoded records, if any
+    pub(crate) fn flush(&mut self) -> Result<Option<RecordBatch>> {
+        if self.records.is_empty() {
+            return Ok(None);
+        }
+        let rest = self
+            .records
+            .split_off(self.batch_size.min(self.records.len()));
+        let records = mem::replace(&mut self.records, rest);
+        self.build_batch(records).map(Some)
+    }
+
+    fn is_null(&self, value: &str, data_type: &DataType) -> bool {
+        match &self.null_regex {
+            Some(null_regex) if null_regex.is_match(value) => true,
+            _ => {
+                value.is_empty()
+                    && !matches!(data_type, DataType::Utf8 | DataType::LargeUtf8)
+            }
+        }
+    }
+
+    fn build_batch(&self, records: Vec<CsvRecord>) -> Result<RecordBatch> {
+        let mut malformed: Vec<Option<String>> = records
+            .iter()
+            .map(|record| record.values.as_ref().err().cloned())
+            .collect();
+
+        let cast_options = CastOptions { safe: true };
+        let mut columns: Vec<Option<ArrayRef>> = vec![];
+        for column in &self.projection {
+            let csv_index = match self.csv_columns.iter().position(|c| c == column) {
+                Some(csv_index) => csv_index,
+                // the column capturing malformed rows is filled in below
+                None => {
+                    columns.push(None);
+                    continue;
+                }
+            };
+            let field = self.file_schema.field(*column);
+            let values: Vec<Option<&str>> = records
+                .iter()
+                .map(|record| match &record.values {
+                    Ok(values) => {
+                        let value = values[csv_index].as_str();
+                        (!self.is_null(value, field.data_type())).then_some(value)
+                    }
+                    Err(_) => None,
+                })
+                .collect();
+            let array: ArrayRef = Arc::new(StringArray::from(values.clone()));
+            let array = cast_with_options(&array, field.data_type(), &cast_options)?;
+            for (row, malformed) in malformed.iter_mut().enumerate() {
+                match values[row] {
+                    Some(value) if malformed.is_none() && array.is_null(row) => {
+                        *malformed = Some(format!(
+                            "can not parse '{value}' as {} for column {}",
+                            field.data_type(),
+                            field.name()
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+            columns.push(Some(array));
+        }
+
+        if self.malformed_mode == CsvMalformedMode::Fail {
+            let first = malformed
+                .iter()
+                .enumerate()
+                .find_map(|(row, reason)| reason.as_ref().map(|reason| (row, reason)));
+            if let Some((row, reason)) = first {
+                return Err(DataFusionError::Execution(format!(
+                    "Malformed CSV record {}: {reason}",
+                    records[row].number
+                )));
+            }
+        }
+
+        let columns = columns
+            .into_iter()
+            .map(|column| {
+                column.unwrap_or_else(|| {
+                    let raw: StringArray = records
+                        .iter()
+                        .zip(&malformed)
+                        .map(|(record, reason)| {
+                            reason.as_ref().map(|_| record.raw.as_str())
+                        })
+                        .collect();
+                    Arc::new(raw)
+                })
+            })
+            .collect();
+        let schema = Arc::new(self.file_schema.project(&self.projection)?);
+        let options = RecordBatchOptions::new().with_row_count(Some(records.len()));
+        let batch = RecordBatch::try_new_with_options(schema, columns, &options)?;
+
+        match self.malformed_mode {
+            CsvMalformedMode::Skip => {
+                let keep: BooleanArray = malformed
+                    .iter()
+                    .map(|reason| Some(reason.is_none()))
+                    .collect();
+                Ok(filter_record_batch(&batch, &keep)?)
+            }
+            _ => Ok(batch),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_batches_eq;
+    use arrow::datatypes::{Field, Schema};
+
+    fn fields(records: &[RawRecord]) -> Vec<Vec<String>> {
+        records
+            .iter()
+            .map(|record| {
+                record
+                    .fields
+                    .iter()
+                    .map(|field| String::from_utf8(field.clone()).unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_records() {
+        let dialect = CsvDialect {
+            quote: b'\'',
+            escape: Some(b'\\'),
+            comment: Some(b'#'),
+            ..Default::default()
+        };
+        let parser = RecordParser::new(b';', &dialect);
+        let data = b"# a comment\r\na;'b;c'\n\n'it\\'s';'say ''hi'''\r\nd;e";
+        assert_eq!(
+            fields(&parser.records(data)),
+            vec![vec!["a", "b;c"], vec!["it's", "say 'hi'"], vec!["d", "e"],]
+        );
+
+        // a record is not complete before its terminator
+        assert_eq!(parser.next_record(b"a;b", false), None);
+        assert_eq!(parser.next_record(b"a;'b\n", false), None);
+    }
+
+    #[test]
+    fn resume_records() {
+        let dialect = CsvDialect {
+            escape: Some(b'\\'),
+            comment: Some(b'#'),
+            ..Default::default()
+        };
+        let parser = RecordParser::new(b',', &dialect);
+        let data = b"#c\r\na,\"b\\\",\"\"c\"\r\n\r\nd,e";
+
+        // feeding the data byte by byte yields the same records
+        let mut partial = PartialRecord::default();
+        let mut records = vec![];
+        let (mut start, mut end) = (0, 0);
+        while end <= data.len() {
+            let eof = end == data.len();
+            match parser.resume_record(&mut partial, &data[start..end], eof) {
+                Some((record, consumed)) => {
+                    records.extend(record);
+                    start += consumed;
+                }
+                None if eof => break,
+                None => {
+                    // the parsed bytes are not parsed again
+                    assert!(partial.parsed + 1 >= end - start);
+                    end += 1;
+                }
+            }
+        }
+        assert_eq!(fields(&records), fields(&parser.records(data)));
+        assert_eq!(fields(&records), vec![vec!["a", "b\",\"c"], vec!["d", "e"]]);
+    }
+
+    #[test]
+    fn parse_records_with_terminator() {
+        let dialect = CsvDialect {
+            terminator: Some(b'|'),
+            ..Default::default()
+        };
+        let parser = RecordParser::new(b',', &dialect);
+        assert_eq!(
+            fields(&parser.records(b"a,\"b\nc\"|d,e|")),
+            vec![vec!["a", "b\nc"], vec!["d", "e"]]
+        );
+    }
+
+    #[test]
+    fn standard_csv() -> Result<()> {
+        let dialect = CsvDialect {
+            comment: Some(b'#'),
+            null_regex: Some(r"\\N".to_string()),
+            malformed_mode: CsvMalformedMode::Skip,
+            ..Default::default()
+        };
+        let parser = RecordParser::new(b'\t', &dialect);
+        let null_regex = dialect.compile_null_regex()?;
+        let data = b"a\tb\n# comment\n1\t\\N\n2\n3\t\"x,y\"\n";
+        let csv = to_standard_csv(&parser, &dialect, null_regex.as_ref(), data);
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "\"a\",\"b\"\n\"1\",\n\"3\",\"x,y\"\n"
+        );
+        Ok(())
+    }
+
+    fn decode(
+        malformed_mode: CsvMalformedMode,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Vec<RecordBatch>> {
+        let dialect = CsvDialect {
+            comment: Some(b'#'),
+            null_regex: Some(r"\\N|NULL".to_string()),
+            malformed_mode,
+            ..Default::default()
+        };
+        let mut fields = vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("name", DataType::Utf8, true),
+        ];
+        if malformed_mode == CsvMalformedMode::Capture {
+            fields.push(Field::new(MALFORMED_ROW_COLUMN, DataType::Utf8, true));
+        }
+        let schema = Arc::new(Schema::new(fields));
+        let mut decoder =
+            DialectDecoder::try_new(b',', &dialect, true, 2, schema, projection)?;
+        // decode the data in small chunks, splitting records
+        let data = b"id,name\n# comment\n1,a\n\\N,NULL\nx,b\n3\n4,c";
+        for chunk in data.chunks(3) {
+            decoder.decode(chunk, false);
+        }
+        decoder.decode(&[], true);
+
+        let mut batches = vec![];
+        while let Some(batch) = decoder.flush()? {
+            batches.push(batch);
+        }
+        Ok(batches)
+    }
+
+    #[test]
+    fn decode_capture() -> Result<()> {
+        let batches = decode(CsvMalformedMode::Capture, None)?;
+        let expected = vec![
+            "+----+------+----------------+",
+            "| id | name | _malformed_row |",
+            "+----+------+----------------+",
+            "| 1  | a    |                |",
+            "|    |      |                |",
+            "|    | b    | x,b            |",
+            "|    |      | 3              |",
+            "| 4  | c    |                |",
+            "+----+------+----------------+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[test]
+    fn decode_skip() -> Result<()> {
+        let batches = decode(CsvMalformedMode::Skip, Some(vec![1, 0]))?;
+        let expected = vec![
+            "+------+----+",
+            "| name | id |",
+            "+------+----+",
+            "| a    | 1  |",
+            "|      |    |",
+            "| c    | 4  |",
+            "+------+----+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[test]
+    fn decode_fail() {
+        let err = decode(CsvMalformedMode::Fail, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Execution error: Malformed CSV record 4: \
+             can not parse 'x' as Int64 for column id"
+        );
+    }
+
+    #[test]
+    fn decode_empty_strings() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::LargeUtf8, true),
+            Field::new("c", DataType::Int64, true),
+        ]));
+        let dialect = CsvDialect {
+            null_regex: Some("NULL".to_string()),
+            ..Default::default()
+        };
+        let mut decoder =
+            DialectDecoder::try_new(b',', &dialect, false, 10, schema, None)?;
+        decoder.decode(b",,\nNULL,NULL,NULL\n", true);
+        let batch = decoder.flush()?.unwrap();
+
+        // empty strings are read as such, unless they match the null regex
+        for column in 0..2 {
+            let array = batch.column(column);
+            assert!(array.is_valid(0));
+            assert!(array.is_null(1));
+        }
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(a.value(0), "");
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<arrow::array::LargeStringArray>()
+            .unwrap();
+        assert_eq!(b.value(0), "");
+        assert!(batch.column(2).is_null(0));
+        Ok(())
+    }
+}
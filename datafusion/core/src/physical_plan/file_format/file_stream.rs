@@ -374,6 +374,7 @@ mod tests {
 
     use super::*;
     use crate::datasource::object_store::ObjectStoreUrl;
+    use crate::physical_plan::file_format::SchemaEvolution;
     use crate::physical_plan::metrics::ExecutionPlanMetricsSet;
     use crate::prelude::SessionContext;
     use crate::{
@@ -412,10 +413,12 @@ mod tests {
             ]],
             statistics: Default::default(),
             projection: None,
+            nested_projection: None,
             limit,
             table_partition_cols: vec![],
             output_ordering: None,
             infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
         };
         let metrics_set = ExecutionPlanMetricsSet::new();
         let file_stream = FileStream::new(&config, 0, reader, &metrics_set).unwrap();
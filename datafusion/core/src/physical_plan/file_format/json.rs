@@ -20,25 +20,32 @@ use crate::datasource::file_format::file_type::FileCompressionType;
 use crate::error::{DataFusionError, Result};
 use crate::execution::context::TaskContext;
 use crate::physical_plan::expressions::PhysicalSortExpr;
+use crate::physical_plan::file_format::csv::read_chunks;
 use crate::physical_plan::file_format::file_stream::{
     FileOpenFuture, FileOpener, FileStream,
 };
+use crate::physical_plan::file_format::json_values::{
+    needs_value_decoding, write_json_lines, JsonRecordDecoder, JsonValueDecoder,
+};
 use crate::physical_plan::file_format::FileMeta;
 use crate::physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 use crate::physical_plan::{
     DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream, Statistics,
 };
-use arrow::{datatypes::SchemaRef, json};
+use arrow::datatypes::SchemaRef;
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
 
 use bytes::{Buf, Bytes};
 
 use crate::physical_plan::common::AbortOnDropSingle;
 use arrow::json::RawReaderBuilder;
+use futures::stream::BoxStream;
 use futures::{ready, stream, StreamExt, TryStreamExt};
 use object_store::{GetResult, ObjectStore};
 use std::any::Any;
 use std::fs;
-use std::io::BufReader;
+use std::io::{BufWriter, Write};
 use std::path::Path;
 use std::sync::Arc;
 use std::task::Poll;
@@ -73,6 +80,16 @@ impl NdJsonExec {
             file_compression_type,
         }
     }
+
+    /// Ref to the base configs
+    pub fn base_config(&self) -> &FileScanConfig {
+        &self.base_config
+    }
+
+    /// Compression type of the scanned files
+    pub fn file_compression_type(&self) -> &FileCompressionType {
+        &self.file_compression_type
+    }
 }
 
 impl ExecutionPlan for NdJsonExec {
@@ -157,6 +174,7 @@ impl ExecutionPlan for NdJsonExec {
     }
 }
 
+#[derive(Clone)]
 struct JsonOpener {
     batch_size: usize,
     projected_schema: SchemaRef,
@@ -164,60 +182,118 @@ struct JsonOpener {
     object_store: Arc<dyn ObjectStore>,
 }
 
+impl JsonOpener {
+    /// Decodes `input` with the arrow JSON decoder
+    fn decode(
+        &self,
+        input: BoxStream<'static, Result<Bytes>>,
+    ) -> Result<BoxStream<'static, Result<RecordBatch, ArrowError>>> {
+        let mut decoder = RawReaderBuilder::new(self.projected_schema.clone())
+            .with_batch_size(self.batch_size)
+            .build_decoder()?;
+        let mut input = input.fuse();
+        let mut buffered = Bytes::new();
+
+        let s = stream::poll_fn(move |cx| {
+            loop {
+                if buffered.is_empty() {
+                    buffered = match ready!(input.poll_next_unpin(cx)) {
+                        Some(Ok(b)) => b,
+                        Some(Err(e)) => return Poll::Ready(Some(Err(e.into()))),
+                        None => break,
+                    };
+                }
+                let read = buffered.len();
+
+                let decoded = match decoder.decode(buffered.as_ref()) {
+                    Ok(decoded) => decoded,
+                    Err(e) => return Poll::Ready(Some(Err(e))),
+                };
+
+                buffered.advance(decoded);
+                if decoded != read {
+                    break;
+                }
+            }
+
+            Poll::Ready(decoder.flush().transpose())
+        });
+        Ok(s.boxed())
+    }
+
+    /// Decodes `input` with a [`JsonRecordDecoder`], for the files holding a
+    /// top-level array and the schemas with fields read as their JSON text
+    fn decode_values(
+        &self,
+        input: BoxStream<'static, Result<Bytes>>,
+    ) -> BoxStream<'static, Result<RecordBatch, ArrowError>> {
+        let decoder =
+            JsonRecordDecoder::new(self.projected_schema.clone(), self.batch_size);
+
+        let state = Some((input, decoder, false));
+        stream::unfold(state, |state| async move {
+            let (mut input, mut decoder, mut eof) = state?;
+            loop {
+                if eof || decoder.has_batch() {
+                    return match decoder.flush() {
+                        Ok(Some(batch)) => Some((Ok(batch), Some((input, decoder, eof)))),
+                        Ok(None) => None,
+                        Err(e) => Some((Err(e.into()), None)),
+                    };
+                }
+                let decoded = match input.next().await {
+                    Some(Ok(data)) => decoder.decode(&data),
+                    Some(Err(e)) => Err(e),
+                    None => {
+                        eof = true;
+                        decoder.finish()
+                    }
+                };
+                if let Err(e) = decoded {
+                    return Some((Err(e.into()), None));
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
 impl FileOpener for JsonOpener {
     fn open(&self, file_meta: FileMeta) -> Result<FileOpenFuture> {
-        let store = self.object_store.clone();
-        let schema = self.projected_schema.clone();
-        let batch_size = self.batch_size;
-
-        let file_compression_type = self.file_compression_type.to_owned();
+        let opener = self.clone();
         Ok(Box::pin(async move {
-            match store.get(file_meta.location()).await? {
+            let store = &opener.object_store;
+            let file_compression_type = &opener.file_compression_type;
+            let mut input = match store.get(file_meta.location()).await? {
                 GetResult::File(file, _) => {
-                    let bytes = file_compression_type.convert_read(file)?;
-                    let reader = RawReaderBuilder::new(schema)
-                        .with_batch_size(batch_size)
-                        .build(BufReader::new(bytes))?;
-                    Ok(futures::stream::iter(reader).boxed())
+                    let reader = file_compression_type.convert_read(file)?;
+                    stream::iter(read_chunks(reader)).boxed()
                 }
                 GetResult::Stream(s) => {
-                    let mut decoder = RawReaderBuilder::new(schema)
-                        .with_batch_size(batch_size)
-                        .build_decoder()?;
-
                     let s = s.map_err(DataFusionError::from);
-                    let mut input = file_compression_type.convert_stream(s)?.fuse();
-                    let mut buffered = Bytes::new();
-
-                    let s = stream::poll_fn(move |cx| {
-                        loop {
-                            if buffered.is_empty() {
-                                buffered = match ready!(input.poll_next_unpin(cx)) {
-                                    Some(Ok(b)) => b,
-                                    Some(Err(e)) => {
-                                        return Poll::Ready(Some(Err(e.into())))
-                                    }
-                                    None => break,
-                                };
-                            }
-                            let read = buffered.len();
-
-                            let decoded = match decoder.decode(buffered.as_ref()) {
-                                Ok(decoded) => decoded,
-                                Err(e) => return Poll::Ready(Some(Err(e))),
-                            };
-
-                            buffered.advance(decoded);
-                            if decoded != read {
-                                break;
-                            }
-                        }
-
-                        Poll::Ready(decoder.flush().transpose())
-                    });
-                    Ok(s.boxed())
+                    file_compression_type.convert_stream(s)?.boxed()
+                }
+            };
+
+            // buffer the leading chunks until the layout of the file is known
+            let mut head = vec![];
+            let mut is_array = None;
+            while is_array.is_none() {
+                match input.next().await.transpose()? {
+                    Some(chunk) => {
+                        is_array = JsonValueDecoder::is_array(&chunk);
+                        head.push(Ok(chunk));
+                    }
+                    None => break,
                 }
             }
+            let input = stream::iter(head).chain(input).boxed();
+
+            if is_array == Some(true) || needs_value_decoding(&opener.projected_schema) {
+                Ok(opener.decode_values(input))
+            } else {
+                opener.decode(input)
+            }
         }))
     }
 }
@@ -241,15 +317,15 @@ pub async fn plan_to_json(
         let plan = plan.clone();
         let filename = format!("part-{i}.json");
         let path = fs_path.join(filename);
-        let file = fs::File::create(path)?;
-        let mut writer = json::LineDelimitedWriter::new(file);
+        let mut writer = BufWriter::new(fs::File::create(path)?);
         let stream = plan.execute(i, task_ctx.clone())?;
         let handle: JoinHandle<Result<()>> = task::spawn(async move {
             stream
-                .map(|batch| writer.write(batch?))
-                .try_collect()
-                .await
-                .map_err(DataFusionError::from)
+                .map(|batch| write_json_lines(&mut writer, &batch?))
+                .try_collect::<()>()
+                .await?;
+            writer.flush()?;
+            Ok(())
         });
         tasks.push(AbortOnDropSingle::new(handle));
     }
@@ -277,6 +353,7 @@ mod tests {
     use crate::datasource::object_store::ObjectStoreUrl;
     use crate::execution::context::SessionState;
     use crate::physical_plan::file_format::chunked_store::ChunkedStore;
+    use crate::physical_plan::file_format::SchemaEvolution;
     use crate::prelude::NdJsonReadOptions;
     use crate::prelude::*;
     use crate::test::partitioned_file_groups;
@@ -403,10 +480,12 @@ mod tests {
                 file_schema,
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: Some(3),
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             file_compression_type.to_owned(),
         );
@@ -479,10 +558,12 @@ mod tests {
                 file_schema,
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: Some(3),
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             file_compression_type.to_owned(),
         );
@@ -525,10 +606,12 @@ mod tests {
                 file_schema,
                 statistics: Statistics::default(),
                 projection: Some(vec![0, 2]),
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             file_compression_type.to_owned(),
         );
@@ -576,10 +659,12 @@ mod tests {
                 file_schema,
                 statistics: Statistics::default(),
                 projection: Some(vec![3, 0, 2]),
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             file_compression_type.to_owned(),
         );
@@ -656,6 +741,32 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn write_json_nested_results() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let ctx = SessionContext::new();
+        let path = format!("{TEST_DATA_BASE}/nested_array.json");
+        let df = ctx.read_json(&path, NdJsonReadOptions::default()).await?;
+        let expected = df
+            .clone()
+            .select_columns(&["id", "person"])?
+            .collect()
+            .await?;
+
+        let out_dir = tmp_dir.as_ref().to_str().unwrap().to_string() + "/out";
+        df.write_json(&out_dir).await?;
+
+        // the structs and lists are written as nested JSON objects and arrays
+        let actual = ctx
+            .read_json(&out_dir, NdJsonReadOptions::default())
+            .await?
+            .select_columns(&["id", "person"])?
+            .collect()
+            .await?;
+        assert_eq!(expected, actual);
+        Ok(())
+    }
+
     #[rstest(
         file_compression_type,
         case(FileCompressionType::UNCOMPRESSED),
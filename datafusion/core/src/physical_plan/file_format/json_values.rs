@@ -0,0 +1,858 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decoding of JSON files into JSON values, for the files that can not be
+//! read by the arrow JSON decoder: files holding a top-level JSON array of
+//! objects, and files with columns of mixed types read as their JSON text.
+//! Also the writing of record batches as JSON objects with nested values
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::io::Write;
+
+use arrow::array::{Array, ArrayRef};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::json::reader::{Decoder, DecoderOptions};
+use arrow::record_batch::RecordBatch;
+use arrow::util::display::array_value_to_string;
+use datafusion_common::cast::{
+    as_boolean_array, as_large_list_array, as_list_array, as_struct_array,
+};
+use indexmap::IndexMap;
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Number, Value};
+
+use crate::error::{DataFusionError, Result};
+
+/// The metadata key of the `Utf8` JSON fields whose values have mixed types,
+/// or are nested deeper than the inferred depth, and are read as their JSON text
+pub const JSON_TEXT_META_KEY: &str = "json::text";
+
+/// A JSON value read from a file. Unlike a [`Value`], its objects keep the
+/// order of their fields in the file, which is the order of the inferred
+/// columns and of the fields in JSON text
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(IndexMap<String, JsonValue>),
+}
+
+impl<'de> Deserialize<'de> for JsonValue {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonValueVisitor)
+    }
+}
+
+struct JsonValueVisitor;
+
+impl<'de> Visitor<'de> for JsonValueVisitor {
+    type Value = JsonValue;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a JSON value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> std::result::Result<JsonValue, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> std::result::Result<JsonValue, E> {
+        Ok(JsonValue::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> std::result::Result<JsonValue, E> {
+        Ok(JsonValue::Number(v.into()))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> std::result::Result<JsonValue, E> {
+        Ok(JsonValue::Number(v.into()))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> std::result::Result<JsonValue, E> {
+        Number::from_f64(v)
+            .map(JsonValue::Number)
+            .ok_or_else(|| E::custom(format!("invalid JSON number {v}")))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> std::result::Result<JsonValue, E> {
+        Ok(JsonValue::String(v.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> std::result::Result<JsonValue, E> {
+        Ok(JsonValue::String(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut seq: A,
+    ) -> std::result::Result<JsonValue, A::Error> {
+        let mut values = vec![];
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(JsonValue::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(
+        self,
+        mut map: A,
+    ) -> std::result::Result<JsonValue, A::Error> {
+        // a repeated field replaces the value of the first one, in its place
+        let mut object = IndexMap::new();
+        while let Some((name, value)) = map.next_entry()? {
+            object.insert(name, value);
+        }
+        Ok(JsonValue::Object(object))
+    }
+}
+
+impl Serialize for JsonValue {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            JsonValue::Null => serializer.serialize_unit(),
+            JsonValue::Bool(v) => serializer.serialize_bool(*v),
+            JsonValue::Number(v) => v.serialize(serializer),
+            JsonValue::String(v) => serializer.serialize_str(v),
+            JsonValue::Array(values) => serializer.collect_seq(values),
+            JsonValue::Object(object) => serializer.collect_map(object),
+        }
+    }
+}
+
+impl Display for JsonValue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let text = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        write!(f, "{text}")
+    }
+}
+
+impl From<JsonValue> for Value {
+    fn from(value: JsonValue) -> Self {
+        match value {
+            JsonValue::Null => Value::Null,
+            JsonValue::Bool(v) => Value::Bool(v),
+            JsonValue::Number(v) => Value::Number(v),
+            JsonValue::String(v) => Value::String(v),
+            JsonValue::Array(values) => {
+                Value::Array(values.into_iter().map(Value::from).collect())
+            }
+            JsonValue::Object(object) => Value::Object(
+                object
+                    .into_iter()
+                    .map(|(name, value)| (name, value.into()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// The layout of the values of a JSON file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonLayout {
+    /// Values separated by whitespace, usually newlines
+    Lines,
+    /// The elements of a top-level JSON array
+    Array(ArrayPosition),
+}
+
+/// Where the decoding of a top-level JSON array is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArrayPosition {
+    /// After the opening bracket
+    Start,
+    /// After an element, before a comma or the closing bracket
+    Element,
+    /// After a comma, before an element
+    Comma,
+    /// After the closing bracket
+    End,
+}
+
+/// How far a JSON value was scanned for its end. The end is found without
+/// parsing the value, so that a value split across chunks is scanned once and
+/// only parsed when complete
+#[derive(Debug, Default)]
+struct ValueScan {
+    /// The number of bytes of the value that were scanned
+    len: usize,
+    /// The number of arrays and objects open after these bytes
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+}
+
+impl ValueScan {
+    /// Continues to scan the value at the start of `buf`, which holds the
+    /// bytes scanned so far, and returns its length if it is complete
+    fn scan(&mut self, buf: &[u8]) -> Option<usize> {
+        while self.len < buf.len() {
+            let b = buf[self.len];
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if b == b'\\' {
+                    self.escaped = true;
+                } else if b == b'"' {
+                    self.in_string = false;
+                    if self.depth == 0 {
+                        return Some(self.len + 1);
+                    }
+                }
+            } else if self.depth == 0 && self.len > 0 {
+                // a number or literal ends at whitespace or punctuation
+                if b.is_ascii_whitespace() || b"{}[],\"".contains(&b) {
+                    return Some(self.len);
+                }
+            } else {
+                match b {
+                    b'"' => self.in_string = true,
+                    b'{' | b'[' => self.depth += 1,
+                    b'}' | b']' => {
+                        self.depth = self.depth.saturating_sub(1);
+                        if self.depth == 0 {
+                            return Some(self.len + 1);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            self.len += 1;
+        }
+        None
+    }
+}
+
+/// Splits the bytes of a JSON file into its values, which are either
+/// separated by whitespace or the elements of a top-level array. The bytes
+/// can be provided in chunks that split values anywhere.
+#[derive(Debug, Default)]
+pub(crate) struct JsonValueDecoder {
+    buffer: Vec<u8>,
+    /// Unknown until the first byte that is not whitespace
+    layout: Option<JsonLayout>,
+    /// How far the value at the start of `buffer` was scanned
+    scan: ValueScan,
+}
+
+impl JsonValueDecoder {
+    /// Returns true if `data`, the start of a JSON file, holds a top-level
+    /// array, or None if it is only whitespace
+    pub(crate) fn is_array(data: &[u8]) -> Option<bool> {
+        data.iter()
+            .find(|b| !b.is_ascii_whitespace())
+            .map(|b| *b == b'[')
+    }
+
+    /// Decodes the complete values of `data`, appended to the bytes left by
+    /// the previous calls, into `values`
+    pub(crate) fn decode(
+        &mut self,
+        data: &[u8],
+        values: &mut Vec<JsonValue>,
+    ) -> Result<()> {
+        self.buffer.extend_from_slice(data);
+        let mut pos = 0;
+        loop {
+            if self.scan.len == 0 {
+                while pos < self.buffer.len() && self.buffer[pos].is_ascii_whitespace() {
+                    pos += 1;
+                }
+                if pos == self.buffer.len() {
+                    break;
+                }
+                let next = match (self.layout, self.buffer[pos]) {
+                    (None, b'[') => Some(JsonLayout::Array(ArrayPosition::Start)),
+                    (None, _) => {
+                        self.layout = Some(JsonLayout::Lines);
+                        None
+                    }
+                    (Some(JsonLayout::Array(ArrayPosition::End)), _) => {
+                        return Err(DataFusionError::Execution(
+                            "Unexpected data after the end of the JSON array".to_string(),
+                        ))
+                    }
+                    (
+                        Some(JsonLayout::Array(
+                            ArrayPosition::Start | ArrayPosition::Element,
+                        )),
+                        b']',
+                    ) => Some(JsonLayout::Array(ArrayPosition::End)),
+                    (Some(JsonLayout::Array(ArrayPosition::Element)), b',') => {
+                        Some(JsonLayout::Array(ArrayPosition::Comma))
+                    }
+                    (Some(JsonLayout::Array(ArrayPosition::Element)), b) => {
+                        return Err(DataFusionError::Execution(format!(
+                            "Expected ',' or ']' after an element of the JSON array, \
+                             found '{}'",
+                            b as char
+                        )))
+                    }
+                    _ => None,
+                };
+                if let Some(next) = next {
+                    self.layout = Some(next);
+                    pos += 1;
+                    continue;
+                }
+            }
+
+            let len = match self.scan.scan(&self.buffer[pos..]) {
+                Some(len) => len,
+                // the value continues in the next chunk
+                None => break,
+            };
+            let value =
+                serde_json::from_slice(&self.buffer[pos..pos + len]).map_err(|e| {
+                    DataFusionError::Execution(format!("Invalid JSON value: {e}"))
+                })?;
+            values.push(value);
+            pos += len;
+            self.scan = ValueScan::default();
+            if let Some(JsonLayout::Array(position)) = &mut self.layout {
+                *position = ArrayPosition::Element;
+            }
+        }
+        self.buffer.drain(..pos);
+        Ok(())
+    }
+
+    /// Checks that the file did not end within a value or an array
+    pub(crate) fn finish(&self) -> Result<()> {
+        let in_array = matches!(
+            self.layout,
+            Some(JsonLayout::Array(position)) if position != ArrayPosition::End
+        );
+        if self.buffer.iter().any(|b| !b.is_ascii_whitespace()) || in_array {
+            return Err(DataFusionError::Execution(
+                "Unexpected end of JSON file".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The type of JSON values inferred from the values seen so far
+#[derive(Debug, Clone, PartialEq)]
+enum InferredType {
+    Null,
+    Scalar(DataType),
+    List(Box<InferredType>),
+    Object(Vec<(String, InferredType)>),
+    /// Values of mixed types, or nested deeper than the maximum depth
+    Text,
+}
+
+impl InferredType {
+    fn merge(self, other: InferredType) -> InferredType {
+        use InferredType::*;
+        match (self, other) {
+            (Null, other) | (other, Null) => other,
+            (Scalar(a), Scalar(b)) if a == b => Scalar(a),
+            (Scalar(a), Scalar(b))
+                if matches!(
+                    (&a, &b),
+                    (DataType::Int64, DataType::Float64)
+                        | (DataType::Float64, DataType::Int64)
+                ) =>
+            {
+                Scalar(DataType::Float64)
+            }
+            (List(a), List(b)) => List(Box::new(a.merge(*b))),
+            (Object(a), Object(b)) => Object(merge_object_fields(a, b)),
+            _ => Text,
+        }
+    }
+
+    fn to_field(&self, name: &str) -> Field {
+        let data_type = match self {
+            InferredType::Null => DataType::Null,
+            InferredType::Scalar(data_type) => data_type.clone(),
+            InferredType::List(item) => DataType::List(Box::new(item.to_field("item"))),
+            InferredType::Object(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    .map(|(name, inferred)| inferred.to_field(name))
+                    .collect(),
+            ),
+            InferredType::Text => {
+                return Field::new(name, DataType::Utf8, true).with_metadata(
+                    HashMap::from([(JSON_TEXT_META_KEY.to_string(), "true".to_string())]),
+                )
+            }
+        };
+        Field::new(name, data_type, true)
+    }
+}
+
+fn merge_object_fields(
+    mut fields: Vec<(String, InferredType)>,
+    others: Vec<(String, InferredType)>,
+) -> Vec<(String, InferredType)> {
+    for (name, other) in others {
+        match fields.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, inferred)) => {
+                *inferred = std::mem::replace(inferred, InferredType::Null).merge(other)
+            }
+            None => fields.push((name, other)),
+        }
+    }
+    fields
+}
+
+/// Infers the schema of JSON objects. Conflicting types are merged into
+/// `Utf8` fields holding the JSON text of the values, as are the values
+/// nested deeper than the maximum depth
+#[derive(Debug)]
+pub(crate) struct JsonSchemaInference {
+    fields: Vec<(String, InferredType)>,
+    max_depth: usize,
+}
+
+impl JsonSchemaInference {
+    /// Create a new inference of structs and lists nested up to `max_depth`
+    /// levels, the columns of the objects being the first level
+    pub(crate) fn new(max_depth: Option<usize>) -> Self {
+        Self {
+            fields: vec![],
+            max_depth: max_depth.unwrap_or(usize::MAX),
+        }
+    }
+
+    /// Update the schema with the fields of `value`
+    pub(crate) fn push(&mut self, value: &JsonValue) -> Result<()> {
+        match value {
+            JsonValue::Object(object) => {
+                let fields = self.infer_object(object, 1);
+                let current = std::mem::take(&mut self.fields);
+                self.fields = merge_object_fields(current, fields);
+                Ok(())
+            }
+            other => Err(DataFusionError::Execution(format!(
+                "Expected a JSON object, got {other}"
+            ))),
+        }
+    }
+
+    fn infer_object(
+        &self,
+        object: &IndexMap<String, JsonValue>,
+        depth: usize,
+    ) -> Vec<(String, InferredType)> {
+        object
+            .iter()
+            .map(|(name, value)| (name.clone(), self.infer(value, depth)))
+            .collect()
+    }
+
+    fn infer(&self, value: &JsonValue, depth: usize) -> InferredType {
+        match value {
+            JsonValue::Null => InferredType::Null,
+            JsonValue::Bool(_) => InferredType::Scalar(DataType::Boolean),
+            JsonValue::Number(n) if n.is_i64() => InferredType::Scalar(DataType::Int64),
+            JsonValue::Number(_) => InferredType::Scalar(DataType::Float64),
+            JsonValue::String(_) => InferredType::Scalar(DataType::Utf8),
+            JsonValue::Array(_) | JsonValue::Object(_) if depth >= self.max_depth => {
+                InferredType::Text
+            }
+            JsonValue::Array(values) => InferredType::List(Box::new(
+                values
+                    .iter()
+                    .map(|v| self.infer(v, depth + 1))
+                    .fold(InferredType::Null, InferredType::merge),
+            )),
+            JsonValue::Object(object) => {
+                InferredType::Object(self.infer_object(object, depth + 1))
+            }
+        }
+    }
+
+    /// Returns the inferred schema
+    pub(crate) fn finish(self) -> Schema {
+        Schema::new(
+            self.fields
+                .iter()
+                .map(|(name, inferred)| inferred.to_field(name))
+                .collect(),
+        )
+    }
+}
+
+/// Returns true if `field` or one of its nested fields holds JSON text
+fn has_json_text(field: &Field) -> bool {
+    field.metadata().contains_key(JSON_TEXT_META_KEY)
+        || match field.data_type() {
+            DataType::List(item) | DataType::LargeList(item) => has_json_text(item),
+            DataType::Struct(fields) => fields.iter().any(has_json_text),
+            _ => false,
+        }
+}
+
+/// Returns true if files of `schema` must be read with a [`JsonRecordDecoder`]
+/// as it has fields holding JSON text
+pub(crate) fn needs_value_decoding(schema: &Schema) -> bool {
+    schema.fields().iter().any(has_json_text)
+}
+
+/// Converts `value` to the value the arrow decoder expects for `field`: the
+/// values of JSON text fields are converted to strings
+fn normalize(value: JsonValue, field: &Field) -> Value {
+    if field.metadata().contains_key(JSON_TEXT_META_KEY) {
+        return match value {
+            JsonValue::Null => Value::Null,
+            JsonValue::String(v) => Value::String(v),
+            other => Value::String(other.to_string()),
+        };
+    }
+    match (value, field.data_type()) {
+        (JsonValue::Object(object), DataType::Struct(fields)) => {
+            Value::Object(normalize_object(object, fields))
+        }
+        (JsonValue::Array(values), DataType::List(item) | DataType::LargeList(item)) => {
+            Value::Array(values.into_iter().map(|v| normalize(v, item)).collect())
+        }
+        (value, _) => value.into(),
+    }
+}
+
+fn normalize_object(
+    mut object: IndexMap<String, JsonValue>,
+    fields: &[Field],
+) -> Map<String, Value> {
+    fields
+        .iter()
+        .filter_map(|field| {
+            let value = object.swap_remove(field.name())?;
+            Some((field.name().clone(), normalize(value, field)))
+        })
+        .collect()
+}
+
+/// Decodes JSON files with a [`JsonValueDecoder`] into record batches
+pub(crate) struct JsonRecordDecoder {
+    values: JsonValueDecoder,
+    decoder: Decoder,
+    schema: SchemaRef,
+    batch_size: usize,
+    records: Vec<JsonValue>,
+}
+
+impl JsonRecordDecoder {
+    /// Create a new decoder of the records of `schema`
+    pub(crate) fn new(schema: SchemaRef, batch_size: usize) -> Self {
+        let options = DecoderOptions::new().with_batch_size(batch_size);
+        Self {
+            values: JsonValueDecoder::default(),
+            decoder: Decoder::new(schema.clone(), options),
+            schema,
+            batch_size,
+            records: vec![],
+        }
+    }
+
+    /// Decode the complete records of `data`
+    pub(crate) fn decode(&mut self, data: &[u8]) -> Result<()> {
+        self.values.decode(data, &mut self.records)
+    }
+
+    /// Returns true if a full batch of records was decoded
+    pub(crate) fn has_batch(&self) -> bool {
+        self.records.len() >= self.batch_size
+    }
+
+    /// Checks that the file did not end within a record
+    pub(crate) fn finish(&self) -> Result<()> {
+        self.values.finish()
+    }
+
+    /// Returns the next batch of the decoded records, if any
+    pub(crate) fn flush(&mut self) -> Result<Option<RecordBatch>> {
+        if self.records.is_empty() {
+            return Ok(None);
+        }
+        let len = self.records.len().min(self.batch_size);
+        let fields = self.schema.fields();
+        let mut records = self.records.drain(..len).map(|record| match record {
+            JsonValue::Object(object) => {
+                Ok(Value::Object(normalize_object(object, fields)))
+            }
+            other => Err(arrow::error::ArrowError::JsonError(format!(
+                "Expected a JSON object, got {other}"
+            ))),
+        });
+        Ok(self.decoder.next_batch(&mut records)?)
+    }
+}
+
+/// Writes the value at `row` of `array` as JSON. Structs are written as
+/// objects without their null fields and lists as arrays. Numbers are written
+/// as their exact decimal text, or null if they are not finite, and the other
+/// types as their display string
+fn write_json_value(writer: &mut impl Write, array: &ArrayRef, row: usize) -> Result<()> {
+    if array.is_null(row) {
+        writer.write_all(b"null")?;
+        return Ok(());
+    }
+    match array.data_type() {
+        DataType::Boolean => {
+            write!(writer, "{}", as_boolean_array(array)?.value(row))?;
+        }
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Decimal128(_, _) => {
+            writer.write_all(array_value_to_string(array, row)?.as_bytes())?;
+        }
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+            let value = array_value_to_string(array, row)?;
+            match value.parse::<f64>() {
+                Ok(v) if v.is_finite() => writer.write_all(value.as_bytes())?,
+                _ => writer.write_all(b"null")?,
+            }
+        }
+        DataType::List(_) => write_json_array(writer, &as_list_array(array)?.value(row))?,
+        DataType::LargeList(_) => {
+            write_json_array(writer, &as_large_list_array(array)?.value(row))?
+        }
+        DataType::Struct(fields) => {
+            let array = as_struct_array(array)?;
+            let columns = (0..array.num_columns()).map(|i| array.column(i));
+            write_json_object(writer, columns.zip(fields), row)?
+        }
+        DataType::Dictionary(_, value_type) => {
+            let values = cast(&array.slice(row, 1), value_type)?;
+            write_json_value(writer, &values, 0)?
+        }
+        _ => write_json_string(writer, &array_value_to_string(array, row)?)?,
+    }
+    Ok(())
+}
+
+fn write_json_string(writer: &mut impl Write, value: &str) -> Result<()> {
+    serde_json::to_writer(&mut *writer, value)
+        .map_err(|e| DataFusionError::Execution(format!("Can not write JSON: {e}")))
+}
+
+fn write_json_array(writer: &mut impl Write, array: &ArrayRef) -> Result<()> {
+    writer.write_all(b"[")?;
+    for row in 0..array.len() {
+        if row > 0 {
+            writer.write_all(b",")?;
+        }
+        write_json_value(writer, array, row)?;
+    }
+    writer.write_all(b"]")?;
+    Ok(())
+}
+
+fn write_json_object<'a>(
+    writer: &mut impl Write,
+    columns: impl Iterator<Item = (&'a ArrayRef, &'a Field)>,
+    row: usize,
+) -> Result<()> {
+    writer.write_all(b"{")?;
+    let mut first = true;
+    for (column, field) in columns.filter(|(column, _)| column.is_valid(row)) {
+        if !first {
+            writer.write_all(b",")?;
+        }
+        first = false;
+        write_json_string(writer, field.name())?;
+        writer.write_all(b":")?;
+        write_json_value(writer, column, row)?;
+    }
+    writer.write_all(b"}")?;
+    Ok(())
+}
+
+/// Writes the rows of `batch` to `writer` as newline-delimited JSON objects,
+/// with the fields in the order of the schema
+pub(crate) fn write_json_lines(
+    writer: &mut impl Write,
+    batch: &RecordBatch,
+) -> Result<()> {
+    let schema = batch.schema();
+    for row in 0..batch.num_rows() {
+        write_json_object(writer, batch.columns().iter().zip(schema.fields()), row)?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn decode_chunks(data: &[u8], chunk_size: usize) -> Result<Vec<Value>> {
+        let mut decoder = JsonValueDecoder::default();
+        let mut values = vec![];
+        for chunk in data.chunks(chunk_size) {
+            decoder.decode(chunk, &mut values)?;
+            // the bytes of a partial value are not scanned again
+            assert!(decoder.scan.len == 0 || decoder.scan.len == decoder.buffer.len());
+        }
+        decoder.finish()?;
+        Ok(values.into_iter().map(Value::from).collect())
+    }
+
+    fn parse(text: &str) -> JsonValue {
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn decode_lines_and_arrays() -> Result<()> {
+        let expected = vec![json!({"a": 1}), json!({"a": [2, 3]}), json!({"b": "]"})];
+        let lines = b"{\"a\": 1}\n{\"a\": [2, 3]}\n{\"b\": \"]\"}\n";
+        let array = b" [\n  {\"a\": 1},\n  {\"a\": [2, 3]},\n  {\"b\": \"]\"}\n]\n";
+        for chunk_size in [1, 3, 7, 100] {
+            assert_eq!(decode_chunks(lines, chunk_size)?, expected);
+            assert_eq!(decode_chunks(array, chunk_size)?, expected);
+        }
+
+        let err = decode_chunks(b"[{\"a\": 1},", 100).unwrap_err();
+        assert!(
+            err.to_string().contains("Unexpected end of JSON file"),
+            "{err}"
+        );
+
+        // the elements of an array must be separated by commas
+        for data in [
+            &b"[1 2]"[..],
+            b"[{\"a\": 1} {\"a\": 2}]",
+            b"[1,,2]",
+            b"[1,]",
+        ] {
+            for chunk_size in [1, 100] {
+                assert!(decode_chunks(data, chunk_size).is_err());
+            }
+        }
+        assert_eq!(decode_chunks(b"[1, 2]", 1)?, vec![json!(1), json!(2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn keep_field_order() -> Result<()> {
+        let mut inference = JsonSchemaInference::new(Some(1));
+        inference.push(&parse(r#"{"b": 1, "a": {"d": 1, "c": 2}}"#))?;
+        let schema = inference.finish();
+        let names: Vec<_> = schema.fields().iter().map(|f| f.name()).collect();
+        assert_eq!(names, vec!["b", "a"]);
+
+        // JSON text keeps the order of the fields
+        let value = normalize(
+            parse(r#"{"d": 1, "c": 2}"#),
+            &InferredType::Text.to_field("a"),
+        );
+        assert_eq!(value, json!(r#"{"d":1,"c":2}"#));
+        Ok(())
+    }
+
+    #[test]
+    fn infer_nested_schema() -> Result<()> {
+        let mut inference = JsonSchemaInference::new(Some(2));
+        for value in [
+            parse(r#"{"a": 1, "b": {"c": 1.5, "d": {"e": 1}}, "f": [1]}"#),
+            parse(r#"{"a": 2.5, "b": {"c": 2, "d": [1]}, "f": "x", "g": null}"#),
+        ] {
+            inference.push(&value)?;
+        }
+        let text = |name: &str| InferredType::Text.to_field(name);
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new(
+                "b",
+                DataType::Struct(vec![
+                    Field::new("c", DataType::Float64, true),
+                    text("d"),
+                ]),
+                true,
+            ),
+            text("f"),
+            Field::new("g", DataType::Null, true),
+        ]);
+        assert_eq!(inference.finish(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn decode_json_text() -> Result<()> {
+        let mut inference = JsonSchemaInference::new(None);
+        let data =
+            r#"[{"a": 1, "b": {"c": true}}, {"a": "x", "b": {"c": [1, {"d": 2}]}}]"#;
+        let values = decode_chunks(data.as_bytes(), 100)?;
+        for value in &values {
+            inference.push(value)?;
+        }
+        let schema = inference.finish();
+        assert!(needs_value_decoding(&schema));
+
+        let mut decoder = JsonRecordDecoder::new(Arc::new(schema), 10);
+        decoder.decode(data.as_bytes())?;
+        decoder.finish()?;
+        let batch = decoder.flush()?.unwrap();
+        let expected = vec![
+            "+---+------------------+",
+            "| a | b                |",
+            "+---+------------------+",
+            "| 1 | {c: true}        |",
+            "| x | {c: [1,{\"d\":2}]} |",
+            "+---+------------------+",
+        ];
+        crate::assert_batches_eq!(expected, &[batch]);
+        Ok(())
+    }
+
+    #[test]
+    fn write_exact_numbers() -> Result<()> {
+        use arrow::array::{Decimal128Array, Float64Array};
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("d", DataType::Decimal128(20, 2), true),
+            Field::new("f", DataType::Float64, true),
+        ]));
+        let decimals = Decimal128Array::from(vec![Some(1234567890123456789), None])
+            .with_precision_and_scale(20, 2)?;
+        let floats = Float64Array::from(vec![f64::NAN, 0.5]);
+        let batch =
+            RecordBatch::try_new(schema, vec![Arc::new(decimals), Arc::new(floats)])?;
+
+        let mut out = vec![];
+        write_json_lines(&mut out, &batch)?;
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"d\":12345678901234567.89,\"f\":null}\n{\"f\":0.5}\n"
+        );
+        Ok(())
+    }
+}
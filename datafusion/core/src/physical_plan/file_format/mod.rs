@@ -17,29 +17,45 @@
 
 //! Execution plans that read file formats
 
+mod arrow_file;
 mod avro;
 #[cfg(test)]
 mod chunked_store;
 mod csv;
+mod csv_dialect;
 mod file_stream;
 mod json;
+mod json_values;
 mod parquet;
+mod schema_evolution;
 
 pub(crate) use self::csv::plan_to_csv;
 pub use self::csv::CsvExec;
+pub(crate) use self::csv_dialect::{to_standard_csv, RecordParser};
+pub use self::csv_dialect::{CsvDialect, CsvMalformedMode, MALFORMED_ROW_COLUMN};
 pub(crate) use self::parquet::plan_to_parquet;
-pub use self::parquet::{ParquetExec, ParquetFileMetrics, ParquetFileReaderFactory};
+pub use self::parquet::{
+    parquet_writer_properties, ParquetExec, ParquetFileMetrics, ParquetFileReaderFactory,
+};
 use arrow::{
     array::{ArrayData, ArrayRef, DictionaryArray},
     buffer::Buffer,
     datatypes::{DataType, Field, Schema, SchemaRef, UInt16Type},
     record_batch::RecordBatch,
 };
+pub(crate) use arrow_file::plan_to_arrow;
+pub(crate) use arrow_file::read_arrow_schema;
+pub use arrow_file::ArrowExec;
+pub(crate) use avro::plan_to_avro;
 pub use avro::AvroExec;
+use datafusion_common::utils::project_nested_fields;
 use datafusion_physical_expr::PhysicalSortExpr;
 pub use file_stream::{FileOpenFuture, FileOpener, FileStream};
 pub(crate) use json::plan_to_json;
 pub use json::NdJsonExec;
+pub use json_values::JSON_TEXT_META_KEY;
+pub(crate) use json_values::{JsonSchemaInference, JsonValueDecoder};
+pub use schema_evolution::{merge_schemas, SchemaEvolution, PARQUET_FIELD_ID_META_KEY};
 
 use crate::datasource::{
     listing::{FileRange, PartitionedFile},
@@ -51,10 +67,12 @@ use crate::{
 };
 use arrow::array::{new_null_array, UInt16BufferBuilder};
 use arrow::record_batch::RecordBatchOptions;
+use itertools::Itertools;
 use log::{debug, info};
 use object_store::path::Path;
 use object_store::ObjectMeta;
 use std::{
+    cmp::min,
     collections::HashMap,
     fmt::{Display, Formatter, Result as FmtResult},
     sync::Arc,
@@ -63,6 +81,12 @@ use std::{
 
 use super::{ColumnStatistics, Statistics};
 
+#[derive(Default)]
+struct RepartitionState {
+    current_partition_index: usize,
+    current_partition_size: usize,
+}
+
 /// Convert logical type of partition column to physical type: `Dictionary(UInt16, val_type)`
 pub fn partition_type_wrap(val_type: DataType) -> DataType {
     DataType::Dictionary(Box::new(DataType::UInt16), Box::new(val_type))
@@ -97,6 +121,11 @@ pub struct FileScanConfig {
     /// Columns on which to project the data. Indexes that are higher than the
     /// number of columns of `file_schema` refer to `table_partition_cols`.
     pub projection: Option<Vec<usize>>,
+    /// Optional paths of the nested fields to read from the struct columns
+    /// of the projection, e.g. `["payload", "user", "id"]`. Struct columns
+    /// with paths are read with only the fields the paths lead to, see
+    /// [`project_nested_fields`]. Only supported by [`ParquetExec`]
+    pub nested_projection: Option<Vec<Vec<String>>>,
     /// The maximum number of records to read from this plan. If `None`,
     /// all records after filtering are returned.
     pub limit: Option<usize>,
@@ -106,6 +135,8 @@ pub struct FileScanConfig {
     pub output_ordering: Option<Vec<PhysicalSortExpr>>,
     /// Indicates whether this plan may produce an infinite stream of records.
     pub infinite_source: bool,
+    /// How the schemas of the files may differ from `file_schema`
+    pub schema_evolution: SchemaEvolution,
 }
 
 impl FileScanConfig {
@@ -122,11 +153,12 @@ impl FileScanConfig {
             ),
         };
 
+        let file_schema = self.nested_file_schema();
         let mut table_fields = vec![];
         let mut table_cols_stats = vec![];
         for idx in proj_iter {
-            if idx < self.file_schema.fields().len() {
-                table_fields.push(self.file_schema.field(idx).clone());
+            if idx < file_schema.fields().len() {
+                table_fields.push(file_schema.field(idx).clone());
                 if let Some(file_cols_stats) = &self.statistics.column_statistics {
                     table_cols_stats.push(file_cols_stats[idx].clone())
                 } else {
@@ -159,6 +191,85 @@ impl FileScanConfig {
         (table_schema, table_stats)
     }
 
+    /// Redistributes the files of `file_groups` across `target_partitions`
+    /// partitions of about the same size, splitting files into byte
+    /// ranges. Returns `None` if the files are too small or already read in
+    /// ranges
+    pub(crate) fn repartition_file_groups(
+        file_groups: &[Vec<PartitionedFile>],
+        target_partitions: usize,
+        repartition_file_min_size: usize,
+    ) -> Option<Vec<Vec<PartitionedFile>>> {
+        let flattened_files = file_groups.iter().flatten().collect::<Vec<_>>();
+
+        // Perform redistribution only in case all files should be read from beginning to end
+        let has_ranges = flattened_files.iter().any(|f| f.range.is_some());
+        if has_ranges {
+            return None;
+        }
+
+        let total_size = flattened_files
+            .iter()
+            .map(|f| f.object_meta.size as i64)
+            .sum::<i64>();
+        if total_size < (repartition_file_min_size as i64) {
+            return None;
+        }
+
+        let target_partition_size =
+            (total_size as usize + (target_partitions) - 1) / (target_partitions);
+
+        let repartitioned_files = flattened_files
+            .into_iter()
+            .scan(RepartitionState::default(), |state, source_file| {
+                let mut produced_files = vec![];
+                let mut range_start = 0;
+                while range_start < source_file.object_meta.size {
+                    let range_end = min(
+                        range_start
+                            + (target_partition_size - state.current_partition_size),
+                        source_file.object_meta.size,
+                    );
+
+                    let mut produced_file = source_file.clone();
+                    produced_file.range = Some(FileRange {
+                        start: range_start as i64,
+                        end: range_end as i64,
+                    });
+                    produced_files.push((state.current_partition_index, produced_file));
+
+                    if state.current_partition_size + (range_end - range_start)
+                        >= target_partition_size
+                    {
+                        state.current_partition_index += 1;
+                        state.current_partition_size = 0;
+                    } else {
+                        state.current_partition_size += range_end - range_start;
+                    }
+                    range_start = range_end;
+                }
+                Some(produced_files)
+            })
+            .flatten()
+            .group_by(|(partition_idx, _)| *partition_idx)
+            .into_iter()
+            .map(|(_, group)| group.map(|(_, vals)| vals).collect_vec())
+            .collect_vec();
+
+        Some(repartitioned_files)
+    }
+
+    /// The file schema with the struct columns pruned to the fields of the
+    /// nested projection, if any
+    fn nested_file_schema(&self) -> SchemaRef {
+        match &self.nested_projection {
+            Some(paths) => {
+                Arc::new(project_nested_fields(self.file_schema.as_ref(), paths))
+            }
+            None => Arc::clone(&self.file_schema),
+        }
+    }
+
     #[allow(unused)] // Only used by avro
     fn projected_file_column_names(&self) -> Option<Vec<String>> {
         self.projection.as_ref().map(|p| {
@@ -254,11 +365,36 @@ impl<'a> Display for ProjectSchemaDisplay<'a> {
 pub(crate) struct SchemaAdapter {
     /// Schema for the table
     table_schema: SchemaRef,
+    /// How the file schemas may differ from the table schema
+    schema_evolution: SchemaEvolution,
 }
 
 impl SchemaAdapter {
     pub(crate) fn new(table_schema: SchemaRef) -> SchemaAdapter {
-        Self { table_schema }
+        Self {
+            table_schema,
+            schema_evolution: SchemaEvolution::Strict,
+        }
+    }
+
+    /// With [`SchemaEvolution::Merge`], file columns are matched to table
+    /// columns by field id and cast to the types of the table columns
+    pub(crate) fn with_schema_evolution(
+        mut self,
+        schema_evolution: SchemaEvolution,
+    ) -> SchemaAdapter {
+        self.schema_evolution = schema_evolution;
+        self
+    }
+
+    /// Returns the index of the column of `fields` read as `table_field`
+    fn file_field_index(&self, table_field: &Field, fields: &[Field]) -> Option<usize> {
+        match self.schema_evolution {
+            SchemaEvolution::Strict => {
+                fields.iter().position(|f| f.name() == table_field.name())
+            }
+            SchemaEvolution::Merge => schema_evolution::field_index(table_field, fields),
+        }
     }
 
     /// Map a column index in the table schema to a column index in a particular
@@ -271,11 +407,13 @@ impl SchemaAdapter {
         file_schema: &Schema,
     ) -> Option<usize> {
         let field = self.table_schema.field(index);
-        file_schema.index_of(field.name()).ok()
+        self.file_field_index(field, file_schema.fields())
     }
 
     /// Map projected column indexes to the file schema. This will fail if the table schema
-    /// and the file schema contain a field with the same name and different types.
+    /// and the file schema contain a field with the same name and different types, unless
+    /// the file column can be adapted to the type of the table column with
+    /// [`SchemaEvolution::Merge`].
     pub fn map_projections(
         &self,
         file_schema: &Schema,
@@ -284,8 +422,15 @@ impl SchemaAdapter {
         let mut mapped: Vec<usize> = vec![];
         for idx in projections {
             let field = self.table_schema.field(*idx);
-            if let Ok(mapped_idx) = file_schema.index_of(field.name().as_str()) {
-                if file_schema.field(mapped_idx).data_type() == field.data_type() {
+            if let Some(mapped_idx) = self.file_field_index(field, file_schema.fields()) {
+                let file_type = file_schema.field(mapped_idx).data_type();
+                let compatible = match self.schema_evolution {
+                    SchemaEvolution::Strict => file_type == field.data_type(),
+                    SchemaEvolution::Merge => {
+                        schema_evolution::can_adapt(file_type, field.data_type())
+                    }
+                };
+                if compatible {
                     mapped.push(mapped_idx)
                 } else {
                     let msg = format!("Failed to map column projection for field {}. Incompatible data types {:?} and {:?}", field.name(), file_schema.field(mapped_idx).data_type(), field.data_type());
@@ -299,7 +444,8 @@ impl SchemaAdapter {
 
     /// Re-order projected columns by index in record batch to match table schema column ordering. If the record
     /// batch does not contain a column for an expected field, insert a null-valued column at the
-    /// required column index.
+    /// required column index. With [`SchemaEvolution::Merge`], the columns are also adapted to
+    /// the types of the table columns.
     pub fn adapt_batch(
         &self,
         batch: RecordBatch,
@@ -314,10 +460,17 @@ impl SchemaAdapter {
 
         for field_idx in projections {
             let table_field = &self.table_schema.fields()[*field_idx];
-            if let Some((batch_idx, _name)) =
-                batch_schema.column_with_name(table_field.name().as_str())
+            if let Some(batch_idx) =
+                self.file_field_index(table_field, batch_schema.fields())
             {
-                cols.push(batch_cols[batch_idx].clone());
+                let col = &batch_cols[batch_idx];
+                match self.schema_evolution {
+                    SchemaEvolution::Strict => cols.push(col.clone()),
+                    SchemaEvolution::Merge => cols.push(schema_evolution::adapt_array(
+                        col,
+                        table_field.data_type(),
+                    )?),
+                }
             } else {
                 cols.push(new_null_array(table_field.data_type(), batch_rows))
             }
@@ -543,6 +696,7 @@ pub(crate) fn get_output_ordering(
 
 #[cfg(test)]
 mod tests {
+    use arrow::array::{Float32Array, Int32Array};
     use chrono::Utc;
 
     use crate::{
@@ -801,6 +955,59 @@ mod tests {
         assert!(mapped.is_err());
     }
 
+    #[test]
+    fn schema_adapter_merge() {
+        let mut id_field = Field::new("id", DataType::Int64, true);
+        id_field.set_metadata(HashMap::from([(
+            PARQUET_FIELD_ID_META_KEY.to_string(),
+            "1".to_string(),
+        )]));
+        let table_schema = Arc::new(Schema::new(vec![
+            id_field,
+            Field::new("value", DataType::Float64, true),
+        ]));
+
+        // `id` was named `user_id` with the same field id when the file was written
+        let mut user_id_field = Field::new("user_id", DataType::Int32, true);
+        user_id_field.set_metadata(HashMap::from([(
+            PARQUET_FIELD_ID_META_KEY.to_string(),
+            "1".to_string(),
+        )]));
+        let file_schema = Arc::new(Schema::new(vec![
+            Field::new("value", DataType::Float32, true),
+            user_id_field,
+        ]));
+
+        let adapter = SchemaAdapter::new(table_schema.clone());
+        assert!(adapter.map_projections(&file_schema, &[0, 1]).is_err());
+
+        let adapter = adapter.with_schema_evolution(SchemaEvolution::Merge);
+        let mapped = adapter
+            .map_projections(&file_schema, &[0, 1])
+            .expect("mapping projections");
+        assert_eq!(mapped, vec![1, 0]);
+
+        let batch = RecordBatch::try_new(
+            file_schema,
+            vec![
+                Arc::new(Float32Array::from(vec![1.5, 2.5])),
+                Arc::new(Int32Array::from(vec![1, 2])),
+            ],
+        )
+        .unwrap();
+        let adapted = adapter.adapt_batch(batch, &[0, 1]).unwrap();
+        assert_eq!(adapted.schema(), table_schema);
+        let expected = vec![
+            "+----+-------+",
+            "| id | value |",
+            "+----+-------+",
+            "| 1  | 1.5   |",
+            "| 2  | 2.5   |",
+            "+----+-------+",
+        ];
+        crate::assert_batches_eq!(expected, &[adapted]);
+    }
+
     // sets default for configs that play no role in projections
     fn config_for_projection(
         file_schema: SchemaRef,
@@ -811,6 +1018,7 @@ mod tests {
         FileScanConfig {
             file_schema,
             file_groups: vec![vec![]],
+            nested_projection: None,
             limit: None,
             object_store_url: ObjectStoreUrl::parse("test:///").unwrap(),
             projection,
@@ -818,6 +1026,7 @@ mod tests {
             table_partition_cols,
             output_ordering: None,
             infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
         }
     }
 
@@ -17,49 +17,54 @@
 
 //! Execution plan for reading Parquet files
 
-use arrow::datatypes::{DataType, SchemaRef};
+use arrow::datatypes::{DataType, Schema, SchemaRef};
 use datafusion_physical_expr::PhysicalExpr;
 use fmt::Debug;
 use std::any::Any;
-use std::cmp::min;
 use std::fmt;
 use std::fs;
 use std::ops::Range;
 use std::sync::Arc;
 
-use crate::config::ConfigOptions;
-use crate::datasource::file_format::parquet::fetch_parquet_metadata;
+use crate::config::{ConfigOptions, ParquetOptions};
+use crate::datasource::file_format::parquet::fetch_parquet_metadata_cached;
+use crate::datasource::object_store::ObjectStoreUrl;
+use crate::execution::cache::FileMetadataCache;
 use crate::physical_plan::file_format::file_stream::{
     FileOpenFuture, FileOpener, FileStream,
 };
 use crate::physical_plan::file_format::FileMeta;
 use crate::{
-    datasource::listing::FileRange,
     error::{DataFusionError, Result},
     execution::context::TaskContext,
     physical_optimizer::pruning::PruningPredicate,
     physical_plan::{
-        expressions::PhysicalSortExpr,
-        file_format::{FileScanConfig, SchemaAdapter},
+        expressions::{BinaryExpr, Column, PhysicalSortExpr},
+        file_format::{FileScanConfig, SchemaAdapter, SchemaEvolution},
         metrics::{ExecutionPlanMetricsSet, MetricBuilder, MetricsSet},
+        sorts::topk::TopKThreshold,
         DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream,
         Statistics,
     },
 };
 use arrow::error::ArrowError;
 use bytes::Bytes;
+use datafusion_common::utils::project_nested_fields;
+use datafusion_expr::Operator;
 use futures::future::BoxFuture;
 use futures::{FutureExt, StreamExt, TryFutureExt, TryStreamExt};
-use itertools::Itertools;
 use log::debug;
 use object_store::{ObjectMeta, ObjectStore};
 use parquet::arrow::arrow_reader::ArrowReaderOptions;
 use parquet::arrow::async_reader::AsyncFileReader;
 use parquet::arrow::{ArrowWriter, ParquetRecordBatchStreamBuilder, ProjectionMask};
-use parquet::basic::{ConvertedType, LogicalType};
+use parquet::basic::{
+    BrotliLevel, Compression, ConvertedType, GzipLevel, LogicalType, ZstdLevel,
+};
 use parquet::errors::ParquetError;
-use parquet::file::{metadata::ParquetMetaData, properties::WriterProperties};
-use parquet::schema::types::ColumnDescriptor;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::properties::{EnabledStatistics, WriterProperties};
+use parquet::schema::types::{ColumnDescriptor, ColumnPath, SchemaDescriptor};
 
 mod metrics;
 mod page_filter;
@@ -68,16 +73,11 @@ mod row_groups;
 
 use crate::physical_plan::common::AbortOnDropSingle;
 use crate::physical_plan::file_format::parquet::page_filter::PagePruningPredicate;
+use crate::physical_plan::file_format::parquet::row_groups::BloomFilterPredicate;
 pub use metrics::ParquetFileMetrics;
 
 use super::get_output_ordering;
 
-#[derive(Default)]
-struct RepartitionState {
-    current_partition_index: usize,
-    current_partition_size: usize,
-}
-
 /// Execution plan for scanning one or more Parquet partitions
 #[derive(Debug, Clone)]
 pub struct ParquetExec {
@@ -90,6 +90,9 @@ pub struct ParquetExec {
     /// Override for `Self::with_enable_page_index`. If None, uses
     /// values from base_config
     enable_page_index: Option<bool>,
+    /// Override for `Self::with_bloom_filter_enabled`. If None, uses
+    /// values from base_config
+    bloom_filter_enabled: Option<bool>,
     /// Base configuraton for this scan
     base_config: FileScanConfig,
     projected_statistics: Statistics,
@@ -106,6 +109,9 @@ pub struct ParquetExec {
     metadata_size_hint: Option<usize>,
     /// Optional user defined parquet file reader factory
     parquet_file_reader_factory: Option<Arc<dyn ParquetFileReaderFactory>>,
+    /// Optional threshold of a TopK sort above this scan on a column of the
+    /// file schema, consulted for pruning when a file is opened
+    topk_threshold: Option<(Arc<TopKThreshold>, Column)>,
 }
 
 impl ParquetExec {
@@ -157,6 +163,7 @@ impl ParquetExec {
             pushdown_filters: None,
             reorder_filters: None,
             enable_page_index: None,
+            bloom_filter_enabled: None,
             base_config,
             projected_schema,
             projected_statistics,
@@ -166,6 +173,7 @@ impl ParquetExec {
             page_pruning_predicate,
             metadata_size_hint,
             parquet_file_reader_factory: None,
+            topk_threshold: None,
         }
     }
 
@@ -184,6 +192,29 @@ impl ParquetExec {
         self.pruning_predicate.as_ref()
     }
 
+    /// Prune row groups and pages of the files opened during the scan with
+    /// the current value of `threshold`, published by a TopK sort on
+    /// `column` of the file schema
+    ///
+    /// The threshold is read once per file, after its metadata has been
+    /// loaded, and the row groups and pages to read are chosen with that
+    /// value. A threshold that tightens while a file is being read does not
+    /// prune the remaining row groups of that file, only those of the files
+    /// opened afterwards.
+    pub fn with_topk_threshold(
+        mut self,
+        threshold: Arc<TopKThreshold>,
+        column: Column,
+    ) -> Self {
+        self.topk_threshold = Some((threshold, column));
+        self
+    }
+
+    /// Optional threshold of a TopK sort consulted during the scan
+    pub fn topk_threshold(&self) -> Option<&Arc<TopKThreshold>> {
+        self.topk_threshold.as_ref().map(|(threshold, _)| threshold)
+    }
+
     /// Optional user defined parquet file reader factory.
     ///
     /// `ParquetFileReaderFactory` complements `TableProvider`, It enables users to provide custom
@@ -245,75 +276,36 @@ impl ParquetExec {
             .unwrap_or(config_options.execution.parquet.enable_page_index)
     }
 
+    /// If enabled, the reader will read the bloom filters of the columns
+    /// compared with `=` or `IN` in the predicate, and skip the row groups
+    /// whose bloom filters rule out all the compared values
+    pub fn with_bloom_filter_enabled(mut self, bloom_filter_enabled: bool) -> Self {
+        self.bloom_filter_enabled = Some(bloom_filter_enabled);
+        self
+    }
+
+    /// Return the value described in [`Self::with_bloom_filter_enabled`]
+    fn bloom_filter_enabled(&self, config_options: &ConfigOptions) -> bool {
+        self.bloom_filter_enabled
+            .unwrap_or(config_options.execution.parquet.bloom_filter_enabled)
+    }
+
     /// Redistribute files across partitions according to their size
     pub fn get_repartitioned(
         &self,
         target_partitions: usize,
         repartition_file_min_size: usize,
     ) -> Self {
-        let flattened_files = self
-            .base_config()
-            .file_groups
-            .iter()
-            .flatten()
-            .collect::<Vec<_>>();
-
-        // Perform redistribution only in case all files should be read from beginning to end
-        let has_ranges = flattened_files.iter().any(|f| f.range.is_some());
-        if has_ranges {
-            return self.clone();
-        }
-
-        let total_size = flattened_files
-            .iter()
-            .map(|f| f.object_meta.size as i64)
-            .sum::<i64>();
-        if total_size < (repartition_file_min_size as i64) {
-            return self.clone();
-        }
-
-        let target_partition_size =
-            (total_size as usize + (target_partitions) - 1) / (target_partitions);
-
-        let repartitioned_files = flattened_files
-            .into_iter()
-            .scan(RepartitionState::default(), |state, source_file| {
-                let mut produced_files = vec![];
-                let mut range_start = 0;
-                while range_start < source_file.object_meta.size {
-                    let range_end = min(
-                        range_start
-                            + (target_partition_size - state.current_partition_size),
-                        source_file.object_meta.size,
-                    );
-
-                    let mut produced_file = source_file.clone();
-                    produced_file.range = Some(FileRange {
-                        start: range_start as i64,
-                        end: range_end as i64,
-                    });
-                    produced_files.push((state.current_partition_index, produced_file));
-
-                    if state.current_partition_size + (range_end - range_start)
-                        >= target_partition_size
-                    {
-                        state.current_partition_index += 1;
-                        state.current_partition_size = 0;
-                    } else {
-                        state.current_partition_size += range_end - range_start;
-                    }
-                    range_start = range_end;
-                }
-                Some(produced_files)
-            })
-            .flatten()
-            .group_by(|(partition_idx, _)| *partition_idx)
-            .into_iter()
-            .map(|(_, group)| group.map(|(_, vals)| vals).collect_vec())
-            .collect_vec();
+        let repartitioned_file_groups = FileScanConfig::repartition_file_groups(
+            &self.base_config.file_groups,
+            target_partitions,
+            repartition_file_min_size,
+        );
 
         let mut new_parquet_exec = self.clone();
-        new_parquet_exec.base_config.file_groups = repartitioned_files;
+        if let Some(file_groups) = repartitioned_file_groups {
+            new_parquet_exec.base_config.file_groups = file_groups;
+        }
         new_parquet_exec
     }
 }
@@ -367,8 +359,12 @@ impl ExecutionPlan for ParquetExec {
                 ctx.runtime_env()
                     .object_store(&self.base_config.object_store_url)
                     .map(|store| {
-                        Arc::new(DefaultParquetFileReaderFactory::new(store))
-                            as Arc<dyn ParquetFileReaderFactory>
+                        let factory = DefaultParquetFileReaderFactory::new(store)
+                            .with_metadata_cache(
+                                self.base_config.object_store_url.clone(),
+                                ctx.runtime_env().file_metadata_cache.clone(),
+                            );
+                        Arc::new(factory) as Arc<dyn ParquetFileReaderFactory>
                     })
             })?;
 
@@ -389,6 +385,10 @@ impl ExecutionPlan for ParquetExec {
             pushdown_filters: self.pushdown_filters(config_options),
             reorder_filters: self.reorder_filters(config_options),
             enable_page_index: self.enable_page_index(config_options),
+            bloom_filter_enabled: self.bloom_filter_enabled(config_options),
+            topk_threshold: self.topk_threshold.clone(),
+            nested_projection: self.base_config.nested_projection.clone().map(Arc::from),
+            schema_evolution: self.base_config.schema_evolution,
         };
 
         let stream =
@@ -421,15 +421,27 @@ impl ExecutionPlan for ParquetExec {
                     .map(make_output_ordering_string)
                     .unwrap_or_default();
 
+                let nested_projection_string = self
+                    .base_config
+                    .nested_projection
+                    .as_ref()
+                    .map(|paths| {
+                        let paths: Vec<String> =
+                            paths.iter().map(|path| path.join(".")).collect();
+                        format!(", nested_projection=[{}]", paths.join(", "))
+                    })
+                    .unwrap_or_default();
+
                 write!(
                     f,
-                    "ParquetExec: limit={:?}, partitions={}{}{}{}, projection={}",
+                    "ParquetExec: limit={:?}, partitions={}{}{}{}, projection={}{}",
                     self.base_config.limit,
                     super::FileGroupsDisplay(&self.base_config.file_groups),
                     predicate_string,
                     pruning_predicate_string,
                     output_ordering_string,
                     super::ProjectSchemaDisplay(&self.projected_schema),
+                    nested_projection_string,
                 )
             }
         }
@@ -474,6 +486,10 @@ struct ParquetOpener {
     pushdown_filters: bool,
     reorder_filters: bool,
     enable_page_index: bool,
+    bloom_filter_enabled: bool,
+    topk_threshold: Option<(Arc<TopKThreshold>, Column)>,
+    nested_projection: Option<Arc<[Vec<String>]>>,
+    schema_evolution: SchemaEvolution,
 }
 
 impl FileOpener for ParquetOpener {
@@ -486,6 +502,28 @@ impl FileOpener for ParquetOpener {
             &self.metrics,
         );
 
+        // Bloom filters are fetched with a reader of their own, as the one
+        // below is owned by the record batch stream
+        let bloom_filter_predicate = self
+            .predicate
+            .as_ref()
+            .filter(|_| self.bloom_filter_enabled)
+            .and_then(BloomFilterPredicate::try_new);
+        let bloom_filter_reader = match bloom_filter_predicate {
+            Some(_) => Some(self.parquet_file_reader_factory.create_reader(
+                self.partition_index,
+                FileMeta {
+                    object_meta: file_meta.object_meta.clone(),
+                    range: file_meta.range.clone(),
+                    extensions: file_meta.extensions.clone(),
+                },
+                self.metadata_size_hint,
+                &self.metrics,
+            )?),
+            None => None,
+        };
+        let file_size = file_meta.object_meta.size;
+
         let reader: Box<dyn AsyncFileReader> =
             self.parquet_file_reader_factory.create_reader(
                 self.partition_index,
@@ -494,7 +532,13 @@ impl FileOpener for ParquetOpener {
                 &self.metrics,
             )?;
 
-        let schema_adapter = SchemaAdapter::new(self.table_schema.clone());
+        // struct columns are adapted to their fields in the nested projection
+        let nested_projection = self.nested_projection.clone();
+        let schema_adapter = SchemaAdapter::new(match &nested_projection {
+            Some(paths) => Arc::new(project_nested_fields(&self.table_schema, paths)),
+            None => self.table_schema.clone(),
+        })
+        .with_schema_evolution(self.schema_evolution);
         let batch_size = self.batch_size;
         let projection = self.projection.clone();
         let predicate = self.predicate.clone();
@@ -505,21 +549,58 @@ impl FileOpener for ParquetOpener {
         let pushdown_filters = self.pushdown_filters;
         let enable_page_index = self.enable_page_index;
         let limit = self.limit;
+        let topk_threshold = self.topk_threshold.clone();
 
         Ok(Box::pin(async move {
+            // Dynamic pruning: rows that can not enter the TopK above the
+            // scan can be skipped in addition to those ruled out by the predicate.
+            // The threshold is read once here, as the row groups and pages to
+            // read are fixed when the record batch stream is built below
+            let topk_predicate = topk_threshold
+                .and_then(|(threshold, column)| threshold.predicate(Arc::new(column)));
+            let (pruning_predicate, page_pruning_predicate) = match topk_predicate {
+                Some(topk_predicate) => match topk_pruning_predicates(
+                    predicate.as_ref(),
+                    topk_predicate,
+                    &table_schema,
+                ) {
+                    Ok((pruning, page_pruning)) => (Some(pruning), Some(page_pruning)),
+                    Err(e) => {
+                        debug!("Could not create TopK pruning predicate: {e}");
+                        (pruning_predicate, page_pruning_predicate)
+                    }
+                },
+                None => (pruning_predicate, page_pruning_predicate),
+            };
+
             let options = ArrowReaderOptions::new().with_page_index(enable_page_index);
             let mut builder =
                 ParquetRecordBatchStreamBuilder::new_with_options(reader, options)
                     .await?;
-            let adapted_projections =
-                schema_adapter.map_projections(builder.schema(), &projection)?;
+            let (adapted_projections, mask) = match &nested_projection {
+                Some(paths) => {
+                    let file_schema = project_nested_fields(builder.schema(), paths);
+                    let adapted_projections =
+                        schema_adapter.map_projections(&file_schema, &projection)?;
+                    let mask = nested_projection_mask(
+                        builder.parquet_schema(),
+                        &file_schema,
+                        &adapted_projections,
+                    );
+                    (adapted_projections, mask)
+                }
+                None => {
+                    let adapted_projections =
+                        schema_adapter.map_projections(builder.schema(), &projection)?;
+                    let mask = ProjectionMask::roots(
+                        builder.parquet_schema(),
+                        adapted_projections.iter().cloned(),
+                    );
+                    (adapted_projections, mask)
+                }
+            };
             // let predicate = predicate.map(|p| reassign_predicate_columns(p, builder.schema(), true)).transpose()?;
 
-            let mask = ProjectionMask::roots(
-                builder.parquet_schema(),
-                adapted_projections.iter().cloned(),
-            );
-
             // Filter pushdown: evaluate predicates during scan
             if let Some(predicate) = pushdown_filters.then_some(predicate).flatten() {
                 let row_filter = row_filter::build_row_filter(
@@ -555,6 +636,23 @@ impl FileOpener for ParquetOpener {
                 &file_metrics,
             );
 
+            // Bloom filter pruning: skip the remaining row groups whose
+            // bloom filters rule out the values compared with `=` or `IN`
+            let row_groups = match (bloom_filter_reader, &bloom_filter_predicate) {
+                (Some(mut reader), Some(predicate)) if !row_groups.is_empty() => {
+                    row_groups::prune_row_groups_by_bloom_filters(
+                        &mut reader,
+                        file_size,
+                        file_metadata.as_ref(),
+                        row_groups,
+                        predicate,
+                        &file_metrics,
+                    )
+                    .await
+                }
+                _ => row_groups,
+            };
+
             // page index pruning: if all data on individual pages can
             // be ruled using page metadata, rows from other columns
             // with that range can be skipped as well
@@ -593,6 +691,62 @@ impl FileOpener for ParquetOpener {
     }
 }
 
+/// Returns the mask of the leaf columns of the `roots` columns of the file
+/// that are part of their fields in `file_schema`, the arrow schema of the
+/// file with its struct columns pruned by the nested projection
+fn nested_projection_mask(
+    parquet_schema: &SchemaDescriptor,
+    file_schema: &Schema,
+    roots: &[usize],
+) -> ProjectionMask {
+    let leaves = (0..parquet_schema.num_columns()).filter(|leaf| {
+        let root = parquet_schema.get_column_root_idx(*leaf);
+        if !roots.contains(&root) {
+            return false;
+        }
+        // follow the path of the leaf through the struct fields of the root
+        let mut field = file_schema.field(root);
+        for name in &parquet_schema.column(*leaf).path().parts()[1..] {
+            match field.data_type() {
+                DataType::Struct(children) => {
+                    match children.iter().find(|child| child.name() == name) {
+                        Some(child) => field = child,
+                        None => return false,
+                    }
+                }
+                _ => return true,
+            }
+        }
+        true
+    });
+    ProjectionMask::leaves(parquet_schema, leaves)
+}
+
+/// Builds the pruning predicates for `predicate`, if any, combined with the
+/// predicate derived from the threshold of a TopK sort
+fn topk_pruning_predicates(
+    predicate: Option<&Arc<dyn PhysicalExpr>>,
+    topk_predicate: Arc<dyn PhysicalExpr>,
+    table_schema: &SchemaRef,
+) -> Result<(Arc<PruningPredicate>, Arc<PagePruningPredicate>)> {
+    let expr: Arc<dyn PhysicalExpr> = match predicate {
+        Some(predicate) => Arc::new(BinaryExpr::new(
+            predicate.clone(),
+            Operator::And,
+            topk_predicate,
+        )),
+        None => topk_predicate,
+    };
+    let pruning_predicate =
+        PruningPredicate::try_new(expr.clone(), table_schema.clone())?;
+    let page_pruning_predicate =
+        PagePruningPredicate::try_new(&expr, table_schema.clone())?;
+    Ok((
+        Arc::new(pruning_predicate),
+        Arc::new(page_pruning_predicate),
+    ))
+}
+
 /// Factory of parquet file readers.
 ///
 /// Provides means to implement custom data access interface.
@@ -610,17 +764,34 @@ pub trait ParquetFileReaderFactory: Debug + Send + Sync + 'static {
 #[derive(Debug)]
 pub struct DefaultParquetFileReaderFactory {
     store: Arc<dyn ObjectStore>,
+    /// The url of `store` and the cache of the metadata of its files
+    metadata_cache: Option<(ObjectStoreUrl, Arc<dyn FileMetadataCache>)>,
 }
 
 impl DefaultParquetFileReaderFactory {
     pub fn new(store: Arc<dyn ObjectStore>) -> Self {
-        Self { store }
+        Self {
+            store,
+            metadata_cache: None,
+        }
+    }
+
+    /// Reads the metadata of the files through `metadata_cache`, the
+    /// store of this factory being registered for `store_url`
+    pub fn with_metadata_cache(
+        mut self,
+        store_url: ObjectStoreUrl,
+        metadata_cache: Option<Arc<dyn FileMetadataCache>>,
+    ) -> Self {
+        self.metadata_cache = metadata_cache.map(|cache| (store_url, cache));
+        self
     }
 }
 
 /// Implements [`AsyncFileReader`] for a parquet file in object storage
 struct ParquetFileReader {
     store: Arc<dyn ObjectStore>,
+    metadata_cache: Option<(ObjectStoreUrl, Arc<dyn FileMetadataCache>)>,
     meta: ObjectMeta,
     file_metrics: ParquetFileMetrics,
     metadata_size_hint: Option<usize>,
@@ -668,18 +839,20 @@ impl AsyncFileReader for ParquetFileReader {
         &mut self,
     ) -> BoxFuture<'_, parquet::errors::Result<Arc<ParquetMetaData>>> {
         Box::pin(async move {
-            let metadata = fetch_parquet_metadata(
+            fetch_parquet_metadata_cached(
                 self.store.as_ref(),
                 &self.meta,
                 self.metadata_size_hint,
+                self.metadata_cache
+                    .as_ref()
+                    .map(|(url, cache)| (url, cache.as_ref())),
             )
             .await
             .map_err(|e| {
                 ParquetError::General(format!(
                     "AsyncChunkReader::get_metadata error: {e}"
                 ))
-            })?;
-            Ok(Arc::new(metadata))
+            })
         })
     }
 }
@@ -701,13 +874,128 @@ impl ParquetFileReaderFactory for DefaultParquetFileReaderFactory {
         Ok(Box::new(ParquetFileReader {
             meta: file_meta.object_meta,
             store: Arc::clone(&self.store),
+            metadata_cache: self.metadata_cache.clone(),
             metadata_size_hint,
             file_metrics,
         }))
     }
 }
 
+/// Builds the [`WriterProperties`] described by the
+/// `datafusion.execution.parquet.*` write options
+pub fn parquet_writer_properties(options: &ParquetOptions) -> Result<WriterProperties> {
+    let statistics_enabled = match options.statistics_enabled.to_lowercase().as_str() {
+        "none" => EnabledStatistics::None,
+        "chunk" => EnabledStatistics::Chunk,
+        "page" => EnabledStatistics::Page,
+        other => {
+            return Err(DataFusionError::Plan(format!(
+                "Unknown parquet statistics level '{other}', expected one of none, chunk or page"
+            )))
+        }
+    };
+
+    let mut builder = WriterProperties::builder()
+        .set_max_row_group_size(options.max_row_group_size)
+        .set_data_pagesize_limit(options.data_pagesize_limit)
+        .set_dictionary_enabled(options.dictionary_enabled)
+        .set_statistics_enabled(statistics_enabled)
+        .set_created_by(options.created_by.clone());
+
+    if let Some(compression) = &options.compression {
+        builder = builder.set_compression(parse_compression(compression)?);
+    }
+
+    if let Some(column_compression) = &options.column_compression {
+        for entry in split_list(column_compression) {
+            let (column, compression) = entry.split_once(':').ok_or_else(|| {
+                DataFusionError::Plan(format!(
+                    "Invalid parquet column compression '{entry}', expected 'column:codec'"
+                ))
+            })?;
+            builder = builder.set_column_compression(
+                column_path(column.trim()),
+                parse_compression(compression.trim())?,
+            );
+        }
+    }
+
+    if let Some(bloom_filter_columns) = &options.bloom_filter_columns {
+        for column in split_list(bloom_filter_columns) {
+            builder = builder.set_column_bloom_filter_enabled(column_path(column), true);
+        }
+    }
+
+    Ok(builder.build())
+}
+
+/// Splits a comma separated option value, skipping empty entries
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|s| !s.is_empty())
+}
+
+/// Parses a dotted column name such as `a.b` into the path of a (possibly
+/// nested) parquet column
+fn column_path(column: &str) -> ColumnPath {
+    ColumnPath::new(column.split('.').map(str::to_string).collect())
+}
+
+/// Parses a parquet compression codec, such as `snappy` or `zstd(3)`
+fn parse_compression(value: &str) -> Result<Compression> {
+    let value = value.to_lowercase();
+    let (codec, level) = match value.split_once('(') {
+        Some((codec, rest)) => {
+            let level = rest.strip_suffix(')').ok_or_else(|| {
+                DataFusionError::Plan(format!("Invalid parquet compression '{value}'"))
+            })?;
+            (codec.trim(), Some(level.trim()))
+        }
+        None => (value.trim(), None),
+    };
+
+    let level = level
+        .map(|level| {
+            level.parse::<i32>().map_err(|_| {
+                DataFusionError::Plan(format!(
+                    "Invalid level '{level}' for parquet compression '{codec}'"
+                ))
+            })
+        })
+        .transpose()?;
+
+    let compression = match (codec, level) {
+        ("uncompressed", None) => Compression::UNCOMPRESSED,
+        ("snappy", None) => Compression::SNAPPY,
+        ("lzo", None) => Compression::LZO,
+        ("lz4", None) => Compression::LZ4,
+        ("lz4_raw", None) => Compression::LZ4_RAW,
+        ("gzip", None) => Compression::GZIP(GzipLevel::default()),
+        ("gzip", Some(level)) => Compression::GZIP(GzipLevel::try_new(level as u32)?),
+        ("brotli", None) => Compression::BROTLI(BrotliLevel::default()),
+        ("brotli", Some(level)) => {
+            Compression::BROTLI(BrotliLevel::try_new(level as u32)?)
+        }
+        ("zstd", None) => Compression::ZSTD(ZstdLevel::default()),
+        ("zstd", Some(level)) => Compression::ZSTD(ZstdLevel::try_new(level)?),
+        ("uncompressed" | "snappy" | "lzo" | "lz4" | "lz4_raw", Some(_)) => {
+            return Err(DataFusionError::Plan(format!(
+                "Parquet compression '{codec}' does not take a level"
+            )))
+        }
+        (other, _) => {
+            return Err(DataFusionError::Plan(format!(
+                "Unknown parquet compression '{other}', expected one of uncompressed, \
+                 snappy, gzip, lzo, brotli, lz4, zstd or lz4_raw"
+            )))
+        }
+    };
+    Ok(compression)
+}
+
 /// Executes a query and writes the results to a partitioned Parquet file.
+///
+/// When `writer_properties` is `None` the files are written with the
+/// `datafusion.execution.parquet.*` options of the session.
 pub async fn plan_to_parquet(
     task_ctx: Arc<TaskContext>,
     plan: Arc<dyn ExecutionPlan>,
@@ -715,6 +1003,12 @@ pub async fn plan_to_parquet(
     writer_properties: Option<WriterProperties>,
 ) -> Result<()> {
     let path = path.as_ref();
+    let writer_properties = match writer_properties {
+        Some(writer_properties) => writer_properties,
+        None => parquet_writer_properties(
+            &task_ctx.session_config().config_options().execution.parquet,
+        )?,
+    };
     // create directory to contain the Parquet files (one per partition)
     let fs_path = std::path::Path::new(path);
     if let Err(e) = fs::create_dir(fs_path) {
@@ -730,7 +1024,7 @@ pub async fn plan_to_parquet(
         let path = fs_path.join(filename);
         let file = fs::File::create(path)?;
         let mut writer =
-            ArrowWriter::try_new(file, plan.schema(), writer_properties.clone())?;
+            ArrowWriter::try_new(file, plan.schema(), Some(writer_properties.clone()))?;
         let stream = plan.execute(i, task_ctx.clone())?;
         let handle: tokio::task::JoinHandle<Result<()>> =
             tokio::task::spawn(async move {
@@ -805,8 +1099,7 @@ mod tests {
     use super::*;
     use crate::datasource::file_format::parquet::test_util::store_parquet;
     use crate::datasource::file_format::test_util::scan_format;
-    use crate::datasource::listing::{FileRange, PartitionedFile};
-    use crate::datasource::object_store::ObjectStoreUrl;
+    use crate::datasource::listing::{FileRange, ListingTable, PartitionedFile};
     use crate::execution::context::SessionState;
     use crate::execution::options::CsvReadOptions;
     use crate::physical_plan::displayable;
@@ -818,7 +1111,7 @@ mod tests {
         datasource::file_format::{parquet::ParquetFormat, FileFormat},
         physical_plan::collect,
     };
-    use arrow::array::{ArrayRef, Float32Array, Int32Array};
+    use arrow::array::{ArrayRef, Float32Array, Int32Array, StructArray};
     use arrow::datatypes::Schema;
     use arrow::record_batch::RecordBatch;
     use arrow::{
@@ -832,9 +1125,11 @@ mod tests {
     use datafusion_physical_expr::create_physical_expr;
     use datafusion_physical_expr::execution_props::ExecutionProps;
     use futures::StreamExt;
+    use itertools::Itertools;
     use object_store::local::LocalFileSystem;
     use object_store::path::Path;
     use object_store::ObjectMeta;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
     use std::fs::File;
     use std::io::Write;
     use tempfile::TempDir;
@@ -933,10 +1228,12 @@ mod tests {
                     file_schema,
                     statistics: Statistics::default(),
                     projection,
+                    nested_projection: None,
                     limit: None,
                     table_partition_cols: vec![],
                     output_ordering: None,
                     infinite_source: false,
+                    schema_evolution: SchemaEvolution::Strict,
                 },
                 predicate,
                 None,
@@ -1585,10 +1882,12 @@ mod tests {
                     file_schema,
                     statistics: Statistics::default(),
                     projection: None,
+                    nested_projection: None,
                     limit: None,
                     table_partition_cols: vec![],
                     output_ordering: None,
                     infinite_source: false,
+                    schema_evolution: SchemaEvolution::Strict,
                 },
                 None,
                 None,
@@ -1671,6 +1970,7 @@ mod tests {
                 statistics: Statistics::default(),
                 // file has 10 cols so index 12 should be month
                 projection: Some(vec![0, 1, 2, 12]),
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![
                     ("year".to_owned(), partition_type_wrap(DataType::Utf8)),
@@ -1679,6 +1979,7 @@ mod tests {
                 ],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -1735,10 +2036,12 @@ mod tests {
                 file_schema: Arc::new(Schema::empty()),
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -1835,6 +2138,86 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn parquet_bloom_filter_exec_metrics() {
+        let c1: ArrayRef =
+            Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e", "f"]));
+        let c2: ArrayRef = Arc::new(Int64Array::from(vec![1, 2, 3, 4, 5, 6]));
+        let batch = create_batch(vec![("c1", c1), ("c2", c2)]);
+        let schema = batch.schema();
+
+        // two row groups: c1 in [a, c] and [d, f]
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.path().join("bloom.parquet");
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(3)
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut writer = ArrowWriter::try_new(
+            File::create(&path).unwrap(),
+            schema.clone(),
+            Some(props),
+        )
+        .unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        async fn scan(
+            path: &std::path::Path,
+            schema: &SchemaRef,
+            filter: Expr,
+        ) -> (Vec<RecordBatch>, MetricsSet) {
+            let parquet_exec = Arc::new(
+                ParquetExec::new(
+                    FileScanConfig {
+                        object_store_url: ObjectStoreUrl::local_filesystem(),
+                        file_groups: vec![vec![local_unpartitioned_file(path).into()]],
+                        file_schema: schema.clone(),
+                        statistics: Statistics::default(),
+                        projection: None,
+                        nested_projection: None,
+                        limit: None,
+                        table_partition_cols: vec![],
+                        output_ordering: None,
+                        infinite_source: false,
+                        schema_evolution: SchemaEvolution::Strict,
+                    },
+                    Some(logical2physical(&filter, schema)),
+                    None,
+                )
+                .with_bloom_filter_enabled(true),
+            );
+            let task_ctx = SessionContext::new().task_ctx();
+            let batches = collect(parquet_exec.clone(), task_ctx).await.unwrap();
+            (batches, parquet_exec.metrics().unwrap())
+        }
+
+        // 'bb' is within the min/max of the first row group only
+        let (batches, metrics) = scan(&path, &schema, col("c1").eq(lit("bb"))).await;
+        assert!(batches.iter().all(|b| b.num_rows() == 0));
+        assert_eq!(get_value(&metrics, "row_groups_pruned"), 1);
+        assert_eq!(get_value(&metrics, "row_groups_pruned_bloom_filter"), 1);
+
+        let filter = col("c1")
+            .in_list(vec![lit("b"), lit("ee")], false)
+            .and(col("c2").gt(lit(0i64)));
+        let (batches, metrics) = scan(&path, &schema, filter).await;
+        // only the first row group is read, the predicate is not applied to rows
+        #[rustfmt::skip]
+        let expected = vec![
+            "+----+----+",
+            "| c1 | c2 |",
+            "+----+----+",
+            "| a  | 1  |",
+            "| b  | 2  |",
+            "| c  | 3  |",
+            "+----+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        assert_eq!(get_value(&metrics, "row_groups_pruned"), 0);
+        assert_eq!(get_value(&metrics, "row_groups_pruned_bloom_filter"), 1);
+    }
+
     #[tokio::test]
     async fn parquet_exec_display() {
         let c1: ArrayRef = Arc::new(StringArray::from(vec![
@@ -1927,10 +2310,12 @@ mod tests {
                 file_schema: Arc::new(Schema::empty()),
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -1964,10 +2349,12 @@ mod tests {
                 file_schema: Arc::new(Schema::empty()),
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -2006,10 +2393,12 @@ mod tests {
                 file_schema: Arc::new(Schema::empty()),
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -2044,10 +2433,12 @@ mod tests {
                 file_schema: Arc::new(Schema::empty()),
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -2083,10 +2474,12 @@ mod tests {
                 file_schema: Arc::new(Schema::empty()),
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -2112,10 +2505,12 @@ mod tests {
                 file_schema: Arc::new(Schema::empty()),
                 statistics: Statistics::default(),
                 projection: None,
+                nested_projection: None,
                 limit: None,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
             None,
@@ -2266,6 +2661,222 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn write_parquet_results_with_session_options() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let config = SessionConfig::new()
+            .with_target_partitions(1)
+            .set_str("datafusion.execution.parquet.compression", "zstd(3)")
+            .set_str(
+                "datafusion.execution.parquet.column_compression",
+                "c2:snappy",
+            )
+            .set_str("datafusion.execution.parquet.max_row_group_size", "3")
+            .set_str("datafusion.execution.parquet.created_by", "test writer");
+        let ctx = SessionContext::with_config(config);
+
+        let out_dir = tmp_dir.as_ref().to_str().unwrap().to_string() + "/out";
+        let df = ctx
+            .sql("SELECT * FROM (VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')) AS t(c1, c2)")
+            .await?;
+        df.write_parquet(&out_dir, None).await?;
+
+        let file = File::open(format!("{out_dir}/part-0.parquet"))?;
+        let reader = SerializedFileReader::new(file)?;
+        let metadata = reader.metadata();
+        assert_eq!(metadata.file_metadata().created_by(), Some("test writer"));
+        assert_eq!(metadata.num_row_groups(), 2);
+        let row_group = metadata.row_group(0);
+        assert_eq!(row_group.num_rows(), 3);
+        assert_eq!(
+            row_group.column(0).compression(),
+            Compression::ZSTD(ZstdLevel::try_new(3)?)
+        );
+        assert_eq!(row_group.column(1).compression(), Compression::SNAPPY);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_parquet_results_with_table_options() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let config = SessionConfig::new()
+            .with_target_partitions(1)
+            .set_str("datafusion.execution.parquet.compression", "snappy");
+        let ctx = SessionContext::with_config(config);
+        let testdata = crate::test_util::parquet_test_data();
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE t STORED AS PARQUET \
+             OPTIONS ('compression' 'zstd(3)', 'created_by' 'table writer') \
+             LOCATION '{testdata}/alltypes_plain.parquet'"
+        ))
+        .await?;
+
+        let table = ctx.table_provider("t").await?;
+        let table = table.as_any().downcast_ref::<ListingTable>().unwrap();
+        let format = table
+            .options()
+            .format
+            .as_any()
+            .downcast_ref::<ParquetFormat>()
+            .unwrap();
+        let props = format.writer_properties(ctx.state().config_options())?;
+
+        let out_dir = tmp_dir.as_ref().to_str().unwrap().to_string() + "/out";
+        let df = ctx.sql("SELECT id, bool_col FROM t").await?;
+        df.write_parquet(&out_dir, Some(props)).await?;
+
+        let file = File::open(format!("{out_dir}/part-0.parquet"))?;
+        let reader = SerializedFileReader::new(file)?;
+        let metadata = reader.metadata();
+        assert_eq!(metadata.file_metadata().created_by(), Some("table writer"));
+        for column in metadata.row_group(0).columns() {
+            assert_eq!(
+                column.compression(),
+                Compression::ZSTD(ZstdLevel::try_new(3)?)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested_projection_mask_leaves() -> Result<()> {
+        let user = DataType::Struct(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let payload = DataType::Struct(vec![
+            Field::new("user", user, true),
+            Field::new("kind", DataType::Utf8, true),
+        ]);
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("payload", payload, true),
+        ]);
+        // leaves: a, payload.user.id, payload.user.name, payload.kind
+        let parquet_schema = parquet::arrow::arrow_to_parquet_schema(&schema)?;
+
+        let paths = vec![vec![
+            "payload".to_string(),
+            "user".to_string(),
+            "id".to_string(),
+        ]];
+        let pruned = project_nested_fields(&schema, &paths);
+        let mask = nested_projection_mask(&parquet_schema, &pruned, &[0, 1]);
+        let leaves: Vec<bool> = (0..4).map(|i| mask.leaf_included(i)).collect();
+        assert_eq!(leaves, vec![true, true, false, false]);
+
+        // without paths the whole struct is read
+        let mask = nested_projection_mask(&parquet_schema, &schema, &[1]);
+        let leaves: Vec<bool> = (0..4).map(|i| mask.leaf_included(i)).collect();
+        assert_eq!(leaves, vec![false, true, true, true]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_with_nested_projection() -> Result<()> {
+        let id: ArrayRef = Arc::new(Int64Array::from(vec![1, 2, 3]));
+        let s: ArrayRef = Arc::new(StructArray::from(vec![
+            (
+                Field::new("a", DataType::Int64, true),
+                Arc::new(Int64Array::from(vec![10, 20, 30])) as ArrayRef,
+            ),
+            (
+                Field::new("b", DataType::Utf8, true),
+                Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef,
+            ),
+        ]));
+        let batch = create_batch(vec![("id", id), ("s", s)]);
+
+        let tmp_dir = TempDir::new()?;
+        let path = tmp_dir.path().join("nested.parquet");
+        let mut writer =
+            ArrowWriter::try_new(File::create(&path)?, batch.schema(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        let ctx = SessionContext::new();
+        ctx.register_parquet("t", path.to_str().unwrap(), ParquetReadOptions::default())
+            .await?;
+        let df = ctx
+            .sql("SELECT id, s['a'] AS a FROM t WHERE id > 1")
+            .await?;
+
+        // only the leaf `s.a` of the struct is read
+        let plan = df.clone().create_physical_plan().await?;
+        let plan = displayable(plan.as_ref()).indent().to_string();
+        assert_contains!(&plan, "nested_projection=[s.a]");
+
+        let batches = df.collect().await?;
+        let expected = vec![
+            "+----+----+",
+            "| id | a  |",
+            "+----+----+",
+            "| 2  | 20 |",
+            "| 3  | 30 |",
+            "+----+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parquet_writer_properties_from_options() -> Result<()> {
+        let mut options = ParquetOptions {
+            compression: Some("gzip(6)".to_string()),
+            column_compression: Some("a.b: lz4_raw, c:uncompressed,".to_string()),
+            dictionary_enabled: false,
+            statistics_enabled: "chunk".to_string(),
+            bloom_filter_columns: Some("c".to_string()),
+            ..Default::default()
+        };
+        let props = parquet_writer_properties(&options)?;
+        let path = |s: &str| ColumnPath::from(s);
+        assert_eq!(
+            props.compression(&path("x")),
+            Compression::GZIP(GzipLevel::try_new(6)?)
+        );
+        assert_eq!(
+            props.compression(&ColumnPath::new(vec!["a".into(), "b".into()])),
+            Compression::LZ4_RAW
+        );
+        assert_eq!(props.compression(&path("c")), Compression::UNCOMPRESSED);
+        assert!(!props.dictionary_enabled(&path("x")));
+        assert_eq!(
+            props.statistics_enabled(&path("x")),
+            EnabledStatistics::Chunk
+        );
+        assert!(props.bloom_filter_properties(&path("c")).is_some());
+        assert!(props.bloom_filter_properties(&path("x")).is_none());
+
+        for (compression, error) in [
+            ("zip", "Unknown parquet compression 'zip'"),
+            (
+                "snappy(1)",
+                "Parquet compression 'snappy' does not take a level",
+            ),
+            (
+                "zstd(x)",
+                "Invalid level 'x' for parquet compression 'zstd'",
+            ),
+            ("gzip(3", "Invalid parquet compression 'gzip(3'"),
+        ] {
+            options.compression = Some(compression.to_string());
+            let err = parquet_writer_properties(&options).unwrap_err();
+            assert_contains!(err.to_string(), error);
+        }
+
+        options.compression = None;
+        options.statistics_enabled = "all".to_string();
+        let err = parquet_writer_properties(&options).unwrap_err();
+        assert_contains!(err.to_string(), "Unknown parquet statistics level 'all'");
+
+        Ok(())
+    }
+
     fn logical2physical(expr: &Expr, schema: &Schema) -> Arc<dyn PhysicalExpr> {
         let df_schema = schema.clone().to_dfschema().unwrap();
         let execution_props = ExecutionProps::new();
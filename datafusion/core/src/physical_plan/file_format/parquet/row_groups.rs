@@ -17,14 +17,31 @@
 
 use arrow::{
     array::ArrayRef,
-    datatypes::{DataType, Schema},
+    datatypes::{DataType, Schema, TimeUnit},
 };
+use bytes::{Buf, Bytes};
 use datafusion_common::Column;
 use datafusion_common::ScalarValue;
+use datafusion_expr::Operator;
+use datafusion_physical_expr::expressions::{
+    BinaryExpr, Column as ColumnExpr, InListExpr, Literal,
+};
+use datafusion_physical_expr::{split_conjunction, PhysicalExpr};
 use log::debug;
+use std::ops::Range;
+use std::sync::Arc;
 
+use parquet::arrow::async_reader::AsyncFileReader;
+use parquet::basic::Type as PhysicalType;
+use parquet::bloom_filter::Sbbf;
+use parquet::data_type::ByteArray;
+use parquet::errors::ParquetError;
 use parquet::file::{
-    metadata::RowGroupMetaData, statistics::Statistics as ParquetStatistics,
+    metadata::{ParquetMetaData, RowGroupMetaData},
+    properties::ReaderProperties,
+    reader::{ChunkReader, Length, RowGroupReader},
+    serialized_reader::SerializedRowGroupReader,
+    statistics::Statistics as ParquetStatistics,
 };
 
 use crate::physical_plan::file_format::parquet::{
@@ -86,6 +103,315 @@ pub(crate) fn prune_row_groups(
     filtered
 }
 
+/// The equality and `IN` list conjuncts of a predicate that bloom filters
+/// can rule out: a row group can only match if, for each column, the
+/// column's bloom filter may contain one of the listed values.
+///
+/// Floating point values are never checked: bloom filters hash the bit
+/// pattern of a value, but `0.0 = -0.0` holds although their bit patterns
+/// differ, and NaNs have many bit patterns.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BloomFilterPredicate {
+    columns: Vec<(String, Vec<ScalarValue>)>,
+}
+
+impl BloomFilterPredicate {
+    /// Collects the `column = literal` and `column IN (literals)` conjuncts
+    /// of `expr`, returning `None` if there are none
+    pub(crate) fn try_new(expr: &Arc<dyn PhysicalExpr>) -> Option<Self> {
+        let columns: Vec<_> = split_conjunction(expr)
+            .into_iter()
+            .filter_map(|conjunct| {
+                let any = conjunct.as_any();
+                if let Some(binary) = any.downcast_ref::<BinaryExpr>() {
+                    if *binary.op() != Operator::Eq {
+                        return None;
+                    }
+                    let (column, literal) = match (
+                        binary.left().as_any().downcast_ref::<ColumnExpr>(),
+                        binary.right().as_any().downcast_ref::<Literal>(),
+                    ) {
+                        (Some(column), Some(literal)) => (column, literal),
+                        _ => (
+                            binary.right().as_any().downcast_ref::<ColumnExpr>()?,
+                            binary.left().as_any().downcast_ref::<Literal>()?,
+                        ),
+                    };
+                    Some((column.name().to_string(), vec![literal.value().clone()]))
+                } else if let Some(in_list) = any.downcast_ref::<InListExpr>() {
+                    if in_list.negated() {
+                        return None;
+                    }
+                    let column = in_list.expr().as_any().downcast_ref::<ColumnExpr>()?;
+                    let values = in_list
+                        .list()
+                        .iter()
+                        .map(|e| {
+                            e.as_any()
+                                .downcast_ref::<Literal>()
+                                .map(|l| l.value().clone())
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+                    Some((column.name().to_string(), values))
+                } else {
+                    None
+                }
+            })
+            .filter(|(_, values)| {
+                !values.iter().any(|v| {
+                    matches!(v, ScalarValue::Float32(_) | ScalarValue::Float64(_))
+                })
+            })
+            .collect();
+
+        (!columns.is_empty()).then_some(Self { columns })
+    }
+}
+
+/// Prunes the row groups in `row_groups` whose bloom filters show that
+/// they can not contain any row matching `predicate`, returning the row
+/// groups that should still be scanned.
+///
+/// Only the bloom filters of the columns referenced by `predicate` are
+/// fetched; row groups without bloom filters are kept.
+pub(crate) async fn prune_row_groups_by_bloom_filters<T: AsyncFileReader>(
+    reader: &mut T,
+    file_size: usize,
+    metadata: &ParquetMetaData,
+    row_groups: Vec<usize>,
+    predicate: &BloomFilterPredicate,
+    metrics: &ParquetFileMetrics,
+) -> Vec<usize> {
+    let chunks =
+        match fetch_bloom_filters(reader, file_size, metadata, &row_groups, predicate)
+            .await
+        {
+            Ok(Some(chunks)) => Arc::new(chunks),
+            Ok(None) => return row_groups,
+            Err(e) => {
+                debug!("Ignoring error fetching bloom filters: {e}");
+                metrics.predicate_evaluation_errors.add(1);
+                return row_groups;
+            }
+        };
+
+    let props = Arc::new(
+        ReaderProperties::builder()
+            .set_read_bloom_filter(true)
+            .build(),
+    );
+    row_groups
+        .into_iter()
+        .filter(|idx| {
+            let row_group = metadata.row_group(*idx);
+            let columns: Vec<_> = predicate
+                .columns
+                .iter()
+                .map(|(name, values)| (find_column(row_group, name), values))
+                .collect();
+            let consulted: Vec<usize> =
+                columns.iter().filter_map(|(column, _)| *column).collect();
+            let matches =
+                with_bloom_filters_of(row_group, &consulted).and_then(|row_group| {
+                    let reader = SerializedRowGroupReader::new(
+                        chunks.clone(),
+                        &row_group,
+                        None,
+                        props.clone(),
+                    )?;
+                    Ok(columns.iter().all(|(column, values)| {
+                        let column = match column {
+                            Some(column) => *column,
+                            None => return true,
+                        };
+                        let physical_type = row_group.column(column).column_type();
+                        match reader.get_column_bloom_filter(column) {
+                            Some(sbbf) => values.iter().any(|value| {
+                                bloom_filter_may_contain(sbbf, value, physical_type)
+                            }),
+                            None => true,
+                        }
+                    }))
+                });
+            let matches = match matches {
+                Ok(matches) => matches,
+                Err(e) => {
+                    debug!("Ignoring error reading bloom filters: {e}");
+                    metrics.predicate_evaluation_errors.add(1);
+                    true
+                }
+            };
+            if !matches {
+                metrics.row_groups_pruned_bloom_filter.add(1);
+            }
+            matches
+        })
+        .collect()
+}
+
+/// Returns a copy of `row_group` that only references the bloom filters of
+/// `columns`, so that reading it does not need the others
+fn with_bloom_filters_of(
+    row_group: &RowGroupMetaData,
+    columns: &[usize],
+) -> parquet::errors::Result<RowGroupMetaData> {
+    let mut thrift = row_group.to_thrift();
+    for (i, column) in thrift.columns.iter_mut().enumerate() {
+        if !columns.contains(&i) {
+            if let Some(meta_data) = column.meta_data.as_mut() {
+                meta_data.bloom_filter_offset = None;
+            }
+        }
+    }
+    RowGroupMetaData::from_thrift(row_group.schema_descr_ptr(), thrift)
+}
+
+/// Returns the index of the top level column `name` in `row_group`
+fn find_column(row_group: &RowGroupMetaData, name: &str) -> Option<usize> {
+    row_group.columns().iter().position(|column| {
+        let path = column.column_path().parts();
+        path.len() == 1 && path[0] == name
+    })
+}
+
+/// Fetches the bloom filters of the columns in `predicate` for `row_groups`
+///
+/// The length of a bloom filter is not recorded in the metadata, so each
+/// one is read up to the next known offset in the file: the start of a
+/// column chunk, a page index, another bloom filter or the footer.
+async fn fetch_bloom_filters<T: AsyncFileReader>(
+    reader: &mut T,
+    file_size: usize,
+    metadata: &ParquetMetaData,
+    row_groups: &[usize],
+    predicate: &BloomFilterPredicate,
+) -> parquet::errors::Result<Option<BloomFilterChunks>> {
+    let mut offsets = vec![file_size.saturating_sub(8)];
+    for row_group in metadata.row_groups() {
+        for column in row_group.columns() {
+            offsets.push(column.data_page_offset() as usize);
+            offsets.extend(
+                [
+                    column.dictionary_page_offset(),
+                    column.bloom_filter_offset(),
+                    column.column_index_offset(),
+                    column.offset_index_offset(),
+                ]
+                .into_iter()
+                .flatten()
+                .map(|offset| offset as usize),
+            );
+        }
+    }
+    offsets.sort_unstable();
+    offsets.dedup();
+
+    let mut ranges: Vec<Range<usize>> = vec![];
+    for idx in row_groups {
+        let row_group = metadata.row_group(*idx);
+        for (name, _) in &predicate.columns {
+            let start = match find_column(row_group, name)
+                .and_then(|column| row_group.column(column).bloom_filter_offset())
+            {
+                Some(start) => start as usize,
+                None => continue,
+            };
+            let end = offsets
+                .iter()
+                .copied()
+                .find(|offset| *offset > start)
+                .unwrap_or(file_size);
+            ranges.push(start..end);
+        }
+    }
+    if ranges.is_empty() {
+        return Ok(None);
+    }
+    ranges.sort_unstable_by_key(|range| range.start);
+    ranges.dedup();
+
+    let data = reader.get_byte_ranges(ranges.clone()).await?;
+    Ok(Some(BloomFilterChunks {
+        file_size: file_size as u64,
+        chunks: ranges
+            .into_iter()
+            .zip(data)
+            .map(|(range, bytes)| (range.start as u64, bytes))
+            .collect(),
+    }))
+}
+
+/// A [`ChunkReader`] over the byte ranges of a file that hold the fetched
+/// bloom filters
+struct BloomFilterChunks {
+    file_size: u64,
+    /// The fetched ranges, by start offset
+    chunks: Vec<(u64, Bytes)>,
+}
+
+impl Length for BloomFilterChunks {
+    fn len(&self) -> u64 {
+        self.file_size
+    }
+}
+
+impl ChunkReader for BloomFilterChunks {
+    type T = bytes::buf::Reader<Bytes>;
+
+    fn get_read(&self, start: u64, length: usize) -> parquet::errors::Result<Self::T> {
+        Ok(self.get_bytes(start, length)?.reader())
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> parquet::errors::Result<Bytes> {
+        let end = start + length as u64;
+        self.chunks
+            .iter()
+            .find(|(offset, bytes)| {
+                *offset <= start && end <= offset + bytes.len() as u64
+            })
+            .map(|(offset, bytes)| {
+                bytes.slice((start - offset) as usize..(end - offset) as usize)
+            })
+            .ok_or_else(|| {
+                ParquetError::General(format!(
+                    "Range {start}..{end} of the bloom filters was not fetched"
+                ))
+            })
+    }
+}
+
+/// Returns false if `sbbf`, the bloom filter of a column with the given
+/// physical type, shows that the column does not contain `value`
+fn bloom_filter_may_contain(
+    sbbf: &Sbbf,
+    value: &ScalarValue,
+    physical_type: PhysicalType,
+) -> bool {
+    match (value, physical_type) {
+        (ScalarValue::Boolean(Some(v)), PhysicalType::BOOLEAN) => sbbf.check(v),
+        (ScalarValue::Int8(Some(v)), PhysicalType::INT32) => sbbf.check(&(*v as i32)),
+        (ScalarValue::Int16(Some(v)), PhysicalType::INT32) => sbbf.check(&(*v as i32)),
+        (ScalarValue::Int32(Some(v)), PhysicalType::INT32) => sbbf.check(v),
+        (ScalarValue::UInt8(Some(v)), PhysicalType::INT32) => sbbf.check(&(*v as i32)),
+        (ScalarValue::UInt16(Some(v)), PhysicalType::INT32) => sbbf.check(&(*v as i32)),
+        (ScalarValue::UInt32(Some(v)), PhysicalType::INT32) => sbbf.check(&(*v as i32)),
+        (ScalarValue::Date32(Some(v)), PhysicalType::INT32) => sbbf.check(v),
+        (ScalarValue::Int64(Some(v)), PhysicalType::INT64) => sbbf.check(v),
+        (ScalarValue::UInt64(Some(v)), PhysicalType::INT64) => sbbf.check(&(*v as i64)),
+        (
+            ScalarValue::Utf8(Some(v)) | ScalarValue::LargeUtf8(Some(v)),
+            PhysicalType::BYTE_ARRAY,
+        ) => sbbf.check(&ByteArray::from(v.as_str())),
+        (
+            ScalarValue::Binary(Some(v)) | ScalarValue::LargeBinary(Some(v)),
+            PhysicalType::BYTE_ARRAY,
+        ) => sbbf.check(&ByteArray::from(v.clone())),
+        // the filter can not rule out values of other types, including
+        // floats whose equal values may hash differently
+        _ => true,
+    }
+}
+
 /// Wraps parquet statistics in a way
 /// that implements [`PruningStatistics`]
 struct RowGroupPruningStatistics<'a> {
@@ -124,6 +450,22 @@ macro_rules! get_statistic {
                             scale,
                         ))
                     }
+                    // int64 to duration of the same unit
+                    Some(DataType::Duration(unit)) => {
+                        let value = Some(*s.$func());
+                        Some(match unit {
+                            TimeUnit::Second => ScalarValue::DurationSecond(value),
+                            TimeUnit::Millisecond => {
+                                ScalarValue::DurationMillisecond(value)
+                            }
+                            TimeUnit::Microsecond => {
+                                ScalarValue::DurationMicrosecond(value)
+                            }
+                            TimeUnit::Nanosecond => {
+                                ScalarValue::DurationNanosecond(value)
+                            }
+                        })
+                    }
                     _ => Some(ScalarValue::Int64(Some(*s.$func()))),
                 }
             }
@@ -189,7 +531,8 @@ macro_rules! get_min_max_values {
             .and_then(|c| if c.statistics().is_some() {Some((c.statistics().unwrap(), c.column_descr()))} else {None})
             .map(|(stats, column_descr)|
                 {
-                    let target_data_type = parquet_to_arrow_decimal_type(column_descr);
+                    let target_data_type = parquet_to_arrow_decimal_type(column_descr)
+                        .or_else(|| Some(data_type.clone()));
                     get_statistic!(stats, $func, $bytes_func, target_data_type)
                 })
             .flatten()
@@ -453,6 +796,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn row_group_pruning_predicate_duration_type() {
+        // INT64: c1 > 5 seconds, the c1 is a duration in seconds
+        let schema = Schema::new(vec![Field::new(
+            "c1",
+            DataType::Duration(TimeUnit::Second),
+            false,
+        )]);
+        let schema_descr = get_test_schema_descr(vec![(
+            "c1",
+            PhysicalType::INT64,
+            None,
+            None,
+            None,
+            None,
+        )]);
+        let expr = col("c1").gt(lit(ScalarValue::DurationSecond(Some(5))));
+        let expr = logical2physical(&expr, &schema);
+        let pruning_predicate =
+            PruningPredicate::try_new(expr, Arc::new(schema)).unwrap();
+        let rgm1 = get_row_group_meta_data(
+            &schema_descr,
+            vec![ParquetStatistics::int64(Some(1), Some(10), None, 0, false)],
+        );
+        let rgm2 = get_row_group_meta_data(
+            &schema_descr,
+            vec![ParquetStatistics::int64(Some(1), Some(4), None, 0, false)],
+        );
+        let metrics = parquet_file_metrics();
+        assert_eq!(
+            prune_row_groups(&[rgm1, rgm2], None, Some(&pruning_predicate), &metrics),
+            vec![0]
+        );
+    }
+
     #[test]
     fn row_group_pruning_predicate_decimal_type() {
         // For the decimal data type, parquet can use `INT32`, `INT64`, `BYTE_ARRAY`, `FIXED_LENGTH_BYTE_ARRAY` to
@@ -840,6 +1218,66 @@ mod tests {
         ParquetFileMetrics::new(0, "file.parquet", &metrics)
     }
 
+    #[test]
+    fn bloom_filter_predicate() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, false),
+            Field::new("c2", DataType::Int32, false),
+        ]);
+
+        let expr = col("c1")
+            .eq(lit("a"))
+            .and(col("c2").in_list(vec![lit(1), lit(2)], false))
+            .and(col("c2").gt(lit(0)));
+        let predicate =
+            BloomFilterPredicate::try_new(&logical2physical(&expr, &schema)).unwrap();
+        assert_eq!(
+            predicate.columns,
+            vec![
+                ("c1".to_string(), vec![ScalarValue::from("a")]),
+                (
+                    "c2".to_string(),
+                    vec![ScalarValue::Int32(Some(1)), ScalarValue::Int32(Some(2))]
+                ),
+            ]
+        );
+
+        // disjunctions and negated lists can not be checked against bloom filters
+        let expr = col("c1")
+            .eq(lit("a"))
+            .or(col("c2").eq(lit(1)))
+            .and(col("c2").in_list(vec![lit(1), lit(2)], true));
+        assert!(
+            BloomFilterPredicate::try_new(&logical2physical(&expr, &schema)).is_none()
+        );
+    }
+
+    #[test]
+    fn bloom_filter_predicate_skips_floats() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Float64, false),
+            Field::new("c2", DataType::Float32, false),
+            Field::new("c3", DataType::Int32, false),
+        ]);
+
+        // `c1 = 0.0` also matches `-0.0`, whose bit pattern differs
+        let expr = col("c1")
+            .eq(lit(0.0_f64))
+            .and(col("c2").in_list(vec![lit(1.0_f32), lit(f32::NAN)], false))
+            .and(col("c3").eq(lit(1)));
+        let predicate =
+            BloomFilterPredicate::try_new(&logical2physical(&expr, &schema)).unwrap();
+        assert_eq!(
+            predicate.columns,
+            vec![("c3".to_string(), vec![ScalarValue::Int32(Some(1))])]
+        );
+
+        let expr = col("c1").eq(lit(-0.0_f64));
+        assert!(
+            BloomFilterPredicate::try_new(&logical2physical(&expr, &schema)).is_none()
+        );
+    }
+
     fn logical2physical(expr: &Expr, schema: &Schema) -> Arc<dyn PhysicalExpr> {
         let df_schema = schema.clone().to_dfschema().unwrap();
         let execution_props = ExecutionProps::new();
@@ -0,0 +1,421 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Merging of the evolving schemas of the files of a table, and adaptation
+//! of the record batches read from the files to the merged schema
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use arrow::array::{new_null_array, Array, ArrayRef, StructArray};
+use arrow::compute::{can_cast_types, cast, is_not_null};
+use arrow::datatypes::{DataType, Field, Schema};
+
+use crate::error::{DataFusionError, Result};
+
+/// The metadata key of the field ids of the arrow fields read from parquet files
+pub const PARQUET_FIELD_ID_META_KEY: &str = "PARQUET:field_id";
+
+/// How the schemas of the files of a table may differ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SchemaEvolution {
+    /// The files may only have added or removed columns. The schema of the
+    /// table is the union of their columns, which must have the same type in
+    /// all the files
+    #[default]
+    Strict,
+    /// The columns of the files may also have been widened, e.g. from `Int32`
+    /// to `Int64` or `Float32` to `Float64`, renamed, if the files record the
+    /// field ids of their columns, or have had fields added to their structs.
+    /// The schema of the table is merged with [`merge_schemas`], and the
+    /// columns of each file are cast to their type in the table.
+    ///
+    /// Row groups and pages are still pruned with the statistics of the
+    /// parquet columns of the same name, not field id, so filters on renamed
+    /// columns do not prune the files written before they were renamed, and
+    /// the name of a renamed column must not be reused for another column
+    Merge,
+}
+
+impl FromStr for SchemaEvolution {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "strict" => Ok(Self::Strict),
+            "merge" => Ok(Self::Merge),
+            _ => Err(DataFusionError::Plan(format!(
+                "Unknown schema evolution mode '{s}', expected 'strict' or 'merge'"
+            ))),
+        }
+    }
+}
+
+impl Display for SchemaEvolution {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Strict => write!(f, "strict"),
+            Self::Merge => write!(f, "merge"),
+        }
+    }
+}
+
+/// Merges the schemas of the files of a table into the schema of the table.
+///
+/// Fields with the same field id, or the same name if either has no field
+/// id, are merged: their types are widened to a type that values of both
+/// types can be cast to without loss, and the fields of their structs are
+/// merged the same way. Renamed fields take their name in the last schema.
+/// Fields missing from some schemas are nullable.
+pub fn merge_schemas(schemas: &[Schema]) -> Result<Schema> {
+    let mut schemas = schemas.iter();
+    let first = match schemas.next() {
+        Some(schema) => schema,
+        None => return Ok(Schema::empty()),
+    };
+    let mut fields = first.fields().clone();
+    let mut metadata = first.metadata().clone();
+    for schema in schemas {
+        fields = merge_fields(&fields, schema.fields())?;
+        metadata.extend(schema.metadata().clone());
+    }
+    Ok(Schema::new_with_metadata(fields, metadata))
+}
+
+fn merge_fields(fields: &[Field], others: &[Field]) -> Result<Vec<Field>> {
+    let mut merged: Vec<Field> = fields
+        .iter()
+        .map(|field| match find_field(field, others) {
+            Some(other) => merge_field(field, other),
+            None => Ok(with_nullable(field, true)),
+        })
+        .collect::<Result<_>>()?;
+    for other in others {
+        if find_field(other, fields).is_none() {
+            merged.push(with_nullable(other, true));
+        }
+    }
+    Ok(merged)
+}
+
+fn merge_field(field: &Field, other: &Field) -> Result<Field> {
+    let data_type = widen(field.data_type(), other.data_type())?.ok_or_else(|| {
+        DataFusionError::Execution(format!(
+            "Can not merge the schemas of the files: field {} has types {:?} and {:?}",
+            other.name(),
+            field.data_type(),
+            other.data_type()
+        ))
+    })?;
+    let mut merged = Field::new(
+        other.name(),
+        data_type,
+        field.is_nullable() || other.is_nullable(),
+    );
+    merged.set_metadata(other.metadata().clone());
+    Ok(merged)
+}
+
+fn with_nullable(field: &Field, nullable: bool) -> Field {
+    let mut field_with_nullable =
+        Field::new(field.name(), field.data_type().clone(), nullable);
+    field_with_nullable.set_metadata(field.metadata().clone());
+    field_with_nullable
+}
+
+/// Returns the type that values of types `a` and `b` can be cast to
+/// without loss, if any
+fn widen(a: &DataType, b: &DataType) -> Result<Option<DataType>> {
+    use DataType::*;
+    Ok(match (a, b) {
+        _ if a == b => Some(a.clone()),
+        (Null, other) | (other, Null) => Some(other.clone()),
+        (Struct(a), Struct(b)) => Some(Struct(merge_fields(a, b)?)),
+        (List(a), List(b)) => Some(List(Box::new(merge_field(a, b)?))),
+        (LargeList(a), LargeList(b)) => Some(LargeList(Box::new(merge_field(a, b)?))),
+        (Utf8 | LargeUtf8, Utf8 | LargeUtf8) => Some(LargeUtf8),
+        (Binary | LargeBinary, Binary | LargeBinary) => Some(LargeBinary),
+        (Date32 | Date64, Date32 | Date64) => Some(Date64),
+        (Float16 | Float32, Float16 | Float32) => Some(Float32),
+        (Float16 | Float32 | Float64, Float16 | Float32 | Float64) => Some(Float64),
+        (Decimal128(p1, s1), Decimal128(p2, s2)) => {
+            let scale = *s1.max(s2);
+            let integer_digits = (*p1 as i8 - *s1).max(*p2 as i8 - *s2);
+            let precision = integer_digits + scale;
+            (precision <= 38).then_some(Decimal128(precision as u8, scale))
+        }
+        _ => match (integer_width(a), integer_width(b)) {
+            (Some((signed_a, bits_a)), Some((signed_b, bits_b))) => {
+                let (signed, bits) = if signed_a == signed_b {
+                    (signed_a, bits_a.max(bits_b))
+                } else {
+                    // the signed type must be wider than the unsigned one
+                    let (signed_bits, unsigned_bits) = if signed_a {
+                        (bits_a, bits_b)
+                    } else {
+                        (bits_b, bits_a)
+                    };
+                    (true, signed_bits.max(2 * unsigned_bits))
+                };
+                integer_type(signed, bits)
+            }
+            // 64 bit integers do not all fit in the mantissa of a Float64
+            (Some((_, bits)), None) if bits <= 32 && matches!(b, Float64) => {
+                Some(Float64)
+            }
+            (None, Some((_, bits))) if bits <= 32 && matches!(a, Float64) => {
+                Some(Float64)
+            }
+            _ => None,
+        },
+    })
+}
+
+fn integer_width(data_type: &DataType) -> Option<(bool, u8)> {
+    match data_type {
+        DataType::Int8 => Some((true, 8)),
+        DataType::Int16 => Some((true, 16)),
+        DataType::Int32 => Some((true, 32)),
+        DataType::Int64 => Some((true, 64)),
+        DataType::UInt8 => Some((false, 8)),
+        DataType::UInt16 => Some((false, 16)),
+        DataType::UInt32 => Some((false, 32)),
+        DataType::UInt64 => Some((false, 64)),
+        _ => None,
+    }
+}
+
+fn integer_type(signed: bool, bits: u8) -> Option<DataType> {
+    match (signed, bits) {
+        (true, 8) => Some(DataType::Int8),
+        (true, 16) => Some(DataType::Int16),
+        (true, 32) => Some(DataType::Int32),
+        (true, 64) => Some(DataType::Int64),
+        (false, 8) => Some(DataType::UInt8),
+        (false, 16) => Some(DataType::UInt16),
+        (false, 32) => Some(DataType::UInt32),
+        (false, 64) => Some(DataType::UInt64),
+        _ => None,
+    }
+}
+
+/// Returns the index of the field of `fields` that `field` was read from:
+/// the field with the same field id, or the same name if either has no
+/// field id
+pub(crate) fn field_index(field: &Field, fields: &[Field]) -> Option<usize> {
+    match field_id(field) {
+        Some(id) => fields
+            .iter()
+            .position(|f| field_id(f) == Some(id))
+            .or_else(|| {
+                fields
+                    .iter()
+                    .position(|f| field_id(f).is_none() && f.name() == field.name())
+            }),
+        None => fields.iter().position(|f| f.name() == field.name()),
+    }
+}
+
+fn find_field<'a>(field: &Field, fields: &'a [Field]) -> Option<&'a Field> {
+    field_index(field, fields).map(|i| &fields[i])
+}
+
+fn field_id(field: &Field) -> Option<&str> {
+    field
+        .metadata()
+        .get(PARQUET_FIELD_ID_META_KEY)
+        .map(String::as_str)
+}
+
+/// Returns true if arrays of `from` type can be adapted to `to` type by
+/// [`adapt_array`]
+pub(crate) fn can_adapt(from: &DataType, to: &DataType) -> bool {
+    match (from, to) {
+        (DataType::Struct(from_fields), DataType::Struct(to_fields)) => {
+            to_fields.iter().all(|to_field| {
+                find_field(to_field, from_fields).map_or(true, |from_field| {
+                    can_adapt(from_field.data_type(), to_field.data_type())
+                })
+            })
+        }
+        _ => can_cast_types(from, to),
+    }
+}
+
+/// Adapts an array read from a file to the type `to` of its column in the
+/// table: structs are rebuilt with the fields of `to`, with null fields
+/// for the fields they do not have, and other arrays are cast
+pub(crate) fn adapt_array(array: &ArrayRef, to: &DataType) -> Result<ArrayRef> {
+    if array.data_type() == to {
+        return Ok(array.clone());
+    }
+    match (array.data_type(), to) {
+        (DataType::Struct(from_fields), DataType::Struct(to_fields)) => {
+            let struct_array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let columns = to_fields
+                .iter()
+                .map(|to_field| {
+                    let column = match field_index(to_field, from_fields) {
+                        Some(i) => {
+                            adapt_array(struct_array.column(i), to_field.data_type())?
+                        }
+                        None => new_null_array(to_field.data_type(), array.len()),
+                    };
+                    Ok((to_field.clone(), column))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let adapted = if array.null_count() > 0 {
+                let validity = is_not_null(array.as_ref())?;
+                StructArray::from((columns, validity.values().clone()))
+            } else {
+                StructArray::from(columns)
+            };
+            Ok(Arc::new(adapted))
+        }
+        _ => Ok(cast(array, to)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Float64Array, Int32Array, Int64Array};
+    use std::collections::HashMap;
+
+    fn field_with_id(name: &str, data_type: DataType, id: i32) -> Field {
+        let mut field = Field::new(name, data_type, false);
+        field.set_metadata(HashMap::from([(
+            PARQUET_FIELD_ID_META_KEY.to_string(),
+            id.to_string(),
+        )]));
+        field
+    }
+
+    #[test]
+    fn merge_widened_types() -> Result<()> {
+        let schemas = vec![
+            Schema::new(vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Float32, false),
+                Field::new("c", DataType::UInt16, false),
+                Field::new("d", DataType::Decimal128(10, 2), false),
+            ]),
+            Schema::new(vec![
+                Field::new("a", DataType::Int64, false),
+                Field::new("b", DataType::Float64, false),
+                Field::new("c", DataType::Int16, false),
+                Field::new("d", DataType::Decimal128(12, 4), false),
+                Field::new("e", DataType::Utf8, false),
+            ]),
+        ];
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Float64, false),
+            Field::new("c", DataType::Int32, false),
+            Field::new("d", DataType::Decimal128(12, 4), false),
+            Field::new("e", DataType::Utf8, true),
+        ]);
+        assert_eq!(merge_schemas(&schemas)?, expected);
+
+        let schemas = vec![
+            Schema::new(vec![Field::new("a", DataType::Int32, false)]),
+            Schema::new(vec![Field::new("a", DataType::Float64, false)]),
+        ];
+        let expected = Schema::new(vec![Field::new("a", DataType::Float64, false)]);
+        assert_eq!(merge_schemas(&schemas)?, expected);
+
+        // Int64 values can not all be cast to Float64 without loss
+        for other in [DataType::Float64, DataType::Utf8] {
+            let schemas = vec![
+                Schema::new(vec![Field::new("a", DataType::Int64, false)]),
+                Schema::new(vec![Field::new("a", other.clone(), false)]),
+            ];
+            let err = merge_schemas(&schemas).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains(&format!("field a has types Int64 and {other:?}")),
+                "{err}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn merge_renamed_and_nested_fields() -> Result<()> {
+        let schemas = vec![
+            Schema::new(vec![
+                field_with_id("user_id", DataType::Int32, 1),
+                field_with_id(
+                    "payload",
+                    DataType::Struct(vec![field_with_id("x", DataType::Int32, 3)]),
+                    2,
+                ),
+            ]),
+            Schema::new(vec![
+                field_with_id("uid", DataType::Int64, 1),
+                field_with_id(
+                    "payload",
+                    DataType::Struct(vec![
+                        field_with_id("x", DataType::Int32, 3),
+                        field_with_id("y", DataType::Utf8, 4),
+                    ]),
+                    2,
+                ),
+            ]),
+        ];
+        let expected = Schema::new(vec![
+            field_with_id("uid", DataType::Int64, 1),
+            field_with_id(
+                "payload",
+                DataType::Struct(vec![
+                    field_with_id("x", DataType::Int32, 3),
+                    with_nullable(&field_with_id("y", DataType::Utf8, 4), true),
+                ]),
+                2,
+            ),
+        ]);
+        assert_eq!(merge_schemas(&schemas)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn adapt_struct_array() -> Result<()> {
+        let from = Arc::new(StructArray::from(vec![(
+            Field::new("x", DataType::Int32, false),
+            Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+        )])) as ArrayRef;
+        let to = DataType::Struct(vec![
+            Field::new("x", DataType::Int64, false),
+            Field::new("y", DataType::Float64, true),
+        ]);
+        assert!(can_adapt(from.data_type(), &to));
+
+        let adapted = adapt_array(&from, &to)?;
+        let adapted = adapted.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(adapted.data_type(), &to);
+        assert_eq!(
+            adapted.column(0).as_ref(),
+            &Int64Array::from(vec![1, 2]) as &dyn Array
+        );
+        assert_eq!(
+            adapted.column(1).as_ref(),
+            &Float64Array::from(vec![None, None]) as &dyn Array
+        );
+        Ok(())
+    }
+}
@@ -385,8 +385,16 @@ impl SymmetricHashJoinExec {
 }
 
 impl Debug for SymmetricHashJoinExec {
-    fn fmt(&self, _f: &mut Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SymmetricHashJoinExec")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .field("on", &self.on)
+            .field("filter", &self.filter)
+            .field("join_type", &self.join_type)
+            .field("schema", &self.schema)
+            .field("null_equals_null", &self.null_equals_null)
+            .finish()
     }
 }
 
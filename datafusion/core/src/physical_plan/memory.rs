@@ -150,6 +150,21 @@ impl MemoryExec {
         })
     }
 
+    /// The partitions of record batches
+    pub fn partitions(&self) -> &[Vec<RecordBatch>] {
+        &self.partitions
+    }
+
+    /// The schema of the record batches, before the projection is applied
+    pub fn original_schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Optional projection
+    pub fn projection(&self) -> &Option<Vec<usize>> {
+        &self.projection
+    }
+
     /// Set sort information
     pub fn with_sort_information(
         mut self,
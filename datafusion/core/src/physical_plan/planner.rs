@@ -472,6 +472,7 @@ impl DefaultPhysicalPlanner {
                 LogicalPlan::TableScan(TableScan {
                     source,
                     projection,
+                    nested_projection,
                     filters,
                     fetch,
                     ..
@@ -482,7 +483,16 @@ impl DefaultPhysicalPlanner {
                     // referred to in the query
                     let filters = unnormalize_cols(filters.iter().cloned());
                     let unaliased: Vec<Expr> = filters.into_iter().map(unalias).collect();
-                    source.scan(session_state, projection.as_ref(), &unaliased, *fetch).await
+                    match nested_projection {
+                        Some(nested_projection) => source.scan_nested(
+                            session_state,
+                            projection.as_ref(),
+                            nested_projection,
+                            &unaliased,
+                            *fetch,
+                        ).await,
+                        None => source.scan(session_state, projection.as_ref(), &unaliased, *fetch).await,
+                    }
                 }
                 LogicalPlan::Values(Values {
                     values,
@@ -1169,6 +1179,40 @@ impl DefaultPhysicalPlanner {
                         "Unsupported logical plan: DropView".to_string(),
                     ))
                 }
+                LogicalPlan::CreateFunction(_) => {
+                    // There is no default plan for "CREATE FUNCTION".
+                    // It must be handled at a higher level (so
+                    // that the function can be registered with
+                    // the context)
+                    Err(DataFusionError::NotImplemented(
+                        "Unsupported logical plan: CreateFunction".to_string(),
+                    ))
+                }
+                LogicalPlan::DropFunction(_) => {
+                    // There is no default plan for "DROP FUNCTION".
+                    // It must be handled at a higher level (so
+                    // that the function can be deregistered from
+                    // the context)
+                    Err(DataFusionError::NotImplemented(
+                        "Unsupported logical plan: DropFunction".to_string(),
+                    ))
+                }
+                LogicalPlan::AlterTable(_) => {
+                    // There is no default plan for "ALTER TABLE".
+                    // It must be handled at a higher level (so
+                    // that the table can be changed in the catalog)
+                    Err(DataFusionError::NotImplemented(
+                        "Unsupported logical plan: AlterTable".to_string(),
+                    ))
+                }
+                LogicalPlan::AlterView(_) => {
+                    // There is no default plan for "ALTER VIEW".
+                    // It must be handled at a higher level (so
+                    // that the view can be changed in the catalog)
+                    Err(DataFusionError::NotImplemented(
+                        "Unsupported logical plan: AlterView".to_string(),
+                    ))
+                }
                 LogicalPlan::CreateView(_) => {
                     // There is no default plan for "CREATE VIEW".
                     // It must be handled at a higher level (so
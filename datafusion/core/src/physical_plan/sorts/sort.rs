@@ -31,6 +31,7 @@ use crate::physical_plan::metrics::{
     BaselineMetrics, CompositeMetricsSet, MemTrackingMetrics, MetricsSet,
 };
 use crate::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeStream;
+use crate::physical_plan::sorts::topk::{TopK, TopKThreshold};
 use crate::physical_plan::sorts::SortedStream;
 use crate::physical_plan::stream::{RecordBatchReceiverStream, RecordBatchStreamAdapter};
 use crate::physical_plan::{
@@ -632,6 +633,8 @@ pub struct SortExec {
     preserve_partitioning: bool,
     /// Fetch highest/lowest n results
     fetch: Option<usize>,
+    /// Threshold published by the TopK heaps when `fetch` is set
+    topk_threshold: Option<Arc<TopKThreshold>>,
 }
 
 impl SortExec {
@@ -657,12 +660,17 @@ impl SortExec {
         preserve_partitioning: bool,
         fetch: Option<usize>,
     ) -> Self {
+        let topk_threshold = match (fetch, expr.first()) {
+            (Some(_), Some(first)) => Some(Arc::new(TopKThreshold::new(first.options))),
+            _ => None,
+        };
         Self {
             expr,
             input,
             metrics_set: CompositeMetricsSet::new(),
             preserve_partitioning,
             fetch,
+            topk_threshold,
         }
     }
 
@@ -680,6 +688,12 @@ impl SortExec {
     pub fn fetch(&self) -> Option<usize> {
         self.fetch
     }
+
+    /// If `fetch` is set, the threshold on the first sort expression that
+    /// rows must reach to be part of the output, updated during execution
+    pub fn topk_threshold(&self) -> Option<&Arc<TopKThreshold>> {
+        self.topk_threshold.as_ref()
+    }
 }
 
 impl ExecutionPlan for SortExec {
@@ -743,12 +757,15 @@ impl ExecutionPlan for SortExec {
         self: Arc<Self>,
         children: Vec<Arc<dyn ExecutionPlan>>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        Ok(Arc::new(SortExec::new_with_partitioning(
+        let mut sort = SortExec::new_with_partitioning(
             self.expr.clone(),
             children[0].clone(),
             self.preserve_partitioning,
             self.fetch,
-        )))
+        );
+        // keep the threshold, scans below may already consult it
+        sort.topk_threshold = self.topk_threshold.clone();
+        Ok(Arc::new(sort))
     }
 
     fn execute(
@@ -763,10 +780,41 @@ impl ExecutionPlan for SortExec {
             partition
         );
 
+        // The threshold outlives an execution of the plan: start over before
+        // the scans below read it. Partitions executed later may drop a value
+        // published by a partition already running, which only loses pruning
+        if let Some(threshold) = &self.topk_threshold {
+            threshold.reset();
+        }
+
         let input = self.input.execute(partition, context.clone())?;
 
         debug!("End SortExec's input.execute for partition: {}", partition);
 
+        if let Some(fetch) = self.fetch {
+            let mut topk = TopK::try_new(
+                partition,
+                input.schema(),
+                self.expr.clone(),
+                fetch,
+                context.session_config().batch_size(),
+                context.memory_pool(),
+                self.metrics_set.new_final_baseline(partition),
+                self.topk_threshold.clone(),
+            )?;
+            let mut input = input;
+            return Ok(Box::pin(RecordBatchStreamAdapter::new(
+                self.schema(),
+                futures::stream::once(async move {
+                    while let Some(batch) = input.next().await {
+                        topk.insert_batch(batch?)?;
+                    }
+                    topk.emit()
+                })
+                .try_flatten(),
+            )));
+        }
+
         Ok(Box::pin(RecordBatchStreamAdapter::new(
             self.schema(),
             futures::stream::once(do_sort(
@@ -922,6 +970,7 @@ mod tests {
     use arrow::compute::SortOptions;
     use arrow::datatypes::*;
     use datafusion_common::cast::{as_primitive_array, as_string_array};
+    use datafusion_common::ScalarValue;
     use futures::FutureExt;
     use std::collections::HashMap;
 
@@ -1161,6 +1210,40 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_sort_fetch_resets_threshold() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let task_ctx = session_ctx.task_ctx();
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::UInt64, true)]));
+        let data: ArrayRef =
+            Arc::new(vec![3, 2, 1].into_iter().map(Some).collect::<UInt64Array>());
+        let batch = RecordBatch::try_new(schema.clone(), vec![data])?;
+        let input = Arc::new(MemoryExec::try_new(&[vec![batch]], schema.clone(), None)?);
+
+        let sort_exec = Arc::new(SortExec::try_new(
+            vec![PhysicalSortExpr {
+                expr: col("a", &schema)?,
+                options: SortOptions::default(),
+            }],
+            input,
+            Some(2),
+        )?);
+        let threshold = sort_exec.topk_threshold().unwrap().clone();
+
+        let first = collect(sort_exec.clone(), task_ctx.clone()).await?;
+        assert_eq!(threshold.value(), Some(ScalarValue::UInt64(Some(2))));
+
+        // a stale threshold from a previous execution is not used
+        threshold.update(ScalarValue::UInt64(Some(0)));
+        let stream = sort_exec.execute(0, task_ctx.clone())?;
+        assert_eq!(threshold.value(), None);
+        drop(stream);
+
+        let second = collect(sort_exec, task_ctx).await?;
+        assert_eq!(first, second);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_lex_sort_by_float() -> Result<()> {
         let session_ctx = SessionContext::new();
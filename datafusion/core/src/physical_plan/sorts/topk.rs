@@ -0,0 +1,632 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! TopK: keeps the first `k` rows of a sort in a bounded heap instead of
+//! sorting and buffering the whole input.
+
+use crate::error::Result;
+use crate::execution::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
+use crate::physical_plan::common::batch_byte_size;
+use crate::physical_plan::expressions::{BinaryExpr, IsNullExpr, Literal};
+use crate::physical_plan::memory::MemoryStream;
+use crate::physical_plan::metrics::BaselineMetrics;
+use crate::physical_plan::{PhysicalExpr, SendableRecordBatchStream};
+use arrow::array::{Array, ArrayRef, UInt32Array};
+use arrow::compute::{concat, take, SortOptions};
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use arrow::row::{RowConverter, SortField};
+use datafusion_common::ScalarValue;
+use datafusion_expr::Operator;
+use datafusion_physical_expr::PhysicalSortExpr;
+use parking_lot::Mutex;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::Arc;
+
+/// The value of the first sort key that a row must reach to enter a
+/// [`TopK`] heap, shared between the partitions of a sort and the scans
+/// feeding it.
+///
+/// Once a heap is full, any row whose first sort key sorts after the value
+/// of the worst row in the heap can never be part of the result, so scans
+/// can use [`TopKThreshold::predicate`] to skip data while the query runs.
+#[derive(Debug)]
+pub struct TopKThreshold {
+    /// Sort options of the first sort key
+    options: SortOptions,
+    /// The tightest threshold published so far, if any
+    value: Mutex<Option<ScalarValue>>,
+}
+
+impl TopKThreshold {
+    /// Create a threshold for a first sort key sorted with `options`
+    pub fn new(options: SortOptions) -> Self {
+        Self {
+            options,
+            value: Mutex::new(None),
+        }
+    }
+
+    /// Sort options of the first sort key
+    pub fn options(&self) -> SortOptions {
+        self.options
+    }
+
+    /// The current threshold, `None` until a heap has been filled
+    pub fn value(&self) -> Option<ScalarValue> {
+        self.value.lock().clone()
+    }
+
+    /// Forget the current threshold, so that a new execution of the plan
+    /// does not prune with a value published by a previous one
+    pub fn reset(&self) {
+        *self.value.lock() = None;
+    }
+
+    /// Publish `value` as the threshold if it is tighter than the current one
+    pub fn update(&self, value: ScalarValue) {
+        let mut current = self.value.lock();
+        let tighter = match current.as_ref() {
+            Some(current) => {
+                compare_values(&value, current, &self.options) == Ordering::Less
+            }
+            None => true,
+        };
+        if tighter {
+            *current = Some(value);
+        }
+    }
+
+    /// Returns a predicate on `column`, the first sort key, that holds for
+    /// every row that may still enter the heap, or `None` if there is no
+    /// threshold yet
+    pub fn predicate(
+        &self,
+        column: Arc<dyn PhysicalExpr>,
+    ) -> Option<Arc<dyn PhysicalExpr>> {
+        let value = self.value()?;
+        let is_null: Arc<dyn PhysicalExpr> = Arc::new(IsNullExpr::new(column.clone()));
+        if value.is_null() {
+            // a full heap of nulls sorted first can only be improved by nulls
+            return self.options.nulls_first.then_some(is_null);
+        }
+
+        let op = if self.options.descending {
+            Operator::GtEq
+        } else {
+            Operator::LtEq
+        };
+        let comparison: Arc<dyn PhysicalExpr> =
+            Arc::new(BinaryExpr::new(column, op, Arc::new(Literal::new(value))));
+        if self.options.nulls_first {
+            Some(Arc::new(BinaryExpr::new(comparison, Operator::Or, is_null)))
+        } else {
+            Some(comparison)
+        }
+    }
+}
+
+/// Compares two values of a sort key in the order given by `options`
+fn compare_values(a: &ScalarValue, b: &ScalarValue, options: &SortOptions) -> Ordering {
+    match (a.is_null(), b.is_null()) {
+        (true, true) => Ordering::Equal,
+        (true, false) if options.nulls_first => Ordering::Less,
+        (true, false) => Ordering::Greater,
+        (false, true) if options.nulls_first => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => {
+            let ordering = a.partial_cmp(b).unwrap_or(Ordering::Equal);
+            if options.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        }
+    }
+}
+
+/// A row kept in the heap: its sort key in the row format and its position
+/// in the stored batches
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct TopKRow {
+    /// Sort key encoded with the row format, compares in the sort order
+    row: Vec<u8>,
+    /// Id of the stored batch holding the row, ties are broken by arrival
+    /// so that earlier rows are preferred
+    batch_id: usize,
+    /// Index of the row in its batch
+    index: usize,
+}
+
+/// An input batch that still has rows in the heap
+struct StoredBatch {
+    batch: RecordBatch,
+    /// The evaluated first sort key of `batch`
+    first_key: ArrayRef,
+    /// Number of rows of this batch in the heap
+    uses: usize,
+}
+
+/// Keeps the first `k` rows of its input in the order given by `expr`
+///
+/// Rows are compared with the arrow row format and kept in a max-heap whose
+/// top is the worst row retained so far, so each incoming row only needs to
+/// be compared with the top. The heap references rows of the input batches,
+/// which are compacted once they hold many more rows than the heap needs.
+pub(crate) struct TopK {
+    schema: SchemaRef,
+    expr: Vec<PhysicalSortExpr>,
+    k: usize,
+    batch_size: usize,
+    row_converter: RowConverter,
+    heap: BinaryHeap<TopKRow>,
+    store: HashMap<usize, StoredBatch>,
+    next_batch_id: usize,
+    threshold: Option<Arc<TopKThreshold>>,
+    metrics: BaselineMetrics,
+    reservation: MemoryReservation,
+}
+
+impl TopK {
+    /// Create a new [`TopK`] keeping `k` rows for `partition`, publishing
+    /// its progress to `threshold` if given
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn try_new(
+        partition: usize,
+        schema: SchemaRef,
+        expr: Vec<PhysicalSortExpr>,
+        k: usize,
+        batch_size: usize,
+        pool: &Arc<dyn MemoryPool>,
+        metrics: BaselineMetrics,
+        threshold: Option<Arc<TopKThreshold>>,
+    ) -> Result<Self> {
+        let sort_fields = expr
+            .iter()
+            .map(|e| {
+                Ok(SortField::new_with_options(
+                    e.expr.data_type(&schema)?,
+                    e.options,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let row_converter = RowConverter::new(sort_fields)?;
+        let reservation =
+            MemoryConsumer::new(format!("TopK[{partition}]")).register(pool);
+
+        Ok(Self {
+            schema,
+            expr,
+            k,
+            batch_size,
+            row_converter,
+            heap: BinaryHeap::new(),
+            store: HashMap::new(),
+            next_batch_id: 0,
+            threshold,
+            metrics,
+            reservation,
+        })
+    }
+
+    /// Insert the rows of `batch` that belong to the first `k` rows seen so far
+    pub(crate) fn insert_batch(&mut self, batch: RecordBatch) -> Result<()> {
+        let elapsed_compute = self.metrics.elapsed_compute().clone();
+        let _timer = elapsed_compute.timer();
+        if self.k == 0 || batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let sort_keys = self
+            .expr
+            .iter()
+            .map(|e| Ok(e.expr.evaluate(&batch)?.into_array(batch.num_rows())))
+            .collect::<Result<Vec<_>>>()?;
+        let rows = self.row_converter.convert_columns(&sort_keys)?;
+
+        let batch_id = self.next_batch_id;
+        self.next_batch_id += 1;
+        let mut uses = 0;
+        for (index, row) in rows.iter().enumerate() {
+            let row = row.as_ref();
+            if self.heap.len() >= self.k {
+                // the heap is full: the row must beat the current worst row
+                match self.heap.peek() {
+                    Some(worst) if row < worst.row.as_slice() => {}
+                    _ => continue,
+                }
+                let evicted = self.heap.pop().unwrap();
+                if evicted.batch_id == batch_id {
+                    uses -= 1;
+                } else {
+                    self.release(evicted.batch_id);
+                }
+            }
+            self.heap.push(TopKRow {
+                row: row.to_vec(),
+                batch_id,
+                index,
+            });
+            uses += 1;
+        }
+
+        if uses > 0 {
+            self.store.insert(
+                batch_id,
+                StoredBatch {
+                    batch,
+                    first_key: sort_keys[0].clone(),
+                    uses,
+                },
+            );
+        }
+
+        if self.stored_rows() > 2 * self.k {
+            self.compact()?;
+        }
+        self.publish_threshold()?;
+        self.update_reservation()
+    }
+
+    /// Returns the retained rows in sort order
+    pub(crate) fn emit(mut self) -> Result<SendableRecordBatchStream> {
+        let rows = std::mem::take(&mut self.heap).into_sorted_vec();
+        let (batch, _) = self.take_rows(&rows)?;
+        self.store.clear();
+        self.reservation.free();
+
+        let batches = if batch.num_rows() == 0 {
+            vec![]
+        } else {
+            let batch_size = self.batch_size.max(1);
+            (0..batch.num_rows())
+                .step_by(batch_size)
+                .map(|offset| {
+                    batch.slice(offset, batch_size.min(batch.num_rows() - offset))
+                })
+                .collect()
+        };
+
+        self.metrics.record_output(batch.num_rows());
+        self.metrics.done();
+        Ok(Box::pin(MemoryStream::try_new(
+            batches,
+            self.schema.clone(),
+            None,
+        )?))
+    }
+
+    /// Drops one use of the stored batch `batch_id`
+    fn release(&mut self, batch_id: usize) {
+        if let Some(stored) = self.store.get_mut(&batch_id) {
+            stored.uses -= 1;
+            if stored.uses == 0 {
+                self.store.remove(&batch_id);
+            }
+        }
+    }
+
+    fn stored_rows(&self) -> usize {
+        self.store.values().map(|s| s.batch.num_rows()).sum()
+    }
+
+    /// Copies the rows referenced by the heap into a single batch so that
+    /// the input batches can be released
+    fn compact(&mut self) -> Result<()> {
+        let rows = std::mem::take(&mut self.heap).into_vec();
+        let (batch, first_key) = self.take_rows(&rows)?;
+        self.store.clear();
+
+        let batch_id = self.next_batch_id;
+        self.next_batch_id += 1;
+        self.heap = rows
+            .into_iter()
+            .enumerate()
+            .map(|(index, row)| TopKRow {
+                row: row.row,
+                batch_id,
+                index,
+            })
+            .collect();
+        self.store.insert(
+            batch_id,
+            StoredBatch {
+                uses: batch.num_rows(),
+                batch,
+                first_key,
+            },
+        );
+        Ok(())
+    }
+
+    /// Gathers `rows`, in order, from the stored batches, returning the
+    /// batch and its first sort key
+    fn take_rows(&self, rows: &[TopKRow]) -> Result<(RecordBatch, ArrayRef)> {
+        let mut batch_ids: Vec<usize> = self.store.keys().copied().collect();
+        batch_ids.sort_unstable();
+
+        let mut offsets = HashMap::with_capacity(batch_ids.len());
+        let mut offset = 0;
+        for batch_id in &batch_ids {
+            offsets.insert(*batch_id, offset);
+            offset += self.store[batch_id].batch.num_rows();
+        }
+        let indices = UInt32Array::from_iter_values(
+            rows.iter()
+                .map(|row| (offsets[&row.batch_id] + row.index) as u32),
+        );
+
+        let gather = |arrays: Vec<&dyn Array>| -> Result<ArrayRef> {
+            let array = concat(&arrays)?;
+            Ok(take(array.as_ref(), &indices, None)?)
+        };
+
+        if batch_ids.is_empty() {
+            let first_key = arrow::array::new_empty_array(
+                &self.expr[0].expr.data_type(&self.schema)?,
+            );
+            return Ok((RecordBatch::new_empty(self.schema.clone()), first_key));
+        }
+
+        let columns = (0..self.schema.fields().len())
+            .map(|i| {
+                gather(
+                    batch_ids
+                        .iter()
+                        .map(|id| self.store[id].batch.column(i).as_ref())
+                        .collect(),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let first_key = gather(
+            batch_ids
+                .iter()
+                .map(|id| self.store[id].first_key.as_ref())
+                .collect(),
+        )?;
+
+        Ok((
+            RecordBatch::try_new(self.schema.clone(), columns)?,
+            first_key,
+        ))
+    }
+
+    /// Publishes the first sort key of the worst retained row once the heap
+    /// is full
+    fn publish_threshold(&self) -> Result<()> {
+        let threshold = match &self.threshold {
+            Some(threshold) if self.heap.len() >= self.k => threshold,
+            _ => return Ok(()),
+        };
+        if let Some(worst) = self.heap.peek() {
+            let stored = &self.store[&worst.batch_id];
+            threshold
+                .update(ScalarValue::try_from_array(&stored.first_key, worst.index)?);
+        }
+        Ok(())
+    }
+
+    /// Resizes the memory reservation to the size of the retained rows
+    fn update_reservation(&mut self) -> Result<()> {
+        let size = self
+            .store
+            .values()
+            .map(|s| batch_byte_size(&s.batch))
+            .sum::<usize>()
+            + self
+                .heap
+                .iter()
+                .map(|r| r.row.len() + std::mem::size_of::<TopKRow>())
+                .sum::<usize>();
+        match size.cmp(&self.reservation.size()) {
+            Ordering::Greater => {
+                self.reservation.try_grow(size - self.reservation.size())
+            }
+            Ordering::Less => {
+                self.reservation.shrink(self.reservation.size() - size);
+                Ok(())
+            }
+            Ordering::Equal => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::memory_pool::GreedyMemoryPool;
+    use crate::physical_plan::common::collect;
+    use crate::physical_plan::expressions::col;
+    use crate::physical_plan::metrics::ExecutionPlanMetricsSet;
+    use arrow::array::{Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]))
+    }
+
+    fn batch(a: Vec<Option<i32>>, b: Vec<&str>) -> RecordBatch {
+        RecordBatch::try_new(
+            schema(),
+            vec![
+                Arc::new(Int32Array::from(a)),
+                Arc::new(StringArray::from(b)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn new_topk(
+        k: usize,
+        options: SortOptions,
+        threshold: Option<Arc<TopKThreshold>>,
+    ) -> TopK {
+        let schema = schema();
+        let expr = vec![PhysicalSortExpr {
+            expr: col("a", &schema).unwrap(),
+            options,
+        }];
+        let pool: Arc<dyn MemoryPool> = Arc::new(GreedyMemoryPool::new(1 << 20));
+        let metrics = BaselineMetrics::new(&ExecutionPlanMetricsSet::new(), 0);
+        TopK::try_new(0, schema, expr, k, 2, &pool, metrics, threshold).unwrap()
+    }
+
+    async fn values(topk: TopK) -> Vec<(Option<i32>, String)> {
+        let batches = collect(topk.emit().unwrap()).await.unwrap();
+        let mut values = vec![];
+        for batch in batches {
+            assert!(batch.num_rows() <= 2);
+            let a = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            let b = batch
+                .column(1)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            for i in 0..batch.num_rows() {
+                let value = (!a.is_null(i)).then(|| a.value(i));
+                values.push((value, b.value(i).to_string()));
+            }
+        }
+        values
+    }
+
+    #[tokio::test]
+    async fn topk_keeps_first_rows() {
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let threshold = Arc::new(TopKThreshold::new(options));
+        let mut topk = new_topk(3, options, Some(threshold.clone()));
+        topk.insert_batch(batch(vec![Some(5), Some(3), None], vec!["a", "b", "c"]))
+            .unwrap();
+        assert_eq!(threshold.value(), Some(ScalarValue::Int32(None)));
+        topk.insert_batch(batch(vec![Some(4), Some(1), Some(3)], vec!["d", "e", "f"]))
+            .unwrap();
+        assert_eq!(threshold.value(), Some(ScalarValue::Int32(Some(3))));
+        topk.insert_batch(batch(vec![Some(9), Some(2)], vec!["g", "h"]))
+            .unwrap();
+        assert_eq!(threshold.value(), Some(ScalarValue::Int32(Some(3))));
+        assert_eq!(topk.heap.len(), 3);
+
+        // ties keep the earlier row
+        assert_eq!(
+            values(topk).await,
+            vec![
+                (Some(1), "e".to_string()),
+                (Some(2), "h".to_string()),
+                (Some(3), "b".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn topk_descending_nulls_first() {
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let mut topk = new_topk(2, options, None);
+        topk.insert_batch(batch(vec![Some(5), Some(3)], vec!["a", "b"]))
+            .unwrap();
+        topk.insert_batch(batch(vec![Some(7), None], vec!["c", "d"]))
+            .unwrap();
+        topk.insert_batch(batch(vec![Some(6)], vec!["e"])).unwrap();
+
+        assert_eq!(
+            values(topk).await,
+            vec![(None, "d".to_string()), (Some(7), "c".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn topk_fewer_rows_than_k() {
+        let mut topk = new_topk(10, SortOptions::default(), None);
+        for i in 0..4 {
+            topk.insert_batch(batch(vec![Some(4 - i)], vec!["x"]))
+                .unwrap();
+        }
+        let values = values(topk).await;
+        assert_eq!(
+            values.iter().map(|(a, _)| a.unwrap()).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+
+        let topk = new_topk(0, SortOptions::default(), None);
+        assert!(collect(topk.emit().unwrap()).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn threshold_only_tightens() {
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let threshold = TopKThreshold::new(options);
+        threshold.update(ScalarValue::Int32(Some(10)));
+        threshold.update(ScalarValue::Int32(Some(5)));
+        assert_eq!(threshold.value(), Some(ScalarValue::Int32(Some(10))));
+        threshold.update(ScalarValue::Int32(Some(20)));
+        assert_eq!(threshold.value(), Some(ScalarValue::Int32(Some(20))));
+        threshold.update(ScalarValue::Int32(None));
+        assert_eq!(threshold.value(), Some(ScalarValue::Int32(None)));
+    }
+
+    #[test]
+    fn threshold_predicate() {
+        let schema = schema();
+        let a = col("a", &schema).unwrap();
+
+        let threshold = TopKThreshold::new(SortOptions::default());
+        assert!(threshold.predicate(a.clone()).is_none());
+        threshold.update(ScalarValue::Int32(Some(10)));
+        assert_eq!(
+            threshold.predicate(a.clone()).unwrap().to_string(),
+            "a@0 <= 10"
+        );
+
+        let threshold = TopKThreshold::new(SortOptions {
+            descending: true,
+            nulls_first: true,
+        });
+        threshold.update(ScalarValue::Int32(Some(10)));
+        assert_eq!(
+            threshold.predicate(a.clone()).unwrap().to_string(),
+            "a@0 >= 10 OR a@0 IS NULL"
+        );
+        threshold.update(ScalarValue::Int32(None));
+        assert_eq!(
+            threshold.predicate(a.clone()).unwrap().to_string(),
+            "a@0 IS NULL"
+        );
+
+        let threshold = TopKThreshold::new(SortOptions {
+            descending: false,
+            nulls_first: false,
+        });
+        threshold.update(ScalarValue::Int32(None));
+        assert!(threshold.predicate(a).is_none());
+    }
+}
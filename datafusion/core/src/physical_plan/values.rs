@@ -88,8 +88,28 @@ impl ValuesExec {
         Ok(Self { schema, data })
     }
 
+    /// Create a new values exec from the record batches of the values
+    pub fn try_new_from_batches(
+        schema: SchemaRef,
+        batches: Vec<RecordBatch>,
+    ) -> Result<Self> {
+        if batches.is_empty() {
+            return Err(DataFusionError::Plan("Values list cannot be empty".into()));
+        }
+        if let Some(batch) = batches.iter().find(|b| b.schema() != schema) {
+            return Err(DataFusionError::Plan(format!(
+                "Values batch schema {:?} does not match the schema {schema:?}",
+                batch.schema()
+            )));
+        }
+        Ok(Self {
+            schema,
+            data: batches,
+        })
+    }
+
     /// provides the data
-    fn data(&self) -> Vec<RecordBatch> {
+    pub fn data(&self) -> Vec<RecordBatch> {
         self.data.clone()
     }
 }
@@ -167,6 +187,7 @@ impl ExecutionPlan for ValuesExec {
 mod tests {
     use super::*;
     use crate::test_util;
+    use arrow::datatypes::Schema;
 
     #[tokio::test]
     async fn values_empty_case() -> Result<()> {
@@ -175,4 +196,22 @@ mod tests {
         assert!(empty.is_err());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn values_from_batches() -> Result<()> {
+        let schema = test_util::aggr_test_schema();
+        let empty = ValuesExec::try_new_from_batches(schema.clone(), vec![]);
+        assert!(empty.is_err());
+
+        let batch = RecordBatch::new_empty(schema.clone());
+        let values = ValuesExec::try_new_from_batches(schema, vec![batch.clone()])?;
+        assert_eq!(values.data(), vec![batch]);
+
+        let mismatch = ValuesExec::try_new_from_batches(
+            Arc::new(Schema::empty()),
+            vec![RecordBatch::new_empty(test_util::aggr_test_schema())],
+        );
+        assert!(mismatch.is_err());
+        Ok(())
+    }
 }
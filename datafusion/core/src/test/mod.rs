@@ -25,7 +25,7 @@ use crate::datasource::{MemTable, TableProvider};
 use crate::error::Result;
 use crate::from_slice::FromSlice;
 use crate::logical_expr::LogicalPlan;
-use crate::physical_plan::file_format::{CsvExec, FileScanConfig};
+use crate::physical_plan::file_format::{CsvExec, FileScanConfig, SchemaEvolution};
 use crate::physical_plan::memory::MemoryExec;
 use crate::physical_plan::ExecutionPlan;
 use crate::test::object_store::local_unpartitioned_file;
@@ -192,10 +192,12 @@ pub fn partitioned_csv_config(
         file_groups,
         statistics: Default::default(),
         projection: None,
+        nested_projection: None,
         limit: None,
         table_partition_cols: vec![],
         output_ordering: None,
         infinite_source: false,
+        schema_evolution: SchemaEvolution::Strict,
     })
 }
 
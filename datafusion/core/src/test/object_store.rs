@@ -16,9 +16,19 @@
 // under the License.
 //! Object store implementation used for testing
 use crate::prelude::SessionContext;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::BoxStream;
 use futures::FutureExt;
-use object_store::{memory::InMemory, path::Path, ObjectMeta, ObjectStore};
+use object_store::{
+    memory::InMemory, path::Path, GetResult, ListResult, MultipartId, ObjectMeta,
+    ObjectStore,
+};
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use tokio::io::AsyncWrite;
 
 /// Returns a test object store with the provided `ctx`
 pub fn register_test_store(ctx: &SessionContext, files: &[(&str, u64)]) {
@@ -51,3 +61,106 @@ pub fn local_unpartitioned_file(path: impl AsRef<std::path::Path>) -> ObjectMeta
         size: metadata.len() as usize,
     }
 }
+
+/// An [`ObjectStore`] that counts the range requests made to an inner
+/// store, and does not implement any other operation
+#[derive(Debug)]
+pub struct RequestCountingObjectStore {
+    inner: Arc<dyn ObjectStore>,
+    request_count: AtomicUsize,
+}
+
+impl Display for RequestCountingObjectStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RequestCounting({})", self.inner)
+    }
+}
+
+impl RequestCountingObjectStore {
+    /// Creates a store counting the range requests made to `inner`
+    pub fn new(inner: Arc<dyn ObjectStore>) -> Self {
+        Self {
+            inner,
+            request_count: Default::default(),
+        }
+    }
+
+    /// Returns the number of range requests made so far
+    pub fn request_count(&self) -> usize {
+        self.request_count.load(Ordering::SeqCst)
+    }
+
+    /// Returns this store as a `dyn ObjectStore`
+    pub fn upcast(self: &Arc<Self>) -> Arc<dyn ObjectStore> {
+        self.clone()
+    }
+}
+
+#[async_trait]
+impl ObjectStore for RequestCountingObjectStore {
+    async fn put(&self, _location: &Path, _bytes: Bytes) -> object_store::Result<()> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn put_multipart(
+        &self,
+        _location: &Path,
+    ) -> object_store::Result<(MultipartId, Box<dyn AsyncWrite + Unpin + Send>)> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn abort_multipart(
+        &self,
+        _location: &Path,
+        _multipart_id: &MultipartId,
+    ) -> object_store::Result<()> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn get(&self, _location: &Path) -> object_store::Result<GetResult> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn get_range(
+        &self,
+        location: &Path,
+        range: Range<usize>,
+    ) -> object_store::Result<Bytes> {
+        self.request_count.fetch_add(1, Ordering::SeqCst);
+        self.inner.get_range(location, range).await
+    }
+
+    async fn head(&self, _location: &Path) -> object_store::Result<ObjectMeta> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn delete(&self, _location: &Path) -> object_store::Result<()> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn list(
+        &self,
+        _prefix: Option<&Path>,
+    ) -> object_store::Result<BoxStream<'_, object_store::Result<ObjectMeta>>> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn list_with_delimiter(
+        &self,
+        _prefix: Option<&Path>,
+    ) -> object_store::Result<ListResult> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn copy(&self, _from: &Path, _to: &Path) -> object_store::Result<()> {
+        Err(object_store::Error::NotImplemented)
+    }
+
+    async fn copy_if_not_exists(
+        &self,
+        _from: &Path,
+        _to: &Path,
+    ) -> object_store::Result<()> {
+        Err(object_store::Error::NotImplemented)
+    }
+}
@@ -25,6 +25,7 @@ use datafusion::datasource::listing::PartitionedFile;
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::physical_plan::file_format::{
     FileMeta, FileScanConfig, ParquetExec, ParquetFileMetrics, ParquetFileReaderFactory,
+    SchemaEvolution,
 };
 use datafusion::physical_plan::metrics::ExecutionPlanMetricsSet;
 use datafusion::physical_plan::{collect, Statistics};
@@ -80,10 +81,12 @@ async fn route_data_access_ops_to_parquet_file_reader_factory() {
             file_schema,
             statistics: Statistics::default(),
             projection: None,
+            nested_projection: None,
             limit: None,
             table_partition_cols: vec![],
             output_ordering: None,
             infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
         },
         None,
         None,
@@ -22,7 +22,9 @@ use datafusion::datasource::file_format::FileFormat;
 use datafusion::datasource::listing::PartitionedFile;
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::execution::context::SessionState;
-use datafusion::physical_plan::file_format::{FileScanConfig, ParquetExec};
+use datafusion::physical_plan::file_format::{
+    FileScanConfig, ParquetExec, SchemaEvolution,
+};
 use datafusion::physical_plan::ExecutionPlan;
 use datafusion::prelude::SessionContext;
 use datafusion_common::{ScalarValue, Statistics, ToDFSchema};
@@ -73,10 +75,12 @@ async fn get_parquet_exec(state: &SessionState, filter: Expr) -> ParquetExec {
             statistics: Statistics::default(),
             // file has 10 cols so index 12 should be month
             projection: None,
+            nested_projection: None,
             limit: None,
             table_partition_cols: vec![],
             output_ordering: None,
             infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
         },
         Some(predicate),
         None,
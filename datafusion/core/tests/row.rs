@@ -20,7 +20,7 @@ use datafusion::datasource::file_format::FileFormat;
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::error::Result;
 use datafusion::execution::context::SessionState;
-use datafusion::physical_plan::file_format::FileScanConfig;
+use datafusion::physical_plan::file_format::{FileScanConfig, SchemaEvolution};
 use datafusion::physical_plan::{collect, ExecutionPlan};
 use datafusion::prelude::SessionContext;
 use datafusion_row::layout::RowType::{Compact, WordAligned};
@@ -110,10 +110,12 @@ async fn get_exec(
                 file_groups,
                 statistics,
                 projection: projection.cloned(),
+                nested_projection: None,
                 limit,
                 table_partition_cols: vec![],
                 output_ordering: None,
                 infinite_source: false,
+                schema_evolution: SchemaEvolution::Strict,
             },
             None,
         )
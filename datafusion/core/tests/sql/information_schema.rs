@@ -60,25 +60,40 @@ async fn information_schema_tables_tables_with_multiple_catalogs() {
         .unwrap();
 
     let expected = vec![
-        "+------------------+--------------------+-------------+------------+",
-        "| table_catalog    | table_schema       | table_name  | table_type |",
-        "+------------------+--------------------+-------------+------------+",
-        "| datafusion       | information_schema | columns     | VIEW       |",
-        "| datafusion       | information_schema | df_settings | VIEW       |",
-        "| datafusion       | information_schema | tables      | VIEW       |",
-        "| datafusion       | information_schema | views       | VIEW       |",
-        "| my_catalog       | information_schema | columns     | VIEW       |",
-        "| my_catalog       | information_schema | df_settings | VIEW       |",
-        "| my_catalog       | information_schema | tables      | VIEW       |",
-        "| my_catalog       | information_schema | views       | VIEW       |",
-        "| my_catalog       | my_schema          | t1          | BASE TABLE |",
-        "| my_catalog       | my_schema          | t2          | BASE TABLE |",
-        "| my_other_catalog | information_schema | columns     | VIEW       |",
-        "| my_other_catalog | information_schema | df_settings | VIEW       |",
-        "| my_other_catalog | information_schema | tables      | VIEW       |",
-        "| my_other_catalog | information_schema | views       | VIEW       |",
-        "| my_other_catalog | my_other_schema    | t3          | BASE TABLE |",
-        "+------------------+--------------------+-------------+------------+",
+        "+------------------+--------------------+------------------+------------+",
+        "| table_catalog    | table_schema       | table_name       | table_type |",
+        "+------------------+--------------------+------------------+------------+",
+        "| datafusion       | information_schema | columns          | VIEW       |",
+        "| datafusion       | information_schema | df_settings      | VIEW       |",
+        "| datafusion       | information_schema | key_column_usage | VIEW       |",
+        "| datafusion       | information_schema | parameters       | VIEW       |",
+        "| datafusion       | information_schema | routines         | VIEW       |",
+        "| datafusion       | information_schema | schemata         | VIEW       |",
+        "| datafusion       | information_schema | table_statistics | VIEW       |",
+        "| datafusion       | information_schema | tables           | VIEW       |",
+        "| datafusion       | information_schema | views            | VIEW       |",
+        "| my_catalog       | information_schema | columns          | VIEW       |",
+        "| my_catalog       | information_schema | df_settings      | VIEW       |",
+        "| my_catalog       | information_schema | key_column_usage | VIEW       |",
+        "| my_catalog       | information_schema | parameters       | VIEW       |",
+        "| my_catalog       | information_schema | routines         | VIEW       |",
+        "| my_catalog       | information_schema | schemata         | VIEW       |",
+        "| my_catalog       | information_schema | table_statistics | VIEW       |",
+        "| my_catalog       | information_schema | tables           | VIEW       |",
+        "| my_catalog       | information_schema | views            | VIEW       |",
+        "| my_catalog       | my_schema          | t1               | BASE TABLE |",
+        "| my_catalog       | my_schema          | t2               | BASE TABLE |",
+        "| my_other_catalog | information_schema | columns          | VIEW       |",
+        "| my_other_catalog | information_schema | df_settings      | VIEW       |",
+        "| my_other_catalog | information_schema | key_column_usage | VIEW       |",
+        "| my_other_catalog | information_schema | parameters       | VIEW       |",
+        "| my_other_catalog | information_schema | routines         | VIEW       |",
+        "| my_other_catalog | information_schema | schemata         | VIEW       |",
+        "| my_other_catalog | information_schema | table_statistics | VIEW       |",
+        "| my_other_catalog | information_schema | tables           | VIEW       |",
+        "| my_other_catalog | information_schema | views            | VIEW       |",
+        "| my_other_catalog | my_other_schema    | t3               | BASE TABLE |",
+        "+------------------+--------------------+------------------+------------+",
     ];
     assert_batches_sorted_eq!(expected, &result);
 }
@@ -127,17 +142,22 @@ async fn information_schema_tables_table_types() {
         .unwrap();
 
     let expected = vec![
-        "+---------------+--------------------+-------------+-----------------+",
-        "| table_catalog | table_schema       | table_name  | table_type      |",
-        "+---------------+--------------------+-------------+-----------------+",
-        "| datafusion    | information_schema | columns     | VIEW            |",
-        "| datafusion    | information_schema | df_settings | VIEW            |",
-        "| datafusion    | information_schema | tables      | VIEW            |",
-        "| datafusion    | information_schema | views       | VIEW            |",
-        "| datafusion    | public             | physical    | BASE TABLE      |",
-        "| datafusion    | public             | query       | VIEW            |",
-        "| datafusion    | public             | temp        | LOCAL TEMPORARY |",
-        "+---------------+--------------------+-------------+-----------------+",
+        "+---------------+--------------------+------------------+-----------------+",
+        "| table_catalog | table_schema       | table_name       | table_type      |",
+        "+---------------+--------------------+------------------+-----------------+",
+        "| datafusion    | information_schema | columns          | VIEW            |",
+        "| datafusion    | information_schema | df_settings      | VIEW            |",
+        "| datafusion    | information_schema | key_column_usage | VIEW            |",
+        "| datafusion    | information_schema | parameters       | VIEW            |",
+        "| datafusion    | information_schema | routines         | VIEW            |",
+        "| datafusion    | information_schema | schemata         | VIEW            |",
+        "| datafusion    | information_schema | table_statistics | VIEW            |",
+        "| datafusion    | information_schema | tables           | VIEW            |",
+        "| datafusion    | information_schema | views            | VIEW            |",
+        "| datafusion    | public             | physical         | BASE TABLE      |",
+        "| datafusion    | public             | query            | VIEW            |",
+        "| datafusion    | public             | temp             | LOCAL TEMPORARY |",
+        "+---------------+--------------------+------------------+-----------------+",
     ];
     assert_batches_sorted_eq!(expected, &result);
 }
@@ -97,3 +97,46 @@ async fn json_explain() {
     ];
     assert_eq!(expected, actual);
 }
+
+#[tokio::test]
+async fn json_array_nested_query() -> Result<()> {
+    let ctx = SessionContext::new();
+    let path = format!("{TEST_DATA_BASE}/nested_array.json");
+    ctx.register_json("t1", &path, NdJsonReadOptions::default())
+        .await?;
+    ctx.register_json(
+        "t2",
+        &path,
+        NdJsonReadOptions::default().schema_infer_max_depth(2),
+    )
+    .await?;
+
+    // the amounts have mixed types and are read as their JSON text
+    let sql = "SELECT id, person['name'] AS name, person['tags'] AS tags, amount FROM t1";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let expected = vec![
+        "+----+-------+--------+--------+",
+        "| id | name  | tags   | amount |",
+        "+----+-------+--------+--------+",
+        "| 1  | alice | [x, y] | 1      |",
+        "| 2  | bob   | []     | n/a    |",
+        "| 3  |       |        | 2.5    |",
+        "+----+-------+--------+--------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+
+    // the objects nested deeper than the inference depth are read as their JSON text
+    let sql = "SELECT id, person['address'] AS address FROM t2";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let expected = vec![
+        "+----+------------------+",
+        "| id | address          |",
+        "+----+------------------+",
+        "| 1  | {\"city\":\"Paris\"} |",
+        "| 2  |                  |",
+        "| 3  |                  |",
+        "+----+------------------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+    Ok(())
+}
@@ -427,7 +427,7 @@ async fn set_time_zone_bad_time_zone_format() {
             .unwrap();
 
     let err = pretty_format_batches(&result).err().unwrap().to_string();
-    assert_eq!(err, "Parser error: Invalid timezone \"08:00\": only offset based timezones supported without chrono-tz feature");
+    assert_contains!(err, "Invalid timezone \"08:00\"");
 
     plan_and_collect(&ctx, "SET TIME ZONE = '08'")
         .await
@@ -440,9 +440,9 @@ async fn set_time_zone_bad_time_zone_format() {
             .unwrap();
 
     let err = pretty_format_batches(&result).err().unwrap().to_string();
-    assert_eq!(err, "Parser error: Invalid timezone \"08\": only offset based timezones supported without chrono-tz feature");
+    assert_contains!(err, "Invalid timezone \"08\"");
 
-    // we dont support named time zone yet
+    // named time zones are resolved using the chrono-tz database
     plan_and_collect(&ctx, "SET TIME ZONE = 'Asia/Taipei'")
         .await
         .unwrap();
@@ -452,11 +452,9 @@ async fn set_time_zone_bad_time_zone_format() {
         plan_and_collect(&ctx, "SELECT '2000-01-01T00:00:00'::TIMESTAMP::TIMESTAMPTZ")
             .await
             .unwrap();
+    assert!(pretty_format_batches(&result).is_ok());
 
-    let err = pretty_format_batches(&result).err().unwrap().to_string();
-    assert_eq!(err, "Parser error: Invalid timezone \"Asia/Taipei\": only offset based timezones supported without chrono-tz feature");
-
-    // this is invalid even after we support named time zone
+    // this is not a valid named time zone
     plan_and_collect(&ctx, "SET TIME ZONE = 'Asia/Taipei2'")
         .await
         .unwrap();
@@ -467,5 +465,5 @@ async fn set_time_zone_bad_time_zone_format() {
             .await
             .unwrap();
     let err = pretty_format_batches(&result).err().unwrap().to_string();
-    assert_eq!(err, "Parser error: Invalid timezone \"Asia/Taipei2\": only offset based timezones supported without chrono-tz feature");
+    assert_contains!(err, "Invalid timezone \"Asia/Taipei2\"");
 }
@@ -81,6 +81,10 @@ pub fn i128_to_str(value: i128, scale: u32) -> String {
     )
 }
 
+pub fn decimal256_str_to_str(value: &str) -> String {
+    big_decimal_to_str(BigDecimal::from_str(value).unwrap())
+}
+
 pub fn decimal_to_str(value: Decimal) -> String {
     big_decimal_to_str(BigDecimal::from_str(&value.to_string()).unwrap())
 }
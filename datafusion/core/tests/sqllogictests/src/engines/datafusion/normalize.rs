@@ -210,6 +210,10 @@ pub fn cell_to_string(col: &ArrayRef, row: usize) -> Result<String> {
                 let decimal_scale = u32::try_from((*scale).max(0)).unwrap();
                 Ok(i128_to_str(value, decimal_scale))
             }
+            DataType::Decimal256(_, _) => {
+                arrow::util::display::array_value_to_string(col, row)
+                    .map(|value| decimal256_str_to_str(&value))
+            }
             DataType::LargeUtf8 => Ok(varchar_to_str(get_row_value!(
                 array::LargeStringArray,
                 col,
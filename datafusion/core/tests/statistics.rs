@@ -29,11 +29,12 @@ use datafusion::{
         DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream,
         Statistics,
     },
-    prelude::SessionContext,
+    prelude::{SessionConfig, SessionContext},
     scalar::ScalarValue,
 };
 
 use async_trait::async_trait;
+use datafusion::assert_batches_eq;
 use datafusion::execution::context::{SessionState, TaskContext};
 
 /// This is a testing structure for statistics
@@ -72,6 +73,10 @@ impl TableProvider for StatisticsValidation {
         TableType::Base
     }
 
+    fn statistics(&self) -> Option<Statistics> {
+        Some(self.stats.clone())
+    }
+
     async fn scan(
         &self,
         _state: &SessionState,
@@ -289,3 +294,97 @@ async fn sql_window() -> Result<()> {
 
     Ok(())
 }
+
+/// Statistics of a table with `num_rows` rows and the given number of
+/// distinct values in its columns
+fn with_distinct_counts(
+    num_rows: usize,
+    distinct_counts: [Option<usize>; 2],
+) -> Statistics {
+    Statistics {
+        num_rows: Some(num_rows),
+        is_exact: true,
+        total_byte_size: None,
+        column_statistics: Some(
+            distinct_counts
+                .iter()
+                .map(|distinct_count| ColumnStatistics {
+                    distinct_count: *distinct_count,
+                    ..Default::default()
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Returns the names of the scanned tables of the optimized plan of `sql`, in
+/// the order they are displayed
+async fn scanned_tables(config: SessionConfig, sql: &str) -> Result<Vec<String>> {
+    let ctx = SessionContext::with_config(config);
+    let (_, schema) = fully_defined();
+    let schema = Arc::new(schema);
+    for (name, stats) in [
+        ("t1", with_distinct_counts(1000, [Some(1000), Some(100)])),
+        ("t2", with_distinct_counts(100, [Some(100), None])),
+        ("t3", with_distinct_counts(10, [Some(10), Some(10)])),
+    ] {
+        let provider: Arc<dyn TableProvider> =
+            Arc::new(StatisticsValidation::new(stats, schema.clone()));
+        ctx.register_table(name, provider)?;
+    }
+
+    let plan = ctx.sql(sql).await?.into_optimized_plan()?;
+    Ok(plan
+        .display_indent()
+        .to_string()
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("TableScan: "))
+        .map(|scan| scan.split(' ').next().unwrap().to_string())
+        .collect())
+}
+
+#[tokio::test]
+async fn sql_join_reordering() -> Result<()> {
+    let sql = "SELECT t1.c1 FROM t1 \
+        JOIN t2 ON t1.c1 = t2.c1 \
+        JOIN t3 ON t1.c2 = t3.c2 \
+        WHERE t3.c1 = 5";
+
+    // the filter on t3 makes its join with t1 the most selective
+    let tables = scanned_tables(SessionConfig::new(), sql).await?;
+    assert_eq!(tables, vec!["t3", "t1", "t2"]);
+
+    let config = SessionConfig::new()
+        .set_bool("datafusion.optimizer.enable_join_reordering", false);
+    let tables = scanned_tables(config, sql).await?;
+    assert_eq!(tables, vec!["t1", "t2", "t3"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sql_information_schema() -> Result<()> {
+    let (stats, schema) = fully_defined();
+    let ctx =
+        SessionContext::with_config(SessionConfig::new().with_information_schema(true));
+    ctx.register_table(
+        "stats_table",
+        Arc::new(StatisticsValidation::new(stats, Arc::new(schema))),
+    )?;
+
+    let batches = ctx
+        .sql("SELECT * FROM information_schema.table_statistics")
+        .await?
+        .collect()
+        .await?;
+    let expected = vec![
+        "+---------------+--------------+-------------+----------+-----------------+----------+",
+        "| table_catalog | table_schema | table_name  | num_rows | total_byte_size | is_exact |",
+        "+---------------+--------------+-------------+----------+-----------------+----------+",
+        "| datafusion    | public       | stats_table | 13       |                 | YES      |",
+        "+---------------+--------------+-------------+----------+-----------------+----------+",
+    ];
+    assert_batches_eq!(expected, &batches);
+
+    Ok(())
+}
@@ -212,12 +212,37 @@ impl ObjectStoreRegistry {
             },
         }
     }
+
+    /// Returns the url `store` is registered for, or `None` if `store`
+    /// is not registered on this registry
+    pub fn get_url(&self, store: &Arc<dyn ObjectStore>) -> Option<ObjectStoreUrl> {
+        let store = Arc::as_ptr(store) as *const ();
+        self.object_stores
+            .iter()
+            .find(|entry| Arc::as_ptr(entry.value()) as *const () == store)
+            .and_then(|entry| ObjectStoreUrl::parse(entry.key()).ok())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_get_url() {
+        let registry = ObjectStoreRegistry::new();
+        let store: Arc<dyn ObjectStore> = Arc::new(LocalFileSystem::new());
+        assert_eq!(registry.get_url(&store), None);
+
+        registry.register_store("s3", "bucket", store.clone());
+        let url = registry.get_url(&store).unwrap();
+        assert_eq!(url.as_str(), "s3://bucket/");
+
+        let local_url = ObjectStoreUrl::local_filesystem();
+        let local = registry.get_by_url(&local_url).unwrap();
+        assert_eq!(registry.get_url(&local), Some(local_url));
+    }
+
     #[test]
     fn test_object_store_url() {
         let file = ObjectStoreUrl::parse("file://").unwrap();
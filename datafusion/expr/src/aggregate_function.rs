@@ -72,41 +72,59 @@ impl fmt::Display for AggregateFunction {
     }
 }
 
+/// The aggregate functions by the names they are called by, aliases included
+static NAME_TO_FUNCTION: &[(&str, AggregateFunction)] = &[
+    ("min", AggregateFunction::Min),
+    ("max", AggregateFunction::Max),
+    ("count", AggregateFunction::Count),
+    ("avg", AggregateFunction::Avg),
+    ("mean", AggregateFunction::Avg),
+    ("sum", AggregateFunction::Sum),
+    ("median", AggregateFunction::Median),
+    ("approx_distinct", AggregateFunction::ApproxDistinct),
+    ("array_agg", AggregateFunction::ArrayAgg),
+    ("var", AggregateFunction::Variance),
+    ("var_samp", AggregateFunction::Variance),
+    ("var_pop", AggregateFunction::VariancePop),
+    ("stddev", AggregateFunction::Stddev),
+    ("stddev_samp", AggregateFunction::Stddev),
+    ("stddev_pop", AggregateFunction::StddevPop),
+    ("covar", AggregateFunction::Covariance),
+    ("covar_samp", AggregateFunction::Covariance),
+    ("covar_pop", AggregateFunction::CovariancePop),
+    ("corr", AggregateFunction::Correlation),
+    (
+        "approx_percentile_cont",
+        AggregateFunction::ApproxPercentileCont,
+    ),
+    (
+        "approx_percentile_cont_with_weight",
+        AggregateFunction::ApproxPercentileContWithWeight,
+    ),
+    ("approx_median", AggregateFunction::ApproxMedian),
+    ("grouping", AggregateFunction::Grouping),
+];
+
+impl AggregateFunction {
+    /// Returns the aggregate functions by the names they are called by,
+    /// aliases included
+    pub fn by_name() -> &'static [(&'static str, AggregateFunction)] {
+        NAME_TO_FUNCTION
+    }
+}
+
 impl FromStr for AggregateFunction {
     type Err = DataFusionError;
     fn from_str(name: &str) -> Result<AggregateFunction> {
-        Ok(match name {
-            "min" => AggregateFunction::Min,
-            "max" => AggregateFunction::Max,
-            "count" => AggregateFunction::Count,
-            "avg" => AggregateFunction::Avg,
-            "mean" => AggregateFunction::Avg,
-            "sum" => AggregateFunction::Sum,
-            "median" => AggregateFunction::Median,
-            "approx_distinct" => AggregateFunction::ApproxDistinct,
-            "array_agg" => AggregateFunction::ArrayAgg,
-            "var" => AggregateFunction::Variance,
-            "var_samp" => AggregateFunction::Variance,
-            "var_pop" => AggregateFunction::VariancePop,
-            "stddev" => AggregateFunction::Stddev,
-            "stddev_samp" => AggregateFunction::Stddev,
-            "stddev_pop" => AggregateFunction::StddevPop,
-            "covar" => AggregateFunction::Covariance,
-            "covar_samp" => AggregateFunction::Covariance,
-            "covar_pop" => AggregateFunction::CovariancePop,
-            "corr" => AggregateFunction::Correlation,
-            "approx_percentile_cont" => AggregateFunction::ApproxPercentileCont,
-            "approx_percentile_cont_with_weight" => {
-                AggregateFunction::ApproxPercentileContWithWeight
-            }
-            "approx_median" => AggregateFunction::ApproxMedian,
-            "grouping" => AggregateFunction::Grouping,
-            _ => {
-                return Err(DataFusionError::Plan(format!(
+        NAME_TO_FUNCTION
+            .iter()
+            .find(|(function_name, _)| *function_name == name)
+            .map(|(_, function)| function.clone())
+            .ok_or_else(|| {
+                DataFusionError::Plan(format!(
                     "There is no built-in function named {name}"
-                )));
-            }
-        })
+                ))
+            })
     }
 }
 
@@ -152,6 +152,8 @@ pub enum BuiltinScalarFunction {
     ToTimestampMicros,
     /// to_timestamp_seconds
     ToTimestampSeconds,
+    /// to_utc_timestamp
+    ToUtcTimestamp,
     /// from_unixtime
     FromUnixtime,
     ///now
@@ -177,6 +179,12 @@ pub enum BuiltinScalarFunction {
 }
 
 impl BuiltinScalarFunction {
+    /// Returns the built-in scalar functions by the names they are called by,
+    /// aliases included
+    pub fn by_name() -> &'static [(&'static str, BuiltinScalarFunction)] {
+        NAME_TO_FUNCTION
+    }
+
     /// an allowlist of functions to take zero arguments, so that they will get special treatment
     /// while executing.
     pub fn supports_zero_argument(&self) -> bool {
@@ -254,6 +262,7 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ToTimestampMillis => Volatility::Immutable,
             BuiltinScalarFunction::ToTimestampMicros => Volatility::Immutable,
             BuiltinScalarFunction::ToTimestampSeconds => Volatility::Immutable,
+            BuiltinScalarFunction::ToUtcTimestamp => Volatility::Immutable,
             BuiltinScalarFunction::Translate => Volatility::Immutable,
             BuiltinScalarFunction::Trim => Volatility::Immutable,
             BuiltinScalarFunction::Upper => Volatility::Immutable,
@@ -281,97 +290,113 @@ impl fmt::Display for BuiltinScalarFunction {
     }
 }
 
+/// The built-in scalar functions by the names they are called by, aliases included
+static NAME_TO_FUNCTION: &[(&str, BuiltinScalarFunction)] = &[
+    // math functions
+    ("abs", BuiltinScalarFunction::Abs),
+    ("acos", BuiltinScalarFunction::Acos),
+    ("asin", BuiltinScalarFunction::Asin),
+    ("atan", BuiltinScalarFunction::Atan),
+    ("atan2", BuiltinScalarFunction::Atan2),
+    ("ceil", BuiltinScalarFunction::Ceil),
+    ("cos", BuiltinScalarFunction::Cos),
+    ("exp", BuiltinScalarFunction::Exp),
+    ("floor", BuiltinScalarFunction::Floor),
+    ("ln", BuiltinScalarFunction::Ln),
+    ("log", BuiltinScalarFunction::Log),
+    ("log10", BuiltinScalarFunction::Log10),
+    ("log2", BuiltinScalarFunction::Log2),
+    ("power", BuiltinScalarFunction::Power),
+    ("pow", BuiltinScalarFunction::Power),
+    ("round", BuiltinScalarFunction::Round),
+    ("signum", BuiltinScalarFunction::Signum),
+    ("sin", BuiltinScalarFunction::Sin),
+    ("sqrt", BuiltinScalarFunction::Sqrt),
+    ("tan", BuiltinScalarFunction::Tan),
+    ("trunc", BuiltinScalarFunction::Trunc),
+    // conditional functions
+    ("coalesce", BuiltinScalarFunction::Coalesce),
+    // array functions
+    ("make_array", BuiltinScalarFunction::MakeArray),
+    // string functions
+    ("ascii", BuiltinScalarFunction::Ascii),
+    ("bit_length", BuiltinScalarFunction::BitLength),
+    ("btrim", BuiltinScalarFunction::Btrim),
+    ("char_length", BuiltinScalarFunction::CharacterLength),
+    ("character_length", BuiltinScalarFunction::CharacterLength),
+    ("concat", BuiltinScalarFunction::Concat),
+    ("concat_ws", BuiltinScalarFunction::ConcatWithSeparator),
+    ("chr", BuiltinScalarFunction::Chr),
+    ("current_date", BuiltinScalarFunction::CurrentDate),
+    ("current_time", BuiltinScalarFunction::CurrentTime),
+    ("date_part", BuiltinScalarFunction::DatePart),
+    ("datepart", BuiltinScalarFunction::DatePart),
+    ("date_trunc", BuiltinScalarFunction::DateTrunc),
+    ("datetrunc", BuiltinScalarFunction::DateTrunc),
+    ("date_bin", BuiltinScalarFunction::DateBin),
+    ("initcap", BuiltinScalarFunction::InitCap),
+    ("left", BuiltinScalarFunction::Left),
+    ("length", BuiltinScalarFunction::CharacterLength),
+    ("lower", BuiltinScalarFunction::Lower),
+    ("lpad", BuiltinScalarFunction::Lpad),
+    ("ltrim", BuiltinScalarFunction::Ltrim),
+    ("md5", BuiltinScalarFunction::MD5),
+    ("nullif", BuiltinScalarFunction::NullIf),
+    ("octet_length", BuiltinScalarFunction::OctetLength),
+    ("random", BuiltinScalarFunction::Random),
+    ("regexp_replace", BuiltinScalarFunction::RegexpReplace),
+    ("repeat", BuiltinScalarFunction::Repeat),
+    ("replace", BuiltinScalarFunction::Replace),
+    ("reverse", BuiltinScalarFunction::Reverse),
+    ("right", BuiltinScalarFunction::Right),
+    ("rpad", BuiltinScalarFunction::Rpad),
+    ("rtrim", BuiltinScalarFunction::Rtrim),
+    ("sha224", BuiltinScalarFunction::SHA224),
+    ("sha256", BuiltinScalarFunction::SHA256),
+    ("sha384", BuiltinScalarFunction::SHA384),
+    ("sha512", BuiltinScalarFunction::SHA512),
+    ("digest", BuiltinScalarFunction::Digest),
+    ("split_part", BuiltinScalarFunction::SplitPart),
+    ("starts_with", BuiltinScalarFunction::StartsWith),
+    ("strpos", BuiltinScalarFunction::Strpos),
+    ("substr", BuiltinScalarFunction::Substr),
+    ("to_hex", BuiltinScalarFunction::ToHex),
+    ("to_timestamp", BuiltinScalarFunction::ToTimestamp),
+    (
+        "to_timestamp_millis",
+        BuiltinScalarFunction::ToTimestampMillis,
+    ),
+    (
+        "to_timestamp_micros",
+        BuiltinScalarFunction::ToTimestampMicros,
+    ),
+    (
+        "to_timestamp_seconds",
+        BuiltinScalarFunction::ToTimestampSeconds,
+    ),
+    ("to_utc_timestamp", BuiltinScalarFunction::ToUtcTimestamp),
+    ("now", BuiltinScalarFunction::Now),
+    ("translate", BuiltinScalarFunction::Translate),
+    ("trim", BuiltinScalarFunction::Trim),
+    ("upper", BuiltinScalarFunction::Upper),
+    ("uuid", BuiltinScalarFunction::Uuid),
+    ("regexp_match", BuiltinScalarFunction::RegexpMatch),
+    ("struct", BuiltinScalarFunction::Struct),
+    ("from_unixtime", BuiltinScalarFunction::FromUnixtime),
+    ("arrow_typeof", BuiltinScalarFunction::ArrowTypeof),
+];
+
 impl FromStr for BuiltinScalarFunction {
     type Err = DataFusionError;
     fn from_str(name: &str) -> Result<BuiltinScalarFunction> {
-        Ok(match name {
-            // math functions
-            "abs" => BuiltinScalarFunction::Abs,
-            "acos" => BuiltinScalarFunction::Acos,
-            "asin" => BuiltinScalarFunction::Asin,
-            "atan" => BuiltinScalarFunction::Atan,
-            "atan2" => BuiltinScalarFunction::Atan2,
-            "ceil" => BuiltinScalarFunction::Ceil,
-            "cos" => BuiltinScalarFunction::Cos,
-            "exp" => BuiltinScalarFunction::Exp,
-            "floor" => BuiltinScalarFunction::Floor,
-            "ln" => BuiltinScalarFunction::Ln,
-            "log" => BuiltinScalarFunction::Log,
-            "log10" => BuiltinScalarFunction::Log10,
-            "log2" => BuiltinScalarFunction::Log2,
-            "power" | "pow" => BuiltinScalarFunction::Power,
-            "round" => BuiltinScalarFunction::Round,
-            "signum" => BuiltinScalarFunction::Signum,
-            "sin" => BuiltinScalarFunction::Sin,
-            "sqrt" => BuiltinScalarFunction::Sqrt,
-            "tan" => BuiltinScalarFunction::Tan,
-            "trunc" => BuiltinScalarFunction::Trunc,
-
-            // conditional functions
-            "coalesce" => BuiltinScalarFunction::Coalesce,
-
-            // array functions
-            "make_array" => BuiltinScalarFunction::MakeArray,
-
-            // string functions
-            "ascii" => BuiltinScalarFunction::Ascii,
-            "bit_length" => BuiltinScalarFunction::BitLength,
-            "btrim" => BuiltinScalarFunction::Btrim,
-            "char_length" => BuiltinScalarFunction::CharacterLength,
-            "character_length" => BuiltinScalarFunction::CharacterLength,
-            "concat" => BuiltinScalarFunction::Concat,
-            "concat_ws" => BuiltinScalarFunction::ConcatWithSeparator,
-            "chr" => BuiltinScalarFunction::Chr,
-            "current_date" => BuiltinScalarFunction::CurrentDate,
-            "current_time" => BuiltinScalarFunction::CurrentTime,
-            "date_part" | "datepart" => BuiltinScalarFunction::DatePart,
-            "date_trunc" | "datetrunc" => BuiltinScalarFunction::DateTrunc,
-            "date_bin" => BuiltinScalarFunction::DateBin,
-            "initcap" => BuiltinScalarFunction::InitCap,
-            "left" => BuiltinScalarFunction::Left,
-            "length" => BuiltinScalarFunction::CharacterLength,
-            "lower" => BuiltinScalarFunction::Lower,
-            "lpad" => BuiltinScalarFunction::Lpad,
-            "ltrim" => BuiltinScalarFunction::Ltrim,
-            "md5" => BuiltinScalarFunction::MD5,
-            "nullif" => BuiltinScalarFunction::NullIf,
-            "octet_length" => BuiltinScalarFunction::OctetLength,
-            "random" => BuiltinScalarFunction::Random,
-            "regexp_replace" => BuiltinScalarFunction::RegexpReplace,
-            "repeat" => BuiltinScalarFunction::Repeat,
-            "replace" => BuiltinScalarFunction::Replace,
-            "reverse" => BuiltinScalarFunction::Reverse,
-            "right" => BuiltinScalarFunction::Right,
-            "rpad" => BuiltinScalarFunction::Rpad,
-            "rtrim" => BuiltinScalarFunction::Rtrim,
-            "sha224" => BuiltinScalarFunction::SHA224,
-            "sha256" => BuiltinScalarFunction::SHA256,
-            "sha384" => BuiltinScalarFunction::SHA384,
-            "sha512" => BuiltinScalarFunction::SHA512,
-            "digest" => BuiltinScalarFunction::Digest,
-            "split_part" => BuiltinScalarFunction::SplitPart,
-            "starts_with" => BuiltinScalarFunction::StartsWith,
-            "strpos" => BuiltinScalarFunction::Strpos,
-            "substr" => BuiltinScalarFunction::Substr,
-            "to_hex" => BuiltinScalarFunction::ToHex,
-            "to_timestamp" => BuiltinScalarFunction::ToTimestamp,
-            "to_timestamp_millis" => BuiltinScalarFunction::ToTimestampMillis,
-            "to_timestamp_micros" => BuiltinScalarFunction::ToTimestampMicros,
-            "to_timestamp_seconds" => BuiltinScalarFunction::ToTimestampSeconds,
-            "now" => BuiltinScalarFunction::Now,
-            "translate" => BuiltinScalarFunction::Translate,
-            "trim" => BuiltinScalarFunction::Trim,
-            "upper" => BuiltinScalarFunction::Upper,
-            "uuid" => BuiltinScalarFunction::Uuid,
-            "regexp_match" => BuiltinScalarFunction::RegexpMatch,
-            "struct" => BuiltinScalarFunction::Struct,
-            "from_unixtime" => BuiltinScalarFunction::FromUnixtime,
-            "arrow_typeof" => BuiltinScalarFunction::ArrowTypeof,
-            _ => {
-                return Err(DataFusionError::Plan(format!(
+        NAME_TO_FUNCTION
+            .iter()
+            .find(|(function_name, _)| *function_name == name)
+            .map(|(_, function)| function.clone())
+            .ok_or_else(|| {
+                DataFusionError::Plan(format!(
                     "There is no built-in function named {name}"
-                )))
-            }
-        })
+                ))
+            })
     }
 }
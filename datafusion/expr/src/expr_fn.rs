@@ -565,6 +565,12 @@ scalar_expr!(
     date,
     "converts a string to a `Timestamp(Seconds, None)`"
 );
+scalar_expr!(
+    ToUtcTimestamp,
+    to_utc_timestamp,
+    timestamp timezone,
+    "interprets a timestamp without timezone as a wall clock time in `timezone` and converts it to UTC"
+);
 scalar_expr!(
     FromUnixtime,
     from_unixtime,
@@ -787,6 +793,7 @@ mod test {
         test_scalar_expr!(DatePart, date_part, part, date);
         test_scalar_expr!(DateTrunc, date_trunc, part, date);
         test_scalar_expr!(DateBin, date_bin, stride, source, origin);
+        test_scalar_expr!(ToUtcTimestamp, to_utc_timestamp, timestamp, timezone);
         test_scalar_expr!(FromUnixtime, from_unixtime, unixtime);
 
         test_unary_scalar_expr!(ArrowTypeof, arrow_typeof);
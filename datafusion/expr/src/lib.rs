@@ -43,6 +43,7 @@ pub mod logical_plan;
 mod nullif;
 mod operator;
 mod signature;
+mod sql_function;
 pub mod struct_expressions;
 mod table_source;
 pub mod type_coercion;
@@ -70,8 +71,9 @@ pub use logical_plan::{
     builder::{
         build_join_schema, union, wrap_projection_for_join_if_necessary, UNNAMED_TABLE,
     },
-    Aggregate, CreateCatalog, CreateCatalogSchema, CreateExternalTable,
-    CreateMemoryTable, CreateView, CrossJoin, DescribeTable, Distinct, DmlStatement,
+    Aggregate, AlterTable, AlterTableOperation, AlterView, CreateCatalog,
+    CreateCatalogSchema, CreateExternalTable, CreateFunction, CreateMemoryTable,
+    CreateView, CrossJoin, DescribeTable, Distinct, DmlStatement, DropFunction,
     DropTable, DropView, EmptyRelation, Explain, Extension, Filter, Join, JoinConstraint,
     JoinType, Limit, LogicalPlan, LogicalPlanBuilder, Partitioning, PlanType,
     PlanVisitor, Projection, Repartition, SetVariable, Sort, StringifiedPlan, Subquery,
@@ -80,7 +82,8 @@ pub use logical_plan::{
 };
 pub use nullif::SUPPORTED_NULLIF_TYPES;
 pub use operator::Operator;
-pub use signature::{Signature, TypeSignature, Volatility};
+pub use signature::{Signature, TypeSignature, Volatility, TIMEZONE_WILDCARD};
+pub use sql_function::SqlFunction;
 pub use table_source::{TableProviderFilterPushDown, TableSource, TableType};
 pub use udaf::AggregateUDF;
 pub use udf::ScalarUDF;
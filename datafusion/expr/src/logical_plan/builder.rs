@@ -241,6 +241,7 @@ impl LogicalPlanBuilder {
             source: table_source,
             projected_schema: Arc::new(projected_schema),
             projection,
+            nested_projection: None,
             filters,
             fetch: None,
         });
@@ -22,8 +22,9 @@ mod plan;
 
 pub use builder::{table_scan, LogicalPlanBuilder};
 pub use plan::{
-    Aggregate, Analyze, CreateCatalog, CreateCatalogSchema, CreateExternalTable,
-    CreateMemoryTable, CreateView, CrossJoin, DescribeTable, Distinct, DmlStatement,
+    Aggregate, AlterTable, AlterTableOperation, AlterView, Analyze, CreateCatalog,
+    CreateCatalogSchema, CreateExternalTable, CreateFunction, CreateMemoryTable,
+    CreateView, CrossJoin, DescribeTable, Distinct, DmlStatement, DropFunction,
     DropTable, DropView, EmptyRelation, Explain, Extension, Filter, Join, JoinConstraint,
     JoinType, Limit, LogicalPlan, Partitioning, PlanType, PlanVisitor, Prepare,
     Projection, Repartition, SetVariable, Sort, StringifiedPlan, Subquery, SubqueryAlias,
@@ -28,7 +28,8 @@ use crate::utils::{
     grouping_set_to_exprlist,
 };
 use crate::{
-    build_join_schema, Expr, ExprSchemable, TableProviderFilterPushDown, TableSource,
+    build_join_schema, Expr, ExprSchemable, SqlFunction, TableProviderFilterPushDown,
+    TableSource,
 };
 use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
 use datafusion_common::parsers::CompressionTypeVariant;
@@ -102,6 +103,14 @@ pub enum LogicalPlan {
     DropTable(DropTable),
     /// Drops a view.
     DropView(DropView),
+    /// Creates a SQL macro function.
+    CreateFunction(CreateFunction),
+    /// Drops a SQL macro function.
+    DropFunction(DropFunction),
+    /// Alters a table.
+    AlterTable(AlterTable),
+    /// Alters a view.
+    AlterView(AlterView),
     /// Values expression. See
     /// [Postgres VALUES](https://www.postgresql.org/docs/current/queries-values.html)
     /// documentation for more details.
@@ -165,6 +174,10 @@ impl LogicalPlan {
             LogicalPlan::CreateCatalog(CreateCatalog { schema, .. }) => schema,
             LogicalPlan::DropTable(DropTable { schema, .. }) => schema,
             LogicalPlan::DropView(DropView { schema, .. }) => schema,
+            LogicalPlan::CreateFunction(CreateFunction { schema, .. }) => schema,
+            LogicalPlan::DropFunction(DropFunction { schema, .. }) => schema,
+            LogicalPlan::AlterTable(AlterTable { schema, .. }) => schema,
+            LogicalPlan::AlterView(AlterView { schema, .. }) => schema,
             LogicalPlan::SetVariable(SetVariable { schema, .. }) => schema,
             LogicalPlan::DescribeTable(DescribeTable { dummy_schema, .. }) => {
                 dummy_schema
@@ -220,6 +233,10 @@ impl LogicalPlan {
             // return empty
             LogicalPlan::DropTable(_)
             | LogicalPlan::DropView(_)
+            | LogicalPlan::CreateFunction(_)
+            | LogicalPlan::DropFunction(_)
+            | LogicalPlan::AlterTable(_)
+            | LogicalPlan::AlterView(_)
             | LogicalPlan::DescribeTable(_)
             | LogicalPlan::SetVariable(_) => vec![],
         }
@@ -318,6 +335,10 @@ impl LogicalPlan {
             | LogicalPlan::DropTable(_)
             | LogicalPlan::SetVariable(_)
             | LogicalPlan::DropView(_)
+            | LogicalPlan::CreateFunction(_)
+            | LogicalPlan::DropFunction(_)
+            | LogicalPlan::AlterTable(_)
+            | LogicalPlan::AlterView(_)
             | LogicalPlan::CrossJoin(_)
             | LogicalPlan::Analyze(_)
             | LogicalPlan::Explain(_)
@@ -368,6 +389,10 @@ impl LogicalPlan {
             | LogicalPlan::DropTable(_)
             | LogicalPlan::SetVariable(_)
             | LogicalPlan::DropView(_)
+            | LogicalPlan::CreateFunction(_)
+            | LogicalPlan::DropFunction(_)
+            | LogicalPlan::AlterTable(_)
+            | LogicalPlan::AlterView(_)
             | LogicalPlan::DescribeTable(_) => vec![],
         }
     }
@@ -568,6 +593,10 @@ impl LogicalPlan {
             | LogicalPlan::DropTable(_)
             | LogicalPlan::SetVariable(_)
             | LogicalPlan::DropView(_)
+            | LogicalPlan::CreateFunction(_)
+            | LogicalPlan::DropFunction(_)
+            | LogicalPlan::AlterTable(_)
+            | LogicalPlan::AlterView(_)
             | LogicalPlan::DescribeTable(_) => true,
         };
         if !recurse {
@@ -940,6 +969,7 @@ impl LogicalPlan {
                         ref source,
                         ref table_name,
                         ref projection,
+                        ref nested_projection,
                         ref filters,
                         ref fetch,
                         ..
@@ -958,6 +988,12 @@ impl LogicalPlan {
 
                         write!(f, "TableScan: {table_name}{projected_fields}")?;
 
+                        if let Some(paths) = nested_projection {
+                            let paths: Vec<String> =
+                                paths.iter().map(|path| path.join(".")).collect();
+                            write!(f, ", nested_projection=[{}]", paths.join(", "))?;
+                        }
+
                         if !filters.is_empty() {
                             let mut full_filter = vec![];
                             let mut partial_filter = vec![];
@@ -1163,6 +1199,22 @@ impl LogicalPlan {
                     }) => {
                         write!(f, "DropView: {name:?} if not exist:={if_exists}")
                     }
+                    LogicalPlan::CreateFunction(CreateFunction { function, .. }) => {
+                        write!(f, "CreateFunction: {:?}", function.name)
+                    }
+                    LogicalPlan::DropFunction(DropFunction {
+                        name, if_exists, ..
+                    }) => {
+                        write!(f, "DropFunction: {name:?} if not exist:={if_exists}")
+                    }
+                    LogicalPlan::AlterTable(AlterTable {
+                        name, operation, ..
+                    }) => {
+                        write!(f, "AlterTable: {name:?} {operation}")
+                    }
+                    LogicalPlan::AlterView(AlterView { name, new_name, .. }) => {
+                        write!(f, "AlterView: {name:?} rename to {new_name:?}")
+                    }
                     LogicalPlan::SetVariable(SetVariable {
                         variable, value, ..
                     }) => {
@@ -1301,6 +1353,96 @@ pub struct DropView {
     pub schema: DFSchemaRef,
 }
 
+/// Creates a SQL macro function.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CreateFunction {
+    /// The function definition
+    pub function: SqlFunction,
+    /// Option to replace the function if it already exists
+    pub or_replace: bool,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
+/// Drops a SQL macro function.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DropFunction {
+    /// The function name
+    pub name: String,
+    /// If the function exists
+    pub if_exists: bool,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
+/// Alters a table.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AlterTable {
+    /// The table name
+    pub name: OwnedTableReference,
+    /// The change of the table
+    pub operation: AlterTableOperation,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
+/// A change of a table, see [`AlterTable`]
+#[derive(Clone, PartialEq, Eq)]
+pub enum AlterTableOperation {
+    /// Renames the table
+    RenameTo(OwnedTableReference),
+    /// Adds a nullable column to the table, which is null in the rows the
+    /// table already has
+    AddColumn(Field),
+    /// Sets the table(provider) specific options of the table, replacing the
+    /// options with the same keys
+    SetOptions(HashMap<String, String>),
+}
+
+// Hashing refers to a subset of fields considered in PartialEq.
+#[allow(clippy::derived_hash_with_manual_eq)]
+impl Hash for AlterTableOperation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            AlterTableOperation::RenameTo(new_name) => new_name.hash(state),
+            AlterTableOperation::AddColumn(field) => field.hash(state),
+            // HashMap is not hashable
+            AlterTableOperation::SetOptions(options) => options.len().hash(state),
+        }
+    }
+}
+
+impl Display for AlterTableOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AlterTableOperation::RenameTo(new_name) => {
+                write!(f, "rename to {new_name:?}")
+            }
+            AlterTableOperation::AddColumn(field) => {
+                write!(f, "add column {}:{:?}", field.name(), field.data_type())
+            }
+            AlterTableOperation::SetOptions(options) => {
+                let mut options: Vec<_> =
+                    options.iter().map(|(k, v)| format!("{k}={v}")).collect();
+                options.sort();
+                write!(f, "set options [{}]", options.join(", "))
+            }
+        }
+    }
+}
+
+/// Alters a view.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AlterView {
+    /// The view name
+    pub name: OwnedTableReference,
+    /// The new name of the view
+    pub new_name: OwnedTableReference,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
 /// Set a Variable's value -- value in
 /// [`ConfigOptions`](datafusion_common::config::ConfigOptions)
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -1507,6 +1649,11 @@ pub struct TableScan {
     pub source: Arc<dyn TableSource>,
     /// Optional column indices to use as a projection
     pub projection: Option<Vec<usize>>,
+    /// Optional paths of the nested fields to read from the struct columns
+    /// of the projection, such as `["payload", "user", "id"]`. Struct columns
+    /// with paths are only read with the fields they lead to, see
+    /// [`TableSource::supports_nested_projection`]
+    pub nested_projection: Option<Vec<Vec<String>>>,
     /// The schema description of the output
     pub projected_schema: DFSchemaRef,
     /// Optional expressions to be used as filters by the table provider
@@ -1519,6 +1666,7 @@ impl PartialEq for TableScan {
     fn eq(&self, other: &Self) -> bool {
         self.table_name == other.table_name
             && self.projection == other.projection
+            && self.nested_projection == other.nested_projection
             && self.projected_schema == other.projected_schema
             && self.filters == other.filters
             && self.fetch == other.fetch
@@ -1531,6 +1679,7 @@ impl Hash for TableScan {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.table_name.hash(state);
         self.projection.hash(state);
+        self.nested_projection.hash(state);
         self.projected_schema.hash(state);
         self.filters.hash(state);
         self.fetch.hash(state);
@@ -1611,6 +1760,8 @@ pub struct CreateExternalTable {
     pub table_partition_cols: Vec<String>,
     /// Option to not error if table already exists
     pub if_not_exists: bool,
+    /// Option to replace the table if it already exists
+    pub or_replace: bool,
     /// SQL used to create the table, if available
     pub definition: Option<String>,
     /// File compression type (GZIP, BZIP2, XZ, ZSTD)
@@ -1631,6 +1782,7 @@ impl Hash for CreateExternalTable {
         self.delimiter.hash(state);
         self.table_partition_cols.hash(state);
         self.if_not_exists.hash(state);
+        self.or_replace.hash(state);
         self.definition.hash(state);
         self.file_compression_type.hash(state);
         self.options.len().hash(state); // HashMap is not hashable
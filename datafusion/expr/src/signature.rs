@@ -20,6 +20,14 @@
 
 use arrow::datatypes::DataType;
 
+/// Constant used as a placeholder for any valid timezone in a
+/// `DataType::Timestamp` of a [`TypeSignature`].
+///
+/// A function accepting `Timestamp(unit, Some(TIMEZONE_WILDCARD))` accepts
+/// timestamps in any timezone, and its arguments keep their own timezone
+/// during coercion instead of being cast to a fixed one.
+pub const TIMEZONE_WILDCARD: &str = "+TZ";
+
 ///A function's volatility, which defines the functions eligibility for certain optimizations
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 pub enum Volatility {
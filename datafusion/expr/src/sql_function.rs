@@ -0,0 +1,170 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! SqlFunction module contains the representation of scalar macros defined
+//! in SQL with `CREATE FUNCTION`.
+
+use crate::expr_rewriter::{ExprRewritable, ExprRewriter, RewriteRecursion};
+use crate::{cast, Expr};
+use arrow::datatypes::DataType;
+use datafusion_common::{Column, DataFusionError, Result};
+use std::collections::HashMap;
+
+/// Logical representation of a scalar function defined in SQL, such as
+///
+/// ```sql
+/// CREATE FUNCTION discount(price DOUBLE, pct DOUBLE) RETURNS DOUBLE
+/// RETURN price * (1 - pct)
+/// ```
+///
+/// Unlike a [`ScalarUDF`](crate::ScalarUDF), a `SqlFunction` has no
+/// implementation of its own: calls to it are replaced by its body
+/// during planning, with the parameters substituted by the call arguments.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SqlFunction {
+    /// name
+    pub name: String,
+    /// Names and types of the parameters
+    pub args: Vec<(String, DataType)>,
+    /// Declared return type, if any
+    pub return_type: Option<DataType>,
+    /// The body, referring to the parameters as unqualified columns
+    pub body: Expr,
+    /// The SQL text of the body
+    pub definition: String,
+}
+
+impl SqlFunction {
+    /// Create a new SqlFunction
+    pub fn new(
+        name: impl Into<String>,
+        args: Vec<(String, DataType)>,
+        return_type: Option<DataType>,
+        body: Expr,
+        definition: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            args,
+            return_type,
+            body,
+            definition: definition.into(),
+        }
+    }
+
+    /// Returns the expression a call to this function with the arguments
+    /// `args` expands to.
+    ///
+    /// The arguments are cast to the declared parameter types, and the
+    /// result to the declared return type.
+    pub fn inline(&self, args: Vec<Expr>) -> Result<Expr> {
+        if args.len() != self.args.len() {
+            return Err(DataFusionError::Plan(format!(
+                "Function '{}' expects {} arguments but {} were provided",
+                self.name,
+                self.args.len(),
+                args.len()
+            )));
+        }
+
+        let mut rewriter = ParameterRewriter {
+            args: self
+                .args
+                .iter()
+                .zip(args)
+                .map(|((name, data_type), arg)| {
+                    (name.as_str(), cast(arg, data_type.clone()))
+                })
+                .collect(),
+        };
+        let expr = self.body.clone().rewrite(&mut rewriter)?;
+
+        Ok(match &self.return_type {
+            Some(return_type) => cast(expr, return_type.clone()),
+            None => expr,
+        })
+    }
+}
+
+/// Replaces the parameters of a [`SqlFunction`] with the call arguments
+struct ParameterRewriter<'a> {
+    args: HashMap<&'a str, Expr>,
+}
+
+impl<'a> ExprRewriter for ParameterRewriter<'a> {
+    fn pre_visit(&mut self, expr: &Expr) -> Result<RewriteRecursion> {
+        Ok(match expr {
+            Expr::Column(Column {
+                relation: None,
+                name,
+            }) if self.args.contains_key(name.as_str()) => RewriteRecursion::Mutate,
+            _ => RewriteRecursion::Continue,
+        })
+    }
+
+    fn mutate(&mut self, expr: Expr) -> Result<Expr> {
+        match expr {
+            Expr::Column(Column {
+                relation: None,
+                name,
+            }) if self.args.contains_key(name.as_str()) => {
+                Ok(self.args[name.as_str()].clone())
+            }
+            expr => Ok(expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{col, lit};
+
+    fn discount() -> SqlFunction {
+        SqlFunction::new(
+            "discount",
+            vec![
+                ("price".to_string(), DataType::Float64),
+                ("pct".to_string(), DataType::Float64),
+            ],
+            Some(DataType::Float64),
+            col("price") * (lit(1_i64) - col("pct")),
+            "price * (1 - pct)",
+        )
+    }
+
+    #[test]
+    fn inline_arguments() -> Result<()> {
+        let expr = discount().inline(vec![col("t.a"), lit(0.5)])?;
+        let expected = cast(
+            cast(col("t.a"), DataType::Float64)
+                * (lit(1_i64) - cast(lit(0.5), DataType::Float64)),
+            DataType::Float64,
+        );
+        assert_eq!(expr, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn inline_wrong_number_of_arguments() {
+        let err = discount().inline(vec![col("a")]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error during planning: Function 'discount' expects 2 arguments but 1 were provided"
+        );
+    }
+}
@@ -17,7 +17,7 @@
 
 use crate::{Expr, LogicalPlan};
 use arrow::datatypes::SchemaRef;
-use datafusion_common::Result;
+use datafusion_common::{Result, Statistics};
 use std::any::Any;
 
 ///! Table source
@@ -96,4 +96,17 @@ pub trait TableSource: Sync + Send {
     fn get_logical_plan(&self) -> Option<&LogicalPlan> {
         None
     }
+
+    /// Tests whether the table source can read only some of the nested
+    /// fields of its struct columns, as described by
+    /// [`TableScan::nested_projection`](crate::logical_plan::TableScan::nested_projection)
+    fn supports_nested_projection(&self) -> bool {
+        false
+    }
+
+    /// Get statistics for this table, if available. These are used by the
+    /// logical optimizer to estimate the cardinality of plans
+    fn statistics(&self) -> Option<Statistics> {
+        None
+    }
 }
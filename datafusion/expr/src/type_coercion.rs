@@ -45,6 +45,7 @@ pub fn is_signed_numeric(dt: &DataType) -> bool {
             | DataType::Float32
             | DataType::Float64
             | DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _)
     )
 }
 
@@ -72,6 +73,11 @@ pub fn is_interval(dt: &DataType) -> bool {
     matches!(dt, DataType::Interval(_))
 }
 
+/// Determine whether the given data type `dt` is a `Duration`.
+pub fn is_duration(dt: &DataType) -> bool {
+    matches!(dt, DataType::Duration(_))
+}
+
 /// Determine whether the given data type `dt` is a `Date`.
 pub fn is_date(dt: &DataType) -> bool {
     matches!(dt, DataType::Date32 | DataType::Date64)
@@ -17,6 +17,7 @@
 
 use arrow::datatypes::{
     DataType, TimeUnit, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE,
+    DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE,
 };
 use datafusion_common::{DataFusionError, Result};
 use std::ops::Deref;
@@ -306,6 +307,12 @@ pub fn sum_return_type(arg_type: &DataType) -> Result<DataType> {
             let new_precision = DECIMAL128_MAX_PRECISION.min(*precision + 10);
             Ok(DataType::Decimal128(new_precision, *scale))
         }
+        DataType::Decimal256(precision, scale) => {
+            // same as Decimal128, but the precision is capped at 76 instead of 38,
+            // so the result type is DECIMAL(min(76,precision+10), s)
+            let new_precision = DECIMAL256_MAX_PRECISION.min(*precision + 10);
+            Ok(DataType::Decimal256(new_precision, *scale))
+        }
         other => Err(DataFusionError::Plan(format!(
             "SUM does not support type \"{other:?}\""
         ))),
@@ -398,6 +405,13 @@ pub fn avg_return_type(arg_type: &DataType) -> Result<DataType> {
             let new_scale = DECIMAL128_MAX_SCALE.min(*scale + 4);
             Ok(DataType::Decimal128(new_precision, new_scale))
         }
+        DataType::Decimal256(precision, scale) => {
+            // same as Decimal128, but precision and scale are capped at 76 instead of 38,
+            // so the result type is DECIMAL(min(76,precision+4), min(76,scale+4)).
+            let new_precision = DECIMAL256_MAX_PRECISION.min(*precision + 4);
+            let new_scale = DECIMAL256_MAX_SCALE.min(*scale + 4);
+            Ok(DataType::Decimal256(new_precision, new_scale))
+        }
         DataType::Int8
         | DataType::Int16
         | DataType::Int32
@@ -428,6 +442,7 @@ pub fn is_sum_support_arg_type(arg_type: &DataType) -> bool {
             | DataType::Float32
             | DataType::Float64
             | DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _)
     )
 }
 
@@ -445,6 +460,7 @@ pub fn is_avg_support_arg_type(arg_type: &DataType) -> bool {
             | DataType::Float32
             | DataType::Float64
             | DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _)
     )
 }
 
@@ -646,6 +662,10 @@ mod tests {
         let data_type = DataType::Decimal128(36, 10);
         let result_type = avg_return_type(&data_type)?;
         assert_eq!(DataType::Decimal128(38, 14), result_type);
+
+        let data_type = DataType::Decimal256(74, 10);
+        let result_type = avg_return_type(&data_type)?;
+        assert_eq!(DataType::Decimal256(76, 14), result_type);
         Ok(())
     }
 
@@ -669,6 +689,10 @@ mod tests {
         let data_type = DataType::Decimal128(36, 10);
         let result_type = sum_return_type(&data_type)?;
         assert_eq!(DataType::Decimal128(38, 10), result_type);
+
+        let data_type = DataType::Decimal256(50, 10);
+        let result_type = sum_return_type(&data_type)?;
+        assert_eq!(DataType::Decimal256(60, 10), result_type);
         Ok(())
     }
 
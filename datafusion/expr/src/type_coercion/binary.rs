@@ -17,11 +17,12 @@
 
 //! Coercion rules for matching argument types for binary operators
 
-use crate::type_coercion::{is_date, is_interval, is_numeric, is_timestamp};
+use crate::type_coercion::{is_date, is_duration, is_interval, is_numeric, is_timestamp};
 use crate::Operator;
 use arrow::compute::can_cast_types;
 use arrow::datatypes::{
     DataType, TimeUnit, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE,
+    DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE,
 };
 use datafusion_common::DataFusionError;
 use datafusion_common::Result;
@@ -121,6 +122,11 @@ pub fn coerce_types(
         {
             temporal_add_sub_coercion(lhs_type, rhs_type, op)?
         }
+        Operator::Plus | Operator::Minus | Operator::Multiply | Operator::Divide
+            if is_duration(lhs_type) || is_duration(rhs_type) =>
+        {
+            duration_coercion(lhs_type, op, rhs_type)
+        }
         // for math expressions, the final value of the coercion is also the return type
         // because coercion favours higher information types
         Operator::Plus
@@ -220,6 +226,25 @@ pub fn temporal_add_sub_coercion(
         return Ok(Some(lhs_type.clone()));
     }
 
+    // duration + timestamp
+    if is_duration(lhs_type) && is_timestamp(rhs_type) && op == &Operator::Plus {
+        return Ok(Some(rhs_type.clone()));
+    }
+
+    // timestamp +/- duration
+    if is_duration(rhs_type) && is_timestamp(lhs_type) {
+        return Ok(Some(lhs_type.clone()));
+    }
+
+    // timestamp - timestamp yields the duration between them, in the unit
+    // of the timestamp type both sides are coerced to
+    if is_timestamp(lhs_type) && is_timestamp(rhs_type) && op == &Operator::Minus {
+        return match temporal_coercion(lhs_type, rhs_type) {
+            Some(DataType::Timestamp(unit, _)) => Ok(Some(DataType::Duration(unit))),
+            _ => Ok(None),
+        };
+    }
+
     // date or timestamp + date or timestamp
     if (is_date(lhs_type) || is_timestamp(lhs_type))
         && (is_date(rhs_type) || is_timestamp(rhs_type))
@@ -227,12 +252,45 @@ pub fn temporal_add_sub_coercion(
         return Err(DataFusionError::Plan(
                         format!(
                             "'{lhs_type:?} {op} {rhs_type:?}' is an unsupported operation. \
-                                addition/subtraction on dates/timestamps only supported with interval types"
+                                addition/subtraction on dates/timestamps only supported with interval or duration types"
                         ),));
     }
     Ok(None)
 }
 
+/// Returns the output type of applying arithmetic operations on durations:
+/// durations of the same unit can be added and subtracted, and durations
+/// can be multiplied or divided by integers.
+fn duration_coercion(
+    lhs_type: &DataType,
+    op: &Operator,
+    rhs_type: &DataType,
+) -> Option<DataType> {
+    use arrow::datatypes::DataType::*;
+    let is_integer = |t: &DataType| {
+        matches!(
+            t,
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64
+        )
+    };
+    match (lhs_type, op, rhs_type) {
+        (Duration(lhs_unit), Operator::Plus | Operator::Minus, Duration(rhs_unit))
+            if lhs_unit == rhs_unit =>
+        {
+            Some(lhs_type.clone())
+        }
+        (Duration(_), Operator::Multiply | Operator::Divide, _)
+            if is_integer(rhs_type) =>
+        {
+            Some(lhs_type.clone())
+        }
+        (_, Operator::Multiply, Duration(_)) if is_integer(lhs_type) => {
+            Some(rhs_type.clone())
+        }
+        _ => None,
+    }
+}
+
 /// Returns the output type of applying numeric operations such as `=`
 /// to arguments `lhs_type` and `rhs_type` if one is numeric and one
 /// is `Utf8`/`LargeUtf8`.
@@ -267,9 +325,16 @@ fn comparison_binary_numeric_coercion(
     // that the coercion does not lose information via truncation
     match (lhs_type, rhs_type) {
         // support decimal data type for comparison operation
-        (d1 @ Decimal128(_, _), d2 @ Decimal128(_, _)) => get_wider_decimal_type(d1, d2),
-        (Decimal128(_, _), _) => get_comparison_common_decimal_type(lhs_type, rhs_type),
-        (_, Decimal128(_, _)) => get_comparison_common_decimal_type(rhs_type, lhs_type),
+        (
+            d1 @ (Decimal128(_, _) | Decimal256(_, _)),
+            d2 @ (Decimal128(_, _) | Decimal256(_, _)),
+        ) => get_wider_decimal_type(d1, d2),
+        (Decimal128(_, _) | Decimal256(_, _), _) => {
+            get_comparison_common_decimal_type(lhs_type, rhs_type)
+        }
+        (_, Decimal128(_, _) | Decimal256(_, _)) => {
+            get_comparison_common_decimal_type(rhs_type, lhs_type)
+        }
         (Float64, _) | (_, Float64) => Some(Float64),
         (_, Float32) | (Float32, _) => Some(Float32),
         // The following match arms encode the following logic: Given the two
@@ -326,31 +391,37 @@ fn get_comparison_common_decimal_type(
             return None;
         }
     };
-    match (decimal_type, &other_decimal_type) {
-        (d1 @ DataType::Decimal128(_, _), d2 @ DataType::Decimal128(_, _)) => {
-            get_wider_decimal_type(d1, d2)
-        }
+    get_wider_decimal_type(decimal_type, other_decimal_type)
+}
+
+/// Returns the precision and scale of a `Decimal128` or `Decimal256` type
+fn decimal_precision_scale(data_type: &DataType) -> Option<(u8, i8)> {
+    match data_type {
+        DataType::Decimal128(p, s) | DataType::Decimal256(p, s) => Some((*p, *s)),
         _ => None,
     }
 }
 
 /// Returns a `DataType::Decimal128` that can store any value from either
-/// `lhs_decimal_type` and `rhs_decimal_type`
+/// `lhs_decimal_type` and `rhs_decimal_type`, or a `DataType::Decimal256`
+/// if either of them is a `DataType::Decimal256`
 ///
 /// The result decimal type is `(max(s1, s2) + max(p1-s1, p2-s2), max(s1, s2))`.
 fn get_wider_decimal_type(
     lhs_decimal_type: &DataType,
     rhs_type: &DataType,
 ) -> Option<DataType> {
-    match (lhs_decimal_type, rhs_type) {
-        (DataType::Decimal128(p1, s1), DataType::Decimal128(p2, s2)) => {
-            // max(s1, s2) + max(p1-s1, p2-s2), max(s1, s2)
-            let s = *s1.max(s2);
-            let range = (*p1 as i8 - s1).max(*p2 as i8 - s2);
-            Some(create_decimal_type((range + s) as u8, s))
-        }
-        (_, _) => None,
-    }
+    let (p1, s1) = decimal_precision_scale(lhs_decimal_type)?;
+    let (p2, s2) = decimal_precision_scale(rhs_type)?;
+    // max(s1, s2) + max(p1-s1, p2-s2), max(s1, s2)
+    let s = s1.max(s2);
+    let range = (p1 as i8 - s1).max(p2 as i8 - s2);
+    Some(create_decimal_type_for(
+        lhs_decimal_type,
+        rhs_type,
+        (range + s) as u8,
+        s,
+    ))
 }
 
 /// Convert the numeric data type to the decimal data type.
@@ -395,10 +466,11 @@ fn mathematics_numerical_coercion(
     // these are ordered from most informative to least informative so
     // that the coercion removes the least amount of information
     match (lhs_type, rhs_type) {
-        (Decimal128(_, _), Decimal128(_, _)) => {
+        (Decimal128(_, _) | Decimal256(_, _), Decimal128(_, _) | Decimal256(_, _)) => {
             coercion_decimal_mathematics_type(mathematics_op, lhs_type, rhs_type)
         }
-        (Null, dec_type @ Decimal128(_, _)) | (dec_type @ Decimal128(_, _), Null) => {
+        (Null, dec_type @ (Decimal128(_, _) | Decimal256(_, _)))
+        | (dec_type @ (Decimal128(_, _) | Decimal256(_, _)), Null) => {
             Some(dec_type.clone())
         }
         (Dictionary(_, lhs_value_type), Dictionary(_, rhs_value_type)) => {
@@ -413,9 +485,9 @@ fn mathematics_numerical_coercion(
         (_, Dictionary(_, value_type)) => {
             mathematics_numerical_coercion(mathematics_op, lhs_type, value_type)
         }
-        (Decimal128(_, _), Float32 | Float64) => Some(Float64),
-        (Float32 | Float64, Decimal128(_, _)) => Some(Float64),
-        (Decimal128(_, _), _) => {
+        (Decimal128(_, _) | Decimal256(_, _), Float32 | Float64) => Some(Float64),
+        (Float32 | Float64, Decimal128(_, _) | Decimal256(_, _)) => Some(Float64),
+        (Decimal128(_, _) | Decimal256(_, _), _) => {
             let converted_decimal_type = coerce_numeric_type_to_decimal(rhs_type);
             match converted_decimal_type {
                 None => None,
@@ -426,7 +498,7 @@ fn mathematics_numerical_coercion(
                 ),
             }
         }
-        (_, Decimal128(_, _)) => {
+        (_, Decimal128(_, _) | Decimal256(_, _)) => {
             let converted_decimal_type = coerce_numeric_type_to_decimal(lhs_type);
             match converted_decimal_type {
                 None => None,
@@ -458,49 +530,78 @@ fn create_decimal_type(precision: u8, scale: i8) -> DataType {
     )
 }
 
+fn create_decimal256_type(precision: u8, scale: i8) -> DataType {
+    DataType::Decimal256(
+        DECIMAL256_MAX_PRECISION.min(precision),
+        DECIMAL256_MAX_SCALE.min(scale),
+    )
+}
+
+/// Creates the result decimal type of combining `lhs_type` and `rhs_type`:
+/// a `Decimal256` if either side is a `Decimal256`, `Decimal128` otherwise
+fn create_decimal_type_for(
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+    precision: u8,
+    scale: i8,
+) -> DataType {
+    if matches!(lhs_type, DataType::Decimal256(_, _))
+        || matches!(rhs_type, DataType::Decimal256(_, _))
+    {
+        create_decimal256_type(precision, scale)
+    } else {
+        create_decimal_type(precision, scale)
+    }
+}
+
 fn coercion_decimal_mathematics_type(
     mathematics_op: &Operator,
     left_decimal_type: &DataType,
     right_decimal_type: &DataType,
 ) -> Option<DataType> {
-    use arrow::datatypes::DataType::*;
-    match (left_decimal_type, right_decimal_type) {
-        // The coercion rule from spark
-        // https://github.com/apache/spark/blob/c20af535803a7250fef047c2bf0fe30be242369d/sql/catalyst/src/main/scala/org/apache/spark/sql/catalyst/analysis/DecimalPrecision.scala#L35
-        (Decimal128(p1, s1), Decimal128(p2, s2)) => {
-            match mathematics_op {
-                Operator::Plus | Operator::Minus => {
-                    // max(s1, s2)
-                    let result_scale = *s1.max(s2);
-                    // max(s1, s2) + max(p1-s1, p2-s2) + 1
-                    let result_precision =
-                        result_scale + (*p1 as i8 - *s1).max(*p2 as i8 - *s2) + 1;
-                    Some(create_decimal_type(result_precision as u8, result_scale))
-                }
-                Operator::Multiply => {
-                    // s1 + s2
-                    let result_scale = *s1 + *s2;
-                    // p1 + p2 + 1
-                    let result_precision = *p1 + *p2 + 1;
-                    Some(create_decimal_type(result_precision, result_scale))
-                }
-                Operator::Divide => {
-                    // max(6, s1 + p2 + 1)
-                    let result_scale = 6.max(*s1 + *p2 as i8 + 1);
-                    // p1 - s1 + s2 + max(6, s1 + p2 + 1)
-                    let result_precision = result_scale + *p1 as i8 - *s1 + *s2;
-                    Some(create_decimal_type(result_precision as u8, result_scale))
-                }
-                Operator::Modulo => {
-                    // max(s1, s2)
-                    let result_scale = *s1.max(s2);
-                    // min(p1-s1, p2-s2) + max(s1, s2)
-                    let result_precision =
-                        result_scale + (*p1 as i8 - *s1).min(*p2 as i8 - *s2);
-                    Some(create_decimal_type(result_precision as u8, result_scale))
-                }
-                _ => None,
-            }
+    let (p1, s1) = decimal_precision_scale(left_decimal_type)?;
+    let (p2, s2) = decimal_precision_scale(right_decimal_type)?;
+    // widen to i16 so that the intermediate results of `Decimal256`
+    // precisions and scales don't overflow
+    let (p1, s1, p2, s2) = (p1 as i16, s1 as i16, p2 as i16, s2 as i16);
+    let create_decimal_type = |precision: i16, scale: i16| {
+        create_decimal_type_for(
+            left_decimal_type,
+            right_decimal_type,
+            precision.min(DECIMAL256_MAX_PRECISION as i16) as u8,
+            scale.min(DECIMAL256_MAX_SCALE as i16) as i8,
+        )
+    };
+    // The coercion rule from spark
+    // https://github.com/apache/spark/blob/c20af535803a7250fef047c2bf0fe30be242369d/sql/catalyst/src/main/scala/org/apache/spark/sql/catalyst/analysis/DecimalPrecision.scala#L35
+    match mathematics_op {
+        Operator::Plus | Operator::Minus => {
+            // max(s1, s2)
+            let result_scale = s1.max(s2);
+            // max(s1, s2) + max(p1-s1, p2-s2) + 1
+            let result_precision = result_scale + (p1 - s1).max(p2 - s2) + 1;
+            Some(create_decimal_type(result_precision, result_scale))
+        }
+        Operator::Multiply => {
+            // s1 + s2
+            let result_scale = s1 + s2;
+            // p1 + p2 + 1
+            let result_precision = p1 + p2 + 1;
+            Some(create_decimal_type(result_precision, result_scale))
+        }
+        Operator::Divide => {
+            // max(6, s1 + p2 + 1)
+            let result_scale = 6.max(s1 + p2 + 1);
+            // p1 - s1 + s2 + max(6, s1 + p2 + 1)
+            let result_precision = result_scale + p1 - s1 + s2;
+            Some(create_decimal_type(result_precision, result_scale))
+        }
+        Operator::Modulo => {
+            // max(s1, s2)
+            let result_scale = s1.max(s2);
+            // min(p1-s1, p2-s2) + max(s1, s2)
+            let result_precision = result_scale + (p1 - s1).min(p2 - s2);
+            Some(create_decimal_type(result_precision, result_scale))
         }
         _ => None,
     }
@@ -890,6 +991,54 @@ mod tests {
         assert_eq!(DataType::Decimal128(11, 4), result.unwrap());
     }
 
+    #[test]
+    fn test_decimal256_mathematics_op_type() {
+        // mixing Decimal128 and Decimal256 widens to Decimal256
+        let left_decimal_type = DataType::Decimal128(10, 3);
+        let right_decimal_type = DataType::Decimal256(50, 4);
+        let result = coercion_decimal_mathematics_type(
+            &Operator::Plus,
+            &left_decimal_type,
+            &right_decimal_type,
+        );
+        assert_eq!(DataType::Decimal256(51, 4), result.unwrap());
+        let result = coercion_decimal_mathematics_type(
+            &Operator::Multiply,
+            &left_decimal_type,
+            &right_decimal_type,
+        );
+        assert_eq!(DataType::Decimal256(61, 7), result.unwrap());
+
+        // precision is capped at 76 digits
+        let result = coercion_decimal_mathematics_type(
+            &Operator::Multiply,
+            &DataType::Decimal256(60, 2),
+            &DataType::Decimal256(60, 2),
+        );
+        assert_eq!(DataType::Decimal256(76, 4), result.unwrap());
+
+        // integers are coerced to decimal first
+        let result = mathematics_numerical_coercion(
+            &Operator::Plus,
+            &DataType::Int64,
+            &DataType::Decimal256(50, 4),
+        );
+        assert_eq!(Some(DataType::Decimal256(51, 4)), result);
+
+        // comparison finds a common type for both sides
+        assert_eq!(
+            comparison_coercion(
+                &DataType::Decimal128(20, 3),
+                &DataType::Decimal256(40, 2)
+            ),
+            Some(DataType::Decimal256(41, 3))
+        );
+        assert_eq!(
+            comparison_coercion(&DataType::Decimal256(40, 2), &DataType::Int32),
+            Some(DataType::Decimal256(40, 2))
+        );
+    }
+
     #[test]
     fn test_dictionary_type_coercion() {
         use DataType::*;
@@ -940,18 +1089,92 @@ mod tests {
     fn test_date_timestamp_arithmetic_error() -> Result<()> {
         let err = coerce_types(
             &DataType::Timestamp(TimeUnit::Nanosecond, None),
-            &Operator::Minus,
+            &Operator::Plus,
             &DataType::Timestamp(TimeUnit::Nanosecond, None),
         )
         .unwrap_err()
         .to_string();
-        assert_contains!(&err, "'Timestamp(Nanosecond, None) - Timestamp(Nanosecond, None)' is an unsupported operation. addition/subtraction on dates/timestamps only supported with interval types");
+        assert_contains!(&err, "'Timestamp(Nanosecond, None) + Timestamp(Nanosecond, None)' is an unsupported operation. addition/subtraction on dates/timestamps only supported with interval or duration types");
 
         let err = coerce_types(&DataType::Date32, &Operator::Plus, &DataType::Date64)
             .unwrap_err()
             .to_string();
-        assert_contains!(&err, "'Date32 + Date64' is an unsupported operation. addition/subtraction on dates/timestamps only supported with interval types");
+        assert_contains!(&err, "'Date32 + Date64' is an unsupported operation. addition/subtraction on dates/timestamps only supported with interval or duration types");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_duration_arithmetic() -> Result<()> {
+        test_coercion_binary_rule!(
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+            Operator::Minus,
+            DataType::Duration(TimeUnit::Nanosecond)
+        );
+        test_coercion_binary_rule!(
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+            Operator::Minus,
+            DataType::Duration(TimeUnit::Millisecond)
+        );
+        test_coercion_binary_rule!(
+            DataType::Timestamp(TimeUnit::Second, None),
+            DataType::Duration(TimeUnit::Millisecond),
+            Operator::Minus,
+            DataType::Timestamp(TimeUnit::Second, None)
+        );
+        test_coercion_binary_rule!(
+            DataType::Duration(TimeUnit::Second),
+            DataType::Timestamp(TimeUnit::Second, None),
+            Operator::Plus,
+            DataType::Timestamp(TimeUnit::Second, None)
+        );
+        test_coercion_binary_rule!(
+            DataType::Duration(TimeUnit::Second),
+            DataType::Duration(TimeUnit::Second),
+            Operator::Minus,
+            DataType::Duration(TimeUnit::Second)
+        );
+        test_coercion_binary_rule!(
+            DataType::Duration(TimeUnit::Microsecond),
+            DataType::Int32,
+            Operator::Multiply,
+            DataType::Duration(TimeUnit::Microsecond)
+        );
+        test_coercion_binary_rule!(
+            DataType::Int64,
+            DataType::Duration(TimeUnit::Microsecond),
+            Operator::Multiply,
+            DataType::Duration(TimeUnit::Microsecond)
+        );
+        test_coercion_binary_rule!(
+            DataType::Duration(TimeUnit::Microsecond),
+            DataType::Int64,
+            Operator::Divide,
+            DataType::Duration(TimeUnit::Microsecond)
+        );
 
+        // durations can't be subtracted from timestamps the other way round
+        // or combined across units
+        assert!(coerce_types(
+            &DataType::Duration(TimeUnit::Second),
+            &Operator::Minus,
+            &DataType::Timestamp(TimeUnit::Second, None),
+        )
+        .is_err());
+        assert!(coerce_types(
+            &DataType::Duration(TimeUnit::Second),
+            &Operator::Plus,
+            &DataType::Duration(TimeUnit::Millisecond),
+        )
+        .is_err());
+        assert!(coerce_types(
+            &DataType::Int64,
+            &Operator::Divide,
+            &DataType::Duration(TimeUnit::Second),
+        )
+        .is_err());
         Ok(())
     }
 
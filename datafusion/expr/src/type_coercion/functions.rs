@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::signature::TIMEZONE_WILDCARD;
 use crate::{Signature, TypeSignature};
 use arrow::{
     compute::can_cast_types,
@@ -36,7 +37,10 @@ pub fn data_types(
     if current_types.is_empty() {
         return Ok(vec![]);
     }
-    let valid_types = get_valid_types(&signature.type_signature, current_types)?;
+    let valid_types = get_valid_types(&signature.type_signature, current_types)?
+        .into_iter()
+        .map(|valid_types| resolve_timezone_wildcard(valid_types, current_types))
+        .collect::<Vec<_>>();
 
     if valid_types
         .iter()
@@ -99,6 +103,34 @@ fn get_valid_types(
     Ok(valid_types)
 }
 
+/// Replaces any [`TIMEZONE_WILDCARD`] timezone in `valid_types` with the
+/// timezone of the corresponding argument in `current_types`, so that
+/// timezone aware timestamps keep their timezone instead of being cast.
+fn resolve_timezone_wildcard(
+    valid_types: Vec<DataType>,
+    current_types: &[DataType],
+) -> Vec<DataType> {
+    if valid_types.len() != current_types.len() {
+        return valid_types;
+    }
+
+    valid_types
+        .into_iter()
+        .zip(current_types)
+        .map(
+            |(valid_type, current_type)| match (valid_type, current_type) {
+                (
+                    DataType::Timestamp(unit, Some(tz)),
+                    DataType::Timestamp(_, Some(current_tz)),
+                ) if tz == TIMEZONE_WILDCARD => {
+                    DataType::Timestamp(unit, Some(current_tz.clone()))
+                }
+                (valid_type, _) => valid_type,
+            },
+        )
+        .collect()
+}
+
 /// Try to coerce current_types into valid_types.
 fn maybe_data_types(
     valid_types: &[DataType],
@@ -175,6 +207,7 @@ pub fn can_coerce_from(type_into: &DataType, type_from: &DataType) -> bool {
                 | Float32
                 | Float64
                 | Decimal128(_, _)
+                | Decimal256(_, _)
         ),
         Timestamp(TimeUnit::Nanosecond, _) => {
             matches!(
@@ -256,4 +289,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_data_types_timezone_wildcard() -> Result<()> {
+        let signature = Signature::one_of(
+            vec![
+                TypeSignature::Exact(vec![
+                    DataType::Utf8,
+                    DataType::Timestamp(TimeUnit::Nanosecond, None),
+                ]),
+                TypeSignature::Exact(vec![
+                    DataType::Utf8,
+                    DataType::Timestamp(
+                        TimeUnit::Nanosecond,
+                        Some(TIMEZONE_WILDCARD.to_string()),
+                    ),
+                ]),
+            ],
+            crate::Volatility::Immutable,
+        );
+
+        let tz = DataType::Timestamp(
+            TimeUnit::Nanosecond,
+            Some("America/New_York".to_string()),
+        );
+        let coerced = data_types(&[DataType::Utf8, tz.clone()], &signature)?;
+        assert_eq!(coerced, vec![DataType::Utf8, tz]);
+
+        let coerced = data_types(&[DataType::Utf8, DataType::Utf8], &signature)?;
+        assert_eq!(
+            coerced,
+            vec![
+                DataType::Utf8,
+                DataType::Timestamp(TimeUnit::Nanosecond, None)
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -730,6 +730,10 @@ pub fn from_plan(
         | LogicalPlan::CreateExternalTable(_)
         | LogicalPlan::DropTable(_)
         | LogicalPlan::DropView(_)
+        | LogicalPlan::CreateFunction(_)
+        | LogicalPlan::DropFunction(_)
+        | LogicalPlan::AlterTable(_)
+        | LogicalPlan::AlterView(_)
         | LogicalPlan::SetVariable(_)
         | LogicalPlan::CreateCatalogSchema(_)
         | LogicalPlan::CreateCatalog(_) => {
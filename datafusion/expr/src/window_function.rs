@@ -112,27 +112,40 @@ pub enum BuiltInWindowFunction {
     NthValue,
 }
 
+/// The built-in window functions by the names they are called by
+static NAME_TO_FUNCTION: &[(&str, BuiltInWindowFunction)] = &[
+    ("row_number", BuiltInWindowFunction::RowNumber),
+    ("rank", BuiltInWindowFunction::Rank),
+    ("dense_rank", BuiltInWindowFunction::DenseRank),
+    ("percent_rank", BuiltInWindowFunction::PercentRank),
+    ("cume_dist", BuiltInWindowFunction::CumeDist),
+    ("ntile", BuiltInWindowFunction::Ntile),
+    ("lag", BuiltInWindowFunction::Lag),
+    ("lead", BuiltInWindowFunction::Lead),
+    ("first_value", BuiltInWindowFunction::FirstValue),
+    ("last_value", BuiltInWindowFunction::LastValue),
+    ("nth_value", BuiltInWindowFunction::NthValue),
+];
+
+impl BuiltInWindowFunction {
+    /// Returns the built-in window functions by the names they are called by
+    pub fn by_name() -> &'static [(&'static str, BuiltInWindowFunction)] {
+        NAME_TO_FUNCTION
+    }
+}
+
 impl FromStr for BuiltInWindowFunction {
     type Err = DataFusionError;
     fn from_str(name: &str) -> Result<BuiltInWindowFunction> {
-        Ok(match name.to_uppercase().as_str() {
-            "ROW_NUMBER" => BuiltInWindowFunction::RowNumber,
-            "RANK" => BuiltInWindowFunction::Rank,
-            "DENSE_RANK" => BuiltInWindowFunction::DenseRank,
-            "PERCENT_RANK" => BuiltInWindowFunction::PercentRank,
-            "CUME_DIST" => BuiltInWindowFunction::CumeDist,
-            "NTILE" => BuiltInWindowFunction::Ntile,
-            "LAG" => BuiltInWindowFunction::Lag,
-            "LEAD" => BuiltInWindowFunction::Lead,
-            "FIRST_VALUE" => BuiltInWindowFunction::FirstValue,
-            "LAST_VALUE" => BuiltInWindowFunction::LastValue,
-            "NTH_VALUE" => BuiltInWindowFunction::NthValue,
-            _ => {
-                return Err(DataFusionError::Plan(format!(
+        NAME_TO_FUNCTION
+            .iter()
+            .find(|(function_name, _)| function_name.eq_ignore_ascii_case(name))
+            .map(|(_, function)| function.clone())
+            .ok_or_else(|| {
+                DataFusionError::Plan(format!(
                     "There is no built-in window function named {name}"
-                )))
-            }
-        })
+                ))
+            })
     }
 }
 
@@ -218,6 +231,7 @@ pub fn signature_for_built_in(fun: &BuiltInWindowFunction) -> Signature {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::BuiltinScalarFunction;
 
     #[test]
     fn test_count_return_type() -> Result<()> {
@@ -384,4 +398,23 @@ mod tests {
         );
         assert_eq!(find_df_window_func("not_exist"), None)
     }
+
+    #[test]
+    fn test_function_names() {
+        for (name, fun) in BuiltinScalarFunction::by_name() {
+            assert_eq!(&BuiltinScalarFunction::from_str(name).unwrap(), fun);
+        }
+        for (name, fun) in AggregateFunction::by_name() {
+            assert_eq!(
+                find_df_window_func(name),
+                Some(WindowFunction::AggregateFunction(fun.clone()))
+            );
+        }
+        for (name, fun) in BuiltInWindowFunction::by_name() {
+            assert_eq!(
+                find_df_window_func(name),
+                Some(WindowFunction::BuiltInWindowFunction(fun.clone()))
+            );
+        }
+    }
 }
@@ -236,6 +236,10 @@ impl OptimizerRule for CommonSubexprEliminate {
             | LogicalPlan::CreateCatalog(_)
             | LogicalPlan::DropTable(_)
             | LogicalPlan::DropView(_)
+            | LogicalPlan::CreateFunction(_)
+            | LogicalPlan::DropFunction(_)
+            | LogicalPlan::AlterTable(_)
+            | LogicalPlan::AlterView(_)
             | LogicalPlan::SetVariable(_)
             | LogicalPlan::DescribeTable(_)
             | LogicalPlan::Distinct(_)
@@ -721,6 +721,7 @@ impl OptimizerRule for PushDownFilter {
                 let new_scan = LogicalPlan::TableScan(TableScan {
                     source: scan.source.clone(),
                     projection: scan.projection.clone(),
+                    nested_projection: scan.nested_projection.clone(),
                     projected_schema: scan.projected_schema.clone(),
                     table_name: scan.table_name.clone(),
                     filters: new_scan_filters,
@@ -2088,6 +2089,7 @@ mod tests {
                 (*test_provider.schema()).clone(),
             )?),
             projection: None,
+            nested_projection: None,
             source: Arc::new(test_provider),
             fetch: None,
         });
@@ -2160,6 +2162,7 @@ mod tests {
                 (*test_provider.schema()).clone(),
             )?),
             projection: Some(vec![0]),
+            nested_projection: None,
             source: Arc::new(test_provider),
             fetch: None,
         });
@@ -2189,6 +2192,7 @@ mod tests {
                 (*test_provider.schema()).clone(),
             )?),
             projection: Some(vec![0]),
+            nested_projection: None,
             source: Arc::new(test_provider),
             fetch: None,
         });
@@ -122,6 +122,7 @@ impl OptimizerRule for PushDownLimit {
                         table_name: scan.table_name.clone(),
                         source: scan.source.clone(),
                         projection: scan.projection.clone(),
+                        nested_projection: scan.nested_projection.clone(),
                         filters: scan.filters.clone(),
                         fetch: scan.fetch.map(|x| min(x, limit)).or(Some(limit)),
                         projected_schema: scan.projected_schema.clone(),
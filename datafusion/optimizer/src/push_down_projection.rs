@@ -23,11 +23,14 @@ use crate::optimizer::ApplyOrder;
 use crate::push_down_filter::replace_cols_by_name;
 use crate::{OptimizerConfig, OptimizerRule};
 use arrow::error::Result as ArrowResult;
+use datafusion_common::utils::project_nested_fields;
 use datafusion_common::ScalarValue::UInt8;
 use datafusion_common::{
-    Column, DFField, DFSchema, DFSchemaRef, DataFusionError, Result, ToDFSchema,
+    Column, DFField, DFSchema, DFSchemaRef, DataFusionError, Result, ScalarValue,
+    ToDFSchema,
 };
-use datafusion_expr::expr::AggregateFunction;
+use datafusion_expr::expr::{AggregateFunction, GetIndexedField};
+use datafusion_expr::expr_visitor::{ExprVisitable, ExpressionVisitor, Recursion};
 use datafusion_expr::utils::exprlist_to_fields;
 use datafusion_expr::{
     logical_plan::{Aggregate, LogicalPlan, Projection, TableScan, Union},
@@ -36,7 +39,8 @@ use datafusion_expr::{
 };
 use std::collections::HashMap;
 use std::{
-    collections::{BTreeSet, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
+    iter,
     sync::Arc,
 };
 
@@ -81,7 +85,7 @@ impl OptimizerRule for PushDownProjection {
                 return Ok(Some(plan.with_new_inputs(&[optimized_child])?));
             }
             LogicalPlan::TableScan(scan) if scan.projection.is_none() => {
-                return Ok(Some(push_down_scan(&HashSet::new(), scan, false)?));
+                return Ok(Some(push_down_scan(&HashSet::new(), scan, false, vec![])?));
             }
             _ => return Ok(None),
         };
@@ -178,12 +182,17 @@ impl OptimizerRule for PushDownProjection {
                 if projection_is_empty {
                     used_columns
                         .insert(scan.projected_schema.fields()[0].qualified_column());
-                    push_down_scan(&used_columns, scan, true)?
+                    push_down_scan(&used_columns, scan, true, vec![])?
                 } else {
                     for expr in projection.expr.iter() {
                         expr_to_columns(expr, &mut used_columns)?;
                     }
-                    let new_scan = push_down_scan(&used_columns, scan, true)?;
+                    let nested_paths = nested_field_paths(
+                        projection.expr.iter().chain(scan.filters.iter()),
+                        scan,
+                    )?;
+                    let new_scan =
+                        push_down_scan(&used_columns, scan, true, nested_paths)?;
 
                     plan.with_new_inputs(&[new_scan])?
                 }
@@ -324,7 +333,21 @@ impl OptimizerRule for PushDownProjection {
                 }
             }
             LogicalPlan::Filter(filter) => {
-                if can_eliminate(projection, child_plan.schema()) {
+                let nested_scan = match filter.input.as_ref() {
+                    LogicalPlan::TableScan(scan) if !projection_is_empty => {
+                        let exprs =
+                            projection.expr.iter().chain(iter::once(&filter.predicate));
+                        push_down_nested_scan(exprs, scan)?
+                    }
+                    _ => None,
+                };
+
+                if let Some(new_scan) = nested_scan {
+                    // struct columns of the scan are only read with the
+                    // nested fields used by the filter and the projection
+                    let new_filter = child_plan.with_new_inputs(&[new_scan])?;
+                    plan.with_new_inputs(&[new_filter])?
+                } else if can_eliminate(projection, child_plan.schema()) {
                     // when projection schema == filter schema, we can commute directly.
                     let new_proj =
                         plan.with_new_inputs(&[filter.input.as_ref().clone()])?;
@@ -479,10 +502,116 @@ fn generate_projection(
     Ok(LogicalPlan::Projection(Projection::try_new(expr, input)?))
 }
 
+/// Returns the column and the path of nested struct fields accessed by
+/// `expr`, if it is a column or a chain of `GetIndexedField` with field
+/// names on a column
+fn field_access(expr: &Expr) -> Option<(&Column, Vec<String>)> {
+    match expr {
+        Expr::Column(column) => Some((column, vec![])),
+        Expr::GetIndexedField(GetIndexedField {
+            expr,
+            key: ScalarValue::Utf8(Some(name)),
+        }) => {
+            let (column, mut path) = field_access(expr)?;
+            path.push(name.clone());
+            Some((column, path))
+        }
+        _ => None,
+    }
+}
+
+/// Collects the columns referenced by expressions, along with the path of
+/// the nested struct fields accessed through `GetIndexedField`. An empty
+/// path means that the whole column is used
+#[derive(Default)]
+struct FieldAccessVisitor {
+    accesses: Vec<(Column, Vec<String>)>,
+}
+
+impl ExpressionVisitor for FieldAccessVisitor {
+    fn pre_visit(mut self, expr: &Expr) -> Result<Recursion<Self>> {
+        match field_access(expr) {
+            Some((column, path)) => {
+                self.accesses.push((column.clone(), path));
+                Ok(Recursion::Stop(self))
+            }
+            None => Ok(Recursion::Continue(self)),
+        }
+    }
+}
+
+/// Returns the paths of the nested fields of the struct columns of `scan`
+/// that `exprs` only access through `GetIndexedField`, each starting with
+/// the name of the column, e.g. `["payload", "user", "id"]` for
+/// `payload['user']['id']`.
+///
+/// Returns no paths if the source of the scan can not read nested fields
+fn nested_field_paths<'a>(
+    exprs: impl IntoIterator<Item = &'a Expr>,
+    scan: &TableScan,
+) -> Result<Vec<Vec<String>>> {
+    if !scan.source.supports_nested_projection() {
+        return Ok(vec![]);
+    }
+
+    let mut visitor = FieldAccessVisitor::default();
+    for expr in exprs {
+        visitor = expr.accept(visitor)?;
+    }
+
+    // the paths of each column, `None` if the column is used whole
+    let mut columns: BTreeMap<String, Option<BTreeSet<Vec<String>>>> = BTreeMap::new();
+    for (column, path) in visitor.accesses {
+        if column.relation.is_some() && column.relation.as_ref() != Some(&scan.table_name)
+        {
+            continue;
+        }
+        let paths = columns
+            .entry(column.name)
+            .or_insert_with(|| Some(BTreeSet::new()));
+        if path.is_empty() {
+            *paths = None;
+        } else if let Some(paths) = paths {
+            paths.insert(path);
+        }
+    }
+
+    Ok(columns
+        .into_iter()
+        .flat_map(|(name, paths)| {
+            paths
+                .into_iter()
+                .flatten()
+                .map(move |path| iter::once(name.clone()).chain(path).collect::<Vec<_>>())
+        })
+        .collect())
+}
+
+/// Pushes the projection of `exprs` and of the filters of `scan` into
+/// `scan`, if they access some struct columns of the scan only through
+/// their nested fields
+fn push_down_nested_scan<'a>(
+    exprs: impl Iterator<Item = &'a Expr>,
+    scan: &'a TableScan,
+) -> Result<Option<LogicalPlan>> {
+    let exprs: Vec<&Expr> = exprs.chain(scan.filters.iter()).collect();
+    let nested_paths = nested_field_paths(exprs.iter().copied(), scan)?;
+    if nested_paths.is_empty() {
+        return Ok(None);
+    }
+
+    let mut used_columns = HashSet::new();
+    for expr in exprs {
+        expr_to_columns(expr, &mut used_columns)?;
+    }
+    push_down_scan(&used_columns, scan, true, nested_paths).map(Some)
+}
+
 fn push_down_scan(
     used_columns: &HashSet<Column>,
     scan: &TableScan,
     has_projection: bool,
+    nested_paths: Vec<Vec<String>>,
 ) -> Result<LogicalPlan> {
     // once we reach the table scan, we can use the accumulated set of column
     // names to construct the set of column indexes in the scan
@@ -533,6 +662,22 @@ fn push_down_scan(
         projection.into_iter().collect::<Vec<_>>()
     };
 
+    // only keep the nested paths of the projected struct columns
+    let nested_paths: Vec<Vec<String>> = nested_paths
+        .into_iter()
+        .filter(|path| {
+            projection
+                .iter()
+                .any(|i| schema.field(*i).name() == &path[0])
+        })
+        .collect();
+    let (schema, nested_projection) = if nested_paths.is_empty() {
+        (schema, None)
+    } else {
+        let schema = Arc::new(project_nested_fields(&schema, &nested_paths));
+        (schema, Some(nested_paths))
+    };
+
     // create the projected schema
     let projected_fields: Vec<DFField> = projection
         .iter()
@@ -545,6 +690,7 @@ fn push_down_scan(
         table_name: scan.table_name.clone(),
         source: scan.source.clone(),
         projection: Some(projection),
+        nested_projection,
         projected_schema,
         filters: scan.filters.clone(),
         fetch: scan.fetch,
@@ -558,14 +704,14 @@ mod tests {
     use crate::optimizer::Optimizer;
     use crate::test::*;
     use crate::OptimizerContext;
-    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
     use datafusion_common::DFSchema;
     use datafusion_expr::expr;
     use datafusion_expr::expr::Cast;
     use datafusion_expr::{
         col, count, lit,
         logical_plan::{builder::LogicalPlanBuilder, table_scan, JoinType},
-        max, min, AggregateFunction, Expr,
+        max, min, AggregateFunction, Expr, TableSource,
     };
     use std::collections::HashMap;
 
@@ -1051,6 +1197,92 @@ mod tests {
         assert_optimized_plan_eq(&plan, expected)
     }
 
+    struct NestedProvider {}
+
+    impl TableSource for NestedProvider {
+        fn schema(&self) -> SchemaRef {
+            let payload = DataType::Struct(vec![
+                Field::new("x", DataType::Int32, true),
+                Field::new("y", DataType::Utf8, true),
+                Field::new("z", DataType::Int32, true),
+            ]);
+            Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("payload", payload, true),
+            ]))
+        }
+
+        fn supports_nested_projection(&self) -> bool {
+            true
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    fn nested_table_scan() -> Result<LogicalPlan> {
+        let source = NestedProvider {};
+        Ok(LogicalPlan::TableScan(TableScan {
+            table_name: "test".to_string(),
+            filters: vec![],
+            projected_schema: Arc::new(DFSchema::try_from_qualified_schema(
+                "test",
+                &source.schema(),
+            )?),
+            projection: None,
+            nested_projection: None,
+            source: Arc::new(source),
+            fetch: None,
+        }))
+    }
+
+    fn field(expr: Expr, name: &str) -> Expr {
+        Expr::GetIndexedField(GetIndexedField::new(
+            Box::new(expr),
+            ScalarValue::Utf8(Some(name.to_string())),
+        ))
+    }
+
+    #[test]
+    fn nested_projection() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(nested_table_scan()?)
+            .project(vec![col("a"), field(col("payload"), "x")])?
+            .build()?;
+
+        let expected = "Projection: test.a, (test.payload)[x]        \n  TableScan: test projection=[a, payload], nested_projection=[payload.x]";
+        assert_optimized_plan_eq(&plan, expected)?;
+
+        let optimized_plan = optimize(&plan)?;
+        let payload = optimized_plan.inputs()[0].schema().field(1).data_type();
+        assert_eq!(
+            payload,
+            &DataType::Struct(vec![Field::new("x", DataType::Int32, true)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nested_projection_through_filter() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(nested_table_scan()?)
+            .filter(field(col("payload"), "z").gt(lit(1)))?
+            .project(vec![field(col("payload"), "x")])?
+            .build()?;
+
+        let expected = "Projection: (test.payload)[x]        \n  Filter: (test.payload)[z] > Int32(1)        \n    TableScan: test projection=[payload], nested_projection=[payload.x, payload.z]";
+        assert_optimized_plan_eq(&plan, expected)
+    }
+
+    #[test]
+    fn nested_projection_whole_column() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(nested_table_scan()?)
+            .project(vec![field(col("payload"), "x"), col("payload")])?
+            .build()?;
+
+        let expected = "Projection: (test.payload)[x], test.payload        \n  TableScan: test projection=[payload]";
+        assert_optimized_plan_eq(&plan, expected)
+    }
+
     fn assert_optimized_plan_eq(plan: &LogicalPlan, expected: &str) -> Result<()> {
         let optimized_plan = optimize(plan).expect("failed to optimize plan");
         let formatted_plan = format!("{optimized_plan:?}");
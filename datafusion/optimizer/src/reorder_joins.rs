@@ -0,0 +1,842 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Optimizer rule to reorder inner joins based on cardinality estimates
+//! derived from table statistics.
+use std::sync::Arc;
+
+use crate::optimizer::ApplyOrder;
+use crate::{OptimizerConfig, OptimizerRule};
+use datafusion_common::{DFSchema, Result, ScalarValue};
+use datafusion_expr::expr::{Between, BinaryExpr, Cast, Expr, TryCast};
+use datafusion_expr::logical_plan::{
+    CrossJoin, Join, JoinConstraint, JoinType, LogicalPlan, Projection,
+};
+use datafusion_expr::utils::find_valid_equijoin_key_pair;
+use datafusion_expr::{build_join_schema, Operator};
+
+/// Selectivity of an equality predicate when the number of distinct values
+/// of the compared expressions is unknown
+const DEFAULT_EQUALITY_SELECTIVITY: f64 = 0.1;
+/// Selectivity of a range predicate such as `a < 5`
+const DEFAULT_RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+/// Selectivity of `IS NULL`
+const DEFAULT_NULL_SELECTIVITY: f64 = 0.1;
+/// Selectivity of the predicates with no better estimate
+const DEFAULT_SELECTIVITY: f64 = 0.5;
+
+/// Reorders trees of inner joins so that the intermediate results are as
+/// small as possible.
+///
+/// The relations joined by a tree of inner joins and cross joins are
+/// combined greedily: the rule starts with the pair of joined relations that
+/// has the smallest estimated result, then repeatedly joins the relation
+/// that keeps the intermediate result smallest. Relations that are not
+/// connected by any join predicate are cross joined last.
+///
+/// The number of rows and of distinct values of the relations are estimated
+/// from [`TableSource::statistics`](datafusion_expr::TableSource::statistics)
+/// and propagated through filters, projections, aggregates and joins. The
+/// plan is left untouched if any relation has no row count estimate, or if
+/// the new order is not estimated to be cheaper than the current one.
+#[derive(Default)]
+pub struct ReorderJoins;
+
+impl ReorderJoins {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for ReorderJoins {
+    fn try_optimize(
+        &self,
+        plan: &LogicalPlan,
+        config: &dyn OptimizerConfig,
+    ) -> Result<Option<LogicalPlan>> {
+        if !config.options().optimizer.enable_join_reordering || !is_reorderable(plan) {
+            return Ok(None);
+        }
+
+        let mut leaves = vec![];
+        let mut join_keys = vec![];
+        flatten_join_tree(plan, &mut leaves, &mut join_keys);
+        // the physical optimizer already picks the build side of single joins
+        if leaves.len() < 3 {
+            return Ok(None);
+        }
+
+        let current_cost = match estimate_join_tree(plan) {
+            Some((_, cost)) => cost,
+            None => return Ok(None),
+        };
+
+        let inputs = leaves
+            .into_iter()
+            .map(|plan| estimate(&plan).map(|estimate| JoinInput { plan, estimate }))
+            .collect::<Option<Vec<_>>>();
+        let (new_plan, new_cost) = match inputs {
+            Some(inputs) => match greedy_join_order(inputs, &join_keys)? {
+                Some(result) => result,
+                None => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+
+        if new_cost >= current_cost {
+            return Ok(None);
+        }
+
+        if new_plan.schema() != plan.schema() {
+            Ok(Some(LogicalPlan::Projection(Projection::new_from_schema(
+                Arc::new(new_plan),
+                plan.schema().clone(),
+            ))))
+        } else {
+            Ok(Some(new_plan))
+        }
+    }
+
+    fn name(&self) -> &str {
+        "reorder_joins"
+    }
+
+    fn apply_order(&self) -> Option<ApplyOrder> {
+        Some(ApplyOrder::TopDown)
+    }
+}
+
+/// Returns true if the inputs of `plan` can be freely reordered
+fn is_reorderable(plan: &LogicalPlan) -> bool {
+    match plan {
+        LogicalPlan::Join(join) => {
+            join.join_type == JoinType::Inner
+                && join.join_constraint == JoinConstraint::On
+                && join.filter.is_none()
+                && !join.null_equals_null
+        }
+        LogicalPlan::CrossJoin(_) => true,
+        _ => false,
+    }
+}
+
+/// Collects the relations joined by the tree of reorderable joins rooted at
+/// `plan`, and the equijoin keys of these joins
+fn flatten_join_tree(
+    plan: &LogicalPlan,
+    leaves: &mut Vec<LogicalPlan>,
+    join_keys: &mut Vec<(Expr, Expr)>,
+) {
+    if !is_reorderable(plan) {
+        leaves.push(plan.clone());
+        return;
+    }
+    if let LogicalPlan::Join(join) = plan {
+        join_keys.extend(join.on.iter().cloned());
+    }
+    for input in plan.inputs() {
+        flatten_join_tree(input, leaves, join_keys);
+    }
+}
+
+/// Estimated output of a plan
+#[derive(Debug, Clone)]
+struct Estimate {
+    /// Number of rows
+    rows: f64,
+    /// Number of distinct values of each field of the plan schema, if known
+    distinct: Vec<Option<f64>>,
+}
+
+impl Estimate {
+    fn new(rows: f64, distinct: Vec<Option<f64>>) -> Self {
+        // a column can not have more distinct values than there are rows
+        let distinct = distinct
+            .into_iter()
+            .map(|d| d.map(|d| d.min(rows)))
+            .collect();
+        Self { rows, distinct }
+    }
+
+    /// Number of distinct values of `expr`, evaluated against `schema`
+    fn distinct_count(&self, expr: &Expr, schema: &DFSchema) -> Option<f64> {
+        match expr {
+            Expr::Column(column) => schema
+                .index_of_column(column)
+                .ok()
+                .and_then(|i| self.distinct.get(i).copied().flatten()),
+            Expr::Alias(expr, _)
+            | Expr::Cast(Cast { expr, .. })
+            | Expr::TryCast(TryCast { expr, .. }) => self.distinct_count(expr, schema),
+            _ => None,
+        }
+    }
+
+    /// Estimate of the rows matching `predicate`
+    fn filter(self, predicate: &Expr, schema: &DFSchema) -> Self {
+        let rows = self.rows * self.selectivity(predicate, schema);
+        Self::new(rows, self.distinct)
+    }
+
+    fn limit(self, skip: usize, fetch: Option<usize>) -> Self {
+        let rows = (self.rows - skip as f64).max(0.0);
+        let rows = match fetch {
+            Some(fetch) => rows.min(fetch as f64),
+            None => rows,
+        };
+        Self::new(rows, self.distinct)
+    }
+
+    /// Fraction of the rows that match `predicate`
+    fn selectivity(&self, predicate: &Expr, schema: &DFSchema) -> f64 {
+        match predicate {
+            Expr::BinaryExpr(BinaryExpr { left, op, right }) => match op {
+                Operator::And => {
+                    self.selectivity(left, schema) * self.selectivity(right, schema)
+                }
+                Operator::Or => {
+                    let left = self.selectivity(left, schema);
+                    let right = self.selectivity(right, schema);
+                    left + right - left * right
+                }
+                Operator::Eq => {
+                    equality_selectivity(self.distinct_count_of_pair(left, right, schema))
+                }
+                Operator::NotEq => {
+                    1.0 - equality_selectivity(
+                        self.distinct_count_of_pair(left, right, schema),
+                    )
+                }
+                Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+                    DEFAULT_RANGE_SELECTIVITY
+                }
+                _ => DEFAULT_SELECTIVITY,
+            },
+            Expr::Not(expr) => 1.0 - self.selectivity(expr, schema),
+            Expr::IsNull(_) => DEFAULT_NULL_SELECTIVITY,
+            Expr::IsNotNull(_) => 1.0 - DEFAULT_NULL_SELECTIVITY,
+            Expr::Between(Between { negated, .. }) => {
+                let selectivity = DEFAULT_RANGE_SELECTIVITY * DEFAULT_RANGE_SELECTIVITY;
+                if *negated {
+                    1.0 - selectivity
+                } else {
+                    selectivity
+                }
+            }
+            Expr::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let selectivity = (list.len() as f64
+                    * equality_selectivity(self.distinct_count(expr, schema)))
+                .min(1.0);
+                if *negated {
+                    1.0 - selectivity
+                } else {
+                    selectivity
+                }
+            }
+            Expr::Literal(ScalarValue::Boolean(Some(value))) => {
+                if *value {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            _ => DEFAULT_SELECTIVITY,
+        }
+    }
+
+    /// Number of distinct values of the side of `left = right` with the most
+    /// distinct values
+    fn distinct_count_of_pair(
+        &self,
+        left: &Expr,
+        right: &Expr,
+        schema: &DFSchema,
+    ) -> Option<f64> {
+        match (
+            self.distinct_count(left, schema),
+            self.distinct_count(right, schema),
+        ) {
+            (Some(left), Some(right)) => Some(left.max(right)),
+            (Some(distinct), None) | (None, Some(distinct)) => Some(distinct),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Selectivity of an equality with an expression having `distinct` values,
+/// assuming the values are uniformly distributed
+fn equality_selectivity(distinct: Option<f64>) -> f64 {
+    distinct.map_or(DEFAULT_EQUALITY_SELECTIVITY, |d| 1.0 / d.max(1.0))
+}
+
+/// Estimates the output of the inner join of `left` and `right` on `on`
+fn join_estimate(
+    left: &Estimate,
+    right: &Estimate,
+    on: &[(Expr, Expr)],
+    left_schema: &DFSchema,
+    right_schema: &DFSchema,
+) -> Estimate {
+    let cross_rows = left.rows * right.rows;
+    // keys are assumed to be correlated, so only the most selective one is
+    // taken into account
+    let divisor = on
+        .iter()
+        .map(|(l, r)| {
+            match (
+                left.distinct_count(l, left_schema),
+                right.distinct_count(r, right_schema),
+            ) {
+                (Some(l), Some(r)) => l.max(r),
+                (Some(distinct), None) | (None, Some(distinct)) => distinct,
+                // assume the smaller relation is joined on its primary key
+                (None, None) => left.rows.min(right.rows),
+            }
+        })
+        .fold(None, |acc: Option<f64>, d| {
+            Some(acc.map_or(d, |acc| acc.max(d)))
+        });
+    let rows = match divisor {
+        Some(divisor) => cross_rows / divisor.max(1.0),
+        None => cross_rows,
+    };
+    let distinct = left
+        .distinct
+        .iter()
+        .chain(right.distinct.iter())
+        .copied()
+        .collect();
+    Estimate::new(rows, distinct)
+}
+
+/// Estimates the output of `plan`, returning `None` if the plan has an input
+/// without row count estimate
+fn estimate(plan: &LogicalPlan) -> Option<Estimate> {
+    match plan {
+        LogicalPlan::TableScan(scan) => {
+            let statistics = scan.source.statistics()?;
+            let rows = statistics.num_rows? as f64;
+            let column_statistics = statistics.column_statistics.unwrap_or_default();
+            let indices = match &scan.projection {
+                Some(projection) => projection.clone(),
+                None => (0..scan.source.schema().fields().len()).collect(),
+            };
+            let distinct = indices
+                .iter()
+                .map(|i| {
+                    column_statistics
+                        .get(*i)
+                        .and_then(|s| s.distinct_count)
+                        .map(|d| d as f64)
+                })
+                .collect();
+            let estimate = scan
+                .filters
+                .iter()
+                .fold(Estimate::new(rows, distinct), |estimate, filter| {
+                    estimate.filter(filter, &scan.projected_schema)
+                });
+            Some(estimate.limit(0, scan.fetch))
+        }
+        LogicalPlan::Filter(filter) => Some(
+            estimate(&filter.input)?.filter(&filter.predicate, filter.input.schema()),
+        ),
+        LogicalPlan::Projection(projection) => {
+            let input = estimate(&projection.input)?;
+            let distinct = projection
+                .expr
+                .iter()
+                .map(|expr| input.distinct_count(expr, projection.input.schema()))
+                .collect();
+            Some(Estimate::new(input.rows, distinct))
+        }
+        LogicalPlan::SubqueryAlias(alias) => estimate(&alias.input),
+        LogicalPlan::Sort(sort) => estimate(&sort.input),
+        LogicalPlan::Limit(limit) => {
+            Some(estimate(&limit.input)?.limit(limit.skip, limit.fetch))
+        }
+        LogicalPlan::Aggregate(aggregate) => {
+            let input = estimate(&aggregate.input)?;
+            let fields = aggregate.schema.fields().len();
+            if aggregate
+                .group_expr
+                .iter()
+                .any(|expr| matches!(expr, Expr::GroupingSet(_)))
+            {
+                return Some(Estimate::new(input.rows, vec![None; fields]));
+            }
+            let group_distinct = aggregate
+                .group_expr
+                .iter()
+                .map(|expr| input.distinct_count(expr, aggregate.input.schema()))
+                .collect::<Vec<_>>();
+            // every combination of the group values, up to the number of input rows
+            let rows = group_distinct
+                .iter()
+                .map(|d| d.unwrap_or(input.rows))
+                .product::<f64>()
+                .min(input.rows);
+            let distinct = group_distinct
+                .into_iter()
+                .chain(std::iter::repeat(None))
+                .take(fields)
+                .collect();
+            Some(Estimate::new(rows, distinct))
+        }
+        LogicalPlan::Join(join) => {
+            let left = estimate(&join.left)?;
+            let right = estimate(&join.right)?;
+            let inner = join_estimate(
+                &left,
+                &right,
+                &join.on,
+                join.left.schema(),
+                join.right.schema(),
+            );
+            let inner = match &join.filter {
+                Some(filter) => inner.filter(filter, &join.schema),
+                None => inner,
+            };
+            Some(match join.join_type {
+                JoinType::Inner => inner,
+                JoinType::Left => {
+                    Estimate::new(inner.rows.max(left.rows), inner.distinct)
+                }
+                JoinType::Right => {
+                    Estimate::new(inner.rows.max(right.rows), inner.distinct)
+                }
+                JoinType::Full => Estimate::new(
+                    inner.rows.max(left.rows).max(right.rows),
+                    inner.distinct,
+                ),
+                JoinType::LeftSemi => {
+                    Estimate::new(inner.rows.min(left.rows), left.distinct)
+                }
+                JoinType::RightSemi => {
+                    Estimate::new(inner.rows.min(right.rows), right.distinct)
+                }
+                JoinType::LeftAnti => left,
+                JoinType::RightAnti => right,
+            })
+        }
+        LogicalPlan::CrossJoin(join) => {
+            let left = estimate(&join.left)?;
+            let right = estimate(&join.right)?;
+            Some(join_estimate(
+                &left,
+                &right,
+                &[],
+                join.left.schema(),
+                join.right.schema(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Estimates the output of the tree of reorderable joins rooted at `plan`,
+/// and its cost: the sum of the rows produced by the joins of the tree
+fn estimate_join_tree(plan: &LogicalPlan) -> Option<(Estimate, f64)> {
+    let (left, right, on) = match plan {
+        LogicalPlan::Join(join) if is_reorderable(plan) => {
+            (&join.left, &join.right, join.on.as_slice())
+        }
+        LogicalPlan::CrossJoin(join) => (&join.left, &join.right, &[][..]),
+        _ => return estimate(plan).map(|estimate| (estimate, 0.0)),
+    };
+    let (left_estimate, left_cost) = estimate_join_tree(left)?;
+    let (right_estimate, right_cost) = estimate_join_tree(right)?;
+    let estimate = join_estimate(
+        &left_estimate,
+        &right_estimate,
+        on,
+        left.schema(),
+        right.schema(),
+    );
+    let cost = left_cost + right_cost + estimate.rows;
+    Some((estimate, cost))
+}
+
+/// A relation, or a join of relations, with its estimated output
+struct JoinInput {
+    plan: LogicalPlan,
+    estimate: Estimate,
+}
+
+/// Joins `inputs` greedily, returning the join tree and its cost, or `None`
+/// if no pair of inputs is connected by a join key or if some keys could not
+/// be placed in the new tree
+fn greedy_join_order(
+    mut inputs: Vec<JoinInput>,
+    join_keys: &[(Expr, Expr)],
+) -> Result<Option<(LogicalPlan, f64)>> {
+    let mut used = vec![false; join_keys.len()];
+
+    // start with the connected pair with the smallest result
+    let mut best: Option<(usize, usize, f64)> = None;
+    for i in 0..inputs.len() {
+        for j in i + 1..inputs.len() {
+            if let Some(rows) =
+                connected_join_rows(&inputs[i], &inputs[j], join_keys, &used)?
+            {
+                if best.map_or(true, |(_, _, best_rows)| rows < best_rows) {
+                    best = Some((i, j, rows));
+                }
+            }
+        }
+    }
+    let (i, j) = match best {
+        Some((i, j, _)) => (i, j),
+        None => return Ok(None),
+    };
+    let right = inputs.remove(j);
+    let left = inputs.remove(i);
+    let mut current = join(left, right, join_keys, &mut used)?;
+    let mut cost = current.estimate.rows;
+
+    while !inputs.is_empty() {
+        // join the connected input that keeps the intermediate result smallest
+        let mut best: Option<(usize, f64)> = None;
+        for (i, input) in inputs.iter().enumerate() {
+            if let Some(rows) = connected_join_rows(&current, input, join_keys, &used)? {
+                if best.map_or(true, |(_, best_rows)| rows < best_rows) {
+                    best = Some((i, rows));
+                }
+            }
+        }
+        // or cross join the smallest input if none is connected
+        let next = match best {
+            Some((i, _)) => i,
+            None => inputs.iter().enumerate().fold(0, |smallest, (i, input)| {
+                if input.estimate.rows < inputs[smallest].estimate.rows {
+                    i
+                } else {
+                    smallest
+                }
+            }),
+        };
+        let next = inputs.remove(next);
+        current = join(current, next, join_keys, &mut used)?;
+        cost += current.estimate.rows;
+    }
+
+    // keys that span more than two relations may not have found a place
+    if used.iter().any(|used| !used) {
+        return Ok(None);
+    }
+    Ok(Some((current.plan, cost)))
+}
+
+/// Returns the keys not used yet that can join `left` with `right`, with
+/// their position in `join_keys`
+fn find_join_keys(
+    left: &LogicalPlan,
+    right: &LogicalPlan,
+    join_keys: &[(Expr, Expr)],
+    used: &[bool],
+) -> Result<Vec<(usize, (Expr, Expr))>> {
+    let mut keys = vec![];
+    for (i, (l, r)) in join_keys.iter().enumerate() {
+        if used[i] {
+            continue;
+        }
+        if let Some(key) = find_valid_equijoin_key_pair(
+            l,
+            r,
+            left.schema().clone(),
+            right.schema().clone(),
+        )? {
+            keys.push((i, key));
+        }
+    }
+    Ok(keys)
+}
+
+/// Estimated rows of the join of `left` and `right`, if they are connected
+/// by a join key
+fn connected_join_rows(
+    left: &JoinInput,
+    right: &JoinInput,
+    join_keys: &[(Expr, Expr)],
+    used: &[bool],
+) -> Result<Option<f64>> {
+    let on = find_join_keys(&left.plan, &right.plan, join_keys, used)?
+        .into_iter()
+        .map(|(_, key)| key)
+        .collect::<Vec<_>>();
+    if on.is_empty() {
+        return Ok(None);
+    }
+    let estimate = join_estimate(
+        &left.estimate,
+        &right.estimate,
+        &on,
+        left.plan.schema(),
+        right.plan.schema(),
+    );
+    Ok(Some(estimate.rows))
+}
+
+/// Joins `left` and `right` on all the keys that connect them, marking
+/// these keys as used
+fn join(
+    left: JoinInput,
+    right: JoinInput,
+    join_keys: &[(Expr, Expr)],
+    used: &mut [bool],
+) -> Result<JoinInput> {
+    // the smaller input goes on the left, which is the build side of hash joins
+    let (left, right) = if right.estimate.rows < left.estimate.rows {
+        (right, left)
+    } else {
+        (left, right)
+    };
+
+    let on = find_join_keys(&left.plan, &right.plan, join_keys, used)?
+        .into_iter()
+        .map(|(i, key)| {
+            used[i] = true;
+            key
+        })
+        .collect::<Vec<_>>();
+    let estimate = join_estimate(
+        &left.estimate,
+        &right.estimate,
+        &on,
+        left.plan.schema(),
+        right.plan.schema(),
+    );
+    let schema = Arc::new(build_join_schema(
+        left.plan.schema(),
+        right.plan.schema(),
+        &JoinType::Inner,
+    )?);
+
+    let plan = if on.is_empty() {
+        LogicalPlan::CrossJoin(CrossJoin {
+            left: Arc::new(left.plan),
+            right: Arc::new(right.plan),
+            schema,
+        })
+    } else {
+        LogicalPlan::Join(Join {
+            left: Arc::new(left.plan),
+            right: Arc::new(right.plan),
+            join_type: JoinType::Inner,
+            join_constraint: JoinConstraint::On,
+            on,
+            filter: None,
+            schema,
+            null_equals_null: false,
+        })
+    };
+    Ok(JoinInput { plan, estimate })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::OptimizerContext;
+    use crate::test::*;
+    use arrow::datatypes::{Schema, SchemaRef};
+    use datafusion_common::{ColumnStatistics, Statistics};
+    use datafusion_expr::{col, lit, LogicalPlanBuilder, TableSource};
+    use std::any::Any;
+
+    /// A table source with statistics
+    struct StatisticsSource {
+        statistics: Statistics,
+    }
+
+    impl TableSource for StatisticsSource {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn schema(&self) -> SchemaRef {
+            Arc::new(Schema::new(test_table_scan_fields()))
+        }
+
+        fn statistics(&self) -> Option<Statistics> {
+            Some(self.statistics.clone())
+        }
+    }
+
+    /// Scan of a table with columns a, b and c, `rows` rows, and the given
+    /// distinct counts
+    fn scan_with_statistics(
+        name: &str,
+        rows: usize,
+        distinct: [Option<usize>; 3],
+    ) -> Result<LogicalPlanBuilder> {
+        let statistics = Statistics {
+            num_rows: Some(rows),
+            column_statistics: Some(
+                distinct
+                    .iter()
+                    .map(|distinct_count| ColumnStatistics {
+                        distinct_count: *distinct_count,
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+        LogicalPlanBuilder::scan(name, Arc::new(StatisticsSource { statistics }), None)
+    }
+
+    fn optimize(plan: &LogicalPlan) -> Result<Option<LogicalPlan>> {
+        ReorderJoins::new().try_optimize(plan, &OptimizerContext::new())
+    }
+
+    fn assert_optimized_plan_eq(plan: &LogicalPlan, expected: &str) -> Result<()> {
+        let optimized_plan = optimize(plan)?.expect("failed to optimize plan");
+        let formatted = format!("{optimized_plan:?}");
+        assert_eq!(formatted, expected);
+        assert_eq!(plan.schema(), optimized_plan.schema());
+        Ok(())
+    }
+
+    #[test]
+    fn reorder_by_cardinality() -> Result<()> {
+        let t1 = scan_with_statistics("t1", 1000, [Some(1000), Some(100), None])?;
+        let t2 = scan_with_statistics("t2", 100, [Some(100), None, None])?;
+        // 1 row is expected to match the filter
+        let t3 = scan_with_statistics("t3", 10, [None, Some(10), Some(10)])?
+            .filter(col("t3.c").eq(lit(1u32)))?
+            .build()?;
+
+        let plan = t1
+            .join(
+                t2.build()?,
+                JoinType::Inner,
+                (vec!["t1.a"], vec!["t2.a"]),
+                None,
+            )?
+            .join(t3, JoinType::Inner, (vec!["t1.b"], vec!["t3.b"]), None)?
+            .build()?;
+
+        // t1 is joined with the filtered t3 first, which only keeps 10 rows
+        let expected = "Projection: t1.a, t1.b, t1.c, t2.a, t2.b, t2.c, t3.a, t3.b, t3.c\
+        \n  Inner Join: t1.a = t2.a\
+        \n    Inner Join: t3.b = t1.b\
+        \n      Filter: t3.c = UInt32(1)\
+        \n        TableScan: t3\
+        \n      TableScan: t1\
+        \n    TableScan: t2";
+        assert_optimized_plan_eq(&plan, expected)
+    }
+
+    #[test]
+    fn reordered_plan_is_stable() -> Result<()> {
+        let t1 = scan_with_statistics("t1", 1000, [Some(1000), Some(100), None])?;
+        let t2 = scan_with_statistics("t2", 100, [Some(100), None, None])?;
+        let t3 = scan_with_statistics("t3", 10, [None, Some(10), Some(10)])?
+            .filter(col("t3.c").eq(lit(1u32)))?
+            .build()?;
+
+        let plan = t3
+            .join(
+                t1.build()?,
+                JoinType::Inner,
+                (vec!["t3.b"], vec!["t1.b"]),
+                None,
+            )?
+            .join(
+                t2.build()?,
+                JoinType::Inner,
+                (vec!["t1.a"], vec!["t2.a"]),
+                None,
+            )?
+            .build()?;
+
+        assert!(optimize(&plan)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn cross_join_unconnected_relations_last() -> Result<()> {
+        let t1 = scan_with_statistics("t1", 1000, [Some(1000), None, None])?;
+        let t2 = scan_with_statistics("t2", 10, [None, None, None])?;
+        let t3 = scan_with_statistics("t3", 100, [Some(100), None, None])?;
+
+        let plan = t1
+            .cross_join(t2.build()?)?
+            .join(
+                t3.build()?,
+                JoinType::Inner,
+                (vec!["t1.a"], vec!["t3.a"]),
+                None,
+            )?
+            .build()?;
+
+        let expected = "Projection: t1.a, t1.b, t1.c, t2.a, t2.b, t2.c, t3.a, t3.b, t3.c\
+        \n  CrossJoin:\
+        \n    TableScan: t2\
+        \n    Inner Join: t3.a = t1.a\
+        \n      TableScan: t3\
+        \n      TableScan: t1";
+        assert_optimized_plan_eq(&plan, expected)
+    }
+
+    #[test]
+    fn no_reordering_without_statistics() -> Result<()> {
+        let t1 = test_table_scan_with_name("t1")?;
+        let t2 = test_table_scan_with_name("t2")?;
+        let t3 = test_table_scan_with_name("t3")?;
+
+        let plan = LogicalPlanBuilder::from(t1)
+            .join(t2, JoinType::Inner, (vec!["t1.a"], vec!["t2.a"]), None)?
+            .join(t3, JoinType::Inner, (vec!["t1.b"], vec!["t3.b"]), None)?
+            .build()?;
+
+        assert!(optimize(&plan)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn filter_selectivity() -> Result<()> {
+        let estimate = Estimate::new(1000.0, vec![Some(10.0), None, None]);
+        let schema = test_table_scan_with_name("t")?.schema().clone();
+
+        let filtered = estimate.clone().filter(&col("t.a").eq(lit(1u32)), &schema);
+        assert_eq!(filtered.rows, 100.0);
+        assert_eq!(filtered.distinct, vec![Some(10.0), None, None]);
+
+        let filtered = estimate.clone().filter(
+            &col("t.b").eq(lit(1u32)).and(col("t.a").lt(lit(5u32))),
+            &schema,
+        );
+        assert_eq!(
+            filtered.rows,
+            1000.0 * (DEFAULT_EQUALITY_SELECTIVITY * DEFAULT_RANGE_SELECTIVITY)
+        );
+
+        let filtered = estimate.filter(&col("t.a").is_null(), &schema);
+        assert_eq!(filtered.rows, 100.0);
+
+        // distinct counts are capped by the number of rows
+        let estimate = Estimate::new(5.0, vec![Some(10.0), None, Some(2.0)]);
+        assert_eq!(estimate.distinct, vec![Some(5.0), None, Some(2.0)]);
+        Ok(())
+    }
+}
@@ -35,7 +35,7 @@ use datafusion_expr::type_coercion::other::{
     get_coerce_type_for_case_when, get_coerce_type_for_list,
 };
 use datafusion_expr::type_coercion::{
-    is_date, is_numeric, is_timestamp, is_utf8_or_large_utf8,
+    is_date, is_duration, is_numeric, is_timestamp, is_utf8_or_large_utf8,
 };
 use datafusion_expr::utils::from_plan;
 use datafusion_expr::{
@@ -234,6 +234,53 @@ impl ExprRewriter for TypeCoercionRewriter {
                         // this is a workaround for https://github.com/apache/arrow-datafusion/issues/3419
                         Ok(expr.clone())
                     }
+                    (DataType::Timestamp(_, _), DataType::Duration(_))
+                    | (DataType::Duration(_), DataType::Timestamp(_, _)) => {
+                        // validate the operation, the duration is applied as is
+                        coerce_types(&left_type, &op, &right_type)?;
+                        Ok(expr.clone())
+                    }
+                    (DataType::Timestamp(_, _), DataType::Timestamp(_, _))
+                        if op == Operator::Minus =>
+                    {
+                        // the result is a duration, but both sides are
+                        // coerced to a common timestamp type
+                        coerce_types(&left_type, &op, &right_type)?;
+                        let coerced_type = comparison_coercion(&left_type, &right_type)
+                            .ok_or_else(|| {
+                                DataFusionError::Plan(format!(
+                                    "Failed to coerce types {left_type} and {right_type} in timestamp subtraction"
+                                ))
+                            })?;
+                        let expr = Expr::BinaryExpr(BinaryExpr::new(
+                            Box::new(left.clone().cast_to(&coerced_type, &self.schema)?),
+                            op,
+                            Box::new(right.clone().cast_to(&coerced_type, &self.schema)?),
+                        ));
+                        Ok(expr)
+                    }
+                    (DataType::Duration(_), _) | (_, DataType::Duration(_))
+                        if matches!(op, Operator::Multiply | Operator::Divide) =>
+                    {
+                        // durations are scaled by 64 bit integers
+                        coerce_types(&left_type, &op, &right_type)?;
+                        let (left, right) = if is_duration(&left_type) {
+                            (
+                                left.clone(),
+                                right.clone().cast_to(&DataType::Int64, &self.schema)?,
+                            )
+                        } else {
+                            (
+                                left.clone().cast_to(&DataType::Int64, &self.schema)?,
+                                right.clone(),
+                            )
+                        };
+                        Ok(Expr::BinaryExpr(BinaryExpr::new(
+                            Box::new(left),
+                            op,
+                            Box::new(right),
+                        )))
+                    }
                     _ => {
                         let coerced_type = coerce_types(&left_type, &op, &right_type)?;
                         let expr = Expr::BinaryExpr(BinaryExpr::new(
@@ -34,6 +34,7 @@ use arrow::{
     array::{ArrayRef, UInt64Array},
     datatypes::Field,
 };
+use arrow_buffer::i256;
 use datafusion_common::{downcast_value, ScalarValue};
 use datafusion_common::{DataFusionError, Result};
 use datafusion_expr::Accumulator;
@@ -57,7 +58,7 @@ impl Avg {
         // the result of avg just support FLOAT64 and Decimal data type.
         assert!(matches!(
             data_type,
-            DataType::Float64 | DataType::Decimal128(_, _)
+            DataType::Float64 | DataType::Decimal128(_, _) | DataType::Decimal256(_, _)
         ));
         Self {
             name: name.into(),
@@ -203,6 +204,17 @@ impl Accumulator for AvgAccumulator {
                     ),
                 })
             }
+            ScalarValue::Decimal256(value, precision, scale) => {
+                Ok(match value {
+                    None => ScalarValue::Decimal256(None, precision, scale),
+                    // TODO add the checker for overflow the precision
+                    Some(v) => ScalarValue::Decimal256(
+                        Some(v.wrapping_div(i256::from_i128(self.count as i128))),
+                        precision,
+                        scale,
+                    ),
+                })
+            }
             _ => Err(DataFusionError::Internal(
                 "Sum should be f64 on average".to_string(),
             )),
@@ -345,6 +357,28 @@ mod tests {
         )
     }
 
+    #[test]
+    fn avg_decimal256_with_nulls() -> Result<()> {
+        let array: ArrayRef = Arc::new(
+            (1..6)
+                .map(|i| {
+                    if i == 2 {
+                        None
+                    } else {
+                        Some(i256::from_i128(i))
+                    }
+                })
+                .collect::<Decimal256Array>()
+                .with_precision_and_scale(50, 0)?,
+        );
+        generic_test_op!(
+            array,
+            DataType::Decimal256(50, 0),
+            Avg,
+            ScalarValue::Decimal256(Some(i256::from_i128(32500)), 54, 4)
+        )
+    }
+
     #[test]
     fn avg_i32() -> Result<()> {
         let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]));
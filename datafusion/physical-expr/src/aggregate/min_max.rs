@@ -44,7 +44,7 @@ use crate::aggregate::row_accumulator::{
 };
 use crate::expressions::format_state_name;
 use arrow::array::Array;
-use arrow::array::Decimal128Array;
+use arrow::array::{Decimal128Array, Decimal256Array};
 use datafusion_row::accessor::RowAccessor;
 
 use super::moving_min_max;
@@ -169,22 +169,22 @@ macro_rules! typed_min_max_batch {
 // TODO implement this in arrow-rs with simd
 // https://github.com/apache/arrow-rs/issues/1010
 // Statically-typed version of min/max(array) -> ScalarValue for decimal types.
-macro_rules! typed_min_max_batch_decimal128 {
-    ($VALUES:expr, $PRECISION:ident, $SCALE:ident, $OP:ident) => {{
+macro_rules! typed_min_max_batch_decimal {
+    ($VALUES:expr, $ARRAYTYPE:ident, $SCALAR:ident, $PRECISION:ident, $SCALE:ident, $OP:ident) => {{
         let null_count = $VALUES.null_count();
         if null_count == $VALUES.len() {
-            ScalarValue::Decimal128(None, *$PRECISION, *$SCALE)
+            ScalarValue::$SCALAR(None, *$PRECISION, *$SCALE)
         } else {
-            let array = downcast_value!($VALUES, Decimal128Array);
+            let array = downcast_value!($VALUES, $ARRAYTYPE);
             if null_count == 0 {
                 // there is no null value
                 let mut result = array.value(0);
                 for i in 1..array.len() {
                     result = result.$OP(array.value(i));
                 }
-                ScalarValue::Decimal128(Some(result), *$PRECISION, *$SCALE)
+                ScalarValue::$SCALAR(Some(result), *$PRECISION, *$SCALE)
             } else {
-                let mut result = 0_i128;
+                let mut result = Default::default();
                 let mut has_value = false;
                 for i in 0..array.len() {
                     if !has_value && array.is_valid(i) {
@@ -195,7 +195,7 @@ macro_rules! typed_min_max_batch_decimal128 {
                         result = result.$OP(array.value(i));
                     }
                 }
-                ScalarValue::Decimal128(Some(result), *$PRECISION, *$SCALE)
+                ScalarValue::$SCALAR(Some(result), *$PRECISION, *$SCALE)
             }
         }
     }};
@@ -207,7 +207,24 @@ macro_rules! min_max_batch {
     ($VALUES:expr, $OP:ident) => {{
         match $VALUES.data_type() {
             DataType::Decimal128(precision, scale) => {
-                typed_min_max_batch_decimal128!($VALUES, precision, scale, $OP)
+                typed_min_max_batch_decimal!(
+                    $VALUES,
+                    Decimal128Array,
+                    Decimal128,
+                    precision,
+                    scale,
+                    $OP
+                )
+            }
+            DataType::Decimal256(precision, scale) => {
+                typed_min_max_batch_decimal!(
+                    $VALUES,
+                    Decimal256Array,
+                    Decimal256,
+                    precision,
+                    scale,
+                    $OP
+                )
             }
             // all types that have a natural order
             DataType::Float64 => {
@@ -376,6 +393,19 @@ macro_rules! min_max {
                 )));
                 }
             }
+            (
+                lhs @ ScalarValue::Decimal256(lhsv, lhsp, lhss),
+                rhs @ ScalarValue::Decimal256(rhsv, rhsp, rhss)
+            ) => {
+                if lhsp.eq(rhsp) && lhss.eq(rhss) {
+                    typed_min_max!(lhsv, rhsv, Decimal256, $OP, lhsp, lhss)
+                } else {
+                    return Err(DataFusionError::Internal(format!(
+                    "MIN/MAX is not expected to receive scalars of incompatible types {:?}",
+                    (lhs, rhs)
+                )));
+                }
+            }
             (ScalarValue::Float64(lhs), ScalarValue::Float64(rhs)) => {
                 typed_min_max!(lhs, rhs, Float64, $OP)
             }
@@ -916,6 +946,7 @@ mod tests {
     use crate::generic_test_op;
     use arrow::datatypes::*;
     use arrow::record_batch::RecordBatch;
+    use arrow_buffer::i256;
     use datafusion_common::Result;
     use datafusion_common::ScalarValue;
     use datafusion_common::ScalarValue::Decimal128;
@@ -1082,6 +1113,34 @@ mod tests {
         )
     }
 
+    #[test]
+    fn min_max_decimal256_with_nulls() -> Result<()> {
+        let array: ArrayRef = Arc::new(
+            (1..6)
+                .map(|i| {
+                    if i == 2 {
+                        None
+                    } else {
+                        Some(i256::from_i128(i))
+                    }
+                })
+                .collect::<Decimal256Array>()
+                .with_precision_and_scale(50, 0)?,
+        );
+        generic_test_op!(
+            array.clone(),
+            DataType::Decimal256(50, 0),
+            Min,
+            ScalarValue::Decimal256(Some(i256::from_i128(1)), 50, 0)
+        )?;
+        generic_test_op!(
+            array,
+            DataType::Decimal256(50, 0),
+            Max,
+            ScalarValue::Decimal256(Some(i256::from_i128(5)), 50, 0)
+        )
+    }
+
     #[test]
     fn max_i32() -> Result<()> {
         let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]));
@@ -39,8 +39,9 @@ use crate::aggregate::row_accumulator::{
 };
 use crate::expressions::format_state_name;
 use arrow::array::Array;
-use arrow::array::Decimal128Array;
+use arrow::array::{Decimal128Array, Decimal256Array};
 use arrow::compute::cast;
+use arrow_buffer::i256;
 use datafusion_row::accessor::RowAccessor;
 
 /// SUM aggregate expression
@@ -167,6 +168,27 @@ fn sum_decimal_batch(values: &ArrayRef, precision: u8, scale: i8) -> Result<Scal
     Ok(ScalarValue::Decimal128(result, precision, scale))
 }
 
+fn sum_decimal256_batch(
+    values: &ArrayRef,
+    precision: u8,
+    scale: i8,
+) -> Result<ScalarValue> {
+    let array = downcast_value!(values, Decimal256Array);
+    let result =
+        array
+            .iter()
+            .flatten()
+            .try_fold(None, |acc: Option<i256>, v| match acc {
+                None => Ok(Some(v)),
+                Some(acc) => acc.checked_add(v).map(Some).ok_or_else(|| {
+                    DataFusionError::Execution(format!(
+                        "Overflow happened on: {acc:?} + {v:?}"
+                    ))
+                }),
+            })?;
+    Ok(ScalarValue::Decimal256(result, precision, scale))
+}
+
 // sums the array and returns a ScalarValue of its corresponding type.
 pub(crate) fn sum_batch(values: &ArrayRef, sum_type: &DataType) -> Result<ScalarValue> {
     let values = &cast(values, sum_type)?;
@@ -174,6 +196,9 @@ pub(crate) fn sum_batch(values: &ArrayRef, sum_type: &DataType) -> Result<Scalar
         DataType::Decimal128(precision, scale) => {
             sum_decimal_batch(values, *precision, *scale)?
         }
+        DataType::Decimal256(precision, scale) => {
+            sum_decimal256_batch(values, *precision, *scale)?
+        }
         DataType::Float64 => typed_sum_delta_batch!(values, Float64Array, Float64),
         DataType::Float32 => typed_sum_delta_batch!(values, Float32Array, Float32),
         DataType::Int64 => typed_sum_delta_batch!(values, Int64Array, Int64),
@@ -398,6 +423,36 @@ mod tests {
         )
     }
 
+    #[test]
+    fn sum_decimal256_with_nulls() -> Result<()> {
+        // test with batch
+        let array: ArrayRef = Arc::new(
+            (1..6)
+                .map(|i| {
+                    if i == 2 {
+                        None
+                    } else {
+                        Some(i256::from_i128(i))
+                    }
+                })
+                .collect::<Decimal256Array>()
+                .with_precision_and_scale(50, 0)?,
+        );
+        let result = sum_batch(&array, &DataType::Decimal256(50, 0))?;
+        assert_eq!(
+            ScalarValue::Decimal256(Some(i256::from_i128(13)), 50, 0),
+            result
+        );
+
+        // test agg
+        generic_test_op!(
+            array,
+            DataType::Decimal256(50, 0),
+            Sum,
+            ScalarValue::Decimal256(Some(i256::from_i128(13)), 60, 0)
+        )
+    }
+
     #[test]
     fn sum_i32() -> Result<()> {
         let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]));
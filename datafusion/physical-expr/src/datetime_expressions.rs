@@ -17,25 +17,31 @@
 
 //! DateTime expressions
 
-use arrow::compute::cast;
+use arrow::compute::{cast, unary};
 use arrow::{
     array::TimestampNanosecondArray, compute::kernels::temporal, datatypes::TimeUnit,
     temporal_conversions::timestamp_ns_to_datetime,
 };
 use arrow::{
-    array::{Array, ArrayRef, Float64Array, OffsetSizeTrait, PrimitiveArray},
+    array::{
+        timezone::Tz, Array, ArrayRef, Float64Array, Int64Array, OffsetSizeTrait,
+        PrimitiveArray,
+    },
     compute::kernels::cast_utils::string_to_timestamp_nanos,
     datatypes::{
-        ArrowNumericType, ArrowPrimitiveType, ArrowTemporalType, DataType,
-        IntervalDayTimeType, TimestampMicrosecondType, TimestampMillisecondType,
-        TimestampNanosecondType, TimestampSecondType,
+        ArrowNumericType, ArrowPrimitiveType, ArrowTemporalType, DataType, Date32Type,
+        Date64Type, DurationMicrosecondType, DurationMillisecondType,
+        DurationNanosecondType, DurationSecondType, Float64Type, IntervalDayTimeType,
+        IntervalMonthDayNanoType, IntervalUnit, IntervalYearMonthType,
+        TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+        TimestampSecondType,
     },
 };
 use chrono::prelude::*;
 use chrono::Duration;
 use datafusion_common::cast::{
-    as_date32_array, as_date64_array, as_generic_string_array,
-    as_timestamp_microsecond_array, as_timestamp_millisecond_array,
+    as_date32_array, as_date64_array, as_generic_string_array, as_int64_array,
+    as_primitive_array, as_timestamp_microsecond_array, as_timestamp_millisecond_array,
     as_timestamp_nanosecond_array, as_timestamp_second_array,
 };
 use datafusion_common::{DataFusionError, Result};
@@ -214,12 +220,15 @@ fn quarter_month(date: &NaiveDateTime) -> u32 {
     1 + 3 * ((date.month() - 1) / 3)
 }
 
-fn date_trunc_single(granularity: &str, value: i64) -> Result<i64> {
-    let value = timestamp_ns_to_datetime(value)
-        .ok_or_else(|| {
-            DataFusionError::Execution(format!("Timestamp {value} out of range"))
-        })?
-        .with_nanosecond(0);
+/// Parses the timezone of a timezone aware timestamp, if any
+fn parse_tz(tz: &Option<String>) -> Result<Option<Tz>> {
+    tz.as_ref()
+        .map(|tz| tz.parse::<Tz>().map_err(DataFusionError::from))
+        .transpose()
+}
+
+fn date_trunc_naive(granularity: &str, value: NaiveDateTime) -> Result<NaiveDateTime> {
+    let value = value.with_nanosecond(0);
     let value = match granularity {
         "second" => value,
         "minute" => value.and_then(|d| d.with_second(0)),
@@ -259,7 +268,35 @@ fn date_trunc_single(granularity: &str, value: i64) -> Result<i64> {
         }
     };
     // `with_x(0)` are infalible because `0` are always a valid
-    Ok(value.unwrap().timestamp_nanos())
+    Ok(value.unwrap())
+}
+
+/// Maps a wall clock time in `tz` back to UTC. Wall clock times that are
+/// ambiguous resolve to the earliest instant, and wall clock times that do
+/// not exist (because they fall into a DST gap) keep the `fallback` offset.
+fn local_to_utc(local: NaiveDateTime, tz: &Tz, fallback: FixedOffset) -> NaiveDateTime {
+    match tz.from_local_datetime(&local).earliest() {
+        Some(dt) => dt.naive_utc(),
+        None => local - fallback,
+    }
+}
+
+fn date_trunc_single(granularity: &str, value: i64, tz: Option<&Tz>) -> Result<i64> {
+    let value = timestamp_ns_to_datetime(value).ok_or_else(|| {
+        DataFusionError::Execution(format!("Timestamp {value} out of range"))
+    })?;
+
+    let truncated = match tz {
+        None => date_trunc_naive(granularity, value)?,
+        Some(tz) => {
+            // truncate the wall clock time in `tz`, e.g. `day` truncates to
+            // local midnight, then map the result back to UTC
+            let offset = tz.offset_from_utc_datetime(&value).fix();
+            let local = date_trunc_naive(granularity, value + offset)?;
+            local_to_utc(local, tz, offset)
+        }
+    };
+    Ok(truncated.timestamp_nanos())
 }
 
 /// date_trunc SQL function
@@ -275,21 +312,34 @@ pub fn date_trunc(args: &[ColumnarValue]) -> Result<ColumnarValue> {
             ));
         };
 
-    let f = |x: Option<i64>| x.map(|x| date_trunc_single(granularity, x)).transpose();
+    let tz_opt = match array {
+        ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(_, tz_opt)) => {
+            tz_opt.clone()
+        }
+        ColumnarValue::Array(array) => match array.data_type() {
+            DataType::Timestamp(_, tz_opt) => tz_opt.clone(),
+            _ => None,
+        },
+        _ => None,
+    };
+    let tz = parse_tz(&tz_opt)?;
+
+    let f = |x: Option<i64>| {
+        x.map(|x| date_trunc_single(granularity, x, tz.as_ref()))
+            .transpose()
+    };
 
     Ok(match array {
-        ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(v, tz_opt)) => {
-            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
-                (f)(*v)?,
-                tz_opt.clone(),
-            ))
+        ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(v, _)) => {
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond((f)(*v)?, tz_opt))
         }
         ColumnarValue::Array(array) => {
             let array = as_timestamp_nanosecond_array(array)?;
             let array = array
                 .iter()
                 .map(f)
-                .collect::<Result<TimestampNanosecondArray>>()?;
+                .collect::<Result<TimestampNanosecondArray>>()?
+                .with_timezone_opt(tz_opt);
 
             ColumnarValue::Array(Arc::new(array))
         }
@@ -316,6 +366,23 @@ fn date_bin_single(stride: i64, source: i64, origin: i64) -> i64 {
     origin + time_delta
 }
 
+/// Bins `source` by the wall clock time in `tz`, with `origin` also
+/// interpreted as a wall clock time, and returns the start of the bin in UTC
+fn date_bin_tz(stride: i64, source: i64, origin: i64, tz: &Tz) -> Result<i64> {
+    let utc = timestamp_ns_to_datetime(source).ok_or_else(|| {
+        DataFusionError::Execution(format!("Timestamp {source} out of range"))
+    })?;
+    let offset = tz.offset_from_utc_datetime(&utc).fix();
+
+    let local = source + offset.local_minus_utc() as i64 * 1_000_000_000;
+    let binned = date_bin_single(stride, local, origin);
+    let binned = timestamp_ns_to_datetime(binned).ok_or_else(|| {
+        DataFusionError::Execution(format!("Timestamp {binned} out of range"))
+    })?;
+
+    Ok(local_to_utc(binned, tz, offset).timestamp_nanos())
+}
+
 /// DATE_BIN sql function
 pub fn date_bin(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     if args.len() != 3 {
@@ -366,18 +433,37 @@ pub fn date_bin(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         )),
     };
 
-    let f = |x: Option<i64>| x.map(|x| date_bin_single(stride, x, origin));
+    let tz_opt = match array {
+        ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(_, tz_opt)) => {
+            tz_opt.clone()
+        }
+        ColumnarValue::Array(array) => match array.data_type() {
+            DataType::Timestamp(_, tz_opt) => tz_opt.clone(),
+            _ => None,
+        },
+        _ => None,
+    };
+    let tz = parse_tz(&tz_opt)?;
+
+    let f = |x: Option<i64>| {
+        x.map(|x| match &tz {
+            Some(tz) => date_bin_tz(stride, x, origin, tz),
+            None => Ok(date_bin_single(stride, x, origin)),
+        })
+        .transpose()
+    };
 
     Ok(match array {
-        ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(v, tz_opt)) => {
-            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(f(*v), tz_opt.clone()))
+        ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(v, _)) => {
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(f(*v)?, tz_opt))
         }
         ColumnarValue::Array(array) => match array.data_type() {
             DataType::Timestamp(TimeUnit::Nanosecond, _) => {
                 let array = as_timestamp_nanosecond_array(array)?
                     .iter()
                     .map(f)
-                    .collect::<TimestampNanosecondArray>();
+                    .collect::<Result<TimestampNanosecondArray>>()?
+                    .with_timezone_opt(tz_opt);
 
                 ColumnarValue::Array(Arc::new(array))
             }
@@ -397,6 +483,90 @@ pub fn date_bin(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     })
 }
 
+/// Interprets `value`, a timestamp without timezone, as a wall clock time in
+/// `tz` and returns the corresponding instant in UTC
+fn to_utc_timestamp_single(value: i64, tz: &Tz) -> Result<i64> {
+    let local = timestamp_ns_to_datetime(value).ok_or_else(|| {
+        DataFusionError::Execution(format!("Timestamp {value} out of range"))
+    })?;
+    let fallback = tz.offset_from_utc_datetime(&local).fix();
+    Ok(local_to_utc(local, tz, fallback).timestamp_nanos())
+}
+
+/// to_utc_timestamp SQL function, which `AT TIME ZONE` lowers to for
+/// timestamps without timezone
+pub fn to_utc_timestamp(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let (array, tz) = (&args[0], &args[1]);
+
+    let tz = if let ColumnarValue::Scalar(ScalarValue::Utf8(Some(v))) = tz {
+        v.parse::<Tz>()?
+    } else {
+        return Err(DataFusionError::Execution(
+            "Timezone of `to_utc_timestamp` must be non-null scalar Utf8".to_string(),
+        ));
+    };
+
+    let f = |x: Option<i64>| x.map(|x| to_utc_timestamp_single(x, &tz)).transpose();
+
+    Ok(match array {
+        ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(v, None)) => {
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(f(*v)?, None))
+        }
+        ColumnarValue::Array(array) => {
+            let array = as_timestamp_nanosecond_array(array)?
+                .iter()
+                .map(f)
+                .collect::<Result<TimestampNanosecondArray>>()?;
+
+            ColumnarValue::Array(Arc::new(array))
+        }
+        _ => {
+            return Err(DataFusionError::Execution(
+                "Timestamp of `to_utc_timestamp` must be a Timestamp(Nanosecond, None)"
+                    .to_string(),
+            ));
+        }
+    })
+}
+
+/// Converts a timezone aware timestamp array into a timestamp array without
+/// timezone that holds the wall clock times in that timezone, so that date
+/// parts are extracted in local time. Other arrays are returned unchanged.
+fn to_local_time(array: &ArrayRef) -> Result<ArrayRef> {
+    let (unit, tz) = match array.data_type() {
+        DataType::Timestamp(unit, Some(tz)) => (*unit, tz.parse::<Tz>()?),
+        _ => return Ok(array.clone()),
+    };
+    let units_per_second: i64 = match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    };
+
+    let values = cast(array, &DataType::Int64)?;
+    let local = as_int64_array(&values)?
+        .iter()
+        .map(|v| {
+            v.map(|v| {
+                let seconds = v.div_euclid(units_per_second);
+                let utc =
+                    NaiveDateTime::from_timestamp_opt(seconds, 0).ok_or_else(|| {
+                        DataFusionError::Execution(format!("Timestamp {v} out of range"))
+                    })?;
+                let offset = tz.offset_from_utc_datetime(&utc).fix();
+                Ok(v + offset.local_minus_utc() as i64 * units_per_second)
+            })
+            .transpose()
+        })
+        .collect::<Result<Int64Array>>()?;
+
+    Ok(cast(
+        &(Arc::new(local) as ArrayRef),
+        &DataType::Timestamp(unit, None),
+    )?)
+}
+
 macro_rules! extract_date_part {
     ($ARRAY: expr, $FN:expr) => {
         match $ARRAY.data_type() {
@@ -463,8 +633,17 @@ pub fn date_part(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         ColumnarValue::Array(array) => array.clone(),
         ColumnarValue::Scalar(scalar) => scalar.to_array(),
     };
+    let date_part = date_part.to_lowercase();
+    // the epoch doesn't depend on the timezone, so compute it before
+    // converting the timestamps to local time
+    let array = if date_part == "epoch" {
+        array
+    } else {
+        to_local_time(&array)?
+    };
 
-    let arr = match date_part.to_lowercase().as_str() {
+    let arr = match date_part.as_str() {
+        "epoch" => epoch(&array).map(Ok),
         "year" => extract_date_part!(&array, temporal::year),
         "quarter" => extract_date_part!(&array, temporal::quarter),
         "month" => extract_date_part!(&array, temporal::month),
@@ -537,6 +716,78 @@ where
     to_ticks(array, 1_000_000_000)
 }
 
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Returns the number of seconds of `months`, counting a year as 365.25
+/// days and the remaining months as 30 days each, like PostgreSQL
+fn months_to_seconds(months: i32) -> f64 {
+    let years = (months / 12) as f64;
+    let months = (months % 12) as f64;
+    (years * 365.25 + months * 30.0) * SECONDS_PER_DAY
+}
+
+/// Returns the number of seconds since the unix epoch for dates and
+/// timestamps, and the total number of seconds for intervals and durations
+fn epoch(array: &ArrayRef) -> Result<ArrayRef> {
+    macro_rules! epoch_of {
+        ($TYPE:ty, $FN:expr) => {
+            unary::<$TYPE, _, Float64Type>(as_primitive_array::<$TYPE>(array)?, $FN)
+        };
+    }
+
+    let result = match array.data_type() {
+        DataType::Date32 => epoch_of!(Date32Type, |d| d as f64 * SECONDS_PER_DAY),
+        DataType::Date64 => epoch_of!(Date64Type, |ms| ms as f64 / 1_000.0),
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            epoch_of!(TimestampSecondType, |s| s as f64)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            epoch_of!(TimestampMillisecondType, |ms| ms as f64 / 1_000.0)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            epoch_of!(TimestampMicrosecondType, |us| us as f64 / 1_000_000.0)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            epoch_of!(TimestampNanosecondType, |ns| ns as f64 / 1_000_000_000.0)
+        }
+        DataType::Duration(TimeUnit::Second) => {
+            epoch_of!(DurationSecondType, |s| s as f64)
+        }
+        DataType::Duration(TimeUnit::Millisecond) => {
+            epoch_of!(DurationMillisecondType, |ms| ms as f64 / 1_000.0)
+        }
+        DataType::Duration(TimeUnit::Microsecond) => {
+            epoch_of!(DurationMicrosecondType, |us| us as f64 / 1_000_000.0)
+        }
+        DataType::Duration(TimeUnit::Nanosecond) => {
+            epoch_of!(DurationNanosecondType, |ns| ns as f64 / 1_000_000_000.0)
+        }
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            epoch_of!(IntervalYearMonthType, months_to_seconds)
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            epoch_of!(IntervalDayTimeType, |v| {
+                let (days, ms) = IntervalDayTimeType::to_parts(v);
+                days as f64 * SECONDS_PER_DAY + ms as f64 / 1_000.0
+            })
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            epoch_of!(IntervalMonthDayNanoType, |v| {
+                let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(v);
+                months_to_seconds(months)
+                    + days as f64 * SECONDS_PER_DAY
+                    + nanos as f64 / 1_000_000_000.0
+            })
+        }
+        datatype => {
+            return Err(DataFusionError::Internal(format!(
+                "Extract does not support datatype {datatype:?}"
+            )))
+        }
+    };
+    Ok(Arc::new(result))
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -665,11 +916,174 @@ mod tests {
         cases.iter().for_each(|(original, granularity, expected)| {
             let left = string_to_timestamp_nanos(original).unwrap();
             let right = string_to_timestamp_nanos(expected).unwrap();
-            let result = date_trunc_single(granularity, left).unwrap();
+            let result = date_trunc_single(granularity, left, None).unwrap();
             assert_eq!(result, right, "{original} = {expected}");
         });
     }
 
+    #[test]
+    fn date_trunc_timezone_test() {
+        let cases = vec![
+            // truncates to local midnight, before the DST transition
+            (
+                "2020-03-08T07:30:00Z",
+                "America/New_York",
+                "day",
+                "2020-03-08T05:00:00Z",
+            ),
+            // truncates to local midnight, before the DST transition back
+            (
+                "2020-11-01T12:00:00Z",
+                "America/New_York",
+                "day",
+                "2020-11-01T04:00:00Z",
+            ),
+            (
+                "2020-11-05T03:00:00Z",
+                "America/New_York",
+                "month",
+                "2020-11-01T04:00:00Z",
+            ),
+            (
+                "2020-09-08T13:42:29.190855Z",
+                "+05:30",
+                "hour",
+                "2020-09-08T13:30:00Z",
+            ),
+        ];
+
+        cases
+            .iter()
+            .for_each(|(original, tz, granularity, expected)| {
+                let tz = tz.parse::<Tz>().unwrap();
+                let left = string_to_timestamp_nanos(original).unwrap();
+                let right = string_to_timestamp_nanos(expected).unwrap();
+                let result = date_trunc_single(granularity, left, Some(&tz)).unwrap();
+                assert_eq!(result, right, "{original} = {expected}");
+            });
+    }
+
+    #[test]
+    fn date_trunc_keeps_timezone() -> Result<()> {
+        let tz = Some("America/New_York".to_string());
+        let ts = string_to_timestamp_nanos("2020-03-08T07:30:00Z")?;
+        let array =
+            TimestampNanosecondArray::from(vec![ts]).with_timezone_opt(tz.clone());
+
+        let result = date_trunc(&[
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("day".to_string()))),
+            ColumnarValue::Array(Arc::new(array)),
+        ])?;
+        let result = match result {
+            ColumnarValue::Array(array) => array,
+            _ => panic!("Expected a columnar array"),
+        };
+
+        assert_eq!(
+            result.data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, tz)
+        );
+        assert_eq!(
+            as_timestamp_nanosecond_array(&result)?.value(0),
+            string_to_timestamp_nanos("2020-03-08T05:00:00Z")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn date_part_timezone() -> Result<()> {
+        let ts = string_to_timestamp_nanos("2020-03-08T03:30:00Z")?;
+        let array = TimestampNanosecondArray::from(vec![ts])
+            .with_timezone_opt(Some("America/New_York".to_string()));
+        let array: ArrayRef = Arc::new(array);
+
+        for (part, expected) in [("day", 7.0), ("hour", 22.0), ("minute", 30.0)] {
+            let result = date_part(&[
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some(part.to_string()))),
+                ColumnarValue::Array(array.clone()),
+            ])?;
+            let result = match result {
+                ColumnarValue::Array(array) => array,
+                _ => panic!("Expected a columnar array"),
+            };
+            assert_eq!(
+                datafusion_common::cast::as_float64_array(&result)?.value(0),
+                expected,
+                "{part}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_bin_timezone() -> Result<()> {
+        let source = string_to_timestamp_nanos("2020-03-08T07:30:00Z")?;
+        let origin = string_to_timestamp_nanos("1970-01-01T00:00:00Z")?;
+        let stride = Duration::days(1).num_nanoseconds().unwrap();
+        let tz = "America/New_York".parse::<Tz>()?;
+
+        let result = date_bin_tz(stride, source, origin, &tz)?;
+        assert_eq!(result, string_to_timestamp_nanos("2020-03-08T05:00:00Z")?);
+        Ok(())
+    }
+
+    #[test]
+    fn to_utc_timestamp_test() -> Result<()> {
+        let cases = vec![
+            // standard time
+            (
+                "2020-01-15T12:00:00",
+                "America/New_York",
+                "2020-01-15T17:00:00Z",
+            ),
+            // daylight saving time
+            (
+                "2020-07-15T12:00:00",
+                "America/New_York",
+                "2020-07-15T16:00:00Z",
+            ),
+            // ambiguous wall clock time resolves to the earliest instant
+            (
+                "2020-11-01T01:30:00",
+                "America/New_York",
+                "2020-11-01T05:30:00Z",
+            ),
+            // wall clock time in the DST gap keeps the standard offset
+            (
+                "2020-03-08T02:30:00",
+                "America/New_York",
+                "2020-03-08T07:30:00Z",
+            ),
+            (
+                "2020-01-01T00:00:00",
+                "Asia/Kolkata",
+                "2019-12-31T18:30:00Z",
+            ),
+            ("2020-01-01T00:00:00", "+02:00", "2019-12-31T22:00:00Z"),
+        ];
+
+        for (local, tz, expected) in cases {
+            let result = to_utc_timestamp(&[
+                ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
+                    Some(string_to_timestamp_nanos(local)?),
+                    None,
+                )),
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some(tz.to_string()))),
+            ])?;
+            let expected = ScalarValue::TimestampNanosecond(
+                Some(string_to_timestamp_nanos(expected)?),
+                None,
+            );
+            match result {
+                ColumnarValue::Scalar(result) => {
+                    assert_eq!(result, expected, "{local} AT TIME ZONE {tz}")
+                }
+                _ => panic!("Expected a scalar value"),
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_date_bin_single() {
         use chrono::Duration;
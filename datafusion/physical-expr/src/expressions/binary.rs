@@ -58,14 +58,19 @@ use kernels::{
     bitwise_xor, bitwise_xor_scalar,
 };
 use kernels_arrow::{
-    add_decimal_dyn_scalar, add_dyn_decimal, divide_decimal_dyn_scalar,
-    divide_dyn_opt_decimal, is_distinct_from, is_distinct_from_bool,
-    is_distinct_from_decimal, is_distinct_from_f32, is_distinct_from_f64,
-    is_distinct_from_null, is_distinct_from_utf8, is_not_distinct_from,
-    is_not_distinct_from_bool, is_not_distinct_from_decimal, is_not_distinct_from_f32,
-    is_not_distinct_from_f64, is_not_distinct_from_null, is_not_distinct_from_utf8,
-    modulus_decimal, modulus_decimal_scalar, multiply_decimal_dyn_scalar,
-    multiply_dyn_decimal, subtract_decimal_dyn_scalar, subtract_dyn_decimal,
+    add_decimal256_dyn_scalar, add_decimal_dyn_scalar, add_dyn_decimal,
+    add_dyn_decimal256, divide_decimal256_dyn_scalar, divide_decimal_dyn_scalar,
+    divide_dyn_opt_decimal, divide_dyn_opt_decimal256, is_distinct_from,
+    is_distinct_from_bool, is_distinct_from_decimal, is_distinct_from_decimal256,
+    is_distinct_from_f32, is_distinct_from_f64, is_distinct_from_null,
+    is_distinct_from_utf8, is_not_distinct_from, is_not_distinct_from_bool,
+    is_not_distinct_from_decimal, is_not_distinct_from_decimal256,
+    is_not_distinct_from_f32, is_not_distinct_from_f64, is_not_distinct_from_null,
+    is_not_distinct_from_utf8, modulus_decimal, modulus_decimal256,
+    modulus_decimal256_scalar, modulus_decimal_scalar, multiply_decimal256_dyn_scalar,
+    multiply_decimal_dyn_scalar, multiply_dyn_decimal, multiply_dyn_decimal256,
+    subtract_decimal256_dyn_scalar, subtract_decimal_dyn_scalar, subtract_dyn_decimal,
+    subtract_dyn_decimal256,
 };
 
 use arrow::datatypes::{DataType, Schema, TimeUnit};
@@ -76,7 +81,9 @@ use crate::intervals::cp_solver::{propagate_arithmetic, propagate_comparison};
 use crate::intervals::{apply_operator, Interval};
 use crate::physical_expr::down_cast_any_ref;
 use crate::{analysis_expect, AnalysisContext, ExprBoundaries, PhysicalExpr};
-use datafusion_common::cast::{as_boolean_array, as_decimal128_array};
+use datafusion_common::cast::{
+    as_boolean_array, as_decimal128_array, as_decimal256_array,
+};
 use datafusion_common::ScalarValue;
 use datafusion_common::{DataFusionError, Result};
 use datafusion_expr::type_coercion::binary::binary_operator_data_type;
@@ -183,6 +190,41 @@ macro_rules! compute_decimal_op {
     }};
 }
 
+/// Invoke a dyn compute kernel on a data array and a scalar value for
+/// types without scalar comparison kernels (i256, intervals and durations)
+/// by comparing against an array of the repeated scalar instead
+macro_rules! compute_array_op_dyn_scalar {
+    ($LEFT:expr, $RIGHT:expr, $OP:ident, $OP_TYPE:expr) => {{
+        if $RIGHT.is_null() {
+            // when the $RIGHT is a NULL, generate a NULL array of $OP_TYPE type
+            Ok(Arc::new(new_null_array($OP_TYPE, $LEFT.len())))
+        } else {
+            let right = $RIGHT.to_array_of_size($LEFT.len());
+            Ok(Arc::new(paste::expr! {[<$OP _dyn>]}($LEFT, &right)?))
+        }
+    }};
+}
+
+macro_rules! compute_decimal256_op_scalar {
+    ($LEFT:expr, $RIGHT:expr, $OP:ident) => {{
+        let ll = as_decimal256_array($LEFT).unwrap();
+        if let ScalarValue::Decimal256(Some(v), _, _) = $RIGHT {
+            Ok(Arc::new(paste::expr! {[<$OP _decimal256_scalar>]}(ll, v)?))
+        } else {
+            // when the $RIGHT is a NULL, generate a NULL array of LEFT's datatype
+            Ok(Arc::new(new_null_array($LEFT.data_type(), $LEFT.len())))
+        }
+    }};
+}
+
+macro_rules! compute_decimal256_op {
+    ($LEFT:expr, $RIGHT:expr, $OP:ident) => {{
+        let ll = as_decimal256_array(&$LEFT).unwrap();
+        let rr = as_decimal256_array(&$RIGHT).unwrap();
+        Ok(Arc::new(paste::expr! {[<$OP _decimal256>]}(ll, rr)?))
+    }};
+}
+
 macro_rules! compute_f32_op {
     ($LEFT:expr, $RIGHT:expr, $OP:ident, $DT:ident) => {{
         let ll = $LEFT
@@ -412,6 +454,23 @@ macro_rules! compute_primitive_decimal_op_dyn_scalar {
     }};
 }
 
+/// Invoke a dyn decimal256 compute kernel on a data array and a scalar value
+/// LEFT is a Decimal256 array, RIGHT is scalar value
+/// OP_TYPE is the return type of scalar function
+macro_rules! compute_primitive_decimal256_op_dyn_scalar {
+    ($LEFT:expr, $RIGHT:expr, $OP:ident, $OP_TYPE:expr) => {{
+        // generate the scalar function name, such as add_decimal256_dyn_scalar,
+        // from the $OP parameter (which could have a value of add) and the
+        // suffix _decimal256_dyn_scalar
+        if let Some(value) = $RIGHT {
+            Ok(paste::expr! {[<$OP _decimal256_dyn_scalar>]}($LEFT, value)?)
+        } else {
+            // when the $RIGHT is a NULL, generate a NULL array of $OP_TYPE
+            Ok(Arc::new(new_null_array($OP_TYPE, $LEFT.len())))
+        }
+    }};
+}
+
 /// Invoke a compute kernel on array(s)
 macro_rules! compute_op {
     // invoke binary operator
@@ -455,6 +514,7 @@ macro_rules! binary_primitive_array_op {
     ($LEFT:expr, $RIGHT:expr, $OP:ident) => {{
         match $LEFT.data_type() {
             DataType::Decimal128(_,_) => compute_decimal_op!($LEFT, $RIGHT, $OP, Decimal128Array),
+            DataType::Decimal256(_,_) => compute_decimal256_op!($LEFT, $RIGHT, $OP),
             DataType::Int8 => compute_op!($LEFT, $RIGHT, $OP, Int8Array),
             DataType::Int16 => compute_op!($LEFT, $RIGHT, $OP, Int16Array),
             DataType::Int32 => compute_op!($LEFT, $RIGHT, $OP, Int32Array),
@@ -482,6 +542,9 @@ macro_rules! binary_primitive_array_op_dyn {
             DataType::Decimal128(_, _) => {
                 Ok(paste::expr! {[<$OP _decimal>]}(&$LEFT, &$RIGHT)?)
             }
+            DataType::Decimal256(_, _) => {
+                Ok(paste::expr! {[<$OP _decimal256>]}(&$LEFT, &$RIGHT)?)
+            }
             DataType::Dictionary(_, value_type)
                 if matches!(value_type.as_ref(), &DataType::Decimal128(_, _)) =>
             {
@@ -505,6 +568,7 @@ macro_rules! binary_primitive_array_op_dyn_scalar {
 
         let result: Result<Arc<dyn Array>> = match right {
             ScalarValue::Decimal128(v, _, _) => compute_primitive_decimal_op_dyn_scalar!($LEFT, v, $OP, op_type),
+            ScalarValue::Decimal256(v, _, _) => compute_primitive_decimal256_op_dyn_scalar!($LEFT, v, $OP, op_type),
             ScalarValue::Int8(v) => compute_primitive_op_dyn_scalar!($LEFT, v, $OP, op_type, Int8Type),
             ScalarValue::Int16(v) => compute_primitive_op_dyn_scalar!($LEFT, v, $OP, op_type, Int16Type),
             ScalarValue::Int32(v) => compute_primitive_op_dyn_scalar!($LEFT, v, $OP, op_type, Int32Type),
@@ -532,6 +596,7 @@ macro_rules! binary_primitive_array_op_scalar {
     ($LEFT:expr, $RIGHT:expr, $OP:ident) => {{
         let result: Result<Arc<dyn Array>> = match $LEFT.data_type() {
             DataType::Decimal128(_,_) => compute_decimal_op_scalar!($LEFT, $RIGHT, $OP, Decimal128Array),
+            DataType::Decimal256(_,_) => compute_decimal256_op_scalar!($LEFT, $RIGHT, $OP),
             DataType::Int8 => compute_op_scalar!($LEFT, $RIGHT, $OP, Int8Array),
             DataType::Int16 => compute_op_scalar!($LEFT, $RIGHT, $OP, Int16Array),
             DataType::Int32 => compute_op_scalar!($LEFT, $RIGHT, $OP, Int32Array),
@@ -559,6 +624,7 @@ macro_rules! binary_array_op {
         match $LEFT.data_type() {
             DataType::Null => compute_null_op!($LEFT, $RIGHT, $OP, NullArray),
             DataType::Decimal128(_,_) => compute_decimal_op!($LEFT, $RIGHT, $OP, Decimal128Array),
+            DataType::Decimal256(_,_) => compute_decimal256_op!($LEFT, $RIGHT, $OP),
             DataType::Int8 => compute_op!($LEFT, $RIGHT, $OP, Int8Array),
             DataType::Int16 => compute_op!($LEFT, $RIGHT, $OP, Int16Array),
             DataType::Int32 => compute_op!($LEFT, $RIGHT, $OP, Int32Array),
@@ -1040,6 +1106,7 @@ macro_rules! binary_array_op_dyn_scalar {
         let result: Result<Arc<dyn Array>> = match right {
             ScalarValue::Boolean(b) => compute_bool_op_dyn_scalar!($LEFT, b, $OP, $OP_TYPE),
             ScalarValue::Decimal128(..) => compute_decimal_op_dyn_scalar!($LEFT, right, $OP, $OP_TYPE),
+            ScalarValue::Decimal256(..) => compute_array_op_dyn_scalar!($LEFT, right, $OP, $OP_TYPE),
             ScalarValue::Utf8(v) => compute_utf8_op_dyn_scalar!($LEFT, v, $OP, $OP_TYPE),
             ScalarValue::LargeUtf8(v) => compute_utf8_op_dyn_scalar!($LEFT, v, $OP, $OP_TYPE),
             ScalarValue::Binary(v) => compute_binary_op_dyn_scalar!($LEFT, v, $OP, $OP_TYPE),
@@ -1064,6 +1131,13 @@ macro_rules! binary_array_op_dyn_scalar {
             ScalarValue::TimestampMillisecond(v, _) => compute_op_dyn_scalar!($LEFT, v, $OP, $OP_TYPE),
             ScalarValue::TimestampMicrosecond(v, _) => compute_op_dyn_scalar!($LEFT, v, $OP, $OP_TYPE),
             ScalarValue::TimestampNanosecond(v, _) => compute_op_dyn_scalar!($LEFT, v, $OP, $OP_TYPE),
+            ScalarValue::IntervalYearMonth(_)
+            | ScalarValue::IntervalDayTime(_)
+            | ScalarValue::IntervalMonthDayNano(_)
+            | ScalarValue::DurationSecond(_)
+            | ScalarValue::DurationMillisecond(_)
+            | ScalarValue::DurationMicrosecond(_)
+            | ScalarValue::DurationNanosecond(_) => compute_array_op_dyn_scalar!($LEFT, right, $OP, $OP_TYPE),
             other => Err(DataFusionError::Internal(format!(
                 "Data type {:?} not supported for scalar operation '{}' on dyn array",
                 other, stringify!($OP)))
@@ -1313,6 +1387,7 @@ mod tests {
     use arrow::datatypes::{
         ArrowNumericType, Decimal128Type, Field, Int32Type, SchemaRef,
     };
+    use arrow_buffer::i256;
     use datafusion_common::{ColumnStatistics, Result, Statistics};
     use datafusion_expr::type_coercion::binary::coerce_types;
 
@@ -3944,6 +4019,97 @@ mod tests {
         Ok(())
     }
 
+    fn create_decimal256_array(
+        array: &[Option<i128>],
+        precision: u8,
+        scale: i8,
+    ) -> Decimal256Array {
+        array
+            .iter()
+            .map(|value| value.map(i256::from_i128))
+            .collect::<Decimal256Array>()
+            .with_precision_and_scale(precision, scale)
+            .unwrap()
+    }
+
+    #[test]
+    fn arithmetic_decimal256_expr_test() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Decimal256(50, 2), true),
+            Field::new("b", DataType::Decimal256(50, 2), true),
+        ]));
+        let left = Arc::new(create_decimal256_array(
+            &[Some(123), None, Some(122), Some(124)],
+            50,
+            2,
+        )) as ArrayRef;
+        let right = Arc::new(create_decimal256_array(
+            &[Some(100), Some(100), Some(200), Some(0)],
+            50,
+            2,
+        )) as ArrayRef;
+
+        let expect = Arc::new(create_decimal256_array(
+            &[Some(223), None, Some(322), Some(124)],
+            50,
+            2,
+        )) as ArrayRef;
+        apply_arithmetic_op(&schema, &left, &right, Operator::Plus, expect)?;
+
+        let expect = Arc::new(create_decimal256_array(
+            &[Some(23), None, Some(-78), Some(124)],
+            50,
+            2,
+        )) as ArrayRef;
+        apply_arithmetic_op(&schema, &left, &right, Operator::Minus, expect)?;
+
+        let expect = Arc::new(create_decimal256_array(
+            &[Some(123), None, Some(244), Some(0)],
+            50,
+            2,
+        )) as ArrayRef;
+        apply_arithmetic_op(&schema, &left, &right, Operator::Multiply, expect)?;
+
+        // dividing by zero yields null
+        let expect = Arc::new(create_decimal256_array(
+            &[Some(123), None, Some(61), None],
+            50,
+            2,
+        )) as ArrayRef;
+        apply_arithmetic_op(&schema, &left, &right, Operator::Divide, expect)?;
+
+        // comparison with a scalar
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::Decimal256(50, 2),
+            true,
+        )]));
+        let scalar = ScalarValue::Decimal256(Some(i256::from_i128(123)), 50, 2);
+        apply_logic_op_arr_scalar(
+            &schema,
+            &left,
+            &scalar,
+            Operator::Eq,
+            &BooleanArray::from(vec![Some(true), None, Some(false), Some(false)]),
+        )?;
+        apply_logic_op_arr_scalar(
+            &schema,
+            &left,
+            &scalar,
+            Operator::Lt,
+            &BooleanArray::from(vec![Some(false), None, Some(true), Some(false)]),
+        )?;
+        apply_logic_op_arr_scalar(
+            &schema,
+            &left,
+            &scalar,
+            Operator::GtEq,
+            &BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]),
+        )?;
+
+        Ok(())
+    }
+
     #[test]
     fn arithmetic_decimal_float_expr_test() -> Result<()> {
         let schema = Arc::new(Schema::new(vec![
@@ -18,20 +18,30 @@
 //! This module contains functions that change types or names of other
 //! kernels to make them compatible with the main dispatch logic
 
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 use arrow::array::*;
+use arrow::datatypes::{
+    ArrowPrimitiveType, DataType, DurationMicrosecondType, DurationMillisecondType,
+    DurationNanosecondType, DurationSecondType, IntervalDayTimeType,
+    IntervalMonthDayNanoType, IntervalUnit, IntervalYearMonthType, TimeUnit,
+};
+use datafusion_common::cast::as_primitive_array;
 use datafusion_common::Result;
 
 /// create a `dyn_op` wrapper function for the specified operation
 /// that call the underlying dyn_op arrow kernel if the type is
 /// supported, and translates ArrowError to DataFusionError
 macro_rules! make_dyn_comp_op {
-    ($OP:tt) => {
+    ($OP:tt, $ORDERING:ident) => {
         paste::paste! {
             /// wrapper over arrow compute kernel that maps Error types and
             /// patches missing support in arrow
             pub(crate) fn [<$OP _dyn>] (left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+                if let Some(result) = compare_temporal(left, right, Ordering::$ORDERING) {
+                    return result;
+                }
                 arrow::compute::kernels::comparison::[<$OP _dyn>](left, right)
                             .map_err(|e| e.into())
                             .map(|a| Arc::new(a) as ArrayRef)
@@ -41,9 +51,80 @@ macro_rules! make_dyn_comp_op {
 }
 
 // create eq_dyn, gt_dyn, wrappers etc
-make_dyn_comp_op!(eq);
-make_dyn_comp_op!(gt);
-make_dyn_comp_op!(gt_eq);
-make_dyn_comp_op!(lt);
-make_dyn_comp_op!(lt_eq);
-make_dyn_comp_op!(neq);
+make_dyn_comp_op!(eq, is_eq);
+make_dyn_comp_op!(gt, is_gt);
+make_dyn_comp_op!(gt_eq, is_ge);
+make_dyn_comp_op!(lt, is_lt);
+make_dyn_comp_op!(lt_eq, is_le);
+make_dyn_comp_op!(neq, is_ne);
+
+/// Compares interval and duration arrays of the same type, which the
+/// arrow dyn comparison kernels do not support. Intervals are ordered
+/// by their components, most significant first. Returns `None` for
+/// all other types.
+fn compare_temporal(
+    left: &dyn Array,
+    right: &dyn Array,
+    op: fn(Ordering) -> bool,
+) -> Option<Result<ArrayRef>> {
+    if left.data_type() != right.data_type() {
+        return None;
+    }
+    let result = match left.data_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            compare_primitive::<IntervalYearMonthType, _>(left, right, |l, r| {
+                op(l.cmp(&r))
+            })
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            compare_primitive::<IntervalDayTimeType, _>(left, right, |l, r| {
+                op(IntervalDayTimeType::to_parts(l)
+                    .cmp(&IntervalDayTimeType::to_parts(r)))
+            })
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            compare_primitive::<IntervalMonthDayNanoType, _>(left, right, |l, r| {
+                op(IntervalMonthDayNanoType::to_parts(l)
+                    .cmp(&IntervalMonthDayNanoType::to_parts(r)))
+            })
+        }
+        DataType::Duration(TimeUnit::Second) => {
+            compare_primitive::<DurationSecondType, _>(left, right, |l, r| op(l.cmp(&r)))
+        }
+        DataType::Duration(TimeUnit::Millisecond) => {
+            compare_primitive::<DurationMillisecondType, _>(left, right, |l, r| {
+                op(l.cmp(&r))
+            })
+        }
+        DataType::Duration(TimeUnit::Microsecond) => {
+            compare_primitive::<DurationMicrosecondType, _>(left, right, |l, r| {
+                op(l.cmp(&r))
+            })
+        }
+        DataType::Duration(TimeUnit::Nanosecond) => {
+            compare_primitive::<DurationNanosecondType, _>(left, right, |l, r| {
+                op(l.cmp(&r))
+            })
+        }
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn compare_primitive<T, F>(left: &dyn Array, right: &dyn Array, op: F) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native, T::Native) -> bool,
+{
+    let left = as_primitive_array::<T>(left)?;
+    let right = as_primitive_array::<T>(right)?;
+    let result = left
+        .iter()
+        .zip(right.iter())
+        .map(|(l, r)| match (l, r) {
+            (Some(l), Some(r)) => Some(op(l, r)),
+            _ => None,
+        })
+        .collect::<BooleanArray>();
+    Ok(Arc::new(result))
+}
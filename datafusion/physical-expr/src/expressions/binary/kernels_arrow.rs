@@ -24,8 +24,9 @@ use arrow::compute::{
 };
 use arrow::datatypes::Decimal128Type;
 use arrow::{array::*, datatypes::ArrowNumericType, downcast_dictionary_array};
-use arrow_schema::DataType;
-use datafusion_common::cast::as_decimal128_array;
+use arrow_buffer::i256;
+use arrow_schema::{ArrowError, DataType};
+use datafusion_common::cast::{as_decimal128_array, as_decimal256_array};
 use datafusion_common::{DataFusionError, Result};
 use std::sync::Arc;
 
@@ -409,6 +410,221 @@ pub(crate) fn modulus_decimal_scalar(
     Ok(array)
 }
 
+pub(crate) fn is_distinct_from_decimal256(
+    left: &Decimal256Array,
+    right: &Decimal256Array,
+) -> Result<BooleanArray> {
+    Ok(left
+        .iter()
+        .zip(right.iter())
+        .map(|(left, right)| match (left, right) {
+            (None, None) => Some(false),
+            (None, Some(_)) | (Some(_), None) => Some(true),
+            (Some(left), Some(right)) => Some(left != right),
+        })
+        .collect())
+}
+
+pub(crate) fn is_not_distinct_from_decimal256(
+    left: &Decimal256Array,
+    right: &Decimal256Array,
+) -> Result<BooleanArray> {
+    Ok(left
+        .iter()
+        .zip(right.iter())
+        .map(|(left, right)| match (left, right) {
+            (None, None) => Some(true),
+            (None, Some(_)) | (Some(_), None) => Some(false),
+            (Some(left), Some(right)) => Some(left == right),
+        })
+        .collect())
+}
+
+/// Returns `10^scale` as an [`i256`]
+fn i256_pow10(scale: i8) -> i256 {
+    let ten = i256::from_i128(10);
+    (0..scale.max(0)).fold(i256::ONE, |acc, _| acc.wrapping_mul(ten))
+}
+
+/// Applies `op` to each pair of non null values of two `Decimal256` arrays,
+/// keeping the precision and scale of `left`
+fn decimal256_binary_op<F>(left: &dyn Array, right: &dyn Array, op: F) -> Result<ArrayRef>
+where
+    F: Fn(i256, i256) -> Result<Option<i256>>,
+{
+    let left = as_decimal256_array(left)?;
+    let right = as_decimal256_array(right)?;
+    let array = left
+        .iter()
+        .zip(right.iter())
+        .map(|(left, right)| match (left, right) {
+            (Some(left), Some(right)) => op(left, right),
+            _ => Ok(None),
+        })
+        .collect::<Result<Decimal256Array>>()?
+        .with_precision_and_scale(left.precision(), left.scale())?;
+    Ok(Arc::new(array))
+}
+
+/// Applies `op` to each non null value of a `Decimal256` array, keeping its
+/// precision and scale
+fn decimal256_scalar_op<F>(left: &dyn Array, op: F) -> Result<ArrayRef>
+where
+    F: Fn(i256) -> Result<Option<i256>>,
+{
+    let left = as_decimal256_array(left)?;
+    let array = left
+        .iter()
+        .map(|left| left.map_or(Ok(None), &op))
+        .collect::<Result<Decimal256Array>>()?
+        .with_precision_and_scale(left.precision(), left.scale())?;
+    Ok(Arc::new(array))
+}
+
+fn decimal256_scale(left: &dyn Array) -> Result<i8> {
+    Ok(as_decimal256_array(left)?.scale())
+}
+
+/// Turns the result of a checked `i256` operation into an overflow error
+fn decimal256_checked(
+    result: Option<i256>,
+    left: i256,
+    op: &str,
+    right: i256,
+) -> Result<Option<i256>> {
+    match result {
+        Some(result) => Ok(Some(result)),
+        None => Err(DataFusionError::ArrowError(ArrowError::ComputeError(
+            format!("Overflow happened on: {left:?} {op} {right:?}"),
+        ))),
+    }
+}
+
+/// Multiplies `left` by `right` and divides the product by `divide`,
+/// returning an overflow error if the product does not fit into an `i256`
+fn decimal256_mul_div(left: i256, right: i256, divide: i256) -> Result<Option<i256>> {
+    let product = decimal256_checked(left.checked_mul(right), left, "*", right)?;
+    Ok(product.and_then(|product| product.checked_div(divide)))
+}
+
+pub(crate) fn add_dyn_decimal256(
+    left: &dyn Array,
+    right: &dyn Array,
+) -> Result<ArrayRef> {
+    decimal256_binary_op(left, right, |l, r| {
+        decimal256_checked(l.checked_add(r), l, "+", r)
+    })
+}
+
+pub(crate) fn add_decimal256_dyn_scalar(
+    left: &dyn Array,
+    right: i256,
+) -> Result<ArrayRef> {
+    decimal256_scalar_op(left, |l| {
+        decimal256_checked(l.checked_add(right), l, "+", right)
+    })
+}
+
+pub(crate) fn subtract_dyn_decimal256(
+    left: &dyn Array,
+    right: &dyn Array,
+) -> Result<ArrayRef> {
+    decimal256_binary_op(left, right, |l, r| {
+        decimal256_checked(l.checked_sub(r), l, "-", r)
+    })
+}
+
+pub(crate) fn subtract_decimal256_dyn_scalar(
+    left: &dyn Array,
+    right: i256,
+) -> Result<ArrayRef> {
+    decimal256_scalar_op(left, |l| {
+        decimal256_checked(l.checked_sub(right), l, "-", right)
+    })
+}
+
+pub(crate) fn multiply_dyn_decimal256(
+    left: &dyn Array,
+    right: &dyn Array,
+) -> Result<ArrayRef> {
+    let divide = i256_pow10(decimal256_scale(left)?);
+    decimal256_binary_op(left, right, |l, r| decimal256_mul_div(l, r, divide))
+}
+
+pub(crate) fn multiply_decimal256_dyn_scalar(
+    left: &dyn Array,
+    right: i256,
+) -> Result<ArrayRef> {
+    let divide = i256_pow10(decimal256_scale(left)?);
+    decimal256_scalar_op(left, |l| decimal256_mul_div(l, right, divide))
+}
+
+/// Divides two `Decimal256` arrays, returning null where the divisor is zero
+pub(crate) fn divide_dyn_opt_decimal256(
+    left: &dyn Array,
+    right: &dyn Array,
+) -> Result<ArrayRef> {
+    let mul = i256_pow10(decimal256_scale(left)?);
+    decimal256_binary_op(left, right, |l, r| {
+        if r == i256::ZERO {
+            Ok(None)
+        } else {
+            decimal256_mul_div(l, mul, r)
+        }
+    })
+}
+
+pub(crate) fn divide_decimal256_dyn_scalar(
+    left: &dyn Array,
+    right: i256,
+) -> Result<ArrayRef> {
+    if right == i256::ZERO {
+        return Err(DataFusionError::ArrowError(ArrowError::DivideByZero));
+    }
+    let mul = i256_pow10(decimal256_scale(left)?);
+    decimal256_scalar_op(left, |l| decimal256_mul_div(l, mul, right))
+}
+
+pub(crate) fn modulus_decimal256(
+    left: &Decimal256Array,
+    right: &Decimal256Array,
+) -> Result<Decimal256Array> {
+    let array = left
+        .iter()
+        .zip(right.iter())
+        .map(|(left, right)| match (left, right) {
+            (Some(_), Some(right)) if right == i256::ZERO => {
+                Err(DataFusionError::ArrowError(ArrowError::DivideByZero))
+            }
+            (Some(left), Some(right)) => {
+                decimal256_checked(left.checked_rem(right), left, "%", right)
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<Decimal256Array>>()?
+        .with_precision_and_scale(left.precision(), left.scale())?;
+    Ok(array)
+}
+
+pub(crate) fn modulus_decimal256_scalar(
+    left: &Decimal256Array,
+    right: i256,
+) -> Result<Decimal256Array> {
+    if right == i256::ZERO {
+        return Err(DataFusionError::ArrowError(ArrowError::DivideByZero));
+    }
+    let array = left
+        .iter()
+        .map(|left| {
+            left.map_or(Ok(None), |left| {
+                decimal256_checked(left.checked_rem(right), left, "%", right)
+            })
+        })
+        .collect::<Result<Decimal256Array>>()?
+        .with_precision_and_scale(left.precision(), left.scale())?;
+    Ok(array)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -595,6 +811,145 @@ mod tests {
         assert_eq!("Arrow error: Divide by zero error", err.to_string());
     }
 
+    fn create_decimal256_array(
+        array: &[Option<i128>],
+        precision: u8,
+        scale: i8,
+    ) -> Decimal256Array {
+        array
+            .iter()
+            .map(|value| value.map(i256::from_i128))
+            .collect::<Decimal256Array>()
+            .with_precision_and_scale(precision, scale)
+            .unwrap()
+    }
+
+    #[test]
+    fn arithmetic_decimal256_op_test() -> Result<()> {
+        let left_decimal_array = create_decimal256_array(
+            &[
+                Some(1234567),
+                None,
+                Some(1234567),
+                Some(1234567),
+                Some(1234567),
+            ],
+            50,
+            3,
+        );
+        let right_decimal_array = create_decimal256_array(
+            &[Some(10), Some(10), Some(30), Some(-1000), Some(0)],
+            50,
+            3,
+        );
+        // add
+        let result = add_dyn_decimal256(&left_decimal_array, &right_decimal_array)?;
+        let expect = create_decimal256_array(
+            &[
+                Some(1234577),
+                None,
+                Some(1234597),
+                Some(1233567),
+                Some(1234567),
+            ],
+            50,
+            3,
+        );
+        assert_eq!(&expect, as_decimal256_array(&result)?);
+        let result = add_decimal256_dyn_scalar(&left_decimal_array, i256::from_i128(3))?;
+        let expect = create_decimal256_array(
+            &[
+                Some(1234570),
+                None,
+                Some(1234570),
+                Some(1234570),
+                Some(1234570),
+            ],
+            50,
+            3,
+        );
+        assert_eq!(&expect, as_decimal256_array(&result)?);
+        // subtract
+        let result = subtract_dyn_decimal256(&left_decimal_array, &right_decimal_array)?;
+        let expect = create_decimal256_array(
+            &[
+                Some(1234557),
+                None,
+                Some(1234537),
+                Some(1235567),
+                Some(1234567),
+            ],
+            50,
+            3,
+        );
+        assert_eq!(&expect, as_decimal256_array(&result)?);
+        // multiply
+        let result = multiply_dyn_decimal256(&left_decimal_array, &right_decimal_array)?;
+        let expect = create_decimal256_array(
+            &[Some(12345), None, Some(37037), Some(-1234567), Some(0)],
+            50,
+            3,
+        );
+        assert_eq!(&expect, as_decimal256_array(&result)?);
+        // divide
+        let result =
+            divide_dyn_opt_decimal256(&left_decimal_array, &right_decimal_array)?;
+        let expect = create_decimal256_array(
+            &[Some(123456700), None, Some(41152233), Some(-1234567), None],
+            50,
+            3,
+        );
+        assert_eq!(&expect, as_decimal256_array(&result)?);
+        // modulus
+        let right_decimal_array = create_decimal256_array(
+            &[Some(10), Some(10), Some(30), Some(-1000), Some(7)],
+            50,
+            3,
+        );
+        let result = modulus_decimal256(&left_decimal_array, &right_decimal_array)?;
+        let expect =
+            create_decimal256_array(&[Some(7), None, Some(7), Some(567), Some(5)], 50, 3);
+        assert_eq!(expect, result);
+
+        let err =
+            divide_decimal256_dyn_scalar(&left_decimal_array, i256::ZERO).unwrap_err();
+        assert_eq!("Arrow error: Divide by zero error", err.to_string());
+        let err = modulus_decimal256_scalar(&left_decimal_array, i256::ZERO).unwrap_err();
+        assert_eq!("Arrow error: Divide by zero error", err.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn arithmetic_decimal256_overflow() -> Result<()> {
+        let max_array = vec![Some(i256::MAX)]
+            .into_iter()
+            .collect::<Decimal256Array>()
+            .with_precision_and_scale(76, 0)?;
+        let min_array = vec![Some(i256::MIN)]
+            .into_iter()
+            .collect::<Decimal256Array>()
+            .with_precision_and_scale(76, 0)?;
+        let one_array = create_decimal256_array(&[Some(1)], 76, 0);
+        let two = i256::from_i128(2);
+
+        let err = add_dyn_decimal256(&max_array, &one_array).unwrap_err();
+        assert!(err.to_string().contains("Overflow happened"), "{err}");
+        let err = add_decimal256_dyn_scalar(&max_array, i256::ONE).unwrap_err();
+        assert!(err.to_string().contains("Overflow happened"), "{err}");
+        let err = subtract_dyn_decimal256(&min_array, &one_array).unwrap_err();
+        assert!(err.to_string().contains("Overflow happened"), "{err}");
+        let err = subtract_decimal256_dyn_scalar(&min_array, i256::ONE).unwrap_err();
+        assert!(err.to_string().contains("Overflow happened"), "{err}");
+        let err = multiply_decimal256_dyn_scalar(&max_array, two).unwrap_err();
+        assert!(err.to_string().contains("Overflow happened"), "{err}");
+
+        // scaling the dividend up overflows as well
+        let max_array = max_array.with_precision_and_scale(76, 2)?;
+        let err = divide_decimal256_dyn_scalar(&max_array, two).unwrap_err();
+        assert!(err.to_string().contains("Overflow happened"), "{err}");
+        Ok(())
+    }
+
     #[test]
     fn is_distinct_from_non_nulls() -> Result<()> {
         let left_int_array =
@@ -17,17 +17,19 @@
 
 use crate::physical_expr::down_cast_any_ref;
 use crate::PhysicalExpr;
-use arrow::array::{Array, ArrayRef};
-use arrow::compute::unary;
+use arrow::array::{new_empty_array, Array, ArrayRef, PrimitiveArray};
 use arrow::datatypes::{
-    DataType, Date32Type, Date64Type, Schema, TimeUnit, TimestampMicrosecondType,
-    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType,
+    ArrowPrimitiveType, DataType, Date32Type, Date64Type, DurationMicrosecondType,
+    DurationMillisecondType, DurationNanosecondType, DurationSecondType, Schema,
+    TimeUnit, TimestampMicrosecondType, TimestampMillisecondType,
+    TimestampNanosecondType, TimestampSecondType,
 };
+use arrow::error::ArrowError;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::cast::{
-    as_date32_array, as_date64_array, as_timestamp_microsecond_array,
-    as_timestamp_millisecond_array, as_timestamp_nanosecond_array,
-    as_timestamp_second_array,
+    as_date32_array, as_date64_array, as_int64_array, as_primitive_array,
+    as_timestamp_microsecond_array, as_timestamp_millisecond_array,
+    as_timestamp_nanosecond_array, as_timestamp_second_array,
 };
 use datafusion_common::scalar::{
     date32_add, date64_add, microseconds_add, milliseconds_add, nanoseconds_add,
@@ -40,7 +42,9 @@ use std::any::Any;
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
-/// Perform DATE/TIME/TIMESTAMP +/ INTERVAL math
+/// Perform DATE/TIME/TIMESTAMP +/ INTERVAL math, as well as arithmetic
+/// on timestamps and durations: TIMESTAMP - TIMESTAMP, TIMESTAMP +/- DURATION,
+/// DURATION +/- DURATION and DURATION * or / INTEGER
 #[derive(Debug)]
 pub struct DateTimeIntervalExpr {
     lhs: Arc<dyn PhysicalExpr>,
@@ -59,29 +63,75 @@ impl DateTimeIntervalExpr {
         rhs: Arc<dyn PhysicalExpr>,
         input_schema: &Schema,
     ) -> Result<Self> {
-        match lhs.data_type(input_schema)? {
-            DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _) => {
-                match rhs.data_type(input_schema)? {
-                    DataType::Interval(_) => match &op {
-                        Operator::Plus | Operator::Minus => Ok(Self {
-                            lhs,
-                            op,
-                            rhs,
-                            input_schema: input_schema.clone(),
-                        }),
-                        _ => Err(DataFusionError::Execution(format!(
-                            "Invalid operator '{op}' for DateIntervalExpr"
-                        ))),
-                    },
-                    other => Err(DataFusionError::Execution(format!(
-                        "Operation '{op}' not support for type {other}"
-                    ))),
-                }
+        match (
+            &lhs.data_type(input_schema)?,
+            &op,
+            &rhs.data_type(input_schema)?,
+        ) {
+            (
+                DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _),
+                Operator::Plus | Operator::Minus,
+                DataType::Interval(_),
+            )
+            | (
+                DataType::Timestamp(_, _),
+                Operator::Plus | Operator::Minus,
+                DataType::Duration(_),
+            )
+            | (
+                DataType::Duration(_),
+                Operator::Multiply | Operator::Divide,
+                DataType::Int64,
+            ) => {}
+            (
+                DataType::Timestamp(lhs_unit, _),
+                Operator::Minus,
+                DataType::Timestamp(rhs_unit, _),
+            )
+            | (
+                DataType::Duration(lhs_unit),
+                Operator::Plus | Operator::Minus,
+                DataType::Duration(rhs_unit),
+            ) if lhs_unit == rhs_unit => {}
+            (
+                DataType::Date32
+                | DataType::Date64
+                | DataType::Timestamp(_, _)
+                | DataType::Duration(_),
+                _,
+                DataType::Interval(_)
+                | DataType::Duration(_)
+                | DataType::Timestamp(_, _)
+                | DataType::Int64,
+            ) => {
+                return Err(DataFusionError::Execution(format!(
+                    "Invalid operator '{op}' for DateIntervalExpr"
+                )))
+            }
+            (
+                DataType::Date32
+                | DataType::Date64
+                | DataType::Timestamp(_, _)
+                | DataType::Duration(_),
+                _,
+                other,
+            ) => {
+                return Err(DataFusionError::Execution(format!(
+                    "Operation '{op}' not support for type {other}"
+                )))
+            }
+            (other, _, _) => {
+                return Err(DataFusionError::Execution(format!(
+                    "Invalid lhs type '{other}' for DateIntervalExpr"
+                )))
             }
-            other => Err(DataFusionError::Execution(format!(
-                "Invalid lhs type '{other}' for DateIntervalExpr"
-            ))),
         }
+        Ok(Self {
+            lhs,
+            op,
+            rhs,
+            input_schema: input_schema.clone(),
+        })
     }
 
     /// Get the left-hand side expression
@@ -112,44 +162,57 @@ impl PhysicalExpr for DateTimeIntervalExpr {
     }
 
     fn data_type(&self, input_schema: &Schema) -> Result<DataType> {
-        self.lhs.data_type(input_schema)
+        match (
+            self.lhs.data_type(input_schema)?,
+            self.rhs.data_type(input_schema)?,
+        ) {
+            (DataType::Timestamp(unit, _), DataType::Timestamp(_, _)) => {
+                Ok(DataType::Duration(unit))
+            }
+            (lhs_type, _) => Ok(lhs_type),
+        }
     }
 
     fn nullable(&self, input_schema: &Schema) -> Result<bool> {
-        self.lhs.nullable(input_schema)
+        Ok(self.lhs.nullable(input_schema)? || self.rhs.nullable(input_schema)?)
     }
 
     fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
-        let dates = self.lhs.evaluate(batch)?;
-        let intervals = self.rhs.evaluate(batch)?;
-
-        // Unwrap interval to add
-        let intervals = match &intervals {
-            ColumnarValue::Scalar(interval) => interval,
-            _ => {
-                let msg = "Columnar execution is not yet supported for DateIntervalExpr";
-                return Err(DataFusionError::Execution(msg.to_string()));
-            }
-        };
-
-        // Invert sign for subtraction
-        let sign = match self.op {
-            Operator::Plus => 1,
-            Operator::Minus => -1,
-            _ => {
-                // this should be unreachable because we check the operators in `try_new`
-                let msg = "Invalid operator for DateIntervalExpr";
-                return Err(DataFusionError::Internal(msg.to_string()));
+        let lhs = self.lhs.evaluate(batch)?;
+        let rhs = self.rhs.evaluate(batch)?;
+
+        match (&lhs.data_type(), &rhs) {
+            (
+                DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _),
+                ColumnarValue::Scalar(interval),
+            ) if matches!(
+                interval.get_datatype(),
+                DataType::Interval(_) | DataType::Duration(_)
+            ) =>
+            {
+                // Invert sign for subtraction
+                let sign = match self.op {
+                    Operator::Plus => 1,
+                    Operator::Minus => -1,
+                    _ => {
+                        // this should be unreachable because we check the operators in `try_new`
+                        let msg = "Invalid operator for DateIntervalExpr";
+                        return Err(DataFusionError::Internal(msg.to_string()));
+                    }
+                };
+
+                match lhs {
+                    ColumnarValue::Scalar(operand) => {
+                        Ok(ColumnarValue::Scalar(if sign > 0 {
+                            operand.add(interval)?
+                        } else {
+                            operand.sub(interval)?
+                        }))
+                    }
+                    ColumnarValue::Array(array) => evaluate_array(array, sign, interval),
+                }
             }
-        };
-
-        match dates {
-            ColumnarValue::Scalar(operand) => Ok(ColumnarValue::Scalar(if sign > 0 {
-                operand.add(intervals)?
-            } else {
-                operand.sub(intervals)?
-            })),
-            ColumnarValue::Array(array) => evaluate_array(array, sign, intervals),
+            _ => self.evaluate_arrays(lhs, rhs, batch.num_rows()),
         }
     }
 
@@ -170,6 +233,62 @@ impl PhysicalExpr for DateTimeIntervalExpr {
     }
 }
 
+impl DateTimeIntervalExpr {
+    /// Evaluates the expression element wise, expanding scalar arguments to
+    /// arrays. The result is a scalar when both arguments are scalars.
+    fn evaluate_arrays(
+        &self,
+        lhs: ColumnarValue,
+        rhs: ColumnarValue,
+        num_rows: usize,
+    ) -> Result<ColumnarValue> {
+        let is_scalar = matches!(
+            (&lhs, &rhs),
+            (ColumnarValue::Scalar(_), ColumnarValue::Scalar(_))
+        );
+        let num_rows = if is_scalar { 1 } else { num_rows };
+        let (lhs, rhs) = (lhs.into_array(num_rows), rhs.into_array(num_rows));
+
+        let result = match (lhs.data_type(), &self.op, rhs.data_type()) {
+            (DataType::Timestamp(_, _), Operator::Minus, DataType::Timestamp(_, _)) => {
+                timestamp_sub(&lhs, &rhs)?
+            }
+            (DataType::Duration(_), Operator::Plus, DataType::Duration(_)) => {
+                duration_op(&lhs, &rhs, i64::checked_add, "+")?
+            }
+            (DataType::Duration(_), Operator::Minus, DataType::Duration(_)) => {
+                duration_op(&lhs, &rhs, i64::checked_sub, "-")?
+            }
+            (DataType::Duration(_), Operator::Multiply, DataType::Int64) => {
+                duration_scale(&lhs, &rhs, |d, n| checked(d.checked_mul(n), d, "*", n))?
+            }
+            (DataType::Duration(_), Operator::Divide, DataType::Int64) => {
+                duration_scale(&lhs, &rhs, |d, n| {
+                    // division by zero yields null
+                    if n == 0 {
+                        Ok(None)
+                    } else {
+                        checked(d.checked_div(n), d, "/", n)
+                    }
+                })?
+            }
+            (_, Operator::Plus, _) => evaluate_rows(&lhs, &rhs, 1)?,
+            (_, Operator::Minus, _) => evaluate_rows(&lhs, &rhs, -1)?,
+            (lhs_type, op, rhs_type) => {
+                return Err(DataFusionError::Internal(format!(
+                    "Invalid operation {lhs_type} {op} {rhs_type} for DateIntervalExpr"
+                )))
+            }
+        };
+
+        Ok(if is_scalar {
+            ColumnarValue::Scalar(ScalarValue::try_from_array(&result, 0)?)
+        } else {
+            ColumnarValue::Array(result)
+        })
+    }
+}
+
 impl PartialEq<dyn Any> for DateTimeIntervalExpr {
     fn eq(&self, other: &dyn Any) -> bool {
         down_cast_any_ref(other)
@@ -187,49 +306,52 @@ pub fn evaluate_array(
     let ret = match array.data_type() {
         DataType::Date32 => {
             let array = as_date32_array(&array)?;
-            Arc::new(unary::<Date32Type, _, Date32Type>(array, |days| {
-                date32_add(days, scalar, sign).unwrap()
-            })) as ArrayRef
+            Arc::new(try_unary_op::<Date32Type, Date32Type, _>(array, |days| {
+                date32_add(days, scalar, sign)
+            })?) as ArrayRef
         }
         DataType::Date64 => {
             let array = as_date64_array(&array)?;
-            Arc::new(unary::<Date64Type, _, Date64Type>(array, |ms| {
-                date64_add(ms, scalar, sign).unwrap()
-            })) as ArrayRef
+            Arc::new(try_unary_op::<Date64Type, Date64Type, _>(array, |ms| {
+                date64_add(ms, scalar, sign)
+            })?) as ArrayRef
         }
         DataType::Timestamp(TimeUnit::Second, _) => {
             let array = as_timestamp_second_array(&array)?;
-            Arc::new(unary::<TimestampSecondType, _, TimestampSecondType>(
+            Arc::new(try_unary_op::<TimestampSecondType, TimestampSecondType, _>(
                 array,
-                |ts_s| seconds_add(ts_s, scalar, sign).unwrap(),
-            )) as ArrayRef
+                |ts_s| seconds_add(ts_s, scalar, sign),
+            )?) as ArrayRef
         }
         DataType::Timestamp(TimeUnit::Millisecond, _) => {
             let array = as_timestamp_millisecond_array(&array)?;
-            Arc::new(
-                unary::<TimestampMillisecondType, _, TimestampMillisecondType>(
-                    array,
-                    |ts_ms| milliseconds_add(ts_ms, scalar, sign).unwrap(),
-                ),
-            ) as ArrayRef
+            Arc::new(try_unary_op::<
+                TimestampMillisecondType,
+                TimestampMillisecondType,
+                _,
+            >(array, |ts_ms| {
+                milliseconds_add(ts_ms, scalar, sign)
+            })?) as ArrayRef
         }
         DataType::Timestamp(TimeUnit::Microsecond, _) => {
             let array = as_timestamp_microsecond_array(&array)?;
-            Arc::new(
-                unary::<TimestampMicrosecondType, _, TimestampMicrosecondType>(
-                    array,
-                    |ts_us| microseconds_add(ts_us, scalar, sign).unwrap(),
-                ),
-            ) as ArrayRef
+            Arc::new(try_unary_op::<
+                TimestampMicrosecondType,
+                TimestampMicrosecondType,
+                _,
+            >(array, |ts_us| {
+                microseconds_add(ts_us, scalar, sign)
+            })?) as ArrayRef
         }
         DataType::Timestamp(TimeUnit::Nanosecond, _) => {
             let array = as_timestamp_nanosecond_array(&array)?;
-            Arc::new(
-                unary::<TimestampNanosecondType, _, TimestampNanosecondType>(
-                    array,
-                    |ts_ns| nanoseconds_add(ts_ns, scalar, sign).unwrap(),
-                ),
-            ) as ArrayRef
+            Arc::new(try_unary_op::<
+                TimestampNanosecondType,
+                TimestampNanosecondType,
+                _,
+            >(array, |ts_ns| {
+                nanoseconds_add(ts_ns, scalar, sign)
+            })?) as ArrayRef
         }
         _ => Err(DataFusionError::Execution(format!(
             "Invalid lhs type for DateIntervalExpr: {}",
@@ -239,12 +361,188 @@ pub fn evaluate_array(
     Ok(ColumnarValue::Array(ret))
 }
 
+/// Applies `op` to the values of `array`, returning the first error of `op`
+fn try_unary_op<I, O, F>(array: &PrimitiveArray<I>, op: F) -> Result<PrimitiveArray<O>>
+where
+    I: ArrowPrimitiveType,
+    O: ArrowPrimitiveType,
+    F: Fn(I::Native) -> Result<O::Native>,
+{
+    array.iter().map(|v| v.map(&op).transpose()).collect()
+}
+
+/// Applies `op` to each pair of values of `lhs` and `rhs`, returning the first
+/// error of `op`. The result is null where either value is null or `op`
+/// returns `None`.
+fn try_binary_op<L, R, O, F>(
+    lhs: &PrimitiveArray<L>,
+    rhs: &PrimitiveArray<R>,
+    op: F,
+) -> Result<PrimitiveArray<O>>
+where
+    L: ArrowPrimitiveType,
+    R: ArrowPrimitiveType,
+    O: ArrowPrimitiveType,
+    F: Fn(L::Native, R::Native) -> Result<Option<O::Native>>,
+{
+    lhs.iter()
+        .zip(rhs.iter())
+        .map(|(l, r)| match (l, r) {
+            (Some(l), Some(r)) => op(l, r),
+            _ => Ok(None),
+        })
+        .collect()
+}
+
+/// Turns the result of a checked `i64` operation into an overflow error
+fn checked(result: Option<i64>, lhs: i64, op: &str, rhs: i64) -> Result<Option<i64>> {
+    match result {
+        Some(result) => Ok(Some(result)),
+        None => Err(DataFusionError::ArrowError(ArrowError::ComputeError(
+            format!("Overflow happened on: {lhs:?} {op} {rhs:?}"),
+        ))),
+    }
+}
+
+/// Subtracts two timestamp arrays of the same unit, returning the
+/// durations between them
+fn timestamp_sub(lhs: &ArrayRef, rhs: &ArrayRef) -> Result<ArrayRef> {
+    macro_rules! sub {
+        ($TIMESTAMP:ty, $DURATION:ty) => {{
+            let lhs = as_primitive_array::<$TIMESTAMP>(lhs)?;
+            let rhs = as_primitive_array::<$TIMESTAMP>(rhs)?;
+            Arc::new(try_binary_op::<_, _, $DURATION, _>(lhs, rhs, |l, r| {
+                checked(l.checked_sub(r), l, "-", r)
+            })?) as ArrayRef
+        }};
+    }
+
+    Ok(match (lhs.data_type(), rhs.data_type()) {
+        (
+            DataType::Timestamp(TimeUnit::Second, _),
+            DataType::Timestamp(TimeUnit::Second, _),
+        ) => sub!(TimestampSecondType, DurationSecondType),
+        (
+            DataType::Timestamp(TimeUnit::Millisecond, _),
+            DataType::Timestamp(TimeUnit::Millisecond, _),
+        ) => sub!(TimestampMillisecondType, DurationMillisecondType),
+        (
+            DataType::Timestamp(TimeUnit::Microsecond, _),
+            DataType::Timestamp(TimeUnit::Microsecond, _),
+        ) => sub!(TimestampMicrosecondType, DurationMicrosecondType),
+        (
+            DataType::Timestamp(TimeUnit::Nanosecond, _),
+            DataType::Timestamp(TimeUnit::Nanosecond, _),
+        ) => sub!(TimestampNanosecondType, DurationNanosecondType),
+        (lhs_type, rhs_type) => {
+            return Err(DataFusionError::Execution(format!(
+                "Cannot subtract {rhs_type} from {lhs_type}"
+            )))
+        }
+    })
+}
+
+/// Applies the checked operation `op`, named `name`, to two duration arrays of
+/// the same unit
+fn duration_op(
+    lhs: &ArrayRef,
+    rhs: &ArrayRef,
+    op: fn(i64, i64) -> Option<i64>,
+    name: &str,
+) -> Result<ArrayRef> {
+    macro_rules! apply {
+        ($DURATION:ty) => {{
+            let lhs = as_primitive_array::<$DURATION>(lhs)?;
+            let rhs = as_primitive_array::<$DURATION>(rhs)?;
+            Arc::new(try_binary_op::<_, _, $DURATION, _>(lhs, rhs, |l, r| {
+                checked(op(l, r), l, name, r)
+            })?) as ArrayRef
+        }};
+    }
+
+    Ok(match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Duration(TimeUnit::Second), DataType::Duration(TimeUnit::Second)) => {
+            apply!(DurationSecondType)
+        }
+        (
+            DataType::Duration(TimeUnit::Millisecond),
+            DataType::Duration(TimeUnit::Millisecond),
+        ) => apply!(DurationMillisecondType),
+        (
+            DataType::Duration(TimeUnit::Microsecond),
+            DataType::Duration(TimeUnit::Microsecond),
+        ) => apply!(DurationMicrosecondType),
+        (
+            DataType::Duration(TimeUnit::Nanosecond),
+            DataType::Duration(TimeUnit::Nanosecond),
+        ) => apply!(DurationNanosecondType),
+        (lhs_type, rhs_type) => {
+            return Err(DataFusionError::Execution(format!(
+                "Cannot combine durations of types {lhs_type} and {rhs_type}"
+            )))
+        }
+    })
+}
+
+/// Multiplies or divides a duration array by an Int64 array
+fn duration_scale(
+    lhs: &ArrayRef,
+    rhs: &ArrayRef,
+    op: fn(i64, i64) -> Result<Option<i64>>,
+) -> Result<ArrayRef> {
+    let factors = as_int64_array(rhs)?;
+
+    macro_rules! apply {
+        ($DURATION:ty) => {{
+            let lhs = as_primitive_array::<$DURATION>(lhs)?;
+            Arc::new(try_binary_op::<_, _, $DURATION, _>(lhs, factors, op)?) as ArrayRef
+        }};
+    }
+
+    Ok(match lhs.data_type() {
+        DataType::Duration(TimeUnit::Second) => apply!(DurationSecondType),
+        DataType::Duration(TimeUnit::Millisecond) => apply!(DurationMillisecondType),
+        DataType::Duration(TimeUnit::Microsecond) => apply!(DurationMicrosecondType),
+        DataType::Duration(TimeUnit::Nanosecond) => apply!(DurationNanosecondType),
+        other => {
+            return Err(DataFusionError::Execution(format!(
+                "Cannot scale values of type {other}"
+            )))
+        }
+    })
+}
+
+/// Adds (`sign` > 0) or subtracts arrays of intervals or durations to
+/// dates or timestamps row by row
+fn evaluate_rows(lhs: &ArrayRef, rhs: &ArrayRef, sign: i32) -> Result<ArrayRef> {
+    if lhs.is_empty() {
+        return Ok(new_empty_array(lhs.data_type()));
+    }
+    let values = (0..lhs.len())
+        .map(|i| {
+            let lhs = ScalarValue::try_from_array(lhs, i)?;
+            let rhs = ScalarValue::try_from_array(rhs, i)?;
+            if lhs.is_null() || rhs.is_null() {
+                ScalarValue::try_from(lhs.get_datatype())
+            } else if sign > 0 {
+                lhs.add(rhs)
+            } else {
+                lhs.sub(rhs)
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    ScalarValue::iter_to_array(values)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::create_physical_expr;
     use crate::execution_props::ExecutionProps;
-    use arrow::array::{ArrayRef, Date32Builder};
+    use arrow::array::{
+        ArrayRef, Date32Builder, DurationMillisecondArray, DurationSecondArray,
+        Int64Array, TimestampSecondArray,
+    };
     use arrow::datatypes::*;
     use chrono::{Duration, NaiveDate};
     use datafusion_common::delta::shift_months;
@@ -573,6 +871,184 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn array_timestamp_sub_timestamp() -> Result<()> {
+        let a: ArrayRef = Arc::new(TimestampSecondArray::from(vec![
+            Some(86_400),
+            Some(10),
+            None,
+        ]));
+        let b: ArrayRef =
+            Arc::new(TimestampSecondArray::from(vec![Some(0), Some(20), Some(0)]));
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Timestamp(TimeUnit::Second, None), true),
+            Field::new("b", DataType::Timestamp(TimeUnit::Second, None), true),
+        ]);
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![a, b])?;
+
+        let cut = DateTimeIntervalExpr::try_new(
+            Arc::new(crate::expressions::Column::new("a", 0)),
+            Operator::Minus,
+            Arc::new(crate::expressions::Column::new("b", 1)),
+            &schema,
+        )?;
+        assert_eq!(
+            cut.data_type(&schema)?,
+            DataType::Duration(TimeUnit::Second)
+        );
+
+        let res = cut.evaluate(&batch)?.into_array(batch.num_rows());
+        let expected: ArrayRef = Arc::new(DurationSecondArray::from(vec![
+            Some(86_400),
+            Some(-10),
+            None,
+        ]));
+        assert_eq!(&res, &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn array_duration_scale() -> Result<()> {
+        let a: ArrayRef = Arc::new(DurationMillisecondArray::from(vec![
+            Some(1_000),
+            Some(500),
+            None,
+        ]));
+        let b: ArrayRef = Arc::new(Int64Array::from(vec![Some(3), Some(0), Some(2)]));
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Duration(TimeUnit::Millisecond), true),
+            Field::new("b", DataType::Int64, true),
+        ]);
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![a, b])?;
+
+        let evaluate = |op| -> Result<ArrayRef> {
+            let cut = DateTimeIntervalExpr::try_new(
+                Arc::new(crate::expressions::Column::new("a", 0)),
+                op,
+                Arc::new(crate::expressions::Column::new("b", 1)),
+                &schema,
+            )?;
+            Ok(cut.evaluate(&batch)?.into_array(batch.num_rows()))
+        };
+
+        let expected: ArrayRef = Arc::new(DurationMillisecondArray::from(vec![
+            Some(3_000),
+            Some(0),
+            None,
+        ]));
+        assert_eq!(&evaluate(Operator::Multiply)?, &expected);
+
+        // division by zero yields null
+        let expected: ArrayRef =
+            Arc::new(DurationMillisecondArray::from(vec![Some(333), None, None]));
+        assert_eq!(&evaluate(Operator::Divide)?, &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn array_duration_overflow() -> Result<()> {
+        let timestamps: ArrayRef =
+            Arc::new(TimestampSecondArray::from(vec![Some(1), Some(-1)]));
+        let durations: ArrayRef =
+            Arc::new(DurationSecondArray::from(vec![Some(i64::MAX), Some(1)]));
+        let factors: ArrayRef = Arc::new(Int64Array::from(vec![Some(2), Some(1)]));
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Timestamp(TimeUnit::Second, None), true),
+            Field::new("b", DataType::Duration(TimeUnit::Second), true),
+            Field::new("c", DataType::Int64, true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![timestamps, durations, factors],
+        )?;
+
+        let evaluate = |lhs, op, rhs: Arc<dyn PhysicalExpr>| -> Result<ArrayRef> {
+            let cut = DateTimeIntervalExpr::try_new(lhs, op, rhs, &schema)?;
+            Ok(cut.evaluate(&batch)?.into_array(batch.num_rows()))
+        };
+        let column = |name, index| -> Arc<dyn PhysicalExpr> {
+            Arc::new(crate::expressions::Column::new(name, index))
+        };
+        let timestamp: Arc<dyn PhysicalExpr> =
+            Arc::new(crate::expressions::Literal::new(
+                ScalarValue::TimestampSecond(Some(i64::MIN), None),
+            ));
+        let duration: Arc<dyn PhysicalExpr> = Arc::new(crate::expressions::Literal::new(
+            ScalarValue::DurationSecond(Some(i64::MIN)),
+        ));
+
+        let cases = [
+            evaluate(column("a", 0), Operator::Minus, timestamp),
+            evaluate(column("b", 1), Operator::Plus, column("b", 1)),
+            evaluate(column("b", 1), Operator::Minus, duration.clone()),
+            evaluate(column("b", 1), Operator::Multiply, column("c", 2)),
+            evaluate(column("a", 0), Operator::Plus, duration),
+        ];
+        for result in cases {
+            let err = result.unwrap_err();
+            assert!(err.to_string().contains("Overflow happened"), "{err}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_duration_to_timestamp() -> Result<()> {
+        let ts = Expr::Literal(ScalarValue::TimestampSecond(Some(0), None));
+        let duration = Expr::Literal(ScalarValue::DurationSecond(Some(3_600)));
+
+        let schema = Schema::new(vec![Field::new("a", DataType::Date32, false)]);
+        let dfs = schema.clone().to_dfschema()?;
+        let props = ExecutionProps::new();
+        let lhs = create_physical_expr(&ts, &dfs, &schema, &props)?;
+        let rhs = create_physical_expr(&duration, &dfs, &schema, &props)?;
+        let cut = DateTimeIntervalExpr::try_new(lhs, Operator::Plus, rhs, &schema)?;
+
+        let batch = RecordBatch::new_empty(Arc::new(schema));
+        match cut.evaluate(&batch)? {
+            ColumnarValue::Scalar(ScalarValue::TimestampSecond(value, None)) => {
+                assert_eq!(value, Some(3_600))
+            }
+            other => Err(DataFusionError::NotImplemented(format!(
+                "Unexpected result {other:?}"
+            )))?,
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_duration_op() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Duration(TimeUnit::Second), false),
+            Field::new("b", DataType::Duration(TimeUnit::Millisecond), false),
+        ]);
+        let column = |name, index| -> Arc<dyn PhysicalExpr> {
+            Arc::new(crate::expressions::Column::new(name, index))
+        };
+
+        // durations of different units need to be coerced first
+        let res = DateTimeIntervalExpr::try_new(
+            column("a", 0),
+            Operator::Plus,
+            column("b", 1),
+            &schema,
+        );
+        assert!(res.is_err(), "Can't add durations of different units");
+
+        let res = DateTimeIntervalExpr::try_new(
+            column("a", 0),
+            Operator::Multiply,
+            column("a", 0),
+            &schema,
+        );
+        assert!(res.is_err(), "Can't multiply durations");
+
+        Ok(())
+    }
+
     #[test]
     fn invalid_interval() -> Result<()> {
         // setup
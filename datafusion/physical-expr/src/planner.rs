@@ -207,6 +207,35 @@ pub fn create_physical_expr(
                     lhs,
                     input_schema,
                 )?)),
+                (
+                    DataType::Timestamp(_, _),
+                    Operator::Plus | Operator::Minus,
+                    DataType::Duration(_),
+                )
+                | (
+                    DataType::Timestamp(_, _),
+                    Operator::Minus,
+                    DataType::Timestamp(_, _),
+                )
+                | (
+                    DataType::Duration(_),
+                    Operator::Plus | Operator::Minus,
+                    DataType::Duration(_),
+                )
+                | (
+                    DataType::Duration(_),
+                    Operator::Multiply | Operator::Divide,
+                    DataType::Int64,
+                ) => Ok(Arc::new(DateTimeIntervalExpr::try_new(
+                    lhs,
+                    *op,
+                    rhs,
+                    input_schema,
+                )?)),
+                (DataType::Duration(_), Operator::Plus, DataType::Timestamp(_, _))
+                | (DataType::Int64, Operator::Multiply, DataType::Duration(_)) => Ok(
+                    Arc::new(DateTimeIntervalExpr::try_new(rhs, *op, lhs, input_schema)?),
+                ),
                 _ => {
                     // Note that the logical planner is responsible
                     // for type coercion on the arguments (e.g. if one
@@ -47,6 +47,11 @@ impl WindowShift {
     pub fn get_shift_offset(&self) -> i64 {
         self.shift_offset
     }
+
+    /// Get the default value of window shift expression
+    pub fn get_default_value(&self) -> Option<&ScalarValue> {
+        self.default_value.as_ref()
+    }
 }
 
 /// lead() window function
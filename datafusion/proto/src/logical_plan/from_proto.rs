@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::logical_plan::{DefaultLogicalExtensionCodec, LogicalExtensionCodec};
 use crate::protobuf::{
     self,
     plan_type::PlanTypeEnum::{
@@ -22,12 +23,14 @@ use crate::protobuf::{
         OptimizedLogicalPlan, OptimizedPhysicalPlan,
     },
     CubeNode, GroupingSetNode, OptimizedLogicalPlanType, OptimizedPhysicalPlanType,
-    PlaceholderNode, RollupNode,
+    PlaceholderNode, RollupNode, ScalarVariableNode,
 };
 use arrow::datatypes::{
-    DataType, Field, IntervalMonthDayNanoType, IntervalUnit, Schema, TimeUnit, UnionMode,
+    i256, DataType, Field, IntervalMonthDayNanoType, IntervalUnit, Schema, TimeUnit,
+    UnionMode,
 };
 use datafusion::execution::registry::FunctionRegistry;
+use datafusion::prelude::SessionContext;
 use datafusion_common::{
     Column, DFField, DFSchema, DFSchemaRef, DataFusionError, OwnedTableReference,
     ScalarValue,
@@ -38,19 +41,20 @@ use datafusion_expr::{
     date_part, date_trunc, digest, exp,
     expr::{self, Sort, WindowFunction},
     floor, from_unixtime, left, ln, log, log10, log2,
-    logical_plan::{PlanType, StringifiedPlan},
+    logical_plan::{PlanType, StringifiedPlan, Subquery, WriteOp},
     lower, lpad, ltrim, md5, now, nullif, octet_length, power, random, regexp_match,
     regexp_replace, repeat, replace, reverse, right, round, rpad, rtrim, sha224, sha256,
     sha384, sha512, signum, sin, split_part, sqrt, starts_with, strpos, substr,
     substring, tan, to_hex, to_timestamp_micros, to_timestamp_millis,
-    to_timestamp_seconds, translate, trim, trunc, upper, uuid,
+    to_timestamp_seconds, to_utc_timestamp, translate, trim, trunc, upper, uuid,
     window_frame::regularize,
-    AggregateFunction, Between, BinaryExpr, BuiltInWindowFunction, BuiltinScalarFunction,
-    Case, Cast, Expr, GetIndexedField, GroupingSet,
+    AggregateFunction, AggregateUDF, Between, BinaryExpr, BuiltInWindowFunction,
+    BuiltinScalarFunction, Case, Cast, Expr, GetIndexedField, GroupingSet,
     GroupingSet::GroupingSets,
-    JoinConstraint, JoinType, Like, Operator, TryCast, WindowFrame, WindowFrameBound,
-    WindowFrameUnits,
+    JoinConstraint, JoinType, Like, Operator, ScalarUDF, TryCast, WindowFrame,
+    WindowFrameBound, WindowFrameUnits,
 };
+use std::collections::HashSet;
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -302,6 +306,10 @@ impl TryFrom<&protobuf::arrow_type::ArrowTypeEnum> for DataType {
                 precision,
                 scale,
             }) => DataType::Decimal128(*precision as u8, *scale as i8),
+            arrow_type::ArrowTypeEnum::Decimal256(protobuf::Decimal256Type {
+                precision,
+                scale,
+            }) => DataType::Decimal256(*precision as u8, *scale as i8),
             arrow_type::ArrowTypeEnum::List(list) => {
                 let list_type =
                     list.as_ref().field_type.as_deref().required("field_type")?;
@@ -467,6 +475,7 @@ impl From<&protobuf::ScalarFunction> for BuiltinScalarFunction {
             ScalarFunction::ToHex => Self::ToHex,
             ScalarFunction::ToTimestampMicros => Self::ToTimestampMicros,
             ScalarFunction::ToTimestampSeconds => Self::ToTimestampSeconds,
+            ScalarFunction::ToUtcTimestamp => Self::ToUtcTimestamp,
             ScalarFunction::Now => Self::Now,
             ScalarFunction::CurrentDate => Self::CurrentDate,
             ScalarFunction::CurrentTime => Self::CurrentTime,
@@ -612,6 +621,14 @@ impl TryFrom<&protobuf::ScalarValue> for ScalarValue {
                     val.s as i8,
                 )
             }
+            Value::Decimal256Value(val) => {
+                let array = vec_to_array(val.value.clone());
+                Self::Decimal256(
+                    Some(i256::from_be_bytes(array)),
+                    val.p as u8,
+                    val.s as i8,
+                )
+            }
             Value::Date64Value(v) => Self::Date64(Some(*v)),
             Value::Time32Value(v) => {
                 let time_value =
@@ -637,6 +654,24 @@ impl TryFrom<&protobuf::ScalarValue> for ScalarValue {
                     }
                 }
             }
+            Value::DurationValue(v) => {
+                let duration_value =
+                    v.value.as_ref().ok_or_else(|| Error::required("value"))?;
+                match duration_value {
+                    protobuf::scalar_duration_value::Value::DurationSecondValue(d) => {
+                        Self::DurationSecond(Some(*d))
+                    }
+                    protobuf::scalar_duration_value::Value::DurationMillisecondValue(
+                        d,
+                    ) => Self::DurationMillisecond(Some(*d)),
+                    protobuf::scalar_duration_value::Value::DurationMicrosecondValue(
+                        d,
+                    ) => Self::DurationMicrosecond(Some(*d)),
+                    protobuf::scalar_duration_value::Value::DurationNanosecondValue(
+                        d,
+                    ) => Self::DurationNanosecond(Some(*d)),
+                }
+            }
             Value::IntervalYearmonthValue(v) => Self::IntervalYearMonth(Some(*v)),
             Value::IntervalDaytimeValue(v) => Self::IntervalDayTime(Some(*v)),
             Value::TimestampValue(v) => {
@@ -783,6 +818,17 @@ impl From<protobuf::IntervalUnit> for IntervalUnit {
     }
 }
 
+impl From<protobuf::WriteOp> for WriteOp {
+    fn from(op: protobuf::WriteOp) -> Self {
+        match op {
+            protobuf::WriteOp::Insert => WriteOp::Insert,
+            protobuf::WriteOp::Delete => WriteOp::Delete,
+            protobuf::WriteOp::Update => WriteOp::Update,
+            protobuf::WriteOp::Ctas => WriteOp::Ctas,
+        }
+    }
+}
+
 impl From<protobuf::JoinType> for JoinType {
     fn from(t: protobuf::JoinType) -> Self {
         match t {
@@ -841,9 +887,50 @@ fn validate_list_values(field: &Field, values: &[ScalarValue]) -> Result<(), Err
     Ok(())
 }
 
+/// The [`FunctionRegistry`] the expressions of a logical plan are parsed with,
+/// along with the session and the [`LogicalExtensionCodec`] the logical plans of
+/// their subqueries are parsed with.
+pub struct PlanRegistry<'a> {
+    pub ctx: &'a SessionContext,
+    pub registry: &'a dyn FunctionRegistry,
+    pub extension_codec: &'a dyn LogicalExtensionCodec,
+}
+
+impl FunctionRegistry for PlanRegistry<'_> {
+    fn udfs(&self) -> HashSet<String> {
+        self.registry.udfs()
+    }
+
+    fn udf(&self, name: &str) -> datafusion_common::Result<Arc<ScalarUDF>> {
+        self.registry.udf(name)
+    }
+
+    fn udaf(&self, name: &str) -> datafusion_common::Result<Arc<AggregateUDF>> {
+        self.registry.udaf(name)
+    }
+}
+
+/// Parses `proto`, parsing the logical plans of its subqueries in a new
+/// [`SessionContext`] without a [`LogicalExtensionCodec`], see [`parse_plan_expr`].
 pub fn parse_expr(
     proto: &protobuf::LogicalExprNode,
     registry: &dyn FunctionRegistry,
+) -> Result<Expr, Error> {
+    parse_plan_expr(
+        proto,
+        &PlanRegistry {
+            ctx: &SessionContext::new(),
+            registry,
+            extension_codec: &DefaultLogicalExtensionCodec {},
+        },
+    )
+}
+
+/// Parses `proto`, parsing the logical plans of its subqueries with the session
+/// and the extension codec of `registry`.
+pub fn parse_plan_expr(
+    proto: &protobuf::LogicalExprNode,
+    registry: &PlanRegistry,
 ) -> Result<Expr, Error> {
     use protobuf::{logical_expr_node::ExprType, window_expr_node, ScalarFunction};
 
@@ -858,7 +945,7 @@ pub fn parse_expr(
             let operands = binary_expr
                 .operands
                 .iter()
-                .map(|expr| parse_expr(expr, registry))
+                .map(|expr| parse_plan_expr(expr, registry))
                 .collect::<Result<Vec<_>, _>>()?;
 
             if operands.len() < 2 {
@@ -903,12 +990,12 @@ pub fn parse_expr(
             let partition_by = expr
                 .partition_by
                 .iter()
-                .map(|e| parse_expr(e, registry))
+                .map(|e| parse_plan_expr(e, registry))
                 .collect::<Result<Vec<_>, _>>()?;
             let order_by = expr
                 .order_by
                 .iter()
-                .map(|e| parse_expr(e, registry))
+                .map(|e| parse_plan_expr(e, registry))
                 .collect::<Result<Vec<_>, _>>()?;
             let window_frame = expr
                 .window_frame
@@ -957,6 +1044,23 @@ pub fn parse_expr(
                         window_frame,
                     )))
                 }
+                window_expr_node::WindowFunction::Udaf(udaf_name) => {
+                    let udaf_function = registry.udaf(udaf_name)?;
+
+                    Ok(Expr::WindowFunction(WindowFunction::new(
+                        datafusion_expr::window_function::WindowFunction::AggregateUDF(
+                            udaf_function,
+                        ),
+                        vec![parse_required_expr(
+                            expr.expr.as_deref(),
+                            registry,
+                            "expr",
+                        )?],
+                        partition_by,
+                        order_by,
+                        window_frame,
+                    )))
+                }
             }
         }
         ExprType::AggregateExpr(expr) => {
@@ -966,7 +1070,7 @@ pub fn parse_expr(
                 fun,
                 expr.expr
                     .iter()
-                    .map(|e| parse_expr(e, registry))
+                    .map(|e| parse_plan_expr(e, registry))
                     .collect::<Result<Vec<_>, _>>()?,
                 expr.distinct,
                 parse_optional_expr(expr.filter.as_deref(), registry)?.map(Box::new),
@@ -1116,209 +1220,252 @@ pub fn parse_expr(
             list: in_list
                 .list
                 .iter()
-                .map(|expr| parse_expr(expr, registry))
+                .map(|expr| parse_plan_expr(expr, registry))
                 .collect::<Result<Vec<_>, _>>()?,
             negated: in_list.negated,
         }),
         ExprType::Wildcard(_) => Ok(Expr::Wildcard),
+        ExprType::QualifiedWildcard(qualifier) => Ok(Expr::QualifiedWildcard {
+            qualifier: qualifier.clone(),
+        }),
+        ExprType::ScalarVariable(ScalarVariableNode { data_type, names }) => {
+            Ok(Expr::ScalarVariable(
+                data_type.as_ref().required("data_type")?,
+                names.clone(),
+            ))
+        }
+        ExprType::ScalarSubquery(subquery) => {
+            Ok(Expr::ScalarSubquery(parse_subquery(subquery, registry)?))
+        }
+        ExprType::Exists(exists) => Ok(Expr::Exists {
+            subquery: parse_required_subquery(exists.subquery.as_deref(), registry)?,
+            negated: exists.negated,
+        }),
+        ExprType::InSubquery(in_subquery) => Ok(Expr::InSubquery {
+            expr: Box::new(parse_required_expr(
+                in_subquery.expr.as_deref(),
+                registry,
+                "expr",
+            )?),
+            subquery: parse_required_subquery(in_subquery.subquery.as_deref(), registry)?,
+            negated: in_subquery.negated,
+        }),
         ExprType::ScalarFunction(expr) => {
             let scalar_function = protobuf::ScalarFunction::from_i32(expr.fun)
                 .ok_or_else(|| Error::unknown("ScalarFunction", expr.fun))?;
             let args = &expr.args;
 
             match scalar_function {
-                ScalarFunction::Asin => Ok(asin(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Acos => Ok(acos(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Asin => Ok(asin(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Acos => Ok(acos(parse_plan_expr(&args[0], registry)?)),
                 ScalarFunction::Array => Ok(array(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_plan_expr(expr, registry))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
-                ScalarFunction::Sqrt => Ok(sqrt(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Sin => Ok(sin(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Cos => Ok(cos(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Tan => Ok(tan(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Atan => Ok(atan(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Exp => Ok(exp(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Log2 => Ok(log2(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Ln => Ok(ln(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Log10 => Ok(log10(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Floor => Ok(floor(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Ceil => Ok(ceil(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Round => Ok(round(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Trunc => Ok(trunc(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Abs => Ok(abs(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Signum => Ok(signum(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Sqrt => Ok(sqrt(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Sin => Ok(sin(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Cos => Ok(cos(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Tan => Ok(tan(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Atan => Ok(atan(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Exp => Ok(exp(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Log2 => Ok(log2(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Ln => Ok(ln(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Log10 => Ok(log10(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Floor => Ok(floor(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Ceil => Ok(ceil(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Round => Ok(round(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Trunc => Ok(trunc(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Abs => Ok(abs(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Signum => {
+                    Ok(signum(parse_plan_expr(&args[0], registry)?))
+                }
                 ScalarFunction::OctetLength => {
-                    Ok(octet_length(parse_expr(&args[0], registry)?))
+                    Ok(octet_length(parse_plan_expr(&args[0], registry)?))
                 }
-                ScalarFunction::Lower => Ok(lower(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Upper => Ok(upper(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Trim => Ok(trim(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Ltrim => Ok(ltrim(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Rtrim => Ok(rtrim(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Lower => Ok(lower(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Upper => Ok(upper(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Trim => Ok(trim(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Ltrim => Ok(ltrim(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::Rtrim => Ok(rtrim(parse_plan_expr(&args[0], registry)?)),
                 ScalarFunction::DatePart => Ok(date_part(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::DateTrunc => Ok(date_trunc(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::DateBin => Ok(date_bin(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[2], registry)?,
                 )),
-                ScalarFunction::Sha224 => Ok(sha224(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Sha256 => Ok(sha256(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Sha384 => Ok(sha384(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Sha512 => Ok(sha512(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Md5 => Ok(md5(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Sha224 => {
+                    Ok(sha224(parse_plan_expr(&args[0], registry)?))
+                }
+                ScalarFunction::Sha256 => {
+                    Ok(sha256(parse_plan_expr(&args[0], registry)?))
+                }
+                ScalarFunction::Sha384 => {
+                    Ok(sha384(parse_plan_expr(&args[0], registry)?))
+                }
+                ScalarFunction::Sha512 => {
+                    Ok(sha512(parse_plan_expr(&args[0], registry)?))
+                }
+                ScalarFunction::Md5 => Ok(md5(parse_plan_expr(&args[0], registry)?)),
                 ScalarFunction::NullIf => Ok(nullif(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::Digest => Ok(digest(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
-                ScalarFunction::Ascii => Ok(ascii(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Ascii => Ok(ascii(parse_plan_expr(&args[0], registry)?)),
                 ScalarFunction::BitLength => {
-                    Ok(bit_length(parse_expr(&args[0], registry)?))
+                    Ok(bit_length(parse_plan_expr(&args[0], registry)?))
                 }
                 ScalarFunction::CharacterLength => {
-                    Ok(character_length(parse_expr(&args[0], registry)?))
+                    Ok(character_length(parse_plan_expr(&args[0], registry)?))
+                }
+                ScalarFunction::Chr => Ok(chr(parse_plan_expr(&args[0], registry)?)),
+                ScalarFunction::InitCap => {
+                    Ok(ascii(parse_plan_expr(&args[0], registry)?))
                 }
-                ScalarFunction::Chr => Ok(chr(parse_expr(&args[0], registry)?)),
-                ScalarFunction::InitCap => Ok(ascii(parse_expr(&args[0], registry)?)),
                 ScalarFunction::Left => Ok(left(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::Random => Ok(random()),
                 ScalarFunction::Uuid => Ok(uuid()),
                 ScalarFunction::Repeat => Ok(repeat(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::Replace => Ok(replace(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[2], registry)?,
                 )),
-                ScalarFunction::Reverse => Ok(reverse(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Reverse => {
+                    Ok(reverse(parse_plan_expr(&args[0], registry)?))
+                }
                 ScalarFunction::Right => Ok(right(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::Concat => Ok(concat_expr(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_plan_expr(expr, registry))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::ConcatWithSeparator => Ok(concat_ws_expr(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_plan_expr(expr, registry))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::Lpad => Ok(lpad(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_plan_expr(expr, registry))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::Rpad => Ok(rpad(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_plan_expr(expr, registry))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::RegexpReplace => Ok(regexp_replace(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_plan_expr(expr, registry))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::RegexpMatch => Ok(regexp_match(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_plan_expr(expr, registry))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::Btrim => Ok(btrim(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_plan_expr(expr, registry))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::SplitPart => Ok(split_part(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[2], registry)?,
                 )),
                 ScalarFunction::StartsWith => Ok(starts_with(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::Strpos => Ok(strpos(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::Substr => {
                     if args.len() > 2 {
                         assert_eq!(args.len(), 3);
                         Ok(substring(
-                            parse_expr(&args[0], registry)?,
-                            parse_expr(&args[1], registry)?,
-                            parse_expr(&args[2], registry)?,
+                            parse_plan_expr(&args[0], registry)?,
+                            parse_plan_expr(&args[1], registry)?,
+                            parse_plan_expr(&args[2], registry)?,
                         ))
                     } else {
                         Ok(substr(
-                            parse_expr(&args[0], registry)?,
-                            parse_expr(&args[1], registry)?,
+                            parse_plan_expr(&args[0], registry)?,
+                            parse_plan_expr(&args[1], registry)?,
                         ))
                     }
                 }
-                ScalarFunction::ToHex => Ok(to_hex(parse_expr(&args[0], registry)?)),
+                ScalarFunction::ToHex => Ok(to_hex(parse_plan_expr(&args[0], registry)?)),
                 ScalarFunction::ToTimestampMillis => {
-                    Ok(to_timestamp_millis(parse_expr(&args[0], registry)?))
+                    Ok(to_timestamp_millis(parse_plan_expr(&args[0], registry)?))
                 }
                 ScalarFunction::ToTimestampMicros => {
-                    Ok(to_timestamp_micros(parse_expr(&args[0], registry)?))
+                    Ok(to_timestamp_micros(parse_plan_expr(&args[0], registry)?))
                 }
                 ScalarFunction::ToTimestampSeconds => {
-                    Ok(to_timestamp_seconds(parse_expr(&args[0], registry)?))
+                    Ok(to_timestamp_seconds(parse_plan_expr(&args[0], registry)?))
                 }
+                ScalarFunction::ToUtcTimestamp => Ok(to_utc_timestamp(
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
+                )),
                 ScalarFunction::Now => Ok(now()),
                 ScalarFunction::Translate => Ok(translate(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[2], registry)?,
                 )),
                 ScalarFunction::Coalesce => Ok(coalesce(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_plan_expr(expr, registry))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::Power => Ok(power(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::Log => Ok(log(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::FromUnixtime => {
-                    Ok(from_unixtime(parse_expr(&args[0], registry)?))
+                    Ok(from_unixtime(parse_plan_expr(&args[0], registry)?))
                 }
                 ScalarFunction::Atan2 => Ok(atan2(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_plan_expr(&args[0], registry)?,
+                    parse_plan_expr(&args[1], registry)?,
                 )),
                 _ => Err(proto_error(
                     "Protobuf deserialization error: Unsupported scalar function",
@@ -1331,7 +1478,7 @@ pub fn parse_expr(
                 fun: scalar_fn,
                 args: args
                     .iter()
-                    .map(|expr| parse_expr(expr, registry))
+                    .map(|expr| parse_plan_expr(expr, registry))
                     .collect::<Result<Vec<_>, Error>>()?,
             })
         }
@@ -1343,7 +1490,7 @@ pub fn parse_expr(
                 args: pb
                     .args
                     .iter()
-                    .map(|expr| parse_expr(expr, registry))
+                    .map(|expr| parse_plan_expr(expr, registry))
                     .collect::<Result<Vec<_>, Error>>()?,
                 filter: parse_optional_expr(pb.filter.as_deref(), registry)?
                     .map(Box::new),
@@ -1357,7 +1504,7 @@ pub fn parse_expr(
                         expr_list
                             .expr
                             .iter()
-                            .map(|expr| parse_expr(expr, registry))
+                            .map(|expr| parse_plan_expr(expr, registry))
                             .collect::<Result<Vec<_>, Error>>()
                     })
                     .collect::<Result<Vec<_>, Error>>()?,
@@ -1365,13 +1512,13 @@ pub fn parse_expr(
         }
         ExprType::Cube(CubeNode { expr }) => Ok(Expr::GroupingSet(GroupingSet::Cube(
             expr.iter()
-                .map(|expr| parse_expr(expr, registry))
+                .map(|expr| parse_plan_expr(expr, registry))
                 .collect::<Result<Vec<_>, Error>>()?,
         ))),
         ExprType::Rollup(RollupNode { expr }) => {
             Ok(Expr::GroupingSet(GroupingSet::Rollup(
                 expr.iter()
-                    .map(|expr| parse_expr(expr, registry))
+                    .map(|expr| parse_plan_expr(expr, registry))
                     .collect::<Result<Vec<_>, Error>>()?,
             )))
         }
@@ -1441,25 +1588,51 @@ pub fn from_proto_binary_op(op: &str) -> Result<Operator, Error> {
 
 fn parse_optional_expr(
     p: Option<&protobuf::LogicalExprNode>,
-    registry: &dyn FunctionRegistry,
+    registry: &PlanRegistry,
 ) -> Result<Option<Expr>, Error> {
     match p {
-        Some(expr) => parse_expr(expr, registry).map(Some),
+        Some(expr) => parse_plan_expr(expr, registry).map(Some),
         None => Ok(None),
     }
 }
 
 fn parse_required_expr(
     p: Option<&protobuf::LogicalExprNode>,
-    registry: &dyn FunctionRegistry,
+    registry: &PlanRegistry,
     field: impl Into<String>,
 ) -> Result<Expr, Error> {
     match p {
-        Some(expr) => parse_expr(expr, registry),
+        Some(expr) => parse_plan_expr(expr, registry),
         None => Err(Error::required(field)),
     }
 }
 
+fn parse_subquery(
+    subquery: &protobuf::SubqueryNode,
+    registry: &PlanRegistry,
+) -> Result<Subquery, Error> {
+    let plan = subquery
+        .subquery
+        .as_deref()
+        .ok_or_else(|| Error::required("subquery"))?
+        .try_into_logical_plan_with_registry(
+            registry.ctx,
+            registry.registry,
+            registry.extension_codec,
+        )?;
+    Ok(Subquery::new(plan))
+}
+
+fn parse_required_subquery(
+    subquery: Option<&protobuf::SubqueryNode>,
+    registry: &PlanRegistry,
+) -> Result<Subquery, Error> {
+    match subquery {
+        Some(subquery) => parse_subquery(subquery, registry),
+        None => Err(Error::required("subquery")),
+    }
+}
+
 fn proto_error<S: Into<String>>(message: S) -> Error {
     Error::General(message.into())
 }
@@ -25,7 +25,7 @@ use crate::{
         logical_plan_node::LogicalPlanType, LogicalExtensionNode, LogicalPlanNode,
     },
 };
-use arrow::datatypes::{DataType, Schema, SchemaRef};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
 use datafusion::{
     datasource::{
         file_format::{
@@ -36,19 +36,23 @@ use datafusion::{
         TableProvider,
     },
     datasource::{provider_as_source, source_as_provider},
+    execution::registry::FunctionRegistry,
     prelude::SessionContext,
 };
 use datafusion_common::{
-    context, parsers::CompressionTypeVariant, DataFusionError, OwnedTableReference,
+    context, parsers::CompressionTypeVariant, Column, DFSchema, DataFusionError,
+    OwnedTableReference,
 };
 use datafusion_expr::{
     logical_plan::{
-        builder::project, Aggregate, CreateCatalog, CreateCatalogSchema,
-        CreateExternalTable, CreateView, CrossJoin, Distinct, EmptyRelation, Extension,
-        Join, JoinConstraint, Limit, Prepare, Projection, Repartition, Sort,
-        SubqueryAlias, TableScan, Values, Window,
+        builder::project, Aggregate, AlterTable, AlterTableOperation, AlterView,
+        CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateFunction,
+        CreateMemoryTable, CreateView, CrossJoin, DescribeTable, Distinct, DmlStatement,
+        DropFunction, DropTable, DropView, EmptyRelation, Extension, Join,
+        JoinConstraint, Limit, Prepare, Projection, Repartition, SetVariable, Sort,
+        Subquery, SubqueryAlias, TableScan, Unnest, Values, Window,
     },
-    Expr, LogicalPlan, LogicalPlanBuilder,
+    Expr, LogicalPlan, LogicalPlanBuilder, SqlFunction,
 };
 use prost::bytes::BufMut;
 use prost::Message;
@@ -179,6 +183,15 @@ macro_rules! into_logical_plan {
             Err(proto_error("Missing required field in protobuf"))
         }
     }};
+    ($PB:expr, $CTX:expr, $REGISTRY:expr, $CODEC:expr) => {{
+        if let Some(field) = $PB.as_ref() {
+            field
+                .as_ref()
+                .try_into_logical_plan_with_registry($CTX, $REGISTRY, $CODEC)
+        } else {
+            Err(proto_error("Missing required field in protobuf"))
+        }
+    }};
 }
 
 fn from_owned_table_reference(
@@ -194,31 +207,23 @@ fn from_owned_table_reference(
     Ok(table_ref.clone().try_into()?)
 }
 
-impl AsLogicalPlan for LogicalPlanNode {
-    fn try_decode(buf: &[u8]) -> Result<Self, DataFusionError>
-    where
-        Self: Sized,
-    {
-        LogicalPlanNode::decode(buf).map_err(|e| {
-            DataFusionError::Internal(format!("failed to decode logical plan: {e:?}"))
-        })
-    }
-
-    fn try_encode<B>(&self, buf: &mut B) -> Result<(), DataFusionError>
-    where
-        B: BufMut,
-        Self: Sized,
-    {
-        self.encode(buf).map_err(|e| {
-            DataFusionError::Internal(format!("failed to encode logical plan: {e:?}"))
-        })
-    }
-
-    fn try_into_logical_plan(
+impl LogicalPlanNode {
+    /// Converts this node into a [`LogicalPlan`], resolving any functions referenced
+    /// by its expressions in `registry` rather than in `ctx`.
+    ///
+    /// This is used to decode subqueries nested inside expressions, whose functions
+    /// are resolved in the registry of the enclosing expression.
+    pub(crate) fn try_into_logical_plan_with_registry(
         &self,
         ctx: &SessionContext,
+        registry: &dyn FunctionRegistry,
         extension_codec: &dyn LogicalExtensionCodec,
     ) -> Result<LogicalPlan, DataFusionError> {
+        let plan_registry = from_proto::PlanRegistry {
+            ctx,
+            registry,
+            extension_codec,
+        };
         let plan = self.logical_plan_type.as_ref().ok_or_else(|| {
             proto_error(format!(
                 "logical_plan::from_proto() Unsupported logical plan '{self:?}'"
@@ -241,7 +246,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                         .chunks_exact(n_cols)
                         .map(|r| {
                             r.iter()
-                                .map(|expr| from_proto::parse_expr(expr, ctx))
+                                .map(|expr| {
+                                    from_proto::parse_plan_expr(expr, &plan_registry)
+                                })
                                 .collect::<Result<Vec<_>, from_proto::Error>>()
                         })
                         .collect::<Result<Vec<_>, _>>()
@@ -251,11 +258,11 @@ impl AsLogicalPlan for LogicalPlanNode {
             }
             LogicalPlanType::Projection(projection) => {
                 let input: LogicalPlan =
-                    into_logical_plan!(projection.input, ctx, extension_codec)?;
+                    into_logical_plan!(projection.input, ctx, registry, extension_codec)?;
                 let expr: Vec<Expr> = projection
                     .expr
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .collect::<Result<Vec<_>, _>>()?;
 
                 let new_proj = project(input, expr)?;
@@ -272,11 +279,11 @@ impl AsLogicalPlan for LogicalPlanNode {
             }
             LogicalPlanType::Selection(selection) => {
                 let input: LogicalPlan =
-                    into_logical_plan!(selection.input, ctx, extension_codec)?;
+                    into_logical_plan!(selection.input, ctx, registry, extension_codec)?;
                 let expr: Expr = selection
                     .expr
                     .as_ref()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .transpose()?
                     .ok_or_else(|| {
                         DataFusionError::Internal("expression required".to_string())
@@ -286,26 +293,26 @@ impl AsLogicalPlan for LogicalPlanNode {
             }
             LogicalPlanType::Window(window) => {
                 let input: LogicalPlan =
-                    into_logical_plan!(window.input, ctx, extension_codec)?;
+                    into_logical_plan!(window.input, ctx, registry, extension_codec)?;
                 let window_expr = window
                     .window_expr
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .collect::<Result<Vec<Expr>, _>>()?;
                 LogicalPlanBuilder::from(input).window(window_expr)?.build()
             }
             LogicalPlanType::Aggregate(aggregate) => {
                 let input: LogicalPlan =
-                    into_logical_plan!(aggregate.input, ctx, extension_codec)?;
+                    into_logical_plan!(aggregate.input, ctx, registry, extension_codec)?;
                 let group_expr = aggregate
                     .group_expr
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .collect::<Result<Vec<Expr>, _>>()?;
                 let aggr_expr = aggregate
                     .aggr_expr
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .collect::<Result<Vec<Expr>, _>>()?;
                 LogicalPlanBuilder::from(input)
                     .aggregate(group_expr, aggr_expr)?
@@ -327,13 +334,13 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let filters = scan
                     .filters
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .collect::<Result<Vec<_>, _>>()?;
 
                 let file_sort_order = scan
                     .file_sort_order
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .collect::<Result<Vec<_>, _>>()?;
 
                 // Protobuf doesn't distinguish between "not present"
@@ -422,7 +429,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let filters = scan
                     .filters
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .collect::<Result<Vec<_>, _>>()?;
                 let provider = extension_codec.try_decode_table_provider(
                     &scan.custom_table_data,
@@ -440,18 +447,22 @@ impl AsLogicalPlan for LogicalPlanNode {
             }
             LogicalPlanType::Sort(sort) => {
                 let input: LogicalPlan =
-                    into_logical_plan!(sort.input, ctx, extension_codec)?;
+                    into_logical_plan!(sort.input, ctx, registry, extension_codec)?;
                 let sort_expr: Vec<Expr> = sort
                     .expr
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .collect::<Result<Vec<Expr>, _>>()?;
                 LogicalPlanBuilder::from(input).sort(sort_expr)?.build()
             }
             LogicalPlanType::Repartition(repartition) => {
                 use datafusion::logical_expr::Partitioning;
-                let input: LogicalPlan =
-                    into_logical_plan!(repartition.input, ctx, extension_codec)?;
+                let input: LogicalPlan = into_logical_plan!(
+                    repartition.input,
+                    ctx,
+                    registry,
+                    extension_codec
+                )?;
                 use protobuf::repartition_node::PartitionMethod;
                 let pb_partition_method = repartition.partition_method.clone().ok_or_else(|| {
                     DataFusionError::Internal(String::from(
@@ -466,7 +477,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                     }) => Partitioning::Hash(
                         pb_hash_expr
                             .iter()
-                            .map(|expr| from_proto::parse_expr(expr, ctx))
+                            .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                             .collect::<Result<Vec<_>, _>>()?,
                         partition_count as usize,
                     ),
@@ -515,6 +526,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                         .table_partition_cols
                         .clone(),
                     if_not_exists: create_extern_table.if_not_exists,
+                    or_replace: create_extern_table.or_replace,
                     file_compression_type: CompressionTypeVariant::from_str(&create_extern_table.file_compression_type).map_err(|_| DataFusionError::NotImplemented(format!("Unsupported file compression type {}", create_extern_table.file_compression_type)))?,
                     definition,
                     options: create_extern_table.options.clone(),
@@ -525,7 +537,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                     .input.clone().ok_or_else(|| DataFusionError::Internal(String::from(
                     "Protobuf deserialization error, CreateViewNode has invalid LogicalPlan input.",
                 )))?
-                    .try_into_logical_plan(ctx, extension_codec)?;
+                    .try_into_logical_plan_with_registry(ctx, registry, extension_codec)?;
                 let definition = if !create_view.definition.is_empty() {
                     Some(create_view.definition.clone())
                 } else {
@@ -570,28 +582,32 @@ impl AsLogicalPlan for LogicalPlanNode {
             }
             LogicalPlanType::Analyze(analyze) => {
                 let input: LogicalPlan =
-                    into_logical_plan!(analyze.input, ctx, extension_codec)?;
+                    into_logical_plan!(analyze.input, ctx, registry, extension_codec)?;
                 LogicalPlanBuilder::from(input)
                     .explain(analyze.verbose, true)?
                     .build()
             }
             LogicalPlanType::Explain(explain) => {
                 let input: LogicalPlan =
-                    into_logical_plan!(explain.input, ctx, extension_codec)?;
+                    into_logical_plan!(explain.input, ctx, registry, extension_codec)?;
                 LogicalPlanBuilder::from(input)
                     .explain(explain.verbose, false)?
                     .build()
             }
             LogicalPlanType::SubqueryAlias(aliased_relation) => {
-                let input: LogicalPlan =
-                    into_logical_plan!(aliased_relation.input, ctx, extension_codec)?;
+                let input: LogicalPlan = into_logical_plan!(
+                    aliased_relation.input,
+                    ctx,
+                    registry,
+                    extension_codec
+                )?;
                 LogicalPlanBuilder::from(input)
                     .alias(&aliased_relation.alias)?
                     .build()
             }
             LogicalPlanType::Limit(limit) => {
                 let input: LogicalPlan =
-                    into_logical_plan!(limit.input, ctx, extension_codec)?;
+                    into_logical_plan!(limit.input, ctx, registry, extension_codec)?;
                 let skip = limit.skip.max(0) as usize;
 
                 let fetch = if limit.fetch < 0 {
@@ -606,12 +622,12 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let left_keys: Vec<Expr> = join
                     .left_join_key
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .collect::<Result<Vec<_>, _>>()?;
                 let right_keys: Vec<Expr> = join
                     .right_join_key
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .collect::<Result<Vec<_>, _>>()?;
                 let join_type =
                     protobuf::JoinType::from_i32(join.join_type).ok_or_else(|| {
@@ -632,17 +648,18 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let filter: Option<Expr> = join
                     .filter
                     .as_ref()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
                     .map_or(Ok(None), |v| v.map(Some))?;
 
                 let builder = LogicalPlanBuilder::from(into_logical_plan!(
                     join.left,
                     ctx,
+                    registry,
                     extension_codec
                 )?);
                 let builder = match join_constraint.into() {
                     JoinConstraint::On => builder.join_with_expr_keys(
-                        into_logical_plan!(join.right, ctx, extension_codec)?,
+                        into_logical_plan!(join.right, ctx, registry, extension_codec)?,
                         join_type.into(),
                         (left_keys, right_keys),
                         filter,
@@ -654,7 +671,12 @@ impl AsLogicalPlan for LogicalPlanNode {
                             .map(|key| key.try_into_col())
                             .collect::<Result<Vec<_>, _>>()?;
                         builder.join_using(
-                            into_logical_plan!(join.right, ctx, extension_codec)?,
+                            into_logical_plan!(
+                                join.right,
+                                ctx,
+                                registry,
+                                extension_codec
+                            )?,
                             join_type.into(),
                             using_keys,
                         )?
@@ -667,7 +689,13 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let mut input_plans: Vec<LogicalPlan> = union
                     .inputs
                     .iter()
-                    .map(|i| i.try_into_logical_plan(ctx, extension_codec))
+                    .map(|i| {
+                        i.try_into_logical_plan_with_registry(
+                            ctx,
+                            registry,
+                            extension_codec,
+                        )
+                    })
                     .collect::<Result<_, DataFusionError>>()?;
 
                 if input_plans.len() < 2 {
@@ -686,15 +714,23 @@ impl AsLogicalPlan for LogicalPlanNode {
                 builder.build()
             }
             LogicalPlanType::CrossJoin(crossjoin) => {
-                let left = into_logical_plan!(crossjoin.left, ctx, extension_codec)?;
-                let right = into_logical_plan!(crossjoin.right, ctx, extension_codec)?;
+                let left =
+                    into_logical_plan!(crossjoin.left, ctx, registry, extension_codec)?;
+                let right =
+                    into_logical_plan!(crossjoin.right, ctx, registry, extension_codec)?;
 
                 LogicalPlanBuilder::from(left).cross_join(right)?.build()
             }
             LogicalPlanType::Extension(LogicalExtensionNode { node, inputs }) => {
                 let input_plans: Vec<LogicalPlan> = inputs
                     .iter()
-                    .map(|i| i.try_into_logical_plan(ctx, extension_codec))
+                    .map(|i| {
+                        i.try_into_logical_plan_with_registry(
+                            ctx,
+                            registry,
+                            extension_codec,
+                        )
+                    })
                     .collect::<Result<_, DataFusionError>>()?;
 
                 let extension_node =
@@ -703,7 +739,7 @@ impl AsLogicalPlan for LogicalPlanNode {
             }
             LogicalPlanType::Distinct(distinct) => {
                 let input: LogicalPlan =
-                    into_logical_plan!(distinct.input, ctx, extension_codec)?;
+                    into_logical_plan!(distinct.input, ctx, registry, extension_codec)?;
                 LogicalPlanBuilder::from(input).distinct()?.build()
             }
             LogicalPlanType::ViewScan(scan) => {
@@ -720,7 +756,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                 }
 
                 let input: LogicalPlan =
-                    into_logical_plan!(scan.input, ctx, extension_codec)?;
+                    into_logical_plan!(scan.input, ctx, registry, extension_codec)?;
 
                 let definition = if !scan.definition.is_empty() {
                     Some(scan.definition.clone())
@@ -739,7 +775,7 @@ impl AsLogicalPlan for LogicalPlanNode {
             }
             LogicalPlanType::Prepare(prepare) => {
                 let input: LogicalPlan =
-                    into_logical_plan!(prepare.input, ctx, extension_codec)?;
+                    into_logical_plan!(prepare.input, ctx, registry, extension_codec)?;
                 let data_types: Vec<DataType> = prepare
                     .data_types
                     .iter()
@@ -749,8 +785,222 @@ impl AsLogicalPlan for LogicalPlanNode {
                     .prepare(prepare.name.clone(), data_types)?
                     .build()
             }
+            LogicalPlanType::Subquery(subquery) => {
+                let input: LogicalPlan = into_logical_plan!(
+                    subquery.subquery,
+                    ctx,
+                    registry,
+                    extension_codec
+                )?;
+                Ok(LogicalPlan::Subquery(Subquery::new(input)))
+            }
+            LogicalPlanType::CreateMemoryTable(create_memory_table) => {
+                let input: LogicalPlan = into_logical_plan!(
+                    create_memory_table.input,
+                    ctx,
+                    registry,
+                    extension_codec
+                )?;
+                Ok(LogicalPlan::CreateMemoryTable(CreateMemoryTable {
+                    name: from_owned_table_reference(
+                        create_memory_table.name.as_ref(),
+                        "CreateMemoryTable",
+                    )?,
+                    input: Arc::new(input),
+                    if_not_exists: create_memory_table.if_not_exists,
+                    or_replace: create_memory_table.or_replace,
+                }))
+            }
+            LogicalPlanType::DropTable(drop_table) => {
+                let schema: DFSchema = convert_required!(drop_table.schema)?;
+                Ok(LogicalPlan::DropTable(DropTable {
+                    name: from_owned_table_reference(
+                        drop_table.name.as_ref(),
+                        "DropTable",
+                    )?,
+                    if_exists: drop_table.if_exists,
+                    schema: Arc::new(schema),
+                }))
+            }
+            LogicalPlanType::DropView(drop_view) => {
+                let schema: DFSchema = convert_required!(drop_view.schema)?;
+                Ok(LogicalPlan::DropView(DropView {
+                    name: from_owned_table_reference(
+                        drop_view.name.as_ref(),
+                        "DropView",
+                    )?,
+                    if_exists: drop_view.if_exists,
+                    schema: Arc::new(schema),
+                }))
+            }
+            LogicalPlanType::CreateFunction(create_function) => {
+                let args = create_function
+                    .args
+                    .iter()
+                    .map(|arg| {
+                        let data_type: DataType = convert_required!(arg.arg_type)?;
+                        Ok((arg.name.clone(), data_type))
+                    })
+                    .collect::<Result<Vec<_>, DataFusionError>>()?;
+                let return_type = create_function
+                    .return_type
+                    .as_ref()
+                    .map(DataType::try_from)
+                    .transpose()?;
+                let body: Expr = create_function
+                    .body
+                    .as_ref()
+                    .map(|expr| from_proto::parse_plan_expr(expr, &plan_registry))
+                    .transpose()?
+                    .ok_or_else(|| {
+                        DataFusionError::Internal("expression required".to_string())
+                    })?;
+                let schema: DFSchema = convert_required!(create_function.schema)?;
+                Ok(LogicalPlan::CreateFunction(CreateFunction {
+                    function: SqlFunction::new(
+                        create_function.name.clone(),
+                        args,
+                        return_type,
+                        body,
+                        create_function.definition.clone(),
+                    ),
+                    or_replace: create_function.or_replace,
+                    schema: Arc::new(schema),
+                }))
+            }
+            LogicalPlanType::DropFunction(drop_function) => {
+                let schema: DFSchema = convert_required!(drop_function.schema)?;
+                Ok(LogicalPlan::DropFunction(DropFunction {
+                    name: drop_function.name.clone(),
+                    if_exists: drop_function.if_exists,
+                    schema: Arc::new(schema),
+                }))
+            }
+            LogicalPlanType::AlterTable(alter_table) => {
+                use protobuf::alter_table_node::Operation;
+                let operation = match alter_table.operation.as_ref().ok_or_else(|| {
+                    DataFusionError::Internal(String::from(
+                        "Protobuf deserialization error, AlterTableNode was missing required field operation.",
+                    ))
+                })? {
+                    Operation::RenameTo(new_name) => AlterTableOperation::RenameTo(
+                        new_name.clone().try_into()?,
+                    ),
+                    Operation::AddColumn(field) => {
+                        AlterTableOperation::AddColumn(Field::try_from(field)?)
+                    }
+                    Operation::SetOptions(options) => {
+                        AlterTableOperation::SetOptions(options.options.clone())
+                    }
+                };
+                let schema: DFSchema = convert_required!(alter_table.schema)?;
+                Ok(LogicalPlan::AlterTable(AlterTable {
+                    name: from_owned_table_reference(
+                        alter_table.name.as_ref(),
+                        "AlterTable",
+                    )?,
+                    operation,
+                    schema: Arc::new(schema),
+                }))
+            }
+            LogicalPlanType::AlterView(alter_view) => {
+                let schema: DFSchema = convert_required!(alter_view.schema)?;
+                Ok(LogicalPlan::AlterView(AlterView {
+                    name: from_owned_table_reference(
+                        alter_view.name.as_ref(),
+                        "AlterView",
+                    )?,
+                    new_name: from_owned_table_reference(
+                        alter_view.new_name.as_ref(),
+                        "AlterView",
+                    )?,
+                    schema: Arc::new(schema),
+                }))
+            }
+            LogicalPlanType::SetVariable(set_variable) => {
+                let schema: DFSchema = convert_required!(set_variable.schema)?;
+                Ok(LogicalPlan::SetVariable(SetVariable {
+                    variable: set_variable.variable.clone(),
+                    value: set_variable.value.clone(),
+                    schema: Arc::new(schema),
+                }))
+            }
+            LogicalPlanType::Dml(dml) => {
+                let input: LogicalPlan =
+                    into_logical_plan!(dml.input, ctx, registry, extension_codec)?;
+                let op = protobuf::WriteOp::from_i32(dml.op).ok_or_else(|| {
+                    proto_error(format!(
+                        "Received a DmlNode message with unknown WriteOp {}",
+                        dml.op
+                    ))
+                })?;
+                let table_schema: DFSchema = convert_required!(dml.table_schema)?;
+                Ok(LogicalPlan::Dml(DmlStatement {
+                    table_name: from_owned_table_reference(
+                        dml.table_name.as_ref(),
+                        "Dml",
+                    )?,
+                    table_schema: Arc::new(table_schema),
+                    op: op.into(),
+                    input: Arc::new(input),
+                }))
+            }
+            LogicalPlanType::DescribeTable(describe_table) => {
+                let schema: Schema = convert_required!(describe_table.schema)?;
+                let dummy_schema: DFSchema =
+                    convert_required!(describe_table.dummy_schema)?;
+                Ok(LogicalPlan::DescribeTable(DescribeTable {
+                    schema: Arc::new(schema),
+                    dummy_schema: Arc::new(dummy_schema),
+                }))
+            }
+            LogicalPlanType::Unnest(unnest) => {
+                let input: LogicalPlan =
+                    into_logical_plan!(unnest.input, ctx, registry, extension_codec)?;
+                let column: Column = unnest
+                    .column
+                    .as_ref()
+                    .map(Column::from)
+                    .ok_or_else(|| {
+                        DataFusionError::Internal(String::from(
+                            "Protobuf deserialization error, UnnestNode was missing required field column.",
+                        ))
+                    })?;
+                LogicalPlanBuilder::from(input)
+                    .unnest_column(column)?
+                    .build()
+            }
         }
     }
+}
+
+impl AsLogicalPlan for LogicalPlanNode {
+    fn try_decode(buf: &[u8]) -> Result<Self, DataFusionError>
+    where
+        Self: Sized,
+    {
+        LogicalPlanNode::decode(buf).map_err(|e| {
+            DataFusionError::Internal(format!("failed to decode logical plan: {e:?}"))
+        })
+    }
+
+    fn try_encode<B>(&self, buf: &mut B) -> Result<(), DataFusionError>
+    where
+        B: BufMut,
+        Self: Sized,
+    {
+        self.encode(buf).map_err(|e| {
+            DataFusionError::Internal(format!("failed to encode logical plan: {e:?}"))
+        })
+    }
+
+    fn try_into_logical_plan(
+        &self,
+        ctx: &SessionContext,
+        extension_codec: &dyn LogicalExtensionCodec,
+    ) -> Result<LogicalPlan, DataFusionError> {
+        self.try_into_logical_plan_with_registry(ctx, ctx, extension_codec)
+    }
 
     fn try_from_logical_plan(
         plan: &LogicalPlan,
@@ -769,7 +1019,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let values_list = values
                     .iter()
                     .flatten()
-                    .map(|v| v.try_into())
+                    .map(|v| to_proto::serialize_expr(v, extension_codec))
                     .collect::<Result<Vec<_>, _>>()?;
                 Ok(protobuf::LogicalPlanNode {
                     logical_plan_type: Some(LogicalPlanType::Values(
@@ -807,7 +1057,7 @@ impl AsLogicalPlan for LogicalPlanNode {
 
                 let filters: Vec<protobuf::LogicalExprNode> = filters
                     .iter()
-                    .map(|filter| filter.try_into())
+                    .map(|filter| to_proto::serialize_expr(filter, extension_codec))
                     .collect::<Result<Vec<_>, _>>()?;
 
                 if let Some(listing_table) = source.downcast_ref::<ListingTable>() {
@@ -829,15 +1079,16 @@ impl AsLogicalPlan for LogicalPlanNode {
                     };
 
                     let options = listing_table.options();
-                    let file_sort_order =
-                        if let Some(file_sort_order) = &options.file_sort_order {
-                            file_sort_order
-                                .iter()
-                                .map(|expr| expr.try_into())
-                                .collect::<Result<Vec<protobuf::LogicalExprNode>, _>>()?
-                        } else {
-                            vec![]
-                        };
+                    let file_sort_order = if let Some(file_sort_order) =
+                        &options.file_sort_order
+                    {
+                        file_sort_order
+                            .iter()
+                            .map(|expr| to_proto::serialize_expr(expr, extension_codec))
+                            .collect::<Result<Vec<protobuf::LogicalExprNode>, _>>()?
+                    } else {
+                        vec![]
+                    };
 
                     Ok(protobuf::LogicalPlanNode {
                         logical_plan_type: Some(LogicalPlanType::ListingScan(
@@ -914,7 +1165,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                             )),
                             expr: expr
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| {
+                                    to_proto::serialize_expr(expr, extension_codec)
+                                })
                                 .collect::<Result<Vec<_>, to_proto::Error>>()?,
                             optional_alias: None,
                         },
@@ -931,7 +1184,10 @@ impl AsLogicalPlan for LogicalPlanNode {
                     logical_plan_type: Some(LogicalPlanType::Selection(Box::new(
                         protobuf::SelectionNode {
                             input: Some(Box::new(input)),
-                            expr: Some((&filter.predicate).try_into()?),
+                            expr: Some(Box::new(to_proto::serialize_expr(
+                                &filter.predicate,
+                                extension_codec,
+                            )?)),
                         },
                     ))),
                 })
@@ -964,7 +1220,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                             input: Some(Box::new(input)),
                             window_expr: window_expr
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| {
+                                    to_proto::serialize_expr(expr, extension_codec)
+                                })
                                 .collect::<Result<Vec<_>, _>>()?,
                         },
                     ))),
@@ -987,11 +1245,15 @@ impl AsLogicalPlan for LogicalPlanNode {
                             input: Some(Box::new(input)),
                             group_expr: group_expr
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| {
+                                    to_proto::serialize_expr(expr, extension_codec)
+                                })
                                 .collect::<Result<Vec<_>, _>>()?,
                             aggr_expr: aggr_expr
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| {
+                                    to_proto::serialize_expr(expr, extension_codec)
+                                })
                                 .collect::<Result<Vec<_>, _>>()?,
                         },
                     ))),
@@ -1019,7 +1281,12 @@ impl AsLogicalPlan for LogicalPlanNode {
                     )?;
                 let (left_join_key, right_join_key) = on
                     .iter()
-                    .map(|(l, r)| Ok((l.try_into()?, r.try_into()?)))
+                    .map(|(l, r)| {
+                        Ok((
+                            to_proto::serialize_expr(l, extension_codec)?,
+                            to_proto::serialize_expr(r, extension_codec)?,
+                        ))
+                    })
                     .collect::<Result<Vec<_>, to_proto::Error>>()?
                     .into_iter()
                     .unzip();
@@ -1028,7 +1295,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                     join_constraint.to_owned().into();
                 let filter = filter
                     .as_ref()
-                    .map(|e| e.try_into())
+                    .map(|e| to_proto::serialize_expr(e, extension_codec).map(Box::new))
                     .map_or(Ok(None), |v| v.map(Some))?;
                 Ok(protobuf::LogicalPlanNode {
                     logical_plan_type: Some(LogicalPlanType::Join(Box::new(
@@ -1045,9 +1312,19 @@ impl AsLogicalPlan for LogicalPlanNode {
                     ))),
                 })
             }
-            LogicalPlan::Subquery(_) => Err(DataFusionError::NotImplemented(
-                "LogicalPlan serde is not yet implemented for subqueries".to_string(),
-            )),
+            LogicalPlan::Subquery(Subquery { subquery }) => {
+                let subquery = protobuf::LogicalPlanNode::try_from_logical_plan(
+                    subquery.as_ref(),
+                    extension_codec,
+                )?;
+                Ok(protobuf::LogicalPlanNode {
+                    logical_plan_type: Some(LogicalPlanType::Subquery(Box::new(
+                        protobuf::SubqueryNode {
+                            subquery: Some(Box::new(subquery)),
+                        },
+                    ))),
+                })
+            }
             LogicalPlan::SubqueryAlias(SubqueryAlias { input, alias, .. }) => {
                 let input: protobuf::LogicalPlanNode =
                     protobuf::LogicalPlanNode::try_from_logical_plan(
@@ -1087,7 +1364,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                     )?;
                 let selection_expr: Vec<protobuf::LogicalExprNode> = expr
                     .iter()
-                    .map(|expr| expr.try_into())
+                    .map(|expr| to_proto::serialize_expr(expr, extension_codec))
                     .collect::<Result<Vec<_>, to_proto::Error>>()?;
                 Ok(protobuf::LogicalPlanNode {
                     logical_plan_type: Some(LogicalPlanType::Sort(Box::new(
@@ -1119,7 +1396,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                         PartitionMethod::Hash(protobuf::HashRepartition {
                             hash_expr: exprs
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| {
+                                    to_proto::serialize_expr(expr, extension_codec)
+                                })
                                 .collect::<Result<Vec<_>, to_proto::Error>>()?,
                             partition_count: *partition_count as u64,
                         })
@@ -1161,6 +1440,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                 schema: df_schema,
                 table_partition_cols,
                 if_not_exists,
+                or_replace,
                 definition,
                 file_compression_type,
                 options,
@@ -1178,6 +1458,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                         definition: definition.clone().unwrap_or_default(),
                         file_compression_type: file_compression_type.to_string(),
                         options: options.clone(),
+                        or_replace: *or_replace,
                     },
                 )),
             }),
@@ -1332,27 +1613,205 @@ impl AsLogicalPlan for LogicalPlanNode {
                     ))),
                 })
             }
-            LogicalPlan::Unnest(_) => Err(proto_error(
-                "LogicalPlan serde is not yet implemented for Unnest",
-            )),
-            LogicalPlan::CreateMemoryTable(_) => Err(proto_error(
-                "LogicalPlan serde is not yet implemented for CreateMemoryTable",
-            )),
-            LogicalPlan::DropTable(_) => Err(proto_error(
-                "LogicalPlan serde is not yet implemented for DropTable",
-            )),
-            LogicalPlan::DropView(_) => Err(proto_error(
-                "LogicalPlan serde is not yet implemented for DropView",
-            )),
-            LogicalPlan::SetVariable(_) => Err(proto_error(
-                "LogicalPlan serde is not yet implemented for SetVariable",
-            )),
-            LogicalPlan::Dml(_) => Err(proto_error(
-                "LogicalPlan serde is not yet implemented for Dml",
-            )),
-            LogicalPlan::DescribeTable(_) => Err(proto_error(
-                "LogicalPlan serde is not yet implemented for DescribeTable",
-            )),
+            LogicalPlan::Unnest(Unnest { input, column, .. }) => {
+                let input = protobuf::LogicalPlanNode::try_from_logical_plan(
+                    input,
+                    extension_codec,
+                )?;
+                Ok(protobuf::LogicalPlanNode {
+                    logical_plan_type: Some(LogicalPlanType::Unnest(Box::new(
+                        protobuf::UnnestNode {
+                            input: Some(Box::new(input)),
+                            column: Some(column.into()),
+                        },
+                    ))),
+                })
+            }
+            LogicalPlan::CreateMemoryTable(CreateMemoryTable {
+                name,
+                input,
+                if_not_exists,
+                or_replace,
+            }) => {
+                let input = protobuf::LogicalPlanNode::try_from_logical_plan(
+                    input,
+                    extension_codec,
+                )?;
+                Ok(protobuf::LogicalPlanNode {
+                    logical_plan_type: Some(LogicalPlanType::CreateMemoryTable(
+                        Box::new(protobuf::CreateMemoryTableNode {
+                            name: Some(name.clone().into()),
+                            input: Some(Box::new(input)),
+                            if_not_exists: *if_not_exists,
+                            or_replace: *or_replace,
+                        }),
+                    )),
+                })
+            }
+            LogicalPlan::DropTable(DropTable {
+                name,
+                if_exists,
+                schema,
+            }) => Ok(protobuf::LogicalPlanNode {
+                logical_plan_type: Some(LogicalPlanType::DropTable(
+                    protobuf::DropTableNode {
+                        name: Some(name.clone().into()),
+                        if_exists: *if_exists,
+                        schema: Some(schema.try_into()?),
+                    },
+                )),
+            }),
+            LogicalPlan::DropView(DropView {
+                name,
+                if_exists,
+                schema,
+            }) => Ok(protobuf::LogicalPlanNode {
+                logical_plan_type: Some(LogicalPlanType::DropView(
+                    protobuf::DropViewNode {
+                        name: Some(name.clone().into()),
+                        if_exists: *if_exists,
+                        schema: Some(schema.try_into()?),
+                    },
+                )),
+            }),
+            LogicalPlan::CreateFunction(CreateFunction {
+                function,
+                or_replace,
+                schema,
+            }) => {
+                let args = function
+                    .args
+                    .iter()
+                    .map(|(name, data_type)| {
+                        Ok(protobuf::SqlFunctionArg {
+                            name: name.clone(),
+                            arg_type: Some(data_type.try_into()?),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, to_proto::Error>>()?;
+                let return_type: Option<protobuf::ArrowType> = match &function.return_type
+                {
+                    Some(data_type) => Some(data_type.try_into()?),
+                    None => None,
+                };
+                Ok(protobuf::LogicalPlanNode {
+                    logical_plan_type: Some(LogicalPlanType::CreateFunction(Box::new(
+                        protobuf::CreateFunctionNode {
+                            name: function.name.clone(),
+                            args,
+                            return_type,
+                            body: Some(Box::new(to_proto::serialize_expr(
+                                &function.body,
+                                extension_codec,
+                            )?)),
+                            definition: function.definition.clone(),
+                            or_replace: *or_replace,
+                            schema: Some(schema.try_into()?),
+                        },
+                    ))),
+                })
+            }
+            LogicalPlan::DropFunction(DropFunction {
+                name,
+                if_exists,
+                schema,
+            }) => Ok(protobuf::LogicalPlanNode {
+                logical_plan_type: Some(LogicalPlanType::DropFunction(
+                    protobuf::DropFunctionNode {
+                        name: name.clone(),
+                        if_exists: *if_exists,
+                        schema: Some(schema.try_into()?),
+                    },
+                )),
+            }),
+            LogicalPlan::AlterTable(AlterTable {
+                name,
+                operation,
+                schema,
+            }) => {
+                use protobuf::alter_table_node::Operation;
+                let operation = match operation {
+                    AlterTableOperation::RenameTo(new_name) => {
+                        Operation::RenameTo(new_name.clone().into())
+                    }
+                    AlterTableOperation::AddColumn(field) => {
+                        Operation::AddColumn(field.try_into()?)
+                    }
+                    AlterTableOperation::SetOptions(options) => {
+                        Operation::SetOptions(protobuf::AlterTableOptions {
+                            options: options.clone(),
+                        })
+                    }
+                };
+                Ok(protobuf::LogicalPlanNode {
+                    logical_plan_type: Some(LogicalPlanType::AlterTable(
+                        protobuf::AlterTableNode {
+                            name: Some(name.clone().into()),
+                            schema: Some(schema.try_into()?),
+                            operation: Some(operation),
+                        },
+                    )),
+                })
+            }
+            LogicalPlan::AlterView(AlterView {
+                name,
+                new_name,
+                schema,
+            }) => Ok(protobuf::LogicalPlanNode {
+                logical_plan_type: Some(LogicalPlanType::AlterView(
+                    protobuf::AlterViewNode {
+                        name: Some(name.clone().into()),
+                        new_name: Some(new_name.clone().into()),
+                        schema: Some(schema.try_into()?),
+                    },
+                )),
+            }),
+            LogicalPlan::SetVariable(SetVariable {
+                variable,
+                value,
+                schema,
+            }) => Ok(protobuf::LogicalPlanNode {
+                logical_plan_type: Some(LogicalPlanType::SetVariable(
+                    protobuf::SetVariableNode {
+                        variable: variable.clone(),
+                        value: value.clone(),
+                        schema: Some(schema.try_into()?),
+                    },
+                )),
+            }),
+            LogicalPlan::Dml(DmlStatement {
+                table_name,
+                table_schema,
+                op,
+                input,
+            }) => {
+                let input = protobuf::LogicalPlanNode::try_from_logical_plan(
+                    input,
+                    extension_codec,
+                )?;
+                let op: protobuf::WriteOp = op.into();
+                Ok(protobuf::LogicalPlanNode {
+                    logical_plan_type: Some(LogicalPlanType::Dml(Box::new(
+                        protobuf::DmlNode {
+                            table_name: Some(table_name.clone().into()),
+                            table_schema: Some(table_schema.try_into()?),
+                            op: op.into(),
+                            input: Some(Box::new(input)),
+                        },
+                    ))),
+                })
+            }
+            LogicalPlan::DescribeTable(DescribeTable {
+                schema,
+                dummy_schema,
+            }) => Ok(protobuf::LogicalPlanNode {
+                logical_plan_type: Some(LogicalPlanType::DescribeTable(
+                    protobuf::DescribeTableNode {
+                        schema: Some(schema.as_ref().try_into()?),
+                        dummy_schema: Some(dummy_schema.try_into()?),
+                    },
+                )),
+            }),
         }
     }
 }
@@ -1370,14 +1829,15 @@ mod roundtrip_tests {
     use arrow::{
         array::ArrayRef,
         datatypes::{
-            DataType, Field, IntervalDayTimeType, IntervalMonthDayNanoType, IntervalUnit,
-            TimeUnit, UnionMode,
+            i256, DataType, Field, IntervalDayTimeType, IntervalMonthDayNanoType,
+            IntervalUnit, TimeUnit, UnionMode,
         },
     };
     use datafusion::datasource::datasource::TableProviderFactory;
     use datafusion::datasource::TableProvider;
     use datafusion::execution::context::SessionState;
     use datafusion::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
+    use datafusion::physical_plan::expressions::MaxAccumulator;
     use datafusion::physical_plan::functions::make_scalar_function;
     use datafusion::prelude::{
         create_udf, CsvReadOptions, SessionConfig, SessionContext,
@@ -1385,13 +1845,15 @@ mod roundtrip_tests {
     use datafusion::test_util::{TestTableFactory, TestTableProvider};
     use datafusion_common::{DFSchemaRef, DataFusionError, ScalarValue};
     use datafusion_expr::expr::{
-        self, Between, BinaryExpr, Case, Cast, GroupingSet, Like, Sort,
+        self, Between, BinaryExpr, Case, Cast, GroupingSet, Like, Sort, TryCast,
+    };
+    use datafusion_expr::logical_plan::{
+        Extension, Subquery, UserDefinedLogicalNodeCore,
     };
-    use datafusion_expr::logical_plan::{Extension, UserDefinedLogicalNodeCore};
     use datafusion_expr::{
         col, lit, Accumulator, AggregateFunction,
         BuiltinScalarFunction::{Sqrt, Substr},
-        Expr, LogicalPlan, Operator, Volatility,
+        Expr, LogicalPlan, LogicalPlanBuilder, Operator, Volatility,
     };
     use datafusion_expr::{
         create_udaf, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowFunction,
@@ -1517,8 +1979,9 @@ mod roundtrip_tests {
         }
     }
 
-    #[tokio::test]
-    async fn roundtrip_custom_tables() -> Result<(), DataFusionError> {
+    /// A session that creates the external tables stored as `testtable` with the
+    /// [`TestTableFactory`]
+    fn custom_tables_context() -> SessionContext {
         let mut table_factories: HashMap<String, Arc<dyn TableProviderFactory>> =
             HashMap::new();
         table_factories.insert("TESTTABLE".to_string(), Arc::new(TestTableFactory {}));
@@ -1528,8 +1991,12 @@ mod roundtrip_tests {
         let mut state = SessionState::with_config_rt(ses, Arc::new(env));
         // replace factories
         *state.table_factories_mut() = table_factories;
-        let ctx = SessionContext::with_state(state);
+        SessionContext::with_state(state)
+    }
 
+    #[tokio::test]
+    async fn roundtrip_custom_tables() -> Result<(), DataFusionError> {
+        let ctx = custom_tables_context();
         let sql = "CREATE EXTERNAL TABLE t STORED AS testtable LOCATION 's3://bucket/schema/table';";
         ctx.sql(sql).await.unwrap();
 
@@ -1542,6 +2009,38 @@ mod roundtrip_tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn roundtrip_custom_tables_in_subqueries() -> Result<(), DataFusionError> {
+        let ctx = custom_tables_context();
+        let sql = "CREATE EXTERNAL TABLE t STORED AS testtable LOCATION 's3://bucket/schema/table';";
+        ctx.sql(sql).await.unwrap();
+
+        let codec = TestTableProviderCodec {};
+        let scan = ctx.table("t").await?.into_optimized_plan()?;
+        let plan = LogicalPlanBuilder::from(scan.clone())
+            .filter(Expr::Exists {
+                subquery: Subquery::new(scan.clone()),
+                negated: false,
+            })?
+            .build()?;
+        let bytes = logical_plan_to_bytes_with_extension_codec(&plan, &codec)?;
+        let logical_round_trip =
+            logical_plan_from_bytes_with_extension_codec(&bytes, &ctx, &codec)?;
+        assert_eq!(format!("{plan:?}"), format!("{logical_round_trip:?}"));
+
+        // the Debug output of subquery expressions does not include the plan
+        match logical_round_trip {
+            LogicalPlan::Filter(filter) => match &filter.predicate {
+                Expr::Exists { subquery, .. } => {
+                    assert_eq!(format!("{scan:?}"), format!("{:?}", subquery.subquery))
+                }
+                other => panic!("unexpected predicate {other:?}"),
+            },
+            other => panic!("unexpected plan {other:?}"),
+        }
+        Ok(())
+    }
+
     #[tokio::test]
     async fn roundtrip_logical_plan_aggregation() -> Result<(), DataFusionError> {
         let ctx = SessionContext::new();
@@ -1624,6 +2123,103 @@ mod roundtrip_tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn roundtrip_logical_plan_with_subqueries() -> Result<(), DataFusionError> {
+        let ctx = SessionContext::new();
+        ctx.register_csv("t1", "testdata/test.csv", CsvReadOptions::default())
+            .await?;
+        ctx.register_csv("t2", "testdata/test.csv", CsvReadOptions::default())
+            .await?;
+
+        // use the unoptimized plans, the optimizer rewrites most subqueries into joins
+        let queries = [
+            "SELECT a FROM t1 WHERE EXISTS (SELECT b FROM t2 WHERE t2.a = t1.a)",
+            "SELECT a FROM t1 WHERE NOT EXISTS (SELECT b FROM t2 WHERE t2.a = t1.a)",
+            "SELECT a FROM t1 WHERE a IN (SELECT a FROM t2 WHERE b > 2)",
+            "SELECT a FROM t1 WHERE a NOT IN (SELECT a FROM t2)",
+            "SELECT a, (SELECT MAX(b) FROM t2 WHERE t2.a = t1.a) AS max_b FROM t1",
+        ];
+        for query in queries {
+            let plan = ctx.state().create_logical_plan(query).await?;
+            let bytes = logical_plan_to_bytes(&plan)?;
+            let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+            assert_eq!(format!("{plan:?}"), format!("{logical_round_trip:?}"));
+        }
+
+        let scan = ctx.table("t2").await?.into_optimized_plan()?;
+        let plan = LogicalPlan::Subquery(Subquery::new(scan));
+        let bytes = logical_plan_to_bytes(&plan)?;
+        let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+        assert_eq!(format!("{plan:?}"), format!("{logical_round_trip:?}"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn roundtrip_logical_plan_ddl() -> Result<(), DataFusionError> {
+        let ctx = SessionContext::new();
+        ctx.register_csv("t1", "testdata/test.csv", CsvReadOptions::default())
+            .await?;
+
+        // these plans do not reference any tables, so they can be compared directly
+        let statements = [
+            "DROP TABLE IF EXISTS t1",
+            "DROP VIEW v1",
+            "CREATE FUNCTION discount(price DOUBLE, pct DOUBLE) RETURNS DOUBLE RETURN price * (1 - pct)",
+            "CREATE OR REPLACE FUNCTION answer() RETURN 42",
+            "DROP FUNCTION IF EXISTS answer",
+            "ALTER TABLE t1 RENAME TO t2",
+            "ALTER TABLE t1 ADD COLUMN c INT",
+            "ALTER TABLE t1 SET OPTIONS ('b' '2', 'a' '1')",
+            "ALTER VIEW v1 RENAME TO v2",
+            "SET datafusion.execution.batch_size = 1",
+            "DESCRIBE t1",
+        ];
+        for statement in statements {
+            let plan = ctx.state().create_logical_plan(statement).await?;
+            let bytes = logical_plan_to_bytes(&plan)?;
+            let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+            assert_eq!(plan, logical_round_trip);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn roundtrip_logical_plan_dml() -> Result<(), DataFusionError> {
+        let ctx = SessionContext::new();
+        ctx.register_csv("t1", "testdata/test.csv", CsvReadOptions::default())
+            .await?;
+
+        let statements = [
+            "CREATE TABLE t2 AS SELECT a, b FROM t1",
+            "INSERT INTO t1 VALUES (1, 2)",
+            "INSERT INTO t1 SELECT b, a FROM t1",
+            "DELETE FROM t1 WHERE a = 1",
+            "UPDATE t1 SET b = 3 WHERE a = 1",
+            "PREPARE q(INT) AS SELECT a FROM t1 WHERE a = $1",
+        ];
+        for statement in statements {
+            let plan = ctx.state().create_logical_plan(statement).await?;
+            let bytes = logical_plan_to_bytes(&plan)?;
+            let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+            assert_eq!(format!("{plan:?}"), format!("{logical_round_trip:?}"));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn roundtrip_logical_plan_unnest() -> Result<(), DataFusionError> {
+        let ctx = SessionContext::new();
+        let plan = ctx
+            .state()
+            .create_logical_plan("SELECT make_array(1, 2, 3) AS a")
+            .await?;
+        let plan = LogicalPlanBuilder::from(plan).unnest_column("a")?.build()?;
+        let bytes = logical_plan_to_bytes(&plan)?;
+        let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+        assert_eq!(format!("{plan:?}"), format!("{logical_round_trip:?}"));
+        Ok(())
+    }
+
     pub mod proto {
         #[derive(Clone, PartialEq, ::prost::Message)]
         pub struct TopKPlanProto {
@@ -1921,6 +2517,10 @@ mod roundtrip_tests {
             ScalarValue::Date64(Some(0)),
             ScalarValue::Date64(Some(i64::MAX)),
             ScalarValue::Date64(None),
+            ScalarValue::Decimal256(Some(i256::from_i128(1234567)), 50, 3),
+            ScalarValue::Decimal256(Some(i256::MIN), 76, 0),
+            ScalarValue::Decimal256(Some(i256::MAX), 76, 10),
+            ScalarValue::Decimal256(None, 40, 2),
             ScalarValue::Time32Second(Some(0)),
             ScalarValue::Time32Second(Some(i32::MAX)),
             ScalarValue::Time32Second(None),
@@ -1966,6 +2566,14 @@ mod roundtrip_tests {
                 IntervalMonthDayNanoType::make_value(i32::MAX, i32::MAX, i64::MAX),
             )),
             ScalarValue::IntervalMonthDayNano(None),
+            ScalarValue::DurationSecond(Some(-86_400)),
+            ScalarValue::DurationSecond(None),
+            ScalarValue::DurationMillisecond(Some(i64::MAX)),
+            ScalarValue::DurationMillisecond(None),
+            ScalarValue::DurationMicrosecond(Some(i64::MIN)),
+            ScalarValue::DurationMicrosecond(None),
+            ScalarValue::DurationNanosecond(Some(1)),
+            ScalarValue::DurationNanosecond(None),
             ScalarValue::new_list(
                 Some(vec![
                     ScalarValue::Float32(Some(-213.1)),
@@ -2127,6 +2735,7 @@ mod roundtrip_tests {
             DataType::Utf8,
             DataType::LargeUtf8,
             DataType::Decimal128(7, 12),
+            DataType::Decimal256(50, 12),
             // Recursive list tests
             DataType::List(new_box_field("Level1", DataType::Binary, true)),
             DataType::List(new_box_field(
@@ -2378,6 +2987,14 @@ mod roundtrip_tests {
         roundtrip_expr_test(test_expr, ctx);
     }
 
+    #[test]
+    fn roundtrip_try_cast() {
+        let test_expr = Expr::TryCast(TryCast::new(Box::new(lit("1")), DataType::Int32));
+
+        let ctx = SessionContext::new();
+        roundtrip_expr_test(test_expr, ctx);
+    }
+
     #[test]
     fn roundtrip_sort_expr() {
         let test_expr = Expr::Sort(Sort::new(Box::new(lit(1.0_f32)), true, true));
@@ -2414,6 +3031,71 @@ mod roundtrip_tests {
         roundtrip_expr_test(test_expr, ctx);
     }
 
+    #[test]
+    fn roundtrip_qualified_wildcard() {
+        let test_expr = Expr::QualifiedWildcard {
+            qualifier: "t1".to_string(),
+        };
+
+        let ctx = SessionContext::new();
+        roundtrip_expr_test(test_expr, ctx);
+    }
+
+    #[test]
+    fn roundtrip_scalar_variable() {
+        let test_expr = Expr::ScalarVariable(
+            DataType::Utf8,
+            vec!["@@".to_string(), "version".to_string()],
+        );
+
+        let ctx = SessionContext::new();
+        roundtrip_expr_test(test_expr, ctx);
+    }
+
+    #[test]
+    fn roundtrip_subquery_exprs() {
+        let plan = LogicalPlanBuilder::empty(true)
+            .project(vec![lit(1_i64)])
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let test_exprs = vec![
+            Expr::Exists {
+                subquery: Subquery::new(plan.clone()),
+                negated: true,
+            },
+            Expr::InSubquery {
+                expr: Box::new(col("a")),
+                subquery: Subquery::new(plan.clone()),
+                negated: false,
+            },
+            Expr::ScalarSubquery(Subquery::new(plan.clone())),
+        ];
+
+        let ctx = SessionContext::new();
+        for test_expr in test_exprs {
+            let proto: protobuf::LogicalExprNode = (&test_expr).try_into().unwrap();
+            let round_trip: Expr = parse_expr(&proto, &ctx).unwrap();
+            // the Debug output of subquery expressions does not include the plan
+            assert_eq!(test_expr, round_trip);
+
+            roundtrip_json_test(&proto);
+        }
+    }
+
+    #[test]
+    fn roundtrip_duration_literal() {
+        // the constant folded difference of two timestamps
+        let duration = ScalarValue::TimestampSecond(Some(86_400), None)
+            .sub(ScalarValue::TimestampSecond(Some(0), None))
+            .unwrap();
+        assert_eq!(duration, ScalarValue::DurationSecond(Some(86_400)));
+        let test_expr = Expr::Literal(duration);
+        let ctx = SessionContext::new();
+        roundtrip_expr_test(test_expr, ctx);
+    }
+
     #[test]
     fn roundtrip_sqrt() {
         let test_expr = Expr::ScalarFunction {
@@ -2703,9 +3385,29 @@ mod roundtrip_tests {
             row_number_frame,
         ));
 
+        // 5. test with AggregateUDF
+        let dummy_agg = create_udaf(
+            "dummy_agg",
+            DataType::Float64,
+            Arc::new(DataType::Float64),
+            Volatility::Immutable,
+            Arc::new(|_| Ok(Box::new(MaxAccumulator::try_new(&DataType::Float64)?))),
+            Arc::new(vec![DataType::Float64]),
+        );
+        ctx.register_udaf(dummy_agg.clone());
+
+        let test_expr5 = Expr::WindowFunction(expr::WindowFunction::new(
+            WindowFunction::AggregateUDF(Arc::new(dummy_agg)),
+            vec![col("col1")],
+            vec![col("col1")],
+            vec![col("col2")],
+            WindowFrame::new(true),
+        ));
+
         roundtrip_expr_test(test_expr1, ctx.clone());
         roundtrip_expr_test(test_expr2, ctx.clone());
         roundtrip_expr_test(test_expr3, ctx.clone());
-        roundtrip_expr_test(test_expr4, ctx);
+        roundtrip_expr_test(test_expr4, ctx.clone());
+        roundtrip_expr_test(test_expr5, ctx);
     }
 }
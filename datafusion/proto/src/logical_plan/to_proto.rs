@@ -19,6 +19,9 @@
 //! DataFusion logical plans to be serialized and transmitted between
 //! processes.
 
+use crate::logical_plan::{
+    AsLogicalPlan, DefaultLogicalExtensionCodec, LogicalExtensionCodec,
+};
 use crate::protobuf::{
     self,
     arrow_type::ArrowTypeEnum,
@@ -27,7 +30,7 @@ use crate::protobuf::{
         OptimizedLogicalPlan, OptimizedPhysicalPlan,
     },
     CubeNode, EmptyMessage, GroupingSetNode, LogicalExprList, OptimizedLogicalPlanType,
-    OptimizedPhysicalPlanType, PlaceholderNode, RollupNode,
+    OptimizedPhysicalPlanType, PlaceholderNode, RollupNode, ScalarVariableNode,
 };
 use arrow::datatypes::{
     DataType, Field, IntervalMonthDayNanoType, IntervalUnit, Schema, SchemaRef, TimeUnit,
@@ -35,12 +38,13 @@ use arrow::datatypes::{
 };
 use datafusion_common::{Column, DFField, DFSchemaRef, OwnedTableReference, ScalarValue};
 use datafusion_expr::expr::{
-    self, Between, BinaryExpr, Cast, GetIndexedField, GroupingSet, Like, Sort,
+    self, Between, BinaryExpr, Cast, GetIndexedField, GroupingSet, Like, Sort, TryCast,
 };
 use datafusion_expr::{
-    logical_plan::PlanType, logical_plan::StringifiedPlan, AggregateFunction,
-    BuiltInWindowFunction, BuiltinScalarFunction, Expr, JoinConstraint, JoinType,
-    WindowFrame, WindowFrameBound, WindowFrameUnits, WindowFunction,
+    logical_plan::PlanType, logical_plan::StringifiedPlan, logical_plan::Subquery,
+    logical_plan::WriteOp, AggregateFunction, BuiltInWindowFunction,
+    BuiltinScalarFunction, Expr, JoinConstraint, JoinType, WindowFrame, WindowFrameBound,
+    WindowFrameUnits, WindowFunction,
 };
 
 #[derive(Debug)]
@@ -215,9 +219,10 @@ impl TryFrom<&DataType> for protobuf::arrow_type::ArrowTypeEnum {
                 precision: *precision as u32,
                 scale: *scale as i32,
             }),
-            DataType::Decimal256(_, _) => {
-                return Err(Error::General("Proto serialization error: The Decimal256 data type is not yet supported".to_owned()))
-            }
+            DataType::Decimal256(precision, scale) => Self::Decimal256(protobuf::Decimal256Type {
+                precision: *precision as u32,
+                scale: *scale as i32,
+            }),
             DataType::Map(field, sorted) => {
                 Self::Map(Box::new(
                     protobuf::Map {
@@ -442,450 +447,495 @@ impl TryFrom<&WindowFrame> for protobuf::WindowFrame {
 impl TryFrom<&Expr> for protobuf::LogicalExprNode {
     type Error = Error;
 
+    /// The logical plans of the subqueries are serialized without a
+    /// [`LogicalExtensionCodec`], see [`serialize_expr`].
     fn try_from(expr: &Expr) -> Result<Self, Self::Error> {
-        use protobuf::logical_expr_node::ExprType;
+        serialize_expr(expr, &DefaultLogicalExtensionCodec {})
+    }
+}
 
-        let expr_node = match expr {
-            Expr::Column(c) => Self {
-                expr_type: Some(ExprType::Column(c.into())),
-            },
-            Expr::Alias(expr, alias) => {
-                let alias = Box::new(protobuf::AliasNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    alias: alias.to_owned(),
-                });
-                Self {
-                    expr_type: Some(ExprType::Alias(alias)),
-                }
+/// Serializes `expr`, serializing the logical plans of its subqueries with `codec`.
+pub fn serialize_expr(
+    expr: &Expr,
+    codec: &dyn LogicalExtensionCodec,
+) -> Result<protobuf::LogicalExprNode, Error> {
+    use protobuf::logical_expr_node::ExprType;
+
+    let expr_node = match expr {
+        Expr::Column(c) => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::Column(c.into())),
+        },
+        Expr::Alias(expr, alias) => {
+            let alias = Box::new(protobuf::AliasNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                alias: alias.to_owned(),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Alias(alias)),
             }
-            Expr::Literal(value) => {
-                let pb_value: protobuf::ScalarValue = value.try_into()?;
-                Self {
-                    expr_type: Some(ExprType::Literal(pb_value)),
-                }
+        }
+        Expr::Literal(value) => {
+            let pb_value: protobuf::ScalarValue = value.try_into()?;
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Literal(pb_value)),
             }
-            Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
-                // Try to linerize a nested binary expression tree of the same operator
-                // into a flat vector of expressions.
-                let mut exprs = vec![right.as_ref()];
-                let mut current_expr = left.as_ref();
-                while let Expr::BinaryExpr(BinaryExpr {
-                    left,
-                    op: current_op,
-                    right,
-                }) = current_expr
-                {
-                    if current_op == op {
-                        exprs.push(right.as_ref());
-                        current_expr = left.as_ref();
-                    } else {
-                        break;
-                    }
+        }
+        Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
+            // Try to linerize a nested binary expression tree of the same operator
+            // into a flat vector of expressions.
+            let mut exprs = vec![right.as_ref()];
+            let mut current_expr = left.as_ref();
+            while let Expr::BinaryExpr(BinaryExpr {
+                left,
+                op: current_op,
+                right,
+            }) = current_expr
+            {
+                if current_op == op {
+                    exprs.push(right.as_ref());
+                    current_expr = left.as_ref();
+                } else {
+                    break;
                 }
-                exprs.push(current_expr);
+            }
+            exprs.push(current_expr);
 
-                let binary_expr = protobuf::BinaryExprNode {
-                    // We need to reverse exprs since operands are expected to be
-                    // linearized from left innermost to right outermost (but while
-                    // traversing the chain we do the exact opposite).
-                    operands: exprs
+            let binary_expr = protobuf::BinaryExprNode {
+                // We need to reverse exprs since operands are expected to be
+                // linearized from left innermost to right outermost (but while
+                // traversing the chain we do the exact opposite).
+                operands: exprs
                     .into_iter()
                     .rev()
-                    .map(|expr| expr.try_into())
+                    .map(|expr| serialize_expr(expr, codec))
                     .collect::<Result<Vec<_>, Error>>()?,
-                    op: format!("{op:?}"),
-                };
-                Self {
-                    expr_type: Some(ExprType::BinaryExpr(binary_expr)),
-                }
+                op: format!("{op:?}"),
+            };
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::BinaryExpr(binary_expr)),
             }
-            Expr::Like(Like { negated, expr, pattern, escape_char }) => {
-                let pb = Box::new(protobuf::LikeNode {
-                    negated: *negated,
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    pattern: Some(Box::new(pattern.as_ref().try_into()?)),
-                    escape_char: escape_char
-                        .map(|ch| ch.to_string())
-                        .unwrap_or_default()
-                });
-                Self {
-                    expr_type: Some(ExprType::Like(pb)),
-                }
+        }
+        Expr::Like(Like {
+            negated,
+            expr,
+            pattern,
+            escape_char,
+        }) => {
+            let pb = Box::new(protobuf::LikeNode {
+                negated: *negated,
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                pattern: Some(Box::new(serialize_expr(pattern.as_ref(), codec)?)),
+                escape_char: escape_char.map(|ch| ch.to_string()).unwrap_or_default(),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Like(pb)),
             }
-            Expr::ILike(Like { negated, expr, pattern, escape_char }) => {
-                let pb = Box::new(protobuf::ILikeNode {
-                    negated: *negated,
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    pattern: Some(Box::new(pattern.as_ref().try_into()?)),
-                    escape_char: escape_char
-                        .map(|ch| ch.to_string())
-                        .unwrap_or_default(),
-                });
-                Self {
-                    expr_type: Some(ExprType::Ilike(pb)),
-                }
+        }
+        Expr::ILike(Like {
+            negated,
+            expr,
+            pattern,
+            escape_char,
+        }) => {
+            let pb = Box::new(protobuf::ILikeNode {
+                negated: *negated,
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                pattern: Some(Box::new(serialize_expr(pattern.as_ref(), codec)?)),
+                escape_char: escape_char.map(|ch| ch.to_string()).unwrap_or_default(),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Ilike(pb)),
             }
-            Expr::SimilarTo(Like { negated, expr, pattern, escape_char }) => {
-                let pb = Box::new(protobuf::SimilarToNode {
-                    negated: *negated,
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    pattern: Some(Box::new(pattern.as_ref().try_into()?)),
-                    escape_char: escape_char
-                        .map(|ch| ch.to_string())
-                        .unwrap_or_default(),
-                });
-                Self {
-                    expr_type: Some(ExprType::SimilarTo(pb)),
-                }
+        }
+        Expr::SimilarTo(Like {
+            negated,
+            expr,
+            pattern,
+            escape_char,
+        }) => {
+            let pb = Box::new(protobuf::SimilarToNode {
+                negated: *negated,
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                pattern: Some(Box::new(serialize_expr(pattern.as_ref(), codec)?)),
+                escape_char: escape_char.map(|ch| ch.to_string()).unwrap_or_default(),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::SimilarTo(pb)),
             }
-            Expr::WindowFunction(expr::WindowFunction {
-                ref fun,
-                ref args,
-                ref partition_by,
-                ref order_by,
-                ref window_frame,
-            }) => {
-                let window_function = match fun {
-                    WindowFunction::AggregateFunction(fun) => {
-                        protobuf::window_expr_node::WindowFunction::AggrFunction(
-                            protobuf::AggregateFunction::from(fun).into(),
-                        )
-                    }
-                    WindowFunction::BuiltInWindowFunction(fun) => {
-                        protobuf::window_expr_node::WindowFunction::BuiltInFunction(
-                            protobuf::BuiltInWindowFunction::from(fun).into(),
-                        )
-                    }
-                    // TODO: Tracked in https://github.com/apache/arrow-datafusion/issues/4584
-                    WindowFunction::AggregateUDF(_) => return Err(Error::NotImplemented("UDAF as window function in proto".to_string()))
-                };
-                let arg_expr: Option<Box<Self>> = if !args.is_empty() {
-                    let arg = &args[0];
-                    Some(Box::new(arg.try_into()?))
-                } else {
-                    None
-                };
-                let partition_by = partition_by
-                    .iter()
-                    .map(|e| e.try_into())
-                    .collect::<Result<Vec<_>, _>>()?;
-                let order_by = order_by
-                    .iter()
-                    .map(|e| e.try_into())
-                    .collect::<Result<Vec<_>, _>>()?;
-
-                let window_frame: Option<protobuf::WindowFrame> = Some(window_frame.try_into()?);
-                let window_expr = Box::new(protobuf::WindowExprNode {
-                    expr: arg_expr,
-                    window_function: Some(window_function),
-                    partition_by,
-                    order_by,
-                    window_frame,
-                });
-                Self {
-                    expr_type: Some(ExprType::WindowExpr(window_expr)),
+        }
+        Expr::WindowFunction(expr::WindowFunction {
+            ref fun,
+            ref args,
+            ref partition_by,
+            ref order_by,
+            ref window_frame,
+        }) => {
+            let window_function = match fun {
+                WindowFunction::AggregateFunction(fun) => {
+                    protobuf::window_expr_node::WindowFunction::AggrFunction(
+                        protobuf::AggregateFunction::from(fun).into(),
+                    )
                 }
-            }
-            Expr::AggregateFunction(expr::AggregateFunction {
-                ref fun,
-                ref args,
-                ref distinct,
-                ref filter
-            }) => {
-                let aggr_function = match fun {
-                    AggregateFunction::ApproxDistinct => {
-                        protobuf::AggregateFunction::ApproxDistinct
-                    }
-                    AggregateFunction::ApproxPercentileCont => {
-                        protobuf::AggregateFunction::ApproxPercentileCont
-                    }
-                    AggregateFunction::ApproxPercentileContWithWeight => {
-                        protobuf::AggregateFunction::ApproxPercentileContWithWeight
-                    }
-                    AggregateFunction::ArrayAgg => protobuf::AggregateFunction::ArrayAgg,
-                    AggregateFunction::Min => protobuf::AggregateFunction::Min,
-                    AggregateFunction::Max => protobuf::AggregateFunction::Max,
-                    AggregateFunction::Sum => protobuf::AggregateFunction::Sum,
-                    AggregateFunction::Avg => protobuf::AggregateFunction::Avg,
-                    AggregateFunction::Count => protobuf::AggregateFunction::Count,
-                    AggregateFunction::Variance => protobuf::AggregateFunction::Variance,
-                    AggregateFunction::VariancePop => {
-                        protobuf::AggregateFunction::VariancePop
-                    }
-                    AggregateFunction::Covariance => {
-                        protobuf::AggregateFunction::Covariance
-                    }
-                    AggregateFunction::CovariancePop => {
-                        protobuf::AggregateFunction::CovariancePop
-                    }
-                    AggregateFunction::Stddev => protobuf::AggregateFunction::Stddev,
-                    AggregateFunction::StddevPop => {
-                        protobuf::AggregateFunction::StddevPop
-                    }
-                    AggregateFunction::Correlation => {
-                        protobuf::AggregateFunction::Correlation
-                    }
-                    AggregateFunction::ApproxMedian => {
-                        protobuf::AggregateFunction::ApproxMedian
-                    }
-                    AggregateFunction::Grouping => protobuf::AggregateFunction::Grouping,
-                    AggregateFunction::Median => protobuf::AggregateFunction::Median,
-                };
-
-                let aggregate_expr = protobuf::AggregateExprNode {
-                    aggr_function: aggr_function.into(),
-                    expr: args
-                        .iter()
-                        .map(|v| v.try_into())
-                        .collect::<Result<Vec<_>, _>>()?,
-                    distinct: *distinct,
-                    filter: match filter {
-                        Some(e) => Some(Box::new(e.as_ref().try_into()?)),
-                        None => None,
-                    },
-                };
-                Self {
-                    expr_type: Some(ExprType::AggregateExpr(Box::new(aggregate_expr))),
+                WindowFunction::BuiltInWindowFunction(fun) => {
+                    protobuf::window_expr_node::WindowFunction::BuiltInFunction(
+                        protobuf::BuiltInWindowFunction::from(fun).into(),
+                    )
                 }
+                WindowFunction::AggregateUDF(fun) => {
+                    protobuf::window_expr_node::WindowFunction::Udaf(fun.name.clone())
+                }
+            };
+            let arg_expr: Option<Box<protobuf::LogicalExprNode>> = if !args.is_empty() {
+                let arg = &args[0];
+                Some(Box::new(serialize_expr(arg, codec)?))
+            } else {
+                None
+            };
+            let partition_by = partition_by
+                .iter()
+                .map(|e| serialize_expr(e, codec))
+                .collect::<Result<Vec<_>, _>>()?;
+            let order_by = order_by
+                .iter()
+                .map(|e| serialize_expr(e, codec))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let window_frame: Option<protobuf::WindowFrame> =
+                Some(window_frame.try_into()?);
+            let window_expr = Box::new(protobuf::WindowExprNode {
+                expr: arg_expr,
+                window_function: Some(window_function),
+                partition_by,
+                order_by,
+                window_frame,
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::WindowExpr(window_expr)),
             }
-            Expr::ScalarVariable(_, _) => return Err(Error::General("Proto serialization error: Scalar Variable not supported".to_string())),
-            Expr::ScalarFunction { ref fun, ref args } => {
-                let fun: protobuf::ScalarFunction = fun.try_into()?;
-                let args: Vec<Self> = args
-                    .iter()
-                    .map(|e| e.try_into())
-                    .collect::<Result<Vec<Self>, Error>>()?;
-                Self {
-                    expr_type: Some(ExprType::ScalarFunction(
-                        protobuf::ScalarFunctionNode {
-                            fun: fun.into(),
-                            args,
-                        },
-                    )),
+        }
+        Expr::AggregateFunction(expr::AggregateFunction {
+            ref fun,
+            ref args,
+            ref distinct,
+            ref filter,
+        }) => {
+            let aggr_function = match fun {
+                AggregateFunction::ApproxDistinct => {
+                    protobuf::AggregateFunction::ApproxDistinct
                 }
+                AggregateFunction::ApproxPercentileCont => {
+                    protobuf::AggregateFunction::ApproxPercentileCont
+                }
+                AggregateFunction::ApproxPercentileContWithWeight => {
+                    protobuf::AggregateFunction::ApproxPercentileContWithWeight
+                }
+                AggregateFunction::ArrayAgg => protobuf::AggregateFunction::ArrayAgg,
+                AggregateFunction::Min => protobuf::AggregateFunction::Min,
+                AggregateFunction::Max => protobuf::AggregateFunction::Max,
+                AggregateFunction::Sum => protobuf::AggregateFunction::Sum,
+                AggregateFunction::Avg => protobuf::AggregateFunction::Avg,
+                AggregateFunction::Count => protobuf::AggregateFunction::Count,
+                AggregateFunction::Variance => protobuf::AggregateFunction::Variance,
+                AggregateFunction::VariancePop => {
+                    protobuf::AggregateFunction::VariancePop
+                }
+                AggregateFunction::Covariance => protobuf::AggregateFunction::Covariance,
+                AggregateFunction::CovariancePop => {
+                    protobuf::AggregateFunction::CovariancePop
+                }
+                AggregateFunction::Stddev => protobuf::AggregateFunction::Stddev,
+                AggregateFunction::StddevPop => protobuf::AggregateFunction::StddevPop,
+                AggregateFunction::Correlation => {
+                    protobuf::AggregateFunction::Correlation
+                }
+                AggregateFunction::ApproxMedian => {
+                    protobuf::AggregateFunction::ApproxMedian
+                }
+                AggregateFunction::Grouping => protobuf::AggregateFunction::Grouping,
+                AggregateFunction::Median => protobuf::AggregateFunction::Median,
+            };
+
+            let aggregate_expr = protobuf::AggregateExprNode {
+                aggr_function: aggr_function.into(),
+                expr: args
+                    .iter()
+                    .map(|v| serialize_expr(v, codec))
+                    .collect::<Result<Vec<_>, _>>()?,
+                distinct: *distinct,
+                filter: match filter {
+                    Some(e) => Some(Box::new(serialize_expr(e.as_ref(), codec)?)),
+                    None => None,
+                },
+            };
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::AggregateExpr(Box::new(aggregate_expr))),
+            }
+        }
+        Expr::ScalarVariable(data_type, names) => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::ScalarVariable(ScalarVariableNode {
+                data_type: Some(data_type.try_into()?),
+                names: names.clone(),
+            })),
+        },
+        Expr::ScalarFunction { ref fun, ref args } => {
+            let fun: protobuf::ScalarFunction = fun.try_into()?;
+            let args: Vec<protobuf::LogicalExprNode> = args
+                .iter()
+                .map(|e| serialize_expr(e, codec))
+                .collect::<Result<Vec<protobuf::LogicalExprNode>, Error>>()?;
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::ScalarFunction(protobuf::ScalarFunctionNode {
+                    fun: fun.into(),
+                    args,
+                })),
             }
-            Expr::ScalarUDF { fun, args } => Self {
-                expr_type: Some(ExprType::ScalarUdfExpr(protobuf::ScalarUdfExprNode {
+        }
+        Expr::ScalarUDF { fun, args } => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::ScalarUdfExpr(protobuf::ScalarUdfExprNode {
+                fun_name: fun.name.clone(),
+                args: args
+                    .iter()
+                    .map(|expr| serialize_expr(expr, codec))
+                    .collect::<Result<Vec<_>, Error>>()?,
+            })),
+        },
+        Expr::AggregateUDF { fun, args, filter } => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::AggregateUdfExpr(Box::new(
+                protobuf::AggregateUdfExprNode {
                     fun_name: fun.name.clone(),
                     args: args
                         .iter()
-                        .map(|expr| expr.try_into())
+                        .map(|expr| serialize_expr(expr, codec))
                         .collect::<Result<Vec<_>, Error>>()?,
-                })),
-            },
-            Expr::AggregateUDF { fun, args, filter } => {
-                Self {
-                    expr_type: Some(ExprType::AggregateUdfExpr(
-                        Box::new(protobuf::AggregateUdfExprNode {
-                            fun_name: fun.name.clone(),
-                            args: args.iter().map(|expr| expr.try_into()).collect::<Result<
-                                Vec<_>,
-                                Error,
-                            >>()?,
-                            filter: match filter {
-                                Some(e) => Some(Box::new(e.as_ref().try_into()?)),
-                                None => None,
-                            },
-                        },
-                        ))),
-                }
-            }
-            Expr::Not(expr) => {
-                let expr = Box::new(protobuf::Not {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::NotExpr(expr)),
-                }
+                    filter: match filter {
+                        Some(e) => Some(Box::new(serialize_expr(e.as_ref(), codec)?)),
+                        None => None,
+                    },
+                },
+            ))),
+        },
+        Expr::Not(expr) => {
+            let expr = Box::new(protobuf::Not {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::NotExpr(expr)),
             }
-            Expr::IsNull(expr) => {
-                let expr = Box::new(protobuf::IsNull {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsNullExpr(expr)),
-                }
+        }
+        Expr::IsNull(expr) => {
+            let expr = Box::new(protobuf::IsNull {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsNullExpr(expr)),
             }
-            Expr::IsNotNull(expr) => {
-                let expr = Box::new(protobuf::IsNotNull {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsNotNullExpr(expr)),
-                }
+        }
+        Expr::IsNotNull(expr) => {
+            let expr = Box::new(protobuf::IsNotNull {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsNotNullExpr(expr)),
             }
-            Expr::IsTrue(expr) => {
-                let expr = Box::new(protobuf::IsTrue {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsTrue(expr)),
-                }
+        }
+        Expr::IsTrue(expr) => {
+            let expr = Box::new(protobuf::IsTrue {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsTrue(expr)),
             }
-            Expr::IsFalse(expr) => {
-                let expr = Box::new(protobuf::IsFalse {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsFalse(expr)),
-                }
+        }
+        Expr::IsFalse(expr) => {
+            let expr = Box::new(protobuf::IsFalse {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsFalse(expr)),
             }
-            Expr::IsUnknown(expr) => {
-                let expr = Box::new(protobuf::IsUnknown {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsUnknown(expr)),
-                }
+        }
+        Expr::IsUnknown(expr) => {
+            let expr = Box::new(protobuf::IsUnknown {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsUnknown(expr)),
             }
-            Expr::IsNotTrue(expr) => {
-                let expr = Box::new(protobuf::IsNotTrue {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsNotTrue(expr)),
-                }
+        }
+        Expr::IsNotTrue(expr) => {
+            let expr = Box::new(protobuf::IsNotTrue {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsNotTrue(expr)),
             }
-            Expr::IsNotFalse(expr) => {
-                let expr = Box::new(protobuf::IsNotFalse {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsNotFalse(expr)),
-                }
+        }
+        Expr::IsNotFalse(expr) => {
+            let expr = Box::new(protobuf::IsNotFalse {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsNotFalse(expr)),
             }
-            Expr::IsNotUnknown(expr) => {
-                let expr = Box::new(protobuf::IsNotUnknown {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsNotUnknown(expr)),
-                }
+        }
+        Expr::IsNotUnknown(expr) => {
+            let expr = Box::new(protobuf::IsNotUnknown {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsNotUnknown(expr)),
             }
-            Expr::Between(Between {
-                expr,
-                negated,
-                low,
-                high,
-            }) => {
-                let expr = Box::new(protobuf::BetweenNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    negated: *negated,
-                    low: Some(Box::new(low.as_ref().try_into()?)),
-                    high: Some(Box::new(high.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::Between(expr)),
-                }
+        }
+        Expr::Between(Between {
+            expr,
+            negated,
+            low,
+            high,
+        }) => {
+            let expr = Box::new(protobuf::BetweenNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                negated: *negated,
+                low: Some(Box::new(serialize_expr(low.as_ref(), codec)?)),
+                high: Some(Box::new(serialize_expr(high.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Between(expr)),
             }
-            Expr::Case(case) => {
-                let when_then_expr = case.when_then_expr
-                    .iter()
-                    .map(|(w, t)| {
-                        Ok(protobuf::WhenThen {
-                            when_expr: Some(w.as_ref().try_into()?),
-                            then_expr: Some(t.as_ref().try_into()?),
-                        })
+        }
+        Expr::Case(case) => {
+            let when_then_expr = case
+                .when_then_expr
+                .iter()
+                .map(|(w, t)| {
+                    Ok(protobuf::WhenThen {
+                        when_expr: Some(serialize_expr(w.as_ref(), codec)?),
+                        then_expr: Some(serialize_expr(t.as_ref(), codec)?),
                     })
-                    .collect::<Result<Vec<protobuf::WhenThen>, Error>>()?;
-                let expr = Box::new(protobuf::CaseNode {
-                    expr: match &case.expr {
-                        Some(e) => Some(Box::new(e.as_ref().try_into()?)),
-                        None => None,
-                    },
-                    when_then_expr,
-                    else_expr: match &case.else_expr {
-                        Some(e) => Some(Box::new(e.as_ref().try_into()?)),
-                        None => None,
-                    },
-                });
-                Self {
-                    expr_type: Some(ExprType::Case(expr)),
-                }
+                })
+                .collect::<Result<Vec<protobuf::WhenThen>, Error>>()?;
+            let expr = Box::new(protobuf::CaseNode {
+                expr: match &case.expr {
+                    Some(e) => Some(Box::new(serialize_expr(e.as_ref(), codec)?)),
+                    None => None,
+                },
+                when_then_expr,
+                else_expr: match &case.else_expr {
+                    Some(e) => Some(Box::new(serialize_expr(e.as_ref(), codec)?)),
+                    None => None,
+                },
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Case(expr)),
             }
-            Expr::Cast(Cast { expr, data_type }) => {
-                let expr = Box::new(protobuf::CastNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    arrow_type: Some(data_type.try_into()?),
-                });
-                Self {
-                    expr_type: Some(ExprType::Cast(expr)),
-                }
+        }
+        Expr::Cast(Cast { expr, data_type }) => {
+            let expr = Box::new(protobuf::CastNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                arrow_type: Some(data_type.try_into()?),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Cast(expr)),
             }
-            Expr::Sort(Sort{
-                expr,
-                asc,
-                nulls_first,
-            }) => {
-                let expr = Box::new(protobuf::SortExprNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    asc: *asc,
-                    nulls_first: *nulls_first,
-                });
-                Self {
-                    expr_type: Some(ExprType::Sort(expr)),
-                }
+        }
+        Expr::Sort(Sort {
+            expr,
+            asc,
+            nulls_first,
+        }) => {
+            let expr = Box::new(protobuf::SortExprNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                asc: *asc,
+                nulls_first: *nulls_first,
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Sort(expr)),
             }
-            Expr::Negative(expr) => {
-                let expr = Box::new(protobuf::NegativeNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::Negative(expr)),
-                }
+        }
+        Expr::Negative(expr) => {
+            let expr = Box::new(protobuf::NegativeNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Negative(expr)),
             }
-            Expr::InList {
-                expr,
-                list,
-                negated,
-            } => {
-                let expr = Box::new(protobuf::InListNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    list: list
-                        .iter()
-                        .map(|expr| expr.try_into())
-                        .collect::<Result<Vec<_>, Error>>()?,
-                    negated: *negated,
-                });
-                Self {
-                    expr_type: Some(ExprType::InList(expr)),
-                }
+        }
+        Expr::InList {
+            expr,
+            list,
+            negated,
+        } => {
+            let expr = Box::new(protobuf::InListNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                list: list
+                    .iter()
+                    .map(|expr| serialize_expr(expr, codec))
+                    .collect::<Result<Vec<_>, Error>>()?,
+                negated: *negated,
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::InList(expr)),
             }
-            Expr::Wildcard => Self {
-                expr_type: Some(ExprType::Wildcard(true)),
-            },
-            Expr::ScalarSubquery(_) | Expr::InSubquery { .. } | Expr::Exists { .. } => {
-                // we would need to add logical plan operators to datafusion.proto to support this
-                // see discussion in https://github.com/apache/arrow-datafusion/issues/2565
-                return Err(Error::General("Proto serialization error: Expr::ScalarSubquery(_) | Expr::InSubquery { .. } | Expr::Exists { .. } not supported".to_string()));
-            }
-            Expr::GetIndexedField(GetIndexedField { key, expr }) =>
-                Self {
-                    expr_type: Some(ExprType::GetIndexedField(Box::new(
-                        protobuf::GetIndexedField {
-                            key: Some(key.try_into()?),
-                            expr: Some(Box::new(expr.as_ref().try_into()?)),
-                        },
-                    ))),
-                },
+        }
+        Expr::Wildcard => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::Wildcard(true)),
+        },
+        Expr::ScalarSubquery(subquery) => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::ScalarSubquery(Box::new(serialize_subquery(
+                subquery, codec,
+            )?))),
+        },
+        Expr::Exists { subquery, negated } => {
+            let expr = Box::new(protobuf::ExistsNode {
+                subquery: Some(Box::new(serialize_subquery(subquery, codec)?)),
+                negated: *negated,
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Exists(expr)),
+            }
+        }
+        Expr::InSubquery {
+            expr,
+            subquery,
+            negated,
+        } => {
+            let expr = Box::new(protobuf::InSubqueryNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                subquery: Some(Box::new(serialize_subquery(subquery, codec)?)),
+                negated: *negated,
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::InSubquery(expr)),
+            }
+        }
+        Expr::GetIndexedField(GetIndexedField { key, expr }) => {
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::GetIndexedField(Box::new(
+                    protobuf::GetIndexedField {
+                        key: Some(key.try_into()?),
+                        expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                    },
+                ))),
+            }
+        }
 
-            Expr::GroupingSet(GroupingSet::Cube(exprs)) => Self {
-                expr_type: Some(ExprType::Cube(CubeNode {
-                    expr: exprs.iter().map(|expr| expr.try_into()).collect::<Result<
-                        Vec<_>,
-                        Self::Error,
-                    >>()?,
-                })),
-            },
-            Expr::GroupingSet(GroupingSet::Rollup(exprs)) => Self {
-                expr_type: Some(ExprType::Rollup(RollupNode {
-                    expr: exprs.iter().map(|expr| expr.try_into()).collect::<Result<
-                        Vec<_>,
-                        Self::Error,
-                    >>()?,
-                })),
-            },
-            Expr::GroupingSet(GroupingSet::GroupingSets(exprs)) => Self {
+        Expr::GroupingSet(GroupingSet::Cube(exprs)) => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::Cube(CubeNode {
+                expr: exprs
+                    .iter()
+                    .map(|expr| serialize_expr(expr, codec))
+                    .collect::<Result<Vec<_>, Error>>()?,
+            })),
+        },
+        Expr::GroupingSet(GroupingSet::Rollup(exprs)) => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::Rollup(RollupNode {
+                expr: exprs
+                    .iter()
+                    .map(|expr| serialize_expr(expr, codec))
+                    .collect::<Result<Vec<_>, Error>>()?,
+            })),
+        },
+        Expr::GroupingSet(GroupingSet::GroupingSets(exprs)) => {
+            protobuf::LogicalExprNode {
                 expr_type: Some(ExprType::GroupingSet(GroupingSetNode {
                     expr: exprs
                         .iter()
@@ -893,34 +943,67 @@ impl TryFrom<&Expr> for protobuf::LogicalExprNode {
                             Ok(LogicalExprList {
                                 expr: expr_list
                                     .iter()
-                                    .map(|expr| expr.try_into())
-                                    .collect::<Result<Vec<_>, Self::Error>>()?,
+                                    .map(|expr| serialize_expr(expr, codec))
+                                    .collect::<Result<Vec<_>, Error>>()?,
                             })
                         })
-                        .collect::<Result<Vec<_>, Self::Error>>()?,
+                        .collect::<Result<Vec<_>, Error>>()?,
                 })),
-            },
-            Expr::Placeholder{ id, data_type } => {
-                let data_type = match data_type {
-                    Some(data_type) => Some(data_type.try_into()?),
-                    None => None,
-                };
-                Self {
-                    expr_type: Some(ExprType::Placeholder(PlaceholderNode {
-                        id: id.clone(),
-                        data_type,
-                    })),
-                }
-            },
+            }
+        }
+        Expr::Placeholder { id, data_type } => {
+            let data_type = match data_type {
+                Some(data_type) => Some(data_type.try_into()?),
+                None => None,
+            };
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Placeholder(PlaceholderNode {
+                    id: id.clone(),
+                    data_type,
+                })),
+            }
+        }
 
-            Expr::QualifiedWildcard { .. } | Expr::TryCast { .. } =>
-                return Err(Error::General("Proto serialization error: Expr::QualifiedWildcard { .. } | Expr::TryCast { .. } not supported".to_string())),
-        };
+        Expr::QualifiedWildcard { qualifier } => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::QualifiedWildcard(qualifier.clone())),
+        },
+        Expr::TryCast(TryCast { expr, data_type }) => {
+            let expr = Box::new(protobuf::TryCastNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                arrow_type: Some(data_type.try_into()?),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::TryCast(expr)),
+            }
+        }
+    };
 
-        Ok(expr_node)
+    Ok(expr_node)
+}
+
+impl TryFrom<&Subquery> for protobuf::SubqueryNode {
+    type Error = Error;
+
+    /// The logical plan of the subquery is serialized without a
+    /// [`LogicalExtensionCodec`], see [`serialize_subquery`].
+    fn try_from(subquery: &Subquery) -> Result<Self, Self::Error> {
+        serialize_subquery(subquery, &DefaultLogicalExtensionCodec {})
     }
 }
 
+/// Serializes `subquery`, serializing its logical plan with `codec`.
+pub fn serialize_subquery(
+    subquery: &Subquery,
+    codec: &dyn LogicalExtensionCodec,
+) -> Result<protobuf::SubqueryNode, Error> {
+    let plan =
+        protobuf::LogicalPlanNode::try_from_logical_plan(&subquery.subquery, codec)
+            .map_err(|e| Error::General(e.to_string()))?;
+    Ok(protobuf::SubqueryNode {
+        subquery: Some(Box::new(plan)),
+    })
+}
+
 impl TryFrom<&ScalarValue> for protobuf::ScalarValue {
     type Error = Error;
 
@@ -1050,6 +1133,24 @@ impl TryFrom<&ScalarValue> for protobuf::ScalarValue {
                     )),
                 }),
             },
+            datafusion::scalar::ScalarValue::Decimal256(val, p, s) => match *val {
+                Some(v) => {
+                    let array = v.to_be_bytes();
+                    let vec_val: Vec<u8> = array.to_vec();
+                    Ok(protobuf::ScalarValue {
+                        value: Some(Value::Decimal256Value(protobuf::Decimal256 {
+                            value: vec_val,
+                            p: *p as i64,
+                            s: *s as i64,
+                        })),
+                    })
+                }
+                None => Ok(protobuf::ScalarValue {
+                    value: Some(protobuf::scalar_value::Value::NullValue(
+                        (&data_type).try_into()?,
+                    )),
+                }),
+            },
             datafusion::scalar::ScalarValue::Date64(val) => {
                 create_proto_scalar(val.as_ref(), &data_type, |s| Value::Date64Value(*s))
             }
@@ -1169,6 +1270,48 @@ impl TryFrom<&ScalarValue> for protobuf::ScalarValue {
                 Ok(protobuf::ScalarValue { value: Some(value) })
             }
 
+            datafusion::scalar::ScalarValue::DurationSecond(v) => {
+                create_proto_scalar(v.as_ref(), &data_type, |v| {
+                    Value::DurationValue(protobuf::ScalarDurationValue {
+                        value: Some(
+                            protobuf::scalar_duration_value::Value::DurationSecondValue(
+                                *v,
+                            ),
+                        ),
+                    })
+                })
+            }
+
+            datafusion::scalar::ScalarValue::DurationMillisecond(v) => {
+                create_proto_scalar(v.as_ref(), &data_type, |v| {
+                    Value::DurationValue(protobuf::ScalarDurationValue {
+                        value: Some(
+                            protobuf::scalar_duration_value::Value::DurationMillisecondValue(*v),
+                        ),
+                    })
+                })
+            }
+
+            datafusion::scalar::ScalarValue::DurationMicrosecond(v) => {
+                create_proto_scalar(v.as_ref(), &data_type, |v| {
+                    Value::DurationValue(protobuf::ScalarDurationValue {
+                        value: Some(
+                            protobuf::scalar_duration_value::Value::DurationMicrosecondValue(*v),
+                        ),
+                    })
+                })
+            }
+
+            datafusion::scalar::ScalarValue::DurationNanosecond(v) => {
+                create_proto_scalar(v.as_ref(), &data_type, |v| {
+                    Value::DurationValue(protobuf::ScalarDurationValue {
+                        value: Some(
+                            protobuf::scalar_duration_value::Value::DurationNanosecondValue(*v),
+                        ),
+                    })
+                })
+            }
+
             datafusion::scalar::ScalarValue::Struct(values, fields) => {
                 // encode null as empty field values list
                 let field_values = if let Some(values) = values {
@@ -1278,6 +1421,7 @@ impl TryFrom<&BuiltinScalarFunction> for protobuf::ScalarFunction {
             BuiltinScalarFunction::ToHex => Self::ToHex,
             BuiltinScalarFunction::ToTimestampMicros => Self::ToTimestampMicros,
             BuiltinScalarFunction::ToTimestampSeconds => Self::ToTimestampSeconds,
+            BuiltinScalarFunction::ToUtcTimestamp => Self::ToUtcTimestamp,
             BuiltinScalarFunction::Now => Self::Now,
             BuiltinScalarFunction::CurrentDate => Self::CurrentDate,
             BuiltinScalarFunction::CurrentTime => Self::CurrentTime,
@@ -1361,6 +1505,17 @@ impl From<JoinType> for protobuf::JoinType {
     }
 }
 
+impl From<&WriteOp> for protobuf::WriteOp {
+    fn from(op: &WriteOp) -> Self {
+        match op {
+            WriteOp::Insert => protobuf::WriteOp::Insert,
+            WriteOp::Delete => protobuf::WriteOp::Delete,
+            WriteOp::Update => protobuf::WriteOp::Update,
+            WriteOp::Ctas => protobuf::WriteOp::Ctas,
+        }
+    }
+}
+
 impl From<JoinConstraint> for protobuf::JoinConstraint {
     fn from(t: JoinConstraint) -> Self {
         match t {
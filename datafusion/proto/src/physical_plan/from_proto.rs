@@ -21,17 +21,22 @@ use crate::protobuf;
 use arrow::datatypes::DataType;
 use chrono::TimeZone;
 use chrono::Utc;
-use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::datatypes::{Schema, SchemaRef};
+use datafusion::arrow::ipc::reader::StreamReader;
+use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::datasource::listing::{FileRange, PartitionedFile};
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::execution::context::ExecutionProps;
 use datafusion::execution::FunctionRegistry;
 use datafusion::logical_expr::window_function::WindowFunction;
+use datafusion::logical_expr::WindowFrame;
 use datafusion::physical_expr::expressions::DateTimeIntervalExpr;
 use datafusion::physical_expr::{PhysicalSortExpr, ScalarFunctionExpr};
 use datafusion::physical_plan::expressions::GetIndexedFieldExpr;
 use datafusion::physical_plan::expressions::LikeExpr;
-use datafusion::physical_plan::file_format::FileScanConfig;
+use datafusion::physical_plan::file_format::{FileScanConfig, SchemaEvolution};
+use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
+use datafusion::physical_plan::windows::create_window_expr;
 use datafusion::physical_plan::{
     expressions::{
         BinaryExpr, CaseExpr, CastExpr, Column, InListExpr, IsNotNullExpr, IsNullExpr,
@@ -39,7 +44,7 @@ use datafusion::physical_plan::{
     },
     functions, Partitioning,
 };
-use datafusion::physical_plan::{ColumnStatistics, PhysicalExpr, Statistics};
+use datafusion::physical_plan::{ColumnStatistics, PhysicalExpr, Statistics, WindowExpr};
 use datafusion_common::DataFusionError;
 use object_store::path::Path;
 use object_store::ObjectMeta;
@@ -338,10 +343,129 @@ impl TryFrom<&protobuf::physical_window_expr_node::WindowFunction> for WindowFun
 
                 Ok(WindowFunction::BuiltInWindowFunction(f.into()))
             }
+            protobuf::physical_window_expr_node::WindowFunction::Udaf(name) => {
+                Err(proto_error(format!(
+                    "The user defined aggregate window function {name} requires a function registry, see parse_physical_window_expr"
+                )))
+            }
         }
     }
 }
 
+/// Parses a physical sort expression from a protobuf.
+pub fn parse_physical_sort_expr(
+    proto: &protobuf::PhysicalSortExprNode,
+    registry: &dyn FunctionRegistry,
+    input_schema: &Schema,
+) -> Result<PhysicalSortExpr, DataFusionError> {
+    let expr = parse_required_physical_expr(
+        proto.expr.as_deref(),
+        registry,
+        "expr",
+        input_schema,
+    )?;
+    Ok(PhysicalSortExpr {
+        expr,
+        options: SortOptions {
+            descending: !proto.asc,
+            nulls_first: proto.nulls_first,
+        },
+    })
+}
+
+/// Parses a physical window expression from a protobuf.
+///
+/// # Arguments
+///
+/// * `proto` - Input proto with physical window expression node
+/// * `name` - Name of the window expression
+/// * `registry` - A registry knows how to build logical expressions out of user-defined function' names
+/// * `input_schema` - The Arrow schema for the input, used for determining expression data types
+///                    when performing type coercion.
+pub fn parse_physical_window_expr(
+    proto: &protobuf::PhysicalWindowExprNode,
+    name: &str,
+    registry: &dyn FunctionRegistry,
+    input_schema: &Schema,
+) -> Result<Arc<dyn WindowExpr>, DataFusionError> {
+    let window_function = match &proto.window_function {
+        Some(protobuf::physical_window_expr_node::WindowFunction::Udaf(name)) => {
+            WindowFunction::AggregateUDF(registry.udaf(name)?)
+        }
+        _ => convert_required!(proto.window_function)?,
+    };
+    let args = proto
+        .args
+        .iter()
+        .map(|e| parse_physical_expr(e, registry, input_schema))
+        .collect::<Result<Vec<_>, _>>()?;
+    let partition_by = proto
+        .partition_by
+        .iter()
+        .map(|e| parse_physical_expr(e, registry, input_schema))
+        .collect::<Result<Vec<_>, _>>()?;
+    let order_by = proto
+        .order_by
+        .iter()
+        .map(|e| parse_physical_sort_expr(e, registry, input_schema))
+        .collect::<Result<Vec<_>, _>>()?;
+    let window_frame = match &proto.window_frame {
+        Some(window_frame) => WindowFrame::try_from(window_frame.clone())?,
+        None => WindowFrame::new(!order_by.is_empty()),
+    };
+
+    create_window_expr(
+        &window_function,
+        name.to_owned(),
+        &args,
+        &partition_by,
+        &order_by,
+        Arc::new(window_frame),
+        input_schema,
+    )
+}
+
+/// Parses a join filter from a protobuf.
+pub fn parse_join_filter(
+    proto: &protobuf::JoinFilter,
+    registry: &dyn FunctionRegistry,
+) -> Result<JoinFilter, DataFusionError> {
+    let schema: Schema = convert_required!(proto.schema)?;
+    let expression = parse_required_physical_expr(
+        proto.expression.as_ref(),
+        registry,
+        "expression",
+        &schema,
+    )?;
+    let column_indices = proto
+        .column_indices
+        .iter()
+        .map(|i| {
+            let side = protobuf::JoinSide::from_i32(i.side).ok_or_else(|| {
+                proto_error(format!(
+                    "Received a JoinFilter message with unknown JoinSide {}",
+                    i.side
+                ))
+            })?;
+            Ok(ColumnIndex {
+                index: i.index as usize,
+                side: side.into(),
+            })
+        })
+        .collect::<Result<Vec<_>, DataFusionError>>()?;
+    Ok(JoinFilter::new(expression, column_indices, schema))
+}
+
+/// Deserializes the schema and record batches of an Arrow IPC stream
+pub fn ipc_bytes_to_batches(
+    bytes: &[u8],
+) -> Result<(SchemaRef, Vec<RecordBatch>), DataFusionError> {
+    let reader = StreamReader::try_new(bytes, None)?;
+    let schema = reader.schema();
+    let batches = reader.collect::<Result<Vec<_>, _>>()?;
+    Ok((schema, batches))
+}
+
 pub fn parse_protobuf_hash_partitioning(
     partitioning: Option<&protobuf::PhysicalHashRepartition>,
     registry: &dyn FunctionRegistry,
@@ -427,6 +551,13 @@ pub fn parse_protobuf_file_scan_config(
     } else {
         Some(output_ordering)
     };
+    let schema_evolution = protobuf::SchemaEvolution::from_i32(proto.schema_evolution)
+        .ok_or_else(|| {
+            proto_error(format!(
+                "Received a FileScanExecConf message with unknown SchemaEvolution {}",
+                proto.schema_evolution
+            ))
+        })?;
 
     Ok(FileScanConfig {
         object_store_url,
@@ -434,13 +565,30 @@ pub fn parse_protobuf_file_scan_config(
         file_groups,
         statistics,
         projection,
+        nested_projection: proto.nested_projection.as_ref().map(|nested_projection| {
+            nested_projection
+                .paths
+                .iter()
+                .map(|path| path.names.clone())
+                .collect()
+        }),
         limit: proto.limit.as_ref().map(|sl| sl.limit as usize),
         table_partition_cols,
         output_ordering,
-        infinite_source: false,
+        infinite_source: proto.infinite_source,
+        schema_evolution: schema_evolution.into(),
     })
 }
 
+impl From<protobuf::SchemaEvolution> for SchemaEvolution {
+    fn from(schema_evolution: protobuf::SchemaEvolution) -> Self {
+        match schema_evolution {
+            protobuf::SchemaEvolution::Strict => SchemaEvolution::Strict,
+            protobuf::SchemaEvolution::Merge => SchemaEvolution::Merge,
+        }
+    }
+}
+
 impl TryFrom<&protobuf::PartitionedFile> for PartitionedFile {
     type Error = DataFusionError;
 
@@ -533,3 +681,12 @@ impl TryFrom<&protobuf::Statistics> for Statistics {
         })
     }
 }
+
+impl From<&protobuf::PhysicalSortOptions> for SortOptions {
+    fn from(options: &protobuf::PhysicalSortOptions) -> Self {
+        SortOptions {
+            descending: !options.asc,
+            nulls_first: options.nulls_first,
+        }
+    }
+}
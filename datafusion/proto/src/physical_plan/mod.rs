@@ -17,6 +17,7 @@
 
 use std::convert::TryInto;
 use std::fmt::Debug;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use datafusion::arrow::compute::SortOptions;
@@ -24,26 +25,33 @@ use datafusion::arrow::datatypes::SchemaRef;
 use datafusion::datasource::file_format::file_type::FileCompressionType;
 use datafusion::execution::runtime_env::RuntimeEnv;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::WindowFrame;
 use datafusion::physical_plan::aggregates::{create_aggregate_expr, AggregateMode};
 use datafusion::physical_plan::aggregates::{AggregateExec, PhysicalGroupBy};
+use datafusion::physical_plan::analyze::AnalyzeExec;
 use datafusion::physical_plan::coalesce_batches::CoalesceBatchesExec;
 use datafusion::physical_plan::coalesce_partitions::CoalescePartitionsExec;
 use datafusion::physical_plan::empty::EmptyExec;
 use datafusion::physical_plan::explain::ExplainExec;
 use datafusion::physical_plan::expressions::{Column, PhysicalSortExpr};
-use datafusion::physical_plan::file_format::{AvroExec, CsvExec, ParquetExec};
+use datafusion::physical_plan::file_format::{
+    AvroExec, CsvExec, NdJsonExec, ParquetExec,
+};
 use datafusion::physical_plan::filter::FilterExec;
-use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
 use datafusion::physical_plan::joins::CrossJoinExec;
 use datafusion::physical_plan::joins::{HashJoinExec, PartitionMode};
+use datafusion::physical_plan::joins::{
+    NestedLoopJoinExec, SortMergeJoinExec, SymmetricHashJoinExec,
+};
 use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
+use datafusion::physical_plan::memory::MemoryExec;
 use datafusion::physical_plan::projection::ProjectionExec;
 use datafusion::physical_plan::repartition::RepartitionExec;
 use datafusion::physical_plan::sorts::sort::SortExec;
 use datafusion::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
 use datafusion::physical_plan::union::UnionExec;
-use datafusion::physical_plan::windows::{create_window_expr, WindowAggExec};
+use datafusion::physical_plan::unnest::UnnestExec;
+use datafusion::physical_plan::values::ValuesExec;
+use datafusion::physical_plan::windows::{BoundedWindowAggExec, WindowAggExec};
 use datafusion::physical_plan::{
     AggregateExpr, ExecutionPlan, Partitioning, PhysicalExpr, WindowExpr,
 };
@@ -54,8 +62,11 @@ use prost::Message;
 use crate::common::proto_error;
 use crate::common::{csv_delimiter_to_string, str_to_byte};
 use crate::physical_plan::from_proto::{
-    parse_physical_expr, parse_protobuf_file_scan_config,
+    ipc_bytes_to_batches, parse_join_filter, parse_physical_expr,
+    parse_physical_sort_expr, parse_physical_window_expr,
+    parse_protobuf_file_scan_config,
 };
+use crate::physical_plan::to_proto::batches_to_ipc_bytes;
 use crate::protobuf::physical_expr_node::ExprType;
 use crate::protobuf::physical_plan_node::PhysicalPlanType;
 use crate::protobuf::repartition_exec_node::PartitionMethod;
@@ -178,6 +189,13 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     registry,
                 )?)))
             }
+            PhysicalPlanType::JsonScan(scan) => Ok(Arc::new(NdJsonExec::new(
+                parse_protobuf_file_scan_config(
+                    scan.base_conf.as_ref().unwrap(),
+                    registry,
+                )?,
+                FileCompressionType::from_str(&scan.file_compression_type)?,
+            ))),
             PhysicalPlanType::CoalesceBatches(coalesce_batches) => {
                 let input: Arc<dyn ExecutionPlan> = into_physical_plan!(
                     coalesce_batches.input,
@@ -260,7 +278,8 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     into_physical_plan!(limit.input, registry, runtime, extension_codec)?;
                 Ok(Arc::new(LocalLimitExec::new(input, limit.fetch as usize)))
             }
-            PhysicalPlanType::Window(window_agg) => {
+            PhysicalPlanType::Window(window_agg)
+            | PhysicalPlanType::BoundedWindow(window_agg) => {
                 let input: Arc<dyn ExecutionPlan> = into_physical_plan!(
                     window_agg.input,
                     registry,
@@ -290,33 +309,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
 
                         match expr_type {
                             ExprType::WindowExpr(window_node) => {
-                                let window_node_expr = window_node
-                                    .expr
-                                    .as_ref()
-                                    .map(|e| {
-                                        parse_physical_expr(
-                                            e.as_ref(),
-                                            registry,
-                                            &physical_schema,
-                                        )
-                                    })
-                                    .transpose()?
-                                    .ok_or_else(|| {
-                                        proto_error(
-                                            "missing window_node expr expression"
-                                                .to_string(),
-                                        )
-                                    })?;
-
-                                Ok(create_window_expr(
-                                    &convert_required!(window_node.window_function)?,
-                                    name.to_owned(),
-                                    &[window_node_expr],
-                                    &[],
-                                    &[],
-                                    Arc::new(WindowFrame::new(false)),
+                                parse_physical_window_expr(
+                                    window_node,
+                                    name,
+                                    registry,
                                     &physical_schema,
-                                )?)
+                                )
                             }
                             _ => Err(DataFusionError::Internal(
                                 "Invalid expression for WindowAggrExec".to_string(),
@@ -324,14 +322,43 @@ impl AsExecutionPlan for PhysicalPlanNode {
                         }
                     })
                     .collect::<Result<Vec<_>, _>>()?;
-                //todo fill partition keys and sort keys
-                Ok(Arc::new(WindowAggExec::try_new(
-                    physical_window_expr,
-                    input,
-                    Arc::new((&input_schema).try_into()?),
-                    vec![],
-                    None,
-                )?))
+
+                let partition_keys = window_agg
+                    .partition_keys
+                    .iter()
+                    .map(|expr| parse_physical_expr(expr, registry, &physical_schema))
+                    .collect::<Result<Vec<Arc<dyn PhysicalExpr>>, _>>()?;
+                let sort_keys = window_agg
+                    .sort_keys
+                    .as_ref()
+                    .map(|sort_keys| {
+                        sort_keys
+                            .physical_sort_expr_nodes
+                            .iter()
+                            .map(|expr| {
+                                parse_physical_sort_expr(expr, registry, &physical_schema)
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .transpose()?;
+
+                if matches!(plan, PhysicalPlanType::BoundedWindow(_)) {
+                    Ok(Arc::new(BoundedWindowAggExec::try_new(
+                        physical_window_expr,
+                        input,
+                        physical_schema,
+                        partition_keys,
+                        sort_keys,
+                    )?))
+                } else {
+                    Ok(Arc::new(WindowAggExec::try_new(
+                        physical_window_expr,
+                        input,
+                        physical_schema,
+                        partition_keys,
+                        sort_keys,
+                    )?))
+                }
             }
             PhysicalPlanType::Aggregate(hash_agg) => {
                 let input: Arc<dyn ExecutionPlan> = into_physical_plan!(
@@ -483,38 +510,8 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 let filter = hashjoin
                     .filter
                     .as_ref()
-                    .map(|f| {
-                        let schema = f
-                            .schema
-                            .as_ref()
-                            .ok_or_else(|| proto_error("Missing JoinFilter schema"))?
-                            .try_into()?;
-
-                        let expression = parse_physical_expr(
-                            f.expression.as_ref().ok_or_else(|| {
-                                proto_error("Unexpected empty filter expression")
-                            })?,
-                            registry, &schema
-                        )?;
-                        let column_indices = f.column_indices
-                            .iter()
-                            .map(|i| {
-                                let side = protobuf::JoinSide::from_i32(i.side)
-                                    .ok_or_else(|| proto_error(format!(
-                                        "Received a HashJoinNode message with JoinSide in Filter {}",
-                                        i.side))
-                                    )?;
-
-                                Ok(ColumnIndex{
-                                    index: i.index as usize,
-                                    side: side.into(),
-                                })
-                            })
-                            .collect::<Result<Vec<_>, DataFusionError>>()?;
-
-                        Ok(JoinFilter::new(expression, column_indices, schema))
-                    })
-                    .map_or(Ok(None), |v: Result<JoinFilter, DataFusionError>| v.map(Some))?;
+                    .map(|f| parse_join_filter(f, registry))
+                    .transpose()?;
 
                 let partition_mode =
                     protobuf::PartitionMode::from_i32(hashjoin.partition_mode)
@@ -565,10 +562,187 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 )?;
                 Ok(Arc::new(CrossJoinExec::new(left, right)))
             }
+            PhysicalPlanType::SortMergeJoin(sort_merge_join) => {
+                let left: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    sort_merge_join.left,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let right: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    sort_merge_join.right,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let on: Vec<(Column, Column)> = sort_merge_join
+                    .on
+                    .iter()
+                    .map(|col| {
+                        let left = into_required!(col.left)?;
+                        let right = into_required!(col.right)?;
+                        Ok((left, right))
+                    })
+                    .collect::<Result<_, DataFusionError>>()?;
+                let join_type = protobuf::JoinType::from_i32(sort_merge_join.join_type)
+                    .ok_or_else(|| {
+                    proto_error(format!(
+                        "Received a SortMergeJoinNode message with unknown JoinType {}",
+                        sort_merge_join.join_type
+                    ))
+                })?;
+                let sort_options: Vec<SortOptions> = sort_merge_join
+                    .sort_options
+                    .iter()
+                    .map(|options| options.into())
+                    .collect();
+                Ok(Arc::new(SortMergeJoinExec::try_new(
+                    left,
+                    right,
+                    on,
+                    join_type.into(),
+                    sort_options,
+                    sort_merge_join.null_equals_null,
+                )?))
+            }
+            PhysicalPlanType::NestedLoopJoin(nested_loop_join) => {
+                let left: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    nested_loop_join.left,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let right: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    nested_loop_join.right,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let join_type = protobuf::JoinType::from_i32(nested_loop_join.join_type)
+                    .ok_or_else(|| {
+                        proto_error(format!(
+                            "Received a NestedLoopJoinNode message with unknown JoinType {}",
+                            nested_loop_join.join_type
+                        ))
+                    })?;
+                let filter = nested_loop_join
+                    .filter
+                    .as_ref()
+                    .map(|f| parse_join_filter(f, registry))
+                    .transpose()?;
+                Ok(Arc::new(NestedLoopJoinExec::try_new(
+                    left,
+                    right,
+                    filter,
+                    &join_type.into(),
+                )?))
+            }
+            PhysicalPlanType::SymmetricHashJoin(sym_join) => {
+                let left: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    sym_join.left,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let right: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    sym_join.right,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let on: Vec<(Column, Column)> = sym_join
+                    .on
+                    .iter()
+                    .map(|col| {
+                        let left = into_required!(col.left)?;
+                        let right = into_required!(col.right)?;
+                        Ok((left, right))
+                    })
+                    .collect::<Result<_, DataFusionError>>()?;
+                let join_type = protobuf::JoinType::from_i32(sym_join.join_type)
+                    .ok_or_else(|| {
+                        proto_error(format!(
+                            "Received a SymmetricHashJoinNode message with unknown JoinType {}",
+                            sym_join.join_type
+                        ))
+                    })?;
+                let filter = sym_join
+                    .filter
+                    .as_ref()
+                    .map(|f| parse_join_filter(f, registry))
+                    .transpose()?
+                    .ok_or_else(|| {
+                        proto_error("Missing filter in SymmetricHashJoinNode")
+                    })?;
+                Ok(Arc::new(SymmetricHashJoinExec::try_new(
+                    left,
+                    right,
+                    on,
+                    filter,
+                    &join_type.into(),
+                    sym_join.null_equals_null,
+                )?))
+            }
             PhysicalPlanType::Empty(empty) => {
                 let schema = Arc::new(convert_required!(empty.schema)?);
                 Ok(Arc::new(EmptyExec::new(empty.produce_one_row, schema)))
             }
+            PhysicalPlanType::Unnest(unnest) => {
+                let input: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    unnest.input,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let column: Column = into_required!(unnest.column)?;
+                let schema = Arc::new(convert_required!(unnest.schema)?);
+                Ok(Arc::new(UnnestExec::new(input, column, schema)))
+            }
+            PhysicalPlanType::Values(values) => {
+                let (schema, batches) = ipc_bytes_to_batches(&values.data)?;
+                Ok(Arc::new(ValuesExec::try_new_from_batches(schema, batches)?))
+            }
+            PhysicalPlanType::Analyze(analyze) => {
+                let input: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    analyze.input,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let schema = Arc::new(convert_required!(analyze.schema)?);
+                Ok(Arc::new(AnalyzeExec::new(analyze.verbose, input, schema)))
+            }
+            PhysicalPlanType::Memory(memory) => {
+                let schema: SchemaRef = Arc::new(convert_required!(memory.schema)?);
+                let partitions = memory
+                    .partitions
+                    .iter()
+                    .map(|bytes| ipc_bytes_to_batches(bytes).map(|(_, batches)| batches))
+                    .collect::<Result<Vec<_>, DataFusionError>>()?;
+                let projection = memory.projection.as_ref().map(|projection| {
+                    projection.columns.iter().map(|i| *i as usize).collect()
+                });
+                let exec = MemoryExec::try_new(&partitions, schema, projection)?;
+                // the sort information refers to the projected schema
+                match &memory.sort_information {
+                    Some(sort_information) => {
+                        let projected_schema = exec.schema();
+                        let sort_information = sort_information
+                            .physical_sort_expr_nodes
+                            .iter()
+                            .map(|expr| {
+                                parse_physical_sort_expr(
+                                    expr,
+                                    registry,
+                                    projected_schema.as_ref(),
+                                )
+                            })
+                            .collect::<Result<Vec<_>, _>>()?;
+                        Ok(Arc::new(exec.with_sort_information(sort_information)))
+                    }
+                    None => Ok(Arc::new(exec)),
+                }
+            }
             PhysicalPlanType::Sort(sort) => {
                 let input: Arc<dyn ExecutionPlan> =
                     into_physical_plan!(sort.input, registry, runtime, extension_codec)?;
@@ -785,33 +959,7 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 })
                 .collect();
             let join_type: protobuf::JoinType = exec.join_type().to_owned().into();
-            let filter = exec
-                .filter()
-                .as_ref()
-                .map(|f| {
-                    let expression = f.expression().to_owned().try_into()?;
-                    let column_indices = f
-                        .column_indices()
-                        .iter()
-                        .map(|i| {
-                            let side: protobuf::JoinSide = i.side.to_owned().into();
-                            protobuf::ColumnIndex {
-                                index: i.index as u32,
-                                side: side.into(),
-                            }
-                        })
-                        .collect();
-                    let schema = f.schema().try_into()?;
-                    Ok(protobuf::JoinFilter {
-                        expression: Some(expression),
-                        column_indices,
-                        schema: Some(schema),
-                    })
-                })
-                .map_or(
-                    Ok(None),
-                    |v: Result<protobuf::JoinFilter, DataFusionError>| v.map(Some),
-                )?;
+            let filter = exec.filter().as_ref().map(|f| f.try_into()).transpose()?;
 
             let partition_mode = match exec.partition_mode() {
                 PartitionMode::CollectLeft => protobuf::PartitionMode::CollectLeft,
@@ -1096,6 +1244,275 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     }),
                 )),
             })
+        } else if let Some(exec) = plan.downcast_ref::<WindowAggExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            let window_expr = exec
+                .window_expr()
+                .iter()
+                .map(|e| e.clone().try_into())
+                .collect::<Result<Vec<protobuf::PhysicalExprNode>, DataFusionError>>(
+            )?;
+            let window_expr_name = exec
+                .window_expr()
+                .iter()
+                .map(|e| e.name().to_string())
+                .collect();
+            let partition_keys = exec
+                .partition_keys
+                .iter()
+                .map(|e| e.clone().try_into())
+                .collect::<Result<Vec<protobuf::PhysicalExprNode>, DataFusionError>>()?;
+            let sort_keys = exec
+                .sort_keys
+                .as_ref()
+                .map(|sort_keys| {
+                    Ok::<_, DataFusionError>(protobuf::PhysicalSortExprNodeCollection {
+                        physical_sort_expr_nodes: sort_keys
+                            .iter()
+                            .map(|e| e.try_into())
+                            .collect::<Result<Vec<_>, DataFusionError>>()?,
+                    })
+                })
+                .transpose()?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Window(Box::new(
+                    protobuf::WindowAggExecNode {
+                        input: Some(Box::new(input)),
+                        window_expr,
+                        window_expr_name,
+                        input_schema: Some(exec.input_schema().as_ref().try_into()?),
+                        partition_keys,
+                        sort_keys,
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<BoundedWindowAggExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            let window_expr = exec
+                .window_expr()
+                .iter()
+                .map(|e| e.clone().try_into())
+                .collect::<Result<Vec<protobuf::PhysicalExprNode>, DataFusionError>>(
+            )?;
+            let window_expr_name = exec
+                .window_expr()
+                .iter()
+                .map(|e| e.name().to_string())
+                .collect();
+            let partition_keys = exec
+                .partition_keys
+                .iter()
+                .map(|e| e.clone().try_into())
+                .collect::<Result<Vec<protobuf::PhysicalExprNode>, DataFusionError>>()?;
+            let sort_keys = exec
+                .sort_keys
+                .as_ref()
+                .map(|sort_keys| {
+                    Ok::<_, DataFusionError>(protobuf::PhysicalSortExprNodeCollection {
+                        physical_sort_expr_nodes: sort_keys
+                            .iter()
+                            .map(|e| e.try_into())
+                            .collect::<Result<Vec<_>, DataFusionError>>()?,
+                    })
+                })
+                .transpose()?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::BoundedWindow(Box::new(
+                    protobuf::WindowAggExecNode {
+                        input: Some(Box::new(input)),
+                        window_expr,
+                        window_expr_name,
+                        input_schema: Some(exec.input_schema().as_ref().try_into()?),
+                        partition_keys,
+                        sort_keys,
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<SortMergeJoinExec>() {
+            let left = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.left().to_owned(),
+                extension_codec,
+            )?;
+            let right = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.right().to_owned(),
+                extension_codec,
+            )?;
+            let on: Vec<protobuf::JoinOn> = exec
+                .on()
+                .iter()
+                .map(|tuple| protobuf::JoinOn {
+                    left: Some(protobuf::PhysicalColumn {
+                        name: tuple.0.name().to_string(),
+                        index: tuple.0.index() as u32,
+                    }),
+                    right: Some(protobuf::PhysicalColumn {
+                        name: tuple.1.name().to_string(),
+                        index: tuple.1.index() as u32,
+                    }),
+                })
+                .collect();
+            let join_type: protobuf::JoinType = exec.join_type().into();
+            let sort_options = exec.sort_options().iter().map(|o| o.into()).collect();
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::SortMergeJoin(Box::new(
+                    protobuf::SortMergeJoinExecNode {
+                        left: Some(Box::new(left)),
+                        right: Some(Box::new(right)),
+                        on,
+                        join_type: join_type.into(),
+                        sort_options,
+                        null_equals_null: exec.null_equals_null(),
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<NestedLoopJoinExec>() {
+            let left = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.left().to_owned(),
+                extension_codec,
+            )?;
+            let right = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.right().to_owned(),
+                extension_codec,
+            )?;
+            let join_type: protobuf::JoinType = exec.join_type().to_owned().into();
+            let filter = exec.filter().map(|f| f.try_into()).transpose()?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::NestedLoopJoin(Box::new(
+                    protobuf::NestedLoopJoinExecNode {
+                        left: Some(Box::new(left)),
+                        right: Some(Box::new(right)),
+                        join_type: join_type.into(),
+                        filter,
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<SymmetricHashJoinExec>() {
+            let left = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.left().to_owned(),
+                extension_codec,
+            )?;
+            let right = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.right().to_owned(),
+                extension_codec,
+            )?;
+            let on: Vec<protobuf::JoinOn> = exec
+                .on()
+                .iter()
+                .map(|tuple| protobuf::JoinOn {
+                    left: Some(protobuf::PhysicalColumn {
+                        name: tuple.0.name().to_string(),
+                        index: tuple.0.index() as u32,
+                    }),
+                    right: Some(protobuf::PhysicalColumn {
+                        name: tuple.1.name().to_string(),
+                        index: tuple.1.index() as u32,
+                    }),
+                })
+                .collect();
+            let join_type: protobuf::JoinType = exec.join_type().to_owned().into();
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::SymmetricHashJoin(Box::new(
+                    protobuf::SymmetricHashJoinExecNode {
+                        left: Some(Box::new(left)),
+                        right: Some(Box::new(right)),
+                        on,
+                        join_type: join_type.into(),
+                        null_equals_null: exec.null_equals_null(),
+                        filter: Some(exec.filter().try_into()?),
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<NdJsonExec>() {
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::JsonScan(
+                    protobuf::JsonScanExecNode {
+                        base_conf: Some(exec.base_config().try_into()?),
+                        file_compression_type: exec
+                            .file_compression_type()
+                            .get_variant()
+                            .to_string(),
+                    },
+                )),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<UnnestExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Unnest(Box::new(
+                    protobuf::UnnestExecNode {
+                        input: Some(Box::new(input)),
+                        column: Some(protobuf::PhysicalColumn {
+                            name: exec.column().name().to_string(),
+                            index: exec.column().index() as u32,
+                        }),
+                        schema: Some(exec.schema().as_ref().try_into()?),
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<ValuesExec>() {
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Values(
+                    protobuf::ValuesExecNode {
+                        data: batches_to_ipc_bytes(exec.schema().as_ref(), &exec.data())?,
+                    },
+                )),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<AnalyzeExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Analyze(Box::new(
+                    protobuf::AnalyzeExecNode {
+                        verbose: exec.verbose(),
+                        input: Some(Box::new(input)),
+                        schema: Some(exec.schema().as_ref().try_into()?),
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<MemoryExec>() {
+            let schema = exec.original_schema();
+            let partitions = exec
+                .partitions()
+                .iter()
+                .map(|batches| batches_to_ipc_bytes(schema.as_ref(), batches))
+                .collect::<Result<Vec<_>, DataFusionError>>()?;
+            let projection =
+                exec.projection()
+                    .as_ref()
+                    .map(|projection| protobuf::MemoryProjection {
+                        columns: projection.iter().map(|i| *i as u32).collect(),
+                    });
+            let sort_information = exec
+                .output_ordering()
+                .map(|sort_information| {
+                    Ok::<_, DataFusionError>(protobuf::PhysicalSortExprNodeCollection {
+                        physical_sort_expr_nodes: sort_information
+                            .iter()
+                            .map(|e| e.try_into())
+                            .collect::<Result<Vec<_>, DataFusionError>>()?,
+                    })
+                })
+                .transpose()?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Memory(
+                    protobuf::MemoryExecNode {
+                        schema: Some(schema.as_ref().try_into()?),
+                        partitions,
+                        projection,
+                        sort_information,
+                    },
+                )),
+            })
         } else {
             let mut buf: Vec<u8> = vec![];
             match extension_codec.try_encode(plan_clone.clone(), &mut buf) {
@@ -1213,17 +1630,35 @@ mod roundtrip_tests {
     use crate::physical_plan::{AsExecutionPlan, DefaultPhysicalExtensionCodec};
     use datafusion::arrow::array::ArrayRef;
     use datafusion::arrow::datatypes::IntervalUnit;
+    use datafusion::datasource::file_format::file_type::FileCompressionType;
     use datafusion::datasource::object_store::ObjectStoreUrl;
     use datafusion::execution::context::ExecutionProps;
-    use datafusion::logical_expr::create_udf;
+    use datafusion::logical_expr::{create_udaf, create_udf};
+    use datafusion::logical_expr::{
+        AggregateFunction, BuiltInWindowFunction, WindowFrame, WindowFrameBound,
+        WindowFrameUnits, WindowFunction,
+    };
     use datafusion::logical_expr::{BuiltinScalarFunction, Volatility};
     use datafusion::physical_expr::expressions::DateTimeIntervalExpr;
     use datafusion::physical_expr::ScalarFunctionExpr;
     use datafusion::physical_plan::aggregates::PhysicalGroupBy;
+    use datafusion::physical_plan::analyze::AnalyzeExec;
     use datafusion::physical_plan::expressions::{like, BinaryExpr, GetIndexedFieldExpr};
+    use datafusion::physical_plan::file_format::NdJsonExec;
     use datafusion::physical_plan::functions;
     use datafusion::physical_plan::functions::make_scalar_function;
+    use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter, JoinSide};
+    use datafusion::physical_plan::joins::{
+        NestedLoopJoinExec, SortMergeJoinExec, SymmetricHashJoinExec,
+    };
+    use datafusion::physical_plan::memory::MemoryExec;
     use datafusion::physical_plan::projection::ProjectionExec;
+    use datafusion::physical_plan::unnest::UnnestExec;
+    use datafusion::physical_plan::values::ValuesExec;
+    use datafusion::physical_plan::windows::{
+        create_window_expr, BoundedWindowAggExec, WindowAggExec,
+    };
+    use datafusion::physical_plan::WindowExpr;
     use datafusion::{
         arrow::{
             compute::kernels::sort::SortOptions,
@@ -1236,7 +1671,7 @@ mod roundtrip_tests {
             empty::EmptyExec,
             expressions::{binary, col, lit, InListExpr, NotExpr},
             expressions::{Avg, Column, DistinctCount, PhysicalSortExpr},
-            file_format::{FileScanConfig, ParquetExec},
+            file_format::{FileScanConfig, ParquetExec, SchemaEvolution},
             filter::FilterExec,
             joins::{HashJoinExec, PartitionMode},
             limit::{GlobalLimitExec, LocalLimitExec},
@@ -1506,10 +1941,12 @@ mod roundtrip_tests {
                 is_exact: false,
             },
             projection: None,
+            nested_projection: None,
             limit: None,
             table_partition_cols: vec![],
             output_ordering: None,
             infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
         };
 
         let predicate = Arc::new(BinaryExpr::new(
@@ -1524,6 +1961,36 @@ mod roundtrip_tests {
         )))
     }
 
+    #[test]
+    fn roundtrip_parquet_exec_with_scan_options() -> Result<()> {
+        let struct_type = DataType::Struct(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let scan_config = FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            file_schema: Arc::new(Schema::new(vec![Field::new("s", struct_type, true)])),
+            file_groups: vec![vec![PartitionedFile::new(
+                "/path/to/file.parquet".to_string(),
+                1024,
+            )]],
+            statistics: Statistics {
+                num_rows: Some(100),
+                total_byte_size: Some(1024),
+                column_statistics: None,
+                is_exact: false,
+            },
+            projection: Some(vec![0]),
+            nested_projection: Some(vec![vec!["s".to_string(), "a".to_string()]]),
+            limit: None,
+            table_partition_cols: vec![],
+            output_ordering: None,
+            infinite_source: true,
+            schema_evolution: SchemaEvolution::Merge,
+        };
+        roundtrip_test(Arc::new(ParquetExec::new(scan_config, None, None)))
+    }
+
     #[test]
     fn roundtrip_builtin_scalar_function() -> Result<()> {
         let field_a = Field::new("a", DataType::Int64, false);
@@ -1659,4 +2126,467 @@ mod roundtrip_tests {
 
         roundtrip_test(plan)
     }
+
+    fn window_exprs(schema: &Schema) -> Result<Vec<Arc<dyn WindowExpr>>> {
+        let partition_by = vec![col("a", schema)?];
+        let order_by = vec![PhysicalSortExpr {
+            expr: col("b", schema)?,
+            options: SortOptions::default(),
+        }];
+        let default_frame = Arc::new(WindowFrame::new(true));
+        let sliding_frame = Arc::new(WindowFrame {
+            units: WindowFrameUnits::Rows,
+            start_bound: WindowFrameBound::Preceding(ScalarValue::UInt64(Some(1))),
+            end_bound: WindowFrameBound::CurrentRow,
+        });
+        let window_expr = |fun: WindowFunction,
+                           name: &str,
+                           args: Vec<Arc<dyn PhysicalExpr>>,
+                           window_frame: &Arc<WindowFrame>| {
+            create_window_expr(
+                &fun,
+                name.to_string(),
+                &args,
+                &partition_by,
+                &order_by,
+                window_frame.clone(),
+                schema,
+            )
+        };
+
+        Ok(vec![
+            window_expr(
+                WindowFunction::BuiltInWindowFunction(BuiltInWindowFunction::RowNumber),
+                "ROW_NUMBER()",
+                vec![],
+                &default_frame,
+            )?,
+            window_expr(
+                WindowFunction::BuiltInWindowFunction(BuiltInWindowFunction::Ntile),
+                "NTILE(3)",
+                vec![lit(ScalarValue::Int64(Some(3)))],
+                &default_frame,
+            )?,
+            window_expr(
+                WindowFunction::BuiltInWindowFunction(BuiltInWindowFunction::Lag),
+                "LAG(b, 2, 0)",
+                vec![
+                    col("b", schema)?,
+                    lit(ScalarValue::Int64(Some(2))),
+                    lit(ScalarValue::Int64(Some(0))),
+                ],
+                &default_frame,
+            )?,
+            window_expr(
+                WindowFunction::BuiltInWindowFunction(BuiltInWindowFunction::Lead),
+                "LEAD(b)",
+                vec![col("b", schema)?],
+                &default_frame,
+            )?,
+            window_expr(
+                WindowFunction::BuiltInWindowFunction(BuiltInWindowFunction::NthValue),
+                "NTH_VALUE(b, 2)",
+                vec![col("b", schema)?, lit(ScalarValue::Int64(Some(2)))],
+                &default_frame,
+            )?,
+            window_expr(
+                WindowFunction::AggregateFunction(AggregateFunction::Avg),
+                "AVG(b)",
+                vec![col("b", schema)?],
+                &default_frame,
+            )?,
+            window_expr(
+                WindowFunction::AggregateFunction(AggregateFunction::Sum),
+                "SUM(b)",
+                vec![col("b", schema)?],
+                &sliding_frame,
+            )?,
+        ])
+    }
+
+    #[test]
+    fn roundtrip_window() -> Result<()> {
+        let field_a = Field::new("a", DataType::Int64, false);
+        let field_b = Field::new("b", DataType::Int64, false);
+        let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+
+        roundtrip_test(Arc::new(WindowAggExec::try_new(
+            window_exprs(&schema)?,
+            Arc::new(EmptyExec::new(false, schema.clone())),
+            schema.clone(),
+            vec![col("a", &schema)?],
+            Some(vec![PhysicalSortExpr {
+                expr: col("a", &schema)?,
+                options: SortOptions::default(),
+            }]),
+        )?))?;
+
+        roundtrip_test(Arc::new(WindowAggExec::try_new(
+            window_exprs(&schema)?,
+            Arc::new(EmptyExec::new(false, schema.clone())),
+            schema,
+            vec![],
+            None,
+        )?))
+    }
+
+    #[test]
+    fn roundtrip_udaf_window() -> Result<()> {
+        let schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Float64, false)]));
+        // the accumulators are not created by the roundtrip
+        let my_sum = create_udaf(
+            "my_sum",
+            DataType::Float64,
+            Arc::new(DataType::Float64),
+            Volatility::Immutable,
+            Arc::new(|_: &DataType| {
+                Err(datafusion_common::DataFusionError::NotImplemented(
+                    "my_sum accumulator".to_string(),
+                ))
+            }),
+            Arc::new(vec![DataType::Float64]),
+        );
+        let window_expr = create_window_expr(
+            &WindowFunction::AggregateUDF(Arc::new(my_sum.clone())),
+            "my_sum(a)".to_string(),
+            &[col("a", &schema)?],
+            &[],
+            &[],
+            Arc::new(WindowFrame::new(false)),
+            &schema,
+        )?;
+
+        let ctx = SessionContext::new();
+        ctx.register_udaf(my_sum);
+        roundtrip_test_with_context(
+            Arc::new(WindowAggExec::try_new(
+                vec![window_expr],
+                Arc::new(EmptyExec::new(false, schema.clone())),
+                schema,
+                vec![],
+                None,
+            )?),
+            ctx,
+        )
+    }
+
+    #[test]
+    fn roundtrip_bounded_window() -> Result<()> {
+        let field_a = Field::new("a", DataType::Int64, false);
+        let field_b = Field::new("b", DataType::Int64, false);
+        let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+
+        roundtrip_test(Arc::new(BoundedWindowAggExec::try_new(
+            window_exprs(&schema)?,
+            Arc::new(EmptyExec::new(false, schema.clone())),
+            schema.clone(),
+            vec![col("a", &schema)?],
+            Some(vec![
+                PhysicalSortExpr {
+                    expr: col("a", &schema)?,
+                    options: SortOptions::default(),
+                },
+                PhysicalSortExpr {
+                    expr: col("b", &schema)?,
+                    options: SortOptions::default(),
+                },
+            ]),
+        )?))
+    }
+
+    #[test]
+    fn roundtrip_sort_merge_join() -> Result<()> {
+        let field_a = Field::new("col", DataType::Int64, false);
+        let schema_left = Schema::new(vec![field_a.clone()]);
+        let schema_right = Schema::new(vec![field_a]);
+        let on = vec![(
+            Column::new("col", schema_left.index_of("col")?),
+            Column::new("col", schema_right.index_of("col")?),
+        )];
+
+        let schema_left = Arc::new(schema_left);
+        let schema_right = Arc::new(schema_right);
+        for join_type in &[
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+            JoinType::LeftAnti,
+            JoinType::RightAnti,
+            JoinType::LeftSemi,
+        ] {
+            for sort_options in [
+                SortOptions::default(),
+                SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                },
+            ] {
+                roundtrip_test(Arc::new(SortMergeJoinExec::try_new(
+                    Arc::new(EmptyExec::new(false, schema_left.clone())),
+                    Arc::new(EmptyExec::new(false, schema_right.clone())),
+                    on.clone(),
+                    *join_type,
+                    vec![sort_options],
+                    true,
+                )?))?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip_nested_loop_join() -> Result<()> {
+        let schema_left =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let schema_right =
+            Arc::new(Schema::new(vec![Field::new("b", DataType::Int64, false)]));
+        let filter_schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]);
+        let filter = JoinFilter::new(
+            binary(
+                col("a", &filter_schema)?,
+                Operator::Lt,
+                col("b", &filter_schema)?,
+                &filter_schema,
+            )?,
+            vec![
+                ColumnIndex {
+                    index: 0,
+                    side: JoinSide::Left,
+                },
+                ColumnIndex {
+                    index: 0,
+                    side: JoinSide::Right,
+                },
+            ],
+            filter_schema,
+        );
+
+        for join_type in &[JoinType::Inner, JoinType::Left, JoinType::Full] {
+            roundtrip_test(Arc::new(NestedLoopJoinExec::try_new(
+                Arc::new(EmptyExec::new(false, schema_left.clone())),
+                Arc::new(EmptyExec::new(false, schema_right.clone())),
+                Some(filter.clone()),
+                join_type,
+            )?))?;
+        }
+        roundtrip_test(Arc::new(NestedLoopJoinExec::try_new(
+            Arc::new(EmptyExec::new(false, schema_left)),
+            Arc::new(EmptyExec::new(false, schema_right)),
+            None,
+            &JoinType::Inner,
+        )?))
+    }
+
+    #[test]
+    fn roundtrip_symmetric_hash_join() -> Result<()> {
+        let schema_left = Arc::new(Schema::new(vec![
+            Field::new("la", DataType::Int32, false),
+            Field::new("lb", DataType::Int32, false),
+        ]));
+        let schema_right = Arc::new(Schema::new(vec![
+            Field::new("ra", DataType::Int32, false),
+            Field::new("rb", DataType::Int32, false),
+        ]));
+        let sorted_input = |schema: &Arc<Schema>, column: &str| -> Result<_> {
+            let sort_information = vec![PhysicalSortExpr {
+                expr: col(column, schema)?,
+                options: SortOptions::default(),
+            }];
+            Ok(Arc::new(
+                MemoryExec::try_new(&[vec![]], schema.clone(), None)?
+                    .with_sort_information(sort_information),
+            ))
+        };
+        let on = vec![(Column::new("lb", 1), Column::new("rb", 1))];
+        let filter_schema = Schema::new(vec![
+            Field::new("left", DataType::Int32, false),
+            Field::new("right", DataType::Int32, false),
+        ]);
+        let filter = JoinFilter::new(
+            binary(
+                col("left", &filter_schema)?,
+                Operator::Gt,
+                col("right", &filter_schema)?,
+                &filter_schema,
+            )?,
+            vec![
+                ColumnIndex {
+                    index: 0,
+                    side: JoinSide::Left,
+                },
+                ColumnIndex {
+                    index: 0,
+                    side: JoinSide::Right,
+                },
+            ],
+            filter_schema,
+        );
+
+        roundtrip_test(Arc::new(SymmetricHashJoinExec::try_new(
+            sorted_input(&schema_left, "la")?,
+            sorted_input(&schema_right, "ra")?,
+            on,
+            filter,
+            &JoinType::Inner,
+            false,
+        )?))
+    }
+
+    #[test]
+    fn roundtrip_json_exec() -> Result<()> {
+        let scan_config = FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            file_schema: Arc::new(Schema::new(vec![Field::new(
+                "col",
+                DataType::Utf8,
+                false,
+            )])),
+            file_groups: vec![vec![PartitionedFile::new(
+                "/path/to/file.json.gz".to_string(),
+                1024,
+            )]],
+            statistics: Statistics {
+                num_rows: Some(100),
+                total_byte_size: Some(1024),
+                column_statistics: None,
+                is_exact: false,
+            },
+            projection: None,
+            nested_projection: None,
+            limit: Some(10),
+            table_partition_cols: vec![],
+            output_ordering: None,
+            infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
+        };
+
+        roundtrip_test(Arc::new(NdJsonExec::new(
+            scan_config.clone(),
+            FileCompressionType::GZIP,
+        )))?;
+        roundtrip_test(Arc::new(NdJsonExec::new(
+            scan_config,
+            FileCompressionType::UNCOMPRESSED,
+        )))
+    }
+
+    #[test]
+    fn roundtrip_unnest() -> Result<()> {
+        let input_schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new(
+                "a",
+                DataType::List(Box::new(Field::new("item", DataType::Int64, true))),
+                true,
+            ),
+        ]));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("a", DataType::Int64, true),
+        ]));
+
+        roundtrip_test(Arc::new(UnnestExec::new(
+            Arc::new(EmptyExec::new(false, input_schema)),
+            Column::new("a", 1),
+            schema,
+        )))
+    }
+
+    #[test]
+    fn roundtrip_values() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+
+        roundtrip_test(Arc::new(ValuesExec::try_new(
+            schema,
+            vec![
+                vec![lit(ScalarValue::Int64(Some(1))), lit("one")],
+                vec![lit(ScalarValue::Int64(None)), lit(ScalarValue::Utf8(None))],
+            ],
+        )?))
+    }
+
+    #[test]
+    fn roundtrip_analyze() -> Result<()> {
+        let input_schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("plan_type", DataType::Utf8, false),
+            Field::new("plan", DataType::Utf8, false),
+        ]));
+
+        for verbose in [false, true] {
+            roundtrip_test(Arc::new(AnalyzeExec::new(
+                verbose,
+                Arc::new(EmptyExec::new(false, input_schema.clone())),
+                schema.clone(),
+            )))?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip_memory() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let values = ValuesExec::try_new(
+            schema.clone(),
+            vec![
+                vec![lit(ScalarValue::Int64(Some(1))), lit("one")],
+                vec![
+                    lit(ScalarValue::Int64(Some(2))),
+                    lit(ScalarValue::Utf8(None)),
+                ],
+            ],
+        )?;
+        let partitions = vec![values.data(), vec![], values.data()];
+        let sort_information = vec![PhysicalSortExpr {
+            expr: Arc::new(Column::new("b", 0)),
+            options: SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+        }];
+
+        let plans = vec![
+            Arc::new(MemoryExec::try_new(&partitions, schema.clone(), None)?),
+            Arc::new(MemoryExec::try_new(
+                &partitions,
+                schema.clone(),
+                Some(vec![]),
+            )?),
+            Arc::new(
+                MemoryExec::try_new(&partitions, schema, Some(vec![1]))?
+                    .with_sort_information(sort_information),
+            ),
+        ];
+        for plan in plans {
+            roundtrip_test(plan.clone())?;
+
+            // the debug output of MemoryExec leaves out its data and sort order
+            let ctx = SessionContext::new();
+            let codec = DefaultPhysicalExtensionCodec {};
+            let proto =
+                protobuf::PhysicalPlanNode::try_from_physical_plan(plan.clone(), &codec)?;
+            let runtime = ctx.runtime_env();
+            let result = proto.try_into_physical_plan(&ctx, runtime.deref(), &codec)?;
+            let result = result.as_any().downcast_ref::<MemoryExec>().unwrap();
+
+            assert_eq!(plan.partitions(), result.partitions());
+            assert_eq!(
+                format!("{:?}", plan.output_ordering()),
+                format!("{:?}", result.output_ordering())
+            );
+        }
+        Ok(())
+    }
 }
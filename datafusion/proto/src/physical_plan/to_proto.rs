@@ -22,6 +22,10 @@ use std::{
     sync::Arc,
 };
 
+use datafusion::arrow::compute::SortOptions;
+use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::ipc::writer::StreamWriter;
+use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::physical_plan::expressions::{CastExpr, TryCastExpr};
 use datafusion::physical_plan::ColumnStatistics;
 use datafusion::physical_plan::{
@@ -32,109 +36,35 @@ use datafusion::physical_plan::{
 };
 
 use datafusion::datasource::listing::{FileRange, PartitionedFile};
-use datafusion::physical_plan::file_format::FileScanConfig;
+use datafusion::physical_plan::file_format::{FileScanConfig, SchemaEvolution};
+use datafusion::physical_plan::udaf::AggregateFunctionExpr;
 
 use datafusion::physical_plan::expressions::{Count, DistinctCount, Literal};
+use datafusion::physical_plan::expressions::{
+    CumeDist, NthValue, Ntile, Rank, RankType, RowNumber, WindowShift,
+};
 
 use datafusion::physical_plan::expressions::{
     Avg, BinaryExpr, Column, LikeExpr, Max, Min, Sum,
 };
-use datafusion::physical_plan::{AggregateExpr, PhysicalExpr};
+use datafusion::physical_plan::{AggregateExpr, PhysicalExpr, WindowExpr};
 
 use crate::protobuf;
 use crate::protobuf::{PhysicalSortExprNode, ScalarValue};
 use datafusion::logical_expr::BuiltinScalarFunction;
 use datafusion::physical_expr::expressions::{DateTimeIntervalExpr, GetIndexedFieldExpr};
-use datafusion::physical_expr::ScalarFunctionExpr;
-use datafusion::physical_plan::joins::utils::JoinSide;
+use datafusion::physical_expr::window::{
+    BuiltInWindowExpr, NthValueKind, PlainAggregateWindowExpr, SlidingAggregateWindowExpr,
+};
+use datafusion::physical_expr::{PhysicalSortExpr, ScalarFunctionExpr};
+use datafusion::physical_plan::joins::utils::{JoinFilter, JoinSide};
 use datafusion_common::DataFusionError;
 
 impl TryFrom<Arc<dyn AggregateExpr>> for protobuf::PhysicalExprNode {
     type Error = DataFusionError;
 
     fn try_from(a: Arc<dyn AggregateExpr>) -> Result<Self, Self::Error> {
-        use datafusion::physical_plan::expressions;
-        use protobuf::AggregateFunction;
-
-        let mut distinct = false;
-        let aggr_function = if a.as_any().downcast_ref::<Avg>().is_some() {
-            Ok(AggregateFunction::Avg.into())
-        } else if a.as_any().downcast_ref::<Sum>().is_some() {
-            Ok(AggregateFunction::Sum.into())
-        } else if a.as_any().downcast_ref::<Count>().is_some() {
-            Ok(AggregateFunction::Count.into())
-        } else if a.as_any().downcast_ref::<DistinctCount>().is_some() {
-            distinct = true;
-            Ok(AggregateFunction::Count.into())
-        } else if a.as_any().downcast_ref::<Min>().is_some() {
-            Ok(AggregateFunction::Min.into())
-        } else if a.as_any().downcast_ref::<Max>().is_some() {
-            Ok(AggregateFunction::Max.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::ApproxDistinct>()
-            .is_some()
-        {
-            Ok(AggregateFunction::ApproxDistinct.into())
-        } else if a.as_any().downcast_ref::<expressions::ArrayAgg>().is_some() {
-            Ok(AggregateFunction::ArrayAgg.into())
-        } else if a.as_any().downcast_ref::<expressions::Variance>().is_some() {
-            Ok(AggregateFunction::Variance.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::VariancePop>()
-            .is_some()
-        {
-            Ok(AggregateFunction::VariancePop.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::Covariance>()
-            .is_some()
-        {
-            Ok(AggregateFunction::Covariance.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::CovariancePop>()
-            .is_some()
-        {
-            Ok(AggregateFunction::CovariancePop.into())
-        } else if a.as_any().downcast_ref::<expressions::Stddev>().is_some() {
-            Ok(AggregateFunction::Stddev.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::StddevPop>()
-            .is_some()
-        {
-            Ok(AggregateFunction::StddevPop.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::Correlation>()
-            .is_some()
-        {
-            Ok(AggregateFunction::Correlation.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::ApproxPercentileCont>()
-            .is_some()
-        {
-            Ok(AggregateFunction::ApproxPercentileCont.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::ApproxPercentileContWithWeight>()
-            .is_some()
-        {
-            Ok(AggregateFunction::ApproxPercentileContWithWeight.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::ApproxMedian>()
-            .is_some()
-        {
-            Ok(AggregateFunction::ApproxMedian.into())
-        } else {
-            Err(DataFusionError::NotImplemented(format!(
-                "Aggregate function not supported: {a:?}"
-            )))
-        }?;
+        let (aggr_function, distinct) = aggr_expr_to_aggr_fn(a.as_ref())?;
         let expressions: Vec<protobuf::PhysicalExprNode> = a
             .expressions()
             .iter()
@@ -143,7 +73,7 @@ impl TryFrom<Arc<dyn AggregateExpr>> for protobuf::PhysicalExprNode {
         Ok(protobuf::PhysicalExprNode {
             expr_type: Some(protobuf::physical_expr_node::ExprType::AggregateExpr(
                 protobuf::PhysicalAggregateExprNode {
-                    aggr_function,
+                    aggr_function: aggr_function.into(),
                     expr: expressions,
                     distinct,
                 },
@@ -152,6 +82,227 @@ impl TryFrom<Arc<dyn AggregateExpr>> for protobuf::PhysicalExprNode {
     }
 }
 
+impl TryFrom<Arc<dyn WindowExpr>> for protobuf::PhysicalExprNode {
+    type Error = DataFusionError;
+
+    fn try_from(window_expr: Arc<dyn WindowExpr>) -> Result<Self, Self::Error> {
+        use datafusion_common::ScalarValue;
+
+        let expr = window_expr.as_any();
+        let mut args = window_expr.expressions();
+
+        let window_function = if let Some(built_in_window_expr) =
+            expr.downcast_ref::<BuiltInWindowExpr>()
+        {
+            let built_in_fn_expr = built_in_window_expr.get_built_in_func_expr();
+            let built_in_fn = built_in_fn_expr.as_any();
+            // parameters of the built-in functions that are not expressions
+            // are encoded as literal arguments, the same way the planner
+            // receives them
+            let built_in_function = if built_in_fn.downcast_ref::<RowNumber>().is_some() {
+                protobuf::BuiltInWindowFunction::RowNumber
+            } else if let Some(rank_expr) = built_in_fn.downcast_ref::<Rank>() {
+                match rank_expr.get_type() {
+                    RankType::Basic => protobuf::BuiltInWindowFunction::Rank,
+                    RankType::Dense => protobuf::BuiltInWindowFunction::DenseRank,
+                    RankType::Percent => protobuf::BuiltInWindowFunction::PercentRank,
+                }
+            } else if built_in_fn.downcast_ref::<CumeDist>().is_some() {
+                protobuf::BuiltInWindowFunction::CumeDist
+            } else if let Some(ntile_expr) = built_in_fn.downcast_ref::<Ntile>() {
+                args.insert(
+                    0,
+                    Arc::new(Literal::new(ScalarValue::Int64(Some(
+                        ntile_expr.get_n() as i64
+                    )))),
+                );
+                protobuf::BuiltInWindowFunction::Ntile
+            } else if let Some(window_shift_expr) =
+                built_in_fn.downcast_ref::<WindowShift>()
+            {
+                // lag has a positive shift offset, lead a negative one
+                let shift_offset = window_shift_expr.get_shift_offset();
+                args.insert(
+                    1,
+                    Arc::new(Literal::new(ScalarValue::Int64(Some(shift_offset.abs())))),
+                );
+                if let Some(default_value) = window_shift_expr.get_default_value() {
+                    args.insert(2, Arc::new(Literal::new(default_value.clone())));
+                }
+                if shift_offset >= 0 {
+                    protobuf::BuiltInWindowFunction::Lag
+                } else {
+                    protobuf::BuiltInWindowFunction::Lead
+                }
+            } else if let Some(nth_value_expr) = built_in_fn.downcast_ref::<NthValue>() {
+                match nth_value_expr.get_kind() {
+                    NthValueKind::First => protobuf::BuiltInWindowFunction::FirstValue,
+                    NthValueKind::Last => protobuf::BuiltInWindowFunction::LastValue,
+                    NthValueKind::Nth(n) => {
+                        args.insert(
+                            1,
+                            Arc::new(Literal::new(ScalarValue::Int64(Some(n as i64)))),
+                        );
+                        protobuf::BuiltInWindowFunction::NthValue
+                    }
+                }
+            } else {
+                return Err(DataFusionError::NotImplemented(format!(
+                    "BuiltIn window function not supported: {built_in_fn_expr:?}"
+                )));
+            };
+            protobuf::physical_window_expr_node::WindowFunction::BuiltInFunction(
+                built_in_function.into(),
+            )
+        } else if let Some(plain_aggr_window_expr) =
+            expr.downcast_ref::<PlainAggregateWindowExpr>()
+        {
+            aggr_expr_to_window_function(
+                plain_aggr_window_expr.get_aggregate_expr().as_ref(),
+            )?
+        } else if let Some(sliding_aggr_window_expr) =
+            expr.downcast_ref::<SlidingAggregateWindowExpr>()
+        {
+            aggr_expr_to_window_function(
+                sliding_aggr_window_expr.get_aggregate_expr().as_ref(),
+            )?
+        } else {
+            return Err(DataFusionError::NotImplemented(format!(
+                "WindowExpr not supported: {window_expr:?}"
+            )));
+        };
+
+        let args = args
+            .into_iter()
+            .map(|e| e.try_into())
+            .collect::<Result<Vec<_>, DataFusionError>>()?;
+        let partition_by = window_expr
+            .partition_by()
+            .iter()
+            .map(|e| e.clone().try_into())
+            .collect::<Result<Vec<_>, DataFusionError>>()?;
+        let order_by = window_expr
+            .order_by()
+            .iter()
+            .map(|e| e.try_into())
+            .collect::<Result<Vec<_>, DataFusionError>>()?;
+        let window_frame: protobuf::WindowFrame =
+            window_expr.get_window_frame().as_ref().try_into()?;
+
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::WindowExpr(
+                protobuf::PhysicalWindowExprNode {
+                    args,
+                    partition_by,
+                    order_by,
+                    window_frame: Some(window_frame),
+                    window_function: Some(window_function),
+                },
+            )),
+        })
+    }
+}
+
+fn aggr_expr_to_window_function(
+    expr: &dyn AggregateExpr,
+) -> Result<protobuf::physical_window_expr_node::WindowFunction, DataFusionError> {
+    // the user defined aggregate functions are deserialized by name from the
+    // function registry
+    if let Some(udaf_expr) = expr.as_any().downcast_ref::<AggregateFunctionExpr>() {
+        return Ok(protobuf::physical_window_expr_node::WindowFunction::Udaf(
+            udaf_expr.fun().name.clone(),
+        ));
+    }
+    let (aggr_function, distinct) = aggr_expr_to_aggr_fn(expr)?;
+    if distinct {
+        return Err(DataFusionError::NotImplemented(format!(
+            "Distinct aggregate functions are not supported in window expressions: {expr:?}"
+        )));
+    }
+    Ok(
+        protobuf::physical_window_expr_node::WindowFunction::AggrFunction(
+            aggr_function.into(),
+        ),
+    )
+}
+
+fn aggr_expr_to_aggr_fn(
+    expr: &dyn AggregateExpr,
+) -> Result<(protobuf::AggregateFunction, bool), DataFusionError> {
+    use datafusion::physical_plan::expressions;
+    use protobuf::AggregateFunction;
+
+    let aggr_expr = expr.as_any();
+    let mut distinct = false;
+    let aggr_function = if aggr_expr.downcast_ref::<Avg>().is_some() {
+        Ok(AggregateFunction::Avg)
+    } else if aggr_expr.downcast_ref::<Sum>().is_some() {
+        Ok(AggregateFunction::Sum)
+    } else if aggr_expr.downcast_ref::<Count>().is_some() {
+        Ok(AggregateFunction::Count)
+    } else if aggr_expr.downcast_ref::<DistinctCount>().is_some() {
+        distinct = true;
+        Ok(AggregateFunction::Count)
+    } else if aggr_expr.downcast_ref::<Min>().is_some() {
+        Ok(AggregateFunction::Min)
+    } else if aggr_expr.downcast_ref::<Max>().is_some() {
+        Ok(AggregateFunction::Max)
+    } else if aggr_expr
+        .downcast_ref::<expressions::ApproxDistinct>()
+        .is_some()
+    {
+        Ok(AggregateFunction::ApproxDistinct)
+    } else if aggr_expr.downcast_ref::<expressions::ArrayAgg>().is_some() {
+        Ok(AggregateFunction::ArrayAgg)
+    } else if aggr_expr.downcast_ref::<expressions::Variance>().is_some() {
+        Ok(AggregateFunction::Variance)
+    } else if aggr_expr
+        .downcast_ref::<expressions::VariancePop>()
+        .is_some()
+    {
+        Ok(AggregateFunction::VariancePop)
+    } else if aggr_expr
+        .downcast_ref::<expressions::Covariance>()
+        .is_some()
+    {
+        Ok(AggregateFunction::Covariance)
+    } else if aggr_expr
+        .downcast_ref::<expressions::CovariancePop>()
+        .is_some()
+    {
+        Ok(AggregateFunction::CovariancePop)
+    } else if aggr_expr.downcast_ref::<expressions::Stddev>().is_some() {
+        Ok(AggregateFunction::Stddev)
+    } else if aggr_expr.downcast_ref::<expressions::StddevPop>().is_some() {
+        Ok(AggregateFunction::StddevPop)
+    } else if aggr_expr
+        .downcast_ref::<expressions::Correlation>()
+        .is_some()
+    {
+        Ok(AggregateFunction::Correlation)
+    } else if aggr_expr
+        .downcast_ref::<expressions::ApproxPercentileCont>()
+        .is_some()
+    {
+        Ok(AggregateFunction::ApproxPercentileCont)
+    } else if aggr_expr
+        .downcast_ref::<expressions::ApproxPercentileContWithWeight>()
+        .is_some()
+    {
+        Ok(AggregateFunction::ApproxPercentileContWithWeight)
+    } else if aggr_expr
+        .downcast_ref::<expressions::ApproxMedian>()
+        .is_some()
+    {
+        Ok(AggregateFunction::ApproxMedian)
+    } else {
+        Err(DataFusionError::NotImplemented(format!(
+            "Aggregate function not supported: {expr:?}"
+        )))
+    }?;
+    Ok((aggr_function, distinct))
+}
+
 impl TryFrom<Arc<dyn PhysicalExpr>> for protobuf::PhysicalExprNode {
     type Error = DataFusionError;
 
@@ -371,6 +522,18 @@ fn try_parse_when_then_expr(
     })
 }
 
+impl TryFrom<&PhysicalSortExpr> for protobuf::PhysicalSortExprNode {
+    type Error = DataFusionError;
+
+    fn try_from(sort_expr: &PhysicalSortExpr) -> Result<Self, Self::Error> {
+        Ok(protobuf::PhysicalSortExprNode {
+            expr: Some(Box::new(sort_expr.expr.clone().try_into()?)),
+            asc: !sort_expr.options.descending,
+            nulls_first: sort_expr.options.nulls_first,
+        })
+    }
+}
+
 impl TryFrom<&PartitionedFile> for protobuf::PartitionedFile {
     type Error = DataFusionError;
 
@@ -454,14 +617,7 @@ impl TryFrom<&FileScanConfig> for protobuf::FileScanExecConf {
         let output_ordering = if let Some(output_ordering) = &conf.output_ordering {
             output_ordering
                 .iter()
-                .map(|o| {
-                    let expr = o.expr.clone().try_into()?;
-                    Ok(PhysicalSortExprNode {
-                        expr: Some(Box::new(expr)),
-                        asc: !o.options.descending,
-                        nulls_first: o.options.nulls_first,
-                    })
-                })
+                .map(|o| o.try_into())
                 .collect::<Result<Vec<PhysicalSortExprNode>, DataFusionError>>()?
         } else {
             vec![]
@@ -486,10 +642,32 @@ impl TryFrom<&FileScanConfig> for protobuf::FileScanExecConf {
                 .collect::<Vec<_>>(),
             object_store_url: conf.object_store_url.to_string(),
             output_ordering,
+            nested_projection: conf.nested_projection.as_ref().map(|paths| {
+                protobuf::NestedProjection {
+                    paths: paths
+                        .iter()
+                        .map(|names| protobuf::NestedFieldPath {
+                            names: names.clone(),
+                        })
+                        .collect(),
+                }
+            }),
+            infinite_source: conf.infinite_source,
+            schema_evolution: protobuf::SchemaEvolution::from(conf.schema_evolution)
+                .into(),
         })
     }
 }
 
+impl From<SchemaEvolution> for protobuf::SchemaEvolution {
+    fn from(schema_evolution: SchemaEvolution) -> Self {
+        match schema_evolution {
+            SchemaEvolution::Strict => protobuf::SchemaEvolution::Strict,
+            SchemaEvolution::Merge => protobuf::SchemaEvolution::Merge,
+        }
+    }
+}
+
 impl From<JoinSide> for protobuf::JoinSide {
     fn from(t: JoinSide) -> Self {
         match t {
@@ -498,3 +676,53 @@ impl From<JoinSide> for protobuf::JoinSide {
         }
     }
 }
+
+impl From<&SortOptions> for protobuf::PhysicalSortOptions {
+    fn from(options: &SortOptions) -> Self {
+        protobuf::PhysicalSortOptions {
+            asc: !options.descending,
+            nulls_first: options.nulls_first,
+        }
+    }
+}
+
+impl TryFrom<&JoinFilter> for protobuf::JoinFilter {
+    type Error = DataFusionError;
+
+    fn try_from(filter: &JoinFilter) -> Result<Self, Self::Error> {
+        let expression = filter.expression().to_owned().try_into()?;
+        let column_indices = filter
+            .column_indices()
+            .iter()
+            .map(|i| {
+                let side: protobuf::JoinSide = i.side.to_owned().into();
+                protobuf::ColumnIndex {
+                    index: i.index as u32,
+                    side: side.into(),
+                }
+            })
+            .collect();
+        let schema = filter.schema().try_into()?;
+        Ok(protobuf::JoinFilter {
+            expression: Some(expression),
+            column_indices,
+            schema: Some(schema),
+        })
+    }
+}
+
+/// Serializes record batches sharing `schema` into an Arrow IPC stream
+pub fn batches_to_ipc_bytes(
+    schema: &Schema,
+    batches: &[RecordBatch],
+) -> Result<Vec<u8>, DataFusionError> {
+    let mut buf = vec![];
+    {
+        let mut writer = StreamWriter::try_new(&mut buf, schema)?;
+        for batch in batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+    }
+    Ok(buf)
+}
@@ -117,6 +117,12 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             return Ok(Expr::ScalarUDF { fun: fm, args });
         }
 
+        // SQL macro functions, inlined into the calling expression
+        if let Some(fm) = self.schema_provider.get_sql_function(&name) {
+            let args = self.function_args_to_expr(function.args, schema)?;
+            return fm.inline(args);
+        }
+
         // User defined aggregate functions
         if let Some(fm) = self.schema_provider.get_aggregate_meta(&name) {
             let args = self.function_args_to_expr(function.args, schema)?;
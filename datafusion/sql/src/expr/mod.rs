@@ -28,7 +28,7 @@ mod value;
 
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 use crate::utils::normalize_ident;
-use arrow_schema::DataType;
+use arrow_schema::{DataType, TimeUnit};
 use datafusion_common::{Column, DFSchema, DataFusionError, Result, ScalarValue};
 use datafusion_expr::expr_rewriter::rewrite_expr;
 use datafusion_expr::{
@@ -178,6 +178,27 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 self.convert_data_type(&data_type)?,
             ))),
 
+            SQLExpr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => {
+                let timestamp = self.sql_expr_to_logical_expr(*timestamp, schema, planner_context)?;
+                // a timestamp with timezone denotes an instant that is only
+                // relabeled, a timestamp without timezone is a wall clock time
+                // in `time_zone` that is first converted to UTC
+                let timestamp = match timestamp.get_type(schema)? {
+                    DataType::Timestamp(_, Some(_)) => timestamp,
+                    _ => Expr::ScalarFunction {
+                        fun: BuiltinScalarFunction::ToUtcTimestamp,
+                        args: vec![timestamp, lit(time_zone.clone())],
+                    },
+                };
+                Ok(Expr::Cast(Cast::new(
+                    Box::new(timestamp),
+                    DataType::Timestamp(TimeUnit::Nanosecond, Some(time_zone)),
+                )))
+            }
+
             SQLExpr::TryCast {
                 expr,
                 data_type,
@@ -20,8 +20,8 @@
 use datafusion_common::parsers::CompressionTypeVariant;
 use sqlparser::{
     ast::{
-        ColumnDef, ColumnOptionDef, ObjectName, Statement as SQLStatement,
-        TableConstraint,
+        ColumnDef, ColumnOptionDef, DataType, Expr, Ident, ObjectName,
+        Statement as SQLStatement, TableConstraint,
     },
     dialect::{keywords::Keyword, Dialect, GenericDialect},
     parser::{Parser, ParserError},
@@ -60,24 +60,74 @@ pub struct CreateExternalTable {
     pub table_partition_cols: Vec<String>,
     /// Option to not error if table already exists
     pub if_not_exists: bool,
+    /// Option to replace the table if it already exists
+    pub or_replace: bool,
     /// File compression type (GZIP, BZIP2, XZ)
     pub file_compression_type: CompressionTypeVariant,
     /// Table(provider) specific options
     pub options: HashMap<String, String>,
 }
 
+/// Writes the statement as SQL that parses back to the same statement, the
+/// options sorted by key
 impl fmt::Display for CreateExternalTable {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "CREATE EXTERNAL TABLE ")?;
+        write!(f, "CREATE ")?;
+        if self.or_replace {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "EXTERNAL TABLE ")?;
         if self.if_not_exists {
-            write!(f, "IF NOT EXSISTS ")?;
+            write!(f, "IF NOT EXISTS ")?;
         }
         write!(f, "{} ", self.name)?;
+        if !self.columns.is_empty() {
+            let columns: Vec<_> = self.columns.iter().map(|c| c.to_string()).collect();
+            write!(f, "({}) ", columns.join(", "))?;
+        }
         write!(f, "STORED AS {} ", self.file_type)?;
-        write!(f, "LOCATION {} ", self.location)
+        if self.has_header {
+            write!(f, "WITH HEADER ROW ")?;
+        }
+        if self.delimiter != ',' {
+            write!(
+                f,
+                "DELIMITER {} ",
+                quote_string(&self.delimiter.to_string())
+            )?;
+        }
+        if self.file_compression_type.is_compressed() {
+            write!(
+                f,
+                "COMPRESSION TYPE {} ",
+                self.file_compression_type.to_string()
+            )?;
+        }
+        if !self.table_partition_cols.is_empty() {
+            write!(
+                f,
+                "PARTITIONED BY ({}) ",
+                self.table_partition_cols.join(", ")
+            )?;
+        }
+        if !self.options.is_empty() {
+            let mut options: Vec<_> = self
+                .options
+                .iter()
+                .map(|(k, v)| format!("{} {}", quote_string(k), quote_string(v)))
+                .collect();
+            options.sort();
+            write!(f, "OPTIONS ({}) ", options.join(", "))?;
+        }
+        write!(f, "LOCATION {}", quote_string(&self.location))
     }
 }
 
+/// Quotes `s` as a SQL string literal
+fn quote_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
 /// DataFusion extension DDL for `DESCRIBE TABLE`
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DescribeTableStmt {
@@ -85,6 +135,72 @@ pub struct DescribeTableStmt {
     pub table_name: ObjectName,
 }
 
+/// DataFusion extension DDL for `CREATE [OR REPLACE] FUNCTION`, defining a
+/// scalar SQL macro:
+///
+/// ```sql
+/// CREATE FUNCTION discount(price DOUBLE, pct DOUBLE) RETURNS DOUBLE
+/// RETURN price * (1 - pct)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateFunction {
+    /// Function name
+    pub name: ObjectName,
+    /// Option to replace the function if it already exists
+    pub or_replace: bool,
+    /// Parameter names and types
+    pub args: Vec<(Ident, DataType)>,
+    /// Declared return type
+    pub return_type: Option<DataType>,
+    /// The expression the function returns
+    pub body: Expr,
+}
+
+/// DataFusion extension DDL for `DROP FUNCTION`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropFunction {
+    /// Function name
+    pub name: ObjectName,
+    /// Option to not error if the function does not exist
+    pub if_exists: bool,
+}
+
+/// DataFusion extension DDL for `ALTER TABLE`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterTable {
+    /// Table name
+    pub name: ObjectName,
+    /// The change of the table
+    pub operation: AlterTableOperation,
+}
+
+/// A change of a table in an [`AlterTable`] statement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterTableOperation {
+    /// `RENAME TO <name>`
+    RenameTo(ObjectName),
+    /// `ADD [COLUMN] <column definition>`
+    AddColumn(ColumnDef),
+    /// `SET OPTIONS ('key' 'value', ...)`
+    SetOptions(HashMap<String, String>),
+}
+
+/// DataFusion extension DDL for `ALTER VIEW <name> RENAME TO <name>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterView {
+    /// View name
+    pub name: ObjectName,
+    /// The new name of the view
+    pub new_name: ObjectName,
+}
+
+/// DataFusion extension for `SHOW FUNCTIONS [LIKE <pattern>]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShowFunctions {
+    /// Optional `LIKE` pattern the function names must match
+    pub filter: Option<String>,
+}
+
 /// DataFusion Statement representations.
 ///
 /// Tokens parsed by [`DFParser`] are converted into these values.
@@ -96,6 +212,16 @@ pub enum Statement {
     CreateExternalTable(CreateExternalTable),
     /// Extension: `DESCRIBE TABLE`
     DescribeTableStmt(DescribeTableStmt),
+    /// Extension: `CREATE FUNCTION`
+    CreateFunction(CreateFunction),
+    /// Extension: `DROP FUNCTION`
+    DropFunction(DropFunction),
+    /// Extension: `SHOW FUNCTIONS`
+    ShowFunctions(ShowFunctions),
+    /// Extension: `ALTER TABLE`
+    AlterTable(AlterTable),
+    /// Extension: `ALTER VIEW`
+    AlterView(AlterView),
 }
 
 /// DataFusion SQL Parser based on [`sqlparser`]
@@ -190,6 +316,51 @@ impl<'a> DFParser<'a> {
                         // use custom parsing
                         self.parse_describe()
                     }
+                    Keyword::DROP => {
+                        // move one token forward
+                        self.parser.next_token();
+                        if self.parser.parse_keyword(Keyword::FUNCTION) {
+                            // use custom parsing
+                            self.parse_drop_function()
+                        } else {
+                            // move back and use the native parser
+                            self.parser.prev_token();
+                            Ok(Statement::Statement(Box::from(
+                                self.parser.parse_statement()?,
+                            )))
+                        }
+                    }
+                    Keyword::ALTER => {
+                        // move one token forward
+                        self.parser.next_token();
+                        if self.parser.parse_keyword(Keyword::TABLE) {
+                            // use custom parsing
+                            self.parse_alter_table()
+                        } else if self.parser.parse_keyword(Keyword::VIEW) {
+                            // use custom parsing
+                            self.parse_alter_view()
+                        } else {
+                            // move back and use the native parser
+                            self.parser.prev_token();
+                            Ok(Statement::Statement(Box::from(
+                                self.parser.parse_statement()?,
+                            )))
+                        }
+                    }
+                    Keyword::SHOW => {
+                        // move one token forward
+                        self.parser.next_token();
+                        if self.parse_word("FUNCTIONS") {
+                            // use custom parsing
+                            self.parse_show_functions()
+                        } else {
+                            // move back and use the native parser
+                            self.parser.prev_token();
+                            Ok(Statement::Statement(Box::from(
+                                self.parser.parse_statement()?,
+                            )))
+                        }
+                    }
                     _ => {
                         // use the native parser
                         Ok(Statement::Statement(Box::from(
@@ -215,15 +386,150 @@ impl<'a> DFParser<'a> {
         }))
     }
 
-    /// Parse a SQL `CREATE` statementm handling `CREATE EXTERNAL TABLE`
+    /// Parse a SQL `CREATE` statementm handling `CREATE [OR REPLACE] EXTERNAL TABLE`
+    /// and `CREATE [OR REPLACE] FUNCTION`
     pub fn parse_create(&mut self) -> Result<Statement, ParserError> {
         if self.parser.parse_keyword(Keyword::EXTERNAL) {
-            self.parse_create_external_table()
+            self.parse_create_external_table(false)
+        } else if self.parser.parse_keywords(&[
+            Keyword::OR,
+            Keyword::REPLACE,
+            Keyword::EXTERNAL,
+        ]) {
+            self.parse_create_external_table(true)
+        } else if self.parser.parse_keyword(Keyword::FUNCTION) {
+            self.parse_create_function(false)
+        } else if self.parser.parse_keywords(&[
+            Keyword::OR,
+            Keyword::REPLACE,
+            Keyword::FUNCTION,
+        ]) {
+            self.parse_create_function(true)
         } else {
             Ok(Statement::Statement(Box::from(self.parser.parse_create()?)))
         }
     }
 
+    /// Consumes the next token if it is the word `word`, ignoring case.
+    ///
+    /// Used for the words that are not keywords of the native parser
+    fn parse_word(&mut self, word: &str) -> bool {
+        match self.parser.peek_token().token {
+            Token::Word(w)
+                if w.quote_style.is_none() && w.value.eq_ignore_ascii_case(word) =>
+            {
+                self.parser.next_token();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_create_function(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<Statement, ParserError> {
+        let name = self.parser.parse_object_name()?;
+
+        let mut args = vec![];
+        self.parser.expect_token(&Token::LParen)?;
+        if !self.parser.consume_token(&Token::RParen) {
+            loop {
+                let arg_name = self.parser.parse_identifier()?;
+                let data_type = self.parser.parse_data_type()?;
+                args.push((arg_name, data_type));
+                if self.parser.consume_token(&Token::RParen) {
+                    break;
+                } else if !self.parser.consume_token(&Token::Comma) {
+                    return self.expected(
+                        "',' or ')' after parameter definition",
+                        self.parser.peek_token(),
+                    );
+                }
+            }
+        }
+
+        let return_type = if self.parse_word("RETURNS") {
+            Some(self.parser.parse_data_type()?)
+        } else {
+            None
+        };
+
+        if !self.parse_word("RETURN") {
+            return self.expected("RETURN", self.parser.peek_token());
+        }
+        let body = self.parser.parse_expr()?;
+
+        Ok(Statement::CreateFunction(CreateFunction {
+            name,
+            or_replace,
+            args,
+            return_type,
+            body,
+        }))
+    }
+
+    fn parse_drop_function(&mut self) -> Result<Statement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parser.parse_object_name()?;
+
+        // functions are identified by their name only, so the optional
+        // parameter types are ignored
+        if self.parser.consume_token(&Token::LParen)
+            && !self.parser.consume_token(&Token::RParen)
+        {
+            loop {
+                self.parser.parse_data_type()?;
+                if self.parser.consume_token(&Token::RParen) {
+                    break;
+                }
+                self.parser.expect_token(&Token::Comma)?;
+            }
+        }
+
+        Ok(Statement::DropFunction(DropFunction { name, if_exists }))
+    }
+
+    /// Parse a SQL `ALTER TABLE` statement, after `ALTER TABLE`
+    pub fn parse_alter_table(&mut self) -> Result<Statement, ParserError> {
+        let name = self.parser.parse_object_name()?;
+        let operation = if self.parser.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterTableOperation::RenameTo(self.parser.parse_object_name()?)
+        } else if self.parser.parse_keyword(Keyword::ADD) {
+            self.parser.parse_keyword(Keyword::COLUMN);
+            AlterTableOperation::AddColumn(self.parse_column_def()?)
+        } else if self.parser.parse_keyword(Keyword::SET) {
+            if !self.parse_has_options() {
+                return self.expected("OPTIONS", self.parser.peek_token());
+            }
+            AlterTableOperation::SetOptions(self.parse_options()?)
+        } else {
+            return self.expected(
+                "RENAME TO, ADD COLUMN or SET OPTIONS",
+                self.parser.peek_token(),
+            );
+        };
+        Ok(Statement::AlterTable(AlterTable { name, operation }))
+    }
+
+    /// Parse a SQL `ALTER VIEW` statement, after `ALTER VIEW`
+    pub fn parse_alter_view(&mut self) -> Result<Statement, ParserError> {
+        let name = self.parser.parse_object_name()?;
+        self.parser
+            .expect_keywords(&[Keyword::RENAME, Keyword::TO])?;
+        let new_name = self.parser.parse_object_name()?;
+        Ok(Statement::AlterView(AlterView { name, new_name }))
+    }
+
+    fn parse_show_functions(&mut self) -> Result<Statement, ParserError> {
+        let filter = if self.parser.parse_keyword(Keyword::LIKE) {
+            Some(self.parser.parse_literal_string()?)
+        } else {
+            None
+        };
+        Ok(Statement::ShowFunctions(ShowFunctions { filter }))
+    }
+
     fn parse_partitions(&mut self) -> Result<Vec<String>, ParserError> {
         let mut partitions: Vec<String> = vec![];
         if !self.parser.consume_token(&Token::LParen)
@@ -326,7 +632,10 @@ impl<'a> DFParser<'a> {
         })
     }
 
-    fn parse_create_external_table(&mut self) -> Result<Statement, ParserError> {
+    fn parse_create_external_table(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<Statement, ParserError> {
         self.parser.expect_keyword(Keyword::TABLE)?;
         let if_not_exists =
             self.parser
@@ -377,6 +686,7 @@ impl<'a> DFParser<'a> {
             location,
             table_partition_cols,
             if_not_exists,
+            or_replace,
             file_compression_type,
             options,
         };
@@ -521,6 +831,7 @@ mod tests {
             location: "foo.csv".into(),
             table_partition_cols: vec![],
             if_not_exists: false,
+            or_replace: false,
             file_compression_type: UNCOMPRESSED,
             options: HashMap::new(),
         });
@@ -538,6 +849,7 @@ mod tests {
             location: "foo.csv".into(),
             table_partition_cols: vec![],
             if_not_exists: false,
+            or_replace: false,
             file_compression_type: UNCOMPRESSED,
             options: HashMap::new(),
         });
@@ -555,6 +867,7 @@ mod tests {
             location: "foo.csv".into(),
             table_partition_cols: vec!["p1".to_string(), "p2".to_string()],
             if_not_exists: false,
+            or_replace: false,
             file_compression_type: UNCOMPRESSED,
             options: HashMap::new(),
         });
@@ -575,6 +888,7 @@ mod tests {
                 location: "foo.csv".into(),
                 table_partition_cols: vec![],
                 if_not_exists: false,
+                or_replace: false,
                 file_compression_type: UNCOMPRESSED,
                 options: HashMap::new(),
             });
@@ -598,6 +912,7 @@ mod tests {
                 location: "foo.csv".into(),
                 table_partition_cols: vec![],
                 if_not_exists: false,
+                or_replace: false,
                 file_compression_type: CompressionTypeVariant::from_str(
                     file_compression_type,
                 )?,
@@ -617,6 +932,7 @@ mod tests {
             location: "foo.parquet".into(),
             table_partition_cols: vec![],
             if_not_exists: false,
+            or_replace: false,
             file_compression_type: UNCOMPRESSED,
             options: HashMap::new(),
         });
@@ -633,6 +949,7 @@ mod tests {
             location: "foo.parquet".into(),
             table_partition_cols: vec![],
             if_not_exists: false,
+            or_replace: false,
             file_compression_type: UNCOMPRESSED,
             options: HashMap::new(),
         });
@@ -649,6 +966,7 @@ mod tests {
             location: "foo.avro".into(),
             table_partition_cols: vec![],
             if_not_exists: false,
+            or_replace: false,
             file_compression_type: UNCOMPRESSED,
             options: HashMap::new(),
         });
@@ -666,6 +984,25 @@ mod tests {
             location: "foo.parquet".into(),
             table_partition_cols: vec![],
             if_not_exists: true,
+            or_replace: false,
+            file_compression_type: UNCOMPRESSED,
+            options: HashMap::new(),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        // positive case: the table can be replaced
+        let sql =
+            "CREATE OR REPLACE EXTERNAL TABLE t STORED AS PARQUET LOCATION 'foo.parquet'";
+        let expected = Statement::CreateExternalTable(CreateExternalTable {
+            name: "t".into(),
+            columns: vec![],
+            file_type: "PARQUET".to_string(),
+            has_header: false,
+            delimiter: ',',
+            location: "foo.parquet".into(),
+            table_partition_cols: vec![],
+            if_not_exists: false,
+            or_replace: true,
             file_compression_type: UNCOMPRESSED,
             options: HashMap::new(),
         });
@@ -688,6 +1025,7 @@ mod tests {
             location: "blahblah".into(),
             table_partition_cols: vec![],
             if_not_exists: false,
+            or_replace: false,
             file_compression_type: UNCOMPRESSED,
             options: HashMap::from([("k1".into(), "v1".into())]),
         });
@@ -705,6 +1043,7 @@ mod tests {
             location: "blahblah".into(),
             table_partition_cols: vec![],
             if_not_exists: false,
+            or_replace: false,
             file_compression_type: UNCOMPRESSED,
             options: HashMap::from([
                 ("k1".into(), "v1".into()),
@@ -739,6 +1078,96 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn display_create_external_table() -> Result<(), ParserError> {
+        let sql = "CREATE EXTERNAL TABLE IF NOT EXISTS t (c1 INT NOT NULL, c2 VARCHAR) \
+                   STORED AS CSV WITH HEADER ROW DELIMITER '|' COMPRESSION TYPE GZIP \
+                   PARTITIONED BY (c2) OPTIONS ('quote' '''', 'comment' '#') \
+                   LOCATION 'it''s.csv.gz'";
+        let statement = match DFParser::parse_sql(sql)?.pop_front() {
+            Some(Statement::CreateExternalTable(statement)) => statement,
+            other => panic!("Expected a CREATE EXTERNAL TABLE statement, got {other:?}"),
+        };
+        let display = statement.to_string();
+        assert_eq!(
+            display,
+            "CREATE EXTERNAL TABLE IF NOT EXISTS t (c1 INT NOT NULL, c2 VARCHAR) \
+             STORED AS CSV WITH HEADER ROW DELIMITER '|' COMPRESSION TYPE GZIP \
+             PARTITIONED BY (c2) OPTIONS ('comment' '#', 'quote' '''') \
+             LOCATION 'it''s.csv.gz'"
+        );
+        expect_parse_ok(&display, Statement::CreateExternalTable(statement))
+    }
+
+    #[test]
+    fn display_create_or_replace_external_table() -> Result<(), ParserError> {
+        let sql = "CREATE OR REPLACE EXTERNAL TABLE t STORED AS PARQUET LOCATION 'foo'";
+        let statement = DFParser::parse_sql(sql)?.pop_front().unwrap();
+        match &statement {
+            Statement::CreateExternalTable(create) => {
+                assert_eq!(create.to_string(), sql)
+            }
+            other => panic!("Expected a CREATE EXTERNAL TABLE statement, got {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn alter_table() -> Result<(), ParserError> {
+        let sql = "ALTER TABLE s.t RENAME TO u";
+        let expected = Statement::AlterTable(AlterTable {
+            name: ObjectName(vec![Ident::new("s"), Ident::new("t")]),
+            operation: AlterTableOperation::RenameTo(ObjectName(vec![Ident::new("u")])),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        // the COLUMN keyword is optional
+        for sql in [
+            "ALTER TABLE t ADD COLUMN c1 int",
+            "ALTER TABLE t ADD c1 int",
+        ] {
+            let expected = Statement::AlterTable(AlterTable {
+                name: ObjectName(vec![Ident::new("t")]),
+                operation: AlterTableOperation::AddColumn(make_column_def(
+                    "c1",
+                    DataType::Int(None),
+                )),
+            });
+            expect_parse_ok(sql, expected)?;
+        }
+
+        let sql = "ALTER TABLE t SET OPTIONS ('k1' 'v1', k2 v2)";
+        let expected = Statement::AlterTable(AlterTable {
+            name: ObjectName(vec![Ident::new("t")]),
+            operation: AlterTableOperation::SetOptions(HashMap::from([
+                ("k1".into(), "v1".into()),
+                ("k2".into(), "v2".into()),
+            ])),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        let sql = "ALTER VIEW v RENAME TO w";
+        let expected = Statement::AlterView(AlterView {
+            name: ObjectName(vec![Ident::new("v")]),
+            new_name: ObjectName(vec![Ident::new("w")]),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        expect_parse_error(
+            "ALTER TABLE t DROP COLUMN c1",
+            "sql parser error: Expected RENAME TO, ADD COLUMN or SET OPTIONS, found: DROP",
+        );
+        expect_parse_error(
+            "ALTER TABLE t SET ('k1' 'v1')",
+            "sql parser error: Expected OPTIONS, found: (",
+        );
+        expect_parse_error(
+            "ALTER VIEW v AS SELECT 1",
+            "sql parser error: Expected RENAME, found: AS",
+        );
+        Ok(())
+    }
+
     #[test]
     fn invalid_compression_type() {
         let sql = "CREATE EXTERNAL TABLE t STORED AS CSV COMPRESSION TYPE ZZZ LOCATION 'blahblah'";
@@ -747,4 +1176,88 @@ mod tests {
             "sql parser error: Unsupported file compression type ZZZ",
         )
     }
+
+    #[test]
+    fn create_function() -> Result<(), ParserError> {
+        let sql = "CREATE FUNCTION discount(price DOUBLE, pct DOUBLE) RETURNS DOUBLE RETURN price * (1 - pct)";
+        let body = sqlparser::parser::Parser::new(&GenericDialect {})
+            .try_with_sql("price * (1 - pct)")?
+            .parse_expr()?;
+        let expected = Statement::CreateFunction(CreateFunction {
+            name: ObjectName(vec![Ident::new("discount")]),
+            or_replace: false,
+            args: vec![
+                (Ident::new("price"), DataType::Double),
+                (Ident::new("pct"), DataType::Double),
+            ],
+            return_type: Some(DataType::Double),
+            body: body.clone(),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        // without parameters and return type
+        let sql = "CREATE OR REPLACE FUNCTION answer() RETURN 42";
+        let expected = Statement::CreateFunction(CreateFunction {
+            name: ObjectName(vec![Ident::new("answer")]),
+            or_replace: true,
+            args: vec![],
+            return_type: None,
+            body: Expr::Value(sqlparser::ast::Value::Number("42".to_string(), false)),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        // Error cases: missing body, bad parameter list
+        let sql = "CREATE FUNCTION f(a INT) RETURNS INT";
+        expect_parse_error(sql, "sql parser error: Expected RETURN, found: EOF");
+
+        let sql = "CREATE FUNCTION f(a INT b INT) RETURN a";
+        expect_parse_error(
+            sql,
+            "sql parser error: Expected ',' or ')' after parameter definition, found: b",
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn drop_function() -> Result<(), ParserError> {
+        let sql = "DROP FUNCTION discount";
+        let expected = Statement::DropFunction(DropFunction {
+            name: ObjectName(vec![Ident::new("discount")]),
+            if_exists: false,
+        });
+        expect_parse_ok(sql, expected)?;
+
+        let sql = "DROP FUNCTION IF EXISTS discount(DOUBLE, DOUBLE)";
+        let expected = Statement::DropFunction(DropFunction {
+            name: ObjectName(vec![Ident::new("discount")]),
+            if_exists: true,
+        });
+        expect_parse_ok(sql, expected)?;
+
+        // other DROP statements are still handled by the native parser
+        let statements = DFParser::parse_sql("DROP TABLE t")?;
+        assert!(matches!(statements[0], Statement::Statement(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn show_functions() -> Result<(), ParserError> {
+        let sql = "SHOW FUNCTIONS";
+        let expected = Statement::ShowFunctions(ShowFunctions { filter: None });
+        expect_parse_ok(sql, expected)?;
+
+        let sql = "SHOW FUNCTIONS LIKE 'disc%'";
+        let expected = Statement::ShowFunctions(ShowFunctions {
+            filter: Some("disc%".to_string()),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        // other SHOW statements are still handled by the native parser
+        let statements = DFParser::parse_sql("SHOW TABLES")?;
+        assert!(matches!(statements[0], Statement::Statement(_)));
+
+        Ok(())
+    }
 }
@@ -32,7 +32,7 @@ use datafusion_common::{OwnedTableReference, TableReference};
 use datafusion_expr::logical_plan::{LogicalPlan, LogicalPlanBuilder};
 use datafusion_expr::utils::find_column_exprs;
 use datafusion_expr::TableSource;
-use datafusion_expr::{col, AggregateUDF, Expr, ScalarUDF, SubqueryAlias};
+use datafusion_expr::{col, AggregateUDF, Expr, ScalarUDF, SqlFunction, SubqueryAlias};
 
 use crate::utils::make_decimal_type;
 
@@ -45,6 +45,10 @@ pub trait ContextProvider {
     fn get_function_meta(&self, name: &str) -> Option<Arc<ScalarUDF>>;
     /// Getter for a UDAF description
     fn get_aggregate_meta(&self, name: &str) -> Option<Arc<AggregateUDF>>;
+    /// Getter for a SQL macro function, see [`SqlFunction`]
+    fn get_sql_function(&self, _name: &str) -> Option<Arc<SqlFunction>> {
+        None
+    }
     /// Getter for system/user-defined variable type
     fn get_variable_type(&self, variable_names: &[String]) -> Option<DataType>;
 
@@ -252,7 +256,18 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             | SQLDataType::Varchar(_)
             | SQLDataType::Text
             | SQLDataType::String => Ok(DataType::Utf8),
-            SQLDataType::Timestamp(None, tz_info) => {
+            SQLDataType::Timestamp(precision, tz_info) => {
+                let time_unit = match precision {
+                    None | Some(9) => TimeUnit::Nanosecond,
+                    Some(6) => TimeUnit::Microsecond,
+                    Some(3) => TimeUnit::Millisecond,
+                    Some(0) => TimeUnit::Second,
+                    Some(_) => {
+                        return Err(DataFusionError::NotImplemented(format!(
+                            "Unsupported SQL type {sql_type:?}, only precisions 0, 3, 6 and 9 are supported"
+                        )))
+                    }
+                };
                 let tz = if matches!(tz_info, TimezoneInfo::Tz)
                     || matches!(tz_info, TimezoneInfo::WithTimeZone)
                 {
@@ -264,7 +279,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                     // Timestamp Without Time zone
                     None
                 };
-                Ok(DataType::Timestamp(TimeUnit::Nanosecond, tz))
+                Ok(DataType::Timestamp(time_unit, tz))
             }
             SQLDataType::Date => Ok(DataType::Date32),
             SQLDataType::Time(None, tz_info) => {
@@ -315,8 +330,6 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             | SQLDataType::CharacterLargeObject(_)
             | SQLDataType::CharLargeObject(_)
             // precision is not supported
-            | SQLDataType::Timestamp(Some(_), _)
-            // precision is not supported
             | SQLDataType::Time(Some(_), _)
             | SQLDataType::Dec(_)
             | SQLDataType::BigNumeric(_)
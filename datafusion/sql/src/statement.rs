@@ -16,13 +16,14 @@
 // under the License.
 
 use crate::parser::{
-    CreateExternalTable, DFParser, DescribeTableStmt, Statement as DFStatement,
+    AlterTable, AlterTableOperation, AlterView, CreateExternalTable, CreateFunction,
+    DFParser, DescribeTableStmt, DropFunction, ShowFunctions, Statement as DFStatement,
 };
 use crate::planner::{
     object_name_to_qualifier, ContextProvider, PlannerContext, SqlToRel,
 };
 use crate::utils::normalize_ident;
-use arrow_schema::DataType;
+use arrow_schema::{DataType, Field};
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::{
     Column, DFField, DFSchema, DFSchemaRef, DataFusionError, ExprSchema,
@@ -33,19 +34,24 @@ use datafusion_expr::logical_plan::builder::project;
 use datafusion_expr::logical_plan::{Analyze, Prepare};
 use datafusion_expr::utils::expr_to_columns;
 use datafusion_expr::{
-    cast, col, CreateCatalog, CreateCatalogSchema,
-    CreateExternalTable as PlanCreateExternalTable, CreateMemoryTable, CreateView,
-    DescribeTable, DmlStatement, DropTable, DropView, Explain, ExprSchemable, Filter,
-    LogicalPlan, LogicalPlanBuilder, PlanType, SetVariable, ToStringifiedPlan, WriteOp,
+    cast, col, AggregateFunction, AlterTable as PlanAlterTable,
+    AlterTableOperation as PlanAlterTableOperation, AlterView as PlanAlterView,
+    BuiltinScalarFunction, CreateCatalog, CreateCatalogSchema,
+    CreateExternalTable as PlanCreateExternalTable, CreateFunction as PlanCreateFunction,
+    CreateMemoryTable, CreateView, DescribeTable, DmlStatement,
+    DropFunction as PlanDropFunction, DropTable, DropView, Explain, ExprSchemable,
+    Filter, LogicalPlan, LogicalPlanBuilder, PlanType, SetVariable, SqlFunction,
+    ToStringifiedPlan, WriteOp,
 };
 use sqlparser::ast;
 use sqlparser::ast::{
-    Assignment, Expr as SQLExpr, Expr, Ident, ObjectName, ObjectType, Query, SchemaName,
-    SetExpr, ShowCreateObject, ShowStatementFilter, Statement, TableFactor,
-    TableWithJoins, UnaryOperator, Value,
+    Assignment, ColumnOption, Expr as SQLExpr, Expr, Ident, ObjectName, ObjectType,
+    Query, SchemaName, SetExpr, ShowCreateObject, ShowStatementFilter, Statement,
+    TableFactor, TableWithJoins, UnaryOperator, Value,
 };
 use sqlparser::parser::ParserError::ParserError;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
 use std::sync::Arc;
 
 fn ident_to_string(ident: &Ident) -> String {
@@ -80,6 +86,11 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             DFStatement::CreateExternalTable(s) => self.external_table_to_plan(s),
             DFStatement::Statement(s) => self.sql_statement_to_plan(*s),
             DFStatement::DescribeTableStmt(s) => self.describe_table_to_plan(s),
+            DFStatement::CreateFunction(s) => self.create_function_to_plan(s),
+            DFStatement::DropFunction(s) => self.drop_function_to_plan(s),
+            DFStatement::ShowFunctions(s) => self.show_functions_to_plan(s),
+            DFStatement::AlterTable(s) => self.alter_table_to_plan(s),
+            DFStatement::AlterView(s) => self.alter_view_to_plan(s),
         }
     }
 
@@ -406,6 +417,147 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         }
     }
 
+    /// Generate a logical plan from a "SHOW FUNCTIONS" query
+    fn show_functions_to_plan(&self, statement: ShowFunctions) -> Result<LogicalPlan> {
+        if !self.has_table("information_schema", "routines") {
+            return Err(DataFusionError::Plan(
+                "SHOW FUNCTIONS is not supported unless information_schema is enabled"
+                    .to_string(),
+            ));
+        }
+
+        // the user defined functions, without a row for each of their signatures
+        let filter = match statement.filter {
+            Some(pattern) => {
+                format!(" AND routine_name LIKE '{}'", pattern.replace('\'', "''"))
+            }
+            None => String::new(),
+        };
+        let query = format!(
+            "SELECT DISTINCT routine_name, routine_type, data_type, routine_body, \
+             routine_definition FROM information_schema.routines \
+             WHERE is_user_defined = 'YES'{filter} ORDER BY routine_name, data_type"
+        );
+        let mut rewrite = DFParser::parse_sql(&query)?;
+        assert_eq!(rewrite.len(), 1);
+        self.statement_to_plan(rewrite.pop_front().unwrap()) // length of rewrite is 1
+    }
+
+    /// Generate a logical plan from a CREATE FUNCTION statement
+    fn create_function_to_plan(&self, statement: CreateFunction) -> Result<LogicalPlan> {
+        let CreateFunction {
+            name,
+            or_replace,
+            args,
+            return_type,
+            body,
+        } = statement;
+
+        let name = object_name_to_string(&name);
+        if BuiltinScalarFunction::from_str(&name).is_ok()
+            || AggregateFunction::from_str(&name).is_ok()
+        {
+            return Err(DataFusionError::Plan(format!(
+                "Function '{name}' is a built-in function and can not be redefined"
+            )));
+        }
+
+        let args = args
+            .iter()
+            .map(|(arg_name, data_type)| {
+                Ok((
+                    ident_to_string(arg_name),
+                    self.convert_data_type(data_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let return_type = return_type
+            .as_ref()
+            .map(|data_type| self.convert_data_type(data_type))
+            .transpose()?;
+
+        // the body is planned against the parameters, so that errors such as
+        // unknown columns are reported when the function is created
+        let schema = DFSchema::new_with_metadata(
+            args.iter()
+                .map(|(arg_name, data_type)| {
+                    DFField::new(None, arg_name, data_type.clone(), true)
+                })
+                .collect(),
+            HashMap::new(),
+        )?;
+        let definition = body.to_string();
+        let body = self.sql_to_expr(body, &schema, &mut PlannerContext::new())?;
+        if let Some(return_type) = &return_type {
+            // the body must be convertible to the declared return type
+            body.clone().cast_to(return_type, &schema)?;
+        }
+
+        Ok(LogicalPlan::CreateFunction(PlanCreateFunction {
+            function: SqlFunction::new(name, args, return_type, body, definition),
+            or_replace,
+            schema: DFSchemaRef::new(DFSchema::empty()),
+        }))
+    }
+
+    /// Generate a logical plan from a DROP FUNCTION statement
+    fn drop_function_to_plan(&self, statement: DropFunction) -> Result<LogicalPlan> {
+        Ok(LogicalPlan::DropFunction(PlanDropFunction {
+            name: object_name_to_string(&statement.name),
+            if_exists: statement.if_exists,
+            schema: DFSchemaRef::new(DFSchema::empty()),
+        }))
+    }
+
+    /// Generate a logical plan from an ALTER TABLE statement
+    fn alter_table_to_plan(&self, statement: AlterTable) -> Result<LogicalPlan> {
+        let AlterTable { name, operation } = statement;
+        let operation = match operation {
+            AlterTableOperation::RenameTo(new_name) => PlanAlterTableOperation::RenameTo(
+                self.object_name_to_table_reference(new_name)?,
+            ),
+            AlterTableOperation::AddColumn(column) => {
+                // the rows the table already has are null in the new column
+                if column
+                    .options
+                    .iter()
+                    .any(|x| x.option == ColumnOption::NotNull)
+                {
+                    return Err(DataFusionError::Plan(format!(
+                        "Can not add the NOT NULL column {} to a table, \
+                         the column is null in the rows the table already has",
+                        column.name
+                    )));
+                }
+                let schema = self.build_schema(vec![column])?;
+                let field = schema.field(0);
+                PlanAlterTableOperation::AddColumn(Field::new(
+                    field.name(),
+                    field.data_type().clone(),
+                    true,
+                ))
+            }
+            AlterTableOperation::SetOptions(options) => {
+                PlanAlterTableOperation::SetOptions(options)
+            }
+        };
+
+        Ok(LogicalPlan::AlterTable(PlanAlterTable {
+            name: self.object_name_to_table_reference(name)?,
+            operation,
+            schema: DFSchemaRef::new(DFSchema::empty()),
+        }))
+    }
+
+    /// Generate a logical plan from an ALTER VIEW statement
+    fn alter_view_to_plan(&self, statement: AlterView) -> Result<LogicalPlan> {
+        Ok(LogicalPlan::AlterView(PlanAlterView {
+            name: self.object_name_to_table_reference(statement.name)?,
+            new_name: self.object_name_to_table_reference(statement.new_name)?,
+            schema: DFSchemaRef::new(DFSchema::empty()),
+        }))
+    }
+
     fn describe_table_to_plan(
         &self,
         statement: DescribeTableStmt,
@@ -440,14 +592,15 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             location,
             table_partition_cols,
             if_not_exists,
+            or_replace,
             file_compression_type,
             options,
         } = statement;
 
         // semantic checks
-        if file_type == "PARQUET" && !columns.is_empty() {
+        if if_not_exists && or_replace {
             Err(DataFusionError::Plan(
-                "Column definitions can not be specified for PARQUET files.".into(),
+                "'IF NOT EXISTS' cannot coexist with 'REPLACE'".into(),
             ))?;
         }
 
@@ -474,6 +627,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             delimiter,
             table_partition_cols,
             if_not_exists,
+            or_replace,
             definition,
             file_compression_type,
             options,
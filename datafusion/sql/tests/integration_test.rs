@@ -138,6 +138,15 @@ fn test_int_decimal_no_scale() {
     );
 }
 
+#[test]
+fn test_int_decimal256() {
+    quick_test(
+        "SELECT CAST(10 AS DECIMAL(39, 2))",
+        "Projection: CAST(Int64(10) AS Decimal256(39, 2))\
+             \n  EmptyRelation",
+    );
+}
+
 #[test]
 fn test_tinyint() {
     quick_test(
@@ -174,16 +183,16 @@ fn cast_to_invalid_decimal_type() {
         let sql = "SELECT CAST(10 AS DECIMAL(0))";
         let err = logical_plan(sql).expect_err("query should have failed");
         assert_eq!(
-            r##"Internal("Decimal(precision = 0, scale = 0) should satisfy `0 < precision <= 38`, and `scale <= precision`.")"##,
+            r##"Internal("Decimal(precision = 0, scale = 0) should satisfy `0 < precision <= 76`, and `scale <= precision`.")"##,
             format!("{err:?}")
         );
     }
-    // precision > 38
+    // precision > 76
     {
-        let sql = "SELECT CAST(10 AS DECIMAL(39))";
+        let sql = "SELECT CAST(10 AS DECIMAL(77))";
         let err = logical_plan(sql).expect_err("query should have failed");
         assert_eq!(
-            r##"Internal("Decimal(precision = 39, scale = 0) should satisfy `0 < precision <= 38`, and `scale <= precision`.")"##,
+            r##"Internal("Decimal(precision = 77, scale = 0) should satisfy `0 < precision <= 76`, and `scale <= precision`.")"##,
             format!("{err:?}")
         );
     }
@@ -192,7 +201,7 @@ fn cast_to_invalid_decimal_type() {
         let sql = "SELECT CAST(10 AS DECIMAL(5, 10))";
         let err = logical_plan(sql).expect_err("query should have failed");
         assert_eq!(
-            r##"Internal("Decimal(precision = 5, scale = 10) should satisfy `0 < precision <= 38`, and `scale <= precision`.")"##,
+            r##"Internal("Decimal(precision = 5, scale = 10) should satisfy `0 < precision <= 76`, and `scale <= precision`.")"##,
             format!("{err:?}")
         );
     }
@@ -1597,6 +1606,40 @@ fn create_schema_with_unquoted_normalized_name() {
     quick_test(sql, expected);
 }
 
+#[test]
+fn create_function() {
+    let sql = "CREATE FUNCTION Discount(price DOUBLE, pct DOUBLE) RETURNS DOUBLE RETURN price * (1 - pct)";
+    let expected = "CreateFunction: \"discount\"";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn create_function_unknown_parameter() {
+    let sql = "CREATE FUNCTION f(a INT) RETURNS INT RETURN a + b";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Schema error: No field named 'b'. Valid fields are 'a'.",
+        err.to_string()
+    );
+}
+
+#[test]
+fn create_function_builtin_name() {
+    let sql = "CREATE FUNCTION abs(a INT) RETURN a";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Error during planning: Function 'abs' is a built-in function and can not be redefined",
+        err.to_string()
+    );
+}
+
+#[test]
+fn drop_function() {
+    let sql = "DROP FUNCTION IF EXISTS discount";
+    let expected = "DropFunction: \"discount\" if not exist:=true";
+    quick_test(sql, expected);
+}
+
 #[test]
 fn create_external_table_custom() {
     let sql = "CREATE EXTERNAL TABLE dt STORED AS DELTATABLE LOCATION 's3://bucket/schema/table';";
@@ -1643,18 +1686,59 @@ fn create_external_table_with_compression_type() {
 
 #[test]
 fn create_external_table_parquet() {
+    // the declared columns replace the schema of the files
     let sql = "CREATE EXTERNAL TABLE t(c1 int) STORED AS PARQUET LOCATION 'foo.parquet'";
+    let expected = "CreateExternalTable: Bare { table: \"t\" }";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn create_external_table_parquet_no_schema() {
+    let sql = "CREATE EXTERNAL TABLE t STORED AS PARQUET LOCATION 'foo.parquet'";
+    let expected = "CreateExternalTable: Bare { table: \"t\" }";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn create_or_replace_external_table() {
+    let sql = "CREATE OR REPLACE EXTERNAL TABLE t STORED AS CSV LOCATION 'foo.csv'";
+    let expected = "CreateExternalTable: Bare { table: \"t\" }";
+    quick_test(sql, expected);
+
+    let sql = "CREATE OR REPLACE EXTERNAL TABLE IF NOT EXISTS t STORED AS CSV LOCATION 'foo.csv'";
     let err = logical_plan(sql).expect_err("query should have failed");
     assert_eq!(
-        "Plan(\"Column definitions can not be specified for PARQUET files.\")",
+        "Plan(\"'IF NOT EXISTS' cannot coexist with 'REPLACE'\")",
         format!("{err:?}")
     );
 }
 
 #[test]
-fn create_external_table_parquet_no_schema() {
-    let sql = "CREATE EXTERNAL TABLE t STORED AS PARQUET LOCATION 'foo.parquet'";
-    let expected = "CreateExternalTable: Bare { table: \"t\" }";
+fn alter_table() {
+    let sql = "ALTER TABLE t RENAME TO u";
+    let expected = r#"AlterTable: Bare { table: "t" } rename to Bare { table: "u" }"#;
+    quick_test(sql, expected);
+
+    let sql = "ALTER TABLE t ADD COLUMN c INT";
+    let expected = r#"AlterTable: Bare { table: "t" } add column c:Int32"#;
+    quick_test(sql, expected);
+
+    let sql = "ALTER TABLE t SET OPTIONS ('b' '2', 'a' '1')";
+    let expected = r#"AlterTable: Bare { table: "t" } set options [a=1, b=2]"#;
+    quick_test(sql, expected);
+
+    let sql = "ALTER TABLE t ADD COLUMN c INT NOT NULL";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Plan(\"Can not add the NOT NULL column c to a table, the column is null in the rows the table already has\")",
+        format!("{err:?}")
+    );
+}
+
+#[test]
+fn alter_view() {
+    let sql = "ALTER VIEW v RENAME TO w";
+    let expected = r#"AlterView: Bare { table: "v" } rename to Bare { table: "w" }"#;
     quick_test(sql, expected);
 }
 
@@ -2337,6 +2421,32 @@ fn select_typed_time_string() {
     quick_test(sql, expected);
 }
 
+#[test]
+fn select_at_time_zone() {
+    let sql = "SELECT birth_date AT TIME ZONE 'America/New_York' FROM person";
+    let expected = "Projection: CAST(toutctimestamp(person.birth_date, Utf8(\"America/New_York\")) AS Timestamp(Nanosecond, Some(\"America/New_York\")))\
+            \n  TableScan: person";
+    quick_test(sql, expected);
+
+    // a timestamp that already has a timezone is only relabeled
+    let sql = "SELECT (birth_date AT TIME ZONE 'UTC') AT TIME ZONE 'America/New_York' FROM person";
+    let expected = "Projection: CAST(CAST(toutctimestamp(person.birth_date, Utf8(\"UTC\")) AS Timestamp(Nanosecond, Some(\"UTC\"))) AS Timestamp(Nanosecond, Some(\"America/New_York\")))\
+            \n  TableScan: person";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn select_typed_timestamp_with_precision() {
+    let sql = "SELECT CAST(birth_date AS TIMESTAMP(3)) FROM person";
+    let expected = "Projection: CAST(person.birth_date AS Timestamp(Millisecond, None))\
+            \n  TableScan: person";
+    quick_test(sql, expected);
+
+    let sql = "SELECT CAST(birth_date AS TIMESTAMP(2)) FROM person";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert!(matches!(err, DataFusionError::NotImplemented(_)));
+}
+
 #[test]
 fn select_multibyte_column() {
     let sql = r#"SELECT "😀" FROM person"#;
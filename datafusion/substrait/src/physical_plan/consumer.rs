@@ -21,7 +21,9 @@ use datafusion::arrow::datatypes::Schema;
 use datafusion::datasource::listing::PartitionedFile;
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::error::{DataFusionError, Result};
-use datafusion::physical_plan::file_format::{FileScanConfig, ParquetExec};
+use datafusion::physical_plan::file_format::{
+    FileScanConfig, ParquetExec, SchemaEvolution,
+};
 use datafusion::physical_plan::ExecutionPlan;
 use datafusion::prelude::SessionContext;
 use object_store::ObjectMeta;
@@ -110,10 +112,12 @@ pub async fn from_substrait_rel(
                         file_groups,
                         statistics: Default::default(),
                         projection: None,
+                        nested_projection: None,
                         limit: None,
                         table_partition_cols: vec![],
                         output_ordering: None,
                         infinite_source: false,
+                        schema_evolution: SchemaEvolution::Strict,
                     };
 
                     if let Some(MaskExpression { select, .. }) = &read.projection {
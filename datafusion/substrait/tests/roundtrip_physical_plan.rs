@@ -21,7 +21,9 @@ mod tests {
     use datafusion::datasource::listing::PartitionedFile;
     use datafusion::datasource::object_store::ObjectStoreUrl;
     use datafusion::error::Result;
-    use datafusion::physical_plan::file_format::{FileScanConfig, ParquetExec};
+    use datafusion::physical_plan::file_format::{
+        FileScanConfig, ParquetExec, SchemaEvolution,
+    };
     use datafusion::physical_plan::{displayable, ExecutionPlan};
     use datafusion::prelude::SessionContext;
     use datafusion_substrait::physical_plan::{consumer, producer};
@@ -46,10 +48,12 @@ mod tests {
             ],
             statistics: Default::default(),
             projection: None,
+            nested_projection: None,
             limit: None,
             table_partition_cols: vec![],
             output_ordering: None,
             infinite_source: false,
+            schema_evolution: SchemaEvolution::Strict,
         };
         let parquet_exec: Arc<dyn ExecutionPlan> =
             Arc::new(ParquetExec::new(scan_config, None, None));